@@ -0,0 +1,100 @@
+//! Per-frame render cost of a mostly static screen, with and without damage tracking
+//!
+//! Run with `cargo bench -p reratui-core --bench damage`. A clock on the top
+//! row changes every frame while an expensive plot fills the rest of the
+//! screen. With damage tracking the plot copies its cells from the previous
+//! frame instead of recomputing them.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use reratui_core::damage::{Damage, DamageTracker, set_frame_damage};
+use reratui_core::{Component, Element};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const FRAMES: u32 = 200;
+
+/// A plot whose cells are costly to compute and depend on nothing
+#[derive(Clone)]
+struct Plot;
+
+impl Component for Plot {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let value: f64 = (1..64)
+                    .map(|k| (f64::from(x) * 0.1 * f64::from(k)).sin() / f64::from(k))
+                    .sum::<f64>()
+                    + f64::from(y) * 0.01;
+                let symbol = if value.rem_euclid(1.0) > 0.5 {
+                    "█"
+                } else {
+                    "·"
+                };
+                buffer[(x, y)].set_symbol(symbol);
+            }
+        }
+    }
+
+    fn render_into(&self, area: Rect, buffer: &mut Buffer, damage: &Damage) {
+        if !damage.reuse(area, buffer) {
+            self.render(area, buffer);
+        }
+    }
+}
+
+/// A frame counter that changes every frame
+#[derive(Clone)]
+struct Clock {
+    frame: u32,
+}
+
+impl Component for Clock {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        buffer.set_string(
+            area.x,
+            area.y,
+            format!("frame {}", self.frame),
+            Style::default(),
+        );
+    }
+}
+
+/// Renders `FRAMES` frames, returning the average time per frame
+fn run(track_damage: bool) -> Duration {
+    let area = Rect::new(0, 0, 160, 48);
+    let clock_area = Rect { height: 1, ..area };
+    let plot_area = Rect {
+        y: 1,
+        height: area.height - 1,
+        ..area
+    };
+    let mut tracker = DamageTracker::new();
+
+    let start = Instant::now();
+    for frame in 0..FRAMES {
+        let mut buffer = Buffer::empty(area);
+        if track_damage {
+            // Nothing but the clock changes, so no frame is invalidated
+            set_frame_damage(tracker.damage(area, false));
+        }
+        Element::component(Clock { frame }).render(clock_area, &mut buffer);
+        Element::component(Plot).render(plot_area, &mut buffer);
+        tracker.finish_frame(&buffer);
+        black_box(&buffer);
+    }
+    set_frame_damage(Damage::full());
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let full = run(false);
+    let tracked = run(true);
+    println!("full redraw:     {full:>10.2?} per frame");
+    println!("damage tracking: {tracked:>10.2?} per frame");
+    println!(
+        "speedup:         {:>10.1}x",
+        full.as_secs_f64() / tracked.as_secs_f64()
+    );
+}
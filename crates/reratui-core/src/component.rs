@@ -1,16 +1,23 @@
+use crate::damage::{Damage, frame_damage};
 use ratatui::Frame;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 thread_local! {
     // Track mounted component instances and their mount states
     pub(crate) static MOUNT_STATE: std::cell::RefCell<MountState> = Default::default();
+    // ID hashes of the components being rendered, innermost last
+    static RENDERING: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
 }
 
 // Store cleanup callbacks for unmounting
 type CleanupFn = Box<dyn Fn() + 'static>;
 
+// Callbacks registered with `on_current_unmount`
+type UnmountCallback = Box<dyn FnOnce() + 'static>;
+
 #[derive(Default)]
 pub(crate) struct MountState {
     // Tracks all currently mounted components by their ID hash
@@ -19,6 +26,8 @@ pub(crate) struct MountState {
     current_render: std::collections::HashSet<usize>,
     // Cleanup functions for each mounted component
     cleanup_fns: HashMap<usize, CleanupFn>,
+    // Unmount callbacks registered during each component's latest render
+    unmount_callbacks: HashMap<usize, Vec<UnmountCallback>>,
 }
 
 impl MountState {
@@ -26,20 +35,23 @@ impl MountState {
     where
         F: Fn() + 'static,
     {
-        self.current_render.insert(id_hash);
+        // Keep only the callbacks registered by this render
+        if self.current_render.insert(id_hash) {
+            self.unmount_callbacks.remove(&id_hash);
+        }
 
         // Returns true if this is the first time mounting (newly inserted)
         let is_new = self.mounted.insert(id_hash);
 
-        if is_new {
-            // Store cleanup function
-            self.cleanup_fns.insert(id_hash, Box::new(cleanup_fn));
-        }
+        // Keep the latest instance, so `on_unmount` sees its current props
+        self.cleanup_fns.insert(id_hash, Box::new(cleanup_fn));
 
         is_new
     }
 
-    fn cleanup_unmounted(&mut self) {
+    /// Forgets the components missing from the last render, returning
+    /// their `on_unmount` and unmount callbacks in the order to call them
+    fn take_unmounted(&mut self) -> Vec<UnmountCallback> {
         // Find components that were mounted before but not in current render
         let unmounted: Vec<_> = self
             .mounted
@@ -47,29 +59,109 @@ impl MountState {
             .cloned()
             .collect();
 
-        // Call cleanup functions and remove unmounted components
+        // Collect cleanup functions and remove unmounted components
+        let mut callbacks: Vec<UnmountCallback> = Vec::new();
         for &id_hash in &unmounted {
             if let Some(cleanup_fn) = self.cleanup_fns.remove(&id_hash) {
-                cleanup_fn(); // Call on_unmount
+                callbacks.push(Box::new(cleanup_fn)); // Calls on_unmount
             }
+            callbacks.extend(self.unmount_callbacks.remove(&id_hash).unwrap_or_default());
             self.mounted.remove(&id_hash);
         }
 
         // Prepare for next render
         self.current_render.clear();
+        callbacks
+    }
+}
+
+/// Marks a component as being rendered until dropped
+struct RenderingGuard;
+
+impl RenderingGuard {
+    fn enter(id_hash: usize) -> Self {
+        RENDERING.with(|rendering| rendering.borrow_mut().push(id_hash));
+        Self
+    }
+}
+
+impl Drop for RenderingGuard {
+    fn drop(&mut self) {
+        RENDERING.with(|rendering| rendering.borrow_mut().pop());
     }
 }
 
+/// A piece of UI that renders itself into a buffer.
+///
+/// `Component` is object safe: a component can be stored as
+/// `Box<dyn Component>` or `Rc<dyn Component>`, for example to keep the current
+/// screen of a router in state. Both implement `Component` themselves by
+/// delegating to the inner value, so they can be rendered with
+/// `Element::component` or as `<{screen} />` in `rsx!`. Only the methods that
+/// require `Self: Clone` are unavailable on the trait object.
+///
+/// # Lifecycle
+///
+/// A component rendered from an [`Element`](crate::Element) is mounted while
+/// it renders in consecutive frames, identified by its
+/// [`component_id`](Self::component_id):
+///
+/// - [`on_mount`](Self::on_mount) runs once, just before the first render
+///   after the component was not mounted.
+/// - [`on_unmount`](Self::on_unmount) runs once, from [`cleanup_unmounted`]
+///   after the first frame the component did not render in, on the instance
+///   from its last render. The runtime calls `cleanup_unmounted` after each
+///   frame is drawn, and [`unmount_all`] when the app exits, so every mounted
+///   component is unmounted exactly once.
+///
+/// Rendering again later mounts the component anew. Components sharing a
+/// `component_id` share one mount.
 pub trait Component: 'static {
     /// Called once when the component is first mounted
     fn on_mount(&self) {}
 
-    /// Called when the component is about to be unmounted
+    /// Called once when the component leaves the tree
+    ///
+    /// Struct components release what they hold here, such as open files or
+    /// spawned tasks.
     fn on_unmount(&self) {}
 
     /// Called on every render
     fn render(&self, area: Rect, buffer: &mut Buffer);
 
+    /// The rows the component needs when rendered `width` columns wide
+    ///
+    /// Used by [`AutoLayout`](crate::measure::AutoLayout) to size the
+    /// component to its content; `None`, the default, lets it fill the space
+    /// left over. Must not call hooks, see [`measure`](crate::measure).
+    fn measure(&self, width: u16) -> Option<u16> {
+        let _ = width;
+        None
+    }
+
+    /// Renders the component knowing which regions changed since the last frame
+    ///
+    /// Called instead of [`render`](Self::render) when the component is
+    /// rendered from an [`Element`](crate::Element); the default just calls
+    /// `render`. An expensive component whose output depends only on its
+    /// props and state can override it to skip its render body while its
+    /// area is clean:
+    ///
+    /// ```rust,ignore
+    /// fn render_into(&self, area: Rect, buffer: &mut Buffer, damage: &Damage) {
+    ///     if !damage.reuse(area, buffer) {
+    ///         self.render(area, buffer);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// A skipped render makes no hook calls and renders no children, so
+    /// only components that do neither should skip.
+    fn render_into(&self, area: Rect, buffer: &mut Buffer, damage: &Damage) {
+        let _ = damage;
+        self.render(area, buffer);
+    }
+
     /// Gets a unique identifier for this component instance
     fn component_id(&self) -> String {
         // Default implementation uses the type name
@@ -95,13 +187,70 @@ pub trait Component: 'static {
             self_clone.on_unmount();
         };
 
-        track_and_call_lifecycle(self, cleanup_fn);
-        self.render(area, frame.buffer_mut());
+        let id_hash = track_and_call_lifecycle(self, cleanup_fn);
+        let _rendering = RenderingGuard::enter(id_hash);
+        self.render_into(area, frame.buffer_mut(), &frame_damage());
+    }
+}
+
+impl Component for Box<dyn Component> {
+    fn on_mount(&self) {
+        (**self).on_mount()
+    }
+
+    fn on_unmount(&self) {
+        (**self).on_unmount()
+    }
+
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        (**self).render(area, buffer)
+    }
+
+    fn measure(&self, width: u16) -> Option<u16> {
+        (**self).measure(width)
+    }
+
+    fn render_into(&self, area: Rect, buffer: &mut Buffer, damage: &Damage) {
+        (**self).render_into(area, buffer, damage)
+    }
+
+    fn component_id(&self) -> String {
+        // Keep the inner component's identity for lifecycle tracking
+        (**self).component_id()
+    }
+}
+
+impl Component for Rc<dyn Component> {
+    fn on_mount(&self) {
+        (**self).on_mount()
+    }
+
+    fn on_unmount(&self) {
+        (**self).on_unmount()
+    }
+
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        (**self).render(area, buffer)
+    }
+
+    fn measure(&self, width: u16) -> Option<u16> {
+        (**self).measure(width)
+    }
+
+    fn render_into(&self, area: Rect, buffer: &mut Buffer, damage: &Damage) {
+        (**self).render_into(area, buffer, damage)
+    }
+
+    fn component_id(&self) -> String {
+        // Keep the inner component's identity for lifecycle tracking
+        (**self).component_id()
     }
 }
 
 /// Helper function to track component lifecycle and call on_mount if needed
-fn track_and_call_lifecycle<F>(component: &dyn Component, cleanup_fn: F)
+///
+/// Returns the ID hash the component is tracked under.
+fn track_and_call_lifecycle<F>(component: &dyn Component, cleanup_fn: F) -> usize
 where
     F: Fn() + 'static,
 {
@@ -124,6 +273,7 @@ where
     if is_first_render {
         component.on_mount();
     }
+    id_hash
 }
 
 /// Renders a component with lifecycle tracking (on_mount/on_unmount)
@@ -139,15 +289,134 @@ pub(crate) fn render_component_with_lifecycle(
         component_clone.on_unmount();
     };
 
-    track_and_call_lifecycle(component.as_ref(), cleanup_fn);
-    component.render(area, buffer);
+    let id_hash = track_and_call_lifecycle(component.as_ref(), cleanup_fn);
+    let _rendering = RenderingGuard::enter(id_hash);
+    component.render_into(area, buffer, &frame_damage());
 }
 
 /// Cleans up any components that were unmounted in the last render cycle
 /// This should be called after each render cycle
 pub fn cleanup_unmounted() {
-    MOUNT_STATE.with(|state| {
+    let callbacks = MOUNT_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.take_unmounted()
+    });
+    // Called once the state is released, so they may render or register again
+    for callback in callbacks {
+        callback();
+    }
+}
+
+/// Unmounts every mounted component, as when the app exits
+///
+/// Runs their `on_unmount` and unmount callbacks like [`cleanup_unmounted`]
+/// does for components missing from a frame.
+pub fn unmount_all() {
+    let callbacks = MOUNT_STATE.with(|state| {
         let mut state = state.borrow_mut();
-        state.cleanup_unmounted();
+        state.current_render.clear();
+        state.take_unmounted()
+    });
+    for callback in callbacks {
+        callback();
+    }
+}
+
+/// The ID hash of the innermost component being rendered from an
+/// [`Element`](crate::Element), if any
+pub(crate) fn current_component_id() -> Option<usize> {
+    RENDERING.with(|rendering| rendering.borrow().last().copied())
+}
+
+/// Registers `callback` to run when the component being rendered unmounts
+///
+/// Only the callbacks registered during the component's latest render are
+/// kept; they run from [`cleanup_unmounted`] after the first render the
+/// component is missing from. Returns `false`, dropping `callback`, when no
+/// component rendered from an [`Element`](crate::Element) is being rendered.
+pub fn on_current_unmount(callback: impl FnOnce() + 'static) -> bool {
+    let Some(id_hash) = current_component_id() else {
+        return false;
+    };
+    MOUNT_STATE.with(|state| {
+        state
+            .borrow_mut()
+            .unmount_callbacks
+            .entry(id_hash)
+            .or_default()
+            .push(Box::new(callback));
     });
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Element;
+    use std::cell::RefCell;
+
+    /// Records its lifecycle calls, tagged with its label
+    #[derive(Clone)]
+    struct Tracked {
+        label: &'static str,
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Component for Tracked {
+        fn on_mount(&self) {
+            self.log.borrow_mut().push(format!("mount {}", self.label));
+        }
+
+        fn on_unmount(&self) {
+            self.log
+                .borrow_mut()
+                .push(format!("unmount {}", self.label));
+        }
+
+        fn render(&self, _area: Rect, _buffer: &mut Buffer) {}
+    }
+
+    fn frame(component: Option<&Tracked>) {
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buffer = Buffer::empty(area);
+        if let Some(component) = component {
+            Element::component(component.clone()).render(area, &mut buffer);
+        }
+        cleanup_unmounted();
+    }
+
+    #[test]
+    fn test_unmount_runs_once_after_leaving_the_tree() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let tracked = |label| Tracked {
+            label,
+            log: log.clone(),
+        };
+
+        frame(Some(&tracked("first")));
+        frame(Some(&tracked("second")));
+        assert_eq!(*log.borrow(), vec!["mount first"]);
+
+        // The latest instance is unmounted, once
+        frame(None);
+        frame(None);
+        assert_eq!(*log.borrow(), vec!["mount first", "unmount second"]);
+
+        // Coming back mounts it again
+        frame(Some(&tracked("third")));
+        assert_eq!(log.borrow().last().unwrap(), "mount third");
+    }
+
+    #[test]
+    fn test_unmount_all() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        frame(Some(&Tracked {
+            label: "app",
+            log: log.clone(),
+        }));
+
+        unmount_all();
+        unmount_all();
+        assert_eq!(*log.borrow(), vec!["mount app", "unmount app"]);
+    }
 }
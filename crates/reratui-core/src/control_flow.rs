@@ -0,0 +1,302 @@
+//! Components for conditional and repeated rendering
+//!
+//! [`Show`] and [`For`] cover what `{if ...}` and `{for ...}` blocks do in
+//! `rsx!`, as ordinary components: a condition with an optional fallback,
+//! and a keyed list rendered from a function of each item.
+
+use crate::{Component, ComponentProps, Element};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::rc::Rc;
+
+/// Props for the `Show` component
+#[derive(Clone, Default)]
+pub struct ShowProps {
+    pub when: bool,
+    pub fallback: Option<Element>,
+    pub children: Vec<Element>,
+}
+
+impl ShowProps {
+    /// Sets whether the children are shown
+    pub fn when(mut self, when: bool) -> Self {
+        self.when = when;
+        self
+    }
+
+    /// Sets what is shown instead of the children while `when` is false
+    pub fn fallback(mut self, fallback: impl Into<Element>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ShowProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders its children when `when` holds, and the fallback otherwise
+///
+/// Without a fallback nothing is rendered while `when` is false.
+///
+/// ```ignore
+/// rsx! {
+///     <Show when={!items.is_empty()} fallback={rsx! { <Paragraph>{"No items"}</Paragraph> }}>
+///         <ItemList items={items.clone()} />
+///     </Show>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ShowComponent {
+    props: ShowProps,
+}
+
+impl ShowComponent {
+    pub fn new(props: ShowProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for ShowComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        if self.props.when {
+            Element::fragment(self.props.children.clone()).render(area, buffer);
+        } else if let Some(fallback) = &self.props.fallback {
+            fallback.render(area, buffer);
+        }
+    }
+}
+
+/// The `Show` component, usable as `<Show when={...}>...</Show>` in `rsx!`
+pub type Show = ShowComponent;
+
+/// Props for the `For` component
+pub struct ForProps<T> {
+    pub each: Vec<T>,
+    #[allow(clippy::type_complexity)]
+    pub key: Option<Rc<dyn Fn(&T) -> String>>,
+    #[allow(clippy::type_complexity)]
+    pub render: Option<Rc<dyn Fn(&T) -> Element>>,
+    pub fallback: Vec<Element>,
+}
+
+impl<T> Default for ForProps<T> {
+    fn default() -> Self {
+        Self {
+            each: Vec::new(),
+            key: None,
+            render: None,
+            fallback: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for ForProps<T> {
+    fn clone(&self) -> Self {
+        Self {
+            each: self.each.clone(),
+            key: self.key.clone(),
+            render: self.render.clone(),
+            fallback: self.fallback.clone(),
+        }
+    }
+}
+
+impl<T> ForProps<T> {
+    /// Sets the items to render
+    pub fn each(mut self, each: impl IntoIterator<Item = T>) -> Self {
+        self.each = each.into_iter().collect();
+        self
+    }
+
+    /// Sets how each item's key is derived, which becomes its element's key
+    pub fn key(mut self, key: impl Fn(&T) -> String + 'static) -> Self {
+        self.key = Some(Rc::new(key));
+        self
+    }
+
+    /// Sets how each item is rendered
+    pub fn render(mut self, render: impl Fn(&T) -> Element + 'static) -> Self {
+        self.render = Some(Rc::new(render));
+        self
+    }
+
+    /// Sets what is shown while `each` is empty
+    pub fn fallback(mut self, fallback: impl Into<Element>) -> Self {
+        self.fallback = vec![fallback.into()];
+        self
+    }
+
+    /// Children are the fallback, shown while `each` is empty
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.fallback = children;
+        self
+    }
+
+    /// The element of every item, with its key set
+    fn elements(&self) -> Vec<Element> {
+        let Some(render) = &self.render else {
+            return Vec::new();
+        };
+        self.each
+            .iter()
+            .map(|item| {
+                let element = render(item);
+                match &self.key {
+                    Some(key) => element.with_key(key(item)),
+                    None => element,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<T> ComponentProps for ForProps<T> {
+    fn get_children(&self) -> Vec<Element> {
+        self.fallback.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.fallback = children;
+    }
+}
+
+/// Renders one element per item of `each`, stacked like a `{for ...}` block
+///
+/// Each element comes from `render` and is keyed by `key`. While `each` is
+/// empty the fallback is shown, given as `fallback={...}` or as children.
+///
+/// ```ignore
+/// rsx! {
+///     <For
+///         each={todos.clone()}
+///         key={|todo: &Todo| todo.id.to_string()}
+///         render={|todo: &Todo| rsx! { <TodoRow todo={todo.clone()} /> }}
+///     >
+///         <Paragraph>{"Nothing to do"}</Paragraph>
+///     </For>
+/// }
+/// ```
+pub struct ForComponent<T> {
+    props: ForProps<T>,
+}
+
+impl<T> ForComponent<T> {
+    pub fn new(props: ForProps<T>) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl<T> Default for ForComponent<T> {
+    fn default() -> Self {
+        Self::new(ForProps::default())
+    }
+}
+
+impl<T: Clone> Clone for ForComponent<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.props.clone())
+    }
+}
+
+impl<T: 'static> Component for ForComponent<T> {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let elements = if self.props.each.is_empty() {
+            self.props.fallback.clone()
+        } else {
+            self.props.elements()
+        };
+        Element::fragment(elements).render(area, buffer);
+    }
+}
+
+/// The `For` component, usable as `<For each={...} render={...} />` in `rsx!`
+pub type For<T> = ForComponent<T>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+
+    fn text(content: &str) -> Element {
+        Element::widget(Paragraph::new(content.to_string()))
+    }
+
+    fn render(element: Element, height: u16) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, height));
+        element.render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn test_show_picks_children_or_fallback() {
+        let show = |when| {
+            Element::component(
+                Show::new(ShowProps::default().when(when).fallback(text("no")))
+                    .with_children(vec![text("yes")]),
+            )
+        };
+        assert_eq!(render(show(true), 1), Buffer::with_lines(["yes   "]));
+        assert_eq!(render(show(false), 1), Buffer::with_lines(["no    "]));
+
+        let without_fallback = Show::new(ShowProps::default()).with_children(vec![text("yes")]);
+        assert_eq!(
+            render(Element::component(without_fallback), 1),
+            Buffer::with_lines(["      "])
+        );
+    }
+
+    #[test]
+    fn test_for_renders_keyed_items() {
+        let props = ForProps::default()
+            .each(["a", "b"])
+            .key(|item: &&str| format!("item-{item}"))
+            .render(|item: &&str| text(item));
+        let keys: Vec<_> = props
+            .elements()
+            .iter()
+            .map(|element| match element {
+                Element::Widget { key, .. } => key.clone(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            keys,
+            [Some("item-a".to_string()), Some("item-b".to_string())]
+        );
+
+        let list = For::new(props);
+        assert_eq!(
+            render(Element::component(list), 2),
+            Buffer::with_lines(["a     ", "b     "])
+        );
+
+        let empty = For::new(ForProps::<&str>::default().render(|item| text(item)))
+            .with_children(vec![text("none")]);
+        assert_eq!(
+            render(Element::component(empty), 1),
+            Buffer::with_lines(["none  "])
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Damage tracking for skipping unchanged regions
+//!
+//! Every frame is rendered into a fresh buffer, and ratatui only diffs the
+//! result to decide what to write to the terminal. The render bodies still
+//! run in full, which is wasted work for expensive widgets on a screen that
+//! hasn't changed.
+//!
+//! The runtime keeps the previous frame as a back buffer in a
+//! [`DamageTracker`], along with the bounding box of the cells that changed
+//! in it. Before each frame it hands components a [`Damage`] describing what
+//! may differ from the back buffer. Components rendered from an
+//! [`Element`](crate::Element) receive it through
+//! [`Component::render_into`](crate::Component::render_into), and can copy
+//! their clean region back with [`Damage::reuse`] instead of rendering.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    static FRAME_DAMAGE: RefCell<Damage> = RefCell::new(Damage::full());
+}
+
+/// What may have changed since the previous frame
+///
+/// A region is clean when nothing that could affect it has changed, so the
+/// cells of the previous frame are still correct there.
+#[derive(Clone, Debug, Default)]
+pub struct Damage {
+    /// The previous frame, or `None` when everything is dirty
+    back: Option<Rc<Buffer>>,
+    /// Bounding box of the cells that may differ from `back`
+    region: Option<Rect>,
+}
+
+impl Damage {
+    /// Everything is dirty, as on the first frame or after a resize
+    pub fn full() -> Self {
+        Self::default()
+    }
+
+    /// Only `region` may differ from `back`; `None` means nothing does
+    pub fn partial(back: Rc<Buffer>, region: Option<Rect>) -> Self {
+        Self {
+            back: Some(back),
+            region,
+        }
+    }
+
+    /// The dirty region, or `None` when nothing is dirty
+    ///
+    /// Covers the whole frame when everything is dirty.
+    pub fn region(&self) -> Option<Rect> {
+        match &self.back {
+            None => Some(Rect::new(0, 0, u16::MAX, u16::MAX)),
+            Some(_) => self.region,
+        }
+    }
+
+    /// Whether the previous frame's cells in `area` are still correct
+    pub fn is_clean(&self, area: Rect) -> bool {
+        let Some(back) = &self.back else {
+            return false;
+        };
+        back.area.union(area) == back.area
+            && self.region.is_none_or(|region| !region.intersects(area))
+    }
+
+    /// Copies the previous frame's cells for `area` into `buffer` if clean
+    ///
+    /// Returns whether it did; when it returns `false` the caller renders
+    /// `area` as usual.
+    pub fn reuse(&self, area: Rect, buffer: &mut Buffer) -> bool {
+        if !self.is_clean(area) {
+            return false;
+        }
+        let Some(back) = &self.back else {
+            return false;
+        };
+        let area = area.intersection(buffer.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buffer[(x, y)] = back[(x, y)].clone();
+            }
+        }
+        true
+    }
+}
+
+/// Bounding box of the cells that differ between two buffers
+///
+/// Buffers of different sizes differ everywhere, so the area of `current`
+/// is returned. Returns `None` when they are identical.
+pub fn changed_region(previous: &Buffer, current: &Buffer) -> Option<Rect> {
+    if previous.area != current.area {
+        return Some(current.area);
+    }
+
+    let area = current.area;
+    let (mut left, mut top) = (u16::MAX, u16::MAX);
+    let (mut right, mut bottom) = (0, 0);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if previous[(x, y)] != current[(x, y)] {
+                left = left.min(x);
+                top = top.min(y);
+                right = right.max(x + 1);
+                bottom = bottom.max(y + 1);
+            }
+        }
+    }
+    (left < right).then(|| Rect::new(left, top, right - left, bottom - top))
+}
+
+/// Back buffer and damage region kept across frames by the renderer
+#[derive(Debug, Default)]
+pub struct DamageTracker {
+    /// The last finished frame
+    back: Option<Rc<Buffer>>,
+    /// Bounding box of the cells the last frame changed
+    changed: Option<Rect>,
+}
+
+impl DamageTracker {
+    /// Creates a tracker without a previous frame
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The damage for the next frame of size `area`
+    ///
+    /// `invalidated` reports that something components read, such as state
+    /// or input, may have changed since the last frame, which dirties
+    /// everything. Otherwise only the region the last frame changed is
+    /// dirty, so output still settling from a change is redrawn.
+    pub fn damage(&self, area: Rect, invalidated: bool) -> Damage {
+        match &self.back {
+            Some(back) if !invalidated && back.area == area => {
+                Damage::partial(Rc::clone(back), self.changed)
+            }
+            _ => Damage::full(),
+        }
+    }
+
+    /// Keeps `buffer` as the back buffer, returning the region it changed
+    pub fn finish_frame(&mut self, buffer: &Buffer) -> Option<Rect> {
+        self.changed = match &self.back {
+            Some(back) => changed_region(back, buffer),
+            None => Some(buffer.area),
+        };
+        self.back = Some(Rc::new(buffer.clone()));
+        self.changed
+    }
+
+    /// The region the last finished frame changed
+    pub fn last_changed(&self) -> Option<Rect> {
+        self.changed
+    }
+}
+
+/// Sets the damage handed to components for the frame about to render
+///
+/// The runtime calls this before each frame. Outside the runtime every frame
+/// is fully damaged.
+pub fn set_frame_damage(damage: Damage) {
+    FRAME_DAMAGE.with(|frame| *frame.borrow_mut() = damage);
+}
+
+/// The damage of the frame being rendered
+pub fn frame_damage() -> Damage {
+    FRAME_DAMAGE.with(|frame| frame.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(lines: &[&str]) -> Buffer {
+        Buffer::with_lines(lines.iter().copied())
+    }
+
+    #[test]
+    fn test_changed_region_is_bounding_box() {
+        let before = buffer(&["abcd", "efgh", "ijkl"]);
+        assert_eq!(changed_region(&before, &before), None);
+
+        let after = buffer(&["abcd", "eXgh", "ijkY"]);
+        assert_eq!(changed_region(&before, &after), Some(Rect::new(1, 1, 3, 2)));
+
+        let resized = buffer(&["ab"]);
+        assert_eq!(changed_region(&before, &resized), Some(resized.area));
+    }
+
+    #[test]
+    fn test_tracker_damage() {
+        let area = Rect::new(0, 0, 4, 3);
+        let mut tracker = DamageTracker::new();
+        assert!(!tracker.damage(area, false).is_clean(area));
+
+        assert_eq!(
+            tracker.finish_frame(&buffer(&["abcd", "efgh", "ijkl"])),
+            Some(area)
+        );
+        assert_eq!(
+            tracker.finish_frame(&buffer(&["abcd", "efgh", "ijXl"])),
+            Some(Rect::new(2, 2, 1, 1))
+        );
+
+        // Only the region the last frame changed is dirty
+        let damage = tracker.damage(area, false);
+        assert!(damage.is_clean(Rect::new(0, 0, 4, 2)));
+        assert!(!damage.is_clean(Rect::new(2, 1, 2, 2)));
+
+        // Invalidated or resized frames are dirty everywhere
+        assert!(!tracker.damage(area, true).is_clean(Rect::new(0, 0, 1, 1)));
+        let larger = Rect::new(0, 0, 5, 3);
+        assert!(
+            !tracker
+                .damage(larger, false)
+                .is_clean(Rect::new(0, 0, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_reuse_copies_clean_cells() {
+        let area = Rect::new(0, 0, 4, 2);
+        let mut tracker = DamageTracker::new();
+        tracker.finish_frame(&buffer(&["abcd", "efgh"]));
+        tracker.finish_frame(&buffer(&["abcd", "efgh"]));
+        let damage = tracker.damage(area, false);
+        assert_eq!(damage.region(), None);
+
+        let mut next = Buffer::empty(area);
+        assert!(damage.reuse(Rect::new(1, 0, 2, 2), &mut next));
+        assert_eq!(next, buffer(&[" bc ", " fg "]));
+
+        assert!(!Damage::full().reuse(area, &mut next));
+    }
+}
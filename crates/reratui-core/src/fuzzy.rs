@@ -0,0 +1,254 @@
+//! Fuzzy matching for search-as-you-type filters
+//!
+//! A query matches a candidate when its characters appear in the candidate
+//! in order, ignoring case: `"sf"` matches `"Save File"`. Among the ways the
+//! characters can be picked, the best scoring one is used, which favours
+//! matches that run together and matches at the start of words. Filters
+//! rank candidates with [`fuzzy_score`] and draw the matched characters with
+//! the spans of [`fuzzy_highlight`].
+
+use std::ops::Range;
+
+/// How well a query matches a candidate; higher is better
+pub type Score = i64;
+
+/// Awarded for every matched character
+const MATCH: Score = 16;
+/// Extra for a character matched right after the previous one
+const CONSECUTIVE: Score = 24;
+/// Extra for a character matched at the start of a word
+const WORD_START: Score = 20;
+/// Extra for a match on the candidate's first character
+const FIRST_CHAR: Score = 8;
+/// Taken off for every candidate character skipped before or between matches
+const GAP: Score = 1;
+
+/// A query found in a candidate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: Score,
+    /// Positions of the matched characters, counted in `char`s
+    pub indices: Vec<usize>,
+}
+
+/// Whether `current` starts a word, given the character before it
+fn is_word_start(previous: Option<char>, current: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric() && current.is_alphanumeric()
+                || previous.is_lowercase() && current.is_uppercase()
+        }
+    }
+}
+
+fn chars_eq(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Finds `query` in `candidate`, choosing the best scoring characters
+///
+/// Returns `None` unless every character of `query` appears in `candidate`
+/// in order. An empty query matches anything with a score of 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use reratui_core::fuzzy::fuzzy_match;
+///
+/// let found = fuzzy_match("th", "Toggle Theme").unwrap();
+/// // The "Th" of "Theme" rather than the "T" of "Toggle"
+/// assert_eq!(found.indices, [7, 8]);
+/// assert!(fuzzy_match("tx", "Toggle Theme").is_none());
+/// ```
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+    if query.len() > candidate.len() {
+        return None;
+    }
+
+    // What matching each candidate character earns on its own
+    let bonus: Vec<Score> = candidate
+        .iter()
+        .enumerate()
+        .map(|(j, &c)| {
+            let previous = j.checked_sub(1).map(|p| candidate[p]);
+            let mut score = MATCH;
+            if is_word_start(previous, c) {
+                score += WORD_START;
+            }
+            if j == 0 {
+                score += FIRST_CHAR;
+            }
+            score
+        })
+        .collect();
+
+    // best[i][j]: the best score with query[i] matched at candidate[j], and
+    // the position query[i - 1] was matched at to get it
+    let mut best = vec![vec![None::<(Score, usize)>; candidate.len()]; query.len()];
+    for (i, &q) in query.iter().enumerate() {
+        // The best earlier match to continue from, scored as if the gap
+        // reached the start so one subtraction applies the gap penalty
+        let mut jump: Option<(Score, usize)> = None;
+        for j in 0..candidate.len() {
+            let previous = if i > 0 && j > 0 {
+                best[i - 1][j - 1]
+            } else {
+                None
+            };
+            if let Some((score, _)) = previous
+                && jump.is_none_or(|(best, _)| score + GAP * (j - 1) as Score > best)
+            {
+                jump = Some((score + GAP * (j - 1) as Score, j - 1));
+            }
+            if !chars_eq(q, candidate[j]) {
+                continue;
+            }
+
+            best[i][j] = if i == 0 {
+                Some((bonus[j] - GAP * j as Score, 0))
+            } else {
+                let gap =
+                    jump.map(|(score, from)| (score - GAP * (j - 1) as Score + bonus[j], from));
+                let run = previous.map(|(score, _)| (score + bonus[j] + CONSECUTIVE, j - 1));
+                match (gap, run) {
+                    (Some(gap), Some(run)) => Some(if run.0 >= gap.0 { run } else { gap }),
+                    (gap, run) => gap.or(run),
+                }
+            };
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, &(score, _)) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, entry)| entry.as_ref().map(|entry| (j, entry)))
+        .max_by_key(|(j, (score, _))| (*score, std::cmp::Reverse(*j)))?;
+
+    let mut indices = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        indices[i] = j;
+        if i > 0 {
+            j = best[i][j].map(|(_, from)| from)?;
+        }
+    }
+    Some(FuzzyMatch { score, indices })
+}
+
+/// How well `query` matches `candidate`, or `None` if it doesn't
+///
+/// # Examples
+///
+/// ```rust
+/// use reratui_core::fuzzy::fuzzy_score;
+///
+/// // Matches that run together and start words score higher
+/// let save_file = fuzzy_score("sf", "Save File").unwrap();
+/// let preferences = fuzzy_score("sf", "Show preferences").unwrap();
+/// assert!(save_file > preferences);
+/// assert_eq!(fuzzy_score("zz", "Save File"), None);
+/// ```
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<Score> {
+    fuzzy_match(query, candidate).map(|found| found.score)
+}
+
+/// `candidate` split into runs of matched and unmatched characters
+///
+/// The byte ranges cover the whole candidate in order, each flagged with
+/// whether its characters matched `query`. A candidate that doesn't match
+/// comes back as a single unmatched span, so a filter can draw every
+/// candidate the same way.
+///
+/// # Examples
+///
+/// ```rust
+/// use reratui_core::fuzzy::fuzzy_highlight;
+///
+/// assert_eq!(
+///     fuzzy_highlight("sf", "Save File"),
+///     [(0..1, true), (1..5, false), (5..6, true), (6..9, false)]
+/// );
+/// ```
+pub fn fuzzy_highlight(query: &str, candidate: &str) -> Vec<(Range<usize>, bool)> {
+    let indices = fuzzy_match(query, candidate)
+        .map(|found| found.indices)
+        .unwrap_or_default();
+    highlight_indices(candidate, &indices)
+}
+
+/// `candidate` split into runs of characters in and out of `indices`
+///
+/// `indices` are sorted `char` positions, as in [`FuzzyMatch::indices`].
+pub fn highlight_indices(candidate: &str, indices: &[usize]) -> Vec<(Range<usize>, bool)> {
+    let mut spans: Vec<(Range<usize>, bool)> = Vec::new();
+    let mut matched = indices.iter().peekable();
+    for (position, (start, c)) in candidate.char_indices().enumerate() {
+        let is_match = matched.next_if_eq(&&position).is_some();
+        let end = start + c.len_utf8();
+        match spans.last_mut() {
+            Some((range, flag)) if *flag == is_match => range.end = end,
+            _ => spans.push((start..end, is_match)),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_matching() {
+        assert_eq!(fuzzy_match("sfl", "Save File").unwrap().indices, [0, 5, 7]);
+        assert!(fuzzy_match("fs", "Save File").is_none());
+        assert!(fuzzy_match("saves", "Save").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(
+            fuzzy_match("SAVE", "save file").unwrap(),
+            fuzzy_match("save", "save file").unwrap()
+        );
+        assert_eq!(fuzzy_match("é", "Élan").unwrap().indices, [0]);
+    }
+
+    #[test]
+    fn test_contiguous_runs_score_higher() {
+        // "the" together beats the same letters spread out
+        let run = fuzzy_score("the", "the cat").unwrap();
+        let spread = fuzzy_score("the", "to hear").unwrap();
+        assert!(run > spread, "{run} <= {spread}");
+
+        // The run is picked over an earlier scattered match
+        assert_eq!(fuzzy_match("ab", "xaxxab").unwrap().indices, [4, 5]);
+    }
+
+    #[test]
+    fn test_word_starts_score_higher() {
+        let camel = fuzzy_score("fb", "fooBar").unwrap();
+        let inner = fuzzy_score("fb", "foobar").unwrap();
+        assert!(camel > inner, "{camel} <= {inner}");
+        assert_eq!(fuzzy_match("fb", "foo_bar").unwrap().indices, [0, 4]);
+    }
+
+    #[test]
+    fn test_highlight_spans_cover_candidate() {
+        assert_eq!(
+            fuzzy_highlight("日b", "a日本b"),
+            [(0..1, false), (1..4, true), (4..7, false), (7..8, true)]
+        );
+        assert_eq!(fuzzy_highlight("x", "abc"), [(0..3, false)]);
+        assert!(fuzzy_highlight("a", "").is_empty());
+    }
+}
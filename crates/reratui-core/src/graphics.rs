@@ -0,0 +1,48 @@
+//! Terminal graphics drawn over the cell grid
+//!
+//! Graphics protocols such as Kitty's draw pictures with escape sequences
+//! that a [`Buffer`](ratatui::buffer::Buffer) has no room for. A widget that
+//! draws one clears the cells of its area and records a [`GraphicsPlacement`]
+//! with [`place_graphics`] instead. After the frame is drawn, the runtime takes
+//! the placements with [`take_graphics`] and writes their sequences itself.
+//!
+//! Pictures stay on screen until they are cleared, independently of the
+//! cells below them, so the runtime only writes a placement when it is new or
+//! has moved, and writes the `clear` sequence of placements that are gone.
+//! Because the cells under a picture are blank in every frame, ratatui's diff
+//! never touches them and the picture survives redraws around it.
+
+use ratatui::layout::Rect;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+thread_local! {
+    /// Placements recorded so far in the current frame
+    static FRAME_GRAPHICS: RefCell<Vec<GraphicsPlacement>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A picture drawn by escape sequences over an area of the frame
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphicsPlacement {
+    /// Identifies the picture across frames; placements with the same key and
+    /// area are written once
+    pub key: u64,
+    /// The cells the picture covers
+    pub area: Rect,
+    /// Written with the cursor at the top-left cell of `area` to show it
+    pub draw: Arc<[u8]>,
+    /// Written to remove it again
+    pub clear: Arc<[u8]>,
+}
+
+/// Records a placement to be written once the current frame is drawn
+pub fn place_graphics(placement: GraphicsPlacement) {
+    FRAME_GRAPHICS.with(|graphics| graphics.borrow_mut().push(placement));
+}
+
+/// Takes the placements recorded since the last call, in drawing order
+///
+/// The runtime calls this after each frame.
+pub fn take_graphics() -> Vec<GraphicsPlacement> {
+    FRAME_GRAPHICS.with(|graphics| std::mem::take(&mut *graphics.borrow_mut()))
+}
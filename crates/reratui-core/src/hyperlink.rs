@@ -0,0 +1,191 @@
+//! Clickable links in terminal text
+//!
+//! [`Hyperlink`] renders text that terminals supporting OSC 8 turn into a
+//! link to a URL, opened with a click (often Ctrl+click). Terminals without
+//! support show the plain text.
+//!
+//! # How links reach the terminal
+//!
+//! A [`Buffer`] holds symbols and styles only, with no room for escape
+//! sequences, so a `Hyperlink` draws its text like any other widget and
+//! records where it did as a [`LinkRegion`]. After the frame is drawn, the
+//! runtime takes the regions with [`take_hyperlinks`] and prints their cells
+//! again between the OSC 8 sequences that open and close the link. Regions are
+//! only printed again when they are new or their cells changed, since the
+//! terminal keeps the link on cells nothing has overwritten.
+//!
+//! # Limitations
+//!
+//! - A link covers a single row, so the text is cut off at the width of the
+//!   area rather than wrapped.
+//! - Backends other than the runtime's own terminal, such as `TestBackend`
+//!   with `render_to`, get the text without the link.
+//! - Anything drawn over a link later in the frame, such as a portal
+//!   overlay, is printed as part of the link.
+//! - Control characters are removed from URLs, since they would end the
+//!   escape sequence early.
+//!
+//! # Terminal support
+//!
+//! OSC 8 links work in iTerm2, WezTerm, kitty, Ghostty, foot, Konsole,
+//! Alacritty (0.11+), Windows Terminal (1.4+) and terminals based on VTE
+//! (0.50+) such as GNOME Terminal and Tilix. tmux passes them on from 3.4
+//! when the outer terminal is declared to support them, with
+//! `set -as terminal-features ",*:hyperlinks"`. The Linux console, xterm and
+//! older terminals ignore the sequences and show the text alone.
+
+use crate::layout::AnyWidget;
+use crate::vnode::Element;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+use std::cell::RefCell;
+
+thread_local! {
+    /// Links drawn so far in the current frame
+    static FRAME_LINKS: RefCell<Vec<LinkRegion>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Cells of a drawn frame that link to a URL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkRegion {
+    /// The cells holding the link text, on one row
+    pub area: Rect,
+    /// The link target, free of control characters
+    pub url: String,
+}
+
+impl LinkRegion {
+    /// The OSC 8 sequence that starts the link
+    pub fn open_sequence(&self) -> String {
+        format!("\x1b]8;;{}\x1b\\", self.url)
+    }
+
+    /// The OSC 8 sequence that ends any open link
+    pub fn close_sequence() -> &'static str {
+        "\x1b]8;;\x1b\\"
+    }
+}
+
+/// Takes the links drawn since the last call, in drawing order
+///
+/// The runtime calls this after each frame.
+pub fn take_hyperlinks() -> Vec<LinkRegion> {
+    FRAME_LINKS.with(|links| std::mem::take(&mut *links.borrow_mut()))
+}
+
+/// Text linking to a URL, see the [module docs](self) for how it is shown
+///
+/// The text is drawn on the first row of its area, in `style`, and cut off at
+/// the area's width.
+///
+/// ```ignore
+/// use reratui::core::hyperlink::Hyperlink;
+///
+/// Hyperlink::new("reratui on GitHub", "https://github.com/sabry-awad97/reratui")
+///     .style(Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED))
+///     .render(area, buffer);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hyperlink {
+    text: String,
+    url: String,
+    style: Style,
+}
+
+impl Hyperlink {
+    /// A link showing `text` that opens `url`
+    pub fn new(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            url: url.into().chars().filter(|c| !c.is_control()).collect(),
+            style: Style::default(),
+        }
+    }
+
+    /// A link showing its own URL
+    pub fn from_url(url: impl Into<String>) -> Self {
+        let url = url.into();
+        Self::new(url.clone(), url)
+    }
+
+    /// Sets the style of the text
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// The link target
+    pub fn target(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Widget for Hyperlink {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let area = area.intersection(buffer.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let line = Line::styled(self.text, self.style);
+        let width = u16::try_from(line.width())
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        let row = Rect {
+            width,
+            height: 1,
+            ..area
+        };
+        line.render(row, buffer);
+
+        if width > 0 {
+            FRAME_LINKS.with(|links| {
+                links.borrow_mut().push(LinkRegion {
+                    area: row,
+                    url: self.url,
+                })
+            });
+        }
+    }
+}
+
+impl From<Hyperlink> for Element {
+    fn from(link: Hyperlink) -> Self {
+        Element::widget(link)
+    }
+}
+
+impl From<Hyperlink> for AnyWidget {
+    fn from(link: Hyperlink) -> Self {
+        AnyWidget::VNode(Element::widget(link))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperlink_records_its_cells() {
+        let area = Rect::new(2, 1, 6, 3);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        Hyperlink::new("reratui docs", "https://example.com/\x1b]docs").render(area, &mut buffer);
+
+        // Cut off at the area's width, on its first row
+        let row: String = (0..10).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert_eq!(row, "  reratu  ");
+        assert_eq!(
+            take_hyperlinks(),
+            [LinkRegion {
+                area: Rect::new(2, 1, 6, 1),
+                url: "https://example.com/]docs".to_string(),
+            }]
+        );
+
+        Hyperlink::from_url("https://example.com").render(Rect::ZERO, &mut buffer);
+        assert!(take_hyperlinks().is_empty());
+    }
+}
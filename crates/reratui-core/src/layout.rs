@@ -3,6 +3,7 @@
 //! This module provides wrapper components that enable ratatui's Layout and Block
 //! to work with nested children in the RSX macro system.
 
+use crate::measure::{block_chrome, measure_paragraph};
 use crate::vnode::Element;
 use ratatui::{
     buffer::Buffer,
@@ -37,6 +38,27 @@ impl Widget for AnyWidget {
     }
 }
 
+impl AnyWidget {
+    /// The rows this widget needs at `width`, see [`measure`](crate::measure)
+    pub fn measure(&self, width: u16) -> Option<u16> {
+        match self {
+            AnyWidget::Paragraph(paragraph) => measure_paragraph(paragraph, width),
+            AnyWidget::Block(block) => block.measure(width),
+            AnyWidget::VNode(element) => element.measure(width),
+            AnyWidget::Layout(_) | AnyWidget::List(_) => None,
+        }
+    }
+}
+
+impl From<AnyWidget> for Element {
+    fn from(widget: AnyWidget) -> Self {
+        match widget {
+            AnyWidget::VNode(vnode) => vnode,
+            widget => Element::widget(widget),
+        }
+    }
+}
+
 impl From<LayoutWrapper> for AnyWidget {
     fn from(layout: LayoutWrapper) -> Self {
         AnyWidget::Layout(layout)
@@ -222,6 +244,15 @@ impl BlockWrapper {
     pub fn new(block: Block<'static>, children: Vec<AnyWidget>) -> Self {
         Self { block, children }
     }
+
+    /// The rows this block needs at `width`: its chrome plus its children
+    pub fn measure(&self, width: u16) -> Option<u16> {
+        let (chrome, inner_width) = block_chrome(&self.block, width);
+        let content = self.children.iter().try_fold(0u16, |total, child| {
+            Some(total.saturating_add(child.measure(inner_width)?))
+        })?;
+        Some(chrome.saturating_add(content))
+    }
 }
 
 impl Widget for BlockWrapper {
@@ -3,12 +3,27 @@
 //! This crate provides the foundational types and traits for the Reratui framework.
 
 pub mod component;
+pub mod control_flow;
+pub mod damage;
+pub mod fuzzy;
+pub mod graphics;
+pub mod hyperlink;
 pub mod layout;
+pub mod measure;
+pub mod portal;
 pub mod props;
+pub mod snapshot;
+pub mod suspense;
+pub mod text;
 pub mod vnode;
 
 // Re-export commonly used types
 pub use component::Component;
+pub use control_flow::{For, ForComponent, ForProps, Show, ShowComponent, ShowProps};
+pub use hyperlink::Hyperlink;
 pub use layout::{AnyWidget, BlockWrapper, LayoutWrapper};
+pub use measure::{AutoLayout, AutoLayoutComponent, AutoLayoutProps, auto_constraints};
+pub use portal::{Portal, PortalComponent, PortalProps};
 pub use props::ComponentProps;
+pub use suspense::{Suspense, SuspenseComponent, SuspenseProps};
 pub use vnode::{Element, PropValue};
@@ -0,0 +1,251 @@
+//! Content-based sizing
+//!
+//! Layouts usually size their children with fixed constraints such as
+//! `Constraint::Length(7)`, which have to be kept in sync with the content by
+//! hand. Instead, an element can report the height its content needs at a
+//! given width through [`Element::measure`], and [`AutoLayout`] stacks its
+//! children at exactly those heights.
+//!
+//! # Protocol
+//!
+//! `measure(width)` returns the number of rows the element needs when
+//! rendered `width` columns wide, or `None` when it cannot tell. Measuring
+//! must not render: it makes no hook calls and has no side effects, so it can
+//! run any number of times per frame.
+//!
+//! - `Paragraph`s report their line count after wrapping, including the
+//!   borders and padding of their block. Ratatui wraps a paragraph's text at
+//!   the full width even when its block has side borders, so put wrapped
+//!   text in a `<Block>` rather than giving the paragraph a `block`.
+//! - `Block`s report their borders, padding and title rows, plus their
+//!   children stacked vertically.
+//! - Text nodes need no rows.
+//! - Components report [`Component::measure`], which is `None` unless the
+//!   component overrides it. Function components render through hooks, so
+//!   they are not measured; render a measured widget from the parent instead.
+//! - Other widgets and layouts are not measured.
+//!
+//! [`Component::measure`]: crate::Component::measure
+
+use crate::layout::{AnyWidget, BlockWrapper};
+use crate::{Component, ComponentProps, Element};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::widgets::{Block, Paragraph};
+use std::any::Any;
+
+/// Rows `block` takes around its content, and the width left inside it
+pub(crate) fn block_chrome(block: &Block<'_>, width: u16) -> (u16, u16) {
+    let outer = Rect::new(0, 0, width, u16::MAX);
+    let inner = block.inner(outer);
+    (outer.height - inner.height, inner.width)
+}
+
+/// Total height of `elements` stacked vertically, if all of them are measured
+fn measure_stack<'a>(elements: impl IntoIterator<Item = &'a Element>, width: u16) -> Option<u16> {
+    elements.into_iter().try_fold(0u16, |total, element| {
+        Some(total.saturating_add(element.measure(width)?))
+    })
+}
+
+/// Rows `paragraph` takes after wrapping, including its block
+///
+/// `line_count` is unstable in ratatui; a test checks it still agrees with
+/// how paragraphs render.
+pub(crate) fn measure_paragraph(paragraph: &Paragraph<'_>, width: u16) -> Option<u16> {
+    Some(u16::try_from(paragraph.line_count(width)).unwrap_or(u16::MAX))
+}
+
+/// Measures a widget stored in an [`Element::Widget`]
+pub(crate) fn measure_widget(widget: &dyn Any, width: u16) -> Option<u16> {
+    if let Some(paragraph) = widget.downcast_ref::<Paragraph<'static>>() {
+        measure_paragraph(paragraph, width)
+    } else if let Some(block) = widget.downcast_ref::<Block<'static>>() {
+        Some(block_chrome(block, width).0)
+    } else if let Some(block) = widget.downcast_ref::<BlockWrapper>() {
+        block.measure(width)
+    } else if let Some(any) = widget.downcast_ref::<AnyWidget>() {
+        any.measure(width)
+    } else {
+        None
+    }
+}
+
+/// Constraints giving each element the height it measures at `width`
+///
+/// Elements that are not measured share the rows left over equally.
+pub fn auto_constraints(elements: &[Element], width: u16) -> Vec<Constraint> {
+    elements
+        .iter()
+        .map(|element| match element.measure(width) {
+            Some(height) => Constraint::Length(height),
+            None => Constraint::Fill(1),
+        })
+        .collect()
+}
+
+/// Props for the `AutoLayout` component
+#[derive(Clone, Default)]
+pub struct AutoLayoutProps {
+    pub children: Vec<Element>,
+}
+
+impl AutoLayoutProps {
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for AutoLayoutProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Stacks its children vertically, each as tall as its content
+///
+/// Heights come from [`auto_constraints`]; rows no measured child needs go
+/// to the children that are not measured, or stay empty below the last
+/// child. Children that don't fit are cut off at the bottom.
+///
+/// ```ignore
+/// rsx! {
+///     <AutoLayout>
+///         <Paragraph wrap={Wrap { trim: true }}>{description}</Paragraph>
+///         <Block title="Details" borders={Borders::ALL}>
+///             <Paragraph>{details}</Paragraph>
+///         </Block>
+///     </AutoLayout>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct AutoLayoutComponent {
+    props: AutoLayoutProps,
+}
+
+impl AutoLayoutComponent {
+    pub fn new(props: AutoLayoutProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for AutoLayoutComponent {
+    fn measure(&self, width: u16) -> Option<u16> {
+        measure_stack(&self.props.children, width)
+    }
+
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let children = &self.props.children;
+        let mut constraints = auto_constraints(children, area.width);
+        if !constraints.contains(&Constraint::Fill(1)) {
+            // Keep the children at their heights, leaving the rest empty
+            constraints.push(Constraint::Fill(1));
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+        for (child, chunk) in children.iter().zip(chunks.iter()) {
+            child.render(*chunk, buffer);
+        }
+    }
+}
+
+/// The `AutoLayout` component, usable as `<AutoLayout>` in `rsx!`
+pub type AutoLayout = AutoLayoutComponent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::{Borders, Padding, Widget, Wrap};
+
+    #[test]
+    fn test_paragraph_and_block_measurement() {
+        let text = "one two three four";
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        assert_eq!(Element::widget(paragraph.clone()).measure(9), Some(3));
+        assert_eq!(Element::widget(paragraph.clone()).measure(20), Some(1));
+
+        let bordered =
+            Paragraph::new("a\nb").block(Block::bordered().padding(Padding::vertical(1)));
+        assert_eq!(Element::widget(bordered).measure(11), Some(6));
+
+        let block = BlockWrapper::new(
+            Block::default().borders(Borders::ALL),
+            vec![Paragraph::new(text).wrap(Wrap { trim: true }).into()],
+        );
+        assert_eq!(Element::widget(block.clone()).measure(11), Some(5));
+        assert_eq!(Element::widget(AnyWidget::from(block)).measure(22), Some(3));
+
+        assert_eq!(Element::text("").measure(10), Some(0));
+        assert_eq!(Element::widget(Block::bordered()).measure(10), Some(2));
+    }
+
+    /// Guards the unstable `Paragraph::line_count` against ratatui updates
+    #[test]
+    fn test_paragraph_measurement_matches_rendering() {
+        let long = "the quick brown fox jumps over the lazy dog ".repeat(4);
+        // Each paragraph with the blank rows it renders below its last text row
+        let cases = [
+            (Paragraph::new(long.clone()).wrap(Wrap { trim: true }), 0),
+            (Paragraph::new(long.clone()).wrap(Wrap { trim: false }), 0),
+            (
+                Paragraph::new("first line\n  indented second\nthird").wrap(Wrap { trim: true }),
+                0,
+            ),
+            (Paragraph::new("a\nb\nc"), 0),
+            (
+                Paragraph::new(long)
+                    .wrap(Wrap { trim: true })
+                    .block(Block::new().padding(Padding::vertical(1))),
+                1,
+            ),
+        ];
+
+        for width in [7, 12, 30] {
+            for (paragraph, blank_below) in &cases {
+                let area = Rect::new(0, 0, width, 100);
+                let mut buffer = Buffer::empty(area);
+                paragraph.clone().render(area, &mut buffer);
+                let last_text_row = (0..area.height)
+                    .rev()
+                    .find(|&y| (0..width).any(|x| buffer[(x, y)].symbol() != " "))
+                    .map_or(0, |y| y + 1);
+
+                assert_eq!(
+                    measure_paragraph(paragraph, width),
+                    Some(last_text_row + blank_below),
+                    "{paragraph:?} at width {width}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_layout_sizes_children_to_content() {
+        let area = Rect::new(0, 0, 5, 6);
+        let mut buffer = Buffer::empty(area);
+        let layout = AutoLayoutComponent::new(AutoLayoutProps::default().with_children(vec![
+            Element::widget(Paragraph::new("a\nb")),
+            Element::widget(Paragraph::new("c").block(Block::bordered())),
+        ]));
+        assert_eq!(layout.measure(area.width), Some(5));
+
+        layout.render(area, &mut buffer);
+        let rows: Vec<String> = (0..area.height)
+            .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rows, ["a    ", "b    ", "┌───┐", "│c  │", "└───┘", "     "]);
+    }
+}
@@ -0,0 +1,120 @@
+//! Portals for rendering overlays above the rest of the tree
+//!
+//! A [`Portal`] does not draw its children where it appears. Instead they are
+//! queued on the portal layer and painted over the full frame area once the
+//! normal tree has rendered, so modals and tooltips declared deep in the tree
+//! are not clipped by their parent's area.
+//!
+//! Portal children render after the components around the portal have
+//! finished, so context provided inside those components is no longer
+//! available to them; only context provided at the root is. Pass the values
+//! a portal needs as props instead.
+
+use crate::{Component, ComponentProps, Element};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::cell::RefCell;
+
+/// Children queued by portals during the current frame
+struct PortalEntry {
+    z_index: i32,
+    children: Vec<Element>,
+}
+
+thread_local! {
+    static PORTAL_LAYER: RefCell<Vec<PortalEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Paints the children queued by portals over `area`
+///
+/// Portals are painted in ascending `z_index` order; portals with the same
+/// `z_index` keep the order in which they rendered. Portals rendered by portal
+/// children are painted afterwards, above them. The runtime calls this after
+/// rendering the root element of each frame.
+pub fn render_portals(area: Rect, buffer: &mut Buffer) {
+    loop {
+        let mut entries = PORTAL_LAYER.with(|layer| std::mem::take(&mut *layer.borrow_mut()));
+        if entries.is_empty() {
+            return;
+        }
+
+        entries.sort_by_key(|entry| entry.z_index);
+        for entry in entries {
+            for child in &entry.children {
+                child.render(area, buffer);
+            }
+        }
+    }
+}
+
+/// Props for the `Portal` component
+#[derive(Clone, Default)]
+pub struct PortalProps {
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl PortalProps {
+    /// Sets the stacking order relative to other portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for PortalProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders its children above the rest of the tree, over the full frame area
+///
+/// ```ignore
+/// rsx! {
+///     <Block title="Settings" borders={Borders::ALL}>
+///         <Paragraph>{"Press d to delete"}</Paragraph>
+///         <Portal z_index={1}>
+///             <ConfirmDialog />
+///         </Portal>
+///     </Block>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct PortalComponent {
+    props: PortalProps,
+}
+
+impl PortalComponent {
+    pub fn new(props: PortalProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for PortalComponent {
+    fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+        PORTAL_LAYER.with(|layer| {
+            layer.borrow_mut().push(PortalEntry {
+                z_index: self.props.z_index,
+                children: self.props.children.clone(),
+            })
+        });
+    }
+}
+
+/// The `Portal` component, usable as `<Portal z_index={1}>...</Portal>` in `rsx!`
+pub type Portal = PortalComponent;
@@ -0,0 +1,173 @@
+//! Text snapshots of rendered buffers
+//!
+//! [`snapshot`] turns a [`Buffer`] into the characters on screen, one line
+//! per row, and [`styled_snapshot`] adds a list of the styled runs below
+//! them. Both are stable, diff-friendly strings for snapshot assertions such
+//! as `insta::assert_snapshot!`, locking down what a component looks like.
+//!
+//! ```ignore
+//! use ratatui::backend::TestBackend;
+//! use reratui::core::snapshot::styled_snapshot;
+//!
+//! let terminal = render_to(TestBackend::new(40, 8), || rsx! { <HelpPanel /> }).await?;
+//! insta::assert_snapshot!(styled_snapshot(terminal.backend().buffer()));
+//! ```
+//!
+//! A styled snapshot looks like this, with each run given as
+//! `row:columns` followed by the attributes that differ from the default:
+//!
+//! ```text
+//! ┌Help──────┐
+//! │q: Quit   │
+//! └──────────┘
+//! --- styles ---
+//! 0:0..12 fg=Cyan
+//! 1:1..2 fg=Yellow BOLD
+//! 2:0..12 fg=Cyan
+//! ```
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use std::fmt::Write;
+use unicode_width::UnicodeWidthStr;
+
+/// Separates the text of a styled snapshot from its runs
+const STYLES_HEADER: &str = "--- styles ---";
+
+/// The characters of `buffer`, one line per row
+///
+/// Trailing spaces are trimmed from every line, so snapshots survive editors
+/// that strip them. Wide characters appear once, however many cells they
+/// cover.
+pub fn snapshot(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut text = String::new();
+    for y in area.top()..area.bottom() {
+        let start = text.len();
+        for (_, cell) in visible_cells(buffer, y) {
+            text.push_str(cell.symbol());
+        }
+        text.truncate(start + text[start..].trim_end_matches(' ').len());
+        if y + 1 < area.bottom() {
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// The characters of `buffer` followed by its styled runs
+///
+/// A run is a stretch of cells on one row sharing their foreground,
+/// background and modifiers. Runs with the default style are left out, so a
+/// plain buffer has an empty style list.
+pub fn styled_snapshot(buffer: &Buffer) -> String {
+    let mut text = snapshot(buffer);
+    text.push('\n');
+    text.push_str(STYLES_HEADER);
+
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut run: Option<(u16, u16, (Color, Color, Modifier))> = None;
+        for (x, cell) in visible_cells(buffer, y) {
+            let style = (cell.fg, cell.bg, cell.modifier);
+            let end = x + cell.symbol().width().max(1) as u16;
+            match &mut run {
+                Some((_, run_end, run_style)) if *run_style == style && *run_end == x => {
+                    *run_end = end;
+                }
+                _ => {
+                    if let Some(finished) = run.replace((x, end, style)) {
+                        write_run(&mut text, y, finished);
+                    }
+                }
+            }
+        }
+        if let Some(finished) = run {
+            write_run(&mut text, y, finished);
+        }
+    }
+    text
+}
+
+/// The cells of row `y` that start a symbol, with their column
+fn visible_cells(buffer: &Buffer, y: u16) -> impl Iterator<Item = (u16, &Cell)> {
+    let area = buffer.area;
+    let mut to_skip = 0;
+    (area.left()..area.right()).filter_map(move |x| {
+        let cell = &buffer[(x, y)];
+        if to_skip > 0 {
+            to_skip -= 1;
+            None
+        } else {
+            to_skip = cell.symbol().width().saturating_sub(1);
+            Some((x, cell))
+        }
+    })
+}
+
+/// Appends a run as `row:start..end` and its non-default attributes
+fn write_run(text: &mut String, y: u16, (start, end, style): (u16, u16, (Color, Color, Modifier))) {
+    let (fg, bg, modifier) = style;
+    if fg == Color::Reset && bg == Color::Reset && modifier.is_empty() {
+        return;
+    }
+
+    let _ = write!(text, "\n{y}:{start}..{end}");
+    if fg != Color::Reset {
+        let _ = write!(text, " fg={fg}");
+    }
+    if bg != Color::Reset {
+        let _ = write!(text, " bg={bg}");
+    }
+    for (name, _) in modifier.iter_names() {
+        let _ = write!(text, " {name}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+    use ratatui::widgets::{Block, Widget};
+
+    #[test]
+    fn test_snapshot_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        Block::bordered()
+            .title("Help")
+            .render(Rect::new(0, 0, 6, 3), &mut buffer);
+        buffer.set_string(1, 1, "日本", Style::default());
+
+        assert_eq!(snapshot(&buffer), "┌Help┐\n│日本│\n└────┘");
+    }
+
+    #[test]
+    fn test_styled_snapshot_lists_runs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        buffer.set_string(
+            0,
+            0,
+            "q",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        buffer.set_string(1, 0, ": Quit", Style::default());
+        buffer.set_string(
+            2,
+            1,
+            "ok",
+            Style::default().fg(Color::Rgb(1, 2, 3)).bg(Color::Blue),
+        );
+
+        assert_eq!(
+            styled_snapshot(&buffer),
+            "q: Quit\n  ok\n--- styles ---\n0:0..1 fg=Yellow BOLD\n1:2..4 fg=#010203 bg=Blue"
+        );
+        assert_eq!(
+            styled_snapshot(&Buffer::empty(Rect::new(0, 0, 2, 1))),
+            "\n--- styles ---"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Suspense boundaries for hoisting loading states
+//!
+//! A [`Suspense`] boundary renders its children every frame, so their hooks
+//! keep running, but shows its fallback instead of them while any descendant
+//! is waiting on async work. Descendants report that by calling [`suspend`]
+//! during their render; `use_future` and `use_query` do so on their own until
+//! they first have data, so a boundary around components that fetch data
+//! shows one loading state until every fetch has settled. Refreshes don't
+//! suspend, so loaded content stays visible while it reloads.
+//!
+//! Boundaries nest: [`suspend`] reaches only the innermost boundary being
+//! rendered, which shows its own fallback while the boundaries around it keep
+//! showing their children. Outside any boundary, [`suspend`] does nothing.
+//!
+//! Whichever of the children and the fallback is hidden doesn't receive
+//! input: while [`is_hidden`] is true, `use_event` and the handlers built on
+//! it see no event. A boundary decides what is hidden from what it showed the
+//! frame before, so in the frame a boundary first suspends its children still
+//! receive the event.
+//!
+//! Portals, hyperlinks and graphics recorded by suspended children are still
+//! shown, since they are painted outside the boundary's area.
+
+use crate::component::{current_component_id, on_current_unmount};
+use crate::{Component, ComponentProps, Element};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+
+thread_local! {
+    /// Whether each boundary being rendered was suspended, innermost last
+    static BOUNDARIES: RefCell<Vec<bool>> = const { RefCell::new(Vec::new()) };
+    /// Boundaries, by component ID hash, that showed their fallback last frame
+    static SUSPENDED: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+    /// How many hidden subtrees are being rendered
+    static HIDDEN_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Whether the element being rendered is hidden by a [`Suspense`] boundary
+///
+/// True inside the children of a boundary showing its fallback, and inside
+/// the fallback of one showing its children. Hidden content still renders,
+/// but shouldn't react to input.
+pub fn is_hidden() -> bool {
+    HIDDEN_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Suspends the innermost [`Suspense`] boundary being rendered
+///
+/// Called by components waiting on async work, during their render. The
+/// boundary shows its fallback for this frame.
+pub fn suspend() {
+    BOUNDARIES.with(|boundaries| {
+        if let Some(suspended) = boundaries.borrow_mut().last_mut() {
+            *suspended = true;
+        }
+    });
+}
+
+/// Props for the `Suspense` component
+#[derive(Clone, Default)]
+pub struct SuspenseProps {
+    pub fallback: Option<Element>,
+    pub children: Vec<Element>,
+}
+
+impl SuspenseProps {
+    /// Sets what is shown while a descendant is suspended
+    pub fn fallback(mut self, fallback: impl Into<Element>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for SuspenseProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Shows a fallback instead of its children while any of them is suspended
+///
+/// The children and the fallback both render into copies of the area each
+/// frame, so the hooks of each run every frame, and the children are copied
+/// to the screen once none of them called [`suspend`]. Without a fallback the
+/// area keeps what was behind it while suspended.
+///
+/// ```ignore
+/// rsx! {
+///     <Suspense fallback={rsx! { <Spinner /> }}>
+///         <UserProfile />
+///         <Notifications />
+///     </Suspense>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SuspenseComponent {
+    props: SuspenseProps,
+}
+
+impl SuspenseComponent {
+    pub fn new(props: SuspenseProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for SuspenseComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let area = area.intersection(buffer.area);
+        let id = current_component_id();
+        let was_suspended =
+            id.is_some_and(|id| SUSPENDED.with(|suspended| suspended.borrow().contains(&id)));
+
+        BOUNDARIES.with(|boundaries| boundaries.borrow_mut().push(false));
+        let children = render_offscreen(&self.props.children, area, buffer, was_suspended);
+        let suspended = BOUNDARIES
+            .with(|boundaries| boundaries.borrow_mut().pop())
+            .unwrap_or(false);
+
+        // The fallback renders every frame too, so its hooks are called in
+        // the same order whether or not it is shown
+        let fallback =
+            render_offscreen(self.props.fallback.as_slice(), area, buffer, !was_suspended);
+
+        if let Some(id) = id {
+            SUSPENDED.with(|boundaries| {
+                let mut boundaries = boundaries.borrow_mut();
+                if suspended {
+                    boundaries.insert(id);
+                } else {
+                    boundaries.remove(&id);
+                }
+            });
+            on_current_unmount(move || {
+                SUSPENDED.with(|boundaries| boundaries.borrow_mut().remove(&id));
+            });
+        }
+
+        buffer.merge(if suspended { &fallback } else { &children });
+    }
+}
+
+/// Renders `elements` into a copy of `area` of `buffer`, over what is behind them
+fn render_offscreen(elements: &[Element], area: Rect, buffer: &Buffer, hidden: bool) -> Buffer {
+    let mut scratch = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            scratch[(x, y)] = buffer[(x, y)].clone();
+        }
+    }
+    let _hidden = hidden.then(HiddenGuard::enter);
+    Element::fragment(elements.to_vec()).render(area, &mut scratch);
+    scratch
+}
+
+/// Marks the subtree being rendered as hidden until dropped
+struct HiddenGuard;
+
+impl HiddenGuard {
+    fn enter() -> Self {
+        HIDDEN_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        Self
+    }
+}
+
+impl Drop for HiddenGuard {
+    fn drop(&mut self) {
+        HIDDEN_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// The `Suspense` component, usable as `<Suspense fallback={...}>...</Suspense>` in `rsx!`
+pub type Suspense = SuspenseComponent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::{Paragraph, Widget};
+
+    fn text(content: &str) -> Element {
+        Element::widget(Paragraph::new(content.to_string()))
+    }
+
+    /// Shows "data", suspending while `pending`
+    #[derive(Clone)]
+    struct Fetching {
+        pending: bool,
+    }
+
+    impl Component for Fetching {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            if self.pending {
+                suspend();
+            }
+            Paragraph::new("data").render(area, buffer);
+        }
+    }
+
+    fn boundary(fallback: &str, children: Vec<Element>) -> Element {
+        Element::component(
+            Suspense::new(SuspenseProps::default().fallback(text(fallback)))
+                .with_children(children),
+        )
+    }
+
+    fn render(element: Element) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        element.render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn test_suspense_shows_fallback_while_pending() {
+        let pending = boundary("wait", vec![Element::component(Fetching { pending: true })]);
+        assert_eq!(render(pending), Buffer::with_lines(["wait  "]));
+
+        let settled = boundary(
+            "wait",
+            vec![Element::component(Fetching { pending: false })],
+        );
+        assert_eq!(render(settled), Buffer::with_lines(["data  "]));
+
+        // Outside a boundary nothing is suspended
+        assert_eq!(
+            render(Element::component(Fetching { pending: true })),
+            Buffer::with_lines(["data  "])
+        );
+    }
+
+    /// Records whether it was hidden when rendered
+    #[derive(Clone)]
+    struct Probe {
+        pending: bool,
+        hidden: std::rc::Rc<Cell<bool>>,
+    }
+
+    impl Component for Probe {
+        fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+            self.hidden.set(is_hidden());
+            if self.pending {
+                suspend();
+            }
+        }
+    }
+
+    #[test]
+    fn test_hidden_content_is_reported() {
+        let children_hidden = std::rc::Rc::new(Cell::new(false));
+        let fallback_hidden = std::rc::Rc::new(Cell::new(false));
+        let frame = |pending: bool| {
+            let probe = |pending, hidden: &std::rc::Rc<Cell<bool>>| {
+                Element::component(Probe {
+                    pending,
+                    hidden: hidden.clone(),
+                })
+            };
+            let suspense =
+                Suspense::new(SuspenseProps::default().fallback(probe(false, &fallback_hidden)))
+                    .with_children(vec![probe(pending, &children_hidden)]);
+            render(Element::component(suspense));
+            crate::component::cleanup_unmounted();
+            (children_hidden.get(), fallback_hidden.get())
+        };
+
+        // Shown children aren't hidden; the unused fallback is
+        assert_eq!(frame(false), (false, true));
+        // The frame the children first suspend they still count as shown
+        assert_eq!(frame(true), (false, true));
+        // From then on the children are hidden behind the fallback
+        assert_eq!(frame(true), (true, false));
+        assert_eq!(frame(false), (true, false));
+        assert_eq!(frame(false), (false, true));
+        assert!(!is_hidden());
+    }
+
+    #[test]
+    fn test_innermost_boundary_catches() {
+        let nested = boundary(
+            "outer",
+            vec![boundary(
+                "inner",
+                vec![Element::component(Fetching { pending: true })],
+            )],
+        );
+        assert_eq!(render(nested), Buffer::with_lines(["inner "]));
+    }
+}
@@ -0,0 +1,131 @@
+//! Measuring and fitting text to terminal columns
+//!
+//! A string's `len()` counts bytes and `chars().count()` counts code points,
+//! but neither is the number of cells the terminal draws: CJK characters and
+//! most emoji take two cells, while combining marks and joiners take none.
+//! These helpers work in display columns and never split a grapheme, so
+//! columns of text line up whatever they contain.
+
+use ratatui::layout::Alignment;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Number of terminal columns `text` takes up
+///
+/// # Examples
+///
+/// ```rust
+/// use reratui_core::text::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("🎨 art"), 6);
+/// assert_eq!(display_width("日本"), 4);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+/// The longest prefix of `text` that fits in `width` columns
+///
+/// Graphemes are kept whole, so a wide character that would straddle the
+/// limit is dropped and the result may be one column narrower than `width`.
+///
+/// # Examples
+///
+/// ```rust
+/// use reratui_core::text::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("hello", 3), "hel");
+/// assert_eq!(truncate_to_width("日本語", 5), "日本");
+/// ```
+pub fn truncate_to_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > width {
+            return &text[..index];
+        }
+    }
+    text
+}
+
+/// `text` fitted to exactly `width` columns
+///
+/// Text that is too wide is truncated; the rest of the space is filled with
+/// spaces placed according to `alignment`. Centered text leans left when the
+/// padding can't be split evenly.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::layout::Alignment;
+/// use reratui_core::text::pad_to_width;
+///
+/// assert_eq!(pad_to_width("🎨", 4, Alignment::Left), "🎨  ");
+/// assert_eq!(pad_to_width("42", 5, Alignment::Right), "   42");
+/// assert_eq!(pad_to_width("toolong", 4, Alignment::Center), "tool");
+/// ```
+pub fn pad_to_width(text: &str, width: usize, alignment: Alignment) -> String {
+    let text = truncate_to_width(text, width);
+    let padding = width - display_width(text);
+    let (left, right) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::Right => (padding, 0),
+    };
+
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_width_graphemes_stay_whole() {
+        // `e` with a combining accent, and a family joined from four people
+        let accented = "e\u{301}";
+        let family = "👨\u{200d}👩\u{200d}👧\u{200d}👦";
+
+        assert_eq!(display_width(accented), 1);
+        assert_eq!(truncate_to_width(accented, 1), accented);
+        assert_eq!(truncate_to_width(&format!("{family}!"), 1), "");
+        assert_eq!(truncate_to_width("ab", 0), "");
+    }
+
+    #[test]
+    fn test_wide_character_at_the_edge_is_padded() {
+        assert_eq!(pad_to_width("a日", 2, Alignment::Left), "a ");
+        assert_eq!(pad_to_width("日", 3, Alignment::Center), "日 ");
+    }
+
+    #[test]
+    fn test_mixed_columns_align() {
+        let rows = [
+            ("alice", "Admin"),
+            ("🎨 bob", "User"),
+            ("山田太郎", "Moderator"),
+            ("zoë", "User"),
+        ];
+
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|(name, role)| {
+                format!(
+                    "{}|{}|",
+                    pad_to_width(name, 8, Alignment::Left),
+                    pad_to_width(role, 6, Alignment::Right)
+                )
+            })
+            .collect();
+
+        // Every separator lands in the same column
+        for line in &lines {
+            let (name, rest) = line.split_once('|').unwrap();
+            assert_eq!(display_width(name), 8, "{line}");
+            assert_eq!(display_width(rest), 7, "{line}");
+        }
+        assert_eq!(lines[1], "🎨 bob  |  User|");
+        assert_eq!(lines[2], "山田太郎|Modera|");
+    }
+}
@@ -1,7 +1,7 @@
 //! Virtual DOM node types
 
 use crate::component::Component;
-use crate::layout::{AnyWidget, LayoutWrapper};
+use crate::layout::LayoutWrapper;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 use std::{
@@ -92,27 +92,25 @@ impl Element {
     }
 
     /// Creates a fragment containing multiple elements.
-    /// This creates a container that can hold and render multiple child elements.
+    ///
+    /// A fragment has no layout of its own: its children are stacked
+    /// vertically in whatever area the fragment is rendered into, each getting
+    /// an equal share of the rows. Inside a parent layout that is the chunk
+    /// the fragment was given, such as the space left after a fixed header, so
+    /// the elements of a `for` loop in `rsx!` land on distinct rows instead of
+    /// drawing over each other. A single element is returned as is.
     pub fn fragment(elements: Vec<Element>) -> Self {
         if elements.is_empty() {
             Element::text("")
         } else if elements.len() == 1 {
             elements.into_iter().next().unwrap()
         } else {
-            // Create a fragment container that holds all elements
-
-            // Convert all elements to AnyWidget
-            let children: Vec<AnyWidget> = elements.into_iter().map(AnyWidget::VNode).collect();
-
-            // Create a vertical layout with equal constraints for each child
-            let constraints: Vec<Constraint> =
-                (0..children.len()).map(|_| Constraint::Min(0)).collect();
-
-            let layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints(constraints);
-
-            let layout_wrapper = LayoutWrapper::new(layout, children);
+            let constraints = vec![Constraint::Fill(1); elements.len()];
+            let layout_wrapper = LayoutWrapper::from_elements_with_constraints(
+                Layout::default().direction(Direction::Vertical),
+                elements,
+                constraints,
+            );
 
             Element::Widget {
                 widget: Rc::new(layout_wrapper),
@@ -136,6 +134,20 @@ impl Element {
         self
     }
 
+    /// The rows this node needs when rendered `width` columns wide
+    ///
+    /// `None` when the node can't tell; see [`measure`](crate::measure) for
+    /// which nodes are measured.
+    pub fn measure(&self, width: u16) -> Option<u16> {
+        match self {
+            Element::Component { component, .. } => component.measure(width),
+            Element::Widget { widget, .. } => {
+                crate::measure::measure_widget(widget.as_ref(), width)
+            }
+            Element::Text(_) => Some(0),
+        }
+    }
+
     /// Renders this node to the buffer.
     pub fn render(&self, area: Rect, buffer: &mut Buffer) {
         match self {
@@ -169,3 +181,47 @@ pub enum PropValue {
     /// Represents an object property value.
     Object(Rc<dyn Any>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+
+    fn rows(element: &Element, area: Rect) -> Vec<String> {
+        let mut buffer = Buffer::empty(area);
+        element.render(area, &mut buffer);
+        (0..area.height)
+            .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    fn items() -> Element {
+        Element::fragment(
+            ["one", "two", "six"]
+                .map(|text| Element::widget(Paragraph::new(text)))
+                .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_fragment_stacks_children_vertically() {
+        assert_eq!(rows(&items(), Rect::new(0, 0, 3, 3)), ["one", "two", "six"]);
+        assert_eq!(
+            rows(&items(), Rect::new(0, 0, 3, 6)),
+            ["one", "   ", "two", "   ", "six", "   "]
+        );
+    }
+
+    #[test]
+    fn test_fragment_fills_leftover_space_of_parent_layout() {
+        let layout = LayoutWrapper::from_elements_with_constraints(
+            Layout::default().direction(Direction::Vertical),
+            vec![Element::widget(Paragraph::new("top")), items()],
+            vec![Constraint::Length(1), Constraint::Min(0)],
+        );
+        assert_eq!(
+            rows(&Element::widget(layout), Rect::new(0, 0, 3, 4)),
+            ["top", "one", "two", "six"]
+        );
+    }
+}
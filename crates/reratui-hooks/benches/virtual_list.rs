@@ -0,0 +1,64 @@
+//! Per-frame render cost of a 100,000 row list, materialized versus virtualized
+//!
+//! Run with `cargo bench -p reratui-hooks --bench virtual_list`. The full list
+//! builds a `ListItem` for every row each frame, as a `<List>` over the data
+//! would; the `VirtualList` only renders the rows in view.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{List, ListItem, ListState, Paragraph, StatefulWidget};
+use reratui_core::{Component, Element};
+use reratui_hooks::hook_context::{HookContext, set_hook_context, with_hook_context};
+use reratui_hooks::scroll::{VirtualListComponent, VirtualListProps};
+use std::hint::black_box;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+const ROWS: usize = 100_000;
+const FRAMES: u32 = 50;
+
+fn label(index: usize) -> String {
+    format!("{index:>6} INFO request handled in {}ms", index % 997)
+}
+
+/// Renders `FRAMES` frames with `render`, returning the average time per frame
+fn run(mut render: impl FnMut(Rect, &mut Buffer)) -> Duration {
+    let area = Rect::new(0, 0, 120, 40);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        let mut buffer = Buffer::empty(area);
+        render(area, &mut buffer);
+        black_box(&buffer);
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let lines: Rc<Vec<String>> = Rc::new((0..ROWS).map(label).collect());
+
+    let full = run(|area, buffer| {
+        let items: Vec<ListItem> = lines
+            .iter()
+            .map(|line| ListItem::new(line.clone()))
+            .collect();
+        List::new(items).render(area, buffer, &mut ListState::default());
+    });
+
+    set_hook_context(Rc::new(HookContext::new()));
+    let list =
+        VirtualListComponent::new(VirtualListProps::default().item_count(ROWS).render_item({
+            let lines = lines.clone();
+            move |index| Element::widget(Paragraph::new(lines[index].clone()))
+        }));
+    let virtualized = run(|area, buffer| {
+        with_hook_context(|ctx| ctx.reset_hook_index());
+        list.render(area, buffer);
+    });
+
+    println!("full list:   {full:>10.2?} per frame");
+    println!("virtualized: {virtualized:>10.2?} per frame");
+    println!(
+        "speedup:     {:>10.1}x",
+        full.as_secs_f64() / virtualized.as_secs_f64()
+    );
+}
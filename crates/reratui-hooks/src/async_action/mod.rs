@@ -0,0 +1,204 @@
+//! Async action hook for running async work on demand
+//!
+//! Unlike `use_future`, which runs whenever its dependencies change, `use_async`
+//! does nothing until [`AsyncHandle::execute`] is called, e.g. from a key
+//! handler. The handle exposes the same [`FutureState`] lifecycle.
+//! `use_mutation` builds callbacks, retries and cancellation status on top of
+//! the same idea.
+
+use parking_lot::{Mutex, RwLock};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::task::JoinHandle;
+
+use crate::future::{DefaultError, FutureState};
+use crate::panic_handler::spawn_registered;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// Boxed async action stored by the handle
+type AsyncAction<Args, T, E> =
+    Arc<dyn Fn(Args) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send + Sync>;
+
+/// A handle to an on-demand async action
+///
+/// Clones share the same state, so a handle can be moved into event handlers
+/// and read during render.
+pub struct AsyncHandle<T, E = DefaultError, Args = ()> {
+    state: Arc<RwLock<FutureState<T, E>>>,
+    action: Arc<RwLock<AsyncAction<Args, T, E>>>,
+    task_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// Execution counter; only the latest execution may update the state
+    generation: Arc<AtomicU64>,
+}
+
+impl<T, E, Args> AsyncHandle<T, E, Args>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+    Args: 'static,
+{
+    fn new(action: AsyncAction<Args, T, E>) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(FutureState::Idle)),
+            action: Arc::new(RwLock::new(action)),
+            task_handle: Arc::new(Mutex::new(None)),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Runs the action with `args`
+    ///
+    /// The state becomes `Pending` until the action finishes. Calling
+    /// `execute` again while a run is in flight cancels that run.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn execute(&self, args: Args) {
+        self.cancel();
+        let generation = self.generation.load(Ordering::Acquire);
+        *self.state.write() = FutureState::Pending;
+
+        let future = (self.action.read())(args);
+        let state = self.state.clone();
+        let current_generation = self.generation.clone();
+
+        let task = spawn_registered(async move {
+            let result = future.await;
+
+            // The state lock is held while checking so a cancelled run never
+            // overwrites a newer one
+            let mut state = state.write();
+            if current_generation.load(Ordering::Acquire) == generation {
+                *state = result.into();
+            }
+        });
+        *self.task_handle.lock() = Some(task);
+    }
+
+    /// Cancels any run in flight and returns to `Idle`
+    pub fn reset(&self) {
+        self.cancel();
+        *self.state.write() = FutureState::Idle;
+    }
+
+    /// Get the current state of the action
+    pub fn state(&self) -> FutureState<T, E> {
+        self.state.read().clone()
+    }
+
+    /// Returns true while a run is in flight
+    pub fn is_pending(&self) -> bool {
+        self.state.read().is_pending()
+    }
+
+    /// Returns the value of the last successful run, if the state is `Resolved`
+    pub fn value(&self) -> Option<T> {
+        self.state.read().value().cloned()
+    }
+
+    /// Returns the error of the last failed run, if the state is `Error`
+    pub fn error(&self) -> Option<E> {
+        self.state.read().error().cloned()
+    }
+
+    fn set_action(&self, action: AsyncAction<Args, T, E>) {
+        *self.action.write() = action;
+    }
+}
+
+impl<T, E, Args> AsyncHandle<T, E, Args> {
+    /// Invalidates the current run and aborts its task
+    fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        if let Some(task) = self.task_handle.lock().take() {
+            task.abort();
+        }
+    }
+}
+
+impl<T, E, Args> Clone for AsyncHandle<T, E, Args> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            action: self.action.clone(),
+            task_handle: self.task_handle.clone(),
+            generation: self.generation.clone(),
+        }
+    }
+}
+
+impl<T, E, Args> fmt::Debug for AsyncHandle<T, E, Args>
+where
+    T: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncHandle")
+            .field("state", &*self.state.read())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Hook state that cancels the in-flight run when the component unmounts
+struct AsyncHookState<T, E, Args> {
+    handle: AsyncHandle<T, E, Args>,
+}
+
+impl<T, E, Args> Drop for AsyncHookState<T, E, Args> {
+    fn drop(&mut self) {
+        self.handle.cancel();
+    }
+}
+
+/// Hook for async work triggered imperatively rather than by dependencies
+///
+/// Returns an [`AsyncHandle`] whose [`execute`](AsyncHandle::execute) runs
+/// `action` with the given arguments. The latest `action` passed to the hook
+/// is used, so it may capture values from the current render. Running the
+/// action again cancels the previous run, and an unmount cancels it too.
+///
+/// # Example
+/// ```rust,no_run
+/// use reratui_hooks::async_action::use_async;
+/// use reratui_hooks::future::FutureState;
+///
+/// let save = use_async(|name: String| async move {
+///     // Persist the name somewhere
+///     Ok::<String, String>(format!("Saved {}", name))
+/// });
+///
+/// // In a key handler:
+/// save.execute("report.txt".to_string());
+///
+/// match save.state() {
+///     FutureState::Idle => println!("Press s to save"),
+///     FutureState::Pending => println!("Saving..."),
+///     FutureState::Resolved(message) => println!("{}", message),
+///     FutureState::Error(err) => println!("Save failed: {}", err),
+/// }
+/// ```
+pub fn use_async<T, E, Args, F, Fut>(action: F) -> AsyncHandle<T, E, Args>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+    Args: 'static,
+    F: Fn(Args) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+{
+    let action: AsyncAction<Args, T, E> = Arc::new(move |args| Box::pin(action(args)));
+
+    let action_for_init = action.clone();
+    let hook_state = use_ref(move || AsyncHookState {
+        handle: AsyncHandle::new(action_for_init),
+    });
+    let handle = hook_state.with(|hook_state| hook_state.handle.clone());
+
+    // Keep the latest closure so execute sees values from this render
+    handle.set_action(action);
+    handle
+}
@@ -0,0 +1,81 @@
+//! Tests for the use_async hook
+
+use super::*;
+use crate::test_utils::{with_async_component_id, with_async_test_isolate};
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_use_async_runs_only_on_execute() {
+    with_async_test_isolate(|| async {
+        with_async_component_id("AsyncExecuteComponent", |_context| async {
+            let double = use_async(|n: i32| async move { Ok::<i32, String>(n * 2) });
+
+            assert_eq!(double.state(), FutureState::Idle);
+            sleep(Duration::from_millis(10)).await;
+            assert_eq!(double.state(), FutureState::Idle);
+
+            double.execute(21);
+            assert!(double.is_pending());
+
+            sleep(Duration::from_millis(10)).await;
+            assert_eq!(double.value(), Some(42));
+        })
+        .await;
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_use_async_error_and_reset() {
+    with_async_test_isolate(|| async {
+        with_async_component_id("AsyncErrorComponent", |_context| async {
+            let action = use_async(|_: ()| async { Err::<i32, String>("offline".to_string()) });
+
+            action.execute(());
+            sleep(Duration::from_millis(10)).await;
+            assert_eq!(action.error(), Some("offline".to_string()));
+
+            action.reset();
+            assert_eq!(action.state(), FutureState::Idle);
+        })
+        .await;
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_use_async_latest_execution_wins() {
+    with_async_test_isolate(|| async {
+        with_async_component_id("AsyncLatestComponent", |_context| async {
+            let load = use_async(|delay_ms: u64| async move {
+                sleep(Duration::from_millis(delay_ms)).await;
+                Ok::<u64, String>(delay_ms)
+            });
+
+            load.execute(50);
+            load.execute(5);
+
+            sleep(Duration::from_millis(100)).await;
+            assert_eq!(load.value(), Some(5));
+        })
+        .await;
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_use_async_uses_latest_action() {
+    with_async_test_isolate(|| async {
+        for label in ["first", "second"] {
+            with_async_component_id("AsyncLatestActionComponent", |_context| async move {
+                let greet = use_async(move |_: ()| async move { Ok::<&str, String>(label) });
+                greet.execute(());
+                sleep(Duration::from_millis(10)).await;
+                assert_eq!(greet.value(), Some(label));
+            })
+            .await;
+        }
+    })
+    .await;
+}
@@ -0,0 +1,225 @@
+//! Typed event bus for communication between components
+//!
+//! This module provides the `use_event_bus` hook. Any component can emit a
+//! value of type `T`, and every component subscribed to `T` receives it,
+//! without lifting state into a common parent. Values are queued when
+//! emitted and delivered between frames, when the render loop calls
+//! [`flush_event_bus`].
+//!
+//! Channels are kept per type, keyed by `TypeId`, on an [`EventBus`]. The
+//! bus provided as context is used when there is one, and a global bus
+//! otherwise.
+
+use crate::callback::Callback;
+use crate::context::try_use_context;
+use crate::hook_context::with_hook_context;
+use parking_lot::Mutex;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+
+#[cfg(test)]
+mod tests;
+
+type Delivery = Box<dyn FnOnce() + Send>;
+
+/// Emitted values waiting for the render loop
+static DELIVERIES: Mutex<Vec<Delivery>> = Mutex::new(Vec::new());
+
+static GLOBAL_BUS: LazyLock<EventBus> = LazyLock::new(EventBus::new);
+
+/// Delivers the values emitted since the last call to their subscribers
+///
+/// The runtime calls this after drawing each frame. Subscribers are called
+/// in the order they subscribed, and the next frame is rendered in full
+/// since they may have changed anything. Returns `true` if any value was
+/// delivered.
+pub fn flush_event_bus() -> bool {
+    let deliveries = std::mem::take(&mut *DELIVERIES.lock());
+
+    let delivered = !deliveries.is_empty();
+    for deliver in deliveries {
+        deliver();
+    }
+    if delivered {
+        crate::state::bump_state_epoch();
+    }
+    delivered
+}
+
+/// The subscribers of one type of value
+struct Channel<T> {
+    subscribers: Mutex<Vec<(u64, Callback<T>)>>,
+    next_id: AtomicU64,
+}
+
+impl<T: Clone + Send + 'static> Channel<T> {
+    fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Queues `value` for delivery to the subscribers at the next flush
+    fn emit(self: &Arc<Self>, value: T) {
+        let channel = Arc::clone(self);
+        DELIVERIES.lock().push(Box::new(move || {
+            let subscribers = channel.subscribers.lock().clone();
+            for (_, callback) in subscribers {
+                callback.emit(value.clone());
+            }
+        }));
+    }
+
+    /// Sets the callback of subscription `id`, replacing any previous one
+    fn subscribe(&self, id: u64, callback: Callback<T>) {
+        let mut subscribers = self.subscribers.lock();
+        match subscribers.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) => *existing = callback,
+            None => subscribers.push((id, callback)),
+        }
+    }
+
+    fn unsubscribe(&self, id: u64) {
+        self.subscribers
+            .lock()
+            .retain(|(existing, _)| *existing != id);
+    }
+}
+
+/// Broadcast channels for values of any type, one per `TypeId`
+///
+/// A global bus is used unless one is provided as context, which scopes the
+/// communication to the provider's subtree. Create it once and provide
+/// clones, so every render shares the same channels.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::bus::EventBus;
+/// use reratui_hooks::context::use_context_provider;
+/// use reratui_hooks::ref_hook::use_ref;
+///
+/// let bus = use_ref(EventBus::new).get();
+/// use_context_provider(|| bus.clone());
+/// ```
+#[derive(Clone, Default)]
+pub struct EventBus {
+    channels: Arc<Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl EventBus {
+    /// Creates a bus without any subscribers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bus used when none is provided as context
+    pub fn global() -> Self {
+        GLOBAL_BUS.clone()
+    }
+
+    /// The channel for values of type `T`
+    fn channel<T: Clone + Send + 'static>(&self) -> Arc<Channel<T>> {
+        let channel = Arc::clone(
+            self.channels
+                .lock()
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Arc::new(Channel::<T>::new())),
+        );
+        channel
+            .downcast()
+            .expect("event bus channels are keyed by their value type")
+    }
+}
+
+/// The hook state of a subscription, removed from its channel when dropped
+struct Subscription<T: Clone + Send + 'static> {
+    channel: Arc<Channel<T>>,
+    id: u64,
+}
+
+impl<T: Clone + Send + 'static> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        self.channel.unsubscribe(self.id);
+    }
+}
+
+/// A hook for sending and receiving values of type `T` across components
+///
+/// Returns an `emit` function, which queues a value for every subscriber to
+/// `T`, and a `subscribe` function, which sets the callback this component
+/// receives values with. Emitted values are delivered between frames, and
+/// the frame after a delivery is rendered in full.
+///
+/// Each call of the hook holds one subscription: subscribing again, as on
+/// every render, replaces its callback rather than adding another. The
+/// subscription ends when the component unmounts.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::bus::use_event_bus;
+/// use reratui_hooks::callback::Callback;
+/// use reratui_hooks::state::use_state;
+///
+/// #[derive(Clone)]
+/// struct Toast {
+///     message: String,
+/// }
+///
+/// // In any component
+/// let (emit, _subscribe) = use_event_bus::<Toast>();
+/// emit(Toast { message: "Saved".to_string() });
+///
+/// // In a single Toaster component
+/// let (toasts, set_toasts) = use_state(Vec::<String>::new);
+/// let (_emit, subscribe) = use_event_bus::<Toast>();
+/// subscribe(Callback::new(move |toast: Toast| {
+///     set_toasts.update(|toasts| {
+///         let mut toasts = toasts.clone();
+///         toasts.push(toast.message);
+///         toasts
+///     });
+/// }));
+/// ```
+pub fn use_event_bus<T>() -> (
+    impl Fn(T) + Clone + Send + Sync + 'static,
+    impl Fn(Callback<T>) + Clone + 'static,
+)
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let bus = try_use_context::<EventBus>().unwrap_or_else(EventBus::global);
+    let channel = bus.channel::<T>();
+
+    let subscription = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || Subscription {
+            id: channel.next_id.fetch_add(1, Ordering::Relaxed),
+            channel: Arc::clone(&channel),
+        })
+    });
+
+    // A different bus may be provided than on the last render
+    {
+        let mut subscription = subscription.borrow_mut();
+        if !Arc::ptr_eq(&subscription.channel, &channel) {
+            *subscription = Subscription {
+                id: channel.next_id.fetch_add(1, Ordering::Relaxed),
+                channel: Arc::clone(&channel),
+            };
+        }
+    }
+
+    let id = subscription.borrow().id;
+    let subscribed = Arc::clone(&channel);
+    let on_unmount = Arc::clone(&channel);
+    reratui_core::component::on_current_unmount(move || on_unmount.unsubscribe(id));
+
+    let emit = move |value: T| channel.emit(value);
+    let subscribe = move |callback: Callback<T>| subscribed.subscribe(id, callback);
+    (emit, subscribe)
+}
@@ -0,0 +1,127 @@
+//! Tests for the event bus hook
+
+use super::*;
+use crate::context::use_context_provider;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use reratui_core::{Component, Element};
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel tests from flushing each other's deliveries
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[derive(Clone, Debug, PartialEq)]
+struct Toast(&'static str);
+
+type Received = Arc<Mutex<Vec<Toast>>>;
+
+fn toaster(received: &Received) {
+    let received = received.clone();
+    let (_emit, subscribe) = use_event_bus::<Toast>();
+    subscribe(Callback::new(move |toast| received.lock().push(toast)));
+}
+
+#[test]
+fn test_values_are_delivered_between_frames() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        flush_event_bus();
+        let bus = EventBus::new();
+        let received: Received = Arc::default();
+
+        for _ in 0..2 {
+            with_component_id("BusToasterTest", |_ctx| {
+                use_context_provider(|| bus.clone());
+                toaster(&received);
+            });
+        }
+        let emit = with_component_id("BusEmitterTest", |_ctx| {
+            use_context_provider(|| bus.clone());
+            use_event_bus::<Toast>().0
+        });
+
+        emit(Toast("saved"));
+        emit(Toast("closed"));
+        assert!(received.lock().is_empty(), "Delivery waits for the flush");
+
+        let epoch = crate::state::state_epoch();
+        assert!(flush_event_bus());
+        assert_eq!(*received.lock(), [Toast("saved"), Toast("closed")]);
+        assert_ne!(crate::state::state_epoch(), epoch, "Delivery marks dirty");
+
+        // Subscribing on every render kept a single subscription
+        assert!(!flush_event_bus());
+        assert_eq!(received.lock().len(), 2);
+    });
+}
+
+/// Subscribes while rendered from an element
+struct Toaster {
+    bus: EventBus,
+    received: Received,
+}
+
+impl Component for Toaster {
+    fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+        use_context_provider(|| self.bus.clone());
+        toaster(&self.received);
+    }
+}
+
+#[test]
+fn test_subscription_ends_on_unmount() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        flush_event_bus();
+        let bus = EventBus::new();
+        let received: Received = Arc::default();
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buffer = Buffer::empty(area);
+
+        let render = |children: Vec<Element>, buffer: &mut Buffer| {
+            with_component_id("BusUnmountTest", |_ctx| {
+                Element::fragment(children).render(area, buffer);
+            });
+            reratui_core::component::cleanup_unmounted();
+        };
+        let toaster = Element::component(Toaster {
+            bus: bus.clone(),
+            received: received.clone(),
+        });
+
+        render(vec![toaster], &mut buffer);
+        bus.channel::<Toast>().emit(Toast("first"));
+        flush_event_bus();
+        assert_eq!(*received.lock(), [Toast("first")]);
+
+        render(Vec::new(), &mut buffer);
+        bus.channel::<Toast>().emit(Toast("second"));
+        flush_event_bus();
+        assert_eq!(*received.lock(), [Toast("first")]);
+        assert!(bus.channel::<Toast>().subscribers.lock().is_empty());
+    });
+}
+
+#[test]
+fn test_channels_are_typed() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        flush_event_bus();
+        let bus = EventBus::new();
+        let counts = Arc::new(Mutex::new(Vec::new()));
+
+        with_component_id("BusTypedTest", |_ctx| {
+            use_context_provider(|| bus.clone());
+            let counts = counts.clone();
+            let (emit_toast, _) = use_event_bus::<Toast>();
+            let (emit_count, subscribe_count) = use_event_bus::<u32>();
+            subscribe_count(Callback::new(move |count| counts.lock().push(count)));
+
+            emit_toast(Toast("ignored"));
+            emit_count(7);
+        });
+        flush_event_bus();
+        assert_eq!(*counts.lock(), [7]);
+    });
+}
@@ -9,6 +9,17 @@ use crate::hook_context::with_hook_context;
 /// This provides a type-safe way to pass function callbacks between components,
 /// similar to React's event handlers and Yew's callback system.
 ///
+/// `Callback<IN>` is fire-and-forget. `Callback<IN, OUT>` returns a value from
+/// [`emit`](Self::emit), so a parent can ask a child a question synchronously,
+/// or a handler can report whether it handled an event:
+///
+/// ```rust
+/// use reratui_hooks::callback::Callback;
+///
+/// let is_valid: Callback<&str, bool> = Callback::from(|name: &str| !name.is_empty());
+/// assert!(is_valid.emit("reratui"));
+/// assert!(!is_valid.emit(""));
+/// ```
 pub struct Callback<IN, OUT = ()> {
     callback: Arc<dyn Fn(IN) -> OUT + Send + Sync>,
 }
@@ -279,6 +290,9 @@ where
     }
 }
 
+#[cfg(test)]
+mod tests;
+
 /// Internal state for tracking memoized callbacks
 struct CallbackState<IN, OUT> {
     /// Previous dependencies for comparison
@@ -304,6 +318,16 @@ impl<IN, OUT> CallbackState<IN, OUT> {
 ///
 /// Uses the same dependency system as effect hooks for consistency.
 ///
+/// # Identity
+///
+/// Callbacks compare equal when they share the same closure, so the callback
+/// returned on every render with unchanged `deps` is `==` to the one from the
+/// render before. A child that compares its props to skip work, or an effect
+/// that lists the callback as a dependency, sees no change. A closure passed
+/// as a prop directly is a new callback each render. Passing `()` as `deps`
+/// keeps the first closure for the component's lifetime, so it should only
+/// capture values that don't change, such as state setters.
+///
 /// # Arguments
 ///
 /// * `func` - The callback function to memoize
@@ -0,0 +1,81 @@
+use super::*;
+use crate::hook_context::{HookContext, set_hook_context};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Helper function to set up a hook context for testing
+fn setup_context() -> Rc<HookContext> {
+    let context = Rc::new(HookContext::new());
+    set_hook_context(context.clone());
+    context
+}
+
+#[test]
+fn test_callback_stable_with_same_deps() {
+    let context = setup_context();
+
+    let first = use_callback(|value: i32| value * 2, 1);
+
+    // Re-render with a fresh closure and the same dependency
+    context.reset_hook_index();
+    let second = use_callback(|value: i32| value * 3, 1);
+
+    assert_eq!(first, second);
+    assert_eq!(second.emit(5), 10);
+
+    context.clear();
+}
+
+#[test]
+fn test_callback_recreated_when_deps_change() {
+    let context = setup_context();
+
+    let first = use_callback(|value: i32| value * 2, 1);
+
+    context.reset_hook_index();
+    let second = use_callback(|value: i32| value * 3, 2);
+
+    assert_ne!(first, second);
+    assert_eq!(second.emit(5), 15);
+
+    // Stable again from the new dependency on
+    context.reset_hook_index();
+    let third = use_callback(|value: i32| value * 4, 2);
+    assert_eq!(second, third);
+
+    context.clear();
+}
+
+#[test]
+fn test_callback_without_deps_created_once() {
+    let context = setup_context();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let first = use_callback::<_, _, _, ()>(
+        {
+            let calls = calls.clone();
+            move |_: ()| {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+        None,
+    );
+
+    context.reset_hook_index();
+    let second = use_callback::<_, _, _, ()>(|_: ()| {}, None);
+
+    assert_eq!(first, second);
+    second.emit(());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    context.clear();
+}
+
+#[test]
+fn test_inline_closures_differ() {
+    let first: Callback<i32, i32> = Callback::from(|value: i32| value);
+    let second: Callback<i32, i32> = Callback::from(|value: i32| value);
+
+    assert_ne!(first, second);
+    assert_eq!(first, first.clone());
+}
@@ -0,0 +1,90 @@
+//! Capabilities Hook - What the terminal supports
+//!
+//! Filename: mod.rs
+//! Folder: /crates/reratui-hooks/src/capabilities/
+//!
+//! The runtime works out what the terminal supports once at startup, from
+//! environment variables and from the terminal's answers to a few queries,
+//! and records it with [`set_terminal_capabilities`]. Components read it with
+//! [`use_terminal_capabilities`] to degrade gracefully, e.g. falling back from
+//! `Color::Rgb` to the 16 named colors when truecolor isn't available.
+
+use parking_lot::RwLock;
+
+#[cfg(test)]
+mod tests;
+
+/// The capabilities recorded by the runtime, until then the defaults
+static CAPABILITIES: RwLock<TerminalCaps> = RwLock::new(TerminalCaps::UNKNOWN);
+
+/// Features of the terminal the application is running in
+///
+/// Each flag is `true` only when the terminal was found to support the
+/// feature, except [`mouse`](Self::mouse), so a terminal that doesn't answer
+/// gets the conservative [`default`](Self::default).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerminalCaps {
+    /// 24-bit colors: `Color::Rgb` is shown as given rather than approximated
+    pub truecolor: bool,
+    /// The 256-color palette, for `Color::Indexed` beyond the first 16 colors
+    pub ansi256: bool,
+    /// Mouse events are reported, which the runtime enables in fullscreen mode
+    pub mouse: bool,
+    /// The kitty keyboard protocol, which reports key releases and repeats
+    pub keyboard_enhancement: bool,
+    /// The Kitty graphics protocol, for drawing images
+    pub kitty_graphics: bool,
+    /// Sixel graphics
+    pub sixel: bool,
+}
+
+impl TerminalCaps {
+    /// What is assumed before anything is detected
+    pub const UNKNOWN: Self = Self {
+        truecolor: false,
+        ansi256: false,
+        mouse: true,
+        keyboard_enhancement: false,
+        kitty_graphics: false,
+        sixel: false,
+    };
+
+    /// Whether the terminal can draw images with any graphics protocol
+    pub fn graphics(&self) -> bool {
+        self.kitty_graphics || self.sixel
+    }
+}
+
+impl Default for TerminalCaps {
+    fn default() -> Self {
+        Self::UNKNOWN
+    }
+}
+
+/// Record the detected terminal capabilities
+///
+/// Called by the runtime after querying the terminal at startup.
+pub fn set_terminal_capabilities(caps: TerminalCaps) {
+    *CAPABILITIES.write() = caps;
+}
+
+/// Returns what the terminal supports
+///
+/// The runtime detects this once when it starts. Before that, and wherever
+/// detection was inconclusive, the conservative [`TerminalCaps::default`]
+/// applies: no truecolor, graphics or keyboard enhancement.
+///
+/// # Example
+/// ```rust,no_run
+/// use ratatui::style::Color;
+/// use reratui_hooks::capabilities::use_terminal_capabilities;
+///
+/// let accent = if use_terminal_capabilities().truecolor {
+///     Color::Rgb(255, 121, 198)
+/// } else {
+///     Color::LightMagenta
+/// };
+/// ```
+pub fn use_terminal_capabilities() -> TerminalCaps {
+    *CAPABILITIES.read()
+}
@@ -0,0 +1,21 @@
+//! Tests for the capabilities hook
+
+use super::*;
+
+#[test]
+fn test_set_terminal_capabilities() {
+    assert_eq!(use_terminal_capabilities(), TerminalCaps::default());
+    assert!(!TerminalCaps::default().graphics());
+
+    let caps = TerminalCaps {
+        truecolor: true,
+        kitty_graphics: true,
+        ..TerminalCaps::default()
+    };
+    set_terminal_capabilities(caps);
+    assert_eq!(use_terminal_capabilities(), caps);
+    assert!(use_terminal_capabilities().graphics());
+
+    set_terminal_capabilities(TerminalCaps::default());
+    assert_eq!(use_terminal_capabilities(), TerminalCaps::UNKNOWN);
+}
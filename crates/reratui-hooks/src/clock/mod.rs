@@ -0,0 +1,272 @@
+//! Clock - The source of time for time-based hooks
+//!
+//! Filename: mod.rs
+//! Folder: /crates/reratui-hooks/src/clock/
+//!
+//! Hooks that depend on time, such as `use_timeout`, `use_interval`, the
+//! stopwatch and countdown, toasts and frame timestamps, read the time with
+//! [`now`] and wait with [`sleep`] instead of calling `Instant::now` or tokio
+//! directly. Both go through the [`Clock`] installed on the current thread,
+//! which is the [`SystemClock`] unless a test installs a [`MockClock`] with
+//! [`set_clock`].
+//!
+//! A mock clock stands still until it is advanced, and advancing it wakes
+//! the sleepers that are due, so a test can fire a five-second timeout
+//! without waiting five seconds. The runtime's `TestHarness` installs one and
+//! advances it with `advance_time`.
+//!
+//! # Threads
+//!
+//! The clock is per thread. Tasks spawned on a current-thread tokio runtime,
+//! as in `#[tokio::test]`, run on the test's thread and see its clock.
+
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// A future completing once a clock reaches a deadline
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+thread_local! {
+    /// The clock installed on this thread, if not the system clock
+    static CLOCK: RefCell<Option<Arc<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+/// A source of the current time and of timers
+pub trait Clock: Send + Sync + 'static {
+    /// The current time
+    fn now(&self) -> Instant;
+
+    /// A future that completes once `deadline` has passed on this clock
+    fn sleep_until(&self, deadline: Instant) -> Sleep;
+
+    /// Whether time only moves when advanced, rather than on its own
+    ///
+    /// Hooks that would otherwise wait on a background thread run on the
+    /// tokio runtime instead under a virtual clock, so advancing it drives
+    /// them deterministically.
+    fn is_virtual(&self) -> bool {
+        false
+    }
+}
+
+/// The real time, with tokio timers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
+
+/// A clock that only moves when advanced, for tests
+///
+/// Clones share the same time.
+///
+/// # Example
+/// ```rust,no_run
+/// use reratui_hooks::clock::{MockClock, now, set_clock};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let clock = MockClock::new();
+/// set_clock(Arc::new(clock.clone()));
+///
+/// let start = now();
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(now() - start, Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    now: Instant,
+    /// Id for the next sleeper
+    next_id: u64,
+    /// Sleepers waiting for their deadline, one entry per pending sleeper
+    sleepers: Vec<Sleeper>,
+}
+
+/// A pending [`MockSleep`] and the waker from its last poll
+#[derive(Debug)]
+struct Sleeper {
+    id: u64,
+    deadline: Instant,
+    waker: Waker,
+}
+
+impl MockClock {
+    /// A clock standing at the current real time
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState {
+                now: Instant::now(),
+                next_id: 0,
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `duration`, waking the sleepers now due
+    pub fn advance(&self, duration: Duration) {
+        let target = self.now() + duration;
+        self.advance_to(target);
+    }
+
+    /// Moves the clock forward to `instant`, waking the sleepers now due
+    ///
+    /// The clock never goes back; an instant in the past leaves it unchanged.
+    pub fn advance_to(&self, instant: Instant) {
+        let due = {
+            let mut state = self.state.lock();
+            state.now = state.now.max(instant);
+            let now = state.now;
+            let (due, waiting) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition::<Vec<_>, _>(|sleeper| sleeper.deadline <= now);
+            state.sleepers = waiting;
+            due
+        };
+        for sleeper in due {
+            sleeper.waker.wake();
+        }
+    }
+
+    /// The earliest deadline a sleeper is waiting for
+    pub fn next_deadline(&self) -> Option<Instant> {
+        let state = self.state.lock();
+        state.sleepers.iter().map(|sleeper| sleeper.deadline).min()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().now
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        let id = {
+            let mut state = self.state.lock();
+            state.next_id += 1;
+            state.next_id
+        };
+        Box::pin(MockSleep {
+            clock: self.clone(),
+            id,
+            deadline,
+        })
+    }
+
+    fn is_virtual(&self) -> bool {
+        true
+    }
+}
+
+/// Sleeper on a [`MockClock`]
+///
+/// Registered with the clock while pending; dropping it unregisters it, so a
+/// cancelled sleep no longer counts toward [`MockClock::next_deadline`].
+struct MockSleep {
+    clock: MockClock,
+    id: u64,
+    deadline: Instant,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.clock.state.lock();
+        if state.now >= self.deadline {
+            return Poll::Ready(());
+        }
+        let waker = context.waker();
+        match state
+            .sleepers
+            .iter_mut()
+            .find(|sleeper| sleeper.id == self.id)
+        {
+            Some(sleeper) => {
+                if !sleeper.waker.will_wake(waker) {
+                    sleeper.waker = waker.clone();
+                }
+            }
+            None => state.sleepers.push(Sleeper {
+                id: self.id,
+                deadline: self.deadline,
+                waker: waker.clone(),
+            }),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for MockSleep {
+    fn drop(&mut self) {
+        self.clock
+            .state
+            .lock()
+            .sleepers
+            .retain(|sleeper| sleeper.id != self.id);
+    }
+}
+
+/// Install `clock` as the source of time on this thread
+pub fn set_clock(clock: Arc<dyn Clock>) {
+    CLOCK.with(|current| *current.borrow_mut() = Some(clock));
+}
+
+/// Go back to the system clock on this thread
+pub fn clear_clock() {
+    CLOCK.with(|current| current.borrow_mut().take());
+}
+
+/// The clock installed on this thread
+pub fn current_clock() -> Arc<dyn Clock> {
+    CLOCK
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| Arc::new(SystemClock))
+}
+
+/// The current time on this thread's clock
+pub fn now() -> Instant {
+    CLOCK.with(|current| match current.borrow().as_ref() {
+        Some(clock) => clock.now(),
+        None => Instant::now(),
+    })
+}
+
+/// Waits for `duration` on this thread's clock
+///
+/// The clock and deadline are taken when this is called, not when the future
+/// is first polled.
+pub fn sleep(duration: Duration) -> Sleep {
+    let clock = current_clock();
+    clock.sleep_until(clock.now() + duration)
+}
+
+/// Waits until `deadline` on this thread's clock
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    current_clock().sleep_until(deadline)
+}
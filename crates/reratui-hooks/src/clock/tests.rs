@@ -0,0 +1,101 @@
+//! Tests for the clock
+
+use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Wake;
+
+/// Records whether it was woken
+#[derive(Default)]
+struct Flag(AtomicBool);
+
+impl Wake for Flag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+fn poll(sleep: &mut Sleep, flag: &Arc<Flag>) -> Poll<()> {
+    let waker = Waker::from(flag.clone());
+    sleep.as_mut().poll(&mut Context::from_waker(&waker))
+}
+
+#[test]
+fn test_mock_clock_only_moves_when_advanced() {
+    let clock = MockClock::new();
+    let start = clock.now();
+    assert_eq!(clock.now(), start);
+
+    clock.advance(Duration::from_millis(250));
+    assert_eq!(clock.now() - start, Duration::from_millis(250));
+
+    // Never goes back
+    clock.advance_to(start);
+    assert_eq!(clock.now() - start, Duration::from_millis(250));
+}
+
+#[test]
+fn test_mock_sleep_wakes_when_due() {
+    let clock = MockClock::new();
+    let flag = Arc::new(Flag::default());
+    let mut sleep = clock.sleep_until(clock.now() + Duration::from_secs(5));
+
+    assert!(poll(&mut sleep, &flag).is_pending());
+    assert_eq!(
+        clock.next_deadline(),
+        Some(clock.now() + Duration::from_secs(5))
+    );
+
+    clock.advance(Duration::from_secs(4));
+    assert!(!flag.0.load(Ordering::SeqCst));
+
+    clock.advance(Duration::from_secs(1));
+    assert!(flag.0.load(Ordering::SeqCst));
+    assert_eq!(clock.next_deadline(), None);
+    assert!(poll(&mut sleep, &flag).is_ready());
+}
+
+#[test]
+fn test_mock_sleep_registers_once_and_unregisters_on_drop() {
+    let clock = MockClock::new();
+    let first = Arc::new(Flag::default());
+    let second = Arc::new(Flag::default());
+    let mut sleep = clock.sleep_until(clock.now() + Duration::from_secs(5));
+    let mut later = clock.sleep_until(clock.now() + Duration::from_secs(8));
+
+    // Re-polling a sleep keeps its single entry
+    assert!(poll(&mut sleep, &first).is_pending());
+    assert!(poll(&mut sleep, &second).is_pending());
+    assert!(poll(&mut later, &first).is_pending());
+    assert_eq!(clock.state.lock().sleepers.len(), 2);
+
+    // A dropped sleep no longer holds back the next deadline
+    drop(sleep);
+    assert_eq!(
+        clock.next_deadline(),
+        Some(clock.now() + Duration::from_secs(8))
+    );
+
+    clock.advance(Duration::from_secs(5));
+    assert!(!second.0.load(Ordering::SeqCst));
+
+    drop(later);
+    assert_eq!(clock.next_deadline(), None);
+}
+
+#[test]
+fn test_installed_clock_is_per_thread() {
+    let clock = MockClock::new();
+    set_clock(Arc::new(clock.clone()));
+    let start = now();
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(now() - start, Duration::from_secs(60));
+    assert!(current_clock().is_virtual());
+
+    let elsewhere = std::thread::spawn(|| current_clock().is_virtual())
+        .join()
+        .unwrap();
+    assert!(!elsewhere);
+
+    clear_clock();
+    assert!(!current_clock().is_virtual());
+}
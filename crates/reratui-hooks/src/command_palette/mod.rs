@@ -0,0 +1,460 @@
+//! A searchable list of commands
+//!
+//! [`CommandPalette`] is the "Ctrl+P" pattern as a component: a query line
+//! above the commands that fuzzy-match it, best match first. Typing narrows
+//! the list as you go, the navigation keys move the selection and Enter
+//! reports the chosen command's id through `on_execute`.
+//!
+//! Commands are matched with [`reratui_core::fuzzy`] against their title and
+//! keywords; [`filter_commands`] exposes the ranking on its own.
+
+use crossterm::event::{Event, KeyCode};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Widget};
+use reratui_core::fuzzy::{Score, fuzzy_match, fuzzy_score};
+use reratui_core::text::display_width;
+use reratui_core::{Component, ComponentProps, Element};
+use std::cmp::Reverse;
+
+use crate::callback::Callback;
+use crate::event::use_event_handle;
+use crate::list_selection::use_list_selection;
+use crate::ref_hook::use_ref;
+use crate::text_input::{TextInputState, is_typed_character};
+
+#[cfg(test)]
+mod tests;
+
+/// A command listed by a `CommandPalette`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteCommand {
+    /// Reported through `on_execute` when the command is chosen
+    pub id: String,
+    pub title: String,
+    /// Extra words the command is found by, such as synonyms
+    pub keywords: Vec<String>,
+}
+
+impl PaletteCommand {
+    /// A command without keywords
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Sets the keywords the command is also found by
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// A command matching the query of a `CommandPalette`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteMatch {
+    /// Position of the command in the list passed to [`filter_commands`]
+    pub index: usize,
+    /// Higher is better
+    pub score: Score,
+    /// Character positions of the title that matched the query; empty when
+    /// only a keyword matched
+    pub indices: Vec<usize>,
+}
+
+/// The commands matching `query`, best match first
+///
+/// A command's score is the best of its title and keywords. Commands with
+/// equal scores keep their order, and an empty query matches every command
+/// in order.
+pub fn filter_commands(commands: &[PaletteCommand], query: &str) -> Vec<PaletteMatch> {
+    let query = query.trim();
+    if query.is_empty() {
+        return (0..commands.len())
+            .map(|index| PaletteMatch {
+                index,
+                score: 0,
+                indices: Vec::new(),
+            })
+            .collect();
+    }
+
+    let mut matches: Vec<PaletteMatch> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            let title = fuzzy_match(query, &command.title);
+            let keyword = command
+                .keywords
+                .iter()
+                .filter_map(|keyword| fuzzy_score(query, keyword))
+                .max();
+            match (title, keyword) {
+                // Prefer the title, whose matched characters can be shown
+                (Some(title), keyword) if keyword.is_none_or(|k| title.score >= k) => {
+                    Some(PaletteMatch {
+                        index,
+                        score: title.score,
+                        indices: title.indices,
+                    })
+                }
+                (_, keyword) => keyword.map(|score| PaletteMatch {
+                    index,
+                    score,
+                    indices: Vec::new(),
+                }),
+            }
+        })
+        .collect();
+    matches.sort_by_key(|found| Reverse(found.score));
+    matches
+}
+
+/// Props for the `CommandPalette` component
+#[derive(Clone)]
+pub struct CommandPaletteProps {
+    pub commands: Vec<PaletteCommand>,
+    /// Receives the id of the command chosen with Enter
+    pub on_execute: Callback<String>,
+    /// Called when Esc is pressed
+    pub on_close: Callback<()>,
+    pub title: String,
+    /// Shown while the query is empty
+    pub placeholder: String,
+    /// Whether keys edit the query and move the selection
+    pub focused: bool,
+    pub style: Style,
+    /// Style patched onto the selected command
+    pub highlight_style: Style,
+    /// Style patched onto the characters matching the query
+    pub match_style: Style,
+    pub children: Vec<Element>,
+}
+
+impl Default for CommandPaletteProps {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            on_execute: Callback::noop(),
+            on_close: Callback::noop(),
+            title: "Commands".to_string(),
+            placeholder: "Type a command".to_string(),
+            focused: true,
+            style: Style::default(),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            match_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl CommandPaletteProps {
+    /// Sets the commands to choose from
+    pub fn commands(mut self, commands: Vec<PaletteCommand>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Sets the callback receiving the id of the chosen command
+    pub fn on_execute(mut self, on_execute: impl Into<Callback<String>>) -> Self {
+        self.on_execute = on_execute.into();
+        self
+    }
+
+    /// Sets the callback called when Esc is pressed
+    pub fn on_close(mut self, on_close: impl Into<Callback<()>>) -> Self {
+        self.on_close = on_close.into();
+        self
+    }
+
+    /// Sets the title of the border
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the text shown while the query is empty
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets whether keys edit the query and move the selection
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the style of the palette
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched onto the selected command
+    pub fn highlight_style(mut self, highlight_style: Style) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    /// Sets the style patched onto the characters matching the query
+    pub fn match_style(mut self, match_style: Style) -> Self {
+        self.match_style = match_style;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for CommandPaletteProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A query line over the commands matching it
+///
+/// While focused, typed characters and the editing keys of
+/// [`TextInput`](crate::text_input::TextInput) edit the query, Up/Down and
+/// PageUp/PageDown move the selection, Enter reports the selected command
+/// through `on_execute` and Esc calls `on_close`. The selection returns to
+/// the best match whenever the query changes.
+///
+/// ```ignore
+/// rsx! {
+///     <CommandPalette
+///         commands={vec![
+///             PaletteCommand::new("file.save", "Save File").keywords(["write"]),
+///             PaletteCommand::new("view.theme", "Toggle Theme"),
+///         ]}
+///         on_execute={move |id: String| run(&id)}
+///     />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct CommandPaletteComponent {
+    props: CommandPaletteProps,
+}
+
+impl CommandPaletteComponent {
+    pub fn new(props: CommandPaletteProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+
+    /// Draws the query, or the placeholder while it is empty
+    fn render_query(&self, query: &TextInputState, area: Rect, buffer: &mut Buffer) {
+        buffer.set_stringn(area.x, area.y, "> ", area.width as usize, self.props.style);
+        let area = Rect {
+            x: area.x.saturating_add(2),
+            width: area.width.saturating_sub(2),
+            ..area
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        let text = if query.value().is_empty() {
+            let style = self.props.style.add_modifier(Modifier::DIM);
+            buffer.set_stringn(
+                area.x,
+                area.y,
+                &self.props.placeholder,
+                area.width as usize,
+                style,
+            );
+            ""
+        } else {
+            query.value()
+        };
+        // Keep the end of a long query, and the cursor after it, in view
+        let mut visible = text;
+        while display_width(visible) >= area.width as usize
+            && let Some(c) = visible.chars().next()
+        {
+            visible = &visible[c.len_utf8()..];
+        }
+        let skipped = text.len() - visible.len();
+        buffer.set_stringn(
+            area.x,
+            area.y,
+            visible,
+            area.width as usize,
+            self.props.style,
+        );
+
+        if self.props.focused {
+            let column = display_width(&text[skipped.min(query.cursor())..query.cursor()]);
+            let x = area.x + column.min(area.width as usize - 1) as u16;
+            buffer[(x, area.y)].modifier.insert(Modifier::REVERSED);
+        }
+    }
+
+    /// Draws one command, patching the matched characters
+    fn render_match(&self, found: &PaletteMatch, area: Rect, buffer: &mut Buffer) {
+        let title = &self.props.commands[found.index].title;
+        let mut x = area.x;
+        for (position, c) in title.chars().enumerate() {
+            let width = display_width(c.encode_utf8(&mut [0; 4])) as u16;
+            if x + width > area.right() {
+                break;
+            }
+            let mut style = self.props.style;
+            if found.indices.contains(&position) {
+                style = style.patch(self.props.match_style);
+            }
+            buffer.set_stringn(x, area.y, c.to_string(), width as usize, style);
+            x += width;
+        }
+    }
+}
+
+impl Component for CommandPaletteComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let query = use_ref(TextInputState::default);
+        let mut input = query.get();
+        let matches = filter_commands(&self.props.commands, input.value());
+        let selection = use_list_selection(matches.len());
+
+        // The first visible match
+        let offset = use_ref(|| 0usize);
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let page = inner.height.saturating_sub(1) as usize;
+
+        let event = use_event_handle();
+        let mut handled = false;
+        match event.as_deref() {
+            Some(Event::Key(key)) if self.props.focused && key.is_press() => match key.code {
+                KeyCode::Enter => {
+                    if let Some(found) = selection.selected().and_then(|index| matches.get(index)) {
+                        let id = self.props.commands[found.index].id.clone();
+                        self.props.on_execute.emit(id);
+                    }
+                    handled = true;
+                }
+                KeyCode::Esc => {
+                    self.props.on_close.emit(());
+                    handled = true;
+                }
+                KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown => {
+                    selection.handle_key(key, page);
+                    handled = true;
+                }
+                _ => {
+                    let before = input.clone();
+                    input.handle_key(key);
+                    handled = input != before;
+                }
+            },
+            Some(Event::Paste(text)) if self.props.focused => {
+                input.insert_str(text);
+                handled = true;
+            }
+            _ => {}
+        }
+        // Typed characters are the palette's even when they change nothing
+        if let Some(event) = &event
+            && self.props.focused
+            && (handled || is_typed_character(event))
+        {
+            event.consume();
+        }
+
+        // Narrow the list as the query changes, starting from the best match
+        let matches = if input.value() != query.get().value() {
+            selection.select_first();
+            offset.set(0);
+            filter_commands(&self.props.commands, input.value())
+        } else {
+            matches
+        };
+        query.set(input.clone());
+
+        if area.is_empty() {
+            return;
+        }
+
+        buffer.set_style(area, self.props.style);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.props.title));
+        block.render(area, buffer);
+        if inner.is_empty() {
+            return;
+        }
+
+        self.render_query(&input, Rect { height: 1, ..inner }, buffer);
+        let list = Rect {
+            y: inner.y + 1,
+            height: inner.height - 1,
+            ..inner
+        };
+        if matches.is_empty() {
+            let style = self.props.style.add_modifier(Modifier::DIM);
+            buffer.set_stringn(
+                list.x,
+                list.y,
+                "No matching commands",
+                list.width as usize,
+                style,
+            );
+            return;
+        }
+
+        // Scroll just enough to keep the selected match on screen
+        let selected = selection
+            .selected()
+            .map(|index| index.min(matches.len() - 1));
+        let mut first = offset.get().min(matches.len().saturating_sub(page));
+        if let Some(index) = selected {
+            if index < first {
+                first = index;
+            } else if page > 0 && index >= first + page {
+                first = index + 1 - page;
+            }
+        }
+        offset.set(first);
+
+        for (line, (index, found)) in matches
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(page)
+            .enumerate()
+        {
+            let row = Rect {
+                y: list.y + line as u16,
+                height: 1,
+                ..list
+            };
+            self.render_match(found, row, buffer);
+            if selected == Some(index) {
+                buffer.set_style(row, self.props.highlight_style);
+            }
+        }
+    }
+}
+
+/// The `CommandPalette` component, usable as `<CommandPalette commands={...} on_execute={...} />` in `rsx!`
+pub type CommandPalette = CommandPaletteComponent;
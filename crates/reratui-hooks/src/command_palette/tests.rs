@@ -0,0 +1,154 @@
+//! Tests for the command palette
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect()
+}
+
+fn press(code: KeyCode) {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    set_current_event(Some(Arc::new(Event::Key(key))));
+}
+
+fn commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand::new("file.open", "Open File"),
+        PaletteCommand::new("file.save", "Save File").keywords(["write"]),
+        PaletteCommand::new("view.theme", "Toggle Theme").keywords(["dark", "light"]),
+        PaletteCommand::new("app.quit", "Quit"),
+    ]
+}
+
+fn ids(matches: &[PaletteMatch]) -> Vec<String> {
+    let commands = commands();
+    matches
+        .iter()
+        .map(|found| commands[found.index].id.clone())
+        .collect()
+}
+
+fn render_palette(palette: &CommandPalette, area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("CommandPaletteTest", |_ctx| {
+        palette.render(area, &mut buffer)
+    });
+    buffer
+}
+
+#[test]
+fn test_filter_ranks_fuzzy_matches() {
+    let commands = commands();
+
+    // An empty query lists everything in order
+    let all = filter_commands(&commands, "  ");
+    assert_eq!(
+        ids(&all),
+        ["file.open", "file.save", "view.theme", "app.quit"]
+    );
+
+    let matches = filter_commands(&commands, "sf");
+    assert_eq!(ids(&matches), ["file.save"]);
+    assert_eq!(matches[0].indices, [0, 5]);
+
+    // Both files match, the closer one first
+    let matches = filter_commands(&commands, "file");
+    assert_eq!(ids(&matches), ["file.open", "file.save"]);
+    let matches = filter_commands(&commands, "savefile");
+    assert_eq!(ids(&matches)[0], "file.save");
+
+    // Keywords find commands without highlighting the title
+    let matches = filter_commands(&commands, "DARK");
+    assert_eq!(ids(&matches), ["view.theme"]);
+    assert!(matches[0].indices.is_empty());
+
+    assert!(filter_commands(&commands, "xyz").is_empty());
+}
+
+#[test]
+fn test_typing_narrows_the_list() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let palette =
+            CommandPaletteComponent::new(CommandPaletteProps::default().commands(commands()));
+        let area = Rect::new(0, 0, 20, 7);
+
+        set_current_event(None);
+        let buffer = render_palette(&palette, area);
+        assert_eq!(row(&buffer, 0), "┌ Commands ────────┐");
+        assert_eq!(row(&buffer, 1), "│> Type a command  │");
+        assert_eq!(row(&buffer, 2), "│Open File         │");
+        assert_eq!(row(&buffer, 5), "│Quit              │");
+
+        for c in "th".chars() {
+            press(KeyCode::Char(c));
+            render_palette(&palette, area);
+        }
+        set_current_event(None);
+        let buffer = render_palette(&palette, area);
+        assert_eq!(row(&buffer, 1), "│> th              │");
+        assert_eq!(row(&buffer, 2), "│Toggle Theme      │");
+        assert_eq!(row(&buffer, 3), "│                  │");
+
+        // The "Th" of "Theme" is highlighted, and the selected row reversed
+        let t = &buffer[(8, 2)];
+        assert_eq!(t.symbol(), "T");
+        assert_eq!(t.fg, Color::Yellow);
+        assert!(t.modifier.contains(Modifier::REVERSED));
+        assert_eq!(buffer[(9, 2)].fg, Color::Yellow);
+        assert_eq!(buffer[(1, 2)].fg, Color::Reset);
+
+        press(KeyCode::Char('z'));
+        render_palette(&palette, area);
+        set_current_event(None);
+        let buffer = render_palette(&palette, area);
+        assert_eq!(row(&buffer, 2), "│No matching comman│");
+    });
+}
+
+#[test]
+fn test_enter_executes_the_selected_command() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let closed = Arc::new(Mutex::new(0));
+        let palette = CommandPaletteComponent::new(
+            CommandPaletteProps::default()
+                .commands(commands())
+                .on_execute({
+                    let executed = executed.clone();
+                    move |id: String| executed.lock().push(id)
+                })
+                .on_close({
+                    let closed = closed.clone();
+                    move |_| *closed.lock() += 1
+                }),
+        );
+        let area = Rect::new(0, 0, 20, 7);
+
+        for code in [KeyCode::Down, KeyCode::Down, KeyCode::Enter] {
+            press(code);
+            render_palette(&palette, area);
+        }
+        assert_eq!(*executed.lock(), ["view.theme"]);
+
+        // Editing the query goes back to the best match
+        for code in [KeyCode::Char('f'), KeyCode::Enter, KeyCode::Esc] {
+            press(code);
+            render_palette(&palette, area);
+        }
+        set_current_event(None);
+        assert_eq!(*executed.lock(), ["view.theme", "file.open"]);
+        assert_eq!(*closed.lock(), 1);
+    });
+}
@@ -0,0 +1,273 @@
+//! Confirmation dialogs awaited like any other future
+//!
+//! A [`ConfirmProvider`] shows the questions asked through [`use_confirm`] in
+//! a [`Modal`], one at a time. [`ConfirmManager::ask`] returns a future that
+//! resolves to the answer, so a confirm flow reads as linear async code
+//! instead of a state flag checked by the key handler:
+//!
+//! ```rust,no_run
+//! use reratui_hooks::confirm::use_confirm;
+//!
+//! let confirm = use_confirm();
+//! tokio::spawn(async move {
+//!     if confirm.ask("Delete this user?").await {
+//!         // delete the user
+//!     }
+//! });
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+
+use crossterm::event::{Event, KeyCode};
+use parking_lot::Mutex;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget};
+use reratui_core::{Component, ComponentProps, Element};
+use tokio::sync::oneshot;
+
+use crate::context::{use_context, use_context_provider, with_context_scope};
+use crate::event::use_event;
+use crate::modal::{ModalComponent, ModalProps};
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// A question waiting for its answer
+struct ConfirmRequest {
+    prompt: String,
+    reply: oneshot::Sender<bool>,
+}
+
+type ConfirmQueue = Arc<Mutex<VecDeque<ConfirmRequest>>>;
+
+/// Answers the question shown, if any, and moves on to the next one
+fn answer(queue: &ConfirmQueue, confirmed: bool) {
+    if let Some(request) = queue.lock().pop_front() {
+        // The asker may have stopped waiting
+        let _ = request.reply.send(confirmed);
+        crate::state::bump_state_epoch();
+    }
+}
+
+/// Asks the questions of the nearest [`ConfirmProvider`]
+#[derive(Clone)]
+pub struct ConfirmManager {
+    queue: ConfirmQueue,
+}
+
+impl ConfirmManager {
+    /// Shows `prompt` and resolves to whether the user confirmed it
+    ///
+    /// The question is queued right away and shown once those asked before
+    /// it are answered. The future resolves to `false` if the provider goes
+    /// away first; dropping the future withdraws the question.
+    pub fn ask(&self, prompt: impl Into<String>) -> impl Future<Output = bool> + Send + 'static {
+        let (reply, answered) = oneshot::channel();
+        self.queue.lock().push_back(ConfirmRequest {
+            prompt: prompt.into(),
+            reply,
+        });
+        crate::state::bump_state_epoch();
+        async move { answered.await.unwrap_or(false) }
+    }
+
+    /// Whether a question is waiting for an answer
+    ///
+    /// Components behind the dialog still receive events, so they should
+    /// ignore keys while this holds.
+    pub fn is_open(&self) -> bool {
+        self.queue
+            .lock()
+            .iter()
+            .any(|request| !request.reply.is_closed())
+    }
+}
+
+/// Returns the [`ConfirmManager`] of the nearest [`ConfirmProvider`]
+///
+/// # Panics
+///
+/// Panics when not rendered inside a `ConfirmProvider`.
+pub fn use_confirm() -> ConfirmManager {
+    use_context::<ConfirmManager>()
+}
+
+/// Props for the `ConfirmProvider` component
+#[derive(Clone)]
+pub struct ConfirmProviderProps {
+    /// Title on the dialog border
+    pub title: String,
+    /// Width of the dialog including its border, clamped to the frame
+    pub width: u16,
+    /// Stacking order relative to other portals
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl Default for ConfirmProviderProps {
+    fn default() -> Self {
+        Self {
+            title: " Confirm ".to_string(),
+            width: 44,
+            z_index: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl ConfirmProviderProps {
+    /// Sets the title on the dialog border
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the width of the dialog
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the stacking order among portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ConfirmProviderProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders its children with a [`ConfirmManager`] available through
+/// [`use_confirm`], and a dialog for the oldest unanswered question
+///
+/// `y` and Enter confirm; `n`, Esc and clicks outside the dialog decline. A
+/// single child fills the whole area; several children are stacked
+/// vertically.
+///
+/// ```ignore
+/// rsx! {
+///     <ConfirmProvider title=" Confirm Delete ">
+///         <App />
+///     </ConfirmProvider>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ConfirmProviderComponent {
+    props: ConfirmProviderProps,
+}
+
+impl ConfirmProviderComponent {
+    pub fn new(props: ConfirmProviderProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for ConfirmProviderComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let queue: ConfirmQueue = use_ref(ConfirmQueue::default).get();
+
+        with_context_scope(|| {
+            use_context_provider(|| ConfirmManager {
+                queue: queue.clone(),
+            });
+
+            let children = &self.props.children;
+            if children.len() == 1 {
+                children[0].render(area, buffer);
+            } else if !children.is_empty() {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(vec![Constraint::Min(0); children.len()])
+                    .split(area);
+                for (child, chunk) in children.iter().zip(chunks.iter()) {
+                    child.render(*chunk, buffer);
+                }
+            }
+        });
+
+        // Skip questions whose askers stopped waiting
+        let prompt = {
+            let mut requests = queue.lock();
+            requests.retain(|request| !request.reply.is_closed());
+            requests.front().map(|request| request.prompt.clone())
+        };
+        let Some(prompt) = prompt else {
+            return;
+        };
+
+        let on_close = queue.clone();
+        ModalComponent::new(
+            ModalProps::default()
+                .open(true)
+                .title(self.props.title.clone())
+                .width(self.props.width)
+                .height(5)
+                .z_index(self.props.z_index)
+                .on_close(move |_| answer(&on_close, false))
+                .with_children(vec![Element::component(ConfirmPrompt { prompt, queue })]),
+        )
+        .render(area, buffer);
+    }
+}
+
+/// The `ConfirmProvider` component, usable as `<ConfirmProvider>` in `rsx!`
+pub type ConfirmProvider = ConfirmProviderComponent;
+
+/// The question and keys shown inside the dialog
+struct ConfirmPrompt {
+    prompt: String,
+    queue: ConfirmQueue,
+}
+
+impl Component for ConfirmPrompt {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        if let Some(Event::Key(key)) = use_event()
+            && key.is_press()
+        {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => answer(&self.queue, true),
+                KeyCode::Char('n') => answer(&self.queue, false),
+                _ => {}
+            }
+        }
+
+        let key_style = |color| Style::default().fg(color).add_modifier(Modifier::BOLD);
+        let hint_style = Style::default().fg(Color::Gray);
+        Paragraph::new(vec![
+            Line::from(self.prompt.as_str()),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("y", key_style(Color::Red)),
+                Span::styled(" Yes  ", hint_style),
+                Span::styled("n/Esc", key_style(Color::Green)),
+                Span::styled(" No", hint_style),
+            ]),
+        ])
+        .alignment(Alignment::Center)
+        .render(area, buffer);
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for confirmation dialogs
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use futures::FutureExt;
+use reratui_core::portal::render_portals;
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Hands out the provided confirm manager
+#[derive(Clone)]
+struct Page {
+    manager: Arc<Mutex<Option<ConfirmManager>>>,
+}
+
+impl Component for Page {
+    fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+        *self.manager.lock() = Some(use_confirm());
+    }
+}
+
+fn press(code: KeyCode) {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    set_current_event(Some(Arc::new(Event::Key(key))));
+}
+
+/// Renders a provider around `page` like the runtime does, returning the rows
+fn render_frame(page: &Page) -> Vec<String> {
+    let area = Rect::new(0, 0, 24, 7);
+    let mut buffer = Buffer::empty(area);
+    let provider = ConfirmProviderComponent::new(ConfirmProviderProps::default().width(20))
+        .with_children(vec![Element::component(page.clone())]);
+    with_component_id("ConfirmProviderTest", |_ctx| {
+        provider.render(area, &mut buffer);
+        render_portals(area, &mut buffer);
+    });
+    set_current_event(None);
+    (0..area.height)
+        .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+fn manager(page: &Page) -> ConfirmManager {
+    page.manager.lock().clone().unwrap()
+}
+
+#[test]
+fn test_ask_resolves_with_the_answer() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let page = Page {
+            manager: Arc::default(),
+        };
+        assert_eq!(render_frame(&page)[1], " ".repeat(24));
+
+        let confirm = manager(&page);
+        let mut delete = Box::pin(confirm.ask("Delete?"));
+        let quit = Box::pin(confirm.ask("Quit?"));
+        assert!(confirm.is_open());
+
+        let rows = render_frame(&page);
+        assert_eq!(rows[1], "  ┌ Confirm ─────────┐  ");
+        assert_eq!(rows[2], "  │      Delete?     │  ");
+        assert_eq!(rows[4], "  │  y Yes  n/Esc No │  ");
+        assert_eq!(delete.as_mut().now_or_never(), None);
+
+        // Questions are shown one at a time, in the order they were asked
+        press(KeyCode::Char('y'));
+        render_frame(&page);
+        assert_eq!(delete.now_or_never(), Some(true));
+        assert_eq!(render_frame(&page)[2], "  │       Quit?      │  ");
+
+        press(KeyCode::Esc);
+        render_frame(&page);
+        assert_eq!(quit.now_or_never(), Some(false));
+        assert!(!confirm.is_open());
+        assert_eq!(render_frame(&page)[1], " ".repeat(24));
+    });
+}
+
+#[test]
+fn test_dropped_questions_are_withdrawn() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let page = Page {
+            manager: Arc::default(),
+        };
+        render_frame(&page);
+
+        let confirm = manager(&page);
+        drop(confirm.ask("Withdrawn?"));
+        assert!(!confirm.is_open());
+        let kept = confirm.ask("Kept?");
+        assert_eq!(render_frame(&page)[2], "  │       Kept?      │  ");
+
+        press(KeyCode::Char('n'));
+        render_frame(&page);
+        assert_eq!(kept.now_or_never(), Some(false));
+    });
+}
+
+#[test]
+fn test_unanswered_questions_resolve_to_no_without_provider() {
+    let confirm = ConfirmManager {
+        queue: ConfirmQueue::default(),
+    };
+    let question = confirm.ask("Anyone there?");
+    drop(confirm);
+    assert_eq!(question.now_or_never(), Some(false));
+}
@@ -3,6 +3,12 @@
 //! This module provides a more elegant context API that allows components to share state
 //! without having to pass props down through many levels, similar to React's Context API.
 //! This implementation is designed to be more ergonomic and beautiful to use.
+//!
+//! Providers are kept in one stack per type, keyed by `TypeId`, so values of
+//! different types coexist and each is read with `use_context::<T>()`. A
+//! component rendered through [`with_context_scope`] removes the values it
+//! provided once it has rendered, so a nested provider shadows an outer one of
+//! the same type only within its own subtree.
 
 use std::any::{Any, TypeId};
 use std::cell::RefCell;
@@ -12,6 +18,7 @@ use std::collections::HashMap;
 mod tests;
 
 use crate::hook_context::with_hook_context;
+use crate::ref_hook::use_ref;
 
 thread_local! {
     static CONTEXT_PROVIDERS: RefCell<HashMap<TypeId, Vec<Box<dyn Any + Send + Sync>>>> =
@@ -25,6 +32,54 @@ pub fn clear_context_providers() {
     });
 }
 
+/// Runs `render` in its own context scope
+///
+/// Values provided while `render` runs, typically by a component and its
+/// children, are removed again when it returns, uncovering any outer values of
+/// the same types. Components generated by `#[component]` render inside a
+/// scope; hand-written components that provide context should use this too.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::context::{use_context, use_context_provider, with_context_scope};
+///
+/// use_context_provider(|| "outer");
+/// with_context_scope(|| {
+///     use_context_provider(|| "inner");
+///     assert_eq!(use_context::<&str>(), "inner");
+/// });
+/// assert_eq!(use_context::<&str>(), "outer");
+/// ```
+pub fn with_context_scope<R>(render: impl FnOnce() -> R) -> R {
+    /// Truncates the provider stacks on drop, so a panicking render is undone too
+    struct ScopeGuard {
+        depths: HashMap<TypeId, usize>,
+    }
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            CONTEXT_PROVIDERS.with(|providers| {
+                providers.borrow_mut().retain(|type_id, stack| {
+                    stack.truncate(self.depths.get(type_id).copied().unwrap_or(0));
+                    !stack.is_empty()
+                });
+            });
+        }
+    }
+
+    let _guard = ScopeGuard {
+        depths: CONTEXT_PROVIDERS.with(|providers| {
+            providers
+                .borrow()
+                .iter()
+                .map(|(type_id, stack)| (*type_id, stack.len()))
+                .collect()
+        }),
+    };
+    render()
+}
+
 /// Provides a context value for a type
 ///
 /// This function creates a context value that will be available to all components
@@ -77,14 +132,29 @@ where
 /// * The context value
 ///
 pub fn use_context<T>() -> T
+where
+    T: Clone + Send + Sync + 'static,
+{
+    try_use_context().unwrap_or_else(|| {
+        panic!(
+            "Context value for type {} not found. Make sure to call use_context_provider in a parent component.",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+/// Consumes a context value for a type, if one was provided
+///
+/// Like [`use_context`], but returns `None` instead of panicking when no
+/// parent component provided a value, so callers can fall back to a default.
+pub fn try_use_context<T>() -> Option<T>
 where
     T: Clone + Send + Sync + 'static,
 {
     with_hook_context(|_ctx| {
         let type_id = TypeId::of::<T>();
 
-        // Try to get the value from the thread-local provider stack
-        let value = CONTEXT_PROVIDERS.with(|providers| {
+        CONTEXT_PROVIDERS.with(|providers| {
             let providers = providers.borrow();
             if let Some(provider_stack) = providers.get(&type_id)
                 && let Some(last_provider) = provider_stack.last()
@@ -93,17 +163,56 @@ where
                 return Some(value.clone());
             }
             None
-        });
+        })
+    })
+}
 
-        // If found in the thread-local stack, return it
-        if let Some(value) = value {
-            return value;
-        }
+/// Consumes part of a context value
+///
+/// Calls `select` on the context value provided for `C` and returns the
+/// result. The selected value is remembered per consumer: while `select`
+/// returns an equal value, the previously selected instance is returned, so
+/// effects and memos depending on it only re-run when that slice changes, not
+/// whenever an unrelated field of the context does.
+///
+/// This only memoizes the returned value. The runtime renders the whole tree
+/// every frame, so the consumer still renders when any field changes, and
+/// `select` runs on every render; keep it cheap.
+///
+/// # Panics
+///
+/// Panics if no parent component provided a value for `C`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::context::use_context_selector;
+/// use reratui_hooks::effect::use_effect;
+///
+/// #[derive(Clone)]
+/// struct AppState {
+///     user: String,
+///     unread: usize,
+/// }
+///
+/// let unread = use_context_selector(|state: &AppState| state.unread);
+///
+/// // Runs only when the unread count changes, not when the user does
+/// use_effect(move || { println!("{} unread", unread); None::<fn()> }, unread);
+/// ```
+pub fn use_context_selector<C, S, F>(select: F) -> S
+where
+    C: Clone + Send + Sync + 'static,
+    S: Clone + PartialEq + 'static,
+    F: Fn(&C) -> S,
+{
+    let selected = select(&use_context::<C>());
+    let previous = use_ref(|| selected.clone());
 
-        // If not found, panic with a helpful error message
-        panic!(
-            "Context value for type {} not found. Make sure to call use_context_provider in a parent component.",
-            std::any::type_name::<T>()
-        );
+    previous.with_mut(|previous| {
+        if *previous != selected {
+            *previous = selected;
+        }
+        previous.clone()
     })
 }
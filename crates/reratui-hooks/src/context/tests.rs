@@ -1,5 +1,8 @@
 use crate::{
-    context::{use_context, use_context_provider},
+    context::{
+        clear_context_providers, try_use_context, use_context, use_context_provider,
+        use_context_selector, with_context_scope,
+    },
     test_utils::with_component_id,
 };
 
@@ -195,3 +198,111 @@ fn test_nested_context_providers() {
         );
     });
 }
+
+#[test]
+fn test_try_use_context() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Locale(&'static str);
+
+    with_component_id("TryContextComponent", |_ctx| {
+        assert_eq!(try_use_context::<Locale>(), None);
+
+        use_context_provider(|| Locale("en"));
+        assert_eq!(try_use_context::<Locale>(), Some(Locale("en")));
+    });
+}
+
+#[test]
+fn test_use_context_selector_keeps_unchanged_slice() {
+    use crate::effect::use_effect;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct AppState {
+        title: Arc<String>,
+        ticks: u32,
+    }
+
+    let title_effects = Arc::new(AtomicUsize::new(0));
+    let render = |ticks: u32, title: &str| {
+        with_component_id("SelectorProvider", |_ctx| {
+            use_context_provider(|| AppState {
+                title: Arc::new(title.to_string()),
+                ticks,
+            });
+        });
+        with_component_id("SelectorConsumer", |_ctx| {
+            let title = use_context_selector(|state: &AppState| state.title.clone());
+            let ticks = use_context_selector(|state: &AppState| state.ticks);
+
+            let title_effects = title_effects.clone();
+            use_effect(
+                move || {
+                    title_effects.fetch_add(1, Ordering::SeqCst);
+                    None::<fn()>
+                },
+                title.to_string(),
+            );
+            (title, ticks)
+        })
+    };
+
+    let (first, ticks) = render(0, "Inbox");
+    assert_eq!(*first, "Inbox");
+    assert_eq!(ticks, 0);
+    assert_eq!(title_effects.load(Ordering::SeqCst), 1);
+
+    // An unrelated field changed: the same instance is returned and effects
+    // depending on the slice don't run again
+    let (second, ticks) = render(1, "Inbox");
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(ticks, 1);
+    assert_eq!(title_effects.load(Ordering::SeqCst), 1);
+
+    // The selected slice changed
+    let (third, _) = render(2, "Archive");
+    assert_eq!(*third, "Archive");
+    assert!(!Arc::ptr_eq(&second, &third));
+    assert_eq!(title_effects.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_nested_provider_shadows_only_its_subtree() {
+    with_component_id("ScopedRoot", |_ctx| {
+        clear_context_providers();
+        use_context_provider(|| TestUser {
+            name: "Root".to_string(),
+            role: "Admin".to_string(),
+        });
+        use_context_provider(|| TestTheme {
+            color: "Dark".to_string(),
+            font: "Sans".to_string(),
+        });
+
+        with_context_scope(|| {
+            use_context_provider(|| TestUser {
+                name: "Nested".to_string(),
+                role: "Guest".to_string(),
+            });
+
+            with_component_id("ScopedChild", |_ctx| {
+                assert_eq!(use_context::<TestUser>().name, "Nested");
+                // Other types still come from the root
+                assert_eq!(use_context::<TestTheme>().color, "Dark");
+            });
+        });
+
+        // A sibling rendered after the scope sees the root values again
+        with_component_id("ScopedSibling", |_ctx| {
+            assert_eq!(use_context::<TestUser>().name, "Root");
+            assert_eq!(use_context::<TestTheme>().color, "Dark");
+        });
+
+        // Types first provided inside a scope are gone after it
+        with_component_id("ScopedTail", |_ctx| {
+            with_context_scope(|| use_context_provider(|| 42u8));
+            assert_eq!(try_use_context::<u8>(), None);
+        });
+    });
+}
@@ -0,0 +1,152 @@
+//! Countdown hook for timers that fire a callback at zero
+//!
+//! This module provides the `use_countdown` hook, which counts down from a
+//! duration while running and calls a completion callback once it runs out.
+
+use crate::callback::Callback;
+use crate::clock;
+use crate::ref_hook::{RefHandle, use_ref};
+use crate::stopwatch::StopwatchState;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// Countdown progress, measured as running time against a fixed total
+#[derive(Debug, Clone)]
+struct CountdownState {
+    total: Duration,
+    stopwatch: StopwatchState,
+    /// Whether `on_complete` has fired since the last reset
+    completed: bool,
+}
+
+impl CountdownState {
+    fn new(total: Duration) -> Self {
+        Self {
+            total,
+            stopwatch: StopwatchState::default(),
+            completed: false,
+        }
+    }
+
+    fn remaining(&self, now: Instant) -> Duration {
+        self.total.saturating_sub(self.stopwatch.elapsed(now))
+    }
+
+    fn start(&mut self, now: Instant) {
+        if !self.completed {
+            self.stopwatch.start(now);
+        }
+    }
+
+    fn pause(&mut self, now: Instant) {
+        self.stopwatch.stop(now);
+    }
+
+    fn reset(&mut self) {
+        self.stopwatch.reset();
+        self.completed = false;
+    }
+
+    /// Marks the countdown complete when it reaches zero, returning `true`
+    /// only for the call that completed it
+    fn complete_if_elapsed(&mut self, now: Instant) -> bool {
+        if self.completed || !self.remaining(now).is_zero() {
+            return false;
+        }
+
+        self.stopwatch.stop(now);
+        self.completed = true;
+        true
+    }
+}
+
+/// Handle returned by [`use_countdown`]
+///
+/// Cloning the handle shares the same countdown, so it can be moved into
+/// event handlers.
+#[derive(Clone)]
+pub struct Countdown {
+    state: RefHandle<CountdownState>,
+}
+
+impl Countdown {
+    /// Time left before the countdown reaches zero
+    pub fn remaining(&self) -> Duration {
+        let now = clock::now();
+        self.state.with(|state| state.remaining(now))
+    }
+
+    /// Starts or resumes the countdown; does nothing once complete
+    pub fn start(&self) {
+        let now = clock::now();
+        self.state.with_mut(|state| state.start(now));
+    }
+
+    /// Pauses the countdown, keeping the remaining time
+    pub fn pause(&self) {
+        let now = clock::now();
+        self.state.with_mut(|state| state.pause(now));
+    }
+
+    /// Stops the countdown and restores the full duration
+    ///
+    /// The completion callback fires again the next time it reaches zero.
+    pub fn reset(&self) {
+        self.state.with_mut(CountdownState::reset);
+    }
+
+    /// Whether the countdown is currently running
+    pub fn is_running(&self) -> bool {
+        self.state.with(|state| state.stopwatch.is_running())
+    }
+
+    /// Whether the countdown has reached zero
+    pub fn is_complete(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// A hook that counts down from `from` and calls `on_complete` at zero.
+///
+/// The countdown starts paused; call `start` to run it. Each render checks the
+/// remaining time, so in the render loop it advances every frame, and the
+/// first render at or past zero calls `on_complete` exactly once. Calling
+/// `reset` re-arms the callback.
+///
+/// Changing `from` between renders has no effect until the component is
+/// remounted; the duration is fixed when the hook first runs.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::callback::Callback;
+/// use reratui_hooks::countdown::use_countdown;
+/// use reratui_hooks::effect::use_effect_once;
+/// use std::time::Duration;
+///
+/// let countdown = use_countdown(
+///     Duration::from_secs(4),
+///     Callback::new(|_| println!("Done!")),
+/// );
+///
+/// // Start on mount
+/// let starter = countdown.clone();
+/// use_effect_once(move || {
+///     starter.start();
+///     || {}
+/// });
+///
+/// let status = format!("Running... ({}s left)", countdown.remaining().as_secs_f32().ceil());
+/// ```
+pub fn use_countdown(from: Duration, on_complete: Callback<()>) -> Countdown {
+    let state = use_ref(|| CountdownState::new(from));
+
+    let now = clock::now();
+    if state.with_mut(|state| state.complete_if_elapsed(now)) {
+        on_complete.emit(());
+    }
+
+    Countdown { state }
+}
@@ -0,0 +1,102 @@
+//! Tests for use_countdown hook
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_countdown_clamps_at_zero() {
+    let t0 = Instant::now();
+    let mut state = CountdownState::new(ms(100));
+
+    assert_eq!(state.remaining(t0), ms(100));
+    state.start(t0);
+    assert_eq!(state.remaining(t0 + ms(40)), ms(60));
+    assert_eq!(state.remaining(t0 + ms(500)), Duration::ZERO);
+}
+
+#[test]
+fn test_countdown_pause_keeps_remaining() {
+    let t0 = Instant::now();
+    let mut state = CountdownState::new(ms(100));
+
+    state.start(t0);
+    state.pause(t0 + ms(30));
+    assert_eq!(state.remaining(t0 + ms(1000)), ms(70));
+
+    state.start(t0 + ms(1000));
+    assert_eq!(state.remaining(t0 + ms(1050)), ms(20));
+}
+
+#[test]
+fn test_countdown_completes_once_until_reset() {
+    let t0 = Instant::now();
+    let mut state = CountdownState::new(ms(100));
+
+    state.start(t0);
+    assert!(!state.complete_if_elapsed(t0 + ms(50)));
+    assert!(state.complete_if_elapsed(t0 + ms(100)));
+    assert!(!state.complete_if_elapsed(t0 + ms(200)));
+
+    // Starting a completed countdown does nothing until it is reset
+    state.start(t0 + ms(200));
+    assert!(!state.stopwatch.is_running());
+
+    state.reset();
+    assert_eq!(state.remaining(t0 + ms(300)), ms(100));
+    state.start(t0 + ms(300));
+    assert!(state.complete_if_elapsed(t0 + ms(400)));
+}
+
+#[test]
+fn test_use_countdown_fires_on_complete_once() {
+    with_test_isolate(|| {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let render = |fired: &Arc<AtomicUsize>| {
+            let fired = fired.clone();
+            use_countdown(
+                ms(5),
+                Callback::new(move |_| {
+                    fired.fetch_add(1, Ordering::SeqCst);
+                }),
+            )
+        };
+
+        with_component_id("CountdownTest", |_ctx| {
+            let countdown = render(&fired);
+            assert!(!countdown.is_running());
+            countdown.start();
+        });
+
+        std::thread::sleep(ms(10));
+
+        with_component_id("CountdownTest", |_ctx| {
+            let countdown = render(&fired);
+            assert!(countdown.is_complete());
+            assert!(!countdown.is_running());
+        });
+        with_component_id("CountdownTest", |_ctx| {
+            render(&fired);
+        });
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        with_component_id("CountdownTest", |_ctx| {
+            let countdown = render(&fired);
+            countdown.reset();
+            assert_eq!(countdown.remaining(), ms(5));
+            countdown.start();
+        });
+
+        std::thread::sleep(ms(10));
+
+        with_component_id("CountdownTest", |_ctx| {
+            render(&fired);
+        });
+        assert_eq!(fired.load(Ordering::SeqCst), 2);
+    });
+}
@@ -0,0 +1,286 @@
+//! A table of rows with keyboard selection
+//!
+//! [`DataTable`] draws a header and one line per row, highlights the selected
+//! row and moves the selection with the navigation keys of
+//! [`use_list_selection`]. Cells are fitted to their column by display width,
+//! so wide characters and emoji never push later columns out of line.
+
+use crossterm::event::{Event, KeyCode};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use reratui_core::text::pad_to_width;
+use reratui_core::{Component, ComponentProps, Element};
+
+use crate::callback::Callback;
+use crate::event::use_event;
+use crate::list_selection::use_list_selection;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// Blank columns between two table columns
+pub const COLUMN_GAP: u16 = 1;
+
+/// A column of a `DataTable`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub title: String,
+    /// Width in terminal columns
+    pub width: u16,
+    pub alignment: Alignment,
+}
+
+impl Column {
+    /// A left-aligned column `width` columns wide
+    pub fn new(title: impl Into<String>, width: u16) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Sets how the title and cells sit within the column
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+/// Props for the `DataTable` component
+#[derive(Clone)]
+pub struct DataTableProps {
+    pub columns: Vec<Column>,
+    /// One cell per column for every row
+    pub rows: Vec<Vec<String>>,
+    /// Moves the selection to this row whenever it changes
+    pub selected: Option<usize>,
+    /// Receives the selected row when Enter is pressed
+    pub on_select: Callback<usize>,
+    /// Receives the selected row whenever the selection moves
+    pub on_change: Callback<usize>,
+    /// Whether keys move the selection
+    pub focused: bool,
+    pub style: Style,
+    pub header_style: Style,
+    pub highlight_style: Style,
+    pub children: Vec<Element>,
+}
+
+impl Default for DataTableProps {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            selected: None,
+            on_select: Callback::noop(),
+            on_change: Callback::noop(),
+            focused: true,
+            style: Style::default(),
+            header_style: Style::default().add_modifier(Modifier::BOLD),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl DataTableProps {
+    /// Sets the columns
+    pub fn columns(mut self, columns: Vec<Column>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the rows
+    pub fn rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the row to select
+    pub fn selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Sets the callback receiving the row chosen with Enter
+    pub fn on_select(mut self, on_select: impl Into<Callback<usize>>) -> Self {
+        self.on_select = on_select.into();
+        self
+    }
+
+    /// Sets the callback receiving every selection change
+    pub fn on_change(mut self, on_change: impl Into<Callback<usize>>) -> Self {
+        self.on_change = on_change.into();
+        self
+    }
+
+    /// Sets whether keys move the selection
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the style of the rows
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the header
+    pub fn header_style(mut self, header_style: Style) -> Self {
+        self.header_style = header_style;
+        self
+    }
+
+    /// Sets the style patched onto the selected row
+    pub fn highlight_style(mut self, highlight_style: Style) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for DataTableProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A table with a header row and a selectable row
+///
+/// Up/Down, PageUp/PageDown and Home/End move the selection while the table
+/// is focused, and Enter reports the selected row through `on_select`. Rows
+/// below the area scroll into view as the selection reaches them.
+///
+/// ```ignore
+/// rsx! {
+///     <DataTable
+///         columns={vec![Column::new("Name", 18), Column::new("Stars", 8).alignment(Alignment::Right)]}
+///         rows={repos.iter().map(|repo| vec![repo.name.clone(), repo.stars.to_string()]).collect()}
+///         on_select={move |index| open_repo(index)}
+///     />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct DataTableComponent {
+    props: DataTableProps,
+}
+
+impl DataTableComponent {
+    pub fn new(props: DataTableProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+
+    /// Draws one line of cells starting at `(x, y)`
+    fn render_line(&self, cells: &[String], x: u16, y: u16, width: u16, buffer: &mut Buffer) {
+        let mut column_x = x;
+        for (column, cell) in self.props.columns.iter().zip(cells) {
+            let remaining = (x + width).saturating_sub(column_x);
+            if remaining == 0 {
+                break;
+            }
+            let text = pad_to_width(cell, column.width as usize, column.alignment);
+            buffer.set_stringn(column_x, y, text, remaining as usize, Style::default());
+            column_x = column_x.saturating_add(column.width + COLUMN_GAP);
+        }
+    }
+}
+
+impl Component for DataTableComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let selection = use_list_selection(self.props.rows.len());
+        // The `selected` prop last seen, so an unchanged prop doesn't undo
+        // keyboard navigation
+        let last_selected = use_ref(|| None::<usize>);
+        if self.props.selected != last_selected.get() {
+            last_selected.set(self.props.selected);
+            if let Some(index) = self.props.selected {
+                selection.select(index);
+            }
+        }
+
+        // The first row below the header that is visible
+        let offset = use_ref(|| 0usize);
+        let page = area.height.saturating_sub(1) as usize;
+
+        if self.props.focused
+            && let Some(Event::Key(key)) = use_event()
+            && key.is_press()
+            && let Some(index) = selection.selected()
+        {
+            if key.code == KeyCode::Enter {
+                self.props.on_select.emit(index);
+            } else if selection.handle_key(&key, page)
+                && let Some(index) = selection.selected()
+            {
+                self.props.on_change.emit(index);
+            }
+        }
+
+        if area.is_empty() {
+            return;
+        }
+
+        buffer.set_style(area, self.props.style);
+        let titles: Vec<String> = self
+            .props
+            .columns
+            .iter()
+            .map(|column| column.title.clone())
+            .collect();
+        self.render_line(&titles, area.x, area.y, area.width, buffer);
+        buffer.set_style(Rect { height: 1, ..area }, self.props.header_style);
+
+        // Scroll just enough to keep the selected row on screen
+        let selected = selection.selected();
+        let mut first = offset.get().min(self.props.rows.len().saturating_sub(page));
+        if let Some(index) = selected {
+            if index < first {
+                first = index;
+            } else if page > 0 && index >= first + page {
+                first = index + 1 - page;
+            }
+        }
+        offset.set(first);
+
+        for (line, (index, cells)) in self
+            .props
+            .rows
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(page)
+            .enumerate()
+        {
+            let y = area.y + 1 + line as u16;
+            self.render_line(cells, area.x, y, area.width, buffer);
+            if selected == Some(index) {
+                let row = Rect {
+                    y,
+                    height: 1,
+                    ..area
+                };
+                buffer.set_style(row, self.props.highlight_style);
+            }
+        }
+    }
+}
+
+/// The `DataTable` component, usable as `<DataTable columns={...} rows={...} />` in `rsx!`
+pub type DataTable = DataTableComponent;
@@ -0,0 +1,144 @@
+//! Tests for data tables
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect()
+}
+
+fn press(code: KeyCode) {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    set_current_event(Some(Arc::new(Event::Key(key))));
+}
+
+fn users() -> Vec<Vec<String>> {
+    [
+        ("alice", "3"),
+        ("🎨 bob", "12"),
+        ("山田", "7"),
+        ("dave", "1"),
+    ]
+    .iter()
+    .map(|(name, posts)| vec![name.to_string(), posts.to_string()])
+    .collect()
+}
+
+fn table(props: DataTableProps) -> DataTable {
+    DataTableComponent::new(
+        props
+            .columns(vec![
+                Column::new("Name", 7),
+                Column::new("Posts", 5).alignment(Alignment::Right),
+            ])
+            .rows(users()),
+    )
+}
+
+fn render_table(table: &DataTable, area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("DataTableTest", |_ctx| table.render(area, &mut buffer));
+    buffer
+}
+
+#[test]
+fn test_columns_align_with_wide_characters() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let buffer = render_table(&table(DataTableProps::default()), Rect::new(0, 0, 13, 5));
+
+        // Wide characters fill two cells, the second of which is left blank
+        assert_eq!(row(&buffer, 0), "Name    Posts");
+        assert_eq!(row(&buffer, 1), "alice       3");
+        assert_eq!(row(&buffer, 2), "🎨  bob     12");
+        assert_eq!(row(&buffer, 3), "山 田         7");
+        assert_eq!(buffer[(0, 2)].symbol(), "🎨");
+        assert_eq!(buffer[(3, 2)].symbol(), "b");
+        assert_eq!(buffer[(1, 3)].symbol(), " ");
+
+        // The first row is selected
+        assert!(buffer[(0, 1)].modifier.contains(Modifier::REVERSED));
+        assert!(!buffer[(0, 2)].modifier.contains(Modifier::REVERSED));
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::BOLD));
+    });
+}
+
+#[test]
+fn test_keys_move_selection_and_scroll() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let selected = Arc::new(Mutex::new(Vec::new()));
+        let table = table(
+            DataTableProps::default()
+                .on_change({
+                    let changes = changes.clone();
+                    move |index| changes.lock().push(index)
+                })
+                .on_select({
+                    let selected = selected.clone();
+                    move |index| selected.lock().push(index)
+                }),
+        );
+        // Room for the header and two rows
+        let area = Rect::new(0, 0, 13, 3);
+
+        press(KeyCode::Down);
+        render_table(&table, area);
+        press(KeyCode::Down);
+        let buffer = render_table(&table, area);
+        assert_eq!(row(&buffer, 1), "🎨  bob     12");
+        assert_eq!(row(&buffer, 2), "山 田         7");
+        assert!(buffer[(0, 2)].modifier.contains(Modifier::REVERSED));
+
+        press(KeyCode::End);
+        let buffer = render_table(&table, area);
+        assert_eq!(row(&buffer, 2), "dave        1");
+
+        // Already on the last row: no change is reported
+        press(KeyCode::Down);
+        render_table(&table, area);
+        press(KeyCode::Enter);
+        render_table(&table, area);
+        set_current_event(None);
+
+        assert_eq!(*changes.lock(), vec![1, 2, 3]);
+        assert_eq!(*selected.lock(), vec![3]);
+    });
+}
+
+#[test]
+fn test_selected_prop_moves_selection() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let area = Rect::new(0, 0, 13, 5);
+        let highlighted = |buffer: &Buffer| {
+            (1..5)
+                .find(|&y| buffer[(0, y)].modifier.contains(Modifier::REVERSED))
+                .map(|y| y - 1)
+        };
+
+        let buffer = render_table(&table(DataTableProps::default().selected(2)), area);
+        assert_eq!(highlighted(&buffer), Some(2));
+
+        // Navigation isn't undone while the prop stays the same
+        press(KeyCode::Up);
+        let buffer = render_table(&table(DataTableProps::default().selected(2)), area);
+        set_current_event(None);
+        assert_eq!(highlighted(&buffer), Some(1));
+
+        let buffer = render_table(&table(DataTableProps::default().selected(3)), area);
+        assert_eq!(highlighted(&buffer), Some(3));
+    });
+}
@@ -0,0 +1,180 @@
+//! Debounced callback hook for event handlers
+//!
+//! This module provides the `use_debounced_callback` hook, which wraps a
+//! callback so that it only runs once calls to it have stopped for a while,
+//! such as saving a document shortly after the last keystroke.
+
+use crate::clock;
+use crate::hook_context::with_hook_context;
+use crate::panic_handler::spawn_registered;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+#[cfg(test)]
+mod tests;
+
+/// The scheduled call of a [`Debouncer`]
+struct Pending<Args> {
+    /// Bumped by every call and cancellation; only the latest may fire
+    generation: u64,
+    /// The arguments of the latest call, until it fires
+    args: Option<Args>,
+    /// The task waiting out the delay
+    task: Option<JoinHandle<()>>,
+}
+
+/// State shared between the hook slot, the debounced function and its task
+pub(crate) struct Debouncer<Args> {
+    #[allow(clippy::type_complexity)]
+    callback: Mutex<Option<Box<dyn Fn(Args) + Send + 'static>>>,
+    pending: Mutex<Pending<Args>>,
+}
+
+impl<Args: Send + 'static> Debouncer<Args> {
+    pub(crate) fn new() -> Self {
+        Self {
+            callback: Mutex::new(None),
+            pending: Mutex::new(Pending {
+                generation: 0,
+                args: None,
+                task: None,
+            }),
+        }
+    }
+
+    /// Sets the function invoked when a call fires
+    pub(crate) fn set_callback(&self, callback: impl Fn(Args) + Send + 'static) {
+        *self.callback.lock() = Some(Box::new(callback));
+    }
+
+    /// Replaces any scheduled call with one passing `args` after `delay`
+    pub(crate) fn call(self: &Arc<Self>, args: Args, delay: Duration) {
+        let mut pending = self.pending.lock();
+        pending.generation += 1;
+        if let Some(task) = pending.task.take() {
+            task.abort();
+        }
+
+        if tokio::runtime::Handle::try_current().is_err() {
+            pending.args = None;
+            eprintln!("Warning: use_debounced_callback called outside tokio runtime context");
+            return;
+        }
+
+        pending.args = Some(args);
+        let generation = pending.generation;
+        let debouncer = Arc::clone(self);
+        pending.task = Some(spawn_registered(async move {
+            clock::sleep(delay).await;
+            debouncer.fire(generation);
+        }));
+    }
+
+    /// Invokes the callback, unless a later call or a cancellation came first
+    fn fire(&self, generation: u64) {
+        let args = {
+            let mut pending = self.pending.lock();
+            if pending.generation != generation {
+                return;
+            }
+            pending.task = None;
+            pending.args.take()
+        };
+
+        if let Some(args) = args {
+            self.invoke(args);
+        }
+    }
+
+    fn invoke(&self, args: Args) {
+        if let Some(callback) = self.callback.lock().as_ref() {
+            callback(args);
+        }
+    }
+
+    /// Drops any scheduled call and invokes the callback with `args` now
+    pub(crate) fn call_now(&self, args: Args) {
+        self.cancel();
+        self.invoke(args);
+    }
+
+    /// Invokes the scheduled call right away instead of after its delay
+    pub(crate) fn flush(&self) {
+        let args = {
+            let mut pending = self.pending.lock();
+            pending.generation += 1;
+            if let Some(task) = pending.task.take() {
+                task.abort();
+            }
+            pending.args.take()
+        };
+
+        if let Some(args) = args {
+            self.invoke(args);
+        }
+    }
+
+    /// Drops the scheduled call, if any
+    pub(crate) fn cancel(&self) {
+        let mut pending = self.pending.lock();
+        pending.generation += 1;
+        pending.args = None;
+        if let Some(task) = pending.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// A hook that returns a debounced version of `callback`.
+///
+/// Every call to the returned function restarts a `delay` timer, and
+/// `callback` runs once the timer expires, with the arguments of the most
+/// recent call. Calls in between are dropped. This is the imperative
+/// counterpart of deferring a value: it debounces an action, such as an
+/// autosave, rather than what is rendered.
+///
+/// The callback from the latest render is the one invoked, so it may capture
+/// the latest state. When the component is rendered from an
+/// [`Element`](reratui_core::Element), a pending call is cancelled once it
+/// unmounts. **Requires a tokio runtime to be active**; outside one, calls
+/// are dropped with a warning.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::debounce::use_debounced_callback;
+/// use std::time::Duration;
+///
+/// let save = use_debounced_callback(
+///     |content: String| {
+///         let _ = std::fs::write("draft.txt", content);
+///     },
+///     Duration::from_millis(500),
+/// );
+///
+/// // On every keystroke; the file is written 500ms after the last one
+/// save("Hello".to_string());
+/// ```
+pub fn use_debounced_callback<Args, F>(
+    callback: F,
+    delay: Duration,
+) -> impl Fn(Args) + Clone + Send + Sync + 'static
+where
+    Args: Send + 'static,
+    F: Fn(Args) + Send + 'static,
+{
+    let debouncer = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let debouncer_ref = ctx.get_or_init_state(index, || Arc::new(Debouncer::<Args>::new()));
+        debouncer_ref.borrow().clone()
+    });
+
+    debouncer.set_callback(callback);
+
+    let on_unmount = Arc::clone(&debouncer);
+    reratui_core::component::on_current_unmount(move || on_unmount.cancel());
+
+    move |args| debouncer.call(args, delay)
+}
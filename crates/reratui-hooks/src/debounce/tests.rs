@@ -0,0 +1,95 @@
+//! Tests for the debounced callback hook
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use std::time::Duration;
+use tokio::time::sleep;
+
+type Calls = Arc<Mutex<Vec<u32>>>;
+
+fn debounced(calls: &Calls) -> impl Fn(u32) + Clone + Send + Sync + 'static {
+    let calls = calls.clone();
+    with_component_id("DebouncedCallbackTest", |_ctx| {
+        use_debounced_callback(
+            move |value: u32| calls.lock().push(value),
+            Duration::from_millis(30),
+        )
+    })
+}
+
+#[tokio::test]
+async fn test_debounced_callback_delivers_latest_arguments_once() {
+    with_test_isolate(|| async {
+        let calls: Calls = Arc::default();
+        let save = debounced(&calls);
+
+        for value in 1..=3 {
+            save(value);
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert!(calls.lock().is_empty(), "Calls keep restarting the timer");
+
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(*calls.lock(), [3]);
+
+        // A later burst fires again, through the function from any render
+        let save_again = debounced(&calls);
+        save(4);
+        save_again(5);
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(*calls.lock(), [3, 5]);
+    })
+    .await;
+}
+
+/// Saves through a debounced callback on every render
+#[derive(Clone)]
+struct Autosave {
+    calls: Calls,
+}
+
+impl reratui_core::Component for Autosave {
+    fn render(&self, _area: ratatui::layout::Rect, _buffer: &mut ratatui::buffer::Buffer) {
+        let calls = self.calls.clone();
+        let save = use_debounced_callback(
+            move |value: u32| calls.lock().push(value),
+            Duration::from_millis(30),
+        );
+        save(1);
+    }
+}
+
+#[tokio::test]
+async fn test_debounced_callback_is_cancelled_on_unmount() {
+    use reratui_core::Element;
+    use reratui_core::component::cleanup_unmounted;
+
+    with_test_isolate(|| async {
+        let calls: Calls = Arc::default();
+        let area = ratatui::layout::Rect::new(0, 0, 10, 1);
+        let render = |mounted: bool| {
+            let mut buffer = ratatui::buffer::Buffer::empty(area);
+            let children = if mounted {
+                vec![Element::component(Autosave {
+                    calls: calls.clone(),
+                })]
+            } else {
+                Vec::new()
+            };
+            with_component_id("DebouncedCallbackParent", |_ctx| {
+                Element::fragment(children).render(area, &mut buffer)
+            });
+            cleanup_unmounted();
+        };
+
+        render(true);
+        render(false);
+        sleep(Duration::from_millis(60)).await;
+        assert!(calls.lock().is_empty());
+
+        render(true);
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(*calls.lock(), [1]);
+    })
+    .await;
+}
@@ -0,0 +1,46 @@
+//! Deferred value hook for keeping expensive renders responsive
+//!
+//! This module provides the `use_deferred_value` hook, which lets a value used
+//! by an expensive part of the UI lag behind rapidly changing input.
+
+use crate::event::has_current_event;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// A hook that returns `value`, deferred until a frame with no pending input.
+///
+/// While events keep arriving (for example while the user is typing), the
+/// hook keeps returning the value from the last settled frame, so rapid
+/// updates coalesce. On the first frame without an event, which the render
+/// loop produces as soon as input pauses, it catches up to the latest value.
+/// Unlike a debounce there is no fixed delay.
+///
+/// The first render returns `value` immediately.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::deferred::use_deferred_value;
+/// use reratui_hooks::state::use_state;
+///
+/// let (query, _set_query) = use_state(String::new);
+/// let query = query.get();
+///
+/// // The input shows `query` immediately; the list filters by the deferred copy
+/// let deferred_query = use_deferred_value(query.clone());
+/// let is_stale = deferred_query != query;
+/// ```
+pub fn use_deferred_value<T>(value: T) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let deferred = use_ref(|| value.clone());
+
+    if !has_current_event() && deferred.with(|deferred| *deferred != value) {
+        deferred.set(value);
+    }
+
+    deferred.get()
+}
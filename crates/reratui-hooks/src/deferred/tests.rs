@@ -0,0 +1,57 @@
+//! Tests for the deferred value hook
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution from interfering with global event state
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn key_event(c: char) -> Option<Arc<Event>> {
+    Some(Arc::new(Event::Key(KeyEvent::new(
+        KeyCode::Char(c),
+        KeyModifiers::NONE,
+    ))))
+}
+
+#[test]
+fn test_deferred_value_initial_render() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(key_event('a'));
+
+        with_component_id("DeferredInitialTest", |_ctx| {
+            assert_eq!(use_deferred_value("a"), "a");
+        });
+
+        set_current_event(None);
+    });
+}
+
+#[test]
+fn test_deferred_value_coalesces_until_idle_frame() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        with_component_id("DeferredCoalesceTest", |_ctx| {
+            assert_eq!(use_deferred_value(String::new()), "");
+        });
+
+        // Frames carrying input keep the previous value
+        for (c, typed) in [('a', "a"), ('b', "ab"), ('c', "abc")] {
+            set_current_event(key_event(c));
+            with_component_id("DeferredCoalesceTest", |_ctx| {
+                assert_eq!(use_deferred_value(typed.to_string()), "");
+            });
+        }
+
+        // The first frame without input catches up
+        set_current_event(None);
+        with_component_id("DeferredCoalesceTest", |_ctx| {
+            assert_eq!(use_deferred_value("abc".to_string()), "abc");
+        });
+    });
+}
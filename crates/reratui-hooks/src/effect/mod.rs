@@ -646,6 +646,45 @@ where
     use_effect(|| Some(effect()), ())
 }
 
+/// Hook running `callback` once, on the component's first render
+///
+/// Pairs with [`use_unmount`] in function components, which have no
+/// [`Component::on_mount`](reratui_core::Component::on_mount) to override.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use reratui_hooks::effect::{use_mount, use_unmount};
+/// use_mount(|| println!("Subscribing"));
+/// use_unmount(|| println!("Unsubscribing"));
+/// ```
+pub fn use_mount<F>(callback: F)
+where
+    F: FnOnce() + 'static,
+{
+    use_effect(
+        || {
+            callback();
+            None::<fn()>
+        },
+        (),
+    )
+}
+
+/// Hook running `callback` when the component is removed from the tree
+///
+/// The callback from the latest render runs, so it may capture the latest
+/// state. It is called by
+/// [`cleanup_unmounted`](reratui_core::component::cleanup_unmounted) after
+/// the first frame the component is no longer rendered in. A component that
+/// is never unmounted, such as the root, never runs it.
+pub fn use_unmount<F>(callback: F)
+where
+    F: FnOnce() + 'static,
+{
+    reratui_core::component::on_current_unmount(callback);
+}
+
 /// useAsyncEffect hook that runs only once (on mount)
 ///
 /// This is a convenience function for async effects that should run only once when
@@ -1235,3 +1235,66 @@ fn test_use_effect_once_empty_cleanup() {
         });
     });
 }
+
+/// Subscribes on mount and unsubscribes on unmount, counting its renders
+#[derive(Clone)]
+struct Subscriber {
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl reratui_core::Component for Subscriber {
+    fn render(&self, _area: ratatui::layout::Rect, _buffer: &mut ratatui::buffer::Buffer) {
+        let (renders, set_renders) = use_state(|| 0);
+        let renders = renders.get() + 1;
+        set_renders.set(renders);
+
+        let log = self.log.clone();
+        use_mount(move || log.lock().unwrap().push("subscribe".to_string()));
+        let log = self.log.clone();
+        use_unmount(move || {
+            log.lock()
+                .unwrap()
+                .push(format!("unsubscribe after {renders} renders"))
+        });
+    }
+}
+
+/// Test use_mount and use_unmount on a component rendered from an element
+#[test]
+fn test_use_mount_and_use_unmount() {
+    use ratatui::{buffer::Buffer, layout::Rect};
+    use reratui_core::Element;
+    use reratui_core::component::cleanup_unmounted;
+
+    with_test_isolate(|| {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let area = Rect::new(0, 0, 10, 1);
+        let render = |children: Vec<Element>| {
+            let mut buffer = Buffer::empty(area);
+            with_component_id("MountRoot", |_context| {
+                Element::fragment(children).render(area, &mut buffer)
+            });
+            cleanup_unmounted();
+        };
+        let subscriber = || vec![Element::component(Subscriber { log: log.clone() })];
+
+        render(subscriber());
+        render(subscriber());
+        assert_eq!(*log.lock().unwrap(), ["subscribe"]);
+
+        // The callback of the latest render runs once the component is gone
+        render(Vec::new());
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["subscribe", "unsubscribe after 2 renders"]
+        );
+        render(Vec::new());
+        assert_eq!(log.lock().unwrap().len(), 2);
+    });
+
+    // Outside an element there is no component to unmount
+    with_test_isolate(|| {
+        with_component_id("UnmountWithoutElement", |_context| use_unmount(|| {}));
+        assert!(!reratui_core::component::on_current_unmount(|| {}));
+    });
+}
@@ -1,18 +1,247 @@
 //! Global event handling for TUI applications
+//!
+//! Key handlers live in a [`KeymapRegistry`]. The runtime offers every key
+//! press to the [`global`](KeymapRegistry::global) registry before components
+//! see it. Bindings registered as named commands carry a description, so the
+//! registry doubles as the source of a keybinding cheat-sheet.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
-use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 
 type EventHandler = dyn Fn() -> bool + Send + Sync + 'static;
 
-static GLOBAL_EVENT_HANDLERS: Lazy<Mutex<HashMap<KeyCode, Vec<Arc<EventHandler>>>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
+static GLOBAL_KEYMAP: Lazy<KeymapRegistry> = Lazy::new(KeymapRegistry::new);
+
+/// A key together with the modifiers held with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// The key pressed with Ctrl
+    pub fn ctrl(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::CONTROL)
+    }
+
+    /// The key pressed with Alt
+    pub fn alt(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::ALT)
+    }
+
+    /// Whether `event` is this key with exactly these modifiers
+    ///
+    /// Shift is ignored for characters, since it already shows in the
+    /// character itself: `?` matches however the terminal reports it.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let significant = |modifiers: KeyModifiers| match self.code {
+            KeyCode::Char(_) => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        event.code == self.code && significant(event.modifiers) == significant(self.modifiers)
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    /// Formats the binding the way help screens show it, e.g. `Ctrl+S`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c)
+                if self
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            code => write!(f, "{code}"),
+        }
+    }
+}
+
+/// A named action bound to a key, as listed by [`KeymapRegistry::commands`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    pub name: String,
+    pub binding: KeyBinding,
+    /// What the command does, as shown on help screens
+    pub description: String,
+}
+
+struct Entry {
+    binding: KeyBinding,
+    command: Option<Command>,
+    handler: Arc<EventHandler>,
+}
+
+/// Key bindings and the handlers they trigger
+///
+/// Clones share the same bindings. Handlers run in registration order until
+/// one returns `true`.
+#[derive(Clone, Default)]
+pub struct KeymapRegistry {
+    entries: Arc<Mutex<Vec<Entry>>>,
+}
+
+impl KeymapRegistry {
+    /// An empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry the runtime dispatches key presses to
+    pub fn global() -> Self {
+        GLOBAL_KEYMAP.clone()
+    }
+
+    /// Binds `handler` to exactly `binding`
+    ///
+    /// Return `true` from the handler to stop later handlers from seeing the
+    /// key.
+    pub fn bind<F>(&self, binding: impl Into<KeyBinding>, handler: F)
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.entries.lock().push(Entry {
+            binding: binding.into(),
+            command: None,
+            handler: Arc::new(handler),
+        });
+    }
+
+    /// Registers the command `name`, run when `binding` is pressed
+    ///
+    /// Registering a name again replaces the earlier command in place, so
+    /// components can register their commands on every render.
+    pub fn register<F>(
+        &self,
+        name: impl Into<String>,
+        binding: impl Into<KeyBinding>,
+        description: impl Into<String>,
+        handler: F,
+    ) where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let command = Command {
+            name: name.clone(),
+            binding: binding.into(),
+            description: description.into(),
+        };
+        let entry = Entry {
+            binding: command.binding,
+            handler: Arc::new(move || {
+                handler();
+                true
+            }),
+            command: Some(command),
+        };
+
+        let mut entries = self.entries.lock();
+        let existing = entries.iter_mut().find(|existing| {
+            existing
+                .command
+                .as_ref()
+                .is_some_and(|command| command.name == name)
+        });
+        match existing {
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+    }
+
+    /// Removes the command `name`; returns whether it was registered
+    pub fn unregister(&self, name: &str) -> bool {
+        let mut entries = self.entries.lock();
+        let before = entries.len();
+        entries.retain(|entry| {
+            entry
+                .command
+                .as_ref()
+                .is_none_or(|command| command.name != name)
+        });
+        entries.len() != before
+    }
+
+    /// The registered commands, in registration order
+    pub fn commands(&self) -> Vec<Command> {
+        self.entries
+            .lock()
+            .iter()
+            .filter_map(|entry| entry.command.clone())
+            .collect()
+    }
+
+    /// Removes every binding
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    /// Runs the handlers bound to a key press
+    ///
+    /// # Returns
+    /// `true` if a handler handled the event, `false` otherwise
+    pub fn dispatch(&self, event: &KeyEvent) -> bool {
+        if event.kind != KeyEventKind::Press {
+            return false;
+        }
+
+        // Handlers may register bindings themselves, so run them unlocked
+        let handlers: Vec<Arc<EventHandler>> = self
+            .entries
+            .lock()
+            .iter()
+            .filter(|entry| entry.binding.matches(event))
+            .map(|entry| entry.handler.clone())
+            .collect();
+        handlers.iter().any(|handler| handler())
+    }
+
+    /// Number of handlers `code` triggers with any modifiers
+    #[cfg(test)]
+    fn handler_count(&self, code: KeyCode) -> usize {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|entry| entry.binding.code == code)
+            .count()
+    }
+}
 
 /// Register a global event handler for a specific key code
 ///
+/// The handler only runs when no modifiers are held, so a `q` handler
+/// ignores Ctrl+Q; use [`on_global_event_with_modifiers`] for combinations.
+/// Shift is ignored for characters, as for any [`KeyBinding`].
+///
 /// # Arguments
 /// * `key` - The key code to listen for
 /// * `handler` - A closure that will be called when the key is pressed.
@@ -33,9 +262,28 @@ pub fn on_global_event<F>(key: KeyCode, handler: F)
 where
     F: Fn() -> bool + Send + Sync + 'static,
 {
-    let mut handlers = GLOBAL_EVENT_HANDLERS.lock();
-    let handlers_for_key = handlers.entry(key).or_default();
-    handlers_for_key.push(Arc::new(handler));
+    on_global_event_with_modifiers(key, KeyModifiers::NONE, handler);
+}
+
+/// Register a global event handler for a key pressed with `modifiers`
+///
+/// The handler runs only for exactly these modifiers.
+///
+/// # Example
+/// ```
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use reratui_hooks::event::global_events::on_global_event_with_modifiers;
+///
+/// on_global_event_with_modifiers(KeyCode::Char('q'), KeyModifiers::CONTROL, || {
+///     println!("Quit requested");
+///     true
+/// });
+/// ```
+pub fn on_global_event_with_modifiers<F>(key: KeyCode, modifiers: KeyModifiers, handler: F)
+where
+    F: Fn() -> bool + Send + Sync + 'static,
+{
+    GLOBAL_KEYMAP.bind(KeyBinding::new(key, modifiers), handler);
 }
 
 /// Process a key event through all registered global handlers
@@ -43,19 +291,7 @@ where
 /// # Returns
 /// `true` if the event was handled by any handler, `false` otherwise
 pub fn process_global_event(event: &KeyEvent) -> bool {
-    if event.kind != crossterm::event::KeyEventKind::Press {
-        return false;
-    }
-
-    let handlers = GLOBAL_EVENT_HANDLERS.lock();
-    if let Some(handlers_for_key) = handlers.get(&event.code) {
-        for handler in handlers_for_key {
-            if handler() {
-                return true;
-            }
-        }
-    }
-    false
+    GLOBAL_KEYMAP.dispatch(event)
 }
 
 // A simple test mutex for use in tests
@@ -125,7 +361,7 @@ mod tests {
         let _lock = TEST_MUTEX.lock();
 
         // Clear any existing handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         // Use TestMutex to synchronize the test
         let test_state = TestMutex::new((false, false)); // (handler_called, test_complete)
@@ -165,7 +401,7 @@ mod tests {
         let _lock = TEST_MUTEX.lock();
 
         // Clear any existing handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         // Use a test key that's not used in other tests
         let test_key = KeyCode::Char('y');
@@ -194,8 +430,7 @@ mod tests {
 
         // Verify we have the expected number of handlers registered
         {
-            let handlers = GLOBAL_EVENT_HANDLERS.lock();
-            let handlers_for_key = handlers.get(&test_key).unwrap_or(&vec![]).len();
+            let handlers_for_key = GLOBAL_KEYMAP.handler_count(test_key);
             assert_eq!(handlers_for_key, 2, "Expected 2 handlers to be registered");
         }
 
@@ -234,7 +469,7 @@ mod tests {
         let _lock = TEST_MUTEX.lock();
 
         // Clear any existing handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         // Create a counter to track handler calls
         let handler_call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
@@ -262,8 +497,7 @@ mod tests {
 
         // Verify we have the expected number of handlers registered
         {
-            let handlers = GLOBAL_EVENT_HANDLERS.lock();
-            let handlers_for_key = handlers.get(&test_key).unwrap_or(&vec![]).len();
+            let handlers_for_key = GLOBAL_KEYMAP.handler_count(test_key);
             assert_eq!(handlers_for_key, 2, "Expected 2 handlers to be registered");
         }
 
@@ -306,7 +540,7 @@ mod tests {
         let _lock = TEST_MUTEX.lock();
 
         // Clear any existing handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         let event = KeyEvent::new(KeyCode::Char('x'), crossterm::event::KeyModifiers::NONE);
         assert!(!process_global_event(&event));
@@ -317,7 +551,7 @@ mod tests {
         use crate::test_utils::TEST_MUTEX;
         let _lock = TEST_MUTEX.lock();
 
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         let counter1 = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let counter2 = Arc::new(std::sync::atomic::AtomicUsize::new(0));
@@ -358,7 +592,7 @@ mod tests {
     // #[test]
     // fn test_thread_safety() {
     //     // Clear any existing handlers
-    //     GLOBAL_EVENT_HANDLERS.lock().clear();
+    //     GLOBAL_KEYMAP.clear();
 
     //     // Use a simple counter with Arc and AtomicUsize for synchronization
     //     let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
@@ -433,7 +667,7 @@ mod tests {
 
     //     // Explicitly clear handlers to prevent interference with other tests
     //     drop(handlers);
-    //     GLOBAL_EVENT_HANDLERS.lock().clear();
+    //     GLOBAL_KEYMAP.clear();
     // }
 
     #[test]
@@ -442,7 +676,7 @@ mod tests {
         let _lock = TEST_MUTEX.lock();
 
         // Clear any existing handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         // Create a counter to track handler calls
         let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
@@ -497,7 +731,7 @@ mod tests {
         use crate::test_utils::TEST_MUTEX;
         let _lock = TEST_MUTEX.lock();
 
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let c = counter.clone();
@@ -507,31 +741,67 @@ mod tests {
             true
         });
 
-        // Create events with different modifiers but same key code
+        // Only the plain key triggers the handler; Shift is part of the character
         let events = [
-            KeyEvent::new(KeyCode::Char('c'), crossterm::event::KeyModifiers::CONTROL),
-            KeyEvent::new(KeyCode::Char('c'), crossterm::event::KeyModifiers::SHIFT),
-            KeyEvent::new(KeyCode::Char('c'), crossterm::event::KeyModifiers::ALT),
-            KeyEvent::new(KeyCode::Char('c'), crossterm::event::KeyModifiers::NONE),
+            (KeyModifiers::CONTROL, false),
+            (KeyModifiers::SHIFT, true),
+            (KeyModifiers::ALT, false),
+            (KeyModifiers::NONE, true),
         ];
 
-        // Process each event and verify the handler was called
-        for event in &events {
-            let result = process_global_event(event);
-            assert!(
-                result,
-                "Handler should have been called for event: {:?}",
+        for (modifiers, handled) in events {
+            let event = KeyEvent::new(KeyCode::Char('c'), modifiers);
+            assert_eq!(
+                process_global_event(&event),
+                handled,
+                "Unexpected result for event: {:?}",
                 event
             );
         }
 
-        // Verify the handler was called for each event
-        assert_eq!(
-            counter.load(Ordering::SeqCst),
-            events.len(),
-            "Handler should have been called {} times",
-            events.len()
-        );
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_global_event_with_modifiers() {
+        use crate::test_utils::TEST_MUTEX;
+        let _lock = TEST_MUTEX.lock();
+
+        GLOBAL_KEYMAP.clear();
+
+        let plain = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let ctrl = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        {
+            let plain = plain.clone();
+            on_global_event(KeyCode::Char('q'), move || {
+                plain.fetch_add(1, Ordering::SeqCst);
+                true
+            });
+            let ctrl = ctrl.clone();
+            on_global_event_with_modifiers(KeyCode::Char('q'), KeyModifiers::CONTROL, move || {
+                ctrl.fetch_add(1, Ordering::SeqCst);
+                true
+            });
+        }
+
+        assert!(process_global_event(&KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::CONTROL
+        )));
+        assert_eq!(plain.load(Ordering::SeqCst), 0);
+        assert_eq!(ctrl.load(Ordering::SeqCst), 1);
+
+        assert!(process_global_event(&KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE
+        )));
+        assert_eq!(plain.load(Ordering::SeqCst), 1);
+        assert_eq!(ctrl.load(Ordering::SeqCst), 1);
+
+        assert!(!process_global_event(&KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )));
     }
 
     #[test]
@@ -539,7 +809,7 @@ mod tests {
         use crate::test_utils::TEST_MUTEX;
         let _lock = TEST_MUTEX.lock();
 
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let c = counter.clone();
@@ -551,10 +821,70 @@ mod tests {
         });
 
         // Clear all handlers
-        GLOBAL_EVENT_HANDLERS.lock().clear();
+        GLOBAL_KEYMAP.clear();
 
         let event = KeyEvent::new(KeyCode::Char('x'), crossterm::event::KeyModifiers::NONE);
         assert!(!process_global_event(&event));
         assert_eq!(counter.load(Ordering::SeqCst), 0);
     }
+
+    #[test]
+    fn test_bindings_match_modifiers() {
+        let keymap = KeymapRegistry::new();
+        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let c = counter.clone();
+        keymap.bind(KeyBinding::ctrl(KeyCode::Char('s')), move || {
+            c.fetch_add(1, Ordering::SeqCst);
+            true
+        });
+
+        let plain = KeyEvent::new(KeyCode::Char('s'), crossterm::event::KeyModifiers::NONE);
+        let ctrl = KeyEvent::new(KeyCode::Char('s'), crossterm::event::KeyModifiers::CONTROL);
+        assert!(!keymap.dispatch(&plain));
+        assert!(keymap.dispatch(&ctrl));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        // Shift shows in the character itself
+        let question = KeyBinding::from(KeyCode::Char('?'));
+        assert!(question.matches(&KeyEvent::new(
+            KeyCode::Char('?'),
+            crossterm::event::KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn test_register_replaces_command_by_name() {
+        let keymap = KeymapRegistry::new();
+        keymap.register("save", KeyBinding::ctrl(KeyCode::Char('s')), "Save", || {});
+        keymap.register("quit", KeyCode::Char('q'), "Quit", || {});
+        keymap.register(
+            "save",
+            KeyBinding::alt(KeyCode::Char('s')),
+            "Save all",
+            || {},
+        );
+
+        let commands = keymap.commands();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].name, "save");
+        assert_eq!(commands[0].description, "Save all");
+        assert_eq!(commands[0].binding.to_string(), "Alt+S");
+        assert_eq!(commands[1].binding.to_string(), "q");
+
+        assert!(keymap.unregister("save"));
+        assert!(!keymap.unregister("save"));
+        assert_eq!(keymap.commands().len(), 1);
+    }
+
+    #[test]
+    fn test_key_binding_display() {
+        let shift_tab = KeyBinding::new(KeyCode::Tab, crossterm::event::KeyModifiers::SHIFT);
+        assert_eq!(shift_tab.to_string(), "Shift+Tab");
+        assert_eq!(KeyBinding::from(KeyCode::Up).to_string(), "↑");
+        assert_eq!(KeyBinding::from(KeyCode::F(1)).to_string(), "F1");
+        assert_eq!(
+            KeyBinding::ctrl(KeyCode::Char(' ')).to_string(),
+            "Ctrl+Space"
+        );
+    }
 }
@@ -0,0 +1,200 @@
+//! Interactive widget wrapper
+//!
+//! Backs the `on_click` and `on_key` attributes of the `rsx!` macro: the
+//! wrapped widget learns its render area at render time, so clicks can be
+//! bounds-checked without any code in the component body.
+//!
+//! Handlers return `()`, or a `bool` that is `true` when they handled the
+//! event, which stops it reaching components rendered after the widget.
+
+use crate::callback::{Callback, IntoCallback};
+use crossterm::event::{Event, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    widgets::Widget,
+};
+use reratui_core::Element;
+
+use super::next_event_handle;
+
+/// What an event handler returns: whether it handled the event
+///
+/// Implemented for `()`, which never handles it, and `bool`, so handlers can
+/// stop an event from propagating by returning `true`.
+pub trait HandlerResult {
+    /// Whether the event was handled and stops propagating
+    fn handled(self) -> bool;
+}
+
+impl HandlerResult for () {
+    fn handled(self) -> bool {
+        false
+    }
+}
+
+impl HandlerResult for bool {
+    fn handled(self) -> bool {
+        self
+    }
+}
+
+/// A widget wrapper that emits event handlers for the area it renders in
+///
+/// Usually created by `rsx!` for elements with `on_click` or `on_key`
+/// attributes rather than by hand.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     <Paragraph
+///         on_click={move |_| set_count.update(|c| c + 1)}
+///         on_key={move |key: KeyEvent| key.code == KeyCode::Enter && submit()}
+///     >
+///         {"Click me"}
+///     </Paragraph>
+/// }
+/// ```
+///
+/// Here Enter is consumed when `submit` returns `true`; other keys keep
+/// propagating.
+#[derive(Clone)]
+pub struct Interactive {
+    child: Element,
+    on_click: Option<Callback<MouseEvent, bool>>,
+    on_key: Option<Callback<KeyEvent, bool>>,
+}
+
+impl Interactive {
+    /// Wrap an element
+    pub fn new(child: Element) -> Self {
+        Self {
+            child,
+            on_click: None,
+            on_key: None,
+        }
+    }
+
+    /// Emit `handler` when the left mouse button is pressed inside the render area
+    ///
+    /// A handler returning `true` consumes the click.
+    pub fn on_click<OUT: HandlerResult + 'static>(
+        mut self,
+        handler: impl IntoCallback<MouseEvent, OUT>,
+    ) -> Self {
+        self.on_click = Some(handler.into_callback().map(HandlerResult::handled));
+        self
+    }
+
+    /// Emit `handler` for every key press while the element is rendered
+    ///
+    /// A handler returning `true` consumes the key press.
+    pub fn on_key<OUT: HandlerResult + 'static>(
+        mut self,
+        handler: impl IntoCallback<KeyEvent, OUT>,
+    ) -> Self {
+        self.on_key = Some(handler.into_callback().map(HandlerResult::handled));
+        self
+    }
+
+    /// Emit the handler matching `event`, if any, for an element rendered in
+    /// `area`, returning whether it handled the event
+    fn dispatch(&self, event: &Event, area: Rect) -> bool {
+        match event {
+            Event::Mouse(mouse)
+                if mouse.kind == MouseEventKind::Down(MouseButton::Left)
+                    && area.contains(Position::new(mouse.column, mouse.row)) =>
+            {
+                self.on_click
+                    .as_ref()
+                    .is_some_and(|on_click| on_click.emit(*mouse))
+            }
+            Event::Key(key) if key.is_press() => {
+                self.on_key.as_ref().is_some_and(|on_key| on_key.emit(*key))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Widget for Interactive {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        if let Some(handle) = next_event_handle()
+            && self.dispatch(&handle, area)
+        {
+            handle.consume();
+        }
+        self.child.render(area, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    fn click(column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_on_click_checks_bounds() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let interactive = Interactive::new(Element::text("button")).on_click({
+            let clicks = clicks.clone();
+            move |_| {
+                clicks.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        let area = Rect::new(10, 5, 8, 3);
+
+        interactive.dispatch(&click(12, 6), area);
+        interactive.dispatch(&click(2, 6), area);
+        interactive.dispatch(&click(18, 6), area);
+
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_on_key_receives_presses() {
+        let keys = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let interactive = Interactive::new(Element::text("input")).on_key({
+            let keys = keys.clone();
+            move |key: KeyEvent| keys.lock().push(key.code)
+        });
+        let area = Rect::new(0, 0, 10, 1);
+
+        interactive.dispatch(
+            &Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)),
+            area,
+        );
+        // Clicks without an on_click handler are ignored
+        interactive.dispatch(&click(1, 0), area);
+
+        assert_eq!(*keys.lock(), vec![KeyCode::Char('a')]);
+    }
+
+    #[test]
+    fn test_handlers_report_whether_they_handled_the_event() {
+        let interactive = Interactive::new(Element::text("input"))
+            .on_key(|key: KeyEvent| key.code == KeyCode::Enter)
+            .on_click(|_| {});
+        let area = Rect::new(0, 0, 10, 1);
+
+        let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        assert!(interactive.dispatch(&key(KeyCode::Enter), area));
+        assert!(!interactive.dispatch(&key(KeyCode::Esc), area));
+        // Handlers returning nothing never handle the event
+        assert!(!interactive.dispatch(&click(1, 0), area));
+    }
+}
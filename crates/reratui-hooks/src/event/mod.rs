@@ -4,13 +4,23 @@
 //! allowing child components to access the current event without having to
 //! pass it through props.
 
-use crossterm::event::Event;
+use crossterm::event::{Event, KeyEvent, MouseEvent};
 
 pub mod global_events;
+mod interactive;
+
+#[cfg(test)]
+mod tests;
+
+pub use interactive::{HandlerResult, Interactive};
 
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    ops::Deref,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use once_cell::sync::Lazy;
@@ -23,6 +33,8 @@ use crate::hook_context::with_hook_context;
 pub(crate) struct EventState {
     /// The current event
     pub(crate) event: Option<Arc<Event>>,
+    /// Set once a consumer stops the event from propagating
+    pub(crate) consumed: Arc<AtomicBool>,
     /// Map of component IDs to whether they've processed the event
     /// This allows each component to independently process the event
     pub(crate) processed_by: HashMap<usize, bool>,
@@ -31,6 +43,22 @@ pub(crate) struct EventState {
 /// Global storage for the current event
 pub(crate) static CURRENT_EVENT: Lazy<RwLock<EventState>> = Lazy::new(Default::default);
 
+/// Whether the terminal has focus, as last reported by a focus event
+static TERMINAL_FOCUSED: AtomicBool = AtomicBool::new(true);
+
+/// Whether the terminal window has focus
+///
+/// Follows the `FocusGained` and `FocusLost` events the runtime receives.
+/// Terminals that don't report focus changes count as always focused.
+pub fn is_terminal_focused() -> bool {
+    TERMINAL_FOCUSED.load(Ordering::Relaxed)
+}
+
+/// Records whether the terminal has focus
+pub(crate) fn set_terminal_focused(focused: bool) {
+    TERMINAL_FOCUSED.store(focused, Ordering::Relaxed);
+}
+
 /// Sets the current event in the global storage
 ///
 /// This function should be called by the App when an event is received.
@@ -42,9 +70,17 @@ pub fn set_current_event(event: Option<Arc<Event>>) {
     // Clone the event for debugging
     let event_debug = event.clone();
 
+    match event.as_deref() {
+        Some(Event::FocusGained) => set_terminal_focused(true),
+        Some(Event::FocusLost) => set_terminal_focused(false),
+        _ => {}
+    }
+
     // Store the event in the global storage
     let mut current_event = CURRENT_EVENT.write().unwrap();
     current_event.event = event;
+    // A fresh flag, so handles kept from earlier events can't consume this one
+    current_event.consumed = Arc::new(AtomicBool::new(false));
     current_event.processed_by.clear(); // Reset the processed map for the new event
 
     debug!("Set current event in context: {:?}", event_debug);
@@ -56,18 +92,84 @@ pub fn set_current_event(event: Option<Arc<Event>>) {
     }
 }
 
+/// Whether an event is pending in the current frame, without consuming it
+pub(crate) fn has_current_event() -> bool {
+    CURRENT_EVENT.read().unwrap().event.is_some()
+}
+
+/// Whether a consumer has consumed the current event
+///
+/// Lets the runtime tell, once a frame is drawn, whether some component
+/// handled its input through [`EventHandle::consume`].
+pub fn is_current_event_consumed() -> bool {
+    CURRENT_EVENT
+        .read()
+        .unwrap()
+        .consumed
+        .load(Ordering::SeqCst)
+}
+
+/// The current event as seen by one consumer, which may stop it propagating
+///
+/// Returned by [`use_event_handle`]. Dereferences to the [`Event`].
+#[derive(Debug, Clone)]
+pub struct EventHandle {
+    event: Arc<Event>,
+    consumed: Arc<AtomicBool>,
+}
+
+impl EventHandle {
+    /// The event itself
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Marks the event as handled
+    ///
+    /// Consumers that ask for the event later in the same frame, through
+    /// [`use_event`] or [`use_event_handle`], no longer receive it.
+    pub fn consume(&self) {
+        self.consumed.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether a consumer has handled the event
+    pub fn is_consumed(&self) -> bool {
+        self.consumed.load(Ordering::SeqCst)
+    }
+}
+
+impl Deref for EventHandle {
+    type Target = Event;
+
+    fn deref(&self) -> &Event {
+        &self.event
+    }
+}
+
 /// Gets the current event from the context
 ///
 /// This function should be called by components to access the current event.
-/// Each component can only access the event once per event cycle.
+/// Each component can only access the event once per event cycle, and no
+/// component receives it once it has been consumed.
 ///
 /// # Returns
 ///
 /// * `Option<Arc<Event>>` - The current event, or None if no event is available or already processed
 pub(crate) fn get_current_event() -> Option<Arc<Event>> {
+    next_event_handle().map(|handle| handle.event)
+}
+
+/// [`get_current_event`] with the flag to consume the event
+fn next_event_handle() -> Option<EventHandle> {
     // Use hook context to get component's hook index
     let hook_index = with_hook_context(|ctx| ctx.next_hook_index());
 
+    // Content hidden by a suspense boundary doesn't react to input
+    if reratui_core::suspense::is_hidden() {
+        debug!("Hook {} is hidden by a suspense boundary", hook_index);
+        return None;
+    }
+
     // Check the global storage
     let event_state = CURRENT_EVENT.read().unwrap();
 
@@ -79,6 +181,11 @@ pub(crate) fn get_current_event() -> Option<Arc<Event>> {
             return None;
         }
     };
+    let consumed = event_state.consumed.clone();
+    if consumed.load(Ordering::SeqCst) {
+        debug!("Event was consumed before hook {}", hook_index);
+        return None;
+    }
 
     // Check if this hook has already processed the event
     let already_processed = event_state
@@ -99,7 +206,7 @@ pub(crate) fn get_current_event() -> Option<Arc<Event>> {
     mark_event_processed(hook_index);
     debug!("Hook {} processing event", hook_index);
 
-    Some(event)
+    Some(EventHandle { event, consumed })
 }
 
 /// Marks the current event as processed by the specified component
@@ -193,6 +300,8 @@ pub fn mark_event_processed(component_id: usize) {
 ///
 /// - Events are consumed when accessed - each event can only be handled once per hook
 /// - Multiple components can use this hook independently
+/// - Once a consumer calls [`EventHandle::consume`], later consumers in the
+///   frame get `None`; see [`use_event_handle`]
 /// - Events are cleared at the start of each render cycle
 /// - Use with `use_state` for tracking event-related state
 ///
@@ -216,3 +325,119 @@ pub fn use_event() -> Option<Event> {
         }
     }
 }
+
+/// Like [`use_event`], but returns a handle that can stop the event from
+/// reaching later consumers
+///
+/// Components render in tree order, so [`EventHandle::consume`] hides the
+/// event from everything that asks for it after this component in the same
+/// frame, such as a list rendered after a focused input.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use crossterm::event::{Event, KeyCode};
+/// use reratui_hooks::event::use_event_handle;
+///
+/// if let Some(handle) = use_event_handle()
+///     && let Event::Key(key) = handle.event()
+///     && let KeyCode::Char(_) = key.code
+/// {
+///     // Typed characters belong to this input alone
+///     handle.consume();
+/// }
+/// ```
+pub fn use_event_handle() -> Option<EventHandle> {
+    use crate::panic_handler::catch_panic;
+
+    catch_panic(next_event_handle).unwrap_or_else(|panic_payload| {
+        tracing::error!(
+            target: "hooks::event",
+            "Event processing panicked: {:?}",
+            panic_payload.downcast_ref::<&str>().unwrap_or(&"<unknown panic>")
+        );
+        None
+    })
+}
+
+/// Returns the current event mapped through `filter`, or `None` when the
+/// filter rejects it
+///
+/// Builds on [`use_event`], so the event is delivered at most once per hook
+/// and not at all once consumed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use crossterm::event::Event;
+/// use reratui_hooks::event::use_event_filtered;
+///
+/// let resized = use_event_filtered(|event| match event {
+///     Event::Resize(width, height) => Some((width, height)),
+///     _ => None,
+/// });
+/// ```
+pub fn use_event_filtered<T>(filter: impl FnOnce(Event) -> Option<T>) -> Option<T> {
+    use_event().and_then(filter)
+}
+
+/// Returns the current event if it is a key event
+///
+/// Presses, repeats and releases all come through; check
+/// [`KeyEvent::is_press`] to act once per key stroke.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use crossterm::event::KeyCode;
+/// use reratui_hooks::event::use_key_event;
+///
+/// if let Some(key) = use_key_event()
+///     && key.is_press()
+///     && key.code == KeyCode::Enter
+/// {
+///     // submit
+/// }
+/// ```
+pub fn use_key_event() -> Option<KeyEvent> {
+    use_event_filtered(|event| match event {
+        Event::Key(key) => Some(key),
+        _ => None,
+    })
+}
+
+/// Returns the current event if it is a mouse event
+pub fn use_mouse_event() -> Option<MouseEvent> {
+    use_event_filtered(|event| match event {
+        Event::Mouse(mouse) => Some(mouse),
+        _ => None,
+    })
+}
+
+/// Returns the pasted text if the current event is a paste
+pub fn use_paste_event() -> Option<String> {
+    use_event_filtered(|event| match event {
+        Event::Paste(text) => Some(text),
+        _ => None,
+    })
+}
+
+/// Hook returning text pasted into the terminal
+///
+/// The runtime enables bracketed paste, so a paste arrives as a single
+/// `Event::Paste` rather than one key event per character. Handling it here
+/// lets an editor insert the whole text in one update.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::event::use_paste;
+///
+/// let mut content = String::new();
+/// if let Some(text) = use_paste() {
+///     content.push_str(&text);
+/// }
+/// ```
+pub fn use_paste() -> Option<String> {
+    use_paste_event()
+}
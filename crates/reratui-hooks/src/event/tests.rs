@@ -0,0 +1,76 @@
+//! Tests for event hooks
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyCode, KeyModifiers, MouseEventKind};
+use parking_lot::Mutex;
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn mouse() -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: 3,
+        row: 4,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+/// What each filtered hook returns for `event`
+fn filtered(event: Event) -> (Option<KeyEvent>, Option<MouseEvent>, Option<String>) {
+    set_current_event(Some(Arc::new(event)));
+    let seen = with_component_id("FilteredEventTest", |_ctx| {
+        (use_key_event(), use_mouse_event(), use_paste_event())
+    });
+    set_current_event(None);
+    seen
+}
+
+#[test]
+fn test_filtered_hooks_match_their_event_kind() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert_eq!(filtered(Event::Key(key)), (Some(key), None, None));
+
+        // A mouse event doesn't leak through the key hook
+        assert_eq!(filtered(Event::Mouse(mouse())), (None, Some(mouse()), None));
+
+        let paste = Event::Paste("hello".to_string());
+        assert_eq!(filtered(paste), (None, None, Some("hello".to_string())));
+
+        assert_eq!(filtered(Event::FocusGained), (None, None, None));
+    });
+}
+
+#[test]
+fn test_consumed_event_reaches_no_filter() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(key))));
+        let seen = with_component_id("ConsumedEventTest", |_ctx| {
+            let handle = use_event_handle();
+            handle.as_ref().map(EventHandle::consume);
+            (handle.is_some(), use_key_event())
+        });
+        set_current_event(None);
+        assert_eq!(seen, (true, None));
+    });
+}
+
+#[test]
+fn test_paste_arrives_as_one_event() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let text = "fn main() {\n    println!(\"hi\");\n}".repeat(20);
+        set_current_event(Some(Arc::new(Event::Paste(text.clone()))));
+        let (pasted, key) = with_component_id("PasteTest", |_ctx| (use_paste(), use_key_event()));
+        set_current_event(None);
+
+        assert_eq!(pasted, Some(text));
+        assert_eq!(key, None);
+    });
+}
@@ -0,0 +1,273 @@
+//! Focus management with tab-order traversal
+//!
+//! A root component calls [`use_focus_manager`] to create a [`FocusManager`]
+//! and provide it to its subtree. Every focusable component calls
+//! [`use_focus`] with its tab order; Tab and Shift+Tab then move the focus
+//! through the focusables in that order, wrapping at either end.
+//!
+//! Focusables register again on every frame, so components that stop
+//! rendering drop out of the tab order on their own. If the focused component
+//! disappears, the focus moves to the first focusable.
+//!
+//! Focusables registered inside [`FocusManager::trap`] form a focus trap:
+//! while any component renders a trap, only its focusables take part in the
+//! tab order. Once no trap renders anymore, the focus returns to where it was
+//! before the trap appeared.
+
+use crossterm::event::{Event, KeyCode};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::context::{use_context, use_context_provider};
+use crate::event::use_event;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// Source of focusable ids
+static NEXT_FOCUS_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Identifies one focusable across renders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusId(u64);
+
+impl FocusId {
+    fn next() -> Self {
+        Self(NEXT_FOCUS_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[derive(Debug, Default)]
+struct FocusRegistry {
+    /// Focusables registered so far in the current frame, and whether they
+    /// were registered inside a trap
+    current: Vec<(i32, FocusId, bool)>,
+    /// Number of traps currently rendering
+    trap_depth: usize,
+    /// Whether a trap rendered in the current frame
+    trap_rendered: bool,
+    /// Whether the last complete frame had a trap
+    trapped: bool,
+    /// The focus before the current trap appeared
+    restore: Option<FocusId>,
+    /// Focusables of the last complete frame, sorted by tab order
+    order: Vec<FocusId>,
+    /// The focused component, always one of `order` once a frame completed
+    active: Option<FocusId>,
+}
+
+impl FocusRegistry {
+    /// Completes the registrations of the last frame and prunes stale focus
+    fn begin_frame(&mut self) {
+        let mut registered = std::mem::take(&mut self.current);
+        let trap = std::mem::take(&mut self.trap_rendered);
+
+        if trap {
+            registered.retain(|(_, _, trapped)| *trapped);
+            if !self.trapped {
+                self.restore = self.active;
+            }
+        } else if self.trapped {
+            self.active = self.restore.take();
+        }
+        self.trapped = trap;
+
+        // Stable, so equal orders keep their render order
+        registered.sort_by_key(|(order, _, _)| *order);
+        self.order = registered.into_iter().map(|(_, id, _)| id).collect();
+
+        if !self.active.is_some_and(|id| self.order.contains(&id)) {
+            self.active = self.order.first().copied();
+        }
+    }
+
+    fn step(&mut self, forward: bool) {
+        let len = self.order.len();
+        if len == 0 {
+            return;
+        }
+
+        let next = match self
+            .active
+            .and_then(|id| self.order.iter().position(|&other| other == id))
+        {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        self.active = Some(self.order[next]);
+    }
+}
+
+/// Tracks which focusable is active and the tab order between them
+///
+/// Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct FocusManager {
+    registry: Arc<Mutex<FocusRegistry>>,
+}
+
+impl FocusManager {
+    /// Creates a manager without focusables
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new frame
+    ///
+    /// Registrations made since the previous call become the tab order.
+    /// [`use_focus_manager`] calls this on every render.
+    pub fn begin_frame(&self) {
+        self.registry.lock().begin_frame();
+    }
+
+    /// Registers a focusable for the current frame
+    pub fn register(&self, id: FocusId, order: i32) {
+        let mut registry = self.registry.lock();
+        let trapped = registry.trap_depth > 0;
+        registry.current.push((order, id, trapped));
+    }
+
+    /// Runs `render` as a focus trap
+    ///
+    /// From the next frame on, Tab and Shift+Tab only cycle through the
+    /// focusables registered while `render` runs, and the first of them gets
+    /// the focus. When a frame renders no trap, the focus returns to the
+    /// component that had it before the trap appeared. Nested traps share one
+    /// tab order.
+    pub fn trap<R>(&self, render: impl FnOnce() -> R) -> R {
+        {
+            let mut registry = self.registry.lock();
+            registry.trap_depth += 1;
+            registry.trap_rendered = true;
+        }
+        let result = render();
+        self.registry.lock().trap_depth -= 1;
+        result
+    }
+
+    /// Whether a focus trap rendered in the last frame and the component
+    /// rendering now is outside of it
+    pub(crate) fn is_outside_trap(&self) -> bool {
+        let registry = self.registry.lock();
+        registry.trapped && registry.trap_depth == 0
+    }
+
+    /// The focused component, if any
+    pub fn active(&self) -> Option<FocusId> {
+        self.registry.lock().active
+    }
+
+    /// Checks if `id` is focused
+    pub fn is_focused(&self, id: FocusId) -> bool {
+        self.active() == Some(id)
+    }
+
+    /// Focuses `id`
+    pub fn focus(&self, id: FocusId) {
+        self.registry.lock().active = Some(id);
+    }
+
+    /// Moves the focus to the next focusable, wrapping to the first
+    pub fn focus_next(&self) {
+        self.registry.lock().step(true);
+    }
+
+    /// Moves the focus to the previous focusable, wrapping to the last
+    pub fn focus_prev(&self) {
+        self.registry.lock().step(false);
+    }
+
+    /// Number of focusables in the last complete frame
+    pub fn len(&self) -> usize {
+        self.registry.lock().order.len()
+    }
+
+    /// Checks if the last complete frame had no focusables
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The focus state of one focusable component
+#[derive(Debug, Clone)]
+pub struct FocusState {
+    id: FocusId,
+    manager: FocusManager,
+}
+
+impl FocusState {
+    /// The id this component is registered under
+    pub fn id(&self) -> FocusId {
+        self.id
+    }
+
+    /// Checks if this component has the focus
+    pub fn is_focused(&self) -> bool {
+        self.manager.is_focused(self.id)
+    }
+
+    /// Moves the focus to this component
+    pub fn focus(&self) {
+        self.manager.focus(self.id);
+    }
+}
+
+/// Creates a [`FocusManager`] and provides it to the subtree
+///
+/// Call this once near the root, above every component using [`use_focus`].
+/// Tab moves the focus forward and Shift+Tab moves it back.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::focus::{use_focus, use_focus_manager};
+///
+/// // In the root component
+/// let focus = use_focus_manager();
+///
+/// // In each input, rendered below the root
+/// let name = use_focus(0);
+/// let email = use_focus(1);
+/// if name.is_focused() {
+///     // Handle typing into the name field
+/// }
+/// ```
+pub fn use_focus_manager() -> FocusManager {
+    let manager = use_ref(FocusManager::new).get();
+    manager.begin_frame();
+
+    if let Some(Event::Key(key)) = use_event()
+        && key.is_press()
+    {
+        match key.code {
+            KeyCode::Tab => manager.focus_next(),
+            KeyCode::BackTab => manager.focus_prev(),
+            _ => {}
+        }
+    }
+
+    use_context_provider(|| manager)
+}
+
+/// Registers the calling component as focusable with the given tab order
+///
+/// Lower orders come first when tabbing; focusables with the same order keep
+/// the order in which they render. The focus state is also provided to the
+/// subtree, which scopes [`HotkeyScope::WhenFocused`] hotkeys to it.
+///
+/// # Panics
+///
+/// Panics if no ancestor called [`use_focus_manager`].
+///
+/// [`HotkeyScope::WhenFocused`]: crate::keyboard::HotkeyScope::WhenFocused
+pub fn use_focus(order: i32) -> FocusState {
+    let manager = use_context::<FocusManager>();
+    let id = use_ref(FocusId::next).get();
+    manager.register(id, order);
+
+    use_context_provider(|| FocusState { id, manager })
+}
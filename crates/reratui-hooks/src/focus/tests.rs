@@ -0,0 +1,125 @@
+//! Tests for focus management
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[test]
+fn test_tab_order_and_wrapping() {
+    let manager = FocusManager::new();
+    let (a, b, c) = (FocusId::next(), FocusId::next(), FocusId::next());
+
+    // Registered out of order; the tab order follows `order`
+    manager.register(c, 2);
+    manager.register(a, 0);
+    manager.register(b, 1);
+    manager.begin_frame();
+
+    assert_eq!(manager.len(), 3);
+    assert_eq!(manager.active(), Some(a));
+
+    manager.focus_next();
+    assert_eq!(manager.active(), Some(b));
+    manager.focus_next();
+    manager.focus_next();
+    assert_eq!(manager.active(), Some(a));
+
+    manager.focus_prev();
+    assert_eq!(manager.active(), Some(c));
+}
+
+#[test]
+fn test_stale_focusables_are_pruned() {
+    let manager = FocusManager::new();
+    let (a, b) = (FocusId::next(), FocusId::next());
+
+    manager.register(a, 0);
+    manager.register(b, 0);
+    manager.begin_frame();
+    manager.focus(b);
+
+    // `b` stopped rendering
+    manager.register(a, 0);
+    manager.begin_frame();
+
+    assert_eq!(manager.len(), 1);
+    assert!(manager.is_focused(a));
+
+    // Nothing rendered at all
+    manager.begin_frame();
+    assert!(manager.is_empty());
+    assert_eq!(manager.active(), None);
+}
+
+#[test]
+fn test_trap_limits_tab_order_and_restores_focus() {
+    let manager = FocusManager::new();
+    let (a, b, x, y) = (
+        FocusId::next(),
+        FocusId::next(),
+        FocusId::next(),
+        FocusId::next(),
+    );
+
+    let frame = |trap: bool| {
+        manager.register(a, 0);
+        manager.register(b, 1);
+        if trap {
+            manager.trap(|| {
+                manager.register(x, 0);
+                manager.register(y, 1);
+            });
+        }
+        manager.begin_frame();
+    };
+
+    frame(false);
+    manager.focus(b);
+
+    frame(true);
+    assert_eq!(manager.len(), 2);
+    assert!(manager.is_focused(x));
+    manager.focus_next();
+    manager.focus_next();
+    assert!(manager.is_focused(x));
+
+    frame(false);
+    assert_eq!(manager.len(), 2);
+    assert!(manager.is_focused(b));
+}
+
+#[test]
+fn test_use_focus_follows_tab_presses() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+
+        let render = || {
+            with_component_id("FocusRoot", |_ctx| {
+                use_focus_manager();
+                (use_focus(1), use_focus(0))
+            })
+        };
+
+        // Focus settles once the first frame has registered everything
+        render();
+        let (second, first) = render();
+        assert!(first.is_focused());
+        assert!(!second.is_focused());
+
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(tab))));
+        let (second, first) = render();
+        set_current_event(None);
+        assert!(second.is_focused());
+        assert!(!first.is_focused());
+
+        first.focus();
+        assert!(first.is_focused());
+    });
+}
@@ -2,15 +2,17 @@
 //!
 //! Provides a comprehensive form handling solution with:
 //! - Field registration and value management
-//! - Validation with custom rules
+//! - Validation with custom rules, including debounced async validators
 //! - Error tracking per field
+//! - Field arrays for dynamic, repeating inputs with stable item identity
 //! - Dirty/touched state tracking
 //! - Submit handling with async support
 //! - Context API for sharing form state without prop drilling
 
 use crate::context::{use_context, use_context_provider};
+use crate::ref_hook::use_ref;
 use crate::state::use_state;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 mod types;
 mod use_watch;
@@ -71,11 +73,14 @@ pub use validation::*;
 /// }
 /// ```
 pub fn use_form(config: FormConfig) -> FormHandle {
-    let (values, set_values) = use_state(|| config.initial_values.clone());
+    let (defaults, set_defaults) = use_state(|| config.initial_state());
+    let (values, set_values) = use_state(|| defaults.get().values);
     let (errors, set_errors) = use_state(HashMap::<String, String>::new);
     let (touched, set_touched) = use_state(HashMap::<String, bool>::new);
     let (is_submitting, set_is_submitting) = use_state(|| false);
-    let (is_valid, set_is_valid) = use_state(|| true);
+    let (validating, set_validating) = use_state(HashSet::<String>::new);
+    let async_validation = use_ref(AsyncValidationState::default);
+    let (arrays, set_arrays) = use_state(|| defaults.get().arrays);
 
     let form = FormHandle {
         values,
@@ -86,8 +91,15 @@ pub fn use_form(config: FormConfig) -> FormHandle {
         set_touched,
         is_submitting,
         set_is_submitting,
-        is_valid,
-        set_is_valid,
+        validating,
+        set_validating,
+        async_validation,
+        arrays,
+        set_arrays,
+        defaults,
+        set_defaults,
+        initial_values: config.initial_values,
+        field_arrays: config.field_arrays,
         validators: config.validators,
         on_submit: config.on_submit,
     };
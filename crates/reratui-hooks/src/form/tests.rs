@@ -1,7 +1,7 @@
 //! Tests for form hook
 
 use super::*;
-use crate::test_utils::{with_component_id, with_test_isolate};
+use crate::test_utils::{cleanup_component_contexts, with_component_id, with_test_isolate};
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
@@ -18,6 +18,7 @@ fn test_form_initialization() {
 
             let form = use_form(FormConfig {
                 initial_values: initial_values.clone(),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -41,6 +42,7 @@ fn test_form_set_value() {
         with_component_id("FormSetValue", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("username".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -59,6 +61,7 @@ fn test_form_touched_state() {
         with_component_id("FormTouched", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -66,8 +69,12 @@ fn test_form_touched_state() {
             assert!(!form.is_touched("email"));
             assert!(!form.is_dirty());
 
+            // Touching a field without changing it does not make it dirty
             form.set_touched("email", true);
             assert!(form.is_touched("email"));
+            assert!(!form.is_dirty());
+
+            form.set_value("email", "user@example.com".to_string());
             assert!(form.is_dirty());
         });
     });
@@ -79,6 +86,7 @@ fn test_form_validation_required() {
         with_component_id("FormValidationRequired", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "email".to_string(),
                     vec![Validator::required("Email is required")],
@@ -106,6 +114,7 @@ fn test_form_validation_email() {
         with_component_id("FormValidationEmail", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "email".to_string(),
                     vec![Validator::email("Invalid email format")],
@@ -133,6 +142,7 @@ fn test_form_validation_min_length() {
         with_component_id("FormValidationMinLength", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("password".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "password".to_string(),
                     vec![Validator::min_length(
@@ -163,6 +173,7 @@ fn test_form_validation_multiple_validators() {
         with_component_id("FormValidationMultiple", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "email".to_string(),
                     vec![
@@ -203,6 +214,7 @@ fn test_form_validate_all() {
                     ("email".to_string(), "".to_string()),
                     ("password".to_string(), "".to_string()),
                 ]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([
                     (
                         "email".to_string(),
@@ -254,6 +266,7 @@ fn test_form_submit() {
                     ("email".to_string(), "test@example.com".to_string()),
                     ("password".to_string(), "password123".to_string()),
                 ]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([
                     (
                         "email".to_string(),
@@ -289,6 +302,7 @@ fn test_form_submit_invalid() {
 
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "email".to_string(),
                     vec![Validator::required("Email is required")],
@@ -318,6 +332,7 @@ fn test_form_reset() {
 
             let form = use_form(FormConfig {
                 initial_values: initial_values.clone(),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -335,7 +350,7 @@ fn test_form_reset() {
             assert!(form.has_errors());
 
             // Reset form
-            form.reset(initial_values.clone());
+            form.reset();
 
             assert_eq!(
                 form.get_value("email"),
@@ -355,6 +370,7 @@ fn test_form_register_field() {
         with_component_id("FormRegister", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("username".to_string(), "john".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "username".to_string(),
                     vec![Validator::required("Username is required")],
@@ -409,6 +425,7 @@ fn test_form_get_values() {
 
             let form = use_form(FormConfig {
                 initial_values: initial_values.clone(),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -427,6 +444,7 @@ fn test_form_get_errors() {
         with_component_id("FormGetErrors", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("email".to_string(), "".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::from([(
                     "email".to_string(),
                     vec![Validator::required("Email is required")],
@@ -453,6 +471,7 @@ fn test_form_provider_and_context() {
                     ("username".to_string(), "john".to_string()),
                     ("email".to_string(), "john@example.com".to_string()),
                 ]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -489,6 +508,7 @@ fn test_form_context_shared_state() {
         with_component_id("FormProviderShared", |_| {
             let form = use_form(FormConfig {
                 initial_values: HashMap::from([("count".to_string(), "0".to_string())]),
+                field_arrays: HashMap::new(),
                 validators: HashMap::new(),
                 on_submit: Arc::new(|_| {}),
             });
@@ -517,3 +537,520 @@ fn test_form_context_shared_state() {
         });
     });
 }
+
+/// Build a form whose username field is checked by a debounced async validator
+fn username_availability_form(calls: Arc<std::sync::atomic::AtomicUsize>) -> FormHandle {
+    use_form(
+        FormConfig::builder()
+            .field("username", "")
+            .validator("username", Validator::required("Username is required"))
+            .validator(
+                "username",
+                Validator::async_fn(move |value| {
+                    let calls = calls.clone();
+                    async move {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                        if value == "admin" {
+                            Some("Username is taken".to_string())
+                        } else {
+                            None
+                        }
+                    }
+                })
+                .debounce(std::time::Duration::from_millis(20)),
+            )
+            .build_with_default_submit(),
+    )
+}
+
+#[tokio::test]
+async fn test_form_async_validator() {
+    let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let form = with_component_id("FormAsyncValidator", |_| {
+        username_availability_form(calls.clone())
+    });
+
+    assert!(!form.validate_field("username", "admin"));
+    assert!(form.is_validating("username"));
+    assert!(form.is_validating_any());
+    assert!(!form.is_valid(), "Validating fields are not yet valid");
+    assert_eq!(form.get_error("username"), None);
+
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+
+    assert!(!form.is_validating("username"));
+    assert_eq!(
+        form.get_error("username"),
+        Some("Username is taken".to_string())
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // A completed result for the same value is reused without re-running
+    assert!(!form.validate_field("username", "admin"));
+    assert!(!form.is_validating("username"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    cleanup_component_contexts();
+}
+
+#[tokio::test]
+async fn test_form_async_validator_cancelled_by_newer_value() {
+    let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let form = with_component_id("FormAsyncValidatorCancel", |_| {
+        username_availability_form(calls.clone())
+    });
+
+    // Rapid keystrokes within the debounce window only validate the last value
+    form.validate_field("username", "a");
+    form.validate_field("username", "ad");
+    form.validate_field("username", "admin");
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        form.get_error("username"),
+        Some("Username is taken".to_string())
+    );
+
+    // A failing sync validator cancels the in-flight async validation
+    form.validate_field("username", "alice");
+    assert!(form.is_validating("username"));
+    assert!(!form.validate_field("username", ""));
+    assert!(!form.is_validating("username"));
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+
+    assert_eq!(
+        form.get_error("username"),
+        Some("Username is required".to_string())
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    cleanup_component_contexts();
+}
+
+/// Submitting within the debounce window submits once validation passes
+#[tokio::test]
+async fn test_form_submit_waits_for_async_validation() {
+    let submitted = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let form = with_component_id("FormAsyncSubmit", |_| {
+        let submitted = submitted.clone();
+        use_form(
+            FormConfig::builder()
+                .field("username", "")
+                .validator("username", Validator::required("Username is required"))
+                .validator(
+                    "username",
+                    Validator::async_fn(|value| async move {
+                        (value == "admin").then(|| "Username is taken".to_string())
+                    })
+                    .debounce(std::time::Duration::from_millis(20)),
+                )
+                .on_submit(move |values| {
+                    submitted
+                        .lock()
+                        .unwrap()
+                        .push(values.get("username").cloned().unwrap_or_default());
+                })
+                .build(),
+        )
+    });
+
+    form.set_value("username", "alice".to_string());
+    form.submit();
+    assert!(submitted.lock().unwrap().is_empty());
+    assert!(!form.is_valid());
+
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+    assert_eq!(*submitted.lock().unwrap(), vec!["alice".to_string()]);
+    assert!(form.is_valid());
+
+    // A failing async result drops the pending submit
+    form.set_value("username", "admin".to_string());
+    form.submit();
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+    assert_eq!(submitted.lock().unwrap().len(), 1);
+    assert!(!form.is_valid());
+
+    // `is_valid` follows async results after `validate_all`
+    form.set_value("username", "bob".to_string());
+    assert!(!form.validate_all());
+    assert!(!form.is_valid());
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+    assert!(form.is_valid());
+    assert_eq!(submitted.lock().unwrap().len(), 1);
+
+    cleanup_component_contexts();
+}
+
+/// A validation finishing before `validate_field` returns still lands
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_form_async_validator_finishing_immediately() {
+    let form = with_component_id("FormAsyncValidatorImmediate", |_| {
+        use_form(
+            FormConfig::builder()
+                .field("username", "")
+                .validator(
+                    "username",
+                    Validator::async_fn(|value| async move {
+                        (value == "admin").then(|| "Username is taken".to_string())
+                    })
+                    .debounce(std::time::Duration::ZERO),
+                )
+                .build_with_default_submit(),
+        )
+    });
+
+    for value in ["admin", "alice"].into_iter().cycle().take(20) {
+        form.validate_field("username", value);
+        // Poll rather than sleep a fixed time, so a busy machine can't fail it
+        let settled = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while form.is_validating("username") {
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            }
+        })
+        .await;
+
+        assert!(settled.is_ok(), "validating {value:?}");
+        assert_eq!(
+            form.get_error("username").is_some(),
+            value == "admin",
+            "validating {value:?}"
+        );
+    }
+    assert!(form.is_valid());
+
+    cleanup_component_contexts();
+}
+
+/// A panicking async validator is recorded for the render loop to report
+#[tokio::test]
+async fn test_form_async_validator_panic_is_registered() {
+    let form = with_component_id("FormAsyncValidatorPanic", |_| {
+        use_form(
+            FormConfig::builder()
+                .field("username", "")
+                .validator(
+                    "username",
+                    Validator::async_fn(|value| async move {
+                        if value.is_empty() {
+                            None
+                        } else {
+                            panic!("validator failed for {value}")
+                        }
+                    })
+                    .debounce(std::time::Duration::ZERO),
+                )
+                .build_with_default_submit(),
+        )
+    });
+
+    form.validate_field("username", "admin");
+
+    let recorded = async {
+        loop {
+            if let Some(panic) = crate::panic_handler::TaskRegistry::global().take_panic()
+                && panic.message() == "validator failed for admin"
+            {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(1), recorded)
+        .await
+        .expect("the panic should be recorded in the task registry");
+
+    cleanup_component_contexts();
+}
+
+#[test]
+fn test_form_field_array_initial_items() {
+    with_test_isolate(|| {
+        with_component_id("FormFieldArrayInit", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field("name", "")
+                    .field_array("emails", ["a@example.com", "b@example.com"])
+                    .build_with_default_submit(),
+            );
+
+            let items = form.field_array("emails");
+            assert_eq!(items.len(), 2);
+            assert_eq!(items[0].index, 0);
+            assert_eq!(items[0].value, "a@example.com");
+            assert_eq!(items[1].value, "b@example.com");
+            assert_ne!(items[0].key, items[1].key);
+            assert_eq!(
+                form.get_value(&items[1].key),
+                Some("b@example.com".to_string())
+            );
+            assert_eq!(
+                form.get_array_values("emails"),
+                vec!["a@example.com", "b@example.com"]
+            );
+            assert!(form.field_array("missing").is_empty());
+        });
+    });
+}
+
+#[test]
+fn test_form_field_array_append_remove() {
+    with_test_isolate(|| {
+        with_component_id("FormFieldArrayAppendRemove", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field_array("emails", ["first@example.com"])
+                    .build_with_default_submit(),
+            );
+
+            let key = form.append("emails");
+            assert_eq!(form.get_value(&key), Some(String::new()));
+            form.set_value(&key, "second@example.com".to_string());
+
+            let third = form.append_value("emails", "third@example.com".to_string());
+            assert_eq!(form.field_array("emails").len(), 3);
+
+            form.remove("emails", 1);
+            assert_eq!(
+                form.get_array_values("emails"),
+                vec!["first@example.com", "third@example.com"]
+            );
+            assert_eq!(form.get_value(&key), None);
+            assert_eq!(form.field_array("emails")[1].key, third);
+
+            // Out-of-range removal is ignored
+            form.remove("emails", 10);
+            assert_eq!(form.field_array("emails").len(), 2);
+        });
+    });
+}
+
+#[test]
+fn test_form_field_array_ids_not_reused_after_reset() {
+    with_test_isolate(|| {
+        with_component_id("FormFieldArrayResetIds", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field_array("emails", ["a@example.com", "b@example.com"])
+                    .build_with_default_submit(),
+            );
+
+            form.append("emails");
+            let mut seen: Vec<u64> = form.field_array("emails").iter().map(|i| i.id).collect();
+
+            form.reset();
+            let items = form.field_array("emails");
+            assert_eq!(
+                form.get_array_values("emails"),
+                vec!["a@example.com", "b@example.com"]
+            );
+            assert!(items.iter().all(|item| !seen.contains(&item.id)));
+            assert!(!form.is_dirty());
+            seen.extend(items.iter().map(|item| item.id));
+
+            form.reset_to(HashMap::new());
+            let items = form.field_array("emails");
+            assert!(items.iter().all(|item| !seen.contains(&item.id)));
+            seen.extend(items.iter().map(|item| item.id));
+
+            let key = form.append("emails");
+            let appended = form.field_array("emails").last().unwrap().id;
+            assert!(!seen.contains(&appended));
+            assert_eq!(key, format!("emails.{appended}"));
+        });
+    });
+}
+
+#[test]
+fn test_form_field_array_move_keeps_identity() {
+    with_test_isolate(|| {
+        with_component_id("FormFieldArrayMove", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field_array("emails", ["valid@example.com", "invalid"])
+                    .validator("emails", Validator::email("Invalid email format"))
+                    .build_with_default_submit(),
+            );
+
+            // Array validators apply to each item
+            assert!(!form.validate_all());
+            let invalid_key = form.field_array("emails")[1].key.clone();
+            assert_eq!(
+                form.get_error(&invalid_key),
+                Some("Invalid email format".to_string())
+            );
+            form.set_touched(&invalid_key, true);
+
+            form.move_item("emails", 1, 0);
+
+            let items = form.field_array("emails");
+            assert_eq!(items[0].key, invalid_key);
+            assert_eq!(items[0].value, "invalid");
+            assert_eq!(
+                form.get_error(&items[0].key),
+                Some("Invalid email format".to_string())
+            );
+            assert!(form.is_touched(&items[0].key));
+            assert_eq!(form.get_error(&items[1].key), None);
+
+            form.remove("emails", 0);
+            assert_eq!(form.get_error(&invalid_key), None);
+            assert!(!form.is_touched(&invalid_key));
+            assert!(form.validate_all());
+        });
+    });
+}
+
+#[test]
+fn test_form_set_value_marks_dirty_not_touched() {
+    with_test_isolate(|| {
+        with_component_id("FormSetValueDirty", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field("email", "")
+                    .field("name", "")
+                    .validator("email", Validator::email("Invalid email format"))
+                    .build_with_default_submit(),
+            );
+
+            form.set_value("email", "not-an-email".to_string());
+
+            assert!(form.is_field_dirty("email"));
+            assert!(!form.is_touched("email"));
+            assert_eq!(
+                form.get_error("email"),
+                Some("Invalid email format".to_string())
+            );
+
+            form.set_values(HashMap::from([
+                ("email".to_string(), "valid@example.com".to_string()),
+                ("name".to_string(), "Ada".to_string()),
+            ]));
+
+            assert_eq!(form.get_value("name"), Some("Ada".to_string()));
+            assert!(form.is_field_dirty("name"));
+            assert!(!form.is_touched("name"));
+            assert_eq!(form.get_error("email"), None);
+
+            // Setting a field back to its default makes it clean again
+            form.set_value("name", String::new());
+            assert!(!form.is_field_dirty("name"));
+        });
+    });
+}
+
+#[test]
+fn test_form_reset_to_loads_record() {
+    with_test_isolate(|| {
+        with_component_id("FormResetTo", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field("name", "")
+                    .field("email", "")
+                    .field("role", "viewer")
+                    .field_array("tags", ["new"])
+                    .validator("email", Validator::required("Email is required"))
+                    .build_with_default_submit(),
+            );
+
+            // Load an existing record for editing
+            form.reset_to(HashMap::from([
+                ("name".to_string(), "Ada".to_string()),
+                ("email".to_string(), "ada@example.com".to_string()),
+            ]));
+
+            assert_eq!(form.get_value("name"), Some("Ada".to_string()));
+            assert_eq!(form.get_value("role"), Some("viewer".to_string()));
+            assert_eq!(form.get_array_values("tags"), vec!["new"]);
+            assert!(!form.is_field_dirty("name"));
+            assert!(form.is_valid());
+
+            // Edits are dirty relative to the loaded record
+            form.set_value("name", "Ada Lovelace".to_string());
+            form.append_value("tags", "admin".to_string());
+            assert!(form.is_field_dirty("name"));
+
+            // Clearing the loaded email re-runs validation
+            form.set_value("email", String::new());
+            assert!(form.has_errors());
+
+            // reset() goes back to the configured defaults, not the loaded record
+            form.reset();
+            assert_eq!(form.get_value("name"), Some(String::new()));
+            assert_eq!(form.get_array_values("tags"), vec!["new"]);
+            assert!(!form.is_field_dirty("name"));
+            assert!(!form.is_touched("email"));
+            assert_eq!(
+                form.get_error("email"),
+                Some("Email is required".to_string())
+            );
+            assert!(!form.is_valid());
+        });
+    });
+}
+
+#[test]
+fn test_form_dirty_fields() {
+    with_test_isolate(|| {
+        with_component_id("FormDirtyFields", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field("theme", "dark")
+                    .field("language", "en")
+                    .field_array("emails", ["a@example.com", "b@example.com"])
+                    .build_with_default_submit(),
+            );
+
+            assert!(!form.is_dirty());
+            assert!(form.dirty_fields().is_empty());
+
+            form.set_value("theme", "light".to_string());
+            form.set_value("language", "en".to_string());
+            assert!(form.is_dirty());
+            assert_eq!(form.dirty_fields(), vec!["theme"]);
+
+            // Reordering array items dirties the array itself
+            form.move_item("emails", 0, 1);
+            assert_eq!(form.dirty_fields(), vec!["emails", "theme"]);
+            form.move_item("emails", 1, 0);
+
+            // Changing a value back to its default makes the form clean again
+            form.set_value("theme", "dark".to_string());
+            assert!(!form.is_dirty());
+
+            // Removing an array item dirties the array and the removed item
+            let removed = form.field_array("emails")[1].key.clone();
+            form.remove("emails", 1);
+            assert_eq!(form.dirty_fields(), vec!["emails".to_string(), removed]);
+
+            form.reset();
+            assert!(!form.is_dirty());
+        });
+    });
+}
+
+#[test]
+fn test_form_watch() {
+    with_test_isolate(|| {
+        with_component_id("FormWatch", |_| {
+            let form = use_form(
+                FormConfig::builder()
+                    .field("name", "")
+                    .field("bio", "")
+                    .build_with_default_submit(),
+            );
+
+            assert_eq!(form.watch("name"), Some(String::new()));
+            assert_eq!(form.watch("missing"), None);
+
+            form.set_value("name", "Ada".to_string());
+            assert_eq!(form.watch("name"), Some("Ada".to_string()));
+
+            let all = form.watch_all();
+            assert_eq!(all.len(), 2);
+            assert_eq!(all.get("name"), Some(&"Ada".to_string()));
+            assert_eq!(all.get("bio"), Some(&String::new()));
+        });
+    });
+}
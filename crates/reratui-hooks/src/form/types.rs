@@ -1,9 +1,15 @@
 //! Type definitions for form management
 
+use crate::clock;
+use crate::panic_handler::spawn_registered;
+use crate::ref_hook::RefHandle;
 use crate::state::{StateHandle, StateSetter};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use super::validation::Validator;
+use super::validation::{AsyncValidationState, PendingValidation, Validator};
 
 /// Configuration for form initialization
 #[derive(Clone)]
@@ -11,6 +17,9 @@ pub struct FormConfig {
     /// Initial values for form fields
     pub(crate) initial_values: HashMap<String, String>,
 
+    /// Initial item values for field arrays, in order
+    pub(crate) field_arrays: HashMap<String, Vec<String>>,
+
     /// Validators for each field
     pub(crate) validators: HashMap<String, Vec<Validator>>,
     /// Callback when form is submitted
@@ -40,6 +49,11 @@ impl FormConfig {
     pub fn builder() -> FormConfigBuilder {
         FormConfigBuilder::new()
     }
+
+    /// Build the default values and field array state described by this config
+    pub(crate) fn initial_state(&self) -> FormDefaults {
+        FormDefaults::new(&self.initial_values, &self.field_arrays)
+    }
 }
 
 /// Builder for creating FormConfig with a fluent API
@@ -48,6 +62,7 @@ impl FormConfig {
 /// with method chaining.
 pub struct FormConfigBuilder {
     initial_values: HashMap<String, String>,
+    field_arrays: HashMap<String, Vec<String>>,
     validators: HashMap<String, Vec<Validator>>,
     #[allow(clippy::type_complexity)]
     on_submit: Option<Arc<dyn Fn(HashMap<String, String>) + Send + Sync>>,
@@ -58,6 +73,7 @@ impl FormConfigBuilder {
     pub fn new() -> Self {
         Self {
             initial_values: HashMap::new(),
+            field_arrays: HashMap::new(),
             validators: HashMap::new(),
             on_submit: None,
         }
@@ -97,6 +113,31 @@ impl FormConfigBuilder {
         self
     }
 
+    /// Add a field array with its initial items
+    ///
+    /// Field arrays hold a variable number of values, such as multiple email
+    /// addresses. Validators registered for the array name apply to every item.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use reratui::prelude::*;
+    /// FormConfig::builder()
+    ///     .field_array("emails", ["primary@example.com"])
+    ///     .validator("emails", Validator::email("Invalid email format"));
+    /// ```
+    pub fn field_array<I, V>(mut self, name: impl Into<String>, initial_items: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.field_arrays.insert(
+            name.into(),
+            initial_items.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
     /// Add validators for a specific field
     ///
     /// # Example
@@ -160,6 +201,7 @@ impl FormConfigBuilder {
     pub fn build(self) -> FormConfig {
         FormConfig {
             initial_values: self.initial_values,
+            field_arrays: self.field_arrays,
             validators: self.validators,
             on_submit: self
                 .on_submit
@@ -173,6 +215,7 @@ impl FormConfigBuilder {
     pub fn build_with_default_submit(self) -> FormConfig {
         FormConfig {
             initial_values: self.initial_values,
+            field_arrays: self.field_arrays,
             validators: self.validators,
             on_submit: self.on_submit.unwrap_or_else(|| Arc::new(|_| {})),
         }
@@ -196,8 +239,15 @@ pub struct FormHandle {
     pub(crate) set_touched: StateSetter<HashMap<String, bool>>,
     pub(crate) is_submitting: StateHandle<bool>,
     pub(crate) set_is_submitting: StateSetter<bool>,
-    pub(crate) is_valid: StateHandle<bool>,
-    pub(crate) set_is_valid: StateSetter<bool>,
+    pub(crate) validating: StateHandle<HashSet<String>>,
+    pub(crate) set_validating: StateSetter<HashSet<String>>,
+    pub(crate) async_validation: RefHandle<AsyncValidationState>,
+    pub(crate) arrays: StateHandle<FieldArrayState>,
+    pub(crate) set_arrays: StateSetter<FieldArrayState>,
+    pub(crate) defaults: StateHandle<FormDefaults>,
+    pub(crate) set_defaults: StateSetter<FormDefaults>,
+    pub(crate) initial_values: HashMap<String, String>,
+    pub(crate) field_arrays: HashMap<String, Vec<String>>,
     pub(crate) validators: HashMap<String, Vec<Validator>>,
     pub(crate) on_submit: Arc<dyn Fn(HashMap<String, String>) + Send + Sync>,
 }
@@ -213,8 +263,15 @@ impl Default for FormHandle {
             set_touched: StateSetter::default(),
             is_submitting: StateHandle::default(),
             set_is_submitting: StateSetter::default(),
-            is_valid: StateHandle::default(),
-            set_is_valid: StateSetter::default(),
+            validating: StateHandle::default(),
+            set_validating: StateSetter::default(),
+            async_validation: RefHandle::new(AsyncValidationState::default),
+            arrays: StateHandle::default(),
+            set_arrays: StateSetter::default(),
+            defaults: StateHandle::default(),
+            set_defaults: StateSetter::default(),
+            initial_values: HashMap::new(),
+            field_arrays: HashMap::new(),
             validators: HashMap::new(),
             on_submit: Arc::new(|_| {}),
         }
@@ -238,17 +295,42 @@ impl FormHandle {
     }
 
     /// Set the value of a field
+    ///
+    /// The field becomes dirty when the value differs from its default, but it
+    /// is not marked as touched. The field is re-validated with the new value.
     pub fn set_value(&self, name: &str, value: String) {
         let mut values = self.values.get();
         values.insert(name.to_string(), value.clone());
         self.set_values.set(values);
 
-        // Validate field if it has been touched
-        if self.is_touched(name) {
-            self.validate_field(name, &value);
+        self.validate_field(name, &value);
+    }
+
+    /// Set the values of several fields at once
+    ///
+    /// Behaves like calling [`FormHandle::set_value`] for every entry.
+    pub fn set_values(&self, values: HashMap<String, String>) {
+        let mut current = self.values.get();
+        current.extend(values.clone());
+        self.set_values.set(current);
+
+        for (name, value) in &values {
+            self.validate_field(name, value);
         }
     }
 
+    /// Check if a field's value differs from its default
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        let values = self.values.get();
+        let defaults = self.defaults.get();
+        values.get(name).map(String::as_str).unwrap_or_default()
+            != defaults
+                .values
+                .get(name)
+                .map(String::as_str)
+                .unwrap_or_default()
+    }
+
     /// Get the error message for a field
     pub fn get_error(&self, name: &str) -> Option<String> {
         self.errors.get().get(name).cloned()
@@ -277,21 +359,341 @@ impl FormHandle {
         self.set_touched.set(touched);
     }
 
+    /// Get the validators for a field, falling back to the array's validators for array items
+    fn validators_for(&self, name: &str) -> Option<&Vec<Validator>> {
+        self.validators.get(name).or_else(|| {
+            let array = self.array_name_of(name)?;
+            self.validators.get(&array)
+        })
+    }
+
+    /// Get the name of the field array an item key belongs to
+    fn array_name_of(&self, key: &str) -> Option<String> {
+        let (array, id) = key.rsplit_once('.')?;
+        let id = id.parse::<u64>().ok()?;
+        self.arrays
+            .get()
+            .items
+            .get(array)
+            .is_some_and(|ids| ids.contains(&id))
+            .then(|| array.to_string())
+    }
+
+    /// Get the items of a field array in order
+    ///
+    /// Each item has a stable key that can be passed to [`FormHandle::register`],
+    /// [`FormHandle::set_value`], and friends. Keys do not change when items
+    /// are reordered, so errors and focus stay with the right row.
+    pub fn field_array(&self, name: &str) -> Vec<FieldArrayItem> {
+        let values = self.values.get();
+        self.arrays
+            .get()
+            .items
+            .get(name)
+            .map(|ids| {
+                ids.iter()
+                    .enumerate()
+                    .map(|(index, &id)| {
+                        let key = array_item_key(name, id);
+                        FieldArrayItem {
+                            id,
+                            index,
+                            value: values.get(&key).cloned().unwrap_or_default(),
+                            key,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the values of a field array in order
+    pub fn get_array_values(&self, name: &str) -> Vec<String> {
+        self.field_array(name)
+            .into_iter()
+            .map(|item| item.value)
+            .collect()
+    }
+
+    /// Append an empty item to a field array, returning the new item's key
+    pub fn append(&self, name: &str) -> String {
+        self.append_value(name, String::new())
+    }
+
+    /// Append an item with a value to a field array, returning the new item's key
+    pub fn append_value(&self, name: &str, value: String) -> String {
+        let mut arrays = self.arrays.get();
+        let id = arrays.allocate_id();
+        arrays.items.entry(name.to_string()).or_default().push(id);
+        self.set_arrays.set(arrays);
+
+        let key = array_item_key(name, id);
+        let mut values = self.values.get();
+        values.insert(key.clone(), value);
+        self.set_values.set(values);
+        key
+    }
+
+    /// Remove the item at `index` from a field array
+    ///
+    /// The item's value, error, and touched state are discarded.
+    /// Out-of-range indices are ignored.
+    pub fn remove(&self, name: &str, index: usize) {
+        let mut arrays = self.arrays.get();
+        let Some(ids) = arrays.items.get_mut(name) else {
+            return;
+        };
+        if index >= ids.len() {
+            return;
+        }
+        let id = ids.remove(index);
+        self.set_arrays.set(arrays);
+
+        let key = array_item_key(name, id);
+        self.cancel_async_validation(&key);
+        self.async_validation.with_mut(|state| {
+            state.results.remove(&key);
+        });
+
+        let mut values = self.values.get();
+        values.remove(&key);
+        self.set_values.set(values);
+
+        let mut touched = self.touched.get();
+        if touched.remove(&key).is_some() {
+            self.set_touched.set(touched);
+        }
+        self.set_error(&key, None);
+    }
+
+    /// Move an item within a field array from one index to another
+    ///
+    /// Out-of-range indices are ignored.
+    pub fn move_item(&self, name: &str, from: usize, to: usize) {
+        let mut arrays = self.arrays.get();
+        let Some(ids) = arrays.items.get_mut(name) else {
+            return;
+        };
+        if from >= ids.len() || to >= ids.len() || from == to {
+            return;
+        }
+        let id = ids.remove(from);
+        ids.insert(to, id);
+        self.set_arrays.set(arrays);
+    }
+
+    /// Check if an async validation is in flight for a field
+    pub fn is_validating(&self, name: &str) -> bool {
+        self.validating.get().contains(name)
+    }
+
+    /// Check if any field has an async validation in flight
+    pub fn is_validating_any(&self) -> bool {
+        !self.validating.get().is_empty()
+    }
+
     /// Validate a specific field
+    ///
+    /// Synchronous validators run immediately. If they pass and the field has
+    /// async validators, a debounced validation is scheduled and `false` is
+    /// returned until it completes, unless a result for the same value is
+    /// already known.
     pub fn validate_field(&self, name: &str, value: &str) -> bool {
-        if let Some(validators) = self.validators.get(name) {
-            for validator in validators {
+        if let Some(validators) = self.validators_for(name) {
+            for validator in validators.iter().filter(|v| !v.is_async()) {
                 if let Some(error) = validator.validate(value) {
+                    self.cancel_async_validation(name);
                     self.set_error(name, Some(error));
                     return false;
                 }
             }
+
+            let async_validators: Vec<Validator> = validators
+                .iter()
+                .filter(|v| v.is_async())
+                .cloned()
+                .collect();
+
+            if !async_validators.is_empty() {
+                return self.validate_field_async(name, value, async_validators);
+            }
         }
         self.set_error(name, None);
         true
     }
 
+    /// Use a known async result for the value or schedule a new async validation
+    fn validate_field_async(&self, name: &str, value: &str, validators: Vec<Validator>) -> bool {
+        let outcome = self.async_validation.with_mut(|state| {
+            // Reuse the result of a completed validation for the same value
+            if let Some((validated, error)) = state.results.get(name)
+                && validated == value
+            {
+                let error = error.clone();
+                state.cancel(name);
+                return Err(error);
+            }
+
+            // A validation for this exact value is already in flight
+            if state.pending.get(name).is_some_and(|p| p.value == value) {
+                return Ok(None);
+            }
+
+            state.cancel(name);
+            state.next_generation += 1;
+            Ok(Some(state.next_generation))
+        });
+
+        let generation = match outcome {
+            Err(error) => {
+                self.set_validating_flag(name, false);
+                let is_valid = error.is_none();
+                self.set_error(name, error);
+                return is_valid;
+            }
+            Ok(None) => return false,
+            Ok(Some(generation)) => generation,
+        };
+
+        if tokio::runtime::Handle::try_current().is_err() {
+            tracing::warn!(
+                target: "hooks::form",
+                "Async validator for field {:?} skipped: no tokio runtime",
+                name
+            );
+            return false;
+        }
+
+        self.set_error(name, None);
+        self.set_validating_flag(name, true);
+
+        let debounce = validators
+            .iter()
+            .map(Validator::debounce_duration)
+            .max()
+            .unwrap_or_default();
+        let field = name.to_string();
+        let task_value = value.to_string();
+        let async_validation = self.async_validation.clone();
+        let set_errors = self.set_errors.clone();
+        let set_validating = self.set_validating.clone();
+        let form = self.clone();
+
+        // Register the validation before spawning it, so a task finishing
+        // right away still finds itself current
+        self.async_validation.with_mut(|state| {
+            state.pending.insert(
+                name.to_string(),
+                PendingValidation {
+                    value: value.to_string(),
+                    generation,
+                    task: None,
+                },
+            );
+        });
+
+        let task = spawn_registered(async move {
+            clock::sleep(debounce).await;
+
+            let mut error = None;
+            for validator in &validators {
+                error = validator.validate_async(task_value.clone()).await;
+                if error.is_some() {
+                    break;
+                }
+            }
+
+            // Discard the result if a newer change superseded this validation.
+            // The last validation to settle takes over a pending submit.
+            let outcome = async_validation.with_mut(|state| {
+                if state
+                    .pending
+                    .get(&field)
+                    .is_some_and(|p| p.generation == generation)
+                {
+                    state.pending.remove(&field);
+                    state
+                        .results
+                        .insert(field.clone(), (task_value, error.clone()));
+                    Some(state.pending.is_empty() && std::mem::take(&mut state.submit_pending))
+                } else {
+                    None
+                }
+            });
+
+            if let Some(submit) = outcome {
+                set_errors.update(|errors| {
+                    let mut errors = errors.clone();
+                    match error {
+                        Some(error) => errors.insert(field.clone(), error),
+                        None => errors.remove(&field),
+                    };
+                    errors
+                });
+                set_validating.update(|validating| {
+                    let mut validating = validating.clone();
+                    validating.remove(&field);
+                    validating
+                });
+
+                if submit && form.is_valid() {
+                    form.run_submit();
+                }
+            }
+        });
+
+        // Attach the task to its entry; if the entry is gone the task either
+        // finished already or was superseded before it could be aborted
+        self.async_validation
+            .with_mut(|state| match state.pending.get_mut(name) {
+                Some(pending) if pending.generation == generation => pending.task = Some(task),
+                _ => task.abort(),
+            });
+
+        false
+    }
+
+    /// Cancel any in-flight async validation for a field
+    fn cancel_async_validation(&self, name: &str) {
+        if self.async_validation.with_mut(|state| state.cancel(name)) {
+            self.set_validating_flag(name, false);
+        }
+    }
+
+    /// Cancel all in-flight async validations and forget cached results
+    fn cancel_all_async_validations(&self) {
+        self.async_validation.with_mut(|state| {
+            for (_, pending) in state.pending.drain() {
+                pending.abort();
+            }
+            state.results.clear();
+            state.submit_pending = false;
+        });
+        self.set_validating.set(HashSet::new());
+    }
+
+    /// Add or remove a field from the set of fields being validated
+    fn set_validating_flag(&self, name: &str, is_validating: bool) {
+        if self.is_validating(name) == is_validating {
+            return;
+        }
+        self.set_validating.update(|validating| {
+            let mut validating = validating.clone();
+            if is_validating {
+                validating.insert(name.to_string());
+            } else {
+                validating.remove(name);
+            }
+            validating
+        });
+    }
+
     /// Validate all fields in the form
+    ///
+    /// Returns `false` while async validations are in flight; [`is_valid`]
+    /// reflects their results once they complete.
+    ///
+    /// [`is_valid`]: FormHandle::is_valid
     pub fn validate_all(&self) -> bool {
         let values = self.values.get();
         let mut all_valid = true;
@@ -302,20 +704,66 @@ impl FormHandle {
             }
         }
 
-        self.set_is_valid.set(all_valid);
         all_valid
     }
 
-    /// Reset the form to initial values
-    pub fn reset(&self, initial_values: HashMap<String, String>) {
-        self.set_values.set(initial_values);
+    /// Reset the form to the default values it was configured with
+    ///
+    /// Clears touched flags and errors, restores field arrays to their
+    /// configured items, and re-validates the restored values.
+    pub fn reset(&self) {
+        self.apply_defaults(self.configured_defaults());
+    }
+
+    /// Reset the form to the given values, making them the new defaults
+    ///
+    /// Fields missing from `values` fall back to their configured defaults.
+    /// Useful for loading an existing record into an edit form: the loaded
+    /// values are not considered dirty.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user = load_user(id);
+    /// form.reset_to(HashMap::from([
+    ///     ("name".to_string(), user.name),
+    ///     ("email".to_string(), user.email),
+    /// ]));
+    /// ```
+    pub fn reset_to(&self, values: HashMap<String, String>) {
+        let mut defaults = self.configured_defaults();
+        defaults.values.extend(values);
+        self.apply_defaults(defaults);
+    }
+
+    /// The configured defaults, with field array ids continuing after the
+    /// ones already handed out
+    fn configured_defaults(&self) -> FormDefaults {
+        FormDefaults::with_first_id(
+            &self.initial_values,
+            &self.field_arrays,
+            self.arrays.get().next_id,
+        )
+    }
+
+    /// Replace the form state with the given defaults and re-validate
+    fn apply_defaults(&self, defaults: FormDefaults) {
+        self.cancel_all_async_validations();
+        self.set_values.set(defaults.values.clone());
+        self.set_arrays.set(defaults.arrays.clone());
+        self.set_defaults.set(defaults);
         self.set_errors.set(HashMap::new());
+        self.set_validating.set(HashSet::new());
         self.set_touched.set(HashMap::new());
         self.set_is_submitting.set(false);
-        self.set_is_valid.set(true);
+        self.validate_all();
     }
 
     /// Submit the form
+    ///
+    /// If async validations are still in flight, for example when submitting
+    /// within a validator's debounce window, the submit waits for them and
+    /// calls the submit handler once they all pass.
     pub fn submit(&self) {
         // Mark all fields as touched
         let values = self.values.get();
@@ -327,20 +775,48 @@ impl FormHandle {
 
         // Validate all fields
         if self.validate_all() {
-            self.set_is_submitting.set(true);
-            (self.on_submit)(self.values.get());
-            self.set_is_submitting.set(false);
+            self.async_validation
+                .with_mut(|state| state.submit_pending = false);
+            self.run_submit();
+            return;
+        }
+        if self.has_errors() {
+            return;
+        }
+
+        // Wait for the async validations in flight, unless they all settled
+        // since `validate_all` started them
+        let settled = self.async_validation.with_mut(|state| {
+            if state.pending.is_empty() {
+                Some(state.results.values().all(|(_, error)| error.is_none()))
+            } else {
+                state.submit_pending = true;
+                None
+            }
+        });
+        if settled == Some(true) {
+            self.run_submit();
         }
     }
 
+    /// Call the submit handler with the current values
+    fn run_submit(&self) {
+        self.set_is_submitting.set(true);
+        (self.on_submit)(self.values.get());
+        self.set_is_submitting.set(false);
+    }
+
     /// Check if the form is currently submitting
     pub fn is_submitting(&self) -> bool {
         self.is_submitting.get()
     }
 
     /// Check if the form is valid
+    ///
+    /// The form is valid when no field has an error. Fields that are still
+    /// being validated asynchronously count as not yet valid.
     pub fn is_valid(&self) -> bool {
-        self.is_valid.get()
+        !self.has_errors() && !self.is_validating_any()
     }
 
     /// Get all form values
@@ -363,12 +839,165 @@ impl FormHandle {
         !self.errors.get().is_empty()
     }
 
-    /// Check if any field is dirty (modified from initial value)
+    /// Check if any field is dirty (modified from its default value)
+    ///
+    /// Field arrays whose items were added, removed, or reordered also make
+    /// the form dirty. Touching a field without changing it does not.
     pub fn is_dirty(&self) -> bool {
-        !self.touched.get().is_empty()
+        !self.dirty_fields().is_empty()
+    }
+
+    /// Get the names of all dirty fields, sorted by name
+    ///
+    /// Field array items are reported by their item keys, and a field array
+    /// whose items were added, removed, or reordered is reported by its name.
+    pub fn dirty_fields(&self) -> Vec<String> {
+        let values = self.values.get();
+        let defaults = self.defaults.get();
+        let arrays = self.arrays.get();
+
+        let names: HashSet<&String> = values.keys().chain(defaults.values.keys()).collect();
+        let mut dirty: Vec<String> = names
+            .into_iter()
+            .filter(|name| {
+                values.get(*name).map(String::as_str).unwrap_or_default()
+                    != defaults
+                        .values
+                        .get(*name)
+                        .map(String::as_str)
+                        .unwrap_or_default()
+            })
+            .cloned()
+            .collect();
+
+        let array_names: HashSet<&String> = arrays
+            .items
+            .keys()
+            .chain(defaults.arrays.items.keys())
+            .collect();
+        dirty.extend(
+            array_names
+                .into_iter()
+                .filter(|name| arrays.items.get(*name) != defaults.arrays.items.get(*name))
+                .cloned(),
+        );
+
+        dirty.sort();
+        dirty
+    }
+
+    /// Watch the current value of a field
+    ///
+    /// Components re-render every frame, so reading a field through `watch`
+    /// during render always reflects the latest value without owning any state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[component]
+    /// fn Preview() -> Element {
+    ///     let form = use_form_context();
+    ///     let name = form.watch("name").unwrap_or_default();
+    ///
+    ///     rsx! { <Paragraph>{format!("Hello, {name}!")}</Paragraph> }
+    /// }
+    /// ```
+    pub fn watch(&self, name: &str) -> Option<String> {
+        self.get_value(name)
+    }
+
+    /// Watch the current values of all fields
+    ///
+    /// See [`FormHandle::watch`].
+    pub fn watch_all(&self) -> HashMap<String, String> {
+        self.get_values()
     }
 }
 
+/// Build the values-map key for a field array item
+fn array_item_key(name: &str, id: u64) -> String {
+    format!("{name}.{id}")
+}
+
+/// Baseline values a form is reset to and compared against for dirtiness
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormDefaults {
+    /// Default value of every field, including field array items
+    pub(crate) values: HashMap<String, String>,
+    /// Default field array items
+    pub(crate) arrays: FieldArrayState,
+}
+
+impl FormDefaults {
+    /// Build defaults from configured field values and field array items
+    ///
+    /// Field array items are assigned stable ids and stored in the values map
+    /// under their item keys (see [`FieldArrayItem::key`]).
+    pub(crate) fn new(
+        initial_values: &HashMap<String, String>,
+        field_arrays: &HashMap<String, Vec<String>>,
+    ) -> Self {
+        Self::with_first_id(initial_values, field_arrays, 0)
+    }
+
+    /// Build defaults whose field array ids start at `first_id`
+    fn with_first_id(
+        initial_values: &HashMap<String, String>,
+        field_arrays: &HashMap<String, Vec<String>>,
+        first_id: u64,
+    ) -> Self {
+        let mut values = initial_values.clone();
+        let mut arrays = FieldArrayState {
+            items: HashMap::new(),
+            next_id: first_id,
+        };
+
+        for (name, items) in field_arrays {
+            let ids = items
+                .iter()
+                .map(|value| {
+                    let id = arrays.allocate_id();
+                    values.insert(array_item_key(name, id), value.clone());
+                    id
+                })
+                .collect();
+            arrays.items.insert(name.clone(), ids);
+        }
+
+        Self { values, arrays }
+    }
+}
+
+/// Ordered item ids for every field array in a form
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct FieldArrayState {
+    /// Item ids per array, in display order
+    pub(crate) items: HashMap<String, Vec<u64>>,
+    /// Next id to hand out; ids are never reused within a form
+    next_id: u64,
+}
+
+impl FieldArrayState {
+    fn allocate_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
+
+/// A single item of a field array
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldArrayItem {
+    /// Stable identity of the item, unchanged by reordering
+    pub id: u64,
+    /// Current position of the item in the array
+    pub index: usize,
+    /// Field key for this item, e.g. `"emails.3"`
+    pub key: String,
+    /// Current value of the item
+    pub value: String,
+}
+
 /// Field registration information
 #[derive(Debug, Clone)]
 pub struct FieldRegistration {
@@ -1,13 +1,36 @@
 //! Validation utilities for form fields
 
 use regex::Regex;
-use std::sync::{Arc, OnceLock};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+/// Default delay before an async validator runs after the last change
+pub const DEFAULT_ASYNC_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Boxed future returned by async validators
+type ValidationFuture = Pin<Box<dyn Future<Output = Option<String>> + Send + 'static>>;
+
+/// The validation function backing a [`Validator`]
+#[derive(Clone)]
+enum ValidateFn {
+    /// Runs synchronously during `validate_field`
+    #[allow(clippy::type_complexity)]
+    Sync(Arc<dyn Fn(&str) -> Option<String> + Send + Sync>),
+    /// Runs on the tokio runtime after a debounce delay
+    #[allow(clippy::type_complexity)]
+    Async(Arc<dyn Fn(String) -> ValidationFuture + Send + Sync>),
+}
 
 /// Validator for form fields
 #[derive(Clone)]
 pub struct Validator {
-    #[allow(clippy::type_complexity)]
-    validate_fn: Arc<dyn Fn(&str) -> Option<String> + Send + Sync>,
+    validate_fn: ValidateFn,
+    debounce: Duration,
 }
 
 impl Validator {
@@ -17,13 +40,74 @@ impl Validator {
         F: Fn(&str) -> Option<String> + Send + Sync + 'static,
     {
         Self {
-            validate_fn: Arc::new(validate_fn),
+            validate_fn: ValidateFn::Sync(Arc::new(validate_fn)),
+            debounce: Duration::ZERO,
         }
     }
 
+    /// Create an async validator, e.g. for checking availability against a server
+    ///
+    /// Async validators run after all synchronous validators for the field pass,
+    /// and only once the value has been stable for the debounce delay
+    /// ([`DEFAULT_ASYNC_DEBOUNCE`] unless changed with [`Validator::debounce`]).
+    /// A newer change to the field cancels any in-flight validation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use reratui::prelude::*;
+    /// Validator::async_fn(|value| async move {
+    ///     if is_username_taken(&value).await {
+    ///         Some("Username is already taken".to_string())
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// ```
+    pub fn async_fn<F, Fut>(validate_fn: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<String>> + Send + 'static,
+    {
+        Self {
+            validate_fn: ValidateFn::Async(Arc::new(move |value| Box::pin(validate_fn(value)))),
+            debounce: DEFAULT_ASYNC_DEBOUNCE,
+        }
+    }
+
+    /// Set the debounce delay used before running an async validator
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Get the debounce delay for this validator
+    pub fn debounce_duration(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Check whether this validator runs asynchronously
+    pub fn is_async(&self) -> bool {
+        matches!(self.validate_fn, ValidateFn::Async(_))
+    }
+
     /// Validate a value
+    ///
+    /// Async validators always return `None` here; use [`Validator::validate_async`]
+    /// to run them.
     pub fn validate(&self, value: &str) -> Option<String> {
-        (self.validate_fn)(value)
+        match &self.validate_fn {
+            ValidateFn::Sync(validate_fn) => validate_fn(value),
+            ValidateFn::Async(_) => None,
+        }
+    }
+
+    /// Validate a value, awaiting the result for async validators
+    pub async fn validate_async(&self, value: String) -> Option<String> {
+        match &self.validate_fn {
+            ValidateFn::Sync(validate_fn) => validate_fn(&value),
+            ValidateFn::Async(validate_fn) => validate_fn(value).await,
+        }
     }
 
     /// Required field validator
@@ -205,6 +289,53 @@ impl Validator {
     }
 }
 
+/// An async validation that has been scheduled but not yet completed
+pub(crate) struct PendingValidation {
+    /// The value being validated
+    pub(crate) value: String,
+    /// Generation used to discard results from superseded validations
+    pub(crate) generation: u64,
+    /// The spawned validation task, attached once spawned
+    pub(crate) task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl PendingValidation {
+    /// Abort the validation task, if it has been spawned
+    pub(crate) fn abort(&self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+/// Bookkeeping for in-flight and completed async validations, keyed by field name
+#[derive(Default)]
+pub(crate) struct AsyncValidationState {
+    pub(crate) next_generation: u64,
+    pub(crate) pending: HashMap<String, PendingValidation>,
+    /// The last value validated for each field and its result
+    pub(crate) results: HashMap<String, (String, Option<String>)>,
+    /// Whether a submit waits for the in-flight validations to settle
+    pub(crate) submit_pending: bool,
+}
+
+impl AsyncValidationState {
+    /// Cancel the in-flight validation for a field, if any
+    ///
+    /// A submit waiting for validations is dropped once none are left.
+    pub(crate) fn cancel(&mut self, name: &str) -> bool {
+        if let Some(pending) = self.pending.remove(name) {
+            pending.abort();
+            if self.pending.is_empty() {
+                self.submit_pending = false;
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +388,28 @@ mod tests {
         assert!(validator.validate("0").is_none());
         assert!(validator.validate("100").is_none());
     }
+
+    #[tokio::test]
+    async fn test_async_validator() {
+        let validator = Validator::async_fn(|value| async move {
+            if value == "taken" {
+                Some("Already taken".to_string())
+            } else {
+                None
+            }
+        })
+        .debounce(Duration::from_millis(50));
+
+        assert!(validator.is_async());
+        assert_eq!(validator.debounce_duration(), Duration::from_millis(50));
+
+        // Sync validation skips async validators
+        assert!(validator.validate("taken").is_none());
+
+        assert_eq!(
+            validator.validate_async("taken".to_string()).await,
+            Some("Already taken".to_string())
+        );
+        assert!(validator.validate_async("free".to_string()).await.is_none());
+    }
 }
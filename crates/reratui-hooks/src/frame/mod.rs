@@ -9,6 +9,8 @@
 //! - Delta time (time since last frame)
 //! - FPS (frames per second)
 //! - Frame timestamp
+//! - Statistics over recent frame times (average and minimum FPS, slowest
+//!   frame, frames over budget)
 //!
 //! # Architecture
 //!
@@ -22,10 +24,125 @@
 //! The Frame pointer is only valid during the current render cycle.
 //! Do not store the Frame reference beyond the component render.
 
-use crate::context::use_context;
+use crate::clock;
+use crate::context::{try_use_context, use_context};
 use ratatui::Frame;
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// How many recent frames [`FrameTimings`] keeps
+pub const FRAME_WINDOW: usize = 120;
+
+/// The frame time above which a frame counts as dropped, unless configured
+///
+/// Two ticks of the render loop's 16ms idle timeout, the point where a
+/// 30 FPS animation starts to stutter.
+pub const DEFAULT_FRAME_BUDGET: Duration = Duration::from_millis(33);
+
+/// Rolling window of recent frame times, kept by the renderer
+///
+/// The renderer records the time between frames after every frame and hands
+/// a [`FrameStats`] summary to components through [`FrameContext`].
+#[derive(Debug, Clone)]
+pub struct FrameTimings {
+    /// The last [`FRAME_WINDOW`] frame times, oldest first
+    times: VecDeque<Duration>,
+    /// Frame time above which a frame counts as dropped
+    budget: Duration,
+    /// Frames over budget since the first one recorded
+    dropped: u64,
+}
+
+impl FrameTimings {
+    /// Create an empty window with the given frame budget
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            times: VecDeque::with_capacity(FRAME_WINDOW),
+            budget,
+            dropped: 0,
+        }
+    }
+
+    /// Record the time a frame took, evicting the oldest beyond the window
+    pub fn record(&mut self, frame_time: Duration) {
+        if self.times.len() == FRAME_WINDOW {
+            self.times.pop_front();
+        }
+        self.times.push_back(frame_time);
+        if frame_time > self.budget {
+            self.dropped += 1;
+        }
+    }
+
+    /// Summarize the frames in the window
+    pub fn stats(&self) -> FrameStats {
+        let total: Duration = self.times.iter().sum();
+        FrameStats {
+            frames: self.times.len(),
+            total_time: total,
+            min_frame_time: self.times.iter().min().copied().unwrap_or_default(),
+            max_frame_time: self.times.iter().max().copied().unwrap_or_default(),
+            dropped_frames: self.dropped,
+            budget: self.budget,
+        }
+    }
+}
+
+impl Default for FrameTimings {
+    fn default() -> Self {
+        Self::new(DEFAULT_FRAME_BUDGET)
+    }
+}
+
+/// Summary of the recent frame times recorded by the renderer
+///
+/// All zero until the renderer has recorded a frame, as in tests.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Number of frames in the window, at most [`FRAME_WINDOW`]
+    pub frames: usize,
+    /// Sum of the frame times in the window
+    pub total_time: Duration,
+    /// Fastest frame in the window
+    pub min_frame_time: Duration,
+    /// Slowest frame in the window
+    pub max_frame_time: Duration,
+    /// Frames over budget since the app started, including those that have
+    /// left the window
+    pub dropped_frames: u64,
+    /// Frame time above which a frame counts as dropped
+    pub budget: Duration,
+}
+
+impl FrameStats {
+    /// Average FPS over the window
+    pub fn avg_fps(&self) -> f64 {
+        if self.total_time.is_zero() {
+            0.0
+        } else {
+            self.frames as f64 / self.total_time.as_secs_f64()
+        }
+    }
+
+    /// FPS of the slowest frame in the window
+    pub fn min_fps(&self) -> f64 {
+        if self.max_frame_time.is_zero() {
+            0.0
+        } else {
+            1.0 / self.max_frame_time.as_secs_f64()
+        }
+    }
+
+    /// Average time between frames over the window
+    pub fn avg_frame_time(&self) -> Duration {
+        if self.frames == 0 {
+            Duration::ZERO
+        } else {
+            self.total_time / self.frames as u32
+        }
+    }
+}
+
 /// Frame context that holds both the Frame pointer and frame information
 ///
 /// This is provided by the renderer via context and consumed by components.
@@ -43,6 +160,8 @@ pub struct FrameContext {
     pub delta: Duration,
     /// Timestamp when this frame started rendering
     pub timestamp: Instant,
+    /// Statistics over the frames before this one
+    pub stats: FrameStats,
 }
 
 // Safety: FrameContext is only used within a single thread during rendering
@@ -67,6 +186,7 @@ impl FrameContext {
             count,
             delta,
             timestamp,
+            stats: FrameStats::default(),
         }
     }
 
@@ -87,9 +207,16 @@ impl FrameContext {
             count,
             delta,
             timestamp,
+            stats: FrameStats::default(),
         }
     }
 
+    /// Attach the statistics over the previous frames
+    pub fn with_stats(mut self, stats: FrameStats) -> Self {
+        self.stats = stats;
+        self
+    }
+
     /// Get a reference to the Frame
     ///
     /// # Safety
@@ -138,6 +265,31 @@ impl FrameContext {
         self.count == 0
     }
 
+    /// Average FPS over the recent frames
+    pub fn avg_fps(&self) -> f64 {
+        self.stats.avg_fps()
+    }
+
+    /// FPS of the slowest recent frame
+    pub fn min_fps(&self) -> f64 {
+        self.stats.min_fps()
+    }
+
+    /// Time taken by the slowest recent frame
+    pub fn max_frame_time(&self) -> Duration {
+        self.stats.max_frame_time
+    }
+
+    /// Number of frames that went over the frame budget since the app started
+    pub fn dropped_frames(&self) -> u64 {
+        self.stats.dropped_frames
+    }
+
+    /// Frame time above which a frame counts as dropped
+    pub fn frame_budget(&self) -> Duration {
+        self.stats.budget
+    }
+
     /// Get FrameInfo (without the Frame pointer)
     pub fn frame_info(&self) -> FrameInfo {
         FrameInfo {
@@ -321,6 +473,7 @@ impl FrameInfo {
 /// Returns a `FrameContext` which provides access to:
 /// - The current ratatui Frame (via `.frame()` or `.frame_mut()`)
 /// - Frame count, delta time, timestamp
+/// - Statistics over recent frames, for performance overlays
 ///
 /// # Examples
 ///
@@ -340,6 +493,14 @@ pub fn use_frame() -> FrameContext {
     use_context::<FrameContext>()
 }
 
+/// The time the current frame started rendering
+///
+/// Falls back to the current time outside the renderer, so time-based
+/// animations also work in tests and portals rendered without a frame.
+pub(crate) fn frame_time() -> Instant {
+    try_use_context::<FrameContext>().map_or_else(clock::now, |frame| frame.timestamp)
+}
+
 /// Extension trait for ratatui's Frame to access frame information
 ///
 /// This trait extends ratatui's `Frame` with methods to access frame count,
@@ -473,6 +634,7 @@ mod tests {
             count: 100,
             delta: Duration::from_millis(16),
             timestamp: now,
+            stats: FrameStats::default(),
         };
 
         assert_eq!(frame_ctx.count, 100);
@@ -496,6 +658,7 @@ mod tests {
             count: 0,
             delta: Duration::from_millis(16),
             timestamp: Instant::now(),
+            stats: FrameStats::default(),
         };
 
         assert!(frame_ctx.is_first_frame());
@@ -511,12 +674,63 @@ mod tests {
             count: 1,
             delta: Duration::from_micros(16667),
             timestamp: Instant::now(),
+            stats: FrameStats::default(),
         };
 
         let fps = frame_60fps.fps();
         assert!((fps - 60.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_frame_timings_window_stats() {
+        let mut timings = FrameTimings::new(Duration::from_millis(20));
+        assert_eq!(timings.stats().avg_fps(), 0.0);
+        assert_eq!(timings.stats().min_fps(), 0.0);
+
+        for millis in [10, 10, 40, 20] {
+            timings.record(Duration::from_millis(millis));
+        }
+        let stats = timings.stats();
+        assert_eq!(stats.frames, 4);
+        assert_eq!(stats.avg_frame_time(), Duration::from_millis(20));
+        assert!((stats.avg_fps() - 50.0).abs() < 0.01);
+        assert!((stats.min_fps() - 25.0).abs() < 0.01);
+        assert_eq!(stats.min_frame_time, Duration::from_millis(10));
+        assert_eq!(stats.max_frame_time, Duration::from_millis(40));
+        // Exactly on budget is not dropped
+        assert_eq!(stats.dropped_frames, 1);
+    }
+
+    #[test]
+    fn test_frame_timings_evicts_old_frames() {
+        let mut timings = FrameTimings::default();
+        timings.record(Duration::from_millis(100));
+        for _ in 0..FRAME_WINDOW {
+            timings.record(Duration::from_millis(10));
+        }
+
+        let stats = timings.stats();
+        assert_eq!(stats.frames, FRAME_WINDOW);
+        assert_eq!(stats.max_frame_time, Duration::from_millis(10));
+        // The slow frame left the window but is still counted as dropped
+        assert_eq!(stats.dropped_frames, 1);
+
+        let frame_ctx = frame_context_with(stats);
+        assert_eq!(frame_ctx.dropped_frames(), 1);
+        assert_eq!(frame_ctx.frame_budget(), DEFAULT_FRAME_BUDGET);
+        assert!((frame_ctx.avg_fps() - 100.0).abs() < 0.01);
+        assert!((frame_ctx.min_fps() - 100.0).abs() < 0.01);
+        assert_eq!(frame_ctx.max_frame_time(), Duration::from_millis(10));
+    }
+
+    fn frame_context_with(stats: FrameStats) -> FrameContext {
+        // SAFETY: The frame pointer is null and never dereferenced
+        unsafe {
+            FrameContext::from_raw_ptr(std::ptr::null_mut(), 1, Duration::ZERO, Instant::now())
+        }
+        .with_stats(stats)
+    }
+
     #[test]
     #[should_panic(expected = "Context value for type")]
     fn test_use_frame_without_context_panics() {
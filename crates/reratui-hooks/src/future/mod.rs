@@ -1,8 +1,11 @@
+use crate::clock;
 use parking_lot::{Mutex, RwLock};
 use std::fmt;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
 use tokio::task::JoinHandle;
+pub use tokio_util::sync::CancellationToken;
 
 #[cfg(test)]
 mod tests;
@@ -33,6 +36,44 @@ static GLOBAL_ACTIVE_FUTURES: AtomicUsize = AtomicUsize::new(0);
 /// Maximum total concurrent futures across the entire application
 const MAX_GLOBAL_CONCURRENT_FUTURES: usize = 1000;
 
+/// Retry configuration for [`use_future_with_options`]
+///
+/// With the default options a failed fetch is not retried, which is how
+/// [`use_future`] behaves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FutureOptions {
+    /// How many times to retry the fetcher after it returns `Err`
+    pub retry_attempts: u32,
+    /// Delay before the first retry
+    pub retry_delay: Duration,
+    /// Whether to double the delay after each retry: delay * 2^(retry - 1)
+    pub exponential_backoff: bool,
+}
+
+impl Default for FutureOptions {
+    fn default() -> Self {
+        Self {
+            retry_attempts: 0,
+            retry_delay: Duration::from_millis(1000),
+            exponential_backoff: false,
+        }
+    }
+}
+
+impl FutureOptions {
+    /// The delay before the given retry (1-based)
+    fn delay_before_retry(&self, retry: u32) -> Duration {
+        if !self.exponential_backoff {
+            return self.retry_delay;
+        }
+
+        // Cap the exponent at 2^10 to prevent overflow
+        self.retry_delay
+            .checked_mul(2u32.pow(retry.saturating_sub(1).min(10)))
+            .unwrap_or(Duration::MAX)
+    }
+}
+
 /// Represents the current state of a future operation
 ///
 /// This enum provides a comprehensive view of the future's lifecycle,
@@ -141,6 +182,14 @@ pub struct FutureHandle<T, E = Box<dyn std::error::Error + Send + Sync>> {
     state: Arc<RwLock<FutureState<T, E>>>,
     /// Handle to the running task (for cancellation)
     task_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// Token passed to the running fetcher, cancelled when it is superseded
+    cancel_token: Arc<Mutex<Option<CancellationToken>>>,
+    /// Invocation counter; only the latest invocation may update the state
+    generation: Arc<AtomicU64>,
+    /// Attempt the latest invocation is on, starting at 1
+    attempt: Arc<AtomicU32>,
+    /// Whether the future has resolved at least once
+    has_resolved: Arc<AtomicBool>,
 }
 
 impl<T, E> FutureHandle<T, E>
@@ -153,6 +202,10 @@ where
         Self {
             state: Arc::new(RwLock::new(FutureState::Idle)),
             task_handle: Arc::new(Mutex::new(None)),
+            cancel_token: Arc::new(Mutex::new(None)),
+            generation: Arc::new(AtomicU64::new(0)),
+            attempt: Arc::new(AtomicU32::new(0)),
+            has_resolved: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -217,51 +270,159 @@ where
         }
     }
 
+    /// The attempt the current run is on
+    ///
+    /// This is `1` for the first try and increases with each retry made by
+    /// [`use_future_with_options`], while the state stays `Pending`. It is `0`
+    /// before the future first runs.
+    pub fn attempt(&self) -> u32 {
+        self.attempt.load(Ordering::Acquire)
+    }
+
     /// Cancel the running future task if it exists
     ///
     /// This method can be used to manually cancel a long-running future
-    /// to free up resources and prevent unnecessary work.
+    /// to free up resources and prevent unnecessary work. The fetcher's
+    /// [`CancellationToken`] is cancelled and any result it still produces
+    /// is discarded.
     pub fn cancel(&self) {
+        self.supersede();
         if let Some(task_handle) = self.task_handle.lock().take() {
             task_handle.abort();
         }
     }
 
+    /// Suspends the innermost `Suspense` boundary being rendered while the
+    /// first run is pending
+    ///
+    /// Later runs, such as refreshes, don't suspend, so content that has
+    /// loaded once stays visible. `use_future` does this for the component
+    /// that calls it. A component handed the handle as a prop calls it to make
+    /// its own boundary wait.
+    pub fn suspend(&self) {
+        if self.is_pending() && !self.has_resolved.load(Ordering::Acquire) {
+            reratui_core::suspense::suspend();
+        }
+    }
+
     /// Internal method to update the state
     /// Uses write lock for state mutations
     fn set_state(&self, new_state: FutureState<T, E>) {
+        if new_state.is_resolved() {
+            self.has_resolved.store(true, Ordering::Release);
+        }
         *self.state.write() = new_state;
     }
 
+    /// Cancels the previous invocation and starts a new one
+    ///
+    /// The previous task is not aborted: its fetcher stops once the token is
+    /// cancelled and the task exits on its own, releasing its future slot.
+    /// Returns the slot the new invocation reports to and its token.
+    fn begin_invocation(&self) -> (FutureSlot<T, E>, CancellationToken) {
+        self.supersede();
+        self.task_handle.lock().take();
+
+        let token = CancellationToken::new();
+        *self.cancel_token.lock() = Some(token.clone());
+        self.attempt.store(1, Ordering::Release);
+
+        let slot = FutureSlot {
+            state: self.state.clone(),
+            generation: self.generation.clone(),
+            attempt: self.attempt.clone(),
+            has_resolved: self.has_resolved.clone(),
+            current: self.generation.load(Ordering::Acquire),
+        };
+        (slot, token)
+    }
+
     /// Internal method to set the task handle
     fn set_task_handle(&self, handle: JoinHandle<()>) {
         *self.task_handle.lock() = Some(handle);
     }
 }
 
+impl<T, E> FutureHandle<T, E> {
+    /// Invalidates the current invocation and cancels its fetcher's token
+    fn supersede(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        if let Some(token) = self.cancel_token.lock().take() {
+            token.cancel();
+        }
+    }
+}
+
 impl<T, E> Clone for FutureHandle<T, E> {
     fn clone(&self) -> Self {
         Self {
             state: self.state.clone(),
             task_handle: self.task_handle.clone(),
+            cancel_token: self.cancel_token.clone(),
+            generation: self.generation.clone(),
+            attempt: self.attempt.clone(),
+            has_resolved: self.has_resolved.clone(),
         }
     }
 }
 
 /// Enhanced Resource Management: Automatic cleanup on drop
 ///
-/// This ensures that futures are properly cancelled when the handle
+/// This ensures that futures are properly cancelled when the last handle
 /// is dropped, preventing resource leaks and zombie tasks.
 impl<T, E> Drop for FutureHandle<T, E> {
     fn drop(&mut self) {
+        // Clones returned from renders come and go; only the last one cancels
+        if Arc::strong_count(&self.task_handle) > 1 {
+            return;
+        }
+
         // Ensure cleanup on drop - cancel any running task
         // We directly access the task_handle to avoid trait bound issues
+        self.supersede();
         if let Some(task_handle) = self.task_handle.lock().take() {
             task_handle.abort();
         }
     }
 }
 
+/// Where a single invocation reports its result
+///
+/// Holds only the shared state, so running tasks never keep a
+/// [`FutureHandle`] alive.
+struct FutureSlot<T, E> {
+    state: Arc<RwLock<FutureState<T, E>>>,
+    generation: Arc<AtomicU64>,
+    attempt: Arc<AtomicU32>,
+    has_resolved: Arc<AtomicBool>,
+    /// Generation of the invocation this slot belongs to
+    current: u64,
+}
+
+impl<T, E> FutureSlot<T, E> {
+    /// Updates the state only if this is still the latest invocation
+    ///
+    /// The check happens under the state lock so a superseded fetch can never
+    /// overwrite the result of a newer one.
+    fn set_state(&self, new_state: FutureState<T, E>) {
+        let mut state = self.state.write();
+        if self.generation.load(Ordering::Acquire) == self.current {
+            if new_state.is_resolved() {
+                self.has_resolved.store(true, Ordering::Release);
+            }
+            *state = new_state;
+        }
+    }
+
+    /// Records the attempt this invocation is on, if it is still the latest
+    fn set_attempt(&self, attempt: u32) {
+        let _state = self.state.write();
+        if self.generation.load(Ordering::Acquire) == self.current {
+            self.attempt.store(attempt, Ordering::Release);
+        }
+    }
+}
+
 /// Internal state for tracking future operations
 struct FutureHookState<T, E> {
     /// Previous dependencies for comparison
@@ -295,6 +456,11 @@ impl<T, E> Drop for FutureHookState<T, E> {
         // Clear dependencies to prevent memory leaks
         self.prev_deps = None;
 
+        // Stop the fetcher of the unmounted component
+        if let Some(token) = self.handle.cancel_token.lock().take() {
+            token.cancel();
+        }
+
         // Cancel any running task and decrement counters
         if let Some(task_handle) = self.handle.task_handle.lock().take() {
             task_handle.abort();
@@ -330,6 +496,8 @@ impl std::error::Error for FutureError {}
 /// - Supports dependency arrays for conditional re-execution
 /// - Provides comprehensive state management (pending, resolved, error)
 /// - Handles future cancellation on dependency changes or component unmount
+/// - Passes a [`CancellationToken`] to the fetcher that is cancelled when the
+///   run is superseded; only the most recent run can resolve the state
 /// - Thread-safe and optimized for concurrent access
 /// - Supports both Result-returning and direct value futures
 ///
@@ -341,7 +509,7 @@ impl std::error::Error for FutureError {}
 /// use std::time::Duration;
 ///
 /// // Simple example showing basic future usage
-/// let future_handle = use_future::<(), _, _, _, _>(|| async {
+/// let future_handle = use_future::<(), _, _, _, _>(|_token| async {
 ///     // Simulate async work
 ///     tokio::time::sleep(Duration::from_millis(100)).await;
 ///     Ok::<String, String>("Hello, World!".to_string())
@@ -362,7 +530,7 @@ impl std::error::Error for FutureError {}
 ///
 /// // Example with dependency tracking
 /// let user_id = 123;
-/// let user_future = use_future::<i32, _, _, _, _>(move || async move {
+/// let user_future = use_future::<i32, _, _, _, _>(move |_token| async move {
 ///     // Simulate API call
 ///     tokio::time::sleep(Duration::from_millis(200)).await;
 ///     Ok::<String, String>(format!("User data for ID: {}", user_id))
@@ -383,7 +551,7 @@ impl std::error::Error for FutureError {}
 ///
 /// // Example with manual triggering
 /// let trigger = 1;
-/// let data_future = use_future::<i32, _, _, _, _>(move || async move {
+/// let data_future = use_future::<i32, _, _, _, _>(move |_token| async move {
 ///     // Simulate work
 ///     tokio::time::sleep(Duration::from_millis(100)).await;
 ///     Ok::<String, String>(format!("Data fetched at trigger: {}", trigger))
@@ -397,6 +565,23 @@ impl std::error::Error for FutureError {}
 /// }
 /// ```
 ///
+/// ## Cancellation
+/// ```rust,no_run
+/// use reratui_hooks::future::use_future;
+/// use std::time::Duration;
+///
+/// // The token is cancelled when `page` changes before this fetch finishes.
+/// // The hook stops polling the fetch either way; the token lets work handed
+/// // off elsewhere stop early too.
+/// let page = 2;
+/// let page_future = use_future::<i32, _, _, _, _>(move |token| async move {
+///     tokio::select! {
+///         _ = token.cancelled() => Err("cancelled".to_string()),
+///         _ = tokio::time::sleep(Duration::from_millis(300)) => Ok(format!("Page {}", page)),
+///     }
+/// }, Some(page));
+/// ```
+///
 /// # Error Handling
 ///
 /// This function will panic if called outside of a component render context.
@@ -404,7 +589,8 @@ impl std::error::Error for FutureError {}
 ///
 /// # Performance Notes
 ///
-/// - Futures are automatically cancelled when dependencies change
+/// - Futures are automatically cancelled when dependencies change, and a
+///   stale run that finishes late never overwrites a newer result
 /// - State updates are thread-safe and optimized for concurrent access
 /// - Dependency comparison uses PartialEq for efficient change detection
 /// - Multiple futures in the same component are managed independently
@@ -415,12 +601,84 @@ pub fn use_future<Deps, F, Fut, T, E>(
 ) -> FutureHandle<T, E>
 where
     Deps: EffectDependencies + Clone + PartialEq + 'static,
-    F: FnOnce() -> Fut + Send + 'static,
+    F: FnOnce(CancellationToken) -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = Result<T, E>> + Send + 'static,
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + From<String> + 'static,
+{
+    run_future_hook(move |token, _slot| future_factory(token), deps.into())
+}
+
+/// Like [`use_future`], but retries the fetcher on `Err` as configured by `options`
+///
+/// Each retry calls `future_factory` again, so it must be `Fn`. While retries
+/// remain the state stays `Pending` and [`FutureHandle::attempt`] reports the
+/// attempt in progress; once they are exhausted the last error is stored as
+/// `FutureState::Error`.
+///
+/// # Example
+/// ```rust,no_run
+/// use reratui_hooks::future::{use_future_with_options, FutureOptions};
+/// use std::time::Duration;
+///
+/// let options = FutureOptions {
+///     retry_attempts: 3,
+///     retry_delay: Duration::from_millis(500),
+///     exponential_backoff: true,
+/// };
+///
+/// let report = use_future_with_options::<(), _, _, _, _>(
+///     |_token| async { Err::<String, String>("connection reset".to_string()) },
+///     (),
+///     options,
+/// );
+///
+/// if report.is_pending() && report.attempt() > 1 {
+///     println!("Retrying (attempt {})...", report.attempt());
+/// }
+/// ```
+pub fn use_future_with_options<Deps, F, Fut, T, E>(
+    future_factory: F,
+    deps: impl Into<Option<Deps>>,
+    options: FutureOptions,
+) -> FutureHandle<T, E>
+where
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+    F: Fn(CancellationToken) -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = Result<T, E>> + Send + 'static,
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + From<String> + 'static,
+{
+    run_future_hook(
+        move |token, slot| async move {
+            let mut attempt = 1;
+            loop {
+                match future_factory(token.clone()).await {
+                    Err(_) if attempt <= options.retry_attempts => {
+                        clock::sleep(options.delay_before_retry(attempt)).await;
+                        attempt += 1;
+                        slot.set_attempt(attempt);
+                    }
+                    result => return result,
+                }
+            }
+        },
+        deps.into(),
+    )
+}
+
+/// Shared implementation of the future hooks
+///
+/// `run` produces the invocation's result; it gets the fetcher's token and the
+/// slot the invocation reports to.
+fn run_future_hook<Deps, R, Fut, T, E>(run: R, deps: Option<Deps>) -> FutureHandle<T, E>
+where
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+    R: FnOnce(CancellationToken, Arc<FutureSlot<T, E>>) -> Fut + Send + 'static,
     Fut: std::future::Future<Output = Result<T, E>> + Send + 'static,
     T: Clone + Send + Sync + 'static,
     E: Clone + Send + Sync + From<String> + 'static,
 {
-    let deps = deps.into();
     with_hook_context(|ctx| {
         let hook_index = ctx.next_hook_index();
         let mut states = ctx.states.borrow_mut();
@@ -486,8 +744,8 @@ where
                 return future_state.handle.clone();
             }
 
-            // Cancel any existing future
-            future_state.handle.cancel();
+            // Cancel any existing future and start a new invocation
+            let (slot, cancel_token) = future_state.handle.begin_invocation();
 
             // Reset state to pending
             future_state.handle.set_state(FutureState::Pending);
@@ -504,21 +762,19 @@ where
             GLOBAL_ACTIVE_FUTURES.fetch_add(1, Ordering::Relaxed);
 
             // Create clones for the async task
-            let handle_clone_for_success = future_state.handle.clone();
-            let handle_clone_for_error = future_state.handle.clone();
-            let handle_clone_for_panic = future_state.handle.clone();
+            let slot = Arc::new(slot);
+            let slot_for_panic = slot.clone();
             let active_futures_clone = future_state.active_futures.clone();
 
             // Spawn the future
             let task_handle = tokio::spawn(async move {
                 let result = spawn_catch_panic(async move {
-                    match future_factory().await {
-                        Ok(value) => {
-                            handle_clone_for_success.set_state(FutureState::Resolved(value));
-                        }
-                        Err(error) => {
-                            handle_clone_for_error.set_state(FutureState::Error(error));
-                        }
+                    let fetch = run(cancel_token.clone(), slot.clone());
+                    match cancel_token.run_until_cancelled(fetch).await {
+                        Some(Ok(value)) => slot.set_state(FutureState::Resolved(value)),
+                        Some(Err(error)) => slot.set_state(FutureState::Error(error)),
+                        // Superseded by a newer invocation
+                        None => {}
                     }
                 })
                 .await;
@@ -533,7 +789,7 @@ where
                         format!("Future failed: {}", join_error)
                     };
 
-                    handle_clone_for_panic.set_state(FutureState::Error(panic_message.into()));
+                    slot_for_panic.set_state(FutureState::Error(panic_message.into()));
                 }
 
                 // Security: Always decrement counters when future completes (success, error, or panic)
@@ -546,7 +802,9 @@ where
             future_state.initialized = true;
         }
 
-        // Return a clone of the handle
+        // Return a clone of the handle, suspending the enclosing boundary
+        // while the future runs
+        future_state.handle.suspend();
         future_state.handle.clone()
     })
 }
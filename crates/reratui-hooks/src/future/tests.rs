@@ -21,7 +21,7 @@ async fn test_basic_future_success() {
     with_async_test_isolate(|| async {
         with_async_component_id("BasicFutureComponent", |_context| async {
             let handle = use_future(
-                || async { Ok::<i32, String>(42) },
+                |_| async { Ok::<i32, String>(42) },
                 (), // No dependencies - run once
             );
 
@@ -50,7 +50,7 @@ async fn test_basic_future_error() {
     with_async_test_isolate(|| async {
         with_async_component_id("ErrorFutureComponent", |_context| async {
             let handle = use_future(
-                || async { Err::<i32, String>("Test error".to_string()) },
+                |_| async { Err::<i32, String>("Test error".to_string()) },
                 (),
             );
 
@@ -82,7 +82,7 @@ async fn test_future_with_dependencies() {
         with_async_component_id("DependencyFutureComponent", |_context| async {
             let counter_clone = counter.clone();
             let handle1 = use_future(
-                move || {
+                move |_| {
                     let counter = counter_clone.clone();
                     async move {
                         counter.fetch_add(1, Ordering::SeqCst);
@@ -103,7 +103,7 @@ async fn test_future_with_dependencies() {
         with_async_component_id("DependencyFutureComponent", |_context| async {
             let counter_clone2 = counter.clone();
             let _handle2 = use_future(
-                move || {
+                move |_| {
                     let counter = counter_clone2.clone();
                     async move {
                         counter.fetch_add(1, Ordering::SeqCst);
@@ -134,7 +134,7 @@ async fn test_future_dependency_change() {
             let counter_clone = counter.clone();
             let results_clone = results.clone();
             let handle = use_future(
-                move || {
+                move |_| {
                     let counter = counter_clone.clone();
                     let results = results_clone.clone();
                     async move {
@@ -157,7 +157,7 @@ async fn test_future_dependency_change() {
             let counter_clone = counter.clone();
             let results_clone = results.clone();
             let handle = use_future(
-                move || {
+                move |_| {
                     let counter = counter_clone.clone();
                     let results = results_clone.clone();
                     async move {
@@ -239,7 +239,7 @@ async fn test_future_state_map_err() {
 async fn test_future_handle_clone() {
     with_async_test_isolate(|| async {
         with_async_component_id("CloneFutureComponent", |_context| async {
-            let handle = use_future(|| async { Ok::<i32, String>(42) }, ());
+            let handle = use_future(|_| async { Ok::<i32, String>(42) }, ());
             let handle_clone = handle.clone();
 
             // Wait for completion
@@ -265,7 +265,7 @@ async fn test_optimized_state_access() {
     with_async_test_isolate(|| async {
         with_async_component_id("OptimizedStateComponent", |_context| async {
             let handle = use_future(
-                || async { Ok::<String, String>("test_value".to_string()) },
+                |_| async { Ok::<String, String>("test_value".to_string()) },
                 (),
             );
 
@@ -309,7 +309,7 @@ async fn test_memory_cleanup_on_drop() {
         {
             let _handle = with_async_hook_context(|_context| async {
                 use_future(
-                    || async {
+                    |_| async {
                         let _tracker = DropTracker;
                         Ok::<String, String>("test".to_string())
                     },
@@ -343,7 +343,7 @@ async fn test_cancellation_cleanup() {
     with_async_test_isolate(|| async {
         let handle = with_async_hook_context(|_context| async {
             use_future(
-                || async {
+                |_| async {
                     // Long-running future
                     sleep(Duration::from_secs(10)).await;
                     Ok::<String, String>("should_not_complete".to_string())
@@ -374,7 +374,7 @@ async fn test_cancellation_cleanup() {
 async fn test_concurrent_state_access_performance() {
     with_async_test_isolate(|| async {
         with_async_component_id("ConcurrentStateComponent", |_context| async {
-            let handle = use_future(|| async { Ok::<i32, String>(42) }, ());
+            let handle = use_future(|_| async { Ok::<i32, String>(42) }, ());
 
             // Wait for completion
             sleep(Duration::from_millis(50)).await;
@@ -456,7 +456,7 @@ async fn test_resource_exhaustion_protection() {
             // We can't easily test the actual limits without spawning many futures
             // but we can verify the error handling works
 
-            let handle = use_future(|| async { Ok::<String, String>("test".to_string()) }, ());
+            let handle = use_future(|_| async { Ok::<String, String>("test".to_string()) }, ());
 
             // Wait for completion
             sleep(Duration::from_millis(50)).await;
@@ -478,7 +478,7 @@ async fn test_error_handling_security() {
     with_async_test_isolate(|| async {
         with_async_component_id("ErrorTestComponent", |_context| async {
             let handle = use_future(
-                || async {
+                |_| async {
                     // Simulate a security-related error
                     Err::<String, String>("Security violation: unauthorized access".to_string())
                 },
@@ -505,3 +505,174 @@ async fn test_error_handling_security() {
     })
     .await;
 }
+
+/// A slow fetch that finishes after a newer one must not overwrite its result
+#[tokio::test]
+async fn test_stale_future_does_not_overwrite_newer_result() {
+    with_async_test_isolate(|| async {
+        let (release_stale, stale_released) = tokio::sync::oneshot::channel::<()>();
+        let stale_token = Arc::new(Mutex::new(None));
+
+        // First render: a slow fetch that waits until it is released
+        let stale_token_clone = stale_token.clone();
+        let stale_handle = with_async_component_id("StaleFetchComponent", |_context| async {
+            use_future(
+                move |token: CancellationToken| async move {
+                    *stale_token_clone.lock().unwrap() = Some(token);
+                    let _ = stale_released.await;
+                    Ok::<String, String>("stale".to_string())
+                },
+                1,
+            )
+        })
+        .await;
+
+        sleep(Duration::from_millis(10)).await;
+        assert!(stale_handle.is_pending());
+
+        // Second render: the dependency changes and a fast fetch resolves first
+        let fresh_handle = with_async_component_id("StaleFetchComponent", |_context| async {
+            use_future(|_| async { Ok::<String, String>("fresh".to_string()) }, 2)
+        })
+        .await;
+
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(fresh_handle.value(), Some("fresh".to_string()));
+
+        // Now let the old fetch finish
+        let _ = release_stale.send(());
+        sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(fresh_handle.value(), Some("fresh".to_string()));
+        let token = stale_token.lock().unwrap().clone().unwrap();
+        assert!(token.is_cancelled(), "Superseded fetch should be cancelled");
+    })
+    .await;
+}
+
+/// Results from a run cancelled through the handle are discarded
+#[tokio::test]
+async fn test_cancelled_future_result_is_discarded() {
+    with_async_test_isolate(|| async {
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+
+        let handle = with_async_hook_context(|_context| async {
+            use_future(
+                |_| async move {
+                    let _ = released.await;
+                    Ok::<i32, String>(1)
+                },
+                (),
+            )
+        })
+        .await;
+
+        handle.cancel();
+        let _ = release.send(());
+        sleep(Duration::from_millis(10)).await;
+
+        assert!(handle.is_pending());
+        assert_eq!(handle.value(), None);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_future_with_options_retries_until_success() {
+    with_async_test_isolate(|| async {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let handle = with_async_component_id("RetryFutureComponent", |_context| async {
+            use_future_with_options(
+                move |_| {
+                    let calls = calls_clone.clone();
+                    async move {
+                        sleep(Duration::from_millis(50)).await;
+                        match calls.fetch_add(1, Ordering::SeqCst) {
+                            0 | 1 => Err::<i32, String>("transient".to_string()),
+                            _ => Ok(7),
+                        }
+                    }
+                },
+                (),
+                FutureOptions {
+                    retry_attempts: 3,
+                    retry_delay: Duration::from_millis(10),
+                    exponential_backoff: false,
+                },
+            )
+        })
+        .await;
+
+        assert_eq!(handle.attempt(), 1);
+
+        // The first attempt has failed and the second is in flight
+        sleep(Duration::from_millis(80)).await;
+        assert!(handle.is_pending(), "Should stay pending while retrying");
+        assert_eq!(handle.attempt(), 2);
+
+        sleep(Duration::from_millis(250)).await;
+        assert_eq!(handle.value(), Some(7));
+        assert_eq!(handle.attempt(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_future_with_options_errors_after_retries_exhausted() {
+    with_async_test_isolate(|| async {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let handle = with_async_component_id("ExhaustedRetryComponent", |_context| async {
+            use_future_with_options(
+                move |_| {
+                    let calls = calls_clone.clone();
+                    async move {
+                        let call = calls.fetch_add(1, Ordering::SeqCst);
+                        Err::<i32, String>(format!("failure {}", call))
+                    }
+                },
+                (),
+                FutureOptions {
+                    retry_attempts: 2,
+                    retry_delay: Duration::from_millis(5),
+                    exponential_backoff: false,
+                },
+            )
+        })
+        .await;
+
+        sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(handle.attempt(), 3);
+        assert_eq!(handle.error(), Some("failure 2".to_string()));
+    })
+    .await;
+}
+
+#[test]
+fn test_future_options_backoff_delays() {
+    let fixed = FutureOptions {
+        retry_delay: Duration::from_millis(100),
+        ..Default::default()
+    };
+    assert_eq!(fixed.retry_attempts, 0);
+    assert_eq!(fixed.delay_before_retry(1), Duration::from_millis(100));
+    assert_eq!(fixed.delay_before_retry(4), Duration::from_millis(100));
+
+    let backoff = FutureOptions {
+        exponential_backoff: true,
+        ..fixed
+    };
+    assert_eq!(backoff.delay_before_retry(1), Duration::from_millis(100));
+    assert_eq!(backoff.delay_before_retry(2), Duration::from_millis(200));
+    assert_eq!(backoff.delay_before_retry(4), Duration::from_millis(800));
+    assert_eq!(
+        backoff.delay_before_retry(40),
+        Duration::from_millis(102_400)
+    );
+}
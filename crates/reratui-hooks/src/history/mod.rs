@@ -1,5 +1,11 @@
+use crate::clock;
 use crate::reducer::{DispatchFn, use_reducer};
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How long after a [`HistoryManager::push_coalesced`] another push with the
+/// same key still joins the same entry
+pub const COALESCE_WINDOW: Duration = Duration::from_secs(1);
 
 #[cfg(test)]
 pub mod tests;
@@ -15,6 +21,8 @@ pub struct HistoryState<T> {
     future: VecDeque<T>,
     /// Maximum number of history entries
     max_history: usize,
+    /// Key and time of the last coalesced push, while its group is open
+    coalescing: Option<(&'static str, Instant)>,
 }
 
 /// Actions that can be performed on the history
@@ -22,10 +30,28 @@ pub struct HistoryState<T> {
 pub enum HistoryAction<T: Clone> {
     /// Push a new state
     Push(T),
+    /// Push a new state, merging it into the current entry if the previous
+    /// push had the same key and happened within [`COALESCE_WINDOW`]
+    PushCoalesced {
+        /// The new state
+        value: T,
+        /// Groups pushes that may be merged, e.g. `"typing"`
+        key: &'static str,
+        /// When the push happened
+        at: Instant,
+    },
+    /// End the current coalescing group
+    Commit,
+    /// Replace the current state without adding a history entry
+    Replace(T),
     /// Undo the last change
     Undo,
     /// Redo the last undone change
     Redo,
+    /// Make the entry at this timeline index current
+    JumpTo(usize),
+    /// Drop every entry except the current one
+    Clear,
 }
 
 /// A manager for state history with undo/redo functionality
@@ -58,6 +84,35 @@ impl<T: Clone + 'static> HistoryManager<T> {
         self.dispatch.dispatch(HistoryAction::Push(new_state));
     }
 
+    /// Pushes a new state, grouping it with the previous push of the same kind
+    ///
+    /// Consecutive calls with the same `key` less than [`COALESCE_WINDOW`]
+    /// apart replace the current entry instead of adding one, so a burst of
+    /// keystrokes is undone in a single step. Any other history operation, or
+    /// [`commit`](Self::commit), starts a new group.
+    pub fn push_coalesced(&self, new_state: T, key: &'static str) {
+        self.dispatch.dispatch(HistoryAction::PushCoalesced {
+            value: new_state,
+            key,
+            at: clock::now(),
+        });
+    }
+
+    /// Ends the current group of coalesced pushes
+    ///
+    /// The next [`push_coalesced`](Self::push_coalesced) adds a new entry.
+    pub fn commit(&self) {
+        self.dispatch.dispatch(HistoryAction::Commit);
+    }
+
+    /// Replaces the current state without recording it in the history
+    ///
+    /// Unlike [`push`](Self::push), the previous state cannot be restored with
+    /// [`undo`](Self::undo), and the redo stack is kept.
+    pub fn replace(&self, new_state: T) {
+        self.dispatch.dispatch(HistoryAction::Replace(new_state));
+    }
+
     /// Undoes the last change
     pub fn undo(&self) {
         if self.can_undo() {
@@ -71,10 +126,78 @@ impl<T: Clone + 'static> HistoryManager<T> {
             self.dispatch.dispatch(HistoryAction::Redo);
         }
     }
+
+    /// Makes the entry at `index` in [`entries`](Self::entries) current
+    ///
+    /// Entries before it become the undo stack and entries after it the redo
+    /// stack, so a following [`push`](Self::push) discards the latter. Out of
+    /// range indices are ignored.
+    pub fn jump_to(&self, index: usize) {
+        if index < self.len() {
+            self.dispatch.dispatch(HistoryAction::JumpTo(index));
+        }
+    }
+
+    /// Number of entries in the timeline, including the current one
+    pub fn len(&self) -> usize {
+        let state = (self.get_state)();
+        state.past.len() + 1 + state.future.len()
+    }
+
+    /// Always `false`: the timeline contains at least the current entry
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Index of the current entry in [`entries`](Self::entries)
+    pub fn current_index(&self) -> usize {
+        (self.get_state)().past.len()
+    }
+
+    /// Every entry from oldest to newest, including undone ones
+    ///
+    /// Returns a snapshot, as the history lives in reducer state.
+    pub fn entries(&self) -> Vec<T> {
+        let state = (self.get_state)();
+        let mut entries = Vec::with_capacity(state.past.len() + 1 + state.future.len());
+        entries.extend(state.past);
+        entries.push(state.current);
+        entries.extend(state.future);
+        entries
+    }
+
+    /// Forgets all undo and redo entries, keeping the current state
+    pub fn clear(&self) {
+        self.dispatch.dispatch(HistoryAction::Clear);
+    }
 }
 
-fn history_reducer<T: Clone>(state: HistoryState<T>, action: HistoryAction<T>) -> HistoryState<T> {
+fn history_reducer<T: Clone>(
+    mut state: HistoryState<T>,
+    action: HistoryAction<T>,
+) -> HistoryState<T> {
+    // Only a coalesced push can extend the open group
+    let coalescing = state.coalescing.take();
+
     match action {
+        HistoryAction::PushCoalesced { value, key, at } => {
+            let joins_group = coalescing.is_some_and(|(last_key, last_at)| {
+                last_key == key && at.saturating_duration_since(last_at) < COALESCE_WINDOW
+            });
+
+            let mut state = if joins_group {
+                HistoryState {
+                    current: value,
+                    future: VecDeque::new(),
+                    ..state
+                }
+            } else {
+                history_reducer(state, HistoryAction::Push(value))
+            };
+            state.coalescing = Some((key, at));
+            state
+        }
+        HistoryAction::Commit => state,
         HistoryAction::Push(new_state) => {
             let mut past = state.past;
             past.push_back(state.current);
@@ -89,8 +212,13 @@ fn history_reducer<T: Clone>(state: HistoryState<T>, action: HistoryAction<T>) -
                 past,
                 future: VecDeque::new(), // Clear redo stack
                 max_history: state.max_history,
+                coalescing: None,
             }
         }
+        HistoryAction::Replace(new_state) => HistoryState {
+            current: new_state,
+            ..state
+        },
         HistoryAction::Undo => {
             if state.past.is_empty() {
                 return state;
@@ -107,6 +235,7 @@ fn history_reducer<T: Clone>(state: HistoryState<T>, action: HistoryAction<T>) -
                 past,
                 future,
                 max_history: state.max_history,
+                coalescing: None,
             }
         }
         HistoryAction::Redo => {
@@ -125,8 +254,36 @@ fn history_reducer<T: Clone>(state: HistoryState<T>, action: HistoryAction<T>) -
                 past,
                 future,
                 max_history: state.max_history,
+                coalescing: None,
+            }
+        }
+        HistoryAction::JumpTo(index) => {
+            if index >= state.past.len() + 1 + state.future.len() {
+                return state;
+            }
+
+            let mut entries = state.past;
+            entries.push_back(state.current);
+            entries.extend(state.future);
+
+            let future = entries.split_off(index + 1);
+            let current = entries.pop_back().unwrap();
+
+            HistoryState {
+                current,
+                past: entries,
+                future,
+                max_history: state.max_history,
+                coalescing: None,
             }
         }
+        HistoryAction::Clear => HistoryState {
+            current: state.current,
+            past: VecDeque::new(),
+            future: VecDeque::new(),
+            max_history: state.max_history,
+            coalescing: None,
+        },
     }
 }
 
@@ -141,6 +298,7 @@ where
         past: VecDeque::new(),
         future: VecDeque::new(),
         max_history,
+        coalescing: None,
     };
 
     // Use the reducer to manage state
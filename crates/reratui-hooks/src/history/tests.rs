@@ -317,3 +317,171 @@ fn test_history_sequence_operations() {
         });
     });
 }
+
+#[test]
+fn test_history_replace_keeps_past_and_future() {
+    with_test_isolate(|| {
+        with_component_id("HistoryReplaceTest", |_ctx| {
+            let history = use_history(1, 5);
+
+            history.push(2);
+            history.push(3);
+            history.undo();
+            history.replace(20);
+
+            assert_eq!(history.current(), 20);
+            assert!(history.can_redo(), "Replace should keep the redo stack");
+
+            history.undo();
+            assert_eq!(history.current(), 1);
+
+            history.redo();
+            assert_eq!(history.current(), 20);
+            history.redo();
+            assert_eq!(history.current(), 3);
+        });
+    });
+}
+
+#[test]
+fn test_history_timeline() {
+    with_test_isolate(|| {
+        with_component_id("HistoryTimelineTest", |_ctx| {
+            let history = use_history("a", 10);
+
+            history.push("b");
+            history.push("c");
+            history.undo();
+
+            assert_eq!(history.entries(), vec!["a", "b", "c"]);
+            assert_eq!(history.len(), 3);
+            assert_eq!(history.current_index(), 1);
+        });
+    });
+}
+
+#[test]
+fn test_history_jump_to_splits_undo_and_redo() {
+    with_test_isolate(|| {
+        with_component_id("HistoryJumpTest", |_ctx| {
+            let history = use_history(0, 10);
+
+            for value in 1..=4 {
+                history.push(value);
+            }
+
+            history.jump_to(1);
+            assert_eq!(history.current(), 1);
+            assert_eq!(history.current_index(), 1);
+            assert_eq!(history.entries(), vec![0, 1, 2, 3, 4]);
+
+            history.undo();
+            assert_eq!(history.current(), 0);
+            history.redo();
+            history.redo();
+            assert_eq!(history.current(), 2);
+
+            // Jumping back then pushing truncates everything after the jump
+            history.jump_to(1);
+            history.push(10);
+            assert_eq!(history.entries(), vec![0, 1, 10]);
+            assert!(!history.can_redo());
+
+            // Out of range jumps are ignored
+            history.jump_to(3);
+            assert_eq!(history.current(), 10);
+        });
+    });
+}
+
+#[test]
+fn test_history_clear_keeps_current() {
+    with_test_isolate(|| {
+        with_component_id("HistoryClearTest", |_ctx| {
+            let history = use_history(0, 10);
+
+            history.push(1);
+            history.push(2);
+            history.undo();
+            history.clear();
+
+            assert_eq!(history.current(), 1);
+            assert_eq!(history.entries(), vec![1]);
+            assert!(!history.can_undo());
+            assert!(!history.can_redo());
+        });
+    });
+}
+
+#[test]
+fn test_history_push_coalesced_groups_same_key() {
+    with_test_isolate(|| {
+        with_component_id("HistoryCoalesceTest", |_ctx| {
+            let history = use_history(String::new(), 10);
+
+            for text in ["h", "hi", "hi!"] {
+                history.push_coalesced(text.to_string(), "typing");
+            }
+            assert_eq!(history.entries(), vec!["", "hi!"]);
+
+            // A different key starts a new entry
+            history.push_coalesced("hi".to_string(), "deleting");
+            assert_eq!(history.len(), 3);
+
+            history.undo();
+            assert_eq!(history.current(), "hi!");
+            history.undo();
+            assert_eq!(history.current(), "");
+        });
+    });
+}
+
+#[test]
+fn test_history_commit_starts_new_group() {
+    with_test_isolate(|| {
+        with_component_id("HistoryCommitTest", |_ctx| {
+            let history = use_history(0, 10);
+
+            history.push_coalesced(1, "typing");
+            history.push_coalesced(2, "typing");
+            history.commit();
+            history.push_coalesced(3, "typing");
+            history.push_coalesced(4, "typing");
+            assert_eq!(history.entries(), vec![0, 2, 4]);
+
+            // Plain pushes and undo also close the group
+            history.push(5);
+            history.push_coalesced(6, "typing");
+            history.undo();
+            history.push_coalesced(7, "typing");
+            assert_eq!(history.entries(), vec![0, 2, 4, 5, 7]);
+        });
+    });
+}
+
+#[test]
+fn test_history_coalesce_window_expires() {
+    let start = Instant::now();
+    let state = HistoryState {
+        current: 0,
+        past: VecDeque::new(),
+        future: VecDeque::new(),
+        max_history: 10,
+        coalescing: None,
+    };
+    let push = |value, at| HistoryAction::PushCoalesced {
+        value,
+        key: "typing",
+        at,
+    };
+
+    let state = history_reducer(state, push(1, start));
+    let state = history_reducer(state, push(2, start + COALESCE_WINDOW / 2));
+    assert_eq!(state.past, VecDeque::from([0]));
+    assert_eq!(state.current, 2);
+
+    // The window slides with each push, but a longer pause splits the group
+    let state = history_reducer(state, push(3, start + COALESCE_WINDOW * 2));
+    assert_eq!(state.past, VecDeque::from([0, 2]));
+    assert_eq!(state.current, 3);
+}
@@ -1,3 +1,4 @@
+use crate::mouse::HoverRegistry;
 use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 thread_local! {
@@ -8,6 +9,8 @@ thread_local! {
 pub struct HookContext {
     pub(crate) states: RefCell<HashMap<usize, Box<dyn Any>>>,
     current_hook: RefCell<usize>,
+    /// Regions claimed by `use_hover_region`, rotated every frame
+    pub(crate) hover_regions: RefCell<HoverRegistry>,
 }
 
 impl HookContext {
@@ -16,6 +19,7 @@ impl HookContext {
         Self {
             states: RefCell::new(HashMap::new()),
             current_hook: RefCell::new(0),
+            hover_regions: RefCell::new(HoverRegistry::default()),
         }
     }
 
@@ -35,6 +39,7 @@ impl HookContext {
     /// Reset the hook index for a new render cycle
     pub fn reset_hook_index(&self) {
         *self.current_hook.borrow_mut() = 0;
+        self.hover_regions.borrow_mut().next_frame();
     }
 
     /// Get state for a specific hook index
@@ -86,6 +91,7 @@ impl HookContext {
     /// Clear all state (useful for cleanup)
     pub fn clear(&self) {
         self.states.borrow_mut().clear();
+        *self.hover_regions.borrow_mut() = HoverRegistry::default();
         self.reset_hook_index();
     }
 }
@@ -0,0 +1,53 @@
+//! Idle detection hook
+//!
+//! This module provides the `use_idle` hook, which reports whether the user
+//! has gone without pressing a key or using the mouse for a given duration.
+
+use crate::clock;
+use crate::event::use_event;
+use crate::ref_hook::use_ref;
+use crossterm::event::Event;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests;
+
+/// Whether an event counts as user activity
+///
+/// Resizes and focus changes come from the terminal rather than the user, so
+/// they do not end an idle period.
+fn is_user_input(event: &Event) -> bool {
+    matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_))
+}
+
+/// A hook that returns `true` once no input has arrived for `timeout`.
+///
+/// Any key, mouse or paste event resets the idle timer and the hook returns
+/// `false` again. The check runs on every render, so in the render loop the
+/// result flips on the first frame past the threshold. The timer starts when
+/// the component first renders.
+///
+/// Like [`use_event`], this hook consumes the current event for its own hook
+/// slot; other hooks in the same component still see it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::idle::use_idle;
+/// use std::time::Duration;
+///
+/// let idle = use_idle(Duration::from_secs(30));
+///
+/// let title = if idle { "💤 Dashboard (idle)" } else { "📊 Dashboard" };
+/// ```
+pub fn use_idle(timeout: Duration) -> bool {
+    let last_activity = use_ref(clock::now);
+
+    if let Some(event) = use_event()
+        && is_user_input(&event)
+    {
+        last_activity.set(clock::now());
+    }
+
+    last_activity.get().elapsed() >= timeout
+}
@@ -0,0 +1,55 @@
+//! Tests for the idle hook
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution from interfering with global event state
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn key_event() -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE))
+}
+
+#[test]
+fn test_user_input_events() {
+    assert!(is_user_input(&key_event()));
+    assert!(is_user_input(&Event::Paste("text".to_string())));
+    assert!(!is_user_input(&Event::Resize(80, 24)));
+    assert!(!is_user_input(&Event::FocusLost));
+}
+
+#[test]
+fn test_use_idle_after_timeout_and_reset_on_input() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let timeout = Duration::from_millis(20);
+
+        with_component_id("IdleTest", |_ctx| {
+            assert!(!use_idle(timeout), "Should not be idle on first render");
+        });
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        with_component_id("IdleTest", |_ctx| {
+            assert!(use_idle(timeout), "Should be idle after the timeout");
+        });
+
+        set_current_event(Some(Arc::new(key_event())));
+        with_component_id("IdleTest", |_ctx| {
+            assert!(!use_idle(timeout), "Input should reset the idle timer");
+        });
+
+        set_current_event(Some(Arc::new(Event::Resize(80, 24))));
+        std::thread::sleep(Duration::from_millis(30));
+        with_component_id("IdleTest", |_ctx| {
+            assert!(use_idle(timeout), "Resize should not count as input");
+        });
+
+        set_current_event(None);
+    });
+}
@@ -7,6 +7,12 @@
 //! ## Key Features:
 //! - **Synchronous intervals**: `use_interval` for simple periodic callbacks
 //! - **Asynchronous intervals**: `use_async_interval` for async periodic operations
+//! - **Controlled intervals**: `use_interval_controlled` returns an [`IntervalHandle`]
+//!   with pause, resume, and dynamic delay support
+//! - **Dependent intervals**: `use_interval_with_deps` restarts the timer with a
+//!   fresh callback whenever its dependencies change
+//! - **Owned intervals**: `use_interval_callback` keeps its timer in the hook
+//!   slot and stops it as soon as the delay changes or the component unmounts
 //! - Automatic cleanup when component unmounts or dependencies change
 //! - Proper async/await integration with tokio runtime
 //! - Thread-safe execution with proper error handling
@@ -42,6 +48,23 @@
 //! }, Duration::from_millis(500));
 //! ```
 //!
+//! ### Controlled Interval
+//! ```rust,no_run
+//! use reratui_hooks::interval::use_interval_controlled;
+//! use std::time::Duration;
+//!
+//! let interval = use_interval_controlled(|| {
+//!     println!("Tick!");
+//! }, Duration::from_secs(1));
+//!
+//! // Pause while the terminal is unfocused, pick up where we left off later
+//! interval.pause();
+//! interval.resume();
+//!
+//! // Speed up without losing the time already accumulated toward the next tick
+//! interval.set_delay(Duration::from_millis(500));
+//! ```
+//!
 //! ### Asynchronous Interval
 //! ```rust,no_run
 //! use reratui_hooks::interval::use_async_interval;
@@ -63,12 +86,17 @@
 //! }, Duration::from_secs(2));
 //! ```
 
-use std::time::Duration;
+use crate::clock::{self, Clock};
+use parking_lot::{Condvar, Mutex};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
 
 #[cfg(test)]
 mod tests;
 
 use crate::effect::EffectDependencies;
+use crate::panic_handler::spawn_registered;
 
 // Implement EffectDependencies for Duration to enable dependency tracking
 impl EffectDependencies for Duration {
@@ -97,18 +125,313 @@ impl EffectDependencies for Duration {
     }
 }
 
+/// How long a paused worker thread sleeps before re-checking whether its handle is still alive
+const PAUSED_PARK_DURATION: Duration = Duration::from_millis(100);
+
+/// Clamp zero delays to the minimum supported interval
+fn safe_delay(delay: Duration) -> Duration {
+    if delay.is_zero() {
+        Duration::from_millis(1)
+    } else {
+        delay
+    }
+}
+
+/// Mutable timer state for a controlled interval
+struct IntervalTimer {
+    /// Current delay between ticks
+    delay: Duration,
+    /// The delay most recently passed to the hook (used to detect prop changes)
+    requested_delay: Duration,
+    /// Whether ticking is currently suspended
+    paused: bool,
+    /// Whether the worker thread should exit
+    stopped: bool,
+    /// Time accumulated toward the next tick before the current running segment
+    accumulated: Duration,
+    /// When the current running segment started
+    segment_start: Instant,
+}
+
+impl IntervalTimer {
+    /// Total time accumulated toward the next tick at `now`
+    fn elapsed(&self, now: Instant) -> Duration {
+        if self.paused {
+            self.accumulated
+        } else {
+            self.accumulated + now.saturating_duration_since(self.segment_start)
+        }
+    }
+}
+
+/// State shared between an [`IntervalHandle`] and its worker thread
+struct IntervalShared {
+    timer: Mutex<IntervalTimer>,
+    wakeup: Condvar,
+    /// Wakes a worker task, used instead of a thread under a virtual clock
+    changed: Arc<Notify>,
+    /// The clock of the thread the interval was created on
+    clock: Arc<dyn Clock>,
+    /// Cloned out for each tick, so renders replacing it never wait on a tick
+    callback: Mutex<Option<Arc<dyn Fn() + Send + Sync>>>,
+    /// Held while a tick runs, so [`IntervalHandle::stop`] can wait for it
+    tick: Mutex<()>,
+}
+
+impl IntervalShared {
+    /// Wakes the worker after the timer changed
+    fn wake(&self) {
+        self.wakeup.notify_all();
+        self.changed.notify_waiters();
+    }
+
+    /// Runs the current callback once, unless the interval was stopped
+    ///
+    /// The callback lock is released before calling it, so a slow callback
+    /// doesn't hold up the renders that replace it.
+    fn tick(&self) {
+        let _tick = self.tick.lock();
+        if self.timer.lock().stopped {
+            return;
+        }
+        let callback = self.callback.lock().clone();
+        if let Some(callback) = callback {
+            callback();
+        }
+    }
+}
+
+/// Handle for controlling an interval created by [`use_interval_controlled`]
+///
+/// The handle is stable across renders and can be cloned freely, e.g. into
+/// event handlers or other callbacks.
+#[derive(Clone)]
+pub struct IntervalHandle {
+    shared: Arc<IntervalShared>,
+}
+
+impl IntervalHandle {
+    /// Create a new, not yet started interval with the given delay
+    fn new(delay: Duration) -> Self {
+        let clock = clock::current_clock();
+        Self {
+            shared: Arc::new(IntervalShared {
+                timer: Mutex::new(IntervalTimer {
+                    delay: safe_delay(delay),
+                    requested_delay: delay,
+                    paused: false,
+                    stopped: false,
+                    accumulated: Duration::ZERO,
+                    segment_start: clock.now(),
+                }),
+                wakeup: Condvar::new(),
+                changed: Arc::new(Notify::new()),
+                clock,
+                callback: Mutex::new(None),
+                tick: Mutex::new(()),
+            }),
+        }
+    }
+
+    /// Pause the interval, preserving the time accumulated toward the next tick
+    pub fn pause(&self) {
+        let mut timer = self.shared.timer.lock();
+        if !timer.paused {
+            timer.accumulated = timer.elapsed(self.shared.clock.now());
+            timer.paused = true;
+            self.shared.wake();
+        }
+    }
+
+    /// Resume a paused interval
+    ///
+    /// The next tick fires once the remaining time from before the pause has elapsed.
+    pub fn resume(&self) {
+        let mut timer = self.shared.timer.lock();
+        if timer.paused {
+            timer.segment_start = self.shared.clock.now();
+            timer.paused = false;
+            self.shared.wake();
+        }
+    }
+
+    /// Check whether the interval is currently paused
+    pub fn is_paused(&self) -> bool {
+        self.shared.timer.lock().paused
+    }
+
+    /// Change the delay between ticks
+    ///
+    /// Time already accumulated toward the next tick is kept, so shrinking the
+    /// delay below the elapsed time fires immediately.
+    pub fn set_delay(&self, delay: Duration) {
+        let mut timer = self.shared.timer.lock();
+        timer.delay = safe_delay(delay);
+        self.shared.wake();
+    }
+
+    /// Get the current delay between ticks
+    pub fn delay(&self) -> Duration {
+        self.shared.timer.lock().delay
+    }
+
+    /// Apply a delay passed to the hook, only if it differs from the previous render
+    fn sync_requested_delay(&self, delay: Duration) {
+        let changed = {
+            let mut timer = self.shared.timer.lock();
+            let changed = timer.requested_delay != delay;
+            timer.requested_delay = delay;
+            changed
+        };
+
+        if changed {
+            self.set_delay(delay);
+        }
+    }
+
+    /// Replace the callback invoked on each tick
+    fn set_callback<F>(&self, callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.shared.callback.lock() = Some(Arc::new(callback));
+    }
+
+    /// Spawn the worker that drives the interval
+    ///
+    /// This is a thread, or a tokio task under a virtual clock so advancing
+    /// the clock fires the ticks that are due.
+    fn start(&self) {
+        {
+            let mut timer = self.shared.timer.lock();
+            timer.stopped = false;
+            timer.accumulated = Duration::ZERO;
+            timer.segment_start = self.shared.clock.now();
+        }
+
+        let shared = Arc::downgrade(&self.shared);
+        if self.shared.clock.is_virtual() && tokio::runtime::Handle::try_current().is_ok() {
+            spawn_registered(Self::run_virtual(shared));
+        } else {
+            std::thread::spawn(move || Self::run(shared));
+        }
+    }
+
+    /// Signal the worker thread to exit
+    ///
+    /// Waits for a tick already in progress, so the callback never runs once
+    /// this returns.
+    fn stop(&self) {
+        self.shared.timer.lock().stopped = true;
+        self.shared.wake();
+        drop(self.shared.tick.lock());
+        self.shared.callback.lock().take();
+    }
+
+    /// Whether the worker is still running with `delay` as the hook's delay
+    fn is_running_with(&self, delay: Duration) -> bool {
+        let timer = self.shared.timer.lock();
+        !timer.stopped && timer.requested_delay == delay
+    }
+
+    /// Worker loop; exits when stopped or once every handle has been dropped
+    fn run(shared: Weak<IntervalShared>) {
+        while let Some(shared) = shared.upgrade() {
+            let mut timer = shared.timer.lock();
+
+            if timer.stopped {
+                break;
+            }
+
+            if timer.paused {
+                shared.wakeup.wait_for(&mut timer, PAUSED_PARK_DURATION);
+                continue;
+            }
+
+            let elapsed = timer.elapsed(shared.clock.now());
+            if elapsed >= timer.delay {
+                timer.accumulated = Duration::ZERO;
+                timer.segment_start = shared.clock.now();
+                drop(timer);
+
+                shared.tick();
+            } else {
+                let remaining = timer.delay - elapsed;
+                shared.wakeup.wait_for(&mut timer, remaining);
+            }
+        }
+    }
+
+    /// Worker task for a virtual clock, sleeping on the clock instead of a
+    /// condition variable
+    async fn run_virtual(shared: Weak<IntervalShared>) {
+        while let Some(strong) = shared.upgrade() {
+            let changed = strong.changed.clone();
+            let notified = changed.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            // Either fire a due tick, or find out how long to sleep
+            let deadline = {
+                let mut timer = strong.timer.lock();
+                if timer.stopped {
+                    break;
+                }
+                let now = strong.clock.now();
+                if timer.paused {
+                    Some(now + PAUSED_PARK_DURATION)
+                } else {
+                    let elapsed = timer.elapsed(now);
+                    if elapsed >= timer.delay {
+                        timer.accumulated = Duration::ZERO;
+                        timer.segment_start = now;
+                        None
+                    } else {
+                        Some(now + (timer.delay - elapsed))
+                    }
+                }
+            };
+
+            let Some(deadline) = deadline else {
+                strong.tick();
+                continue;
+            };
+            let sleep = strong.clock.sleep_until(deadline);
+            drop(strong);
+
+            tokio::select! {
+                _ = sleep => {}
+                _ = notified => {}
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for IntervalHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let timer = self.shared.timer.lock();
+        f.debug_struct("IntervalHandle")
+            .field("delay", &timer.delay)
+            .field("paused", &timer.paused)
+            .finish()
+    }
+}
+
 /// Professional synchronous interval hook for periodic callback execution
 ///
 /// This hook provides a React-like `setInterval` functionality with proper cleanup
 /// and integration with the component lifecycle. The interval automatically starts
 /// when the component mounts and stops when it unmounts.
 ///
+/// This is a convenience wrapper around [`use_interval_controlled`] that ignores
+/// the returned [`IntervalHandle`].
+///
 /// ## Key Features:
 /// - Automatic cleanup on component unmount
 /// - Thread-safe execution with proper error handling
 /// - No async runtime dependency (uses std::thread)
 /// - Professional resource management
-/// - Consistent timing with std::thread::sleep
+/// - Always invokes the callback from the latest render
 ///
 /// ## Parameters:
 /// - `callback`: Synchronous function to execute at each interval
@@ -116,62 +439,209 @@ impl EffectDependencies for Duration {
 ///
 /// ## Behavior:
 /// - The interval starts immediately when the hook is called
-/// - If the duration changes, the interval is restarted with the new duration
+/// - If the duration changes, the new delay applies without losing accumulated time
 /// - The interval is automatically cancelled when the component unmounts
 /// - All spawned threads are properly cleaned up to prevent memory leaks
 ///
 /// ## Thread Safety:
-/// The callback must be `Send + 'static` to ensure thread safety across thread boundaries.
+/// The callback must be `Send + Sync + 'static` to ensure thread safety across thread boundaries.
 /// State updates should use thread-safe mechanisms like the state hooks.
 ///
 /// ## Performance:
-/// Uses std::thread with sleep for timing. The implementation avoids busy-waiting
-/// and provides accurate timing without requiring an async runtime.
+/// Uses a std::thread parked on a condition variable for timing. The implementation
+/// avoids busy-waiting and provides accurate timing without requiring an async runtime.
+/// Under a [`MockClock`](crate::clock::MockClock) the interval runs as a tokio
+/// task instead, ticking as the clock is advanced.
 pub fn use_interval<F>(callback: F, duration: Duration)
 where
-    F: Fn() + Send + 'static,
+    F: Fn() + Send + Sync + 'static,
+{
+    use_interval_controlled(callback, duration);
+}
+
+/// Synchronous interval hook that returns an [`IntervalHandle`] for runtime control
+///
+/// Behaves like [`use_interval`], but the returned handle can pause, resume, and
+/// change the delay of the running interval. The timer state lives in the hook
+/// slot, so the same handle is returned on every render.
+///
+/// ## Parameters:
+/// - `callback`: Synchronous function to execute at each interval
+/// - `duration`: Initial time between executions
+///
+/// ## Behavior:
+/// - Pausing keeps the time accumulated toward the next tick; resuming continues from it
+/// - Changing `duration` between renders, or calling [`IntervalHandle::set_delay`],
+///   reschedules the next tick without resetting the accumulated time
+/// - The callback from the most recent render is always the one invoked
+/// - The interval is stopped when the component unmounts
+///
+/// ## Example:
+/// ```rust,no_run
+/// use reratui_hooks::interval::use_interval_controlled;
+/// use reratui_hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (uptime, set_uptime) = use_state(|| 0u64);
+/// let interval = use_interval_controlled(
+///     move || set_uptime.update(|secs| secs + 1),
+///     Duration::from_secs(1),
+/// );
+///
+/// // e.g. on Event::FocusLost / Event::FocusGained
+/// interval.pause();
+/// interval.resume();
+/// ```
+pub fn use_interval_controlled<F>(callback: F, duration: Duration) -> IntervalHandle
+where
+    F: Fn() + Send + Sync + 'static,
 {
     use crate::effect::use_effect;
-    use std::sync::Arc;
-    use std::sync::atomic::{AtomicBool, Ordering};
-    use std::thread;
+    use crate::hook_context::with_hook_context;
+
+    // Timer state is stored in the hook slot so it survives re-renders
+    let handle = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let handle_ref = ctx.get_or_init_state(index, || IntervalHandle::new(duration));
+        handle_ref.borrow().clone()
+    });
+
+    handle.sync_requested_delay(duration);
+    handle.set_callback(callback);
 
-    // Use effect to manage the interval lifecycle with proper cleanup
+    // Start the worker once on mount and stop it on unmount
     use_effect(
-        move || {
-            // Handle zero duration by using minimum duration
-            let safe_duration = if duration.is_zero() {
-                Duration::from_millis(1)
-            } else {
-                duration
-            };
+        {
+            let handle = handle.clone();
+            move || {
+                handle.start();
+                Some(Box::new(move || handle.stop()) as Box<dyn FnOnce() + Send>)
+            }
+        },
+        (),
+    );
 
-            // Create a flag to signal when to stop the interval
-            let should_stop = Arc::new(AtomicBool::new(false));
-            let should_stop_clone = should_stop.clone();
+    handle
+}
 
-            // Spawn interval thread
-            let handle = thread::spawn(move || {
-                while !should_stop_clone.load(Ordering::Relaxed) {
-                    thread::sleep(safe_duration);
-                    if !should_stop_clone.load(Ordering::Relaxed) {
-                        callback();
-                    }
-                }
-            });
+/// Synchronous interval hook that restarts whenever `deps` or `delay` change
+///
+/// Unlike [`use_interval`], which swaps in the latest callback without touching
+/// the timer, this hook tears the running timer down and starts a new one with
+/// the callback from the render where `deps` changed. Values the callback
+/// reads can go in `deps` instead of being cloned into it on every render,
+/// and the restarted timer counts a full `delay` before its first tick.
+///
+/// ## Behavior:
+/// - The first render starts the timer
+/// - When `deps` or `delay` differ from the previous render, the old timer is
+///   stopped, waiting for a tick in progress, before the new one starts
+/// - Between changes the callback from the last restart keeps being invoked
+/// - The interval is stopped when the component unmounts
+///
+/// ## Example:
+/// ```rust,no_run
+/// use reratui_hooks::interval::use_interval_with_deps;
+/// use reratui_hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (count, set_count) = use_state(|| 0u32);
+/// let (running, _set_running) = use_state(|| true);
+/// let running = running.get();
+///
+/// // The callback sees `running` as of the last change
+/// use_interval_with_deps(
+///     move || {
+///         if running {
+///             set_count.update(|count| count + 1);
+///         }
+///     },
+///     Duration::from_secs(1),
+///     running,
+/// );
+/// ```
+pub fn use_interval_with_deps<F, D>(callback: F, delay: Duration, deps: D)
+where
+    F: Fn() + Send + Sync + 'static,
+    D: EffectDependencies + Clone + PartialEq + std::fmt::Debug + 'static,
+{
+    use crate::effect::use_effect;
 
-            // Return cleanup function that signals stop and waits for thread
-            Some(Box::new(move || {
-                should_stop.store(true, Ordering::Relaxed);
-                // Note: We can't join the thread here as it would block the cleanup
-                // The thread will exit on its own when it checks the flag
-                let _ = handle; // Take ownership to prevent warnings
-            }) as Box<dyn FnOnce() + Send>)
+    // Effects run the previous cleanup before the new effect, so the old
+    // worker is stopped before the new one is spawned
+    use_effect(
+        move || {
+            let handle = IntervalHandle::new(delay);
+            handle.set_callback(callback);
+            handle.start();
+            Some(Box::new(move || handle.stop()) as Box<dyn FnOnce() + Send>)
         },
-        duration, // Effect depends on duration - restarts when duration changes
+        (delay, deps),
     );
 }
 
+/// Synchronous interval hook whose timer belongs to its hook slot
+///
+/// The running timer is kept in the hook slot rather than in an effect, so
+/// the hook itself decides when it ends: a render with a different `delay`
+/// stops the old timer, waiting for a tick in progress, and starts a new one
+/// that counts a full `delay` before its first tick. When the component is
+/// rendered from an [`Element`](reratui_core::Element), the timer is stopped
+/// once the component unmounts, and started afresh if it mounts again, so
+/// toggling a child never leaves timers behind.
+///
+/// The callback from the latest render is the one invoked, so setters and
+/// other values can be cloned into it on every render.
+///
+/// ## Example:
+/// ```rust,no_run
+/// use reratui_hooks::interval::use_interval_callback;
+/// use reratui_hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (fast, _set_fast) = use_state(|| false);
+/// let (ticks, set_ticks) = use_state(|| 0u64);
+/// let delay = if fast.get() {
+///     Duration::from_millis(100)
+/// } else {
+///     Duration::from_secs(1)
+/// };
+///
+/// use_interval_callback(move || set_ticks.update(|ticks| ticks + 1), delay);
+/// ```
+pub fn use_interval_callback<F>(callback: F, delay: Duration)
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    use crate::hook_context::with_hook_context;
+
+    let slot = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || None::<IntervalHandle>)
+    });
+
+    let handle = {
+        let mut slot = slot.borrow_mut();
+        match slot.as_ref() {
+            Some(handle) if handle.is_running_with(delay) => {
+                handle.set_callback(callback);
+                handle.clone()
+            }
+            _ => {
+                if let Some(old) = slot.take() {
+                    old.stop();
+                }
+                let handle = IntervalHandle::new(delay);
+                handle.set_callback(callback);
+                handle.start();
+                slot.insert(handle).clone()
+            }
+        }
+    };
+
+    reratui_core::component::on_current_unmount(move || handle.stop());
+}
+
 /// Professional asynchronous interval hook for periodic async callback execution
 ///
 /// This hook provides async interval functionality with proper cleanup and integration
@@ -184,7 +654,8 @@ where
 /// - Thread-safe execution with proper error handling
 /// - Integration with tokio async runtime
 /// - Professional resource management
-/// - Consistent timing with tokio::time::interval
+/// - Consistent timing, read from the [clock](crate::clock) so tests can
+///   advance it
 ///
 /// ## Parameters:
 /// - `callback`: Async function to execute at each interval
@@ -202,8 +673,9 @@ where
 /// State updates should use thread-safe mechanisms like the state hooks.
 ///
 /// ## Performance:
-/// Uses tokio's optimized interval timer for accurate timing with minimal overhead.
-/// The implementation properly handles async execution without blocking the runtime.
+/// Ticks are scheduled at fixed points on the clock, so a slow callback is
+/// followed by the ticks it delayed. The implementation properly handles async
+/// execution without blocking the runtime.
 ///
 /// ## Runtime Requirements:
 /// This function requires an active tokio runtime. If no runtime is available,
@@ -247,22 +719,22 @@ where
             };
 
             // Check if we're in a tokio runtime context
-            let handle = match tokio::runtime::Handle::try_current() {
-                Ok(handle) => handle,
-                Err(_) => {
-                    eprintln!("Warning: use_async_interval called outside tokio runtime context");
-                    return None; // No cleanup needed if we can't spawn
-                }
-            };
+            if tokio::runtime::Handle::try_current().is_err() {
+                eprintln!("Warning: use_async_interval called outside tokio runtime context");
+                return None; // No cleanup needed if we can't spawn
+            }
 
-            // Spawn async interval task
-            let task_handle = handle.spawn(async move {
-                let mut interval_timer = tokio::time::interval(safe_duration);
+            // Spawn async interval task, ticking right away and then every
+            // `safe_duration` on the clock of the rendering thread
+            let clock = clock::current_clock();
+            let task_handle = spawn_registered(async move {
+                let mut next_tick = clock.now();
 
                 loop {
-                    interval_timer.tick().await;
+                    clock.sleep_until(next_tick).await;
                     // Execute the async callback and wait for completion
                     callback().await;
+                    next_tick += safe_duration;
                 }
             });
 
@@ -300,6 +300,262 @@ async fn test_use_interval_performance() {
     .await;
 }
 
+// ============================================================================
+// CONTROLLED INTERVAL TESTS
+// ============================================================================
+
+/// Test that a controlled interval stops ticking while paused and resumes afterwards
+#[tokio::test]
+async fn test_use_interval_controlled_pause_resume() {
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let handle = with_component_id("PauseResumeIntervalComponent", |_context| {
+            let counter_clone = counter.clone();
+            use_interval_controlled(
+                move || {
+                    counter_clone.fetch_add(1, Ordering::Relaxed);
+                },
+                Duration::from_millis(10),
+            )
+        });
+
+        sleep(Duration::from_millis(35)).await;
+        handle.pause();
+        assert!(handle.is_paused());
+        let count_at_pause = counter.load(Ordering::Relaxed);
+        assert!(
+            count_at_pause >= 2,
+            "Expected ticks before pause, got {}",
+            count_at_pause
+        );
+
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(
+            counter.load(Ordering::Relaxed),
+            count_at_pause,
+            "Paused interval should not tick"
+        );
+
+        handle.resume();
+        assert!(!handle.is_paused());
+        sleep(Duration::from_millis(35)).await;
+        assert!(
+            counter.load(Ordering::Relaxed) > count_at_pause,
+            "Resumed interval should tick again"
+        );
+
+        handle.stop();
+    })
+    .await;
+}
+
+/// Test that the same handle is returned across re-renders
+#[tokio::test]
+async fn test_use_interval_controlled_handle_is_stable() {
+    with_test_isolate(|| async {
+        let first = with_component_id("StableHandleIntervalComponent", |_context| {
+            use_interval_controlled(|| {}, Duration::from_millis(50))
+        });
+        first.pause();
+
+        let second = with_component_id("StableHandleIntervalComponent", |_context| {
+            use_interval_controlled(|| {}, Duration::from_millis(50))
+        });
+
+        assert!(
+            second.is_paused(),
+            "Re-render should return the same timer state"
+        );
+        second.stop();
+    })
+    .await;
+}
+
+/// Test that changing deps replaces the timer and its callback
+#[tokio::test]
+async fn test_use_interval_with_deps_restarts_on_change() {
+    with_test_isolate(|| async {
+        let first = Arc::new(AtomicUsize::new(0));
+        let second = Arc::new(AtomicUsize::new(0));
+        let unused = Arc::new(AtomicUsize::new(0));
+
+        let render = |counter: &Arc<AtomicUsize>, deps: u32| {
+            with_component_id("DepsIntervalComponent", |_context| {
+                let counter = counter.clone();
+                use_interval_with_deps(
+                    move || {
+                        counter.fetch_add(1, Ordering::Relaxed);
+                    },
+                    Duration::from_millis(15),
+                    deps,
+                );
+            });
+        };
+
+        render(&first, 1);
+        sleep(Duration::from_millis(40)).await;
+        assert!(first.load(Ordering::Relaxed) >= 1);
+
+        // Same deps: the timer keeps the callback it was started with
+        render(&unused, 1);
+        sleep(Duration::from_millis(20)).await;
+        assert_eq!(unused.load(Ordering::Relaxed), 0);
+
+        // New deps: the old timer is stopped before the new one starts
+        render(&second, 2);
+        let first_at_change = first.load(Ordering::Relaxed);
+        sleep(Duration::from_millis(40)).await;
+
+        assert_eq!(first.load(Ordering::Relaxed), first_at_change);
+        assert!(second.load(Ordering::Relaxed) >= 1);
+        assert_eq!(unused.load(Ordering::Relaxed), 0);
+    })
+    .await;
+}
+
+/// Ticks a shared counter for as long as it is mounted
+#[derive(Clone)]
+struct Ticker {
+    counter: Arc<AtomicUsize>,
+    delay: Duration,
+}
+
+impl reratui_core::Component for Ticker {
+    fn render(&self, _area: ratatui::layout::Rect, _buffer: &mut ratatui::buffer::Buffer) {
+        let counter = self.counter.clone();
+        use_interval_callback(
+            move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            },
+            self.delay,
+        );
+    }
+}
+
+/// Test that use_interval_callback stops its timer on unmount and delay changes
+#[tokio::test]
+async fn test_use_interval_callback_stops_old_timers() {
+    use reratui_core::Element;
+    use reratui_core::component::cleanup_unmounted;
+
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let area = ratatui::layout::Rect::new(0, 0, 10, 1);
+        let render = |ticker: Option<Duration>| {
+            let mut buffer = ratatui::buffer::Buffer::empty(area);
+            let children = ticker
+                .map(|delay| {
+                    Element::component(Ticker {
+                        counter: counter.clone(),
+                        delay,
+                    })
+                })
+                .into_iter()
+                .collect();
+            with_component_id("IntervalCallbackParent", |_context| {
+                Element::fragment(children).render(area, &mut buffer)
+            });
+            cleanup_unmounted();
+        };
+
+        // Mounting and unmounting the child repeatedly leaves nothing running
+        for _ in 0..5 {
+            render(Some(Duration::from_millis(10)));
+            render(None);
+        }
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        // Mounted again, a single fresh timer ticks
+        render(Some(Duration::from_millis(10)));
+        sleep(Duration::from_millis(45)).await;
+        let ticks = counter.load(Ordering::Relaxed);
+        assert!((1..=6).contains(&ticks), "ticks: {ticks}");
+
+        // A new delay stops the old timer; the new one waits a full delay
+        render(Some(Duration::from_millis(200)));
+        let at_change = counter.load(Ordering::Relaxed);
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), at_change);
+
+        // Unmounting stops it for good
+        render(None);
+        sleep(Duration::from_millis(250)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), at_change);
+    })
+    .await;
+}
+
+/// Test that set_delay keeps the time accumulated toward the next tick
+#[tokio::test]
+async fn test_use_interval_controlled_set_delay_keeps_accumulated_time() {
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let handle = with_component_id("SetDelayIntervalComponent", |_context| {
+            let counter_clone = counter.clone();
+            use_interval_controlled(
+                move || {
+                    counter_clone.fetch_add(1, Ordering::Relaxed);
+                },
+                Duration::from_millis(200),
+            )
+        });
+
+        // 40ms accumulated; shrinking the delay to 30ms should fire right away
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+        handle.set_delay(Duration::from_millis(30));
+        assert_eq!(handle.delay(), Duration::from_millis(30));
+
+        sleep(Duration::from_millis(10)).await;
+        assert!(
+            counter.load(Ordering::Relaxed) >= 1,
+            "Accumulated time should carry over to the new delay"
+        );
+
+        handle.stop();
+    })
+    .await;
+}
+
+/// Test that pausing preserves accumulated time across the pause
+#[tokio::test]
+async fn test_use_interval_controlled_pause_preserves_accumulated_time() {
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let handle = with_component_id("PauseAccumulatedIntervalComponent", |_context| {
+            let counter_clone = counter.clone();
+            use_interval_controlled(
+                move || {
+                    counter_clone.fetch_add(1, Ordering::Relaxed);
+                },
+                Duration::from_millis(60),
+            )
+        });
+
+        // Run 40ms of the 60ms delay, then pause for longer than the delay
+        sleep(Duration::from_millis(40)).await;
+        handle.pause();
+        sleep(Duration::from_millis(80)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        // Only ~20ms should remain after resuming
+        handle.resume();
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(
+            counter.load(Ordering::Relaxed),
+            1,
+            "Remaining time before the pause should be honored"
+        );
+
+        handle.stop();
+    })
+    .await;
+}
+
 // ============================================================================
 // ASYNC INTERVAL TESTS
 // ============================================================================
@@ -0,0 +1,187 @@
+//! Held-key tracking and key chords
+//!
+//! Terminals only report key releases when the kitty keyboard protocol is
+//! enabled, so these hooks track held keys precisely only when the runtime
+//! managed to push `KeyboardEnhancementFlags`, and fall back to an
+//! approximation otherwise.
+
+use crate::clock;
+use crate::{event::use_event, ref_hook::use_ref};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Whether the terminal reports key release events
+static KEYBOARD_ENHANCEMENT: AtomicBool = AtomicBool::new(false);
+
+/// How long the last pressed key counts as held without enhancement
+///
+/// Longer than the usual key-repeat delay, so a key held down keeps
+/// refreshing itself through repeated press events.
+pub const FALLBACK_HOLD_DURATION: Duration = Duration::from_millis(600);
+
+/// Record whether keyboard enhancement is active
+///
+/// Called by the runtime after it pushed (or popped) the
+/// `REPORT_EVENT_TYPES` keyboard enhancement flag.
+pub fn set_keyboard_enhancement(enabled: bool) {
+    KEYBOARD_ENHANCEMENT.store(enabled, Ordering::SeqCst);
+}
+
+/// Check whether the terminal reports key press, repeat and release events
+pub fn is_keyboard_enhancement_enabled() -> bool {
+    KEYBOARD_ENHANCEMENT.load(Ordering::SeqCst)
+}
+
+/// Keys currently held down, as observed by one hook
+#[derive(Debug, Default)]
+pub(crate) struct HeldKeys {
+    keys: HashSet<KeyCode>,
+    /// When the last key was pressed, used in fallback mode
+    last_press_at: Option<Instant>,
+}
+
+impl HeldKeys {
+    /// Update the held set from an event
+    ///
+    /// With `enhanced` set, presses add keys and releases remove them.
+    /// Otherwise only the most recently pressed key is held.
+    pub(crate) fn apply(&mut self, event: &Event, enhanced: bool, now: Instant) {
+        match event {
+            Event::Key(key) => match key.kind {
+                KeyEventKind::Press | KeyEventKind::Repeat => {
+                    if !enhanced {
+                        self.keys.clear();
+                    }
+                    self.keys.insert(key.code);
+                    self.last_press_at = Some(now);
+                }
+                KeyEventKind::Release => {
+                    self.keys.remove(&key.code);
+                }
+            },
+            // Releases that happen while unfocused are never reported
+            Event::FocusLost => self.clear(),
+            _ => {}
+        }
+    }
+
+    /// Drop the fallback key once it has not been pressed for a while
+    pub(crate) fn expire(&mut self, enhanced: bool, now: Instant) {
+        if enhanced {
+            return;
+        }
+        if let Some(last) = self.last_press_at
+            && now.duration_since(last) >= FALLBACK_HOLD_DURATION
+        {
+            self.clear();
+        }
+    }
+
+    pub(crate) fn keys(&self) -> &HashSet<KeyCode> {
+        &self.keys
+    }
+
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.last_press_at = None;
+    }
+}
+
+/// A hook that returns the set of keys currently held down.
+///
+/// # Fallback behavior
+///
+/// Precise tracking needs key release events, which terminals only send
+/// when the kitty keyboard protocol is active. The runtime tries to enable it
+/// on startup; check [`is_keyboard_enhancement_enabled`] to see if it
+/// succeeded.
+///
+/// - **Enhanced**: presses add keys, releases remove them, so several keys can
+///   be held at once.
+/// - **Fallback**: the set contains at most the last pressed key. It stays held
+///   while the terminal's key repeat keeps sending presses, and is dropped
+///   [`FALLBACK_HOLD_DURATION`] after the last one.
+///
+/// In both modes the set is cleared when the terminal loses focus.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::keyboard::use_keys_held;
+/// use crossterm::event::KeyCode;
+///
+/// let held = use_keys_held();
+/// let dx = held.contains(&KeyCode::Right) as i32 - held.contains(&KeyCode::Left) as i32;
+/// ```
+///
+/// # Note
+///
+/// Modifier keys appear in the set only if the terminal reports them as
+/// separate keys; use [`use_key_chord`] to match modifiers reliably.
+pub fn use_keys_held() -> HashSet<KeyCode> {
+    let held = use_ref(HeldKeys::default);
+    let enhanced = is_keyboard_enhancement_enabled();
+    let now = clock::now();
+
+    held.with_mut(|held| {
+        held.expire(enhanced, now);
+        if let Some(event) = use_event() {
+            held.apply(&event, enhanced, now);
+        }
+        held.keys().clone()
+    })
+}
+
+/// A hook that fires a handler when a chord of keys is pressed together.
+///
+/// The handler runs once, on the press that completes the chord: every key in
+/// `keys` is held and the pressed key's modifiers equal `modifiers`. Holding
+/// the chord does not fire it again through key repeat.
+///
+/// Chords of more than one non-modifier key need keyboard enhancement (see
+/// [`use_keys_held`]); without it only the last pressed key is known, so only
+/// single-key chords such as Ctrl+Shift+P can complete.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::keyboard::use_key_chord;
+/// use crossterm::event::{KeyCode, KeyModifiers};
+///
+/// // Fire when W and D are held together (diagonal movement)
+/// use_key_chord(&[KeyCode::Char('w'), KeyCode::Char('d')], KeyModifiers::NONE, || {
+///     println!("Moving up-right");
+/// });
+/// ```
+pub fn use_key_chord<F>(keys: &[KeyCode], modifiers: KeyModifiers, handler: F)
+where
+    F: Fn(),
+{
+    let held = use_ref(HeldKeys::default);
+    let enhanced = is_keyboard_enhancement_enabled();
+    let now = clock::now();
+
+    let completed = held.with_mut(|held| {
+        held.expire(enhanced, now);
+        let Some(event) = use_event() else {
+            return false;
+        };
+        held.apply(&event, enhanced, now);
+
+        matches!(
+            event,
+            Event::Key(key)
+                if key.kind == KeyEventKind::Press
+                    && key.modifiers == modifiers
+                    && keys.contains(&key.code)
+        ) && keys.iter().all(|code| held.keys().contains(code))
+    });
+
+    if completed {
+        handler();
+    }
+}
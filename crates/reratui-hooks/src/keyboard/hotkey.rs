@@ -0,0 +1,89 @@
+//! Hotkeys limited to the part of the tree they belong to
+//!
+//! Two panels binding the same key with [`use_keyboard_shortcut`] both fire on
+//! every press. [`use_hotkey`] takes a [`HotkeyScope`] instead, so a panel's
+//! keys only act while that panel is focused or not covered by a modal.
+//!
+//! [`use_keyboard_shortcut`]: super::use_keyboard_shortcut
+
+use crate::area::ComponentArea;
+use crate::context::try_use_context;
+use crate::event::global_events::KeyBinding;
+use crate::focus::{FocusManager, FocusState};
+
+use super::use_keyboard_press;
+
+/// When a hotkey registered with [`use_hotkey`] fires
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HotkeyScope {
+    /// Whenever the component renders
+    #[default]
+    Global,
+    /// While the nearest focusable is focused
+    ///
+    /// The nearest focusable is the last one the component registered with
+    /// [`use_focus`](crate::focus::use_focus) before this hook, or else that
+    /// of its closest focusable ancestor. Without one the hotkey never fires.
+    WhenFocused,
+    /// While the component renders into a non-empty area and no focus trap,
+    /// such as an open modal, renders outside its subtree
+    WhenVisible,
+}
+
+impl HotkeyScope {
+    /// Whether hotkeys of this scope may fire in the component rendering now
+    fn is_active(self) -> bool {
+        match self {
+            HotkeyScope::Global => true,
+            HotkeyScope::WhenFocused => {
+                try_use_context::<FocusState>().is_some_and(|focus| focus.is_focused())
+            }
+            HotkeyScope::WhenVisible => {
+                let has_area =
+                    try_use_context::<ComponentArea>().is_none_or(|area| !area.is_empty());
+                let covered = try_use_context::<FocusManager>()
+                    .is_some_and(|manager| manager.is_outside_trap());
+                has_area && !covered
+            }
+        }
+    }
+}
+
+/// A hook that runs `handler` when `binding` is pressed within `scope`
+///
+/// Only presses fire the hotkey, not releases or repeats. Characters match
+/// however Shift was reported, as with [`KeyBinding::matches`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use crossterm::event::KeyCode;
+/// use reratui_hooks::event::global_events::KeyBinding;
+/// use reratui_hooks::focus::use_focus;
+/// use reratui_hooks::keyboard::{HotkeyScope, use_hotkey};
+///
+/// // In a list panel: `n` only adds an item while the list is focused, so
+/// // another panel may bind `n` too
+/// let _focus = use_focus(0);
+/// use_hotkey(KeyCode::Char('n'), HotkeyScope::WhenFocused, || {
+///     println!("New item");
+/// });
+///
+/// // Ctrl+S saves from anywhere
+/// use_hotkey(KeyBinding::ctrl(KeyCode::Char('s')), HotkeyScope::Global, || {
+///     println!("Saved");
+/// });
+/// ```
+pub fn use_hotkey<F>(binding: impl Into<KeyBinding>, scope: HotkeyScope, handler: F)
+where
+    F: Fn() + Clone + Send + Sync + 'static,
+{
+    let binding = binding.into();
+    let active = scope.is_active();
+
+    use_keyboard_press(move |key_event| {
+        if active && binding.matches(&key_event) {
+            handler();
+        }
+    });
+}
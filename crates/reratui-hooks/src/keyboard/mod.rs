@@ -1,13 +1,51 @@
 //! Keyboard event hook
 //!
-//! Provides a convenient hook for handling keyboard events with stable callbacks.
+//! Provides a convenient hook for handling keyboard events with stable callbacks,
+//! plus hooks for key sequences, chords, and held-key tracking.
+//!
+//! With keyboard enhancement enabled, holding a key reports one
+//! `KeyEventKind::Press` followed by `KeyEventKind::Repeat` events.
+//! [`use_keyboard_press`] and [`use_keyboard_shortcut`] ignore the repeats, so
+//! holding a key acts once; [`use_keyboard_repeat`], or [`KeyPressOptions`]
+//! with `include_repeats`, acts on each repeat as well, which suits
+//! navigation such as scrolling a list. Without enhancement, terminals report
+//! every repeat as a press.
 
-use crate::{effect_event::use_effect_event, event::use_event};
+use crate::{
+    effect_event::use_effect_event,
+    event::{global_events::KeyBinding, use_event},
+};
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 
+mod held;
+mod hotkey;
+mod sequence;
+
 #[cfg(test)]
 mod tests;
 
+pub use held::*;
+pub use hotkey::*;
+pub use sequence::*;
+
+/// Which key events [`use_keyboard_press_with_options`] and
+/// [`use_keyboard_shortcut_with_options`] act on
+///
+/// With the default options only presses are handled, which is how
+/// [`use_keyboard_press`] behaves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyPressOptions {
+    /// Whether the repeats of a held key are handled like presses
+    pub include_repeats: bool,
+}
+
+impl KeyPressOptions {
+    /// Whether `key_event` should reach the handler
+    fn accepts(&self, key_event: &KeyEvent) -> bool {
+        key_event.is_press() || (self.include_repeats && key_event.is_repeat())
+    }
+}
+
 /// A hook that handles keyboard events with a stable callback.
 ///
 /// This hook uses `use_effect_event` internally to ensure the callback always
@@ -89,25 +127,88 @@ where
 /// # Note
 ///
 /// - Only triggers on `KeyEventKind::Press` events
-/// - Filters out `KeyEventKind::Release` and `KeyEventKind::Repeat`
+/// - Filters out `KeyEventKind::Release` and `KeyEventKind::Repeat`, so holding
+///   a key acts once; see [`use_keyboard_repeat`] to act on repeats too
 /// - The callback always sees the latest state values (via effect event pattern)
 /// - The callback has a stable identity across renders
 pub fn use_keyboard_press<F>(handler: F)
+where
+    F: Fn(KeyEvent) + Clone + Send + Sync + 'static,
+{
+    use_keyboard_press_with_options(handler, KeyPressOptions::default());
+}
+
+/// A hook that handles key presses, and the repeats of held keys if
+/// `options.include_repeats` is set
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::keyboard::{KeyPressOptions, use_keyboard_press_with_options};
+///
+/// let options = KeyPressOptions {
+///     include_repeats: true,
+/// };
+/// use_keyboard_press_with_options(
+///     move |key_event| println!("Key pressed or held: {:?}", key_event.code),
+///     options,
+/// );
+/// ```
+///
+/// # Note
+///
+/// - Release events are always filtered out
+/// - The callback always sees the latest state values (via effect event pattern)
+pub fn use_keyboard_press_with_options<F>(handler: F, options: KeyPressOptions)
 where
     F: Fn(KeyEvent) + Clone + Send + Sync + 'static,
 {
     use_keyboard(move |key_event| {
-        // Only handle press events, ignore release and repeat
-        if key_event.is_press() {
+        if options.accepts(&key_event) {
             handler(key_event);
         }
     });
 }
 
+/// A hook that handles key presses and the repeats of held keys
+///
+/// Holding a key calls the handler once for the press and again for each
+/// repeat the terminal reports, so holding Down keeps moving a selection.
+/// This is [`use_keyboard_press_with_options`] with `include_repeats` set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use crossterm::event::KeyCode;
+/// use reratui_hooks::keyboard::use_keyboard_repeat;
+/// use reratui_hooks::state::use_state;
+///
+/// let (selected, set_selected) = use_state(|| 0usize);
+///
+/// use_keyboard_repeat(move |key_event| match key_event.code {
+///     KeyCode::Up => set_selected.update(|index| index.saturating_sub(1)),
+///     KeyCode::Down => set_selected.update(|index| index + 1),
+///     _ => {}
+/// });
+/// ```
+pub fn use_keyboard_repeat<F>(handler: F)
+where
+    F: Fn(KeyEvent) + Clone + Send + Sync + 'static,
+{
+    use_keyboard_press_with_options(
+        handler,
+        KeyPressOptions {
+            include_repeats: true,
+        },
+    );
+}
+
 /// A hook that handles keyboard shortcuts with specific key and modifier combinations.
 ///
 /// This is a high-level convenience hook for detecting keyboard shortcuts like Ctrl+S,
-/// Alt+F4, etc. It only triggers on key press events.
+/// Alt+F4, etc. It only triggers on key press events. It is [`use_hotkey`] with
+/// [`HotkeyScope::Global`], so it fires wherever the component is; use
+/// `use_hotkey` to limit a shortcut to a focused or visible panel.
 ///
 /// # Type Parameters
 ///
@@ -158,8 +259,10 @@ where
 ///
 /// # Note
 ///
-/// - Only triggers on exact matches of key code AND modifiers
-/// - Uses `use_keyboard_press` internally (only press events, no release/repeat)
+/// - Only triggers on matches of key code AND modifiers; Shift is ignored for
+///   characters, as in [`KeyBinding::matches`]
+/// - Only press events trigger it, not releases or repeats; see
+///   [`use_keyboard_shortcut_with_options`] to act on repeats too
 /// - The callback always sees the latest state values (via effect event pattern)
 /// - The callback has a stable identity across renders
 /// - For multiple modifiers, use bitwise OR: `KeyModifiers::CONTROL | KeyModifiers::SHIFT`
@@ -167,10 +270,47 @@ pub fn use_keyboard_shortcut<F>(key_code: KeyCode, modifiers: KeyModifiers, hand
 where
     F: Fn() + Clone + Send + Sync + 'static,
 {
-    use_keyboard_press(move |key_event| {
-        // Check if both key code and modifiers match
-        if key_event.code == key_code && key_event.modifiers == modifiers {
-            handler();
-        }
-    });
+    use_hotkey(
+        KeyBinding::new(key_code, modifiers),
+        HotkeyScope::Global,
+        handler,
+    );
+}
+
+/// A hook that handles a keyboard shortcut, and its repeats while held if
+/// `options.include_repeats` is set
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use reratui_hooks::keyboard::{KeyPressOptions, use_keyboard_shortcut_with_options};
+///
+/// // Holding Ctrl+Z keeps undoing
+/// use_keyboard_shortcut_with_options(
+///     KeyCode::Char('z'),
+///     KeyModifiers::CONTROL,
+///     || println!("Undo!"),
+///     KeyPressOptions {
+///         include_repeats: true,
+///     },
+/// );
+/// ```
+pub fn use_keyboard_shortcut_with_options<F>(
+    key_code: KeyCode,
+    modifiers: KeyModifiers,
+    handler: F,
+    options: KeyPressOptions,
+) where
+    F: Fn() + Clone + Send + Sync + 'static,
+{
+    let binding = KeyBinding::new(key_code, modifiers);
+    use_keyboard_press_with_options(
+        move |key_event| {
+            if binding.matches(&key_event) {
+                handler();
+            }
+        },
+        options,
+    );
 }
@@ -0,0 +1,169 @@
+//! Multi-key sequence hook
+//!
+//! Provides vim-style key sequences such as `g g` or `d d`.
+
+use crate::clock;
+use crate::{callback::Callback, event::use_event, ref_hook::use_ref};
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use std::time::{Duration, Instant};
+
+/// Accumulated keys of an in-progress sequence
+#[derive(Debug, Default)]
+pub(crate) struct KeySequenceState {
+    /// Keys typed so far
+    buffer: Vec<KeyCode>,
+    /// When the most recent key was typed
+    last_key_at: Option<Instant>,
+}
+
+impl KeySequenceState {
+    /// Feed a key into the state machine
+    ///
+    /// Returns the indices of the bindings that fired, in order. A pending
+    /// sequence that timed out or cannot be extended by `key` is resolved first,
+    /// so typing an unmatched key can both fire a pending binding and start a
+    /// new sequence.
+    pub(crate) fn push(
+        &mut self,
+        key: KeyCode,
+        sequences: &[&[KeyCode]],
+        now: Instant,
+        timeout: Duration,
+    ) -> Vec<usize> {
+        let mut fired = Vec::new();
+        fired.extend(self.expire(sequences, now, timeout));
+
+        self.buffer.push(key);
+        if !self.is_prefix(sequences) && Self::exact_match(&self.buffer, sequences).is_none() {
+            // The key does not extend the pending sequence: resolve what was
+            // pending and try the key as the start of a new sequence
+            self.buffer.pop();
+            fired.extend(self.flush(sequences));
+            self.buffer.push(key);
+        }
+
+        if self.is_prefix(sequences) {
+            // Wait for more keys, even if the buffer is itself a full match
+            self.last_key_at = Some(now);
+        } else {
+            fired.extend(self.flush(sequences));
+        }
+
+        fired
+    }
+
+    /// Resolve the pending sequence if `timeout` elapsed since the last key
+    ///
+    /// Returns the index of the binding that fired, if the pending keys fully
+    /// matched one.
+    pub(crate) fn expire(
+        &mut self,
+        sequences: &[&[KeyCode]],
+        now: Instant,
+        timeout: Duration,
+    ) -> Option<usize> {
+        match self.last_key_at {
+            Some(last) if now.duration_since(last) >= timeout => self.flush(sequences),
+            _ => None,
+        }
+    }
+
+    /// Clear the buffer, returning the binding it fully matched, if any
+    fn flush(&mut self, sequences: &[&[KeyCode]]) -> Option<usize> {
+        let matched = Self::exact_match(&self.buffer, sequences);
+        self.buffer.clear();
+        self.last_key_at = None;
+        matched
+    }
+
+    /// Check whether the buffer is a strict prefix of a longer binding
+    fn is_prefix(&self, sequences: &[&[KeyCode]]) -> bool {
+        sequences
+            .iter()
+            .any(|seq| seq.len() > self.buffer.len() && seq.starts_with(&self.buffer))
+    }
+
+    fn exact_match(buffer: &[KeyCode], sequences: &[&[KeyCode]]) -> Option<usize> {
+        if buffer.is_empty() {
+            return None;
+        }
+        sequences.iter().position(|seq| *seq == buffer)
+    }
+}
+
+/// A hook that fires callbacks for multi-key sequences, vim style.
+///
+/// Keys are accumulated until they complete one of the bound sequences, at
+/// which point that binding's callback is emitted. The pending keys are
+/// discarded when `timeout` elapses between two keys or when a key arrives
+/// that no binding can continue with.
+///
+/// When one binding is a prefix of another (for example both `d` and `d d`
+/// are bound), the shorter one waits for disambiguation: it fires only once
+/// `timeout` elapses without another key, or when a key arrives that does not
+/// continue the longer sequence.
+///
+/// # Arguments
+///
+/// * `bindings` - Key sequences paired with the callback to emit on completion
+/// * `timeout` - Maximum delay between two keys of a sequence
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::{callback::Callback, keyboard::use_key_sequence, state::use_state};
+/// use crossterm::event::KeyCode;
+/// use std::time::Duration;
+///
+/// let (cursor, set_cursor) = use_state(|| 10usize);
+///
+/// use_key_sequence(
+///     &[
+///         (&[KeyCode::Char('g'), KeyCode::Char('g')], Callback::new({
+///             let set_cursor = set_cursor.clone();
+///             move |_| set_cursor.set(0)
+///         })),
+///         (&[KeyCode::Char('G')], Callback::new({
+///             let set_cursor = set_cursor.clone();
+///             move |_| set_cursor.set(usize::MAX)
+///         })),
+///     ],
+///     Duration::from_millis(500),
+/// );
+/// ```
+///
+/// # Notes
+///
+/// - Only key presses are considered; release and repeat events are ignored
+/// - Keys typed with Ctrl or Alt never match a binding; like any other
+///   unmatched key they resolve the pending keys
+/// - Sequences are compared by [`KeyCode`] only, so bind `Char('G')` for Shift+g
+/// - The timeout is checked on every render, so a pending binding fires on
+///   the first frame after it elapses
+pub fn use_key_sequence(bindings: &[(&[KeyCode], Callback<()>)], timeout: Duration) {
+    let state = use_ref(KeySequenceState::default);
+    let sequences: Vec<&[KeyCode]> = bindings.iter().map(|(seq, _)| *seq).collect();
+    let now = clock::now();
+
+    let fired = match use_event() {
+        Some(Event::Key(key_event)) if key_event.is_press() => state.with_mut(|state| {
+            if key_event
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            {
+                // Modified keys are never part of a sequence
+                state.flush(&sequences).into_iter().collect()
+            } else {
+                state.push(key_event.code, &sequences, now, timeout)
+            }
+        }),
+        _ => state
+            .with_mut(|state| state.expire(&sequences, now, timeout))
+            .into_iter()
+            .collect(),
+    };
+
+    for index in fired {
+        bindings[index].1.emit(());
+    }
+}
@@ -2,13 +2,20 @@
 
 use super::*;
 use crate::{
+    area::ComponentArea,
+    callback::Callback,
+    context::{use_context_provider, with_context_scope},
     event::set_current_event,
+    focus::{FocusManager, use_focus},
     state::use_state,
     test_utils::{with_component_id, with_test_isolate},
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 use parking_lot::Mutex;
+use ratatui::layout::Rect;
+use std::collections::HashSet;
 use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
 // Test mutex to prevent parallel test execution
 static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
@@ -348,6 +355,78 @@ fn test_use_keyboard_press_ignores_repeat() {
     });
 }
 
+#[test]
+fn test_use_keyboard_repeat_handles_held_keys() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        for kind in [
+            KeyEventKind::Press,
+            KeyEventKind::Repeat,
+            KeyEventKind::Repeat,
+            KeyEventKind::Release,
+        ] {
+            let key_event = KeyEvent {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+                kind,
+                state: KeyEventState::NONE,
+            };
+            set_current_event(Some(Arc::new(Event::Key(key_event))));
+
+            with_component_id("KeyboardRepeatTest", |_ctx| {
+                let calls = calls.clone();
+                use_keyboard_repeat(move |key_event| calls.lock().push(key_event.kind));
+            });
+        }
+
+        assert_eq!(
+            *calls.lock(),
+            vec![
+                KeyEventKind::Press,
+                KeyEventKind::Repeat,
+                KeyEventKind::Repeat
+            ],
+            "Should handle the press and each repeat, but not the release"
+        );
+    });
+}
+
+#[test]
+fn test_keyboard_shortcut_options_include_repeats() {
+    let _lock = TEST_MUTEX.lock();
+    for include_repeats in [false, true] {
+        with_test_isolate(|| {
+            let called = Arc::new(Mutex::new(false));
+            let called_clone = called.clone();
+
+            let key_event = KeyEvent {
+                code: KeyCode::Char('z'),
+                modifiers: KeyModifiers::CONTROL,
+                kind: KeyEventKind::Repeat,
+                state: KeyEventState::NONE,
+            };
+            set_current_event(Some(Arc::new(Event::Key(key_event))));
+
+            with_component_id("ShortcutRepeatTest", |_ctx| {
+                use_keyboard_shortcut_with_options(
+                    KeyCode::Char('z'),
+                    KeyModifiers::CONTROL,
+                    move || *called_clone.lock() = true,
+                    KeyPressOptions { include_repeats },
+                );
+            });
+
+            assert_eq!(
+                *called.lock(),
+                include_repeats,
+                "Repeats should only trigger shortcuts that opt in"
+            );
+        });
+    }
+}
+
 #[test]
 fn test_use_keyboard_press_with_state() {
     let _lock = TEST_MUTEX.lock();
@@ -573,3 +652,323 @@ fn test_use_keyboard_shortcut_with_state() {
         });
     });
 }
+
+fn press(code: KeyCode) {
+    set_current_event(Some(Arc::new(Event::Key(KeyEvent::new(
+        code,
+        KeyModifiers::NONE,
+    )))));
+}
+
+#[test]
+fn test_key_sequence_state_machine() {
+    let gg: &[KeyCode] = &[KeyCode::Char('g'), KeyCode::Char('g')];
+    let d: &[KeyCode] = &[KeyCode::Char('d')];
+    let dd: &[KeyCode] = &[KeyCode::Char('d'), KeyCode::Char('d')];
+    let sequences = [gg, d, dd];
+    let timeout = Duration::from_millis(500);
+    let start = Instant::now();
+    let mut state = KeySequenceState::default();
+
+    // Complete sequence fires once the last key arrives
+    assert!(
+        state
+            .push(KeyCode::Char('g'), &sequences, start, timeout)
+            .is_empty()
+    );
+    assert_eq!(
+        state.push(KeyCode::Char('g'), &sequences, start, timeout),
+        vec![0]
+    );
+
+    // Timeout between keys discards the pending prefix
+    state.push(KeyCode::Char('g'), &sequences, start, timeout);
+    let later = start + Duration::from_secs(1);
+    assert!(
+        state
+            .push(KeyCode::Char('g'), &sequences, later, timeout)
+            .is_empty()
+    );
+    assert_eq!(
+        state.push(KeyCode::Char('g'), &sequences, later, timeout),
+        vec![0]
+    );
+
+    // Unmatched key resets without firing
+    state.push(KeyCode::Char('g'), &sequences, start, timeout);
+    assert!(
+        state
+            .push(KeyCode::Char('x'), &sequences, start, timeout)
+            .is_empty()
+    );
+    assert!(state.expire(&sequences, later, timeout).is_none());
+
+    // Ambiguous prefix waits: `d d` wins when completed in time
+    assert!(
+        state
+            .push(KeyCode::Char('d'), &sequences, start, timeout)
+            .is_empty()
+    );
+    assert_eq!(
+        state.push(KeyCode::Char('d'), &sequences, start, timeout),
+        vec![2]
+    );
+
+    // ...and `d` fires once the timeout elapses
+    state.push(KeyCode::Char('d'), &sequences, start, timeout);
+    assert!(state.expire(&sequences, start, timeout).is_none());
+    assert_eq!(state.expire(&sequences, later, timeout), Some(1));
+
+    // ...or when a key arrives that cannot continue `d d`, which then starts anew
+    state.push(KeyCode::Char('d'), &sequences, start, timeout);
+    assert_eq!(
+        state.push(KeyCode::Char('g'), &sequences, start, timeout),
+        vec![1]
+    );
+    assert_eq!(
+        state.push(KeyCode::Char('g'), &sequences, start, timeout),
+        vec![0]
+    );
+}
+
+#[test]
+fn test_use_key_sequence() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let fired = Arc::new(Mutex::new(Vec::new()));
+
+        let render = || {
+            with_component_id("KeySequenceTest", |_ctx| {
+                use_key_sequence(
+                    &[
+                        (&[KeyCode::Char('g'), KeyCode::Char('g')], {
+                            let fired = fired.clone();
+                            Callback::new(move |_| fired.lock().push("gg"))
+                        }),
+                        (&[KeyCode::Char('d')], {
+                            let fired = fired.clone();
+                            Callback::new(move |_| fired.lock().push("d"))
+                        }),
+                        (&[KeyCode::Char('d'), KeyCode::Char('d')], {
+                            let fired = fired.clone();
+                            Callback::new(move |_| fired.lock().push("dd"))
+                        }),
+                    ],
+                    Duration::from_millis(50),
+                );
+            });
+        };
+
+        press(KeyCode::Char('g'));
+        render();
+        assert!(fired.lock().is_empty());
+        press(KeyCode::Char('g'));
+        render();
+        assert_eq!(*fired.lock(), vec!["gg"]);
+
+        // Ambiguous `d` resolves on a later render once the timeout elapses
+        press(KeyCode::Char('d'));
+        render();
+        set_current_event(None);
+        render();
+        assert_eq!(*fired.lock(), vec!["gg"]);
+        std::thread::sleep(Duration::from_millis(80));
+        render();
+        assert_eq!(*fired.lock(), vec!["gg", "d"]);
+
+        // Ctrl-modified keys never take part in a sequence
+        press(KeyCode::Char('g'));
+        render();
+        set_current_event(Some(Arc::new(Event::Key(KeyEvent::new(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL,
+        )))));
+        render();
+        assert_eq!(*fired.lock(), vec!["gg", "d"]);
+    });
+}
+
+fn key_event(code: KeyCode, kind: KeyEventKind) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_held_keys_enhanced() {
+    let now = Instant::now();
+    let mut held = HeldKeys::default();
+
+    held.apply(&key_event(KeyCode::Left, KeyEventKind::Press), true, now);
+    held.apply(&key_event(KeyCode::Up, KeyEventKind::Press), true, now);
+    held.apply(&key_event(KeyCode::Up, KeyEventKind::Repeat), true, now);
+    assert_eq!(held.keys(), &HashSet::from([KeyCode::Left, KeyCode::Up]));
+
+    // Keys stay held until released, however long that takes
+    held.expire(true, now + Duration::from_secs(10));
+    held.apply(&key_event(KeyCode::Left, KeyEventKind::Release), true, now);
+    assert_eq!(held.keys(), &HashSet::from([KeyCode::Up]));
+
+    held.apply(&Event::FocusLost, true, now);
+    assert!(held.keys().is_empty());
+}
+
+#[test]
+fn test_held_keys_fallback() {
+    let now = Instant::now();
+    let mut held = HeldKeys::default();
+
+    // Without enhancement only the last pressed key is held
+    held.apply(&key_event(KeyCode::Left, KeyEventKind::Press), false, now);
+    held.apply(&key_event(KeyCode::Up, KeyEventKind::Press), false, now);
+    assert_eq!(held.keys(), &HashSet::from([KeyCode::Up]));
+
+    held.expire(false, now + FALLBACK_HOLD_DURATION / 2);
+    assert_eq!(held.keys(), &HashSet::from([KeyCode::Up]));
+
+    held.expire(false, now + FALLBACK_HOLD_DURATION);
+    assert!(held.keys().is_empty());
+}
+
+#[test]
+fn test_use_keys_held_and_chord() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_keyboard_enhancement(true);
+        let chords = Arc::new(Mutex::new(0));
+
+        let render = || {
+            with_component_id("KeysHeldTest", |_ctx| {
+                let chords = chords.clone();
+                use_key_chord(
+                    &[KeyCode::Char('w'), KeyCode::Char('d')],
+                    KeyModifiers::NONE,
+                    move || *chords.lock() += 1,
+                );
+                use_keys_held()
+            })
+        };
+
+        set_current_event(Some(Arc::new(key_event(
+            KeyCode::Char('w'),
+            KeyEventKind::Press,
+        ))));
+        assert_eq!(render(), HashSet::from([KeyCode::Char('w')]));
+        assert_eq!(*chords.lock(), 0);
+
+        set_current_event(Some(Arc::new(key_event(
+            KeyCode::Char('d'),
+            KeyEventKind::Press,
+        ))));
+        assert_eq!(
+            render(),
+            HashSet::from([KeyCode::Char('w'), KeyCode::Char('d')])
+        );
+        assert_eq!(*chords.lock(), 1);
+
+        // Key repeat does not fire the chord again
+        set_current_event(Some(Arc::new(key_event(
+            KeyCode::Char('d'),
+            KeyEventKind::Repeat,
+        ))));
+        render();
+        assert_eq!(*chords.lock(), 1);
+
+        set_current_event(Some(Arc::new(key_event(
+            KeyCode::Char('w'),
+            KeyEventKind::Release,
+        ))));
+        assert_eq!(render(), HashSet::from([KeyCode::Char('d')]));
+
+        set_current_event(None);
+        set_keyboard_enhancement(false);
+    });
+}
+
+/// Renders two panels binding `n`, each in its own focus scope
+///
+/// Both share one hook context, as components do in the runtime.
+fn render_hotkey_panels(scope: HotkeyScope, fired: &Arc<Mutex<Vec<&'static str>>>) {
+    with_component_id("HotkeyPanels", |_ctx| {
+        for (name, order) in [("left", 0), ("right", 1)] {
+            with_context_scope(|| {
+                use_focus(order);
+                let fired = fired.clone();
+                use_hotkey(KeyCode::Char('n'), scope, move || fired.lock().push(name));
+            });
+        }
+    });
+}
+
+#[test]
+fn test_use_hotkey_when_focused() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let manager =
+            with_component_id("HotkeyRoot", |_ctx| use_context_provider(FocusManager::new));
+        let fired = Arc::new(Mutex::new(Vec::new()));
+
+        set_current_event(None);
+        render_hotkey_panels(HotkeyScope::WhenFocused, &fired);
+        manager.begin_frame();
+
+        press(KeyCode::Char('n'));
+        render_hotkey_panels(HotkeyScope::WhenFocused, &fired);
+        manager.begin_frame();
+        assert_eq!(*fired.lock(), vec!["left"]);
+
+        manager.focus_next();
+        press(KeyCode::Char('n'));
+        render_hotkey_panels(HotkeyScope::WhenFocused, &fired);
+        assert_eq!(*fired.lock(), vec!["left", "right"]);
+
+        // Both panels fire when scoped globally
+        fired.lock().clear();
+        press(KeyCode::Char('n'));
+        render_hotkey_panels(HotkeyScope::Global, &fired);
+        assert_eq!(*fired.lock(), vec!["left", "right"]);
+    });
+}
+
+#[test]
+fn test_use_hotkey_when_visible() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let manager = with_component_id("HotkeyVisibleRoot", |_ctx| {
+            use_context_provider(FocusManager::new)
+        });
+        let fired = Arc::new(Mutex::new(0));
+        let render = |area: Rect| {
+            press(KeyCode::Char('n'));
+            with_component_id("HotkeyVisiblePanel", |_ctx| {
+                with_context_scope(|| {
+                    use_context_provider(|| ComponentArea(area));
+                    let fired = fired.clone();
+                    use_hotkey(KeyCode::Char('n'), HotkeyScope::WhenVisible, move || {
+                        *fired.lock() += 1
+                    });
+                })
+            });
+        };
+
+        render(Rect::new(0, 0, 10, 5));
+        assert_eq!(*fired.lock(), 1);
+
+        // Collapsed to nothing
+        render(Rect::new(0, 0, 0, 5));
+        assert_eq!(*fired.lock(), 1);
+
+        // Covered by a modal trapping the focus
+        manager.trap(|| {});
+        manager.begin_frame();
+        render(Rect::new(0, 0, 10, 5));
+        assert_eq!(*fired.lock(), 1);
+
+        manager.begin_frame();
+        render(Rect::new(0, 0, 10, 5));
+        assert_eq!(*fired.lock(), 2);
+    });
+}
@@ -0,0 +1,254 @@
+//! Named keyboard commands
+//!
+//! [`use_command`] binds a key combination to a named command in the
+//! [`KeymapRegistry`] returned by [`use_keymap`]. The runtime runs a command
+//! as soon as its keys are pressed, and [`HelpOverlay`] lists every command
+//! with its keys as a cheat-sheet.
+
+use crossterm::event::{Event, KeyCode};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+use reratui_core::text::{display_width, pad_to_width};
+use reratui_core::{Component, ComponentProps, Element, PortalComponent, PortalProps};
+
+use crate::context::try_use_context;
+use crate::event::global_events::{Command, KeyBinding, KeymapRegistry};
+use crate::event::use_event;
+use crate::modal::modal_area;
+use crate::state::use_state;
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the keymap registry of the component tree
+///
+/// This is the registry provided through context with
+/// [`use_context_provider`](crate::context::use_context_provider), or the
+/// [global](KeymapRegistry::global) one the runtime dispatches key presses to.
+pub fn use_keymap() -> KeymapRegistry {
+    try_use_context::<KeymapRegistry>().unwrap_or_else(KeymapRegistry::global)
+}
+
+/// Hook registering a named command bound to `binding`
+///
+/// The command is registered again on every render, replacing the earlier
+/// handler, so the handler may capture the latest state. It stays registered
+/// until removed with [`KeymapRegistry::unregister`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use crossterm::event::KeyCode;
+/// use reratui_hooks::event::global_events::KeyBinding;
+/// use reratui_hooks::keymap::use_command;
+///
+/// use_command("save", KeyBinding::ctrl(KeyCode::Char('s')), "Save the file", || {
+///     // write the file
+/// });
+/// ```
+pub fn use_command<F>(
+    name: impl Into<String>,
+    binding: impl Into<KeyBinding>,
+    description: impl Into<String>,
+    handler: F,
+) where
+    F: Fn() + Send + Sync + 'static,
+{
+    use_keymap().register(name, binding, description, handler);
+}
+
+/// Props for the `HelpOverlay` component
+#[derive(Clone)]
+pub struct HelpOverlayProps {
+    /// Shows and hides the cheat-sheet
+    pub toggle_key: KeyBinding,
+    pub title: String,
+    /// Stacking order among portals; see [`PortalProps::z_index`]
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl Default for HelpOverlayProps {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyBinding::from(KeyCode::Char('?')),
+            title: "Keyboard shortcuts".to_string(),
+            z_index: 100,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl HelpOverlayProps {
+    /// Sets the key showing and hiding the cheat-sheet
+    pub fn toggle_key(mut self, toggle_key: impl Into<KeyBinding>) -> Self {
+        self.toggle_key = toggle_key.into();
+        self
+    }
+
+    /// Sets the title of the cheat-sheet
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the stacking order among portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for HelpOverlayProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A keybinding cheat-sheet generated from the registered commands
+///
+/// Renders its children as usual; pressing `toggle_key` (`?` by default)
+/// lists the commands of [`use_keymap`] in a centered box until it is
+/// pressed again or Esc closes it.
+///
+/// ```ignore
+/// rsx! {
+///     <HelpOverlay>
+///         <Editor />
+///     </HelpOverlay>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct HelpOverlayComponent {
+    props: HelpOverlayProps,
+}
+
+impl HelpOverlayComponent {
+    pub fn new(props: HelpOverlayProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for HelpOverlayComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let (open, set_open) = use_state(|| false);
+        if let Some(Event::Key(key)) = use_event()
+            && key.is_press()
+        {
+            if self.props.toggle_key.matches(&key) {
+                set_open.update(|open| !open);
+            } else if key.code == KeyCode::Esc {
+                set_open.set(false);
+            }
+        }
+
+        let children = &self.props.children;
+        if children.len() == 1 {
+            children[0].render(area, buffer);
+        } else if !children.is_empty() {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, buffer);
+            }
+        }
+
+        if !open.get() {
+            return;
+        }
+        // Read here, while this component's context is still in scope
+        let layer = HelpLayer {
+            title: self.props.title.clone(),
+            commands: use_keymap().commands(),
+        };
+        PortalComponent::new(
+            PortalProps::default()
+                .z_index(self.props.z_index)
+                .with_children(vec![Element::component(layer)]),
+        )
+        .render(area, buffer);
+    }
+}
+
+/// The cheat-sheet of an open overlay, rendered over the full frame
+struct HelpLayer {
+    title: String,
+    commands: Vec<Command>,
+}
+
+impl Component for HelpLayer {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let keys: Vec<String> = self
+            .commands
+            .iter()
+            .map(|command| command.binding.to_string())
+            .collect();
+        let key_width = keys.iter().map(|key| display_width(key)).max().unwrap_or(0);
+
+        let lines: Vec<Line> = if self.commands.is_empty() {
+            vec![Line::from("No shortcuts registered")]
+        } else {
+            self.commands
+                .iter()
+                .zip(&keys)
+                .map(|(command, key)| {
+                    Line::from(vec![
+                        Span::styled(
+                            pad_to_width(key, key_width, Alignment::Right),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw("  "),
+                        Span::raw(command.description.clone()),
+                    ])
+                })
+                .collect()
+        };
+
+        let content_width = lines
+            .iter()
+            .map(Line::width)
+            .chain([display_width(&self.title) + 2])
+            .max()
+            .unwrap_or(0);
+        let dialog = modal_area(
+            area,
+            (content_width + 4).min(u16::MAX as usize) as u16,
+            (lines.len() + 2).min(u16::MAX as usize) as u16,
+        );
+        Clear.render(dialog, buffer);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title));
+        let inner = block.inner(dialog);
+        block.render(dialog, buffer);
+        let inner = Rect {
+            x: inner.x + 1,
+            width: inner.width.saturating_sub(2),
+            ..inner
+        };
+        Paragraph::new(lines).render(inner, buffer);
+    }
+}
+
+/// The `HelpOverlay` component, usable as `<HelpOverlay>` in `rsx!`
+pub type HelpOverlay = HelpOverlayComponent;
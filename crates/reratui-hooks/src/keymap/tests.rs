@@ -0,0 +1,115 @@
+//! Tests for named keyboard commands
+
+use super::*;
+use crate::context::{use_context_provider, with_context_scope};
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use reratui_core::portal::render_portals;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Registers a save and a quit command
+#[derive(Clone)]
+struct Editor {
+    saves: Arc<AtomicUsize>,
+}
+
+impl Component for Editor {
+    fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+        let saves = self.saves.clone();
+        use_command(
+            "save",
+            KeyBinding::ctrl(KeyCode::Char('s')),
+            "Save the file",
+            move || {
+                saves.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+        use_command("quit", KeyCode::Char('q'), "Quit", || {});
+    }
+}
+
+fn press(code: KeyCode) {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    set_current_event(Some(Arc::new(Event::Key(key))));
+}
+
+/// Renders `overlay` with `keymap` provided and returns the rows of the frame
+fn render_overlay(overlay: &HelpOverlay, keymap: &KeymapRegistry) -> Vec<String> {
+    let area = Rect::new(0, 0, 30, 6);
+    let mut buffer = Buffer::empty(area);
+    with_component_id("HelpOverlayTest", |_ctx| {
+        with_context_scope(|| {
+            use_context_provider(|| keymap.clone());
+            overlay.render(area, &mut buffer);
+        })
+    });
+    render_portals(area, &mut buffer);
+    (0..area.height)
+        .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn test_commands_register_in_provided_keymap() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let keymap = KeymapRegistry::new();
+        let saves = Arc::new(AtomicUsize::new(0));
+        let overlay = HelpOverlayComponent::new(HelpOverlayProps::default().with_children(vec![
+            Element::component(Editor {
+                saves: saves.clone(),
+            }),
+        ]));
+
+        set_current_event(None);
+        render_overlay(&overlay, &keymap);
+        // Rendering again replaces the commands instead of adding more
+        render_overlay(&overlay, &keymap);
+
+        let names: Vec<_> = keymap
+            .commands()
+            .into_iter()
+            .map(|command| command.name)
+            .collect();
+        assert_eq!(names, ["save", "quit"]);
+
+        let save = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(keymap.dispatch(&save));
+        assert!(!keymap.dispatch(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)));
+        assert_eq!(saves.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn test_overlay_lists_commands() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let keymap = KeymapRegistry::new();
+        let overlay = HelpOverlayComponent::new(HelpOverlayProps::default().with_children(vec![
+            Element::component(Editor {
+                saves: Arc::default(),
+            }),
+        ]));
+
+        set_current_event(None);
+        let rows = render_overlay(&overlay, &keymap);
+        assert!(rows.iter().all(|row| !row.contains("Save the file")));
+
+        press(KeyCode::Char('?'));
+        let rows = render_overlay(&overlay, &keymap);
+        assert_eq!(rows[1], "  ┌ Keyboard shortcuts ───┐   ");
+        assert_eq!(rows[2], "  │ Ctrl+S  Save the file │   ");
+        assert_eq!(rows[3], "  │      q  Quit          │   ");
+
+        press(KeyCode::Esc);
+        let rows = render_overlay(&overlay, &keymap);
+        set_current_event(None);
+        assert!(rows.iter().all(|row| !row.contains("Quit")));
+    });
+}
@@ -1,26 +1,54 @@
 pub mod area;
+pub mod async_action;
+pub mod bus;
 pub mod callback;
+pub mod capabilities;
+pub mod clock;
+pub mod command_palette;
+pub mod confirm;
 pub mod context;
+pub mod countdown;
+pub mod data_table;
+pub mod debounce;
+pub mod deferred;
 pub mod effect;
 pub mod effect_event;
 pub mod event;
+pub mod focus;
 pub mod form;
 pub mod frame;
 pub mod future;
 pub mod history;
 pub mod hook_context;
 pub mod id;
+pub mod idle;
 pub mod interval;
 pub mod keyboard;
+pub mod keymap;
+pub mod list_selection;
+pub mod logging;
+pub mod measure;
 pub mod memo;
+pub mod modal;
 pub mod mouse;
 pub mod mutation;
+pub mod progress;
 pub mod query;
 pub mod reducer;
 pub mod ref_hook;
 pub mod resize;
+pub mod scroll;
+pub mod spinner;
 pub mod state;
+pub mod stopwatch;
+pub mod tabs;
+pub mod text_input;
+pub mod theme;
 pub mod timeout;
+pub mod toast;
+pub mod transition;
+pub mod viewport;
+pub mod visibility;
 
 #[cfg(test)]
 pub mod test_utils;
@@ -0,0 +1,132 @@
+//! Keyboard-driven selection in a list
+//!
+//! [`use_list_selection`] keeps the index of the selected item of a list,
+//! clamped to its length, and moves it with the usual navigation keys.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// The selected item of a list `len` items long
+#[derive(Clone)]
+pub struct ListSelection {
+    selected: StateHandle<usize>,
+    set_selected: StateSetter<usize>,
+    len: usize,
+}
+
+impl ListSelection {
+    /// The selected index, or `None` while the list is empty
+    pub fn selected(&self) -> Option<usize> {
+        (self.len > 0).then(|| self.selected.get().min(self.len - 1))
+    }
+
+    /// Number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the list has no items to select
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Selects `index`, clamped to the last item
+    pub fn select(&self, index: usize) {
+        self.set_selected.set(index.min(self.len.saturating_sub(1)));
+    }
+
+    /// Selects the item after the current one
+    pub fn select_next(&self) {
+        self.move_by(1, false);
+    }
+
+    /// Selects the item before the current one
+    pub fn select_previous(&self) {
+        self.move_by(1, true);
+    }
+
+    /// Selects the first item
+    pub fn select_first(&self) {
+        self.select(0);
+    }
+
+    /// Selects the last item
+    pub fn select_last(&self) {
+        self.select(usize::MAX);
+    }
+
+    /// Moves the selection down by `page` items
+    pub fn page_down(&self, page: usize) {
+        self.move_by(page.max(1), false);
+    }
+
+    /// Moves the selection up by `page` items
+    pub fn page_up(&self, page: usize) {
+        self.move_by(page.max(1), true);
+    }
+
+    /// Moves the selection for a navigation key
+    ///
+    /// Up/Down move by one item, PageUp/PageDown by `page` items and
+    /// Home/End to either end. Returns whether the selection changed.
+    pub fn handle_key(&self, key: &KeyEvent, page: usize) -> bool {
+        let before = self.selected();
+        match key.code {
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::PageUp => self.page_up(page),
+            KeyCode::PageDown => self.page_down(page),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            _ => return false,
+        }
+        self.selected() != before
+    }
+
+    fn move_by(&self, steps: usize, backwards: bool) {
+        let current = self.selected().unwrap_or(0);
+        self.select(if backwards {
+            current.saturating_sub(steps)
+        } else {
+            current.saturating_add(steps)
+        });
+    }
+}
+
+/// Hook for the selected item of a list
+///
+/// The selection starts on the first item and stays within the list when it
+/// shrinks between renders.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::keyboard::use_keyboard_repeat;
+/// use reratui_hooks::list_selection::use_list_selection;
+///
+/// let items = vec!["apple", "banana", "cherry"];
+/// let selection = use_list_selection(items.len());
+///
+/// // Holding a key keeps moving the selection
+/// use_keyboard_repeat({
+///     let selection = selection.clone();
+///     move |key| {
+///         selection.handle_key(&key, 10);
+///     }
+/// });
+///
+/// let current = selection.selected().map(|index| items[index]);
+/// ```
+pub fn use_list_selection(len: usize) -> ListSelection {
+    let (selected, set_selected) = use_state(|| 0usize);
+
+    ListSelection {
+        selected,
+        set_selected,
+        len,
+    }
+}
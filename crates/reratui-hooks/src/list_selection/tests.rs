@@ -0,0 +1,54 @@
+//! Tests for list selection
+
+use super::*;
+use crate::test_utils::with_component_id;
+use crossterm::event::KeyModifiers;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+#[test]
+fn test_selection_stays_in_list() {
+    with_component_id("ListSelectionTest", |_ctx| {
+        let selection = use_list_selection(5);
+        assert_eq!(selection.selected(), Some(0));
+
+        selection.select_previous();
+        assert_eq!(selection.selected(), Some(0));
+        selection.select(9);
+        assert_eq!(selection.selected(), Some(4));
+        selection.select_next();
+        assert_eq!(selection.selected(), Some(4));
+    });
+
+    // The list shrinks; the selection follows its end
+    with_component_id("ListSelectionTest", |_ctx| {
+        assert_eq!(use_list_selection(2).selected(), Some(1));
+    });
+    with_component_id("ListSelectionTest", |_ctx| {
+        assert_eq!(use_list_selection(0).selected(), None);
+    });
+}
+
+#[test]
+fn test_navigation_keys() {
+    with_component_id("ListSelectionKeysTest", |_ctx| {
+        let selection = use_list_selection(20);
+
+        assert!(selection.handle_key(&key(KeyCode::Down), 5));
+        assert_eq!(selection.selected(), Some(1));
+        assert!(selection.handle_key(&key(KeyCode::PageDown), 5));
+        assert_eq!(selection.selected(), Some(6));
+        assert!(selection.handle_key(&key(KeyCode::End), 5));
+        assert_eq!(selection.selected(), Some(19));
+        assert!(selection.handle_key(&key(KeyCode::PageUp), 5));
+        assert_eq!(selection.selected(), Some(14));
+        assert!(selection.handle_key(&key(KeyCode::Home), 5));
+        assert_eq!(selection.selected(), Some(0));
+
+        // Keys that don't move the selection report no change
+        assert!(!selection.handle_key(&key(KeyCode::Up), 5));
+        assert!(!selection.handle_key(&key(KeyCode::Enter), 5));
+    });
+}
@@ -0,0 +1,449 @@
+//! In-app logging
+//!
+//! Printing to stdout corrupts the alternate screen, so [`init_logging`]
+//! installs a `tracing` subscriber that keeps recent events in a bounded
+//! in-memory [`LogBuffer`] instead. [`use_logs`] reads the buffer back while
+//! rendering, and [`LogOverlay`] shows it over the app at the press of a key.
+
+use crate::clock;
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{Event, KeyCode};
+use parking_lot::Mutex;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+use reratui_core::{Component, ComponentProps, Element, PortalComponent, PortalProps};
+use tracing::field::{Field, Visit};
+use tracing::subscriber::SetGlobalDefaultError;
+use tracing::{Event as TracingEvent, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::event::use_event;
+use crate::state::use_state;
+
+pub use tracing::Level;
+
+#[cfg(test)]
+mod tests;
+
+/// Entries kept by [`init_logging`] when no capacity is given
+pub const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+/// The buffer behind [`use_logs`]
+static LOG_BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+
+/// A recorded `tracing` event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Time since the buffer was created
+    pub elapsed: Duration,
+    pub level: Level,
+    pub target: String,
+    /// The message followed by the other fields as `key=value`
+    pub message: String,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>8.3}s {:<5} {}: {}",
+            self.elapsed.as_secs_f64(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// A bounded, thread-safe ring buffer of log entries
+///
+/// Clones share the same entries. Once full, every new entry drops the
+/// oldest one.
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+    capacity: usize,
+    started: Instant,
+}
+
+impl LogBuffer {
+    /// An empty buffer keeping at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+            started: clock::now(),
+        }
+    }
+
+    /// Records an entry, dropping the oldest one when full
+    pub fn push(&self, entry: LogEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock();
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Records a message stamped with the time since the buffer was created
+    pub fn record(&self, level: Level, target: impl Into<String>, message: impl Into<String>) {
+        self.push(LogEntry {
+            elapsed: self.started.elapsed(),
+            level,
+            target: target.into(),
+            message: message.into(),
+        });
+    }
+
+    /// The entries at `level` or more severe, oldest first
+    pub fn entries(&self, level: Level) -> Vec<LogEntry> {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|entry| entry.level <= level)
+            .cloned()
+            .collect()
+    }
+
+    /// Number of entries held
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Whether no entries are held
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+
+    /// Most entries held at once
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drops every entry
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+/// A `tracing_subscriber` layer recording events into a [`LogBuffer`]
+///
+/// [`init_logging`] installs one globally; compose it yourself to add other
+/// layers, such as a file writer.
+#[derive(Debug, Clone)]
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl LogLayer {
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        self.buffer
+            .record(*metadata.level(), metadata.target(), visitor.finish());
+    }
+}
+
+/// Collects the message and fields of an event into one line
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={:?}", field.name(), value);
+    }
+}
+
+/// Installs a global `tracing` subscriber that records events in memory
+///
+/// Keeps the latest `capacity` events, readable with [`use_logs`] and
+/// [`LogOverlay`]. Fails if a global subscriber is already set, so call it
+/// once at startup, before the app takes over the terminal; `capacity` is
+/// ignored if the buffer was already created by [`global_log_buffer`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::logging::{DEFAULT_LOG_CAPACITY, init_logging};
+///
+/// init_logging(DEFAULT_LOG_CAPACITY).expect("logging is already set up");
+/// tracing::info!("app started");
+/// ```
+pub fn init_logging(capacity: usize) -> Result<LogBuffer, SetGlobalDefaultError> {
+    let buffer = LOG_BUFFER.get_or_init(|| LogBuffer::new(capacity)).clone();
+    let subscriber = tracing_subscriber::registry().with(LogLayer::new(buffer.clone()));
+    tracing::subscriber::set_global_default(subscriber)?;
+    Ok(buffer)
+}
+
+/// The buffer read by [`use_logs`], created with [`DEFAULT_LOG_CAPACITY`] on
+/// first use
+///
+/// Anything recorded here shows up in [`LogOverlay`], with or without
+/// [`init_logging`]; the runtime records captured stdout and stderr lines here.
+pub fn global_log_buffer() -> LogBuffer {
+    LOG_BUFFER
+        .get_or_init(|| LogBuffer::new(DEFAULT_LOG_CAPACITY))
+        .clone()
+}
+
+/// The buffer behind [`use_logs`], if anything created it yet
+pub fn log_buffer() -> Option<LogBuffer> {
+    LOG_BUFFER.get().cloned()
+}
+
+/// Hook for the recent log entries at `level` or more severe
+///
+/// Reads the global buffer filled by [`init_logging`] and by output captured
+/// with `RenderConfig::capture_output`, so entries survive across renders and
+/// components. Returns nothing until something is logged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::logging::{Level, use_logs};
+///
+/// let warnings = use_logs(Level::WARN);
+/// let status = format!("{} warnings", warnings.len());
+/// ```
+pub fn use_logs(level: Level) -> Vec<LogEntry> {
+    log_buffer().map_or_else(Vec::new, |buffer| buffer.entries(level))
+}
+
+/// Props for the `LogOverlay` component
+#[derive(Clone)]
+pub struct LogOverlayProps {
+    /// Shows and hides the log lines
+    pub toggle_key: KeyCode,
+    /// Least severe level shown
+    pub level: Level,
+    /// Rows taken by the log box at the bottom of the frame
+    pub height: u16,
+    /// Stacking order among portals; see [`PortalProps::z_index`]
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl Default for LogOverlayProps {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyCode::F(12),
+            level: Level::INFO,
+            height: 12,
+            z_index: 100,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl LogOverlayProps {
+    /// Sets the key showing and hiding the log lines
+    pub fn toggle_key(mut self, toggle_key: KeyCode) -> Self {
+        self.toggle_key = toggle_key;
+        self
+    }
+
+    /// Sets the least severe level shown
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the rows taken by the log box
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the stacking order among portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for LogOverlayProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Recent log lines drawn over the app, toggled with a key
+///
+/// Renders its children as usual; pressing `toggle_key` (F12 by default)
+/// the latest entries of [`use_logs`] in a box along the bottom of the frame,
+/// newest last, until pressed again.
+///
+/// ```ignore
+/// rsx! {
+///     <LogOverlay level={Level::DEBUG}>
+///         <App />
+///     </LogOverlay>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct LogOverlayComponent {
+    props: LogOverlayProps,
+}
+
+impl LogOverlayComponent {
+    pub fn new(props: LogOverlayProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for LogOverlayComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let (open, set_open) = use_state(|| false);
+        if let Some(Event::Key(key)) = use_event()
+            && key.is_press()
+            && key.code == self.props.toggle_key
+        {
+            set_open.update(|open| !open);
+        }
+
+        let children = &self.props.children;
+        if children.len() == 1 {
+            children[0].render(area, buffer);
+        } else if !children.is_empty() {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, buffer);
+            }
+        }
+
+        if !open.get() {
+            return;
+        }
+        let layer = LogLayerView {
+            props: self.props.clone(),
+        };
+        PortalComponent::new(
+            PortalProps::default()
+                .z_index(self.props.z_index)
+                .with_children(vec![Element::component(layer)]),
+        )
+        .render(area, buffer);
+    }
+}
+
+/// The box of log lines of an open overlay, rendered over the full frame
+struct LogLayerView {
+    props: LogOverlayProps,
+}
+
+fn level_style(level: Level) -> Style {
+    match level {
+        Level::ERROR => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Level::WARN => Style::default().fg(Color::Yellow),
+        Level::INFO => Style::default().fg(Color::Green),
+        Level::DEBUG => Style::default().fg(Color::Blue),
+        Level::TRACE => Style::default().fg(Color::DarkGray),
+    }
+}
+
+impl Component for LogLayerView {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let height = self.props.height.min(area.height);
+        let log_area = Rect {
+            y: area.bottom() - height,
+            height,
+            ..area
+        };
+        Clear.render(log_area, buffer);
+
+        let block = Block::default().borders(Borders::ALL).title(format!(
+            " Logs ({}+) · {} to close ",
+            self.props.level, self.props.toggle_key
+        ));
+        let inner = block.inner(log_area);
+        block.render(log_area, buffer);
+
+        let entries = use_logs(self.props.level);
+        let skip = entries.len().saturating_sub(inner.height as usize);
+        let lines: Vec<Line> = entries[skip..]
+            .iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>8.3}s ", entry.elapsed.as_secs_f64()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{:<5} ", entry.level), level_style(entry.level)),
+                    Span::styled(
+                        format!("{}: ", entry.target),
+                        Style::default().add_modifier(Modifier::DIM),
+                    ),
+                    Span::raw(entry.message.clone()),
+                ])
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buffer);
+    }
+}
+
+/// The `LogOverlay` component, usable as `<LogOverlay>` in `rsx!`
+pub type LogOverlay = LogOverlayComponent;
@@ -0,0 +1,101 @@
+//! Tests for in-app logging
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use tracing_subscriber::registry;
+
+fn entry(level: Level, message: &str) -> LogEntry {
+    LogEntry {
+        elapsed: Duration::ZERO,
+        level,
+        target: "test".to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn messages(entries: &[LogEntry]) -> Vec<&str> {
+    entries.iter().map(|entry| entry.message.as_str()).collect()
+}
+
+#[test]
+fn test_buffer_drops_oldest_entries() {
+    let buffer = LogBuffer::new(2);
+    buffer.push(entry(Level::INFO, "one"));
+    buffer.push(entry(Level::INFO, "two"));
+    buffer.push(entry(Level::INFO, "three"));
+
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(messages(&buffer.entries(Level::TRACE)), ["two", "three"]);
+
+    buffer.clear();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_entries_filter_by_level() {
+    let buffer = LogBuffer::new(10);
+    buffer.push(entry(Level::DEBUG, "debug"));
+    buffer.push(entry(Level::WARN, "warn"));
+    buffer.push(entry(Level::ERROR, "error"));
+    buffer.push(entry(Level::INFO, "info"));
+
+    assert_eq!(messages(&buffer.entries(Level::WARN)), ["warn", "error"]);
+    assert_eq!(
+        messages(&buffer.entries(Level::INFO)),
+        ["warn", "error", "info"]
+    );
+}
+
+#[test]
+fn test_layer_records_message_and_fields() {
+    let buffer = LogBuffer::new(10);
+    let subscriber = registry().with(LogLayer::new(buffer.clone()));
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::warn!(user = "alice", attempts = 3, "login failed");
+        tracing::info!(target: "sync", "done");
+    });
+
+    let entries = buffer.entries(Level::TRACE);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].level, Level::WARN);
+    assert_eq!(entries[0].message, "login failed user=\"alice\" attempts=3");
+    assert_eq!(entries[1].target, "sync");
+    assert_eq!(entries[1].message, "done");
+}
+
+#[test]
+fn test_overlay_toggles_with_key() {
+    with_test_isolate(|| {
+        // Only this test installs the global subscriber
+        init_logging(DEFAULT_LOG_CAPACITY).unwrap();
+        tracing::error!("disk full");
+        assert_eq!(messages(&use_logs(Level::ERROR)), ["disk full"]);
+
+        let overlay = LogOverlayComponent::new(LogOverlayProps::default().height(3));
+        let area = Rect::new(0, 0, 72, 5);
+        let render = || {
+            let mut buffer = Buffer::empty(area);
+            with_component_id("LogOverlayTest", |_ctx| overlay.render(area, &mut buffer));
+            reratui_core::portal::render_portals(area, &mut buffer);
+            (0..area.width)
+                .map(|x| buffer[(x, 3)].symbol())
+                .collect::<String>()
+        };
+
+        set_current_event(None);
+        assert!(!render().contains("disk full"));
+
+        let key = KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(key))));
+        let line = render();
+        set_current_event(None);
+        assert!(line.contains("ERROR"), "{line}");
+        assert!(line.contains("disk full"), "{line}");
+
+        // Stays open until the key is pressed again
+        let line = render();
+        assert!(line.contains("disk full"), "{line}");
+    });
+}
@@ -0,0 +1,106 @@
+//! Content size hook for measure-then-layout
+//!
+//! [`use_measure`] reports how much room the element a component rendered
+//! wants: the rows its content needs at the width it was given, as computed
+//! by [`Element::measure`]. The renderer measures each component's element
+//! right after the component function returns and stores the size for the
+//! next render.
+//!
+//! # One-frame lag
+//!
+//! A component only knows its size after it has rendered, so `use_measure`
+//! returns `None` on the first render and afterwards the size measured on the
+//! previous one. When the size changes, the component renders again with the
+//! new value, so layouts settle one frame after the content changes. During
+//! that frame the old size is used, which can show up as flicker when content
+//! grows or shrinks.
+//!
+//! To avoid it:
+//!
+//! - Prefer sizing on the same frame where possible: [`AutoLayout`] and
+//!   [`auto_constraints`] measure children while laying them out, with no lag.
+//! - Keep the size measured content depends on stable. A layout that changes
+//!   the width its content is measured at can keep oscillating; the renderer
+//!   only renders again when the measured size actually changes.
+//! - Render a sensible fallback for `None`, such as the full area, rather
+//!   than nothing.
+//!
+//! [`Element::measure`]: reratui_core::Element::measure
+//! [`AutoLayout`]: reratui_core::AutoLayout
+//! [`auto_constraints`]: reratui_core::auto_constraints
+
+use crate::state::{StateSetter, use_state};
+use ratatui::layout::{Rect, Size};
+use reratui_core::Element;
+use std::cell::RefCell;
+
+#[cfg(test)]
+mod tests;
+
+thread_local! {
+    /// One entry per component function running under [`measure_content`],
+    /// holding the setter of its `use_measure`, if it called one
+    static MEASURE_TARGETS: RefCell<Vec<Option<StateSetter<Option<Size>>>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// A hook returning the size of the content this component rendered last
+///
+/// The width is that of the component's area and the height the rows its
+/// element needs at that width. Returns `None` before the first render has
+/// been measured, and while the rendered element cannot be measured, such as
+/// a layout or another function component. See the
+/// [module docs](self) for the one-frame lag this implies.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use reratui::prelude::*;
+///
+/// #[component]
+/// fn LogView(lines: Vec<String>) -> Element {
+///     let area = use_area();
+///     let overflowing = use_measure().is_some_and(|size| size.height > area.height);
+///     let title = if overflowing { "Log (more below)" } else { "Log" };
+///
+///     rsx! {
+///         <Block title={title} borders={Borders::ALL}>
+///             <Paragraph wrap={Wrap { trim: false }}>{lines.join("\n")}</Paragraph>
+///         </Block>
+///     }
+/// }
+/// ```
+pub fn use_measure() -> Option<Size> {
+    let (size, set_size) = use_state(|| None);
+
+    MEASURE_TARGETS.with(|targets| {
+        if let Some(target) = targets.borrow_mut().last_mut() {
+            *target = Some(set_size);
+        }
+    });
+
+    size.get()
+}
+
+/// Runs a component function and measures the element it returns in `area`
+///
+/// Used by the renderer around each component function. If the function
+/// called [`use_measure`], the measured size is stored for its next render,
+/// which is scheduled when the size changed.
+pub fn measure_content<F>(area: Rect, render: F) -> Element
+where
+    F: FnOnce() -> Element,
+{
+    MEASURE_TARGETS.with(|targets| targets.borrow_mut().push(None));
+    let element = render();
+    let target = MEASURE_TARGETS.with(|targets| targets.borrow_mut().pop().flatten());
+
+    if let Some(set_size) = target {
+        let size = element
+            .measure(area.width)
+            .map(|height| Size::new(area.width, height));
+        set_size.set_if_changed(size);
+    }
+
+    element
+}
@@ -0,0 +1,53 @@
+//! Tests for the measure hook
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use ratatui::widgets::{Paragraph, Wrap};
+
+fn wrapped(text: &str) -> Element {
+    Element::widget(Paragraph::new(text.to_string()).wrap(Wrap { trim: true }))
+}
+
+#[test]
+fn test_use_measure_reports_previous_render() {
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 9, 10);
+        let render = || {
+            with_component_id("MeasuredText", |_ctx| {
+                let mut seen = None;
+                measure_content(area, || {
+                    seen = Some(use_measure());
+                    wrapped("one two three four")
+                });
+                seen.unwrap()
+            })
+        };
+
+        // Nothing has been measured before the first render
+        assert_eq!(render(), None);
+        assert_eq!(render(), Some(Size::new(9, 3)));
+    });
+}
+
+#[test]
+fn test_nested_component_without_use_measure() {
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 20, 5);
+        let render = || {
+            with_component_id("Outer", |_ctx| {
+                let mut seen = None;
+                measure_content(area, || {
+                    seen = Some(use_measure());
+                    // A nested component that doesn't measure leaves the
+                    // outer component's size alone
+                    measure_content(area, || wrapped("inner"));
+                    wrapped("a\nb")
+                });
+                seen.unwrap()
+            })
+        };
+
+        assert_eq!(render(), None);
+        assert_eq!(render(), Some(Size::new(20, 2)));
+    });
+}
@@ -14,7 +14,6 @@
 //! const memoizedValue = useMemo(() => computeExpensiveValue(a, b), [a, b])
 //! ```
 
-use crate::effect::EffectDependencies;
 use crate::hook_context::with_hook_context;
 
 #[cfg(test)]
@@ -82,11 +81,31 @@ impl<T> MemoState<T> {
 ///
 /// assert_eq!(area, 200);
 /// ```
+///
+/// # Dependency comparison
+///
+/// Dependencies are compared with `PartialEq` against the ones from the
+/// previous render, so any `Clone + PartialEq` type works, including your own
+/// structs. A tuple changes when any of its elements changes; the standard
+/// library implements `PartialEq` for tuples of up to 12 elements, so group
+/// longer lists into nested tuples or a struct. Passing `()` computes once,
+/// like [`use_memo_once`].
+///
+/// # Cloning
+///
+/// The cached value is cloned out on every render. For large values, memoize
+/// an `Rc` or `Arc` so the clone is cheap.
+///
+/// ```rust,ignore
+/// use std::rc::Rc;
+///
+/// let rows = use_memo(|| Rc::new(build_rows(&items)), items.len());
+/// ```
 pub fn use_memo<T, F, Deps>(factory: F, deps: impl Into<Option<Deps>>) -> T
 where
     T: Clone + 'static,
     F: FnOnce() -> T,
-    Deps: EffectDependencies + Clone + PartialEq + 'static,
+    Deps: Clone + PartialEq + 'static,
 {
     let deps = deps.into();
 
@@ -114,7 +133,7 @@ where
                     Some(prev_deps) => {
                         // Compare dependencies
                         if let Some(prev) = prev_deps.downcast_ref::<Deps>() {
-                            !current_deps.deps_eq(prev)
+                            current_deps != prev
                         } else {
                             // Type mismatch - recompute
                             true
@@ -168,3 +187,33 @@ where
 {
     use_memo::<T, F, ()>(factory, None)
 }
+
+/// Derive a value from other state, recomputing only when `deps` change
+///
+/// This is [`use_memo`] for values that follow from state the component
+/// already has, such as a count of loaded sources or a filtered list:
+/// `compute` reads that state and `deps` lists the parts it reads. On every
+/// render where `deps` equal the previous ones the cached value is returned
+/// without calling `compute`.
+///
+/// Unlike [`use_memo`], the dependencies are not optional: a derived value
+/// that never changes is a constant, so there is no compute-once form.
+/// Dependencies are not tracked automatically; a value `compute` reads but
+/// `deps` leaves out goes stale.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use reratui_hooks::memo::use_computed;
+///
+/// let loaded = [users.is_resolved(), weather.is_resolved(), stats.is_resolved()];
+/// let completed = use_computed(|| loaded.iter().filter(|done| **done).count(), loaded);
+/// ```
+pub fn use_computed<T, F, Deps>(compute: F, deps: Deps) -> T
+where
+    T: Clone + 'static,
+    F: FnOnce() -> T,
+    Deps: Clone + PartialEq + 'static,
+{
+    use_memo(compute, deps)
+}
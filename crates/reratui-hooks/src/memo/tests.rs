@@ -376,3 +376,123 @@ fn test_memo_with_result() {
 
     context.clear();
 }
+
+#[test]
+fn test_memo_custom_struct_deps() {
+    #[derive(Clone, PartialEq)]
+    struct Filter {
+        query: String,
+        limit: usize,
+    }
+
+    let context = setup_context();
+    let call_count = Arc::new(Mutex::new(0));
+
+    let render = |filter: Filter| {
+        context.reset_hook_index();
+        let call_count = call_count.clone();
+        let limit = filter.limit;
+        use_memo(
+            move || {
+                *call_count.lock().unwrap() += 1;
+                limit * 2
+            },
+            filter,
+        )
+    };
+
+    let filter = Filter {
+        query: "rust".to_string(),
+        limit: 5,
+    };
+    assert_eq!(render(filter.clone()), 10);
+    assert_eq!(render(filter.clone()), 10);
+    assert_eq!(*call_count.lock().unwrap(), 1);
+
+    assert_eq!(
+        render(Filter {
+            query: "tui".to_string(),
+            ..filter
+        }),
+        10
+    );
+    assert_eq!(*call_count.lock().unwrap(), 2);
+
+    context.clear();
+}
+
+#[test]
+fn test_memo_large_tuple_deps() {
+    let context = setup_context();
+    let call_count = Arc::new(Mutex::new(0));
+
+    let render = |last: i32| {
+        context.reset_hook_index();
+        let call_count = call_count.clone();
+        use_memo(
+            move || {
+                *call_count.lock().unwrap() += 1;
+                last
+            },
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, "eleven", last),
+        )
+    };
+
+    assert_eq!(render(12), 12);
+    assert_eq!(render(12), 12);
+    assert_eq!(*call_count.lock().unwrap(), 1);
+
+    // Changing only the last element is enough to recompute
+    assert_eq!(render(13), 13);
+    assert_eq!(*call_count.lock().unwrap(), 2);
+
+    context.clear();
+}
+
+#[test]
+fn test_memo_unit_deps_compute_once() {
+    let context = setup_context();
+    let call_count = Arc::new(Mutex::new(0));
+
+    for _ in 0..3 {
+        context.reset_hook_index();
+        let call_count = call_count.clone();
+        let titles = use_memo(
+            move || {
+                *call_count.lock().unwrap() += 1;
+                vec!["Overview", "Help"]
+            },
+            (),
+        );
+        assert_eq!(titles.len(), 2);
+    }
+    assert_eq!(*call_count.lock().unwrap(), 1);
+
+    context.clear();
+}
+
+#[test]
+fn test_computed_follows_its_dependencies() {
+    let context = setup_context();
+
+    let computations = Arc::new(Mutex::new(0));
+    let render = |loaded: [bool; 3]| {
+        context.reset_hook_index();
+        let computations = computations.clone();
+        use_computed(
+            move || {
+                *computations.lock().unwrap() += 1;
+                loaded.iter().filter(|done| **done).count()
+            },
+            loaded,
+        )
+    };
+
+    assert_eq!(render([false, false, false]), 0);
+    assert_eq!(render([false, false, false]), 0);
+    assert_eq!(*computations.lock().unwrap(), 1);
+
+    assert_eq!(render([true, false, true]), 2);
+    assert_eq!(render([true, false, true]), 2);
+    assert_eq!(*computations.lock().unwrap(), 2);
+}
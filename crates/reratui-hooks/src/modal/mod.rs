@@ -0,0 +1,235 @@
+//! Modal dialogs painted above the rest of the tree
+//!
+//! An open [`Modal`] renders its children in a centered, bordered box over a
+//! dimmed backdrop. The box is painted through a [`Portal`](reratui_core::Portal),
+//! so it covers the whole frame no matter where the modal appears in the
+//! tree. Esc and clicks on the backdrop call `on_close`; the modal never
+//! closes itself, the parent decides by passing `open`.
+//!
+//! When a [`FocusManager`] is provided, the box is a focus trap: Tab only
+//! cycles through the focusables inside it, and closing the modal returns the
+//! focus to the component that had it before.
+
+use crossterm::event::{Event, KeyCode, MouseButton, MouseEventKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, Clear, Widget};
+use reratui_core::{Component, ComponentProps, Element, PortalComponent, PortalProps};
+
+use crate::callback::Callback;
+use crate::context::{try_use_context, use_context_provider, with_context_scope};
+use crate::event::use_event;
+use crate::focus::FocusManager;
+
+#[cfg(test)]
+mod tests;
+
+/// Props for the `Modal` component
+#[derive(Clone)]
+pub struct ModalProps {
+    /// Whether the modal is shown
+    pub open: bool,
+    /// Called on Esc and on clicks outside the box
+    pub on_close: Callback<()>,
+    /// Title on the box border
+    pub title: String,
+    /// Width of the box including its border, clamped to the frame
+    pub width: u16,
+    /// Height of the box including its border, clamped to the frame
+    pub height: u16,
+    /// Stacking order relative to other portals
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl Default for ModalProps {
+    fn default() -> Self {
+        Self {
+            open: false,
+            on_close: Callback::noop(),
+            title: String::new(),
+            width: 50,
+            height: 9,
+            z_index: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl ModalProps {
+    /// Sets whether the modal is shown
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Sets the callback for Esc and backdrop clicks
+    pub fn on_close(mut self, on_close: impl Into<Callback<()>>) -> Self {
+        self.on_close = on_close.into();
+        self
+    }
+
+    /// Sets the title on the box border
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the width of the box
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the box
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the stacking order relative to other portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ModalProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// The box of `area` a modal of `width` by `height` cells occupies
+pub fn modal_area(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// A centered dialog over a dimmed backdrop
+///
+/// Components behind the modal still receive events, so they should ignore
+/// keys while it is open.
+///
+/// ```ignore
+/// rsx! {
+///     <Modal open={confirming} title="Delete user" on_close={move |_| set_confirming.set(false)}>
+///         <Paragraph>{"Delete this user? (y/n)"}</Paragraph>
+///     </Modal>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ModalComponent {
+    props: ModalProps,
+}
+
+impl ModalComponent {
+    pub fn new(props: ModalProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for ModalComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        if !self.props.open {
+            return;
+        }
+
+        // The box renders after the tree, once this component's context is gone
+        let layer = ModalLayer {
+            props: self.props.clone(),
+            focus: try_use_context::<FocusManager>(),
+        };
+        PortalComponent::new(
+            PortalProps::default()
+                .z_index(self.props.z_index)
+                .with_children(vec![Element::component(layer)]),
+        )
+        .render(area, buffer);
+    }
+}
+
+/// The backdrop and box of an open modal, rendered over the full frame
+struct ModalLayer {
+    props: ModalProps,
+    focus: Option<FocusManager>,
+}
+
+impl ModalLayer {
+    fn render_children(&self, area: Rect, buffer: &mut Buffer) {
+        let children = &self.props.children;
+        if children.len() == 1 {
+            children[0].render(area, buffer);
+        } else if !children.is_empty() {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, buffer);
+            }
+        }
+    }
+}
+
+impl Component for ModalLayer {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let dialog = modal_area(area, self.props.width, self.props.height);
+
+        match use_event() {
+            Some(Event::Key(key)) if key.is_press() && key.code == KeyCode::Esc => {
+                self.props.on_close.emit(());
+            }
+            Some(Event::Mouse(mouse))
+                if mouse.kind == MouseEventKind::Down(MouseButton::Left)
+                    && !dialog.contains(Position::new(mouse.column, mouse.row)) =>
+            {
+                self.props.on_close.emit(());
+            }
+            _ => {}
+        }
+
+        buffer.set_style(area, Style::default().add_modifier(Modifier::DIM));
+        Clear.render(dialog, buffer);
+
+        let mut block = Block::default().borders(Borders::ALL);
+        if !self.props.title.is_empty() {
+            block = block.title(self.props.title.as_str());
+        }
+        let inner = block.inner(dialog);
+        block.render(dialog, buffer);
+
+        with_context_scope(|| match &self.focus {
+            Some(focus) => {
+                // Children may call `use_focus`, so provide the manager again
+                use_context_provider(|| focus.clone());
+                focus.trap(|| self.render_children(inner, buffer));
+            }
+            None => self.render_children(inner, buffer),
+        });
+    }
+}
+
+/// The `Modal` component, usable as `<Modal open={...} on_close={...}>` in `rsx!`
+pub type Modal = ModalComponent;
@@ -0,0 +1,187 @@
+//! Tests for modal dialogs
+
+use super::*;
+use crate::event::set_current_event;
+use crate::focus::{FocusState, use_focus, use_focus_manager};
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers, MouseEvent};
+use parking_lot::Mutex;
+use ratatui::widgets::Paragraph;
+use reratui_core::portal::render_portals;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Renders `modal` like the runtime does: the tree first, then the portals
+fn render_frame(modal: &Modal, area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("ModalTest", |_ctx| {
+        modal.render(area, &mut buffer);
+        render_portals(area, &mut buffer);
+    });
+    buffer
+}
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect()
+}
+
+fn counting_modal(closes: &Arc<AtomicUsize>) -> Modal {
+    let closes = closes.clone();
+    ModalComponent::new(
+        ModalProps::default()
+            .open(true)
+            .width(6)
+            .height(3)
+            .on_close(move |_| {
+                closes.fetch_add(1, Ordering::SeqCst);
+            }),
+    )
+}
+
+#[test]
+fn test_modal_area_is_centered_and_clamped() {
+    let area = Rect::new(0, 0, 20, 10);
+    assert_eq!(modal_area(area, 10, 4), Rect::new(5, 3, 10, 4));
+    assert_eq!(modal_area(area, 30, 30), area);
+}
+
+#[test]
+fn test_modal_paints_box_over_dimmed_backdrop() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let area = Rect::new(0, 0, 10, 5);
+
+        let closed = ModalComponent::new(ModalProps::default().width(8).height(3));
+        let buffer = render_frame(&closed, area);
+        assert_eq!(buffer, Buffer::empty(area));
+
+        let open = ModalComponent::new(
+            ModalProps::default()
+                .open(true)
+                .title("Hi")
+                .width(8)
+                .height(3)
+                .with_children(vec![Element::widget(Paragraph::new("body"))]),
+        );
+        let buffer = render_frame(&open, area);
+        assert_eq!(row(&buffer, 1), " ┌Hi────┐ ");
+        assert_eq!(row(&buffer, 2), " │body  │ ");
+        assert_eq!(row(&buffer, 3), " └──────┘ ");
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::DIM));
+    });
+}
+
+#[test]
+fn test_modal_closes_on_esc_and_backdrop_click() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 10, 5);
+        let closes = Arc::new(AtomicUsize::new(0));
+        let modal = counting_modal(&closes);
+
+        let click = |column, row| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        // Inside the box
+        set_current_event(Some(Arc::new(click(4, 2))));
+        render_frame(&modal, area);
+        assert_eq!(closes.load(Ordering::SeqCst), 0);
+
+        set_current_event(Some(Arc::new(click(0, 0))));
+        render_frame(&modal, area);
+        assert_eq!(closes.load(Ordering::SeqCst), 1);
+
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(esc))));
+        render_frame(&modal, area);
+        set_current_event(None);
+        assert_eq!(closes.load(Ordering::SeqCst), 2);
+    });
+}
+
+/// A focusable that reports its focus state
+#[derive(Clone)]
+struct Focusable {
+    order: i32,
+    state: Arc<Mutex<Option<FocusState>>>,
+}
+
+impl Focusable {
+    fn new(order: i32) -> Self {
+        Self {
+            order,
+            state: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.state.lock().as_ref().is_some_and(|s| s.is_focused())
+    }
+}
+
+impl Component for Focusable {
+    fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+        *self.state.lock() = Some(use_focus(self.order));
+    }
+}
+
+#[test]
+fn test_modal_traps_and_restores_focus() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let area = Rect::new(0, 0, 10, 5);
+        let (first, second) = (Focusable::new(0), Focusable::new(1));
+        let (inside_a, inside_b) = (Focusable::new(0), Focusable::new(1));
+
+        let render = |open: bool| {
+            let modal = ModalComponent::new(ModalProps::default().open(open).with_children(vec![
+                Element::component(inside_a.clone()),
+                Element::component(inside_b.clone()),
+            ]));
+            let mut buffer = Buffer::empty(area);
+            with_component_id("FocusRoot", |_ctx| {
+                use_focus_manager();
+                first.render(area, &mut buffer);
+                second.render(area, &mut buffer);
+                modal.render(area, &mut buffer);
+                render_portals(area, &mut buffer);
+            });
+        };
+
+        render(false);
+        render(false);
+        second.state.lock().as_ref().unwrap().focus();
+
+        // The trap takes over from the next frame
+        render(true);
+        render(true);
+        assert!(inside_a.is_focused());
+        assert!(!second.is_focused());
+
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        for _ in 0..2 {
+            set_current_event(Some(Arc::new(Event::Key(tab))));
+            render(true);
+        }
+        set_current_event(None);
+        render(true);
+        assert!(inside_a.is_focused());
+
+        render(false);
+        render(false);
+        assert!(second.is_focused());
+    });
+}
@@ -0,0 +1,154 @@
+//! Mouse gesture hook
+//!
+//! Turns raw mouse events into clicks, double-clicks, drags and hover state
+//! for a single area.
+
+use crate::clock;
+use crate::{event::use_event, ref_hook::use_ref};
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use std::time::{Duration, Instant};
+
+/// Default maximum delay between the two clicks of a double-click
+pub const DEFAULT_DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
+/// Gestures detected for an area during the current frame
+///
+/// Event flags such as `clicked` or `drag_end` are only set on the frame the
+/// corresponding mouse event arrives; `hovering`, `is_dragging` and
+/// `drag_start` persist across frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MouseGestures {
+    /// The left button was pressed inside the area
+    pub clicked: bool,
+    /// The left button was pressed inside the area twice within the window
+    pub double_clicked: bool,
+    /// The cursor is inside the area
+    pub hovering: bool,
+    /// A drag that started inside the area is in progress
+    pub is_dragging: bool,
+    /// Where the current (or just finished) drag started
+    pub drag_start: Option<(u16, u16)>,
+    /// Movement since the previous drag event, set on frames where the drag moved
+    pub drag_delta: Option<(i32, i32)>,
+    /// Where the drag ended, set on the frame the button was released
+    pub drag_end: Option<(u16, u16)>,
+    /// Last known cursor position, inside or outside the area
+    pub position: Option<(u16, u16)>,
+}
+
+/// Gesture tracking state kept between frames
+#[derive(Debug, Default)]
+pub(crate) struct GestureState {
+    /// Where the left button went down inside the area, until it is released
+    pressed_at: Option<(u16, u16)>,
+    /// Whether the pressed button has moved since going down
+    dragging: bool,
+    /// Time of the last click inside the area, for double-click detection
+    last_click: Option<Instant>,
+    hovering: bool,
+    position: Option<(u16, u16)>,
+}
+
+impl GestureState {
+    /// Feed one frame's mouse event (if any) and report the gestures
+    pub(crate) fn apply(
+        &mut self,
+        event: Option<&MouseEvent>,
+        area: Rect,
+        window: Duration,
+        now: Instant,
+    ) -> MouseGestures {
+        let mut gestures = MouseGestures::default();
+
+        if let Some(event) = event {
+            let pos = (event.column, event.row);
+            let previous = self.position.replace(pos);
+            self.hovering = area.contains(Position::new(event.column, event.row));
+
+            match event.kind {
+                MouseEventKind::Down(MouseButton::Left) if self.hovering => {
+                    gestures.clicked = true;
+                    gestures.double_clicked = self
+                        .last_click
+                        .is_some_and(|last| now.duration_since(last) <= window);
+                    // A triple click starts a new pair instead of a second double-click
+                    self.last_click = (!gestures.double_clicked).then_some(now);
+                    self.pressed_at = Some(pos);
+                    self.dragging = false;
+                }
+                // Drags keep reporting outside the area until the button is released
+                MouseEventKind::Drag(MouseButton::Left) if self.pressed_at.is_some() => {
+                    self.dragging = true;
+                    let (prev_x, prev_y) = previous.unwrap_or(pos);
+                    gestures.drag_delta = Some((
+                        i32::from(pos.0) - i32::from(prev_x),
+                        i32::from(pos.1) - i32::from(prev_y),
+                    ));
+                }
+                MouseEventKind::Up(MouseButton::Left) => {
+                    if let Some(start) = self.pressed_at.take()
+                        && std::mem::take(&mut self.dragging)
+                    {
+                        gestures.drag_start = Some(start);
+                        gestures.drag_end = Some(pos);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        gestures.hovering = self.hovering;
+        gestures.position = self.position;
+        gestures.is_dragging = self.dragging;
+        if self.dragging {
+            gestures.drag_start = self.pressed_at;
+        }
+        gestures
+    }
+}
+
+/// A hook that detects clicks, double-clicks, drags and hovering over an area.
+///
+/// Uses [`DEFAULT_DOUBLE_CLICK_WINDOW`] for double-clicks; see
+/// [`use_mouse_gestures_with_window`] to configure it.
+///
+/// Only the left button is considered. A drag starts when the button is
+/// pressed inside `area` and the mouse moves; it keeps reporting
+/// `drag_delta` even after leaving the area, until the button is released.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::mouse::use_mouse_gestures;
+/// use ratatui::layout::Rect;
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let gestures = use_mouse_gestures(area);
+///
+/// if gestures.double_clicked {
+///     println!("Opening item");
+/// }
+/// if let (Some((dx, dy)), Some(pos)) = (gestures.drag_delta, gestures.position) {
+///     println!("Dragged by ({dx}, {dy}) to {pos:?}");
+/// }
+/// ```
+pub fn use_mouse_gestures(area: Rect) -> MouseGestures {
+    use_mouse_gestures_with_window(area, DEFAULT_DOUBLE_CLICK_WINDOW)
+}
+
+/// A hook like [`use_mouse_gestures`] with a custom double-click window.
+///
+/// # Arguments
+///
+/// * `area` - The area to detect gestures in
+/// * `double_click_window` - Maximum delay between the two clicks of a double-click
+pub fn use_mouse_gestures_with_window(area: Rect, double_click_window: Duration) -> MouseGestures {
+    let state = use_ref(GestureState::default);
+    let event = match use_event() {
+        Some(Event::Mouse(mouse_event)) => Some(mouse_event),
+        _ => None,
+    };
+
+    state.with_mut(|state| state.apply(event.as_ref(), area, double_click_window, clock::now()))
+}
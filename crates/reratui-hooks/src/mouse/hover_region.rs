@@ -0,0 +1,118 @@
+//! Hover regions with z-order
+//!
+//! Every `use_hover_region` call claims its area in a per-frame registry kept
+//! in the [`HookContext`](crate::hook_context::HookContext), so overlapping
+//! regions can agree on which one is on top.
+
+use crate::{event::use_event, hook_context::with_hook_context};
+use crossterm::event::Event;
+use ratatui::layout::{Position, Rect};
+
+/// A region claimed by a `use_hover_region` call
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct HoverRegion {
+    /// Hook index of the claiming call, stable across frames
+    id: usize,
+    area: Rect,
+    z: i32,
+}
+
+impl HoverRegion {
+    /// Whether this region is drawn above `other`
+    ///
+    /// Higher z wins; with equal z the region claimed later (rendered on top)
+    /// wins.
+    fn is_above(&self, other: &HoverRegion) -> bool {
+        (self.z, self.id) > (other.z, other.id)
+    }
+}
+
+/// Hover regions claimed during the current and previous frame
+#[derive(Debug, Default)]
+pub(crate) struct HoverRegistry {
+    current: Vec<HoverRegion>,
+    previous: Vec<HoverRegion>,
+    /// Last known cursor position
+    cursor: Option<Position>,
+}
+
+impl HoverRegistry {
+    /// Start a new frame, keeping the regions claimed in the last one
+    pub(crate) fn next_frame(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+    }
+
+    /// Claim a region and report whether it is the topmost one under the cursor
+    ///
+    /// Regions rendered later in the frame are not known yet, so regions from
+    /// the previous frame are consulted as well. An overlay rendered after the
+    /// content beneath it therefore blocks hover from its second frame on.
+    fn claim(&mut self, region: HoverRegion) -> bool {
+        self.current.retain(|claimed| claimed.id != region.id);
+        self.current.push(region);
+
+        let Some(cursor) = self.cursor else {
+            return false;
+        };
+        region.area.contains(cursor)
+            && !self
+                .current
+                .iter()
+                .chain(&self.previous)
+                .filter(|other| other.id != region.id && other.area.contains(cursor))
+                .any(|other| other.is_above(&region))
+    }
+
+    fn set_cursor(&mut self, cursor: Position) {
+        self.cursor = Some(cursor);
+    }
+}
+
+/// A hook that reports whether the cursor hovers an area, respecting overlaps.
+///
+/// Unlike [`use_mouse_hover`](super::use_mouse_hover), regions claimed with
+/// this hook know about each other: when several contain the cursor, only the
+/// one with the highest `z` returns `true`. Among regions with the same `z`,
+/// the one rendered last wins. This keeps cards underneath a modal or tooltip
+/// from showing hover highlights.
+///
+/// # Arguments
+///
+/// * `area` - The area to track, usually the component's render area
+/// * `z` - Stacking order; overlays should use a higher value than content
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::{area::use_area, mouse::use_hover_region};
+///
+/// // Inside a card component
+/// let card_hovered = use_hover_region(*use_area(), 0);
+///
+/// // Inside a modal drawn over the cards
+/// let modal_hovered = use_hover_region(*use_area(), 10);
+/// ```
+///
+/// # Notes
+///
+/// - Every region must call this hook on every frame it is visible
+/// - Overlap is resolved using the regions claimed so far in this frame plus
+///   those of the previous frame, so a newly shown overlay starts blocking
+///   the content rendered before it one frame later, and a closed one stops
+///   blocking one frame later
+/// - The cursor position is taken from the latest mouse event, including
+///   plain movement
+pub fn use_hover_region(area: Rect, z: i32) -> bool {
+    let event = use_event();
+
+    with_hook_context(|ctx| {
+        let id = ctx.next_hook_index();
+        let mut registry = ctx.hover_regions.borrow_mut();
+
+        if let Some(Event::Mouse(mouse_event)) = event {
+            registry.set_cursor(Position::new(mouse_event.column, mouse_event.row));
+        }
+
+        registry.claim(HoverRegion { id, area, z })
+    })
+}
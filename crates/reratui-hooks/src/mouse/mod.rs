@@ -2,15 +2,22 @@
 //!
 //! Provides a convenient hook for handling mouse events with stable callbacks.
 
+use crate::clock;
 use crate::{
     effect_event::use_effect_event, event::use_event, ref_hook::use_ref, state::use_state,
 };
 use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
 use std::time::{Duration, Instant};
 
+mod gestures;
+mod hover_region;
+
 #[cfg(test)]
 mod tests;
 
+pub use gestures::*;
+pub use hover_region::*;
+
 /// A hook that handles mouse events with a stable callback.
 ///
 /// This hook uses `use_effect_event` internally to ensure the callback always
@@ -278,7 +285,7 @@ where
 
     use_mouse(move |mouse_event| {
         if let MouseEventKind::Down(button) = mouse_event.kind {
-            let now = Instant::now();
+            let now = clock::now();
             let current_pos = (mouse_event.column, mouse_event.row);
 
             if let Some((last_button, last_x, last_y, last_time)) = last_click.get() {
@@ -382,19 +389,13 @@ pub fn use_mouse_position() -> (u16, u16) {
 pub fn use_mouse_hover(area: ratatui::layout::Rect) -> bool {
     let (is_hovering, set_hovering) = use_state(|| false);
 
-    use_mouse({
-        let is_hovering = is_hovering.clone();
-
-        move |mouse_event| {
-            let is_inside = mouse_event.column >= area.x
-                && mouse_event.column < area.x + area.width
-                && mouse_event.row >= area.y
-                && mouse_event.row < area.y + area.height;
+    use_mouse(move |mouse_event| {
+        let is_inside = mouse_event.column >= area.x
+            && mouse_event.column < area.x + area.width
+            && mouse_event.row >= area.y
+            && mouse_event.row < area.y + area.height;
 
-            if is_inside != is_hovering.get() {
-                set_hovering.set(is_inside);
-            }
-        }
+        set_hovering.set_if_changed(is_inside);
     });
 
     is_hovering.get()
@@ -9,6 +9,7 @@ use crate::{
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use parking_lot::Mutex;
 use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
 // Test mutex to prevent parallel test execution
 static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
@@ -942,3 +943,215 @@ fn test_use_mouse_hover_corner_cases() {
         });
     });
 }
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+#[test]
+fn test_gestures_click_and_double_click() {
+    let area = ratatui::layout::Rect::new(10, 10, 10, 5);
+    let window = Duration::from_millis(400);
+    let now = Instant::now();
+    let mut state = GestureState::default();
+    let down = mouse(MouseEventKind::Down(MouseButton::Left), 12, 11);
+
+    let first = state.apply(Some(&down), area, window, now);
+    assert!(first.clicked && first.hovering);
+    assert!(!first.double_clicked);
+
+    // Event flags only last for the frame the event arrived
+    let idle = state.apply(None, area, window, now);
+    assert!(!idle.clicked && idle.hovering);
+
+    // Second click within the window, anywhere in the area
+    let second = mouse(MouseEventKind::Down(MouseButton::Left), 15, 12);
+    assert!(
+        state
+            .apply(
+                Some(&second),
+                area,
+                window,
+                now + Duration::from_millis(200)
+            )
+            .double_clicked
+    );
+
+    // A third click starts a new pair
+    assert!(
+        !state
+            .apply(Some(&down), area, window, now + Duration::from_millis(300))
+            .double_clicked
+    );
+
+    // Clicks too far apart are two single clicks
+    let late = now + Duration::from_secs(2);
+    assert!(!state.apply(Some(&down), area, window, late).double_clicked);
+
+    // Clicks outside the area are ignored
+    let outside = mouse(MouseEventKind::Down(MouseButton::Left), 0, 0);
+    let gestures = state.apply(Some(&outside), area, window, late);
+    assert!(!gestures.clicked && !gestures.hovering);
+}
+
+#[test]
+fn test_gestures_drag_leaving_area() {
+    let area = ratatui::layout::Rect::new(10, 10, 10, 5);
+    let window = Duration::from_millis(400);
+    let now = Instant::now();
+    let mut state = GestureState::default();
+
+    state.apply(
+        Some(&mouse(MouseEventKind::Down(MouseButton::Left), 12, 12)),
+        area,
+        window,
+        now,
+    );
+
+    let moved = state.apply(
+        Some(&mouse(MouseEventKind::Drag(MouseButton::Left), 14, 13)),
+        area,
+        window,
+        now,
+    );
+    assert!(moved.is_dragging);
+    assert_eq!(moved.drag_start, Some((12, 12)));
+    assert_eq!(moved.drag_delta, Some((2, 1)));
+
+    // Leaving the area keeps reporting deltas
+    let outside = state.apply(
+        Some(&mouse(MouseEventKind::Drag(MouseButton::Left), 30, 2)),
+        area,
+        window,
+        now,
+    );
+    assert!(!outside.hovering);
+    assert_eq!(outside.drag_delta, Some((16, -11)));
+
+    let idle = state.apply(None, area, window, now);
+    assert!(idle.is_dragging);
+    assert_eq!(idle.drag_delta, None);
+
+    let ended = state.apply(
+        Some(&mouse(MouseEventKind::Up(MouseButton::Left), 31, 2)),
+        area,
+        window,
+        now,
+    );
+    assert!(!ended.is_dragging);
+    assert_eq!(ended.drag_start, Some((12, 12)));
+    assert_eq!(ended.drag_end, Some((31, 2)));
+
+    // Drags starting outside the area are not tracked
+    state.apply(
+        Some(&mouse(MouseEventKind::Down(MouseButton::Left), 0, 0)),
+        area,
+        window,
+        now,
+    );
+    let foreign = state.apply(
+        Some(&mouse(MouseEventKind::Drag(MouseButton::Left), 12, 12)),
+        area,
+        window,
+        now,
+    );
+    assert!(!foreign.is_dragging);
+    assert_eq!(foreign.drag_delta, None);
+}
+
+#[test]
+fn test_use_mouse_gestures() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let area = ratatui::layout::Rect::new(0, 0, 20, 10);
+
+        set_current_event(Some(Arc::new(Event::Mouse(mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            5,
+            5,
+        )))));
+        let gestures = with_component_id("MouseGesturesTest", |_ctx| use_mouse_gestures(area));
+        assert!(gestures.clicked);
+
+        set_current_event(Some(Arc::new(Event::Mouse(mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            5,
+            5,
+        )))));
+        let gestures = with_component_id("MouseGesturesTest", |_ctx| use_mouse_gestures(area));
+        assert!(gestures.double_clicked);
+
+        set_current_event(None);
+    });
+}
+
+#[test]
+fn test_use_hover_region_topmost_wins() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let card = ratatui::layout::Rect::new(0, 0, 20, 10);
+        let other_card = ratatui::layout::Rect::new(20, 0, 20, 10);
+        let modal = ratatui::layout::Rect::new(5, 2, 20, 5);
+
+        let render = |show_modal: bool| {
+            with_component_id("HoverRegionTest", |_ctx| {
+                let card = use_hover_region(card, 0);
+                let other_card = use_hover_region(other_card, 0);
+                let modal = show_modal.then(|| use_hover_region(modal, 10));
+                (card, other_card, modal)
+            })
+        };
+
+        // Cursor over the card only
+        set_current_event(Some(Arc::new(Event::Mouse(mouse(
+            MouseEventKind::Moved,
+            2,
+            1,
+        )))));
+        assert_eq!(render(false), (true, false, None));
+
+        // Cursor over both the card and the modal: the modal wins once it
+        // has been rendered for a frame
+        set_current_event(Some(Arc::new(Event::Mouse(mouse(
+            MouseEventKind::Moved,
+            10,
+            3,
+        )))));
+        assert_eq!(render(true).2, Some(true));
+        set_current_event(None);
+        assert_eq!(render(true), (false, false, Some(true)));
+
+        // Once the modal closes, the card is hovered again after one frame
+        render(false);
+        assert_eq!(render(false), (true, false, None));
+    });
+}
+
+#[test]
+fn test_use_hover_region_equal_z_prefers_later() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let below = ratatui::layout::Rect::new(0, 0, 10, 10);
+        let above = ratatui::layout::Rect::new(5, 5, 10, 10);
+
+        set_current_event(Some(Arc::new(Event::Mouse(mouse(
+            MouseEventKind::Moved,
+            6,
+            6,
+        )))));
+        let render = || {
+            with_component_id("HoverRegionTieTest", |_ctx| {
+                (use_hover_region(below, 1), use_hover_region(above, 1))
+            })
+        };
+        render();
+        assert_eq!(render(), (false, true));
+
+        set_current_event(None);
+    });
+}
@@ -1,12 +1,17 @@
+use crate::clock;
 pub use parking_lot::Mutex;
 pub use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
 use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
 use uuid::Uuid;
 
 use crate::memo::use_memo_once;
+use crate::panic_handler::spawn_registered;
 
 #[cfg(test)]
 pub mod tests;
@@ -44,6 +49,8 @@ pub type OnErrorCallback<TError, TVariables> =
     Box<dyn Fn(&TError, &TVariables, &MutationContext) + Send + Sync>;
 
 /// Type alias for settled callback function
+///
+/// Called with neither data nor error when the mutation was cancelled.
 pub type OnSettledCallback<TData, TError, TVariables> =
     Box<dyn Fn(Option<&TData>, Option<&TError>, &TVariables, &MutationContext) + Send + Sync>;
 
@@ -51,6 +58,97 @@ pub type OnSettledCallback<TData, TError, TVariables> =
 pub type OnMutateCallback<TVariables> =
     Box<dyn Fn(&TVariables) -> Option<MutationContext> + Send + Sync>;
 
+/// Queue that runs mutations sharing it one at a time
+///
+/// Mutations with the same scope id wait for the ones triggered before them
+/// to settle, in the order they were triggered, so creating an entity and
+/// then updating it can't race. A queued mutation calls `on_mutate` and turns
+/// `Pending` only once its turn comes. Mutations without a scope run
+/// concurrently.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MutationScope {
+    id: String,
+}
+
+impl MutationScope {
+    /// Create a scope; mutations sharing the id share the queue
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+
+    /// The scope's id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Take a place at the back of the queue
+    fn enqueue(&self) -> ScopeTurn {
+        let ticket = NEXT_TICKET.fetch_add(1, Ordering::Relaxed);
+        let (release, settled) = oneshot::channel();
+        let ahead = SCOPE_QUEUES
+            .lock()
+            .insert(self.clone(), (ticket, settled))
+            .map(|(_, settled)| settled);
+        ScopeTurn {
+            scope: self.clone(),
+            ticket,
+            ahead,
+            _release: release,
+        }
+    }
+}
+
+/// A queued mutation's ticket and a receiver resolving once it settles
+type QueueTail = (u64, oneshot::Receiver<()>);
+
+/// The last mutation queued in each scope
+static SCOPE_QUEUES: LazyLock<Mutex<HashMap<MutationScope, QueueTail>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_TICKET: AtomicU64 = AtomicU64::new(0);
+
+/// A mutation's place in its scope's queue
+///
+/// Dropping it, once the mutation settles or is aborted, releases the
+/// mutation queued behind it.
+struct ScopeTurn {
+    scope: MutationScope,
+    ticket: u64,
+    ahead: Option<oneshot::Receiver<()>>,
+    _release: oneshot::Sender<()>,
+}
+
+impl ScopeTurn {
+    /// Wait for the mutation ahead to settle
+    async fn wait(&mut self) {
+        if let Some(ahead) = self.ahead.take() {
+            let _ = ahead.await;
+        }
+    }
+}
+
+impl Drop for ScopeTurn {
+    fn drop(&mut self) {
+        // Forget the scope once nothing is queued behind this mutation
+        let mut queues = SCOPE_QUEUES.lock();
+        if queues
+            .get(&self.scope)
+            .is_some_and(|(ticket, _)| *ticket == self.ticket)
+        {
+            queues.remove(&self.scope);
+        }
+    }
+}
+
+/// A triggered mutation's variables and context
+///
+/// Taken by whichever comes first of the mutation settling and its
+/// cancellation, so `on_settled` runs exactly once.
+type Settlement<TVariables> = Arc<Mutex<Option<(TVariables, MutationContext)>>>;
+
+/// A mutation triggered with `mutate`: its task and settlement
+type Running<TVariables> = (tokio::task::JoinHandle<()>, Settlement<TVariables>);
+
 /// Type alias for mutation function
 pub type MutationFn<TData, TError, TVariables> = Arc<
     dyn Fn(TVariables) -> Pin<Box<dyn Future<Output = Result<TData, TError>> + Send>> + Send + Sync,
@@ -67,7 +165,7 @@ where
     pub on_success: Option<OnSuccessCallback<TData, TVariables>>,
     /// Callback called when mutation fails
     pub on_error: Option<OnErrorCallback<TError, TVariables>>,
-    /// Callback called when mutation settles (success or error)
+    /// Callback called when mutation settles (success, error or cancellation)
     pub on_settled: Option<OnSettledCallback<TData, TError, TVariables>>,
     /// Callback called before mutation starts
     pub on_mutate: Option<OnMutateCallback<TVariables>>,
@@ -81,6 +179,8 @@ where
     pub retry_exponential_backoff: bool,
     /// Maximum delay for exponential backoff (default: 30 seconds)
     pub retry_max_delay: Duration,
+    /// Queue to run in, one mutation at a time (default: none)
+    pub scope: Option<MutationScope>,
 }
 
 impl<TData, TError, TVariables> Default for MutationOptions<TData, TError, TVariables>
@@ -100,6 +200,7 @@ where
             retry_delay: Duration::from_millis(1000),
             retry_exponential_backoff: false,
             retry_max_delay: Duration::from_secs(30),
+            scope: None,
         }
     }
 }
@@ -132,6 +233,7 @@ where
     retry_delay: Duration,
     retry_exponential_backoff: bool,
     retry_max_delay: Duration,
+    scope: Option<MutationScope>,
 }
 
 impl<TData, TError, TVariables> MutationOptionsBuilder<TData, TError, TVariables>
@@ -152,6 +254,7 @@ where
             retry_delay: Duration::from_millis(1000),
             retry_exponential_backoff: false,
             retry_max_delay: Duration::from_secs(30),
+            scope: None,
         }
     }
 
@@ -189,7 +292,9 @@ where
         self
     }
 
-    /// Set the settled callback (called on both success and error)
+    /// Set the settled callback (called on success, error and cancellation)
+    ///
+    /// A cancelled mutation settles with neither data nor error.
     ///
     /// # Example
     /// ```rust,ignore
@@ -293,6 +398,18 @@ where
         self
     }
 
+    /// Set the scope whose mutations run one at a time
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// MutationOptions::builder()
+    ///     .scope(MutationScope::new("user"))
+    /// ```
+    pub fn scope(mut self, scope: MutationScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
     /// Build the final MutationOptions
     pub fn build(self) -> MutationOptions<TData, TError, TVariables> {
         MutationOptions {
@@ -305,6 +422,7 @@ where
             retry_delay: self.retry_delay,
             retry_exponential_backoff: self.retry_exponential_backoff,
             retry_max_delay: self.retry_max_delay,
+            scope: self.scope,
         }
     }
 }
@@ -411,8 +529,8 @@ where
     state: Arc<Mutex<MutationResult<TData, TError, TVariables>>>,
     mutation_fn: MutationFn<TData, TError, TVariables>,
     options: Arc<MutationOptions<TData, TError, TVariables>>,
-    /// Handle to the currently running mutation task (if any)
-    task_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// The last mutation triggered with `mutate`: its task and settlement
+    running: Arc<Mutex<Option<Running<TVariables>>>>,
 }
 
 impl<TData, TError, TVariables> Clone for Mutation<TData, TError, TVariables>
@@ -426,7 +544,7 @@ where
             state: Arc::clone(&self.state),
             mutation_fn: Arc::clone(&self.mutation_fn),
             options: Arc::clone(&self.options),
-            task_handle: Arc::clone(&self.task_handle),
+            running: Arc::clone(&self.running),
         }
     }
 }
@@ -454,7 +572,7 @@ where
             state: Arc::new(Mutex::new(MutationResult::default())),
             mutation_fn: boxed_fn,
             options: Arc::new(options.unwrap_or_default()),
-            task_handle: Arc::new(Mutex::new(None)),
+            running: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -474,16 +592,28 @@ where
 
     /// Cancel the currently running mutation
     ///
-    /// If a mutation is currently executing, it will be aborted and the state
-    /// will be updated to Cancelled.
+    /// If a mutation is currently executing or queued, it will be aborted, the
+    /// state will be updated to Cancelled and `on_settled` is called with
+    /// neither data nor error.
     pub fn cancel(&self) {
-        if let Some(handle) = self.task_handle.lock().take() {
-            handle.abort();
+        let Some((handle, settlement)) = self.running.lock().take() else {
+            return;
+        };
+        // Nothing to cancel once the mutation has settled
+        let Some((variables, context)) = settlement.lock().take() else {
+            return;
+        };
+        handle.abort();
 
+        {
             let mut state = self.state.lock();
             state.update_status(MutationStatus::Cancelled);
             state.failure_reason = Some("Mutation cancelled by user".to_string());
         }
+
+        if let Some(on_settled) = &self.options.on_settled {
+            on_settled(None, None, &variables, &context);
+        }
     }
 
     /// Trigger a mutation (fire and forget)
@@ -491,14 +621,35 @@ where
         let state = Arc::clone(&self.state);
         let mutation_fn = Arc::clone(&self.mutation_fn);
         let options = Arc::clone(&self.options);
-        let task_handle = Arc::clone(&self.task_handle);
+
+        // Record the variables before spawning, so a cancellation before the
+        // task first runs still settles them
+        let context = MutationContext {
+            mutation_id: Uuid::new_v4(),
+            started_at: clock::now(),
+        };
+        let settlement: Settlement<TVariables> =
+            Arc::new(Mutex::new(Some((variables.clone(), context.clone()))));
+
+        // Queue up now, so mutations in a scope run in the order triggered
+        let turn = options.scope.as_ref().map(MutationScope::enqueue);
 
         // Spawn the mutation task and store the handle
-        let handle = tokio::spawn(async move {
-            Self::execute_mutation(state, mutation_fn, options, variables).await;
+        let task_settlement = Arc::clone(&settlement);
+        let handle = spawn_registered(async move {
+            let _ = Self::execute_mutation_async(
+                state,
+                mutation_fn,
+                options,
+                variables,
+                context,
+                turn,
+                Some(task_settlement),
+            )
+            .await;
         });
 
-        *task_handle.lock() = Some(handle);
+        *self.running.lock() = Some((handle, settlement));
     }
 
     /// Trigger a mutation and return a future that resolves when complete
@@ -506,17 +657,21 @@ where
         let state = Arc::clone(&self.state);
         let mutation_fn = Arc::clone(&self.mutation_fn);
         let options = Arc::clone(&self.options);
+        let context = MutationContext {
+            mutation_id: Uuid::new_v4(),
+            started_at: clock::now(),
+        };
+        let turn = options.scope.as_ref().map(MutationScope::enqueue);
 
-        Self::execute_mutation_async(state, mutation_fn, options, variables).await
+        Self::execute_mutation_async(state, mutation_fn, options, variables, context, turn, None)
+            .await
     }
 
-    async fn execute_mutation(
-        state: Arc<Mutex<MutationResult<TData, TError, TVariables>>>,
-        mutation_fn: MutationFn<TData, TError, TVariables>,
-        options: Arc<MutationOptions<TData, TError, TVariables>>,
-        variables: TVariables,
-    ) {
-        let _ = Self::execute_mutation_async(state, mutation_fn, options, variables).await;
+    /// Takes the settlement, false if a cancellation took it first
+    fn claim_settlement(settlement: &Option<Settlement<TVariables>>) -> bool {
+        settlement
+            .as_ref()
+            .is_none_or(|settlement| settlement.lock().take().is_some())
     }
 
     async fn execute_mutation_async(
@@ -524,22 +679,34 @@ where
         mutation_fn: MutationFn<TData, TError, TVariables>,
         options: Arc<MutationOptions<TData, TError, TVariables>>,
         variables: TVariables,
+        default_context: MutationContext,
+        turn: Option<ScopeTurn>,
+        settlement: Option<Settlement<TVariables>>,
     ) -> Result<TData, TError> {
-        let mutation_id = Uuid::new_v4();
-        let started_at = Instant::now();
+        // Wait for the mutation ahead in the scope to settle before starting,
+        // so optimistic updates apply in order too; the turn is dropped once
+        // this one settles or is aborted, releasing the next
+        let mut turn = turn;
+        if let Some(turn) = &mut turn {
+            turn.wait().await;
+        }
+
+        let started_at = clock::now();
+        let default_context = MutationContext {
+            started_at,
+            ..default_context
+        };
 
         // Call on_mutate callback and use custom context if provided
-        let context = if let Some(on_mutate) = &options.on_mutate {
-            on_mutate(&variables).unwrap_or(MutationContext {
-                mutation_id,
-                started_at,
-            })
-        } else {
-            MutationContext {
-                mutation_id,
-                started_at,
-            }
+        let context = match &options.on_mutate {
+            Some(on_mutate) => on_mutate(&variables).unwrap_or(default_context),
+            None => default_context,
         };
+        if let Some(settlement) = &settlement
+            && let Some((_, settled_context)) = settlement.lock().as_mut()
+        {
+            *settled_context = context.clone();
+        }
 
         // Update state to pending
         {
@@ -567,6 +734,11 @@ where
 
             match result {
                 Ok(data) => {
+                    // A cancellation that raced the mutation settled it already
+                    if !Self::claim_settlement(&settlement) {
+                        return Ok(data);
+                    }
+
                     // Success - update state
                     {
                         let mut state_guard = state.lock();
@@ -606,10 +778,14 @@ where
                         };
 
                         // Retry after calculated delay
-                        tokio::time::sleep(delay).await;
+                        clock::sleep(delay).await;
                         continue;
                     }
 
+                    if !Self::claim_settlement(&settlement) {
+                        return Err(error);
+                    }
+
                     // Final failure - update state
                     {
                         let mut state_guard = state.lock();
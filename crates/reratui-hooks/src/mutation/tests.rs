@@ -1002,3 +1002,224 @@ async fn test_no_exponential_backoff_by_default() {
     // Should be close to 300ms (not exponential)
     assert!(total_duration < Duration::from_millis(600));
 }
+
+#[tokio::test]
+async fn test_on_settled_called_on_cancel() {
+    let settled = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mutation = Mutation::new(
+        |variables: TestVariables| async move { mock_long_running_fn(variables).await },
+        Some(
+            MutationOptions::builder()
+                .on_settled({
+                    let settled = settled.clone();
+                    move |data, error, variables: &TestVariables, _context| {
+                        settled.lock().unwrap().push((
+                            data.is_some(),
+                            error.is_some(),
+                            variables.input.clone(),
+                        ));
+                    }
+                })
+                .build(),
+        ),
+    );
+
+    mutation.mutate(TestVariables {
+        input: "cancelled".to_string(),
+        delay_ms: 1000,
+    });
+    sleep(Duration::from_millis(50)).await;
+    mutation.cancel();
+
+    // Settled once, with neither data nor error
+    assert_eq!(
+        *settled.lock().unwrap(),
+        vec![(false, false, "cancelled".to_string())]
+    );
+    assert!(mutation.get_state().is_cancelled);
+
+    // Cancelling again does nothing
+    mutation.cancel();
+    assert_eq!(settled.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_scoped_mutations_run_sequentially() {
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let tracked = |name: &'static str| {
+        let events = events.clone();
+        Mutation::new(
+            move |variables: TestVariables| {
+                let events = events.clone();
+                async move {
+                    events.lock().unwrap().push(format!("{name} start"));
+                    let result = mock_long_running_fn(variables).await;
+                    events.lock().unwrap().push(format!("{name} end"));
+                    result
+                }
+            },
+            Some(
+                MutationOptions::builder()
+                    .scope(MutationScope::new("sequential-test"))
+                    .build(),
+            ),
+        )
+    };
+    let create = tracked("create");
+    let update = tracked("update");
+
+    // The slower create is triggered first, so the update waits for it
+    create.mutate(TestVariables {
+        input: "create".to_string(),
+        delay_ms: 100,
+    });
+    update.mutate(TestVariables {
+        input: "update".to_string(),
+        delay_ms: 10,
+    });
+
+    // The queued update hasn't started
+    sleep(Duration::from_millis(50)).await;
+    assert!(create.get_state().is_pending);
+    assert!(update.get_state().is_idle);
+    assert_eq!(*events.lock().unwrap(), vec!["create start"]);
+
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["create start", "create end", "update start", "update end"]
+    );
+    assert!(create.get_state().is_success);
+    assert!(update.get_state().is_success);
+
+    // The settled scope is forgotten
+    assert!(
+        !SCOPE_QUEUES
+            .lock()
+            .contains_key(&MutationScope::new("sequential-test"))
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_before_task_runs_still_settles() {
+    let settled = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mutation = Mutation::new(
+        |variables: TestVariables| async move { mock_long_running_fn(variables).await },
+        Some(
+            MutationOptions::builder()
+                .on_settled({
+                    let settled = settled.clone();
+                    move |_data, _error, variables: &TestVariables, _context| {
+                        settled.lock().unwrap().push(variables.input.clone());
+                    }
+                })
+                .build(),
+        ),
+    );
+
+    // Cancelled without yielding, so the task never ran
+    mutation.mutate(TestVariables {
+        input: "never started".to_string(),
+        delay_ms: 10,
+    });
+    mutation.cancel();
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(*settled.lock().unwrap(), vec!["never started"]);
+    assert!(mutation.get_state().is_cancelled);
+}
+
+#[tokio::test]
+async fn test_scoped_mutations_apply_on_mutate_in_order() {
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mutation = Mutation::new(
+        {
+            let events = events.clone();
+            move |variables: TestVariables| {
+                let events = events.clone();
+                async move {
+                    let result = mock_long_running_fn(variables.clone()).await;
+                    events
+                        .lock()
+                        .unwrap()
+                        .push(format!("{} settled", variables.input));
+                    result
+                }
+            }
+        },
+        Some(
+            MutationOptions::builder()
+                .scope(MutationScope::new("on-mutate-order-test"))
+                .on_mutate({
+                    let events = events.clone();
+                    move |variables: &TestVariables| {
+                        events
+                            .lock()
+                            .unwrap()
+                            .push(format!("{} optimistic", variables.input));
+                        None
+                    }
+                })
+                .build(),
+        ),
+    );
+
+    let first = mutation.clone();
+    let second = mutation.clone();
+    let (first, second) = tokio::join!(
+        first.mutate_async(TestVariables {
+            input: "first".to_string(),
+            delay_ms: 50,
+        }),
+        second.mutate_async(TestVariables {
+            input: "second".to_string(),
+            delay_ms: 10,
+        }),
+    );
+
+    assert!(first.is_ok() && second.is_ok());
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "first optimistic",
+            "first settled",
+            "second optimistic",
+            "second settled"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_cancelled_mutation_releases_its_scope() {
+    let scoped = || {
+        Mutation::new(
+            |variables: TestVariables| async move { mock_long_running_fn(variables).await },
+            Some(
+                MutationOptions::builder()
+                    .scope(MutationScope::new("release-test"))
+                    .build(),
+            ),
+        )
+    };
+    let first = scoped();
+    let second = scoped();
+
+    first.mutate(TestVariables {
+        input: "first".to_string(),
+        delay_ms: 1000,
+    });
+    second.mutate(TestVariables {
+        input: "second".to_string(),
+        delay_ms: 10,
+    });
+
+    sleep(Duration::from_millis(50)).await;
+    first.cancel();
+
+    sleep(Duration::from_millis(100)).await;
+    assert!(second.get_state().is_success);
+}
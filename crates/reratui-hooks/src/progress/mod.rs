@@ -0,0 +1,192 @@
+//! Progress bars
+//!
+//! [`ProgressBar`] draws a ratatui [`Gauge`] filled to a ratio between `0.0`
+//! and `1.0`. Without a value it is indeterminate and a stripe sweeps across
+//! it instead, for work whose length is unknown.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::symbols;
+use ratatui::text::Span;
+use ratatui::widgets::{Gauge, Widget};
+use reratui_core::text::{display_width, truncate_to_width};
+use reratui_core::{Component, ComponentProps, Element};
+use std::time::{Duration, Instant};
+
+use crate::frame::frame_time;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// Time an animated bar takes to fill from empty to full
+pub const PROGRESS_ANIMATION: Duration = Duration::from_millis(300);
+
+/// Time the indeterminate stripe takes to cross the bar
+pub const STRIPE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// Props for the `ProgressBar` component
+#[derive(Clone, Default)]
+pub struct ProgressBarProps {
+    /// Completed ratio, clamped to `0.0..=1.0`; `None` for indeterminate
+    pub value: Option<f64>,
+    /// Text in the middle of the bar; the percentage if `None`
+    pub label: Option<String>,
+    pub style: Style,
+    /// Whether the bar moves smoothly toward a new value
+    pub animate: bool,
+    pub children: Vec<Element>,
+}
+
+impl ProgressBarProps {
+    /// Sets the completed ratio, or `None` for an indeterminate bar
+    pub fn value(mut self, value: impl Into<Option<f64>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets the text in the middle of the bar
+    pub fn label(mut self, label: impl Into<Option<String>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the style of the bar
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether the bar moves smoothly toward a new value
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ProgressBarProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// The columns of a bar `width` wide covered by the stripe `elapsed` into the
+/// animation, as a start column and a width
+///
+/// The stripe is a quarter of the bar wide and slides in from the left edge
+/// and out over the right edge once every [`STRIPE_PERIOD`].
+pub fn stripe_span(width: u16, elapsed: Duration) -> (u16, u16) {
+    let stripe = (width / 4).max(1);
+    let travel = u128::from(width + stripe);
+    let period = STRIPE_PERIOD.as_millis();
+    let head = (elapsed.as_millis() % period * travel / period) as u16;
+
+    let start = head.saturating_sub(stripe);
+    (start, head.min(width) - start)
+}
+
+/// A gauge showing how much of some work is done
+///
+/// ```ignore
+/// rsx! {
+///     <ProgressBar
+///         value={completed as f64 / total as f64}
+///         label={format!("{}/{}", completed, total)}
+///         style={Style::default().fg(Color::Green)}
+///         animate={true}
+///     />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ProgressBarComponent {
+    props: ProgressBarProps,
+}
+
+impl ProgressBarComponent {
+    pub fn new(props: ProgressBarProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+
+    /// The ratio to draw this frame, moving toward `target` when animated
+    fn displayed_ratio(&self, target: f64, now: Instant) -> f64 {
+        // The ratio drawn last frame and when
+        let shown = use_ref(|| (target, now));
+        let (previous, previous_at) = shown.get();
+
+        let ratio = if self.props.animate {
+            let step = now.saturating_duration_since(previous_at).as_secs_f64()
+                / PROGRESS_ANIMATION.as_secs_f64();
+            previous + (target - previous).clamp(-step, step)
+        } else {
+            target
+        };
+        shown.set((ratio, now));
+        ratio
+    }
+
+    fn render_indeterminate(&self, area: Rect, buffer: &mut Buffer, elapsed: Duration) {
+        let style = self.props.style;
+        buffer.set_style(area, style);
+
+        let (start, width) = stripe_span(area.width, elapsed);
+        for y in area.top()..area.bottom() {
+            for x in area.left() + start..area.left() + start + width {
+                buffer[(x, y)]
+                    .set_symbol(symbols::block::FULL)
+                    .set_fg(style.fg.unwrap_or(Color::Reset))
+                    .set_bg(style.bg.unwrap_or(Color::Reset));
+            }
+        }
+
+        if let Some(label) = &self.props.label {
+            let label = truncate_to_width(label, area.width as usize);
+            let label_width = display_width(label) as u16;
+            let x = area.left() + (area.width - label_width) / 2;
+            let y = area.top() + area.height / 2;
+            buffer.set_span(x, y, &Span::raw(label), label_width);
+        }
+    }
+}
+
+impl Component for ProgressBarComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let now = frame_time();
+        let started = use_ref(|| now).get();
+        let target = self.props.value.map(|value| value.clamp(0.0, 1.0));
+        // Runs even while indeterminate, so the hook order never changes
+        let ratio = self.displayed_ratio(target.unwrap_or(0.0), now);
+
+        if area.is_empty() {
+            return;
+        }
+
+        if target.is_none() {
+            self.render_indeterminate(area, buffer, now.saturating_duration_since(started));
+            return;
+        }
+
+        let mut gauge = Gauge::default().gauge_style(self.props.style).ratio(ratio);
+        if let Some(label) = &self.props.label {
+            gauge = gauge.label(label.as_str());
+        }
+        gauge.render(area, buffer);
+    }
+}
+
+/// The `ProgressBar` component, usable as `<ProgressBar value={0.5} />` in `rsx!`
+pub type ProgressBar = ProgressBarComponent;
@@ -0,0 +1,90 @@
+//! Tests for progress bars
+
+use super::*;
+use crate::context::use_context_provider;
+use crate::test_utils::{frame_context_at, with_component_id};
+
+/// Number of filled cells in the first row
+fn filled(buffer: &Buffer) -> usize {
+    (0..buffer.area.width)
+        .filter(|&x| buffer[(x, 0)].symbol() == symbols::block::FULL)
+        .count()
+}
+
+fn render_at(bar: &ProgressBar, area: Rect, timestamp: Instant) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("ProgressBarTest", |_ctx| {
+        use_context_provider(|| frame_context_at(timestamp));
+        bar.render(area, &mut buffer);
+    });
+    buffer
+}
+
+#[test]
+fn test_value_is_clamped() {
+    // The label sits on the second row, out of the way
+    let area = Rect::new(0, 0, 10, 2);
+    let now = Instant::now();
+    let bar = |value: f64| ProgressBarComponent::new(ProgressBarProps::default().value(value));
+
+    assert_eq!(filled(&render_at(&bar(0.5), area, now)), 5);
+    assert_eq!(filled(&render_at(&bar(1.7), area, now)), 10);
+    assert_eq!(filled(&render_at(&bar(-1.0), area, now)), 0);
+}
+
+#[test]
+fn test_label_is_centered() {
+    let area = Rect::new(0, 0, 10, 1);
+    let bar = ProgressBarComponent::new(
+        ProgressBarProps::default()
+            .value(0.0)
+            .label("2/4".to_string()),
+    );
+
+    let buffer = render_at(&bar, area, Instant::now());
+    let row: String = (0..10).map(|x| buffer[(x, 0)].symbol()).collect();
+    assert_eq!(row, "   2/4    ");
+}
+
+#[test]
+fn test_animated_bar_moves_toward_value() {
+    let area = Rect::new(0, 0, 10, 2);
+    let start = Instant::now();
+    let bar = |value: f64| {
+        ProgressBarComponent::new(ProgressBarProps::default().value(value).animate(true))
+    };
+
+    assert_eq!(filled(&render_at(&bar(0.0), area, start)), 0);
+
+    // Half of the animation time covers half of the bar
+    let halfway = start + PROGRESS_ANIMATION / 2;
+    assert_eq!(filled(&render_at(&bar(1.0), area, halfway)), 5);
+
+    let done = start + PROGRESS_ANIMATION * 2;
+    assert_eq!(filled(&render_at(&bar(1.0), area, done)), 10);
+}
+
+#[test]
+fn test_stripe_span_sweeps_across() {
+    let period = STRIPE_PERIOD.as_millis() as u64;
+    let at = |tenths: u64| Duration::from_millis(period * tenths / 10);
+
+    // Eight columns: a two column stripe travelling ten columns per period
+    assert_eq!(stripe_span(8, at(0)), (0, 0));
+    assert_eq!(stripe_span(8, at(2)), (0, 2));
+    assert_eq!(stripe_span(8, at(5)), (3, 2));
+    assert_eq!(stripe_span(8, at(9)), (7, 1));
+    assert_eq!(stripe_span(8, at(10)), (0, 0));
+}
+
+#[test]
+fn test_indeterminate_bar_draws_stripe() {
+    let area = Rect::new(0, 0, 8, 1);
+    let start = Instant::now();
+    let bar = ProgressBarComponent::new(ProgressBarProps::default().value(None));
+
+    render_at(&bar, area, start);
+    let buffer = render_at(&bar, area, start + STRIPE_PERIOD / 2);
+    let row: String = (0..8).map(|x| buffer[(x, 0)].symbol()).collect();
+    assert_eq!(row, "   ██   ");
+}
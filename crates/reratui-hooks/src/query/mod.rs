@@ -3,7 +3,11 @@
 //! This module provides a hook similar to TanStack React Query for managing
 //! server state, caching, and data fetching operations using Tokio for async execution.
 
+use crate::clock;
 use crate::effect::use_effect;
+use crate::event::is_terminal_focused;
+use crate::memo::use_memo;
+use crate::panic_handler::spawn_registered;
 use crate::reducer::use_reducer;
 
 #[cfg(test)]
@@ -15,6 +19,7 @@ use std::fmt::Debug;
 use std::future::Future;
 use std::hash::Hash;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use tracing::{debug, error, info, trace, warn};
 
@@ -34,10 +39,13 @@ pub enum QueryStatus {
     Error,
 }
 
-/// Configuration options for a query
+/// Configuration options for a query returning `T`
 #[derive(Clone)]
-pub struct QueryOptions {
-    /// Whether to enable automatic background refreshing
+pub struct QueryOptions<T> {
+    /// Whether the query fetches on its own
+    ///
+    /// A disabled query stays `Idle` until it is enabled, then fetches right
+    /// away; see the dependent queries section of [`use_query`].
     pub enabled: bool,
     /// How long the data should be considered fresh
     pub stale_time: Duration,
@@ -47,9 +55,25 @@ pub struct QueryOptions {
     pub retry: bool,
     /// Number of retry attempts
     pub retry_attempts: u32,
+    /// Refetch on this interval whether or not the data is stale
+    ///
+    /// Polling stops while the query is disabled and when the component
+    /// unmounts.
+    pub refetch_interval: Option<Duration>,
+    /// Keep polling while the terminal doesn't have focus
+    pub refetch_interval_in_background: bool,
+    /// Data shown while the query has none of its own; never cached
+    pub placeholder_data: Option<T>,
+    /// Data the cache starts with for a key it has no entry for
+    ///
+    /// The seeded data is treated as fetched now: it is shown right away and
+    /// refetched once it goes stale.
+    pub initial_data: Option<T>,
+    /// Keep showing the previous key's data while a new key loads
+    pub keep_previous_data: bool,
 }
 
-impl Default for QueryOptions {
+impl<T> Default for QueryOptions<T> {
     fn default() -> Self {
         Self {
             enabled: true,
@@ -57,25 +81,61 @@ impl Default for QueryOptions {
             cache_time: Duration::from_secs(300), // 5 minutes
             retry: true,
             retry_attempts: 3,
+            refetch_interval: None,
+            refetch_interval_in_background: false,
+            placeholder_data: None,
+            initial_data: None,
+            keep_previous_data: false,
         }
     }
 }
 
+impl<T> QueryOptions<T> {
+    /// Shows `data` while the query loads, marked with
+    /// [`QueryResult::is_placeholder`]
+    pub fn placeholder_data(mut self, data: T) -> Self {
+        self.placeholder_data = Some(data);
+        self
+    }
+
+    /// Seeds the cache with `data` for a key it has no entry for
+    pub fn initial_data(mut self, data: T) -> Self {
+        self.initial_data = Some(data);
+        self
+    }
+
+    /// Sets whether the previous key's data stays visible while a new key loads
+    pub fn keep_previous_data(mut self, keep: bool) -> Self {
+        self.keep_previous_data = keep;
+        self
+    }
+}
+
+/// Source of [`QueryResult::data_version`], bumped for each new piece of data
+static DATA_VERSION: AtomicU64 = AtomicU64::new(1);
+
+/// A version no data has had before
+fn next_data_version() -> u64 {
+    DATA_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
 /// Cached query data with metadata
 #[derive(Clone, Debug)]
 struct CachedQuery<T> {
     data: Option<T>,
+    /// The [`QueryResult::data_version`] of `data`
+    version: u64,
     last_updated: Instant,
     is_stale: bool,
 }
 
 impl<T> CachedQuery<T> {
     fn is_fresh(&self, stale_time: Duration) -> bool {
-        !self.is_stale && self.last_updated.elapsed() < stale_time
+        !self.is_stale && clock::now().duration_since(self.last_updated) < stale_time
     }
 
     fn should_cache_expire(&self, cache_time: Duration) -> bool {
-        self.last_updated.elapsed() > cache_time
+        clock::now().duration_since(self.last_updated) > cache_time
     }
 }
 
@@ -96,6 +156,14 @@ pub struct QueryResult<T, E> {
     pub error: Option<E>,
     /// Whether the data is stale and being refreshed
     pub is_stale: bool,
+    /// Whether `data` is not the query's own but placeholder data or the
+    /// previous key's data, shown while loading
+    pub is_placeholder: bool,
+    /// Changes whenever different data arrives, so memos can be keyed on it
+    /// without comparing the data itself
+    ///
+    /// `0` while there is no data or it is placeholder data.
+    pub data_version: u64,
     /// Function to manually refetch the data
     pub refetch: Arc<dyn Fn() + Send + Sync>,
     /// Function to invalidate the query cache
@@ -105,19 +173,49 @@ pub struct QueryResult<T, E> {
 /// State for a query operation
 #[derive(Clone, PartialEq, Debug)]
 pub struct QueryState<T, E> {
+    /// The cache key the status, data and error belong to
+    key: Option<String>,
     status: QueryStatus,
     data: Option<T>,
+    /// The [`QueryResult::data_version`] of `data`
+    version: u64,
     error: Option<E>,
     is_stale: bool,
+    /// The last data of an earlier key
+    previous: Option<T>,
+    previous_version: u64,
+}
+
+impl<T, E> QueryState<T, E> {
+    /// The state for `key`, keeping the data of another key as `previous`
+    fn for_key(self, key: String) -> Self {
+        if self.key.as_ref() == Some(&key) {
+            return self;
+        }
+        QueryState {
+            key: Some(key),
+            status: QueryStatus::Idle,
+            data: None,
+            version: 0,
+            error: None,
+            is_stale: false,
+            previous_version: if self.data.is_some() {
+                self.version
+            } else {
+                self.previous_version
+            },
+            previous: self.data.or(self.previous),
+        }
+    }
 }
 
-/// Actions that can be performed on a query
+/// Actions that can be performed on a query, for the cache key they carry
 #[derive(Clone)]
 enum QueryAction<T: Clone, E: Clone> {
-    Loading,
-    Refreshing,
-    Success(T),
-    Error(E),
+    Fetching(String),
+    /// New data for a key, with its version
+    Success(String, T, u64),
+    Error(String, E),
 }
 
 /// A hook for managing cached query operations with Tokio
@@ -139,6 +237,43 @@ enum QueryAction<T: Clone, E: Clone> {
 /// - Successful results are cached according to `cache_time` and `stale_time`
 /// - Failed results are only cached if `cache_time > 0` to allow retries
 /// - Cache can be manually invalidated using the `invalidate` function
+/// - Cached data for the key shows on the first render, even while stale data is refetched
+///
+/// # Placeholder Data
+///
+/// Until a key has data of its own, the result can show other data instead,
+/// with [`QueryResult::is_placeholder`] set: the previous key's data when
+/// `keep_previous_data` is set and there is any, otherwise `placeholder_data`.
+/// `initial_data`
+/// seeds the cache instead, so it counts as the key's own data.
+///
+/// # Polling
+///
+/// `stale_time` revalidates data once it goes stale. To poll instead, set
+/// `refetch_interval`: the query refetches on that interval regardless of
+/// staleness, skipping ticks while the terminal is unfocused unless
+/// `refetch_interval_in_background` is set.
+///
+/// # Dependent Queries
+///
+/// A query that needs another query's result waits for it through
+/// `enabled`, with that result in its key. While disabled it stays `Idle`,
+/// without suspending or fetching; once the first query resolves it is
+/// enabled and its key changes, so it fetches with the new key:
+///
+/// ```ignore
+/// let user = use_query("user", || fetch_user("octocat"), None);
+/// let user_id = user.data.as_ref().map(|user| user.id);
+///
+/// let repos = use_query(
+///     ("repos", user_id),
+///     move || fetch_repos(user_id.unwrap_or_default()),
+///     Some(QueryOptions {
+///         enabled: user_id.is_some(),
+///         ..Default::default()
+///     }),
+/// );
+/// ```
 ///
 /// # Type Parameters
 ///
@@ -174,6 +309,14 @@ enum QueryAction<T: Clone, E: Clone> {
 ///     fetch_posts().await
 /// }, Some(options));
 ///
+/// // Polling every 5 seconds, even while data is fresh
+/// let metrics = use_query("metrics", || async {
+///     fetch_metrics().await
+/// }, Some(QueryOptions {
+///     refetch_interval: Some(Duration::from_secs(5)),
+///     ..Default::default()
+/// }));
+///
 /// // Manual operations
 /// result.refetch(); // Force refetch
 /// result.invalidate(); // Clear cache
@@ -181,7 +324,7 @@ enum QueryAction<T: Clone, E: Clone> {
 pub fn use_query<K, F, Fut, T, E>(
     key: K,
     query_fn: F,
-    options: Option<QueryOptions>,
+    options: Option<QueryOptions<T>>,
 ) -> QueryResult<T, E>
 where
     K: Hash + Eq + Clone + Send + Sync + Debug + 'static,
@@ -202,38 +345,47 @@ where
 
     // Define the reducer function
     let reducer = |state: QueryState<T, E>, action: QueryAction<T, E>| match action {
-        QueryAction::Loading => QueryState {
-            status: QueryStatus::Loading,
-            data: state.data,
-            error: None,
-            is_stale: false,
-        },
-        QueryAction::Refreshing => QueryState {
-            status: QueryStatus::Refreshing,
-            data: state.data,
-            error: None,
-            is_stale: true,
-        },
-        QueryAction::Success(data) => QueryState {
+        QueryAction::Fetching(key) => {
+            let state = state.for_key(key);
+            // Loading without data for the key, refreshing with it
+            let is_stale = state.data.is_some();
+            QueryState {
+                status: if is_stale {
+                    QueryStatus::Refreshing
+                } else {
+                    QueryStatus::Loading
+                },
+                error: None,
+                is_stale,
+                ..state
+            }
+        }
+        QueryAction::Success(key, data, version) => QueryState {
             status: QueryStatus::Success,
             data: Some(data),
+            version,
             error: None,
             is_stale: false,
+            ..state.for_key(key)
         },
-        QueryAction::Error(error) => QueryState {
+        QueryAction::Error(key, error) => QueryState {
             status: QueryStatus::Error,
-            data: state.data,
             error: Some(error),
             is_stale: false,
+            ..state.for_key(key)
         },
     };
 
     // Initialize state
     let initial_state = QueryState {
+        key: None,
         status: QueryStatus::Idle,
         data: None,
+        version: 0,
         error: None,
         is_stale: false,
+        previous: None,
+        previous_version: 0,
     };
 
     // Use the reducer
@@ -267,26 +419,27 @@ where
 
             let dispatch = dispatch.clone();
             let current_state = state.get();
+            let has_data =
+                current_state.key.as_ref() == Some(&cache_key) && current_state.data.is_some();
             let query_fn = query_fn.clone();
             let options = options.clone();
             let cache_key = cache_key.clone();
 
             // Spawn the query execution task using Tokio
-            let _handle = tokio::spawn(async move {
+            let _handle = spawn_registered(async move {
                 // Update status based on current data
-                if current_state.data.is_some() {
+                if has_data {
                     debug!(
                         query_key = ?key,
                         "Refreshing stale data"
                     );
-                    dispatch.dispatch(QueryAction::Refreshing);
                 } else {
                     debug!(
                         query_key = ?key,
                         "Starting initial load"
                     );
-                    dispatch.dispatch(QueryAction::Loading);
                 }
+                dispatch.dispatch(QueryAction::Fetching(cache_key.clone()));
 
                 let mut attempts = 0;
                 loop {
@@ -306,17 +459,19 @@ where
                             );
 
                             // Update cache
+                            let version = next_data_version();
                             {
                                 let mut cache = QUERY_CACHE.lock();
                                 let cached_query: CachedQuery<T> = CachedQuery {
                                     data: Some(result.clone()),
-                                    last_updated: Instant::now(),
+                                    version,
+                                    last_updated: clock::now(),
                                     is_stale: false,
                                 };
                                 cache.insert(cache_key.clone(), Box::new(cached_query));
                             }
 
-                            dispatch.dispatch(QueryAction::Success(result));
+                            dispatch.dispatch(QueryAction::Success(cache_key, result, version));
                             break;
                         }
                         Err(err) => {
@@ -334,13 +489,14 @@ where
                                     let mut cache = QUERY_CACHE.lock();
                                     let cached_query: CachedQuery<T> = CachedQuery {
                                         data: None,
-                                        last_updated: Instant::now(),
+                                        version: 0,
+                                        last_updated: clock::now(),
                                         is_stale: false,
                                     };
                                     cache.insert(cache_key.clone(), Box::new(cached_query));
                                 }
 
-                                dispatch.dispatch(QueryAction::Error(err));
+                                dispatch.dispatch(QueryAction::Error(cache_key, err));
                                 break;
                             }
 
@@ -354,7 +510,7 @@ where
                             );
 
                             // Use Tokio sleep with exponential backoff
-                            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                            clock::sleep(Duration::from_millis(delay_ms)).await;
                         }
                     }
                 }
@@ -375,9 +531,24 @@ where
         })
     };
 
-    // Check cache for existing data
+    // Check cache for existing data, seeding it with the initial data
     let cached_data = {
-        let cache = QUERY_CACHE.lock();
+        let mut cache = QUERY_CACHE.lock();
+        if !cache.contains_key(&cache_key)
+            && let Some(initial) = &options.initial_data
+        {
+            debug!(
+                query_key = ?key,
+                "Seeding cache with initial data"
+            );
+            let cached_query: CachedQuery<T> = CachedQuery {
+                data: Some(initial.clone()),
+                version: next_data_version(),
+                last_updated: clock::now(),
+                is_stale: false,
+            };
+            cache.insert(cache_key.clone(), Box::new(cached_query));
+        }
         if let Some(cached) = cache.get(&cache_key) {
             if let Some(cached_query) = cached.downcast_ref::<CachedQuery<T>>() {
                 if !cached_query.should_cache_expire(options.cache_time) {
@@ -405,12 +576,17 @@ where
         );
         // Only update state with successful cached data
         if let Some(data) = &cached.data {
-            dispatch.dispatch(QueryAction::Success(data.clone()));
+            dispatch.dispatch(QueryAction::Success(
+                cache_key.clone(),
+                data.clone(),
+                cached.version,
+            ));
         }
     }
 
     // Set up effect for initial query and background refresh
     {
+        let cached_data = cached_data.clone();
         let refetch = Arc::clone(&refetch_arc);
         let key = key.clone();
         let unique_key = format!("{:?}", key);
@@ -441,9 +617,9 @@ where
                         let refetch_for_bg = Arc::clone(&refetch);
                         let key_for_bg = key.clone();
 
-                        Some(tokio::spawn(async move {
+                        Some(spawn_registered(async move {
                             loop {
-                                tokio::time::sleep(options.stale_time).await;
+                                clock::sleep(options.stale_time).await;
                                 trace!(
                                     query_key = ?key_for_bg,
                                     "Executing background refresh"
@@ -455,7 +631,28 @@ where
                         None
                     };
 
-                    // Return cleanup function that cancels the background task
+                    // Poll on the refetch interval, whatever the staleness
+                    let poll_task_handle = options.refetch_interval.map(|interval| {
+                        let refetch_for_poll = Arc::clone(&refetch);
+                        let key_for_poll = key.clone();
+
+                        spawn_registered(async move {
+                            loop {
+                                clock::sleep(interval).await;
+                                if !options.refetch_interval_in_background && !is_terminal_focused()
+                                {
+                                    continue;
+                                }
+                                trace!(
+                                    query_key = ?key_for_poll,
+                                    "Executing interval refetch"
+                                );
+                                refetch_for_poll();
+                            }
+                        })
+                    });
+
+                    // Return cleanup function that cancels the background tasks
                     let key = key.clone();
                     Some(move || {
                         if let Some(handle) = bg_task_handle {
@@ -465,6 +662,13 @@ where
                                 "Cancelled background refresh task"
                             );
                         }
+                        if let Some(handle) = poll_task_handle {
+                            handle.abort();
+                            debug!(
+                                query_key = ?key,
+                                "Cancelled refetch interval task"
+                            );
+                        }
                         debug!(
                             query_key = ?key,
                             "Cleaning up query effect"
@@ -478,7 +682,16 @@ where
                     None
                 }
             },
-            unique_key,
+            // Restart when enabled or the polling settings change, so
+            // disabling the query stops its timers
+            (
+                unique_key,
+                options.enabled,
+                options
+                    .refetch_interval
+                    .map(|interval| interval.as_millis() as u64),
+                options.refetch_interval_in_background,
+            ),
         );
     }
 
@@ -493,16 +706,134 @@ where
         "Returning query result"
     );
 
+    // The state still belongs to the previous key until the new key's
+    // fetch starts
+    let QueryState {
+        key: state_key,
+        mut status,
+        mut data,
+        mut version,
+        mut error,
+        mut is_stale,
+        previous,
+        previous_version,
+    } = current_state;
+    let own = state_key.as_ref() == Some(&cache_key);
+    let (previous, previous_version) = if own {
+        (previous, previous_version)
+    } else {
+        let previous_version = if data.is_some() {
+            version
+        } else {
+            previous_version
+        };
+        let previous = data.take().or(previous);
+        version = 0;
+        status = if options.enabled {
+            QueryStatus::Loading
+        } else {
+            QueryStatus::Idle
+        };
+        error = None;
+        is_stale = false;
+        (previous, previous_version)
+    };
+
+    // Cached data for the key shows right away, as refreshing if it is stale
+    if data.is_none()
+        && status == QueryStatus::Loading
+        && let Some(cached) = cached_data
+        && cached.data.is_some()
+    {
+        let fresh = cached.is_fresh(options.stale_time);
+        data = cached.data;
+        version = cached.version;
+        status = if fresh {
+            QueryStatus::Success
+        } else {
+            QueryStatus::Refreshing
+        };
+        is_stale = !fresh;
+    }
+
+    // Otherwise the previous key's data or the placeholder fills in
+    let mut is_placeholder = false;
+    if data.is_none() && status == QueryStatus::Loading {
+        data = previous.filter(|_| options.keep_previous_data);
+        version = previous_version;
+        if data.is_none() {
+            data = options.placeholder_data.clone();
+            version = 0;
+        }
+        is_placeholder = data.is_some();
+    }
+
+    // Suspend the enclosing boundary until there is data to show; refreshes
+    // and placeholders keep showing what they have instead
+    let loading = data.is_none()
+        && (status == QueryStatus::Loading || (status == QueryStatus::Idle && options.enabled));
+    if loading {
+        reratui_core::suspense::suspend();
+    }
+
     QueryResult {
-        status: current_state.status,
-        data: current_state.data,
-        error: current_state.error,
-        is_stale: current_state.is_stale,
+        status,
+        data,
+        error,
+        is_stale,
+        is_placeholder,
+        data_version: version,
         refetch: refetch_arc,
         invalidate: invalidate_arc,
     }
 }
 
+/// Derives view data from a query's result, like React Query's `select`
+///
+/// Returns `result` with `data` replaced by `select` applied to it. The
+/// selection is computed once per change of
+/// [`data_version`](QueryResult::data_version), not on every render, so a
+/// slice of a large response is not re-derived or compared each frame and
+/// the same selected value is returned until new data arrives.
+///
+/// # Examples
+///
+/// ```ignore
+/// let search = use_query(term.clone(), move || search_repos(term), None);
+/// let names = use_query_select(&search, |response: &SearchResponse| {
+///     response.items.iter().map(|repo| repo.name.clone()).collect::<Vec<_>>()
+/// });
+/// ```
+pub fn use_query_select<T, E, S>(
+    result: &QueryResult<T, E>,
+    select: impl Fn(&T) -> S,
+) -> QueryResult<S, E>
+where
+    E: Clone,
+    S: Clone + 'static,
+{
+    // Placeholder data has no version, so switching to or from it reselects
+    let data = use_memo(
+        || result.data.as_ref().map(select),
+        (
+            result.data_version,
+            result.is_placeholder,
+            result.data.is_some(),
+        ),
+    );
+
+    QueryResult {
+        status: result.status,
+        data,
+        error: result.error.clone(),
+        is_stale: result.is_stale,
+        is_placeholder: result.is_placeholder,
+        data_version: result.data_version,
+        refetch: result.refetch.clone(),
+        invalidate: result.invalidate.clone(),
+    }
+}
+
 /// Clear all cached queries - useful for testing and cleanup
 pub fn clear_query_cache() {
     let mut cache = QUERY_CACHE.lock();
@@ -530,6 +530,7 @@ async fn test_background_task_cleanup() {
                 cache_time: Duration::from_secs(300),
                 retry: false,
                 retry_attempts: 0,
+                ..Default::default()
             };
 
             let _result = use_query(
@@ -570,6 +571,7 @@ async fn test_disabled_query_no_background_task() {
                 cache_time: Duration::from_secs(300),
                 retry: false,
                 retry_attempts: 0,
+                ..Default::default()
             };
 
             let _result = use_query(
@@ -609,6 +611,7 @@ async fn test_no_background_refresh_when_stale_time_zero() {
                 cache_time: Duration::from_secs(300),
                 retry: false,
                 retry_attempts: 0,
+                ..Default::default()
             };
 
             let _result = use_query(
@@ -632,3 +635,331 @@ async fn test_no_background_refresh_when_stale_time_zero() {
     })
     .await;
 }
+
+/// Renders a query polling every 40ms with fresh data, counting fetches
+async fn render_polling_query(
+    component_id: &'static str,
+    fetch_count: &Arc<AtomicU32>,
+    enabled: bool,
+    in_background: bool,
+) {
+    with_async_component_id(component_id, |_ctx| async {
+        let fetch_count = fetch_count.clone();
+        let options = QueryOptions {
+            enabled,
+            stale_time: Duration::from_secs(3600),
+            refetch_interval: Some(Duration::from_millis(40)),
+            refetch_interval_in_background: in_background,
+            retry: false,
+            ..Default::default()
+        };
+
+        use_query(
+            component_id,
+            move || {
+                fetch_count.fetch_add(1, Ordering::SeqCst);
+                mock_fetch_success(42)
+            },
+            Some(options),
+        );
+    })
+    .await;
+}
+
+/// Polling runs regardless of staleness, pauses while disabled and skips
+/// ticks while the terminal is unfocused unless told to poll in background
+#[tokio::test]
+async fn test_refetch_interval_polling() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+
+        // Fresh data is refetched on every tick
+        let polled = Arc::new(AtomicU32::new(0));
+        render_polling_query("refetch-interval-poll", &polled, true, false).await;
+        sleep(Duration::from_millis(150)).await;
+        assert!(
+            polled.load(Ordering::SeqCst) >= 3,
+            "Should poll while enabled"
+        );
+
+        // Disabling the query stops the timer
+        render_polling_query("refetch-interval-poll", &polled, false, false).await;
+        sleep(Duration::from_millis(20)).await;
+        let when_disabled = polled.load(Ordering::SeqCst);
+        sleep(Duration::from_millis(120)).await;
+        assert_eq!(polled.load(Ordering::SeqCst), when_disabled);
+
+        // Unfocused terminals skip ticks unless polling in background
+        crate::event::set_terminal_focused(false);
+        let foreground = Arc::new(AtomicU32::new(0));
+        let background = Arc::new(AtomicU32::new(0));
+        render_polling_query("refetch-interval-foreground", &foreground, true, false).await;
+        render_polling_query("refetch-interval-background", &background, true, true).await;
+        sleep(Duration::from_millis(150)).await;
+        crate::event::set_terminal_focused(true);
+
+        assert_eq!(
+            foreground.load(Ordering::SeqCst),
+            1,
+            "Only the initial fetch"
+        );
+        assert!(background.load(Ordering::SeqCst) >= 3);
+    })
+    .await;
+}
+
+/// A query enabled by another's data stays idle until that data arrives
+#[tokio::test]
+async fn test_dependent_query_waits_for_its_dependency() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+        let repo_fetches = Arc::new(AtomicU32::new(0));
+
+        let render = || {
+            let repo_fetches = repo_fetches.clone();
+            with_async_component_id("DependentQueryTest", move |_ctx| async move {
+                let user = use_query("dependent-user", || mock_fetch_success(7), None);
+                let user_id = user.data;
+
+                let repos = use_query(
+                    ("dependent-repos", user_id),
+                    move || {
+                        repo_fetches.fetch_add(1, Ordering::SeqCst);
+                        mock_fetch_success(user_id.unwrap_or_default() * 10)
+                    },
+                    Some(QueryOptions {
+                        enabled: user_id.is_some(),
+                        ..Default::default()
+                    }),
+                );
+                (user.status, repos.status, repos.data)
+            })
+        };
+
+        let (user_status, repos_status, _) = render().await;
+        assert!(matches!(
+            user_status,
+            QueryStatus::Idle | QueryStatus::Loading
+        ));
+        assert_eq!(repos_status, QueryStatus::Idle);
+
+        // The user resolves; the repos query hasn't been enabled yet
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(repo_fetches.load(Ordering::SeqCst), 0);
+
+        // The next render enables it with the user's id in its key
+        let (user_status, repos_status, _) = render().await;
+        assert_eq!(user_status, QueryStatus::Success);
+        assert_ne!(repos_status, QueryStatus::Error);
+
+        sleep(Duration::from_millis(50)).await;
+        let (_, repos_status, repos) = render().await;
+        assert_eq!(repos_status, QueryStatus::Success);
+        assert_eq!(repos, Some(70));
+        assert_eq!(repo_fetches.load(Ordering::SeqCst), 1);
+    })
+    .await;
+}
+
+/// The previous key's data stays visible while the new key loads
+#[tokio::test]
+async fn test_keep_previous_data_across_key_change() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+
+        let render = |page: i32| {
+            with_async_component_id("KeepPreviousDataTest", move |_ctx| async move {
+                let result = use_query(
+                    ("keep-previous-page", page),
+                    move || mock_fetch_success(page * 100),
+                    Some(QueryOptions::default().keep_previous_data(true)),
+                );
+                (result.status, result.data, result.is_placeholder)
+            })
+        };
+
+        render(1).await;
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(render(1).await, (QueryStatus::Success, Some(100), false));
+
+        // Page 2 loads behind page 1's data
+        assert_eq!(render(2).await, (QueryStatus::Loading, Some(100), true));
+        assert_eq!(render(2).await, (QueryStatus::Loading, Some(100), true));
+
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(render(2).await, (QueryStatus::Success, Some(200), false));
+    })
+    .await;
+}
+
+/// The data version changes with new data and follows data kept from an earlier key
+#[tokio::test]
+async fn test_data_version_follows_data() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+
+        let render = |page: i32| {
+            with_async_component_id("DataVersionTest", move |_ctx| async move {
+                let result = use_query(
+                    ("data-version-page", page),
+                    move || mock_fetch_success(page * 100),
+                    Some(QueryOptions::default().keep_previous_data(true)),
+                );
+                (result.data, result.data_version)
+            })
+        };
+
+        assert_eq!(render(1).await, (None, 0));
+        sleep(Duration::from_millis(50)).await;
+        let (data, first) = render(1).await;
+        assert_eq!(data, Some(100));
+        assert_ne!(first, 0);
+        assert_eq!(render(1).await, (Some(100), first));
+
+        // Page 1's data keeps its version while page 2 loads
+        assert_eq!(render(2).await, (Some(100), first));
+        sleep(Duration::from_millis(50)).await;
+        let (data, second) = render(2).await;
+        assert_eq!(data, Some(200));
+        assert_ne!(second, first);
+    })
+    .await;
+}
+
+/// With no previous data yet, the placeholder fills in on the first fetch
+#[tokio::test]
+async fn test_keep_previous_data_falls_back_to_placeholder() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+
+        let render = |page: i32| {
+            with_async_component_id("KeepPreviousPlaceholderTest", move |_ctx| async move {
+                let result = use_query(
+                    ("keep-previous-placeholder", page),
+                    move || mock_fetch_success(page * 100),
+                    Some(
+                        QueryOptions::default()
+                            .keep_previous_data(true)
+                            .placeholder_data(0),
+                    ),
+                );
+                (result.status, result.data, result.is_placeholder)
+            })
+        };
+
+        assert_eq!(render(1).await, (QueryStatus::Loading, Some(0), true));
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(render(1).await, (QueryStatus::Success, Some(100), false));
+
+        // Once there is data, the previous key's data wins over the placeholder
+        assert_eq!(render(2).await, (QueryStatus::Loading, Some(100), true));
+    })
+    .await;
+}
+
+/// Placeholder data shows while loading but never reaches the cache
+#[tokio::test]
+async fn test_placeholder_data_is_not_cached() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+
+        let render = || {
+            with_async_component_id("PlaceholderDataTest", |_ctx| async {
+                let result = use_query(
+                    "placeholder-data",
+                    || mock_fetch_success(42),
+                    Some(QueryOptions::default().placeholder_data(0)),
+                );
+                (result.status, result.data, result.is_placeholder)
+            })
+        };
+
+        assert_eq!(render().await, (QueryStatus::Loading, Some(0), true));
+        assert!(!QUERY_CACHE.lock().contains_key("\"placeholder-data\""));
+
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(render().await, (QueryStatus::Success, Some(42), false));
+    })
+    .await;
+}
+
+/// Initial data seeds the cache, so fresh initial data is not fetched
+#[tokio::test]
+async fn test_initial_data_seeds_the_cache() {
+    with_async_test_isolate(|| async {
+        clear_query_cache();
+        let fetches = Arc::new(AtomicU32::new(0));
+
+        let render = || {
+            let fetches = fetches.clone();
+            with_async_component_id("InitialDataTest", move |_ctx| async move {
+                let result = use_query(
+                    "initial-data",
+                    move || {
+                        fetches.fetch_add(1, Ordering::SeqCst);
+                        mock_fetch_success(42)
+                    },
+                    Some(
+                        QueryOptions {
+                            stale_time: Duration::from_secs(60),
+                            ..Default::default()
+                        }
+                        .initial_data(7),
+                    ),
+                );
+                (result.status, result.data, result.is_placeholder)
+            })
+        };
+
+        assert_eq!(render().await, (QueryStatus::Success, Some(7), false));
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(render().await, (QueryStatus::Success, Some(7), false));
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+    })
+    .await;
+}
+
+/// The selection is computed once per data version, not once per render
+#[test]
+fn test_query_select_runs_once_per_data_change() {
+    use crate::hook_context::{HookContext, set_hook_context};
+    use std::rc::Rc;
+
+    let context = Rc::new(HookContext::new());
+    set_hook_context(context.clone());
+    let selections = Arc::new(AtomicU32::new(0));
+
+    let result = |data_version, data: Option<Vec<&'static str>>| QueryResult::<_, String> {
+        status: QueryStatus::Success,
+        data,
+        error: None,
+        is_stale: false,
+        is_placeholder: false,
+        data_version,
+        refetch: Arc::new(|| {}),
+        invalidate: Arc::new(|| {}),
+    };
+    let render = |data_version, data| {
+        context.reset_hook_index();
+        let selections = selections.clone();
+        use_query_select(&result(data_version, data), move |names: &Vec<&str>| {
+            selections.fetch_add(1, Ordering::SeqCst);
+            names.len()
+        })
+        .data
+    };
+
+    assert_eq!(render(0, None), None);
+    assert_eq!(render(3, Some(vec!["reratui", "ratatui"])), Some(2));
+    assert_eq!(render(3, Some(vec!["reratui", "ratatui"])), Some(2));
+    assert_eq!(selections.load(Ordering::SeqCst), 1);
+
+    // A refetch returning equal data is still new data
+    assert_eq!(render(4, Some(vec!["reratui", "ratatui"])), Some(2));
+    assert_eq!(selections.load(Ordering::SeqCst), 2);
+
+    assert_eq!(render(5, Some(vec!["tokio"])), Some(1));
+    assert_eq!(selections.load(Ordering::SeqCst), 3);
+
+    context.clear();
+}
@@ -0,0 +1,215 @@
+//! Time-travel recording of reducer dispatches for debugging
+
+use super::{DispatchFn, ReducerContainer, ReducerStateHandle};
+use crate::hook_context::with_hook_context;
+use parking_lot::{Mutex, RwLock};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// How many dispatches [`use_reducer_devtools`] keeps
+pub const DEVTOOLS_CAPACITY: usize = 100;
+
+/// The recorded dispatches of one reducer
+struct DevtoolsLog<S, A> {
+    /// The state before the oldest recorded action
+    base: S,
+    /// Each action with the state it produced, oldest first
+    entries: VecDeque<(A, S)>,
+    /// The entry whose state is current, or `None` for `base`
+    position: Option<usize>,
+}
+
+impl<S: Clone, A> DevtoolsLog<S, A> {
+    /// Records a dispatch, dropping the entries after the current one
+    fn record(&mut self, action: A, state: S) {
+        self.entries
+            .truncate(self.position.map_or(0, |position| position + 1));
+        self.entries.push_back((action, state));
+        if self.entries.len() > DEVTOOLS_CAPACITY
+            && let Some((_, oldest)) = self.entries.pop_front()
+        {
+            self.base = oldest;
+        }
+        self.position = Some(self.entries.len() - 1);
+    }
+}
+
+/// Steps through the dispatches recorded by [`use_reducer_devtools`]
+///
+/// Entries are indexed from the oldest recorded action. Moving to an entry
+/// makes the state that action produced current again, as if the later
+/// actions had not happened; dispatching from there discards them.
+pub struct DevtoolsHandle<S, A> {
+    log: Arc<Mutex<DevtoolsLog<S, A>>>,
+    state: Arc<RwLock<S>>,
+    version: Arc<Mutex<u64>>,
+}
+
+impl<S, A> Clone for DevtoolsHandle<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            log: self.log.clone(),
+            state: self.state.clone(),
+            version: self.version.clone(),
+        }
+    }
+}
+
+impl<S, A> DevtoolsHandle<S, A>
+where
+    S: Clone,
+    A: Clone + Debug,
+{
+    /// The recorded actions, oldest first
+    pub fn actions(&self) -> Vec<A> {
+        self.log
+            .lock()
+            .entries
+            .iter()
+            .map(|(action, _)| action.clone())
+            .collect()
+    }
+
+    /// The recorded actions formatted with `Debug`, for display
+    pub fn labels(&self) -> Vec<String> {
+        self.log
+            .lock()
+            .entries
+            .iter()
+            .map(|(action, _)| format!("{action:?}"))
+            .collect()
+    }
+
+    /// Number of recorded actions
+    pub fn len(&self) -> usize {
+        self.log.lock().entries.len()
+    }
+
+    /// Whether no action has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The entry whose state is current, or `None` before the first one
+    pub fn position(&self) -> Option<usize> {
+        self.log.lock().position
+    }
+
+    /// Makes the state produced by entry `index` current
+    ///
+    /// Returns `false`, changing nothing, if there is no such entry.
+    pub fn jump_to(&self, index: usize) -> bool {
+        let mut log = self.log.lock();
+        let Some((_, state)) = log.entries.get(index) else {
+            return false;
+        };
+        let state = state.clone();
+        log.position = Some(index);
+        self.apply(state);
+        true
+    }
+
+    /// Moves to the previous entry, or to the state before the first one
+    ///
+    /// Returns `false` if already there.
+    pub fn step_back(&self) -> bool {
+        let mut log = self.log.lock();
+        let state = match log.position {
+            None => return false,
+            Some(0) => log.base.clone(),
+            Some(position) => log.entries[position - 1].1.clone(),
+        };
+        log.position = log.position.and_then(|position| position.checked_sub(1));
+        self.apply(state);
+        true
+    }
+
+    /// Moves to the next entry, returning `false` if at the latest one
+    pub fn step_forward(&self) -> bool {
+        let next = self.position().map_or(0, |position| position + 1);
+        self.jump_to(next)
+    }
+
+    fn apply(&self, state: S) {
+        *self.state.write() = state;
+        *self.version.lock() += 1;
+    }
+}
+
+/// A useReducer variant that records every dispatch for time travel
+///
+/// Each action is kept with the state it produced, up to the latest
+/// [`DEVTOOLS_CAPACITY`] dispatches. The returned [`DevtoolsHandle`] lists
+/// them and moves between them, so a debug overlay can step backward and
+/// forward through a reducer's history. The state handle and dispatch
+/// function behave like those of [`use_reducer`](super::use_reducer).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::reducer::use_reducer_devtools;
+///
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Increment,
+///     Reset,
+/// }
+///
+/// fn reducer(state: i32, action: Action) -> i32 {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Reset => 0,
+///     }
+/// }
+///
+/// let (state, dispatch, devtools) = use_reducer_devtools(reducer, 0);
+/// dispatch.call(Action::Increment);
+/// dispatch.call(Action::Reset);
+///
+/// // Back to the state after the increment
+/// devtools.jump_to(0);
+/// for (index, label) in devtools.labels().iter().enumerate() {
+///     let marker = if devtools.position() == Some(index) { ">" } else { " " };
+///     println!("{marker} {label}");
+/// }
+/// ```
+pub fn use_reducer_devtools<S, A, R>(
+    reducer: R,
+    initial_state: S,
+) -> (ReducerStateHandle<S>, DispatchFn<A>, DevtoolsHandle<S, A>)
+where
+    S: Clone + Send + Sync + 'static,
+    A: Clone + Debug + Send + Sync + 'static,
+    R: Fn(S, A) -> S + Send + Sync + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+
+        let hook_ref = ctx.get_or_init_state(index, || {
+            let log = Arc::new(Mutex::new(DevtoolsLog {
+                base: initial_state.clone(),
+                entries: VecDeque::new(),
+                position: None,
+            }));
+            let recorder = log.clone();
+            let container = Arc::new(ReducerContainer::new(
+                initial_state,
+                move |state: S, action: A| {
+                    let next = reducer(state, action.clone());
+                    recorder.lock().record(action, next.clone());
+                    next
+                },
+            ));
+            let devtools = DevtoolsHandle {
+                log,
+                state: container.state.clone(),
+                version: container.version.clone(),
+            };
+            (container, devtools)
+        });
+
+        let (container, devtools) = hook_ref.borrow().clone();
+        (container.state_handle(), container.dispatch_fn(), devtools)
+    })
+}
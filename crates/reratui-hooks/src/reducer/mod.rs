@@ -4,12 +4,21 @@
 //! React's API patterns for complex state management scenarios.
 
 use crate::hook_context::with_hook_context;
+use crate::panic_handler::spawn_registered;
 use parking_lot::{Mutex, RwLock};
+use std::future::Future;
 use std::sync::Arc;
+use tokio::task::AbortHandle;
+
+mod devtools;
+mod persistent;
 
 #[cfg(test)]
 mod tests;
 
+pub use devtools::*;
+pub use persistent::*;
+
 /// A handle to the current state managed by useReducer
 ///
 /// This provides read-only access to the current state value with efficient
@@ -57,19 +66,30 @@ where
 ///
 /// This function is used to dispatch actions that will be processed by the
 /// reducer function to produce new state.
-#[derive(Clone)]
 pub struct DispatchFn<A> {
     dispatcher: Arc<dyn Fn(A) + Send + Sync>,
+    /// Thunks spawned by `dispatch_async`, aborted when the reducer is dropped
+    tasks: Arc<Mutex<Vec<AbortHandle>>>,
+}
+
+impl<A> Clone for DispatchFn<A> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatcher: self.dispatcher.clone(),
+            tasks: self.tasks.clone(),
+        }
+    }
 }
 
 impl<A> DispatchFn<A> {
     /// Create a new dispatch function
-    fn new<F>(dispatcher: F) -> Self
+    fn new<F>(dispatcher: F, tasks: Arc<Mutex<Vec<AbortHandle>>>) -> Self
     where
         F: Fn(A) + Send + Sync + 'static,
     {
         Self {
             dispatcher: Arc::new(dispatcher),
+            tasks,
         }
     }
 
@@ -85,6 +105,51 @@ impl<A> DispatchFn<A> {
     pub fn call(&self, action: A) {
         self.dispatch(action);
     }
+
+    /// Run an async thunk that can dispatch follow-up actions
+    ///
+    /// The thunk receives a clone of this dispatch function and its future is
+    /// spawned on the current tokio runtime. When the component owning the
+    /// reducer unmounts, unfinished thunks are aborted so they never dispatch
+    /// into state that is no longer rendered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use reratui_hooks::reducer::use_reducer;
+    /// # #[derive(Clone)]
+    /// # enum Action { Loading, Loaded(String) }
+    /// # fn reducer(state: String, action: Action) -> String {
+    /// #     match action { Action::Loading => "...".into(), Action::Loaded(s) => s }
+    /// # }
+    /// # async fn fetch_user() -> String { String::new() }
+    /// let (state, dispatch) = use_reducer(reducer, String::new());
+    ///
+    /// dispatch.dispatch_async(|dispatch| async move {
+    ///     dispatch.dispatch(Action::Loading);
+    ///     let user = fetch_user().await;
+    ///     dispatch.dispatch(Action::Loaded(user));
+    /// });
+    /// ```
+    pub fn dispatch_async<F, Fut>(&self, thunk: F)
+    where
+        F: FnOnce(DispatchFn<A>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        if tokio::runtime::Handle::try_current().is_err() {
+            tracing::warn!(
+                target: "hooks::reducer",
+                "dispatch_async called outside tokio runtime context"
+            );
+            return;
+        }
+
+        let task = spawn_registered(thunk(self.clone()));
+
+        let mut tasks = self.tasks.lock();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(task.abort_handle());
+    }
 }
 
 /// Internal container for reducer state management
@@ -92,6 +157,16 @@ struct ReducerContainer<S, A> {
     state: Arc<RwLock<S>>,
     version: Arc<Mutex<u64>>,
     reducer: Arc<dyn Fn(S, A) -> S + Send + Sync>,
+    tasks: Arc<Mutex<Vec<AbortHandle>>>,
+}
+
+/// Abort outstanding thunks when the reducer's hook state is dropped
+impl<S, A> Drop for ReducerContainer<S, A> {
+    fn drop(&mut self) {
+        for task in self.tasks.lock().drain(..) {
+            task.abort();
+        }
+    }
 }
 
 impl<S, A> ReducerContainer<S, A>
@@ -108,6 +183,7 @@ where
             state: Arc::new(RwLock::new(initial_state)),
             version: Arc::new(Mutex::new(0)),
             reducer: Arc::new(reducer),
+            tasks: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -142,19 +218,22 @@ where
         let container_version = self.version.clone();
         let container_reducer = self.reducer.clone();
 
-        DispatchFn::new(move |action| {
-            let current_state = container_state.read().clone();
-            let new_state = container_reducer(current_state, action);
-            *container_state.write() = new_state;
+        DispatchFn::new(
+            move |action| {
+                let current_state = container_state.read().clone();
+                let new_state = container_reducer(current_state, action);
+                *container_state.write() = new_state;
 
-            // Increment version counter
-            {
-                let mut version = container_version.lock();
-                *version += 1;
-            }
+                // Increment version counter
+                {
+                    let mut version = container_version.lock();
+                    *version += 1;
+                }
 
-            // TODO: Trigger re-render notification
-        })
+                // TODO: Trigger re-render notification
+            },
+            self.tasks.clone(),
+        )
     }
 }
 
@@ -318,3 +397,81 @@ where
         (state_handle, dispatch_fn)
     })
 }
+
+/// A useReducer variant that reports every dispatch to a middleware
+///
+/// The middleware is called after each action is reduced, with the previous
+/// state, the action and the next state. This allows logging or inspecting
+/// state transitions without touching the reducer itself. The returned state
+/// handle and dispatch function behave exactly like those of [`use_reducer`].
+///
+/// As with the reducer, the middleware is captured on the first render and
+/// later values are ignored.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::reducer::{logging_middleware, use_reducer_with_middleware};
+///
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Increment,
+///     Reset,
+/// }
+///
+/// fn reducer(state: i32, action: Action) -> i32 {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Reset => 0,
+///     }
+/// }
+///
+/// // Log every transition through `tracing`
+/// let (state, dispatch) = use_reducer_with_middleware(reducer, 0, logging_middleware);
+///
+/// // Or inspect transitions directly
+/// let (state, dispatch) = use_reducer_with_middleware(reducer, 0, |prev, action, next| {
+///     if matches!(action, Action::Reset) {
+///         println!("reset from {prev} to {next}");
+///     }
+/// });
+/// ```
+pub fn use_reducer_with_middleware<S, A, R, M>(
+    reducer: R,
+    initial_state: S,
+    middleware: M,
+) -> (ReducerStateHandle<S>, DispatchFn<A>)
+where
+    S: Clone + Send + Sync + 'static,
+    A: Clone + Send + Sync + 'static,
+    R: Fn(S, A) -> S + Send + Sync + 'static,
+    M: Fn(&S, &A, &S) + Send + Sync + 'static,
+{
+    use_reducer(
+        move |state: S, action: A| {
+            let previous = state.clone();
+            let next = reducer(state, action.clone());
+            middleware(&previous, &action, &next);
+            next
+        },
+        initial_state,
+    )
+}
+
+/// A middleware for [`use_reducer_with_middleware`] that logs each dispatch
+///
+/// Emits a `tracing` debug event with target `hooks::reducer` containing the
+/// action and the previous and next states.
+pub fn logging_middleware<S, A>(previous: &S, action: &A, next: &S)
+where
+    S: std::fmt::Debug,
+    A: std::fmt::Debug,
+{
+    tracing::debug!(
+        target: "hooks::reducer",
+        ?action,
+        ?previous,
+        ?next,
+        "Dispatched action"
+    );
+}
@@ -0,0 +1,165 @@
+//! Reducer state that is saved to disk and restored on the next run
+
+use super::{DispatchFn, ReducerContainer, ReducerStateHandle};
+use crate::debounce::Debouncer;
+use crate::hook_context::with_hook_context;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long dispatches must pause before the state is written
+pub const PERSIST_DELAY: Duration = Duration::from_millis(300);
+
+/// Where the state saved under `key` is stored
+///
+/// An absolute `key` is used as the path itself. Any other key names a JSON
+/// file in the `reratui` directory of the platform's data directory:
+/// `$XDG_DATA_HOME`, `%APPDATA%` or `~/.local/share`, falling back to the
+/// temporary directory.
+pub fn persistence_path(key: &str) -> PathBuf {
+    let key_path = Path::new(key);
+    if key_path.is_absolute() {
+        return key_path.to_path_buf();
+    }
+
+    let data_dir = std::env::var_os("XDG_DATA_HOME")
+        .or_else(|| std::env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+        .unwrap_or_else(std::env::temp_dir);
+    data_dir.join("reratui").join(format!("{key}.json"))
+}
+
+/// Reads the state saved at `path`, if there is a readable one
+fn load_state<S: DeserializeOwned>(path: &Path) -> Option<S> {
+    let contents = match std::fs::read(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(error) => {
+            tracing::warn!(target: "hooks::reducer", path = %path.display(), %error, "Failed to read persisted state");
+            return None;
+        }
+    };
+    match serde_json::from_slice(&contents) {
+        Ok(state) => Some(state),
+        Err(error) => {
+            tracing::warn!(target: "hooks::reducer", path = %path.display(), %error, "Ignoring corrupt persisted state");
+            None
+        }
+    }
+}
+
+/// Writes `state` to `path`, through a temporary file so a crash mid-write
+/// never leaves a truncated file behind
+fn save_state<S: Serialize>(path: &Path, state: &S) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_vec_pretty(state)?;
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    std::fs::write(&temporary, contents)?;
+    std::fs::rename(&temporary, path)
+}
+
+/// Hook state of [`use_persistent_reducer`]
+struct PersistentReducer<S: Send + 'static, A> {
+    container: Arc<ReducerContainer<S, A>>,
+    saver: Arc<Debouncer<S>>,
+}
+
+/// Writes a save still waiting out its delay when the hook state is dropped,
+/// as happens once the render loop exits
+impl<S: Send + 'static, A> Drop for PersistentReducer<S, A> {
+    fn drop(&mut self) {
+        self.saver.flush();
+    }
+}
+
+/// A useReducer variant whose state survives restarts
+///
+/// On the first render the state is loaded from the file for `key` (see
+/// [`persistence_path`]), or starts as `default` when the file is missing or
+/// can't be parsed. After each dispatch the new state is written as JSON,
+/// once dispatches have paused for [`PERSIST_DELAY`]. A pending write is
+/// flushed when the component unmounts or the application exits.
+///
+/// The returned state handle and dispatch function are those of
+/// [`use_reducer`](super::use_reducer), so switching to this hook only
+/// changes the call. Without a tokio runtime every dispatch is written
+/// immediately.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::reducer::use_persistent_reducer;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Default, Serialize, Deserialize)]
+/// struct Settings {
+///     dark_mode: bool,
+/// }
+///
+/// enum Action {
+///     ToggleDarkMode,
+/// }
+///
+/// fn reducer(settings: Settings, action: Action) -> Settings {
+///     match action {
+///         Action::ToggleDarkMode => Settings {
+///             dark_mode: !settings.dark_mode,
+///         },
+///     }
+/// }
+///
+/// let (settings, dispatch) = use_persistent_reducer(reducer, "settings", Settings::default());
+/// dispatch.call(Action::ToggleDarkMode);
+/// ```
+pub fn use_persistent_reducer<S, A, R>(
+    reducer: R,
+    key: &str,
+    default: S,
+) -> (ReducerStateHandle<S>, DispatchFn<A>)
+where
+    S: Clone + Send + Sync + Serialize + DeserializeOwned + 'static,
+    A: Send + Sync + 'static,
+    R: Fn(S, A) -> S + Send + Sync + 'static,
+{
+    let hook = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || {
+            let path = persistence_path(key);
+            let initial_state = load_state(&path).unwrap_or(default);
+
+            let saver = Arc::new(Debouncer::new());
+            saver.set_callback(move |state: S| {
+                if let Err(error) = save_state(&path, &state) {
+                    tracing::warn!(target: "hooks::reducer", path = %path.display(), %error, "Failed to persist state");
+                }
+            });
+
+            let schedule = Arc::clone(&saver);
+            let container = ReducerContainer::new(initial_state, move |state: S, action: A| {
+                let next = reducer(state, action);
+                if tokio::runtime::Handle::try_current().is_ok() {
+                    schedule.call(next.clone(), PERSIST_DELAY);
+                } else {
+                    schedule.call_now(next.clone());
+                }
+                next
+            });
+            PersistentReducer {
+                container: Arc::new(container),
+                saver,
+            }
+        })
+    });
+
+    let hook = hook.borrow();
+    let saver = Arc::clone(&hook.saver);
+    reratui_core::component::on_current_unmount(move || saver.flush());
+
+    (hook.container.state_handle(), hook.container.dispatch_fn())
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::test_utils::{with_component_id, with_test_isolate};
+use crate::test_utils::{cleanup_component_contexts, with_component_id, with_test_isolate};
 
 #[derive(Clone, Debug, PartialEq)]
 enum CounterAction {
@@ -313,3 +313,232 @@ fn test_use_reducer_version_tracking() {
         });
     });
 }
+
+#[test]
+fn test_use_reducer_with_middleware() {
+    with_test_isolate(|| {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let log_clone = log.clone();
+
+        with_component_id("ReducerMiddlewareTest", |_ctx| {
+            let (state, dispatch) = use_reducer_with_middleware(
+                counter_reducer,
+                0,
+                move |prev: &i32, action: &CounterAction, next: &i32| {
+                    log_clone.lock().push((*prev, action.clone(), *next));
+                },
+            );
+
+            dispatch.dispatch(CounterAction::Increment);
+            dispatch.dispatch(CounterAction::SetValue(10));
+            dispatch.dispatch(CounterAction::Decrement);
+            assert_eq!(state.get(), 9);
+        });
+
+        assert_eq!(
+            *log.lock(),
+            vec![
+                (0, CounterAction::Increment, 1),
+                (1, CounterAction::SetValue(10), 10),
+                (10, CounterAction::Decrement, 9),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_use_reducer_with_logging_middleware() {
+    with_test_isolate(|| {
+        with_component_id("ReducerLoggingTest", |_ctx| {
+            let (state, dispatch) =
+                use_reducer_with_middleware(counter_reducer, 5, logging_middleware);
+
+            dispatch.dispatch(CounterAction::Reset);
+            assert_eq!(state.get(), 0);
+        });
+    });
+}
+
+#[tokio::test]
+async fn test_dispatch_async_thunk() {
+    let (state, dispatch) =
+        with_component_id("ReducerThunkTest", |_ctx| use_reducer(counter_reducer, 0));
+
+    dispatch.dispatch_async(|dispatch| async move {
+        dispatch.dispatch(CounterAction::Increment);
+        tokio::task::yield_now().await;
+        dispatch.dispatch(CounterAction::SetValue(42));
+    });
+
+    for _ in 0..100 {
+        if state.get() == 42 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+    assert_eq!(state.get(), 42);
+    cleanup_component_contexts();
+}
+
+#[tokio::test]
+async fn test_dispatch_async_cancelled_on_unmount() {
+    let (state, dispatch) = with_component_id("ReducerThunkUnmountTest", |_ctx| {
+        use_reducer(counter_reducer, 0)
+    });
+
+    dispatch.dispatch_async(|dispatch| async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        dispatch.dispatch(CounterAction::Increment);
+    });
+
+    // Dropping the component's hook state unmounts the reducer
+    with_component_id("ReducerThunkUnmountTest", |ctx| ctx.clear());
+
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(state.get(), 0, "Aborted thunk must not dispatch");
+    cleanup_component_contexts();
+}
+
+/// A fresh file for a persistence test, as an absolute key
+fn persistence_key(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("reratui_{name}_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_persistence_path() {
+    let absolute = std::env::temp_dir().join("state.json");
+    assert_eq!(persistence_path(absolute.to_str().unwrap()), absolute);
+
+    let relative = persistence_path("settings");
+    assert!(relative.ends_with("reratui/settings.json"));
+}
+
+#[tokio::test]
+async fn test_persistent_reducer_restores_saved_state() {
+    let key = persistence_key("persistent_reducer_restore");
+
+    let (state, dispatch) = with_component_id("PersistentReducerTest", |_ctx| {
+        use_persistent_reducer(counter_reducer, &key, 5)
+    });
+    assert_eq!(state.get(), 5, "Nothing saved yet");
+    dispatch.call(CounterAction::Increment);
+    dispatch.call(CounterAction::Increment);
+    assert_eq!(state.get(), 7);
+    assert!(
+        std::fs::read_to_string(&key).is_err(),
+        "Writes wait for dispatches to pause"
+    );
+
+    tokio::time::sleep(PERSIST_DELAY * 2).await;
+    assert_eq!(std::fs::read_to_string(&key).unwrap(), "7");
+    cleanup_component_contexts();
+
+    // A later run starts from the saved state
+    let (state, _dispatch) = with_component_id("PersistentReducerTest", |_ctx| {
+        use_persistent_reducer(counter_reducer, &key, 5)
+    });
+    assert_eq!(state.get(), 7);
+    cleanup_component_contexts();
+    let _ = std::fs::remove_file(&key);
+}
+
+#[tokio::test]
+async fn test_persistent_reducer_flushes_when_dropped() {
+    let key = persistence_key("persistent_reducer_flush");
+
+    let (_state, dispatch) = with_component_id("PersistentReducerFlushTest", |_ctx| {
+        use_persistent_reducer(counter_reducer, &key, 0)
+    });
+    dispatch.call(CounterAction::SetValue(3));
+
+    // Dropping the hook state, as on exit, writes the pending save
+    with_component_id("PersistentReducerFlushTest", |ctx| ctx.clear());
+    assert_eq!(std::fs::read_to_string(&key).unwrap(), "3");
+    cleanup_component_contexts();
+    let _ = std::fs::remove_file(&key);
+}
+
+#[test]
+fn test_persistent_reducer_ignores_corrupt_state() {
+    let key = persistence_key("persistent_reducer_corrupt");
+    std::fs::write(&key, "not json").unwrap();
+
+    with_test_isolate(|| {
+        let (state, dispatch) = with_component_id("PersistentReducerCorruptTest", |_ctx| {
+            use_persistent_reducer(counter_reducer, &key, 1)
+        });
+        assert_eq!(state.get(), 1);
+
+        // Without a runtime the state is written right away
+        dispatch.call(CounterAction::Increment);
+        assert_eq!(std::fs::read_to_string(&key).unwrap(), "2");
+    });
+    let _ = std::fs::remove_file(&key);
+}
+
+#[test]
+fn test_reducer_devtools_time_travel() {
+    with_test_isolate(|| {
+        with_component_id("ReducerDevtoolsTest", |_ctx| {
+            let (state, dispatch, devtools) = use_reducer_devtools(counter_reducer, 0);
+            assert!(devtools.is_empty());
+            assert!(!devtools.step_back());
+
+            dispatch.call(CounterAction::Increment);
+            dispatch.call(CounterAction::SetValue(10));
+            dispatch.call(CounterAction::Decrement);
+            assert_eq!(
+                devtools.actions(),
+                vec![
+                    CounterAction::Increment,
+                    CounterAction::SetValue(10),
+                    CounterAction::Decrement,
+                ]
+            );
+            assert_eq!(devtools.labels()[1], "SetValue(10)");
+            assert_eq!(devtools.position(), Some(2));
+
+            let version = state.version();
+            assert!(devtools.jump_to(1));
+            assert_eq!(state.get(), 10);
+            assert!(state.version() > version, "Jumping counts as a change");
+            assert!(!devtools.jump_to(3));
+
+            assert!(devtools.step_back());
+            assert!(devtools.step_back());
+            assert_eq!(devtools.position(), None);
+            assert_eq!(state.get(), 0, "Back to the initial state");
+            assert!(devtools.step_forward());
+            assert_eq!(state.get(), 1);
+
+            // Dispatching from the past discards the later entries
+            dispatch.call(CounterAction::SetValue(5));
+            assert_eq!(
+                devtools.actions(),
+                vec![CounterAction::Increment, CounterAction::SetValue(5)]
+            );
+            assert!(!devtools.step_forward());
+        });
+    });
+}
+
+#[test]
+fn test_reducer_devtools_log_is_bounded() {
+    with_test_isolate(|| {
+        with_component_id("ReducerDevtoolsBoundTest", |_ctx| {
+            let (state, dispatch, devtools) = use_reducer_devtools(counter_reducer, 0);
+            for _ in 0..DEVTOOLS_CAPACITY + 5 {
+                dispatch.call(CounterAction::Increment);
+            }
+            assert_eq!(devtools.len(), DEVTOOLS_CAPACITY);
+            assert_eq!(state.get(), DEVTOOLS_CAPACITY as i32 + 5);
+
+            // The oldest state kept is the one before the first entry left
+            assert!(devtools.jump_to(0));
+            assert!(devtools.step_back());
+            assert_eq!(state.get(), 5);
+        });
+    });
+}
@@ -7,6 +7,10 @@
 //! - Keeping track of previous values
 //! - Managing timers, intervals, or other side-effect handles
 //! - Caching expensive computations
+//!
+//! A [`Ref`] passed down as a prop lets a child report a handle back to its
+//! parent, e.g. its rendered area or callbacks to focus it; see
+//! [`use_forward_ref`] and [`forward_ref`].
 
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -249,3 +253,89 @@ where
         RefHandle::from_container(container)
     })
 }
+
+/// A ref a child component fills during render for its parent to read
+///
+/// Created by the parent with [`use_forward_ref`] and passed to the child as a
+/// prop; the child stores its handle with [`forward_ref`].
+pub type Ref<T> = RefHandle<Option<T>>;
+
+/// Creates a [`Ref`] to forward to a child component
+///
+/// The ref is empty until the child renders. Children render after the
+/// parent's body, so the parent sees the handle from the previous frame.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::layout::Rect;
+/// use reratui_hooks::ref_hook::{Ref, forward_ref, use_forward_ref};
+///
+/// #[derive(Clone)]
+/// struct FieldHandle {
+///     area: Rect,
+/// }
+///
+/// // In the parent: create the ref and pass it as a prop
+/// let field_ref: Ref<FieldHandle> = use_forward_ref();
+///
+/// // In the child's render, with `field_ref: Option<Ref<FieldHandle>>` as a prop
+/// # let area = Rect::default();
+/// forward_ref(&Some(field_ref.clone()), FieldHandle { area });
+///
+/// // Back in the parent, on the next frame
+/// if let Some(field) = field_ref.get() {
+///     println!("field is at row {}", field.area.y);
+/// }
+/// ```
+pub fn use_forward_ref<T>() -> Ref<T>
+where
+    T: 'static,
+{
+    use_ref(|| None)
+}
+
+/// Stores `handle` in a ref prop, if the parent passed one
+pub fn forward_ref<T>(target: &Option<Ref<T>>, handle: T) {
+    if let Some(target) = target {
+        target.set(Some(handle));
+    }
+}
+
+/// Converts a value into a ref prop
+///
+/// Lets `Props` fields of type `Ref<T>` or `Option<Ref<T>>` be set from a ref
+/// or a reference to one in `rsx!`, e.g. `field_ref={&field_ref}`.
+pub trait IntoRefProp<P> {
+    fn into_ref_prop(self) -> P;
+}
+
+impl<T> IntoRefProp<Ref<T>> for Ref<T> {
+    fn into_ref_prop(self) -> Ref<T> {
+        self
+    }
+}
+
+impl<T> IntoRefProp<Ref<T>> for &Ref<T> {
+    fn into_ref_prop(self) -> Ref<T> {
+        self.clone()
+    }
+}
+
+impl<T> IntoRefProp<Option<Ref<T>>> for Ref<T> {
+    fn into_ref_prop(self) -> Option<Ref<T>> {
+        Some(self)
+    }
+}
+
+impl<T> IntoRefProp<Option<Ref<T>>> for &Ref<T> {
+    fn into_ref_prop(self) -> Option<Ref<T>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> IntoRefProp<Option<Ref<T>>> for Option<Ref<T>> {
+    fn into_ref_prop(self) -> Option<Ref<T>> {
+        self
+    }
+}
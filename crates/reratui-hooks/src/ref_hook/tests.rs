@@ -473,3 +473,34 @@ fn test_use_ref_swap_pattern() {
 
     context.clear();
 }
+
+#[test]
+fn test_forward_ref_reports_child_handle() {
+    use ratatui::layout::Rect;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FieldHandle {
+        area: Rect,
+    }
+
+    let context = setup_context();
+
+    let field_ref: Ref<FieldHandle> = use_forward_ref();
+    assert_eq!(field_ref.get(), None);
+
+    // The child fills the ref it was given as a prop
+    let prop: Option<Ref<FieldHandle>> = (&field_ref).into_ref_prop();
+    let area = Rect::new(0, 4, 20, 3);
+    forward_ref(&prop, FieldHandle { area });
+    assert_eq!(field_ref.get(), Some(FieldHandle { area }));
+
+    // Without a ref prop there is nothing to fill
+    forward_ref(&None, FieldHandle { area });
+
+    // The ref survives re-renders
+    context.reset_hook_index();
+    let field_ref_again: Ref<FieldHandle> = use_forward_ref();
+    assert_eq!(field_ref_again.get(), Some(FieldHandle { area }));
+
+    context.clear();
+}
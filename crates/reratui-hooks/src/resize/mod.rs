@@ -1,11 +1,19 @@
 //! Resize event hook for detecting terminal size changes
 //!
-//! This module provides the `use_on_resize` hook for responding to terminal resize events.
+//! This module provides the `use_on_resize` hook for responding to terminal resize events,
+//! and [`use_breakpoint`] for picking a layout from named width [`Breakpoints`].
+//!
+//! The runtime delivers a resize event with the real terminal size on the
+//! first frame, so handlers run once at startup without waiting for the user
+//! to resize the terminal.
 
+use crate::clock;
 use crate::{
-    callback::Callback, effect_event::use_effect_event, event::use_event, state::use_state,
+    callback::Callback, context::try_use_context, effect_event::use_effect_event, event::use_event,
+    ref_hook::use_ref, state::use_state,
 };
 use crossterm::event::Event;
+use std::time::{Duration, Instant};
 
 #[cfg(test)]
 mod tests;
@@ -162,6 +170,61 @@ where
     }
 }
 
+/// A hook that triggers a callback once the terminal has stopped resizing.
+///
+/// Dragging a terminal edge produces a stream of resize events. This hook
+/// waits until no resize has arrived for `delay` and then calls `callback`
+/// once, with the last dimensions. Use it for expensive work such as
+/// recomputing a cached layout or fetching data sized to the viewport.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::resize::{use_on_resize_debounced, use_terminal_dimensions};
+/// use std::time::Duration;
+///
+/// // Cheap: the layout follows every resize
+/// let (width, height) = use_terminal_dimensions();
+///
+/// // Expensive: only once the user lets go of the window edge
+/// use_on_resize_debounced(Duration::from_millis(250), |(width, height)| {
+///     println!("Rebuilding the chart cache for {}x{}", width, height);
+/// });
+/// ```
+///
+/// # Interaction with the immediate path
+///
+/// Debouncing delays only this callback. [`use_on_resize`],
+/// [`use_terminal_dimensions`] and the renderer itself still see every resize
+/// event, so the UI reflows immediately and this callback fires afterwards.
+/// A component may use both: reflow on every event and do the heavy work
+/// here. The resize the runtime delivers on the first frame is debounced too,
+/// so this callback first runs `delay` after startup.
+///
+/// The delay is checked on every render, so the callback runs on the first
+/// frame after the delay has passed.
+pub fn use_on_resize_debounced<F>(delay: Duration, callback: F)
+where
+    F: Fn((u16, u16)) + Clone + Send + Sync + 'static,
+{
+    let stable_handler = use_effect_event(move |dimensions: (u16, u16)| {
+        callback(dimensions);
+    });
+    // The latest dimensions and when they arrived, until they are reported
+    let pending = use_ref(|| None::<((u16, u16), Instant)>);
+
+    if let Some(Event::Resize(width, height)) = use_event() {
+        pending.set(Some(((width, height), clock::now())));
+    }
+
+    if let Some((dimensions, resized_at)) = pending.get()
+        && resized_at.elapsed() >= delay
+    {
+        pending.set(None);
+        stable_handler.emit(dimensions);
+    }
+}
+
 /// A hook that returns the current terminal dimensions as a tuple.
 ///
 /// This is a convenience hook that automatically tracks terminal size and returns
@@ -198,7 +261,8 @@ where
 ///
 /// # Notes
 ///
-/// - Returns (0, 0) until the first resize event occurs
+/// - Returns (0, 0) until the first resize event, which the runtime delivers on
+///   the first frame
 /// - Automatically updates when the terminal is resized
 /// - Re-renders the component when dimensions change
 /// - Most convenient API for read-only dimension access
@@ -298,7 +362,8 @@ pub fn use_terminal_dimensions() -> (u16, u16) {
 ///
 /// # Implementation Details
 ///
-/// - Returns `false` until the first resize event occurs (dimensions are 0x0)
+/// - Returns `false` until the first resize event, which the runtime delivers on
+///   the first frame (dimensions are 0x0)
 /// - Automatically re-evaluates when terminal is resized
 /// - Triggers component re-render when the predicate result changes
 /// - Predicate is only called when dimensions change
@@ -333,3 +398,120 @@ where
 
     matches.get()
 }
+
+/// A named range of terminal widths, as resolved by [`Breakpoints`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Narrow terminals, such as a split pane
+    Mobile,
+    /// Medium terminals
+    Tablet,
+    /// Wide terminals
+    Desktop,
+    /// A breakpoint defined by the app
+    Custom(&'static str),
+}
+
+/// Minimum widths at which each [`Breakpoint`] starts
+///
+/// Provide it through context to use the same thresholds across the app;
+/// [`use_breakpoint`] falls back to the default of [`Breakpoint::Mobile`]
+/// below 60 columns, [`Breakpoint::Tablet`] from 60 and
+/// [`Breakpoint::Desktop`] from 120.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::context::use_context_provider;
+/// use reratui_hooks::resize::{Breakpoint, Breakpoints};
+///
+/// use_context_provider(|| {
+///     Breakpoints::default()
+///         .desktop(100)
+///         .at(180, Breakpoint::Custom("ultrawide"))
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoints {
+    /// Minimum width of each breakpoint, in increasing order
+    thresholds: Vec<(u16, Breakpoint)>,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::empty()
+            .at(0, Breakpoint::Mobile)
+            .at(60, Breakpoint::Tablet)
+            .at(120, Breakpoint::Desktop)
+    }
+}
+
+impl Breakpoints {
+    /// No breakpoints, for apps that define all of their own with [`at`](Self::at)
+    pub fn empty() -> Self {
+        Self {
+            thresholds: Vec::new(),
+        }
+    }
+
+    /// Starts `breakpoint` at `min_width` columns, replacing its previous threshold
+    pub fn at(mut self, min_width: u16, breakpoint: Breakpoint) -> Self {
+        self.thresholds
+            .retain(|(_, existing)| *existing != breakpoint);
+        let index = self
+            .thresholds
+            .partition_point(|(width, _)| *width <= min_width);
+        self.thresholds.insert(index, (min_width, breakpoint));
+        self
+    }
+
+    /// Starts [`Breakpoint::Tablet`] at `min_width` columns
+    pub fn tablet(self, min_width: u16) -> Self {
+        self.at(min_width, Breakpoint::Tablet)
+    }
+
+    /// Starts [`Breakpoint::Desktop`] at `min_width` columns
+    pub fn desktop(self, min_width: u16) -> Self {
+        self.at(min_width, Breakpoint::Desktop)
+    }
+
+    /// The breakpoint a terminal `width` columns wide falls in
+    ///
+    /// That is the one with the largest threshold not above `width`. Widths
+    /// below every threshold get the narrowest breakpoint, and with no
+    /// breakpoints at all the answer is [`Breakpoint::Mobile`].
+    pub fn resolve(&self, width: u16) -> Breakpoint {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(min_width, _)| *min_width <= width)
+            .or(self.thresholds.first())
+            .map_or(Breakpoint::Mobile, |(_, breakpoint)| *breakpoint)
+    }
+}
+
+/// A hook that returns the named [`Breakpoint`] for the terminal width.
+///
+/// The thresholds come from the [`Breakpoints`] provided through context, or
+/// the defaults when none are. Until the first resize event, which the
+/// runtime delivers on the first frame, the width is 0.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::resize::{Breakpoint, use_breakpoint};
+///
+/// let columns = match use_breakpoint() {
+///     Breakpoint::Mobile => 1,
+///     Breakpoint::Tablet => 2,
+///     Breakpoint::Desktop | Breakpoint::Custom(_) => 3,
+/// };
+/// ```
+///
+/// [`use_media_query`] remains the tool for conditions breakpoints don't
+/// cover, such as height or aspect ratio.
+pub fn use_breakpoint() -> Breakpoint {
+    let breakpoints = try_use_context::<Breakpoints>().unwrap_or_default();
+    let (width, _) = use_terminal_dimensions();
+    breakpoints.resolve(width)
+}
@@ -533,3 +533,86 @@ fn test_use_media_query_updates_on_resize() {
         });
     });
 }
+
+#[test]
+fn test_use_on_resize_debounced_waits_for_resizing_to_stop() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let render = || {
+            let calls = calls.clone();
+            with_component_id("ResizeDebouncedTest", |_ctx| {
+                use_on_resize_debounced(Duration::from_millis(30), move |dimensions| {
+                    calls.lock().push(dimensions);
+                });
+            });
+        };
+
+        // A drag-resize: several events in quick succession
+        for width in [100, 110, 120] {
+            set_current_event(Some(Arc::new(Event::Resize(width, 40))));
+            render();
+        }
+        set_current_event(None);
+        render();
+        assert!(calls.lock().is_empty(), "Should wait for resizing to stop");
+
+        std::thread::sleep(Duration::from_millis(40));
+        render();
+        render();
+        assert_eq!(
+            *calls.lock(),
+            vec![(120, 40)],
+            "Should fire once with the last size"
+        );
+    });
+}
+
+#[test]
+fn test_breakpoints_resolve_widths() {
+    let defaults = Breakpoints::default();
+    assert_eq!(defaults.resolve(0), Breakpoint::Mobile);
+    assert_eq!(defaults.resolve(59), Breakpoint::Mobile);
+    assert_eq!(defaults.resolve(60), Breakpoint::Tablet);
+    assert_eq!(defaults.resolve(120), Breakpoint::Desktop);
+
+    // Moving a threshold replaces it, and custom breakpoints slot in by width
+    let custom = Breakpoints::default()
+        .desktop(100)
+        .at(180, Breakpoint::Custom("ultrawide"));
+    assert_eq!(custom.resolve(110), Breakpoint::Desktop);
+    assert_eq!(custom.resolve(200), Breakpoint::Custom("ultrawide"));
+
+    // Below every threshold is the narrowest breakpoint
+    let wide_only = Breakpoints::empty()
+        .at(100, Breakpoint::Custom("wide"))
+        .at(40, Breakpoint::Custom("narrow"));
+    assert_eq!(wide_only.resolve(10), Breakpoint::Custom("narrow"));
+    assert_eq!(wide_only.resolve(100), Breakpoint::Custom("wide"));
+    assert_eq!(Breakpoints::empty().resolve(80), Breakpoint::Mobile);
+}
+
+#[test]
+fn test_use_breakpoint_follows_resizes_and_context() {
+    use crate::context::use_context_provider;
+
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(Some(Arc::new(Event::Resize(80, 24))));
+        with_component_id("BreakpointTest", |_ctx| {
+            assert_eq!(use_breakpoint(), Breakpoint::Tablet);
+        });
+
+        set_current_event(Some(Arc::new(Event::Resize(130, 40))));
+        with_component_id("BreakpointTest", |_ctx| {
+            assert_eq!(use_breakpoint(), Breakpoint::Desktop);
+        });
+
+        // Thresholds provided by the app take over
+        set_current_event(None);
+        with_component_id("BreakpointTest", |_ctx| {
+            use_context_provider(|| Breakpoints::default().desktop(140));
+            assert_eq!(use_breakpoint(), Breakpoint::Tablet);
+        });
+    });
+}
@@ -0,0 +1,264 @@
+//! Scrolling state and a scrollable container component
+//!
+//! [`use_scroll`] keeps a vertical scroll offset clamped to the content.
+//! [`ScrollView`] builds on it: it renders children taller than its area,
+//! shows the visible window with a scrollbar on the right, and scrolls with the
+//! mouse wheel and the arrow keys. [`VirtualList`] scrolls through lists too
+//! long to render whole, rendering only the items in view.
+
+use crossterm::event::{Event, KeyCode, MouseEventKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget};
+use reratui_core::{Component, ComponentProps, Element};
+
+use crate::event::use_event;
+use crate::state::{StateHandle, StateSetter, use_state};
+use crate::visibility::with_clip;
+
+mod virtual_list;
+pub use virtual_list::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Lines scrolled per mouse wheel step
+pub const WHEEL_STEP: u16 = 3;
+
+/// A vertical scroll offset clamped to `0..=max_offset`
+#[derive(Clone)]
+pub struct ScrollHandle {
+    offset: StateHandle<u16>,
+    set_offset: StateSetter<u16>,
+    max_offset: u16,
+}
+
+impl ScrollHandle {
+    /// The first visible line
+    pub fn offset(&self) -> u16 {
+        self.offset.get().min(self.max_offset)
+    }
+
+    /// The largest possible offset
+    pub fn max_offset(&self) -> u16 {
+        self.max_offset
+    }
+
+    /// Scrolls to `offset`, clamped to the content
+    pub fn scroll_to(&self, offset: u16) {
+        self.set_offset.set(offset.min(self.max_offset));
+    }
+
+    /// Scrolls up by `lines`
+    pub fn scroll_up(&self, lines: u16) {
+        self.scroll_to(self.offset().saturating_sub(lines));
+    }
+
+    /// Scrolls down by `lines`
+    pub fn scroll_down(&self, lines: u16) {
+        self.scroll_to(self.offset().saturating_add(lines));
+    }
+
+    /// Scrolls to the first line
+    pub fn scroll_to_top(&self) {
+        self.scroll_to(0);
+    }
+
+    /// Scrolls to the last page
+    pub fn scroll_to_bottom(&self) {
+        self.scroll_to(self.max_offset);
+    }
+}
+
+/// Hook for a vertical scroll offset
+///
+/// `content_height` is the height of everything that can be scrolled and
+/// `viewport_height` the height of the visible window. The offset stays
+/// within the content even when it shrinks between renders.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::scroll::use_scroll;
+///
+/// let lines: Vec<String> = (0..100).map(|i| format!("line {}", i)).collect();
+/// let scroll = use_scroll(lines.len() as u16, 20);
+///
+/// // In a key handler
+/// scroll.scroll_down(1);
+///
+/// let visible = lines.iter().skip(scroll.offset() as usize).take(20);
+/// ```
+pub fn use_scroll(content_height: u16, viewport_height: u16) -> ScrollHandle {
+    let (offset, set_offset) = use_state(|| 0u16);
+
+    ScrollHandle {
+        offset,
+        set_offset,
+        max_offset: content_height.saturating_sub(viewport_height),
+    }
+}
+
+/// Props for the `ScrollView` component
+#[derive(Clone)]
+pub struct ScrollViewProps {
+    /// Height of the scrollable content; the area's height if zero
+    pub content_height: u16,
+    /// Whether the arrow, page and home/end keys scroll this view
+    pub focused: bool,
+    pub children: Vec<Element>,
+}
+
+impl Default for ScrollViewProps {
+    fn default() -> Self {
+        Self {
+            content_height: 0,
+            focused: true,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl ScrollViewProps {
+    /// Sets the height of the scrollable content
+    pub fn content_height(mut self, content_height: u16) -> Self {
+        self.content_height = content_height;
+        self
+    }
+
+    /// Sets whether keys scroll this view
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ScrollViewProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A scrollable window onto children taller than its area
+///
+/// The children are laid out in a virtual area of `content_height` lines
+/// (stacked vertically if there are several) and only the lines at the scroll
+/// offset are shown. A scrollbar is drawn in the rightmost column while the
+/// content doesn't fit. The mouse wheel scrolls the view when over it, and
+/// while `focused` so do Up/Down, PageUp/PageDown and Home/End.
+///
+/// Children scrolled out of the window see
+/// [`use_is_visible`](crate::visibility::use_is_visible) return `false`.
+///
+/// ```ignore
+/// rsx! {
+///     <ScrollView content_height={log_lines.len() as u16}>
+///         <Paragraph>{log_lines.join("\n")}</Paragraph>
+///     </ScrollView>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ScrollViewComponent {
+    props: ScrollViewProps,
+}
+
+impl ScrollViewComponent {
+    pub fn new(props: ScrollViewProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+
+    /// Renders the children into an off-screen buffer the size of the content
+    fn render_content(&self, width: u16, height: u16) -> Buffer {
+        let content_area = Rect::new(0, 0, width, height);
+        let mut content = Buffer::empty(content_area);
+
+        let children = &self.props.children;
+        if children.len() == 1 {
+            children[0].render(content_area, &mut content);
+        } else if !children.is_empty() {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(content_area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, &mut content);
+            }
+        }
+        content
+    }
+}
+
+impl Component for ScrollViewComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let content_height = if self.props.content_height == 0 {
+            area.height
+        } else {
+            self.props.content_height
+        };
+        let scroll = use_scroll(content_height, area.height);
+
+        match use_event() {
+            Some(Event::Mouse(mouse)) if area.contains(Position::new(mouse.column, mouse.row)) => {
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => scroll.scroll_up(WHEEL_STEP),
+                    MouseEventKind::ScrollDown => scroll.scroll_down(WHEEL_STEP),
+                    _ => {}
+                }
+            }
+            Some(Event::Key(key)) if self.props.focused && key.is_press() => match key.code {
+                KeyCode::Up => scroll.scroll_up(1),
+                KeyCode::Down => scroll.scroll_down(1),
+                KeyCode::PageUp => scroll.scroll_up(area.height),
+                KeyCode::PageDown => scroll.scroll_down(area.height),
+                KeyCode::Home => scroll.scroll_to_top(),
+                KeyCode::End => scroll.scroll_to_bottom(),
+                _ => {}
+            },
+            _ => {}
+        }
+
+        // Content that fits needs no scrollbar, so it gets the full width
+        let scrollable = scroll.max_offset() > 0;
+        let content_width = if scrollable {
+            area.width.saturating_sub(1)
+        } else {
+            area.width
+        };
+
+        // Children outside the shown window report themselves as not visible
+        let offset = scroll.offset();
+        let window = Rect::new(0, offset, content_width, area.height);
+        let content = with_clip(area, window, || {
+            self.render_content(content_width, content_height)
+        });
+        for y in 0..area.height.min(content_height - offset) {
+            for x in 0..content_width {
+                buffer[(area.x + x, area.y + y)] = content[(x, offset + y)].clone();
+            }
+        }
+
+        if scrollable {
+            let mut state = ScrollbarState::new(scroll.max_offset() as usize)
+                .viewport_content_length(area.height as usize)
+                .position(offset as usize);
+            Scrollbar::new(ScrollbarOrientation::VerticalRight).render(area, buffer, &mut state);
+        }
+    }
+}
+
+/// The `ScrollView` component, usable as `<ScrollView content_height={...}>` in `rsx!`
+pub type ScrollView = ScrollViewComponent;
@@ -0,0 +1,218 @@
+//! Tests for scrolling
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{KeyEvent, KeyModifiers, MouseEvent};
+use parking_lot::Mutex;
+use ratatui::widgets::Paragraph;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn numbered_lines(count: usize) -> Element {
+    let text = (0..count)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Element::widget(Paragraph::new(text))
+}
+
+fn render_view(view: &ScrollView, area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("ScrollView", |_ctx| view.render(area, &mut buffer));
+    buffer
+}
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect()
+}
+
+#[test]
+fn test_use_scroll_clamps_offset() {
+    with_component_id("ScrollTest", |_ctx| {
+        let scroll = use_scroll(10, 4);
+        assert_eq!(scroll.max_offset(), 6);
+
+        scroll.scroll_up(3);
+        assert_eq!(scroll.offset(), 0);
+
+        scroll.scroll_down(4);
+        assert_eq!(scroll.offset(), 4);
+        scroll.scroll_down(10);
+        assert_eq!(scroll.offset(), 6);
+
+        scroll.scroll_to_top();
+        assert_eq!(scroll.offset(), 0);
+        scroll.scroll_to_bottom();
+        assert_eq!(scroll.offset(), 6);
+    });
+
+    // Content shorter than the viewport can't scroll
+    with_component_id("ShortScrollTest", |_ctx| {
+        let scroll = use_scroll(3, 4);
+        scroll.scroll_down(1);
+        assert_eq!(scroll.max_offset(), 0);
+        assert_eq!(scroll.offset(), 0);
+    });
+}
+
+#[test]
+fn test_scroll_view_scrolls_with_keys_and_wheel() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 4, 3);
+        let view = ScrollViewComponent::new(
+            ScrollViewProps::default()
+                .content_height(10)
+                .with_children(vec![numbered_lines(10)]),
+        );
+
+        set_current_event(None);
+        let buffer = render_view(&view, area);
+        assert_eq!(row(&buffer, 0).trim_end_matches(['▲', ' ']), "0");
+        // The scrollbar takes the rightmost column
+        assert_ne!(buffer[(3, 1)].symbol(), " ");
+
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(down))));
+        render_view(&view, area);
+        set_current_event(None);
+        let buffer = render_view(&view, area);
+        assert!(row(&buffer, 0).starts_with('1'));
+        assert!(row(&buffer, 2).starts_with('3'));
+
+        let wheel = MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 1,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        };
+        set_current_event(Some(Arc::new(Event::Mouse(wheel))));
+        render_view(&view, area);
+        set_current_event(None);
+        let buffer = render_view(&view, area);
+        assert!(row(&buffer, 0).starts_with('4'));
+
+        // The wheel outside the view is ignored
+        let outside = MouseEvent { column: 9, ..wheel };
+        set_current_event(Some(Arc::new(Event::Mouse(outside))));
+        render_view(&view, area);
+        set_current_event(None);
+        let buffer = render_view(&view, area);
+        assert!(row(&buffer, 0).starts_with('4'));
+    });
+}
+
+#[test]
+fn test_scroll_view_hides_scrollbar_when_content_fits() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let area = Rect::new(0, 0, 4, 3);
+        let view = ScrollViewComponent::new(
+            ScrollViewProps::default()
+                .content_height(2)
+                .with_children(vec![Element::widget(Paragraph::new("abcd\nefgh"))]),
+        );
+
+        let buffer = render_view(&view, area);
+        assert_eq!(row(&buffer, 0), "abcd");
+        assert_eq!(row(&buffer, 1), "efgh");
+        assert_eq!(row(&buffer, 2), "    ");
+    });
+}
+
+fn render_list(list: &VirtualList, area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    with_component_id("VirtualList", |_ctx| list.render(area, &mut buffer));
+    buffer
+}
+
+#[test]
+fn test_visible_items() {
+    assert_eq!(visible_items(100, 1, 0, 5), 0..5);
+    assert_eq!(visible_items(100, 2, 10, 5), 10..13);
+    assert_eq!(visible_items(3, 1, 0, 5), 0..3);
+    assert_eq!(visible_items(3, 1, 7, 5), 3..3);
+    assert_eq!(visible_items(100, 0, 0, 2), 0..2);
+}
+
+#[test]
+fn test_virtual_list_renders_only_visible_items() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let rendered = Rc::new(RefCell::new(Vec::new()));
+        let list =
+            VirtualListComponent::new(VirtualListProps::default().item_count(100_000).render_item(
+                {
+                    let rendered = rendered.clone();
+                    move |index| {
+                        rendered.borrow_mut().push(index);
+                        Element::widget(Paragraph::new(index.to_string()))
+                    }
+                },
+            ));
+        let area = Rect::new(0, 0, 8, 3);
+
+        let buffer = render_list(&list, area);
+        assert_eq!(*rendered.borrow(), [0, 1, 2]);
+        assert!(row(&buffer, 2).starts_with('2'));
+        // The scrollbar takes the rightmost column
+        assert_ne!(buffer[(7, 1)].symbol(), " ");
+
+        let end = KeyEvent::new(KeyCode::End, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(end))));
+        render_list(&list, area);
+        set_current_event(None);
+        rendered.borrow_mut().clear();
+        let buffer = render_list(&list, area);
+        assert_eq!(*rendered.borrow(), [99_997, 99_998, 99_999]);
+        assert!(row(&buffer, 2).starts_with("99999"));
+
+        let up = KeyEvent::new(KeyCode::PageUp, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(up))));
+        render_list(&list, area);
+        set_current_event(None);
+        let buffer = render_list(&list, area);
+        assert!(row(&buffer, 0).starts_with("99994"));
+    });
+}
+
+#[test]
+fn test_virtual_list_with_taller_items() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let list = VirtualListComponent::new(
+            VirtualListProps::default()
+                .item_count(2)
+                .item_height(2)
+                .render_item(|index| {
+                    Element::widget(Paragraph::new(format!("{index}a\n{index}b")))
+                }),
+        );
+
+        // The second item is cut off, so the list can scroll to show it
+        let area = Rect::new(0, 0, 3, 3);
+        let buffer = render_list(&list, area);
+        assert!(row(&buffer, 0).starts_with("0a"));
+        assert!(row(&buffer, 1).starts_with("0b"));
+        assert!(row(&buffer, 2).starts_with("1a"));
+
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(down))));
+        render_list(&list, area);
+        set_current_event(None);
+        let buffer = render_list(&list, area);
+        assert!(row(&buffer, 0).starts_with("1a"));
+        assert!(row(&buffer, 1).starts_with("1b"));
+        assert!(row(&buffer, 2).starts_with("  "));
+    });
+}
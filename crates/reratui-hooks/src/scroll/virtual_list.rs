@@ -0,0 +1,192 @@
+//! A list that renders only the items in view
+
+use crossterm::event::{Event, KeyCode, MouseEventKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget};
+use reratui_core::{Component, ComponentProps, Element};
+use std::ops::Range;
+use std::rc::Rc;
+
+use super::WHEEL_STEP;
+use crate::event::use_event;
+use crate::state::use_state;
+
+/// Props for the `VirtualList` component
+#[derive(Clone)]
+pub struct VirtualListProps {
+    /// Number of items in the list
+    pub item_count: usize,
+    /// Rows each item takes, at least one
+    pub item_height: u16,
+    /// Renders the item at an index
+    #[allow(clippy::type_complexity)]
+    pub render_item: Option<Rc<dyn Fn(usize) -> Element>>,
+    /// Whether the arrow, page and home/end keys scroll this list
+    pub focused: bool,
+}
+
+impl Default for VirtualListProps {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            item_height: 1,
+            render_item: None,
+            focused: true,
+        }
+    }
+}
+
+impl VirtualListProps {
+    /// Sets the number of items
+    pub fn item_count(mut self, item_count: usize) -> Self {
+        self.item_count = item_count;
+        self
+    }
+
+    /// Sets the rows each item takes
+    pub fn item_height(mut self, item_height: u16) -> Self {
+        self.item_height = item_height;
+        self
+    }
+
+    /// Sets how the item at an index is rendered
+    pub fn render_item(mut self, render_item: impl Fn(usize) -> Element + 'static) -> Self {
+        self.render_item = Some(Rc::new(render_item));
+        self
+    }
+
+    /// Sets whether keys scroll this list
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// A virtual list has no children; they are ignored
+    pub fn with_children(self, _children: Vec<Element>) -> Self {
+        self
+    }
+}
+
+impl ComponentProps for VirtualListProps {
+    fn get_children(&self) -> Vec<Element> {
+        Vec::new()
+    }
+
+    fn set_children(&mut self, _children: Vec<Element>) {}
+}
+
+/// The indices of the items shown from `offset` in a viewport `height` rows tall
+///
+/// The last item may be cut off at the bottom.
+pub fn visible_items(
+    item_count: usize,
+    item_height: u16,
+    offset: usize,
+    height: u16,
+) -> Range<usize> {
+    let item_height = item_height.max(1);
+    let shown = usize::from(height.div_ceil(item_height));
+    let start = offset.min(item_count);
+    start..start.saturating_add(shown).min(item_count)
+}
+
+/// A scrollable list that only renders the items in view
+///
+/// `render_item` is called for the indices within the viewport alone, so the
+/// work per frame depends on the height of the list and not on `item_count`,
+/// which makes lists of many thousands of items as cheap as short ones. Every
+/// item is `item_height` rows tall.
+///
+/// The list scrolls by whole items and keeps its own offset, since the line
+/// offset of [`use_scroll`](super::use_scroll) can't address more than
+/// `u16::MAX` rows. Like [`ScrollView`](super::ScrollView), it scrolls with
+/// the mouse wheel when over it and, while `focused`, with Up/Down,
+/// PageUp/PageDown and Home/End, and shows a scrollbar while the items don't
+/// fit.
+///
+/// ```ignore
+/// rsx! {
+///     <VirtualList
+///         item_count={log_lines.len()}
+///         render_item={move |i| rsx! { <Paragraph>{log_lines[i].clone()}</Paragraph> }}
+///     />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct VirtualListComponent {
+    props: VirtualListProps,
+}
+
+impl VirtualListComponent {
+    pub fn new(props: VirtualListProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(self, _children: Vec<Element>) -> Self {
+        self
+    }
+}
+
+impl Component for VirtualListComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let (offset, set_offset) = use_state(|| 0usize);
+
+        let item_count = self.props.item_count;
+        let item_height = self.props.item_height.max(1);
+        // Items entirely within the viewport; scrolling stops once the last
+        // item is fully shown
+        let page = usize::from((area.height / item_height).max(1));
+        let max_offset = item_count.saturating_sub(page);
+        let scroll_to = |target: usize| set_offset.set(target.min(max_offset));
+        let current = offset.get().min(max_offset);
+
+        match use_event() {
+            Some(Event::Mouse(mouse)) if area.contains(Position::new(mouse.column, mouse.row)) => {
+                let step = usize::from(WHEEL_STEP);
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => scroll_to(current.saturating_sub(step)),
+                    MouseEventKind::ScrollDown => scroll_to(current.saturating_add(step)),
+                    _ => {}
+                }
+            }
+            Some(Event::Key(key)) if self.props.focused && key.is_press() => match key.code {
+                KeyCode::Up => scroll_to(current.saturating_sub(1)),
+                KeyCode::Down => scroll_to(current.saturating_add(1)),
+                KeyCode::PageUp => scroll_to(current.saturating_sub(page)),
+                KeyCode::PageDown => scroll_to(current.saturating_add(page)),
+                KeyCode::Home => scroll_to(0),
+                KeyCode::End => scroll_to(max_offset),
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let current = offset.get().min(max_offset);
+        let scrollable = max_offset > 0;
+        let content_width = if scrollable {
+            area.width.saturating_sub(1)
+        } else {
+            area.width
+        };
+
+        if let Some(render_item) = &self.props.render_item {
+            let mut y = area.y;
+            for index in visible_items(item_count, item_height, current, area.height) {
+                let height = item_height.min(area.bottom() - y);
+                render_item(index).render(Rect::new(area.x, y, content_width, height), buffer);
+                y += height;
+            }
+        }
+
+        if scrollable {
+            let mut state = ScrollbarState::new(max_offset)
+                .viewport_content_length(page)
+                .position(current);
+            Scrollbar::new(ScrollbarOrientation::VerticalRight).render(area, buffer, &mut state);
+        }
+    }
+}
+
+/// The `VirtualList` component, usable as `<VirtualList item_count={...} render_item={...} />` in `rsx!`
+pub type VirtualList = VirtualListComponent;
@@ -0,0 +1,174 @@
+//! Animated spinners
+//!
+//! [`Spinner`] renders a single animated glyph; [`use_spinner`] returns the
+//! current glyph for components that put it next to text. Both advance with
+//! the time since the component mounted rather than the frame count, so a
+//! spinner turns at the same speed no matter how often frames are drawn.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use reratui_core::{Component, ComponentProps, Element};
+use std::time::Duration;
+
+use crate::frame::frame_time;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// Time each frame of a spinner is shown for, unless configured otherwise
+pub const DEFAULT_SPINNER_SPEED_MS: u64 = 80;
+
+/// The built-in spinner animations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerVariant {
+    /// `⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏`
+    #[default]
+    Braille,
+    /// `⣾ ⣽ ⣻ ⢿ ⡿ ⣟ ⣯ ⣷`
+    Dots,
+    /// `- \ | /`
+    Line,
+    /// `◜ ◠ ◝ ◞ ◡ ◟`
+    Arc,
+    /// `⠁ ⠂ ⠄ ⠂`
+    Bounce,
+}
+
+impl SpinnerVariant {
+    /// The glyphs of one turn of the animation
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            Self::Braille => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Self::Dots => &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+            Self::Line => &["-", "\\", "|", "/"],
+            Self::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+            Self::Bounce => &["⠁", "⠂", "⠄", "⠂"],
+        }
+    }
+
+    /// The glyph shown `elapsed` after the start when each is shown for `speed`
+    pub fn frame_at(self, elapsed: Duration, speed: Duration) -> &'static str {
+        let frames = self.frames();
+        let step = elapsed.as_millis() / speed.as_millis().max(1);
+        frames[(step % frames.len() as u128) as usize]
+    }
+}
+
+/// Hook returning the current glyph of a spinner
+///
+/// The animation starts when the component first renders and advances one
+/// glyph every `speed`. Time is taken from the frame being rendered, so every
+/// spinner in a frame agrees.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::spinner::{SpinnerVariant, use_spinner};
+/// use std::time::Duration;
+///
+/// let glyph = use_spinner(SpinnerVariant::Braille, Duration::from_millis(80));
+/// let status = format!("{} Loading...", glyph);
+/// ```
+pub fn use_spinner(variant: SpinnerVariant, speed: Duration) -> &'static str {
+    let now = frame_time();
+    let started = use_ref(|| now).get();
+
+    variant.frame_at(now.saturating_duration_since(started), speed)
+}
+
+/// Props for the `Spinner` component
+#[derive(Clone)]
+pub struct SpinnerProps {
+    pub style: Style,
+    pub variant: SpinnerVariant,
+    /// Milliseconds each glyph is shown for
+    pub speed_ms: u64,
+    pub children: Vec<Element>,
+}
+
+impl Default for SpinnerProps {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            variant: SpinnerVariant::default(),
+            speed_ms: DEFAULT_SPINNER_SPEED_MS,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl SpinnerProps {
+    /// Sets the style of the glyph
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the animation
+    pub fn variant(mut self, variant: SpinnerVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets how many milliseconds each glyph is shown for
+    pub fn speed_ms(mut self, speed_ms: u64) -> Self {
+        self.speed_ms = speed_ms;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for SpinnerProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A single animated glyph in the top-left corner of its area
+///
+/// ```ignore
+/// rsx! {
+///     <Spinner variant={SpinnerVariant::Arc} speed_ms={100} style={Style::default().fg(Color::Cyan)} />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SpinnerComponent {
+    props: SpinnerProps,
+}
+
+impl SpinnerComponent {
+    pub fn new(props: SpinnerProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for SpinnerComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let glyph = use_spinner(
+            self.props.variant,
+            Duration::from_millis(self.props.speed_ms),
+        );
+
+        if !area.is_empty() {
+            buffer.set_string(area.x, area.y, glyph, self.props.style);
+        }
+    }
+}
+
+/// The `Spinner` component, usable as `<Spinner variant={...} />` in `rsx!`
+pub type Spinner = SpinnerComponent;
@@ -0,0 +1,75 @@
+//! Tests for spinners
+
+use super::*;
+use crate::context::use_context_provider;
+use crate::test_utils::{frame_context_at, with_component_id};
+use ratatui::style::Color;
+use std::time::Instant;
+
+#[test]
+fn test_frame_at_depends_only_on_elapsed_time() {
+    let speed = Duration::from_millis(100);
+    let line = SpinnerVariant::Line;
+
+    assert_eq!(line.frame_at(Duration::ZERO, speed), "-");
+    assert_eq!(line.frame_at(Duration::from_millis(99), speed), "-");
+    assert_eq!(line.frame_at(Duration::from_millis(100), speed), "\\");
+    assert_eq!(line.frame_at(Duration::from_millis(350), speed), "/");
+    // Wraps after one turn
+    assert_eq!(line.frame_at(Duration::from_millis(400), speed), "-");
+
+    // A zero speed doesn't divide by zero
+    assert_eq!(line.frame_at(Duration::from_millis(2), Duration::ZERO), "|");
+}
+
+#[test]
+fn test_every_variant_has_frames() {
+    for variant in [
+        SpinnerVariant::Braille,
+        SpinnerVariant::Dots,
+        SpinnerVariant::Line,
+        SpinnerVariant::Arc,
+        SpinnerVariant::Bounce,
+    ] {
+        assert!(!variant.frames().is_empty());
+        assert!(
+            variant
+                .frames()
+                .iter()
+                .all(|glyph| glyph.chars().count() == 1)
+        );
+    }
+}
+
+#[test]
+fn test_spinner_advances_with_frame_time() {
+    let start = Instant::now();
+    let spinner = SpinnerComponent::new(
+        SpinnerProps::default()
+            .variant(SpinnerVariant::Line)
+            .speed_ms(50)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    let render = |elapsed_ms| {
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+        with_component_id("SpinnerTest", |_ctx| {
+            use_context_provider(|| frame_context_at(start + Duration::from_millis(elapsed_ms)));
+            spinner.render(area, &mut buffer);
+        });
+        buffer
+    };
+
+    let buffer = render(0);
+    assert_eq!(buffer[(0, 0)].symbol(), "-");
+    assert_eq!(buffer[(0, 0)].fg, Color::Cyan);
+    assert_eq!(buffer[(1, 0)].symbol(), " ");
+
+    // Ten frames within one glyph's time don't advance it
+    for _ in 0..10 {
+        assert_eq!(render(30)[(0, 0)].symbol(), "-");
+    }
+    assert_eq!(render(60)[(0, 0)].symbol(), "\\");
+    assert_eq!(render(160)[(0, 0)].symbol(), "/");
+}
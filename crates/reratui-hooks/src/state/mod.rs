@@ -1,6 +1,7 @@
 use crate::hook_context::with_hook_context;
-use parking_lot::{Mutex, RwLock};
-use std::{fmt, sync::Arc};
+use parking_lot::{Mutex, RwLock, RwLockWriteGuard};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::{cell::Cell, fmt, sync::Arc};
 
 #[cfg(test)]
 mod tests;
@@ -44,33 +45,48 @@ impl<T> StateContainer<T> {
             *version += 1;
         }
 
-        // TODO: Trigger re-render notification
-        // This would integrate with the component re-render system
+        // Tell the renderer that the next frame may differ from the last one
+        bump_state_epoch();
     }
 
     /// Set a new value (thread-safe write)
     pub fn set(&self, new_value: T) {
-        {
-            let mut value = self.value.write();
-            *value = new_value;
-        }
+        let mut value = self.value.write();
+        *value = new_value;
+        // Bumped before the value is released, so versions follow write order
+        self.increment_version_and_notify();
+    }
 
+    /// Set a new value unless it equals the current one
+    ///
+    /// Returns whether the value changed. An equal value leaves the version
+    /// untouched, so nothing watching it sees a change.
+    pub fn set_if_changed(&self, new_value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut value = self.value.write();
+        if *value == new_value {
+            return false;
+        }
+        *value = new_value;
         self.increment_version_and_notify();
+        true
     }
 
     /// Update the value using a function (functional update pattern)
+    ///
+    /// `updater` runs while the value is locked for writing, so it sees every
+    /// write that happened before it and concurrent updates never overwrite
+    /// each other.
     pub fn update<F>(&self, updater: F)
     where
         F: FnOnce(&T) -> T,
         T: Clone,
     {
         // Perform atomic read-modify-write operation
-        {
-            let mut value = self.value.write();
-            let new_value = updater(&*value);
-            *value = new_value;
-        }
-
+        let mut value = self.value.write();
+        *value = updater(&*value);
         self.increment_version_and_notify();
     }
 
@@ -163,8 +179,29 @@ impl<T> StateSetter<T> {
         self.container.set(new_value);
     }
 
+    /// Set the state unless the new value equals the current one
+    ///
+    /// Returns whether the state changed. Setting an equal value is a no-op
+    /// that leaves the [`version`](Self::version) as it was, which makes this
+    /// the cheap choice for handlers that run on every event, such as hover
+    /// tracking.
+    pub fn set_if_changed(&self, new_value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.container.set_if_changed(new_value)
+    }
+
     /// Update the state using a function (functional update)
     /// This mirrors React's setState(prevState => newState) pattern
+    ///
+    /// `updater` receives the value current when the update is applied, not
+    /// the one a [`StateHandle`] returned at render time, and updates from
+    /// several threads are applied one at a time. Prefer it over
+    /// `set(handle.get() + 1)` in callbacks that may run after later updates,
+    /// such as intervals and spawned tasks, where the captured value is stale.
+    ///
+    /// `updater` must not read or write this same state, which would deadlock.
     pub fn update<F>(&self, updater: F)
     where
         F: FnOnce(&T) -> T,
@@ -429,3 +466,98 @@ where
         (state_handle, setter)
     })
 }
+
+/// Bumped on every state change, by any state
+static STATE_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// Counter bumped whenever any state changes
+///
+/// The runtime compares it between frames to tell whether anything rendered
+/// from state may have changed. Only the comparison is meaningful.
+pub fn state_epoch() -> u64 {
+    STATE_EPOCH.load(Ordering::Acquire)
+}
+
+/// Bumps the state epoch for changes that may affect rendering without
+/// going through a state
+pub(crate) fn bump_state_epoch() {
+    STATE_EPOCH.fetch_add(1, Ordering::Release);
+}
+
+/// Held by [`batch`] for reading and by [`lock_frame`] for writing
+static FRAME_GATE: RwLock<()> = RwLock::new(());
+
+thread_local! {
+    /// Whether this thread holds the gate, through a batch or a frame
+    static HOLDS_GATE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Releases the frame gate and the thread's claim on it when dropped
+struct GateClaim;
+
+impl GateClaim {
+    /// Claims the gate for this thread, or returns `None` if it already has it
+    fn new() -> Option<Self> {
+        (!HOLDS_GATE.replace(true)).then_some(Self)
+    }
+}
+
+impl Drop for GateClaim {
+    fn drop(&mut self) {
+        HOLDS_GATE.set(false);
+    }
+}
+
+/// Runs `updates` so that its state changes reach the screen together
+///
+/// The runtime does not draw a frame while a batch is running, so the updates
+/// made inside it are all applied before the next draw and no frame shows
+/// some of them without the others. This matters for callbacks on other
+/// threads, such as intervals and spawned tasks, that set several pieces of
+/// state; event handlers run by the runtime are batched already.
+///
+/// Batches may be nested and may run on several threads at once.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::state::{batch, use_state};
+///
+/// let (loading, set_loading) = use_state(|| true);
+/// let (items, set_items) = use_state(Vec::<String>::new);
+///
+/// std::thread::spawn(move || {
+///     let fetched = vec!["one".to_string()];
+///     // Never drawn with the items shown and the spinner still spinning
+///     batch(|| {
+///         set_items.set(fetched);
+///         set_loading.set(false);
+///     });
+/// });
+/// ```
+pub fn batch<R>(updates: impl FnOnce() -> R) -> R {
+    let Some(_claim) = GateClaim::new() else {
+        // Already inside a batch or a frame on this thread
+        return updates();
+    };
+    let _gate = FRAME_GATE.read();
+    updates()
+}
+
+/// Holds off [`batch`]es on other threads until dropped
+///
+/// Returned by [`lock_frame`].
+pub struct FrameLock {
+    _gate: Option<(RwLockWriteGuard<'static, ()>, GateClaim)>,
+}
+
+/// Waits for running batches to finish and keeps new ones from starting
+///
+/// The runtime holds the lock while it runs the global key handlers for an
+/// event and while it draws, so a frame never shows a batch half-applied. Batches started on this thread
+/// while it is held run straight away.
+pub fn lock_frame() -> FrameLock {
+    FrameLock {
+        _gate: GateClaim::new().map(|claim| (FRAME_GATE.write(), claim)),
+    }
+}
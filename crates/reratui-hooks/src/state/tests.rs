@@ -281,3 +281,92 @@ fn test_use_state_field_access() {
         });
     });
 }
+
+/// Test that concurrent functional updates are never lost
+#[test]
+fn test_concurrent_updates_lose_no_updates() {
+    const THREADS: usize = 8;
+    const UPDATES: usize = 1000;
+
+    with_test_isolate(|| {
+        with_component_id("ConcurrentUpdateComponent", |_context| {
+            let (count, set_count) = use_state(|| 0usize);
+            let barrier = Arc::new(Barrier::new(THREADS));
+
+            let workers: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    let set_count = set_count.clone();
+                    let barrier = barrier.clone();
+                    thread::spawn(move || {
+                        barrier.wait();
+                        for _ in 0..UPDATES {
+                            set_count.update(|n| n + 1);
+                        }
+                    })
+                })
+                .collect();
+            for worker in workers {
+                worker.join().unwrap();
+            }
+
+            assert_eq!(count.get(), THREADS * UPDATES);
+        });
+    });
+}
+
+/// Test that setting an equal value is not recorded as a change
+#[test]
+fn test_set_if_changed_skips_equal_values() {
+    with_test_isolate(|| {
+        with_component_id("SetIfChangedComponent", |_context| {
+            let (hovering, set_hovering) = use_state(|| false);
+
+            assert!(!set_hovering.set_if_changed(false));
+            assert_eq!(hovering.version(), 0);
+
+            assert!(set_hovering.set_if_changed(true));
+            assert!(hovering.get());
+            assert_eq!(hovering.version(), 1);
+
+            // Every later event reports the same position
+            for _ in 0..10 {
+                assert!(!set_hovering.set_if_changed(true));
+            }
+            assert_eq!(hovering.version(), 1);
+        });
+    });
+}
+
+/// Test that a frame never sees a batch from another thread half-applied
+#[test]
+fn test_batch_is_applied_before_the_next_frame() {
+    use crate::state::{batch, lock_frame};
+    use std::sync::mpsc;
+
+    with_test_isolate(|| {
+        with_component_id("BatchComponent", |_context| {
+            let (items, set_items) = use_state(|| 0);
+            let (loading, set_loading) = use_state(|| true);
+
+            let (started, batch_started) = mpsc::channel();
+            let worker = thread::spawn(move || {
+                batch(|| {
+                    set_items.set(3);
+                    started.send(()).unwrap();
+                    thread::sleep(Duration::from_millis(50));
+                    // Nested batches run inside the outer one
+                    batch(|| set_loading.set(false));
+                });
+            });
+
+            batch_started.recv().unwrap();
+            let frame = lock_frame();
+            assert_eq!((items.get(), loading.get()), (3, false));
+
+            // Updates made while drawing don't wait for the frame
+            batch(|| assert_eq!(items.get(), 3));
+            drop(frame);
+            worker.join().unwrap();
+        });
+    });
+}
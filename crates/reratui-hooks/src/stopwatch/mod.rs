@@ -0,0 +1,145 @@
+//! Stopwatch hook for tracking elapsed time across renders
+//!
+//! This module provides the `use_stopwatch` hook, which measures time spent
+//! running between `start` and `stop` calls and records lap times.
+
+use crate::clock;
+use crate::ref_hook::{RefHandle, use_ref};
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// Accumulated stopwatch time
+///
+/// All transitions take the current instant as an argument so that every
+/// interval boundary is read exactly once: time before it is counted by the
+/// interval that ended, time after it by the one that started.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StopwatchState {
+    /// Time accumulated by completed running intervals
+    accumulated: Duration,
+    /// Start of the current running interval, if running
+    running_since: Option<Instant>,
+    /// Elapsed time at the last lap
+    last_lap: Duration,
+    /// Recorded lap times, oldest first
+    laps: Vec<Duration>,
+}
+
+impl StopwatchState {
+    pub(crate) fn elapsed(&self, now: Instant) -> Duration {
+        match self.running_since {
+            Some(since) => self.accumulated + now.saturating_duration_since(since),
+            None => self.accumulated,
+        }
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub(crate) fn start(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    pub(crate) fn stop(&mut self, now: Instant) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += now.saturating_duration_since(since);
+        }
+    }
+
+    pub(crate) fn lap(&mut self, now: Instant) -> Duration {
+        let elapsed = self.elapsed(now);
+        let lap = elapsed.saturating_sub(self.last_lap);
+        self.last_lap = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Handle returned by [`use_stopwatch`]
+///
+/// Cloning the handle shares the same stopwatch, so it can be moved into
+/// event handlers.
+#[derive(Clone)]
+pub struct Stopwatch {
+    state: RefHandle<StopwatchState>,
+}
+
+impl Stopwatch {
+    /// Total running time, including the current interval if running
+    pub fn elapsed(&self) -> Duration {
+        let now = clock::now();
+        self.state.with(|state| state.elapsed(now))
+    }
+
+    /// Starts or resumes the stopwatch; does nothing if already running
+    pub fn start(&self) {
+        let now = clock::now();
+        self.state.with_mut(|state| state.start(now));
+    }
+
+    /// Pauses the stopwatch, keeping the elapsed time
+    pub fn stop(&self) {
+        let now = clock::now();
+        self.state.with_mut(|state| state.stop(now));
+    }
+
+    /// Stops the stopwatch and clears the elapsed time and laps
+    pub fn reset(&self) {
+        self.state.with_mut(StopwatchState::reset);
+    }
+
+    /// Records a lap and returns the time since the previous lap (or since
+    /// the stopwatch started)
+    pub fn lap(&self) -> Duration {
+        let now = clock::now();
+        self.state.with_mut(|state| state.lap(now))
+    }
+
+    /// All recorded lap times, oldest first
+    pub fn laps(&self) -> Vec<Duration> {
+        self.state.with(|state| state.laps.clone())
+    }
+
+    /// Whether the stopwatch is currently running
+    pub fn is_running(&self) -> bool {
+        self.state.with(StopwatchState::is_running)
+    }
+}
+
+/// A hook that tracks elapsed time with start, stop, lap and reset controls.
+///
+/// The stopwatch starts stopped. Elapsed time is the sum of all intervals
+/// between `start` and `stop`, read from the clock whenever `elapsed` is
+/// called, so a component reading it during render shows an up-to-date value
+/// on every frame of the render loop.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::stopwatch::use_stopwatch;
+///
+/// let stopwatch = use_stopwatch();
+///
+/// // Toggle with a key handler
+/// if stopwatch.is_running() {
+///     stopwatch.stop();
+/// } else {
+///     stopwatch.start();
+/// }
+///
+/// let elapsed = stopwatch.elapsed();
+/// let text = format!("{:02}:{:02}", elapsed.as_secs() / 60, elapsed.as_secs() % 60);
+/// ```
+pub fn use_stopwatch() -> Stopwatch {
+    let state = use_ref(StopwatchState::default);
+    Stopwatch { state }
+}
@@ -0,0 +1,87 @@
+//! Tests for use_stopwatch hook
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_stopwatch_accumulates_running_intervals() {
+    let t0 = Instant::now();
+    let mut state = StopwatchState::default();
+
+    state.start(t0);
+    assert_eq!(state.elapsed(t0 + ms(100)), ms(100));
+
+    state.stop(t0 + ms(150));
+    assert_eq!(state.elapsed(t0 + ms(500)), ms(150));
+
+    state.start(t0 + ms(500));
+    state.stop(t0 + ms(550));
+    assert_eq!(state.elapsed(t0 + ms(900)), ms(200));
+}
+
+#[test]
+fn test_stopwatch_resume_at_same_instant_counts_boundary_once() {
+    let t0 = Instant::now();
+    let mut state = StopwatchState::default();
+
+    state.start(t0);
+    state.stop(t0 + ms(100));
+    state.start(t0 + ms(100));
+    assert_eq!(state.elapsed(t0 + ms(250)), ms(250));
+}
+
+#[test]
+fn test_stopwatch_start_and_stop_are_idempotent() {
+    let t0 = Instant::now();
+    let mut state = StopwatchState::default();
+
+    state.start(t0);
+    state.start(t0 + ms(50));
+    state.stop(t0 + ms(100));
+    state.stop(t0 + ms(200));
+    assert_eq!(state.elapsed(t0 + ms(300)), ms(100));
+}
+
+#[test]
+fn test_stopwatch_laps() {
+    let t0 = Instant::now();
+    let mut state = StopwatchState::default();
+
+    state.start(t0);
+    assert_eq!(state.lap(t0 + ms(100)), ms(100));
+    state.stop(t0 + ms(130));
+    state.start(t0 + ms(1000));
+    assert_eq!(state.lap(t0 + ms(1020)), ms(50));
+    assert_eq!(state.laps, vec![ms(100), ms(50)]);
+
+    state.reset();
+    assert_eq!(state.elapsed(t0 + ms(2000)), Duration::ZERO);
+    assert!(state.laps.is_empty());
+    assert!(state.running_since.is_none());
+}
+
+#[test]
+fn test_use_stopwatch_persists_across_renders() {
+    with_test_isolate(|| {
+        with_component_id("StopwatchTest", |_ctx| {
+            let stopwatch = use_stopwatch();
+            assert!(!stopwatch.is_running());
+            assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+            stopwatch.start();
+        });
+
+        std::thread::sleep(ms(10));
+
+        with_component_id("StopwatchTest", |_ctx| {
+            let stopwatch = use_stopwatch();
+            assert!(stopwatch.is_running());
+            stopwatch.stop();
+            assert!(stopwatch.elapsed() >= ms(10));
+            assert_eq!(stopwatch.laps(), Vec::<Duration>::new());
+        });
+    });
+}
@@ -0,0 +1,269 @@
+//! Tabbed views
+//!
+//! [`TabView`] draws a row of tab titles above its children and switches
+//! between them with the keyboard. The children read which tab is active with
+//! [`use_active_tab`], so the content area can match on it.
+
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Tabs, Widget};
+use reratui_core::{Component, ComponentProps, Element};
+
+use crate::callback::Callback;
+use crate::context::{try_use_context, use_context_provider, with_context_scope};
+use crate::event::use_event;
+use crate::state::use_state;
+
+#[cfg(test)]
+mod tests;
+
+/// The index of the active tab, provided to the children of a [`TabView`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveTab(pub usize);
+
+/// Returns the index of the active tab of the nearest [`TabView`]
+///
+/// Falls back to the first tab outside of a `TabView`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::tabs::use_active_tab;
+///
+/// let content = match use_active_tab() {
+///     0 => "Overview",
+///     1 => "Settings",
+///     _ => "Help",
+/// };
+/// ```
+pub fn use_active_tab() -> usize {
+    try_use_context::<ActiveTab>().map_or(0, |tab| tab.0)
+}
+
+/// The tab a key switches to from `active`, out of `count` tabs
+///
+/// Tab and Shift+Tab cycle through the tabs; `1` to `9` jump to one.
+pub fn tab_for_key(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    active: usize,
+    count: usize,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match code {
+        KeyCode::Tab if !modifiers.contains(KeyModifiers::SHIFT) => Some((active + 1) % count),
+        KeyCode::BackTab | KeyCode::Tab => Some((active + count - 1) % count),
+        KeyCode::Char(digit @ '1'..='9') if modifiers.is_empty() => {
+            let index = digit as usize - '1' as usize;
+            (index < count).then_some(index)
+        }
+        _ => None,
+    }
+}
+
+/// Props for the `TabView` component
+#[derive(Clone)]
+pub struct TabViewProps {
+    pub titles: Vec<String>,
+    /// The active tab when controlled by the parent; kept by the view if `None`
+    pub selected: Option<usize>,
+    /// Receives the tab to switch to
+    pub on_change: Callback<usize>,
+    /// Whether keys switch tabs
+    pub focused: bool,
+    pub style: Style,
+    pub highlight_style: Style,
+    /// Drawn around the titles
+    pub block: Option<Block<'static>>,
+    pub children: Vec<Element>,
+}
+
+impl Default for TabViewProps {
+    fn default() -> Self {
+        Self {
+            titles: Vec::new(),
+            selected: None,
+            on_change: Callback::noop(),
+            focused: true,
+            style: Style::default(),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            block: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl TabViewProps {
+    /// Sets the tab titles
+    pub fn titles<T: Into<String>>(mut self, titles: impl IntoIterator<Item = T>) -> Self {
+        self.titles = titles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Controls the active tab from the parent
+    pub fn selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Sets the callback receiving the tab to switch to
+    pub fn on_change(mut self, on_change: impl Into<Callback<usize>>) -> Self {
+        self.on_change = on_change.into();
+        self
+    }
+
+    /// Sets whether keys switch tabs
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the style of the titles
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the active title
+    pub fn highlight_style(mut self, highlight_style: Style) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    /// Sets the block drawn around the titles
+    pub fn block(mut self, block: Block<'static>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for TabViewProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A row of tabs above the content of the active one
+///
+/// Tab and Shift+Tab cycle through the tabs and `1` to `9` jump to one while
+/// the view is focused. Without `selected` the view keeps the active tab
+/// itself; with it, the view is controlled and shows `selected` until the
+/// parent updates it from `on_change`. Children render below the titles and
+/// get the active index from [`use_active_tab`].
+///
+/// ```ignore
+/// rsx! {
+///     <TabView titles={vec!["Overview", "Settings"]}>
+///         <TabContent />
+///     </TabView>
+/// }
+///
+/// #[component]
+/// fn TabContent() -> Element {
+///     match use_active_tab() {
+///         0 => rsx! { <Paragraph>{"Overview"}</Paragraph> },
+///         _ => rsx! { <Paragraph>{"Settings"}</Paragraph> },
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct TabViewComponent {
+    props: TabViewProps,
+}
+
+impl TabViewComponent {
+    pub fn new(props: TabViewProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+
+    /// Rows taken by the titles and the block around them
+    fn header_height(&self) -> u16 {
+        let probe = Rect::new(0, 0, 10, 10);
+        let inner = self
+            .props
+            .block
+            .as_ref()
+            .map_or(probe, |block| block.inner(probe));
+        1 + probe.height - inner.height
+    }
+}
+
+impl Component for TabViewComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let count = self.props.titles.len();
+        let (own_tab, set_own_tab) = use_state(|| 0usize);
+        let active_tab = || {
+            self.props
+                .selected
+                .unwrap_or_else(|| own_tab.get())
+                .min(count.saturating_sub(1))
+        };
+        let active = active_tab();
+
+        if self.props.focused
+            && let Some(Event::Key(key)) = use_event()
+            && key.is_press()
+            && let Some(next) = tab_for_key(key.code, key.modifiers, active, count)
+            && next != active
+        {
+            if self.props.selected.is_none() {
+                set_own_tab.set(next);
+            }
+            self.props.on_change.emit(next);
+        }
+        // An uncontrolled switch shows from this frame on
+        let active = active_tab();
+
+        let [header_area, content_area] =
+            Layout::vertical([Constraint::Length(self.header_height()), Constraint::Min(0)])
+                .areas(area);
+
+        let mut tabs = Tabs::new(self.props.titles.clone())
+            .select(active)
+            .style(self.props.style)
+            .highlight_style(self.props.highlight_style);
+        if let Some(block) = &self.props.block {
+            tabs = tabs.block(block.clone());
+        }
+        tabs.render(header_area, buffer);
+
+        with_context_scope(|| {
+            use_context_provider(|| ActiveTab(active));
+
+            let children = &self.props.children;
+            if children.len() == 1 {
+                children[0].render(content_area, buffer);
+                return;
+            }
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(content_area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, buffer);
+            }
+        });
+    }
+}
+
+/// The `TabView` component, usable as `<TabView titles={...} />` in `rsx!`
+pub type TabView = TabViewComponent;
@@ -0,0 +1,120 @@
+//! Tests for tabbed views
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::KeyEvent;
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Writes the active tab it reads from context
+#[derive(Clone)]
+struct ActiveTabProbe;
+
+impl Component for ActiveTabProbe {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        buffer.set_string(
+            area.x,
+            area.y,
+            use_active_tab().to_string(),
+            Style::default(),
+        );
+    }
+}
+
+fn press(code: KeyCode, modifiers: KeyModifiers) {
+    let key = KeyEvent::new(code, modifiers);
+    set_current_event(Some(Arc::new(Event::Key(key))));
+}
+
+/// Renders `view` and returns the first row and the active tab its child saw
+fn render_view(view: &TabView) -> (String, String) {
+    let area = Rect::new(0, 0, 20, 2);
+    let mut buffer = Buffer::empty(area);
+    with_component_id("TabViewTest", |_ctx| view.render(area, &mut buffer));
+    let header = (0..20).map(|x| buffer[(x, 0)].symbol()).collect();
+    (header, buffer[(0, 1)].symbol().to_string())
+}
+
+fn view(props: TabViewProps) -> TabView {
+    TabViewComponent::new(
+        props
+            .titles(["One", "Two", "Three"])
+            .with_children(vec![Element::component(ActiveTabProbe)]),
+    )
+}
+
+#[test]
+fn test_tab_for_key() {
+    let none = KeyModifiers::NONE;
+    assert_eq!(tab_for_key(KeyCode::Tab, none, 2, 3), Some(0));
+    assert_eq!(
+        tab_for_key(KeyCode::BackTab, KeyModifiers::SHIFT, 0, 3),
+        Some(2)
+    );
+    assert_eq!(
+        tab_for_key(KeyCode::Tab, KeyModifiers::SHIFT, 1, 3),
+        Some(0)
+    );
+    assert_eq!(tab_for_key(KeyCode::Char('2'), none, 0, 3), Some(1));
+    assert_eq!(tab_for_key(KeyCode::Char('4'), none, 0, 3), None);
+    assert_eq!(
+        tab_for_key(KeyCode::Char('1'), KeyModifiers::CONTROL, 2, 3),
+        None
+    );
+    assert_eq!(tab_for_key(KeyCode::Tab, none, 0, 0), None);
+}
+
+#[test]
+fn test_keys_switch_tabs() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let view = view(TabViewProps::default().on_change({
+            let changes = changes.clone();
+            move |index| changes.lock().push(index)
+        }));
+
+        set_current_event(None);
+        let (header, active) = render_view(&view);
+        assert_eq!(header, " One │ Two │ Three  ");
+        assert_eq!(active, "0");
+
+        press(KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(render_view(&view).1, "1");
+        press(KeyCode::Char('3'), KeyModifiers::NONE);
+        assert_eq!(render_view(&view).1, "2");
+        press(KeyCode::BackTab, KeyModifiers::SHIFT);
+        assert_eq!(render_view(&view).1, "1");
+        set_current_event(None);
+
+        assert_eq!(*changes.lock(), vec![1, 2, 1]);
+    });
+}
+
+#[test]
+fn test_controlled_view_waits_for_parent() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let view = |selected: usize| {
+            let changes = changes.clone();
+            view(
+                TabViewProps::default()
+                    .selected(selected)
+                    .on_change(move |index| changes.lock().push(index)),
+            )
+        };
+
+        press(KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(render_view(&view(0)).1, "0");
+        set_current_event(None);
+        assert_eq!(*changes.lock(), vec![1]);
+
+        // The parent applies the switch
+        assert_eq!(render_view(&view(1)).1, "1");
+    });
+}
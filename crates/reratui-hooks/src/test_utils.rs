@@ -278,6 +278,22 @@ where
     result
 }
 
+/// A frame context carrying only a timestamp, for hooks that read the frame time
+///
+/// Provide it with `use_context_provider` to drive time-based animations from
+/// a test. Its frame must not be accessed.
+pub fn frame_context_at(timestamp: std::time::Instant) -> crate::frame::FrameContext {
+    // SAFETY: The frame pointer is null and callers never dereference it
+    unsafe {
+        crate::frame::FrameContext::from_raw_ptr(
+            std::ptr::null_mut(),
+            0,
+            std::time::Duration::ZERO,
+            timestamp,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
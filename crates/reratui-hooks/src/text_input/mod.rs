@@ -0,0 +1,446 @@
+//! Single-line text entry
+//!
+//! [`TextInputState`] is the editing model: a value, a cursor and an optional
+//! selection, all kept on grapheme boundaries so emoji and combining marks are
+//! never split. [`TextInput`] is a controlled component around it: the parent
+//! owns the value and receives every edit through `on_change`.
+//!
+//! | Key                        | Action                                   |
+//! |----------------------------|------------------------------------------|
+//! | Left / Right               | Move by one grapheme                     |
+//! | Ctrl+Left / Ctrl+Right     | Move by one word                         |
+//! | Home / End                 | Move to the start / end                  |
+//! | Shift + any of the above   | Extend the selection                     |
+//! | Ctrl+A                     | Select everything                        |
+//! | Backspace / Delete         | Delete the selection or one grapheme     |
+//! | Ctrl+Backspace / Ctrl+W    | Delete the word before the cursor        |
+//! | Ctrl+Delete                | Delete the word after the cursor         |
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use reratui_core::text::display_width;
+use reratui_core::{Component, ComponentProps, Element};
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::callback::Callback;
+use crate::event::use_event_handle;
+use crate::ref_hook::use_ref;
+
+#[cfg(test)]
+mod tests;
+
+/// A line of text being edited
+///
+/// Positions are byte offsets into `value` that always fall on grapheme
+/// boundaries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInputState {
+    value: String,
+    cursor: usize,
+    /// The other end of the selection, if any
+    anchor: Option<usize>,
+}
+
+impl TextInputState {
+    /// Creates a state editing `value` with the cursor at the end
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let cursor = value.len();
+        Self {
+            value,
+            cursor,
+            anchor: None,
+        }
+    }
+
+    /// The text
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The cursor as a byte offset into the value
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The selected byte range, if anything is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.anchor
+            .filter(|&anchor| anchor != self.cursor)
+            .map(|anchor| anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// Replaces the value, keeping the cursor and selection where they still fit
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.cursor = self.snap(self.cursor);
+        self.anchor = self.anchor.map(|anchor| self.snap(anchor));
+    }
+
+    /// Moves the cursor to `position`, dropping the selection
+    pub fn set_cursor(&mut self, position: usize) {
+        self.cursor = self.snap(position);
+        self.anchor = None;
+    }
+
+    /// Inserts `text` at the cursor, replacing the selection
+    pub fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+        self.value.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    /// Applies an editing key, returning whether the value changed
+    ///
+    /// Keys that aren't editing keys are ignored, as are characters typed
+    /// with Ctrl or Alt so they remain available as shortcuts.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let before = self.value.len();
+
+        match key.code {
+            KeyCode::Char('a') if ctrl => {
+                self.anchor = Some(0);
+                self.cursor = self.value.len();
+            }
+            KeyCode::Char('w') if ctrl => self.delete_to(self.prev_word()),
+            KeyCode::Char(c) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                self.insert_str(c.encode_utf8(&mut [0; 4]));
+                return true;
+            }
+            KeyCode::Left if ctrl => self.move_to(self.prev_word(), shift),
+            KeyCode::Right if ctrl => self.move_to(self.next_word(), shift),
+            KeyCode::Left => match self.selection() {
+                Some(selection) if !shift => self.move_to(selection.start, false),
+                _ => self.move_to(self.prev_grapheme(), shift),
+            },
+            KeyCode::Right => match self.selection() {
+                Some(selection) if !shift => self.move_to(selection.end, false),
+                _ => self.move_to(self.next_grapheme(), shift),
+            },
+            KeyCode::Home => self.move_to(0, shift),
+            KeyCode::End => self.move_to(self.value.len(), shift),
+            KeyCode::Backspace if ctrl => self.delete_to(self.prev_word()),
+            KeyCode::Backspace => self.delete_to(self.prev_grapheme()),
+            KeyCode::Delete if ctrl => self.delete_to(self.next_word()),
+            KeyCode::Delete => self.delete_to(self.next_grapheme()),
+            _ => return false,
+        }
+
+        // Every edit that keeps the length also keeps the text
+        self.value.len() != before
+    }
+
+    fn move_to(&mut self, position: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+    }
+
+    /// Deletes the selection, or else the text between the cursor and `position`
+    fn delete_to(&mut self, position: usize) {
+        if !self.delete_selection() {
+            let range = position.min(self.cursor)..position.max(self.cursor);
+            self.value.replace_range(range.clone(), "");
+            self.cursor = range.start;
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        match selection {
+            Some(range) => {
+                self.value.replace_range(range.clone(), "");
+                self.cursor = range.start;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn prev_grapheme(&self) -> usize {
+        self.value[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_grapheme(&self) -> usize {
+        self.value[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map_or(self.cursor, |grapheme| self.cursor + grapheme.len())
+    }
+
+    /// The start of the word before the cursor
+    fn prev_word(&self) -> usize {
+        self.value[..self.cursor]
+            .split_word_bound_indices()
+            .rev()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// The end of the word after the cursor
+    fn next_word(&self) -> usize {
+        self.value[self.cursor..]
+            .split_word_bound_indices()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(self.value.len(), |(index, word)| {
+                self.cursor + index + word.len()
+            })
+    }
+
+    /// The grapheme boundary at or before `position`
+    fn snap(&self, position: usize) -> usize {
+        if position >= self.value.len() {
+            return self.value.len();
+        }
+        self.value
+            .grapheme_indices(true)
+            .map(|(index, _)| index)
+            .take_while(|&index| index <= position)
+            .last()
+            .unwrap_or(0)
+    }
+}
+
+/// Props for the `TextInput` component
+#[derive(Clone)]
+pub struct TextInputProps {
+    /// The text, owned by the parent
+    pub value: String,
+    /// Receives the new text after every edit
+    pub on_change: Callback<String>,
+    /// Shown while the value is empty
+    pub placeholder: String,
+    pub style: Style,
+    pub placeholder_style: Style,
+    /// Shown instead of every grapheme, e.g. for passwords
+    pub mask: Option<char>,
+    /// Whether keys edit this input and the cursor is drawn
+    pub focused: bool,
+    pub children: Vec<Element>,
+}
+
+impl Default for TextInputProps {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            on_change: Callback::noop(),
+            placeholder: String::new(),
+            style: Style::default(),
+            placeholder_style: Style::default().add_modifier(Modifier::DIM),
+            mask: None,
+            focused: true,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl TextInputProps {
+    /// Sets the text
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets the callback receiving every edit
+    pub fn on_change(mut self, on_change: impl Into<Callback<String>>) -> Self {
+        self.on_change = on_change.into();
+        self
+    }
+
+    /// Sets the text shown while the value is empty
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the style of the text
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the placeholder
+    pub fn placeholder_style(mut self, placeholder_style: Style) -> Self {
+        self.placeholder_style = placeholder_style;
+        self
+    }
+
+    /// Sets the character shown instead of every grapheme
+    pub fn mask(mut self, mask: impl Into<Option<char>>) -> Self {
+        self.mask = mask.into();
+        self
+    }
+
+    /// Sets whether keys edit this input
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for TextInputProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// A single-line text input with a cursor and selection
+///
+/// The input is controlled: it shows `value` and reports edits through
+/// `on_change`, so the parent stores the text. The cursor and selection are
+/// kept by the input itself. Text wider than the area scrolls to keep the
+/// cursor visible.
+///
+/// While focused, the input consumes every typed character and every key it
+/// acts on, so components that handle events after it in the frame don't
+/// react to them too.
+///
+/// ```ignore
+/// let (name, set_name) = use_state(String::new);
+///
+/// rsx! {
+///     <TextInput
+///         value={name.get()}
+///         on_change={move |value| set_name.set(value)}
+///         placeholder={"Your name"}
+///     />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct TextInputComponent {
+    props: TextInputProps,
+}
+
+impl TextInputComponent {
+    pub fn new(props: TextInputProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+/// Whether `event` types a character rather than triggering a shortcut
+pub(crate) fn is_typed_character(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key(key) if key.is_press()
+            && matches!(key.code, KeyCode::Char(_))
+            && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    )
+}
+
+impl Component for TextInputComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let state = use_ref(|| TextInputState::new(self.props.value.clone()));
+        let mut input = state.get();
+        // The parent may have changed the value since the last edit
+        if input.value() != self.props.value {
+            input.set_value(self.props.value.clone());
+        }
+
+        let before = input.clone();
+        let event = use_event_handle();
+        let changed = match event.as_deref() {
+            Some(Event::Key(key)) if self.props.focused && key.is_press() => input.handle_key(key),
+            Some(Event::Paste(text)) if self.props.focused => {
+                input.insert_str(text);
+                true
+            }
+            _ => false,
+        };
+        if let Some(event) = &event
+            && self.props.focused
+            && (input != before || is_typed_character(event))
+        {
+            event.consume();
+        }
+        if changed {
+            self.props.on_change.emit(input.value().to_string());
+        }
+        state.set(input.clone());
+
+        if area.is_empty() {
+            return;
+        }
+
+        if input.value().is_empty() {
+            buffer.set_stringn(
+                area.x,
+                area.y,
+                &self.props.placeholder,
+                area.width as usize,
+                self.props.placeholder_style,
+            );
+            if self.props.focused {
+                buffer[(area.x, area.y)].modifier.insert(Modifier::REVERSED);
+            }
+            return;
+        }
+
+        let selection = input.selection().unwrap_or_default();
+        let mask = self.props.mask.map(String::from);
+        let cells: Vec<(usize, &str)> = input
+            .value()
+            .grapheme_indices(true)
+            .map(|(index, grapheme)| (index, mask.as_deref().unwrap_or(grapheme)))
+            .collect();
+
+        // Scroll so the cursor, drawn one cell past the text at the end, fits
+        let cursor_column: usize = cells
+            .iter()
+            .take_while(|(index, _)| *index < input.cursor())
+            .map(|(_, grapheme)| display_width(grapheme))
+            .sum();
+        let scroll = (cursor_column + 1).saturating_sub(area.width as usize);
+
+        let mut column = 0;
+        for &(index, grapheme) in &cells {
+            let width = display_width(grapheme);
+            if column >= scroll && column + width <= scroll + area.width as usize {
+                let mut style = self.props.style;
+                if selection.contains(&index) {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                let x = area.x + (column - scroll) as u16;
+                buffer.set_stringn(x, area.y, grapheme, width, style);
+            }
+            column += width;
+        }
+
+        if self.props.focused && input.selection().is_none() {
+            let x = area.x + (cursor_column - scroll) as u16;
+            let cell = &mut buffer[(x, area.y)];
+            if input.cursor() == input.value().len() {
+                cell.set_style(self.props.style);
+            }
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+    }
+}
+
+/// The `TextInput` component, usable as `<TextInput value={...} on_change={...} />` in `rsx!`
+pub type TextInput = TextInputComponent;
@@ -0,0 +1,231 @@
+//! Tests for text input
+
+use super::*;
+use crate::event::set_current_event;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn ctrl(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::CONTROL)
+}
+
+fn shift(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::SHIFT)
+}
+
+#[test]
+fn test_editing_at_the_cursor() {
+    let mut input = TextInputState::new("helo");
+
+    input.handle_key(&key(KeyCode::Left));
+    assert!(input.handle_key(&key(KeyCode::Char('l'))));
+    assert_eq!(input.value(), "hello");
+    assert_eq!(input.cursor(), 4);
+
+    input.handle_key(&key(KeyCode::Home));
+    assert!(input.handle_key(&key(KeyCode::Delete)));
+    assert_eq!(input.value(), "ello");
+
+    input.handle_key(&key(KeyCode::End));
+    assert!(input.handle_key(&key(KeyCode::Backspace)));
+    assert_eq!(input.value(), "ell");
+
+    // Nothing to delete at the start
+    input.handle_key(&key(KeyCode::Home));
+    assert!(!input.handle_key(&key(KeyCode::Backspace)));
+    // Movement and unknown keys don't change the value
+    assert!(!input.handle_key(&key(KeyCode::Right)));
+    assert!(!input.handle_key(&key(KeyCode::F(1))));
+    // Ctrl shortcuts aren't typed
+    assert!(!input.handle_key(&ctrl(KeyCode::Char('s'))));
+    assert_eq!(input.value(), "ell");
+}
+
+#[test]
+fn test_graphemes_are_never_split() {
+    // A family emoji joined from four people, and an `e` with a combining accent
+    let family = "👨\u{200d}👩\u{200d}👧\u{200d}👦";
+    let mut input = TextInputState::new(format!("a{family}e\u{301}"));
+
+    assert!(input.handle_key(&key(KeyCode::Backspace)));
+    assert_eq!(input.value(), format!("a{family}"));
+
+    input.handle_key(&key(KeyCode::Left));
+    assert_eq!(input.cursor(), 1);
+    input.handle_key(&key(KeyCode::Delete));
+    assert_eq!(input.value(), "a");
+
+    // Snapping a position inside a grapheme moves it to the grapheme's start
+    let mut input = TextInputState::new(format!("{family}!"));
+    input.set_cursor(3);
+    assert_eq!(input.cursor(), 0);
+}
+
+#[test]
+fn test_word_movement_and_deletion() {
+    let mut input = TextInputState::new("one two  three");
+
+    input.handle_key(&ctrl(KeyCode::Left));
+    assert_eq!(input.cursor(), 9);
+    input.handle_key(&ctrl(KeyCode::Left));
+    assert_eq!(input.cursor(), 4);
+    input.handle_key(&ctrl(KeyCode::Right));
+    assert_eq!(input.cursor(), 7);
+    input.handle_key(&ctrl(KeyCode::Right));
+    assert_eq!(input.cursor(), 14);
+
+    assert!(input.handle_key(&ctrl(KeyCode::Backspace)));
+    assert_eq!(input.value(), "one two  ");
+    assert!(input.handle_key(&ctrl(KeyCode::Char('w'))));
+    assert_eq!(input.value(), "one ");
+
+    input.handle_key(&key(KeyCode::Home));
+    assert!(input.handle_key(&ctrl(KeyCode::Delete)));
+    assert_eq!(input.value(), " ");
+}
+
+#[test]
+fn test_selection() {
+    let mut input = TextInputState::new("hello world");
+
+    input.handle_key(&shift(KeyCode::Home));
+    assert_eq!(input.selection(), Some(0..11));
+    // Left collapses the selection to its start
+    input.handle_key(&key(KeyCode::Left));
+    assert_eq!((input.selection(), input.cursor()), (None, 0));
+
+    let ctrl_shift = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+    input.handle_key(&KeyEvent::new(KeyCode::Right, ctrl_shift));
+    assert_eq!(input.selection(), Some(0..5));
+
+    // Typing replaces the selection
+    input.handle_key(&shift(KeyCode::Char('J')));
+    assert_eq!(input.value(), "J world");
+
+    input.handle_key(&ctrl(KeyCode::Char('a')));
+    assert!(input.handle_key(&key(KeyCode::Backspace)));
+    assert_eq!(input.value(), "");
+}
+
+#[test]
+fn test_set_value_keeps_cursor_in_range() {
+    let mut input = TextInputState::new("hello");
+    input.set_value("hi");
+    assert_eq!(input.cursor(), 2);
+
+    input.set_cursor(1);
+    input.set_value("hey");
+    assert_eq!(input.cursor(), 1);
+}
+
+fn row(buffer: &Buffer) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, 0)].symbol())
+        .collect()
+}
+
+#[test]
+fn test_component_reports_edits_and_draws_cursor() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 8, 1);
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let render = |value: &str| {
+            let changes = changes.clone();
+            let input = TextInputComponent::new(
+                TextInputProps::default()
+                    .value(value)
+                    .placeholder("Name")
+                    .on_change(move |value| changes.lock().push(value)),
+            );
+            let mut buffer = Buffer::empty(area);
+            with_component_id("TextInputTest", |_ctx| input.render(area, &mut buffer));
+            buffer
+        };
+
+        set_current_event(None);
+        let buffer = render("");
+        assert_eq!(row(&buffer), "Name    ");
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+
+        set_current_event(Some(Arc::new(Event::Key(key(KeyCode::Char('a'))))));
+        render("");
+        set_current_event(Some(Arc::new(Event::Paste("bc".to_string()))));
+        render("a");
+        set_current_event(None);
+        assert_eq!(*changes.lock(), vec!["a".to_string(), "abc".to_string()]);
+
+        // The cursor sits after the text
+        let buffer = render("abc");
+        assert_eq!(row(&buffer), "abc     ");
+        assert!(buffer[(3, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(!buffer[(2, 0)].modifier.contains(Modifier::REVERSED));
+
+        // A value set by the parent keeps the cursor where it was
+        let buffer = render("abcdefghij");
+        assert_eq!(row(&buffer), "abcdefgh");
+        assert!(buffer[(3, 0)].modifier.contains(Modifier::REVERSED));
+
+        // Long text scrolls to keep the cursor in view
+        set_current_event(Some(Arc::new(Event::Key(key(KeyCode::End)))));
+        let buffer = render("abcdefghij");
+        set_current_event(None);
+        assert_eq!(row(&buffer), "defghij ");
+    });
+}
+
+#[test]
+fn test_component_masks_value() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        set_current_event(None);
+        let area = Rect::new(0, 0, 6, 1);
+        let input = TextInputComponent::new(
+            TextInputProps::default()
+                .value("pä👍")
+                .mask('•')
+                .focused(false),
+        );
+
+        let mut buffer = Buffer::empty(area);
+        with_component_id("MaskedInputTest", |_ctx| input.render(area, &mut buffer));
+        assert_eq!(row(&buffer), "•••   ");
+    });
+}
+
+#[test]
+fn test_focused_input_consumes_typed_keys() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 8, 1);
+        // Renders the input, then returns what a consumer after it receives
+        let render_then_read = |focused: bool| {
+            let input = TextInputComponent::new(TextInputProps::default().focused(focused));
+            let mut buffer = Buffer::empty(area);
+            with_component_id("TextInputConsumeTest", |_ctx| {
+                input.render(area, &mut buffer);
+                use_event_handle().map(|event| event.event().clone())
+            })
+        };
+
+        let typed = Event::Key(key(KeyCode::Char('j')));
+        set_current_event(Some(Arc::new(typed.clone())));
+        assert_eq!(render_then_read(true), None);
+
+        // Shortcuts and unfocused inputs let the event through
+        let shortcut = Event::Key(ctrl(KeyCode::Char('s')));
+        set_current_event(Some(Arc::new(shortcut.clone())));
+        assert_eq!(render_then_read(true), Some(shortcut));
+        set_current_event(Some(Arc::new(typed.clone())));
+        assert_eq!(render_then_read(false), Some(typed));
+        set_current_event(None);
+    });
+}
@@ -0,0 +1,65 @@
+//! Light/dark detection of the terminal background
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::Theme;
+
+/// Whether the terminal background was detected as light
+static LIGHT_BACKGROUND: AtomicBool = AtomicBool::new(false);
+
+/// Whether the terminal has a light or a dark background
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// Dark text on a light background
+    Light,
+    /// Light text on a dark background
+    #[default]
+    Dark,
+}
+
+impl ColorScheme {
+    /// Classifies a background color by its perceived brightness
+    pub fn from_background(r: u8, g: u8, b: u8) -> Self {
+        let luma = 299 * r as u32 + 587 * g as u32 + 114 * b as u32;
+        if luma > 128_000 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+
+    /// The built-in theme matching this scheme
+    pub fn theme(self) -> Theme {
+        match self {
+            Self::Light => Theme::light(),
+            Self::Dark => Theme::dark(),
+        }
+    }
+}
+
+/// Record the detected color scheme
+///
+/// Called by the runtime after querying the terminal background at startup.
+pub fn set_color_scheme(scheme: ColorScheme) {
+    LIGHT_BACKGROUND.store(scheme == ColorScheme::Light, Ordering::SeqCst);
+}
+
+/// Returns whether the terminal has a light or a dark background
+///
+/// The runtime asks the terminal for its background color when it starts.
+/// Terminals that don't answer are assumed to be [`ColorScheme::Dark`].
+///
+/// # Example
+/// ```rust,no_run
+/// use reratui_hooks::theme::{ThemeProviderProps, use_color_scheme};
+///
+/// // Pick a default theme matching the terminal
+/// let props = ThemeProviderProps::default().theme(use_color_scheme().theme());
+/// ```
+pub fn use_color_scheme() -> ColorScheme {
+    if LIGHT_BACKGROUND.load(Ordering::SeqCst) {
+        ColorScheme::Light
+    } else {
+        ColorScheme::Dark
+    }
+}
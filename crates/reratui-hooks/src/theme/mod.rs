@@ -0,0 +1,231 @@
+//! Theme context for centralized styling
+//!
+//! A [`Theme`] holds the named colors and modifiers an application styles
+//! itself with. Provide one with [`ThemeProvider`] (or [`use_theme_provider`])
+//! and read it anywhere below with [`use_theme`], so swapping the provided
+//! theme re-styles the whole tree. [`use_color_scheme`] reports whether the
+//! terminal background is light or dark, to pick a matching default.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use reratui_core::{Component, ComponentProps, Element};
+
+use crate::context::{try_use_context, use_context_provider, with_context_scope};
+
+mod color_scheme;
+
+#[cfg(test)]
+mod tests;
+
+pub use color_scheme::*;
+
+/// Named colors and modifiers shared by the components of an application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Accent color for focused and interactive elements
+    pub primary: Color,
+    /// Color for secondary accents, e.g. hints and labels
+    pub secondary: Color,
+    /// Background color of the application
+    pub background: Color,
+    /// Default text color
+    pub foreground: Color,
+    /// Color for errors and destructive actions
+    pub error: Color,
+    /// Color for block borders
+    pub border: Color,
+    /// Modifiers applied to titles and headings
+    pub title: Modifier,
+    /// Modifiers applied to the selected item of a list or table
+    pub selected: Modifier,
+}
+
+impl Theme {
+    /// A theme for dark terminal backgrounds
+    pub fn dark() -> Self {
+        Self {
+            primary: Color::Cyan,
+            secondary: Color::Magenta,
+            background: Color::Reset,
+            foreground: Color::White,
+            error: Color::LightRed,
+            border: Color::DarkGray,
+            title: Modifier::BOLD,
+            selected: Modifier::REVERSED,
+        }
+    }
+
+    /// A theme for light terminal backgrounds
+    pub fn light() -> Self {
+        Self {
+            primary: Color::Blue,
+            secondary: Color::Magenta,
+            background: Color::White,
+            foreground: Color::Black,
+            error: Color::Red,
+            border: Color::Gray,
+            title: Modifier::BOLD,
+            selected: Modifier::REVERSED,
+        }
+    }
+
+    /// A theme with maximum contrast for accessibility
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: Color::Yellow,
+            secondary: Color::White,
+            background: Color::Black,
+            foreground: Color::White,
+            error: Color::LightRed,
+            border: Color::White,
+            title: Modifier::BOLD | Modifier::UNDERLINED,
+            selected: Modifier::BOLD | Modifier::REVERSED,
+        }
+    }
+
+    /// Style for regular text
+    pub fn text_style(&self) -> Style {
+        Style::default().fg(self.foreground).bg(self.background)
+    }
+
+    /// Style for block borders
+    pub fn border_style(&self) -> Style {
+        Style::default().fg(self.border)
+    }
+
+    /// Style for titles and headings
+    pub fn title_style(&self) -> Style {
+        Style::default().fg(self.primary).add_modifier(self.title)
+    }
+
+    /// Style for the selected item of a list or table
+    pub fn selected_style(&self) -> Style {
+        Style::default()
+            .fg(self.primary)
+            .add_modifier(self.selected)
+    }
+
+    /// Style for error messages
+    pub fn error_style(&self) -> Style {
+        Style::default().fg(self.error)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Provides `theme` to the components rendered below this one
+///
+/// Returns the provided theme so the providing component can use it too.
+pub fn use_theme_provider(theme: Theme) -> Theme {
+    use_context_provider(|| theme)
+}
+
+/// Returns the theme provided by the nearest [`ThemeProvider`]
+///
+/// Falls back to [`Theme::default`] when no theme was provided.
+///
+/// # Example
+/// ```rust,no_run
+/// use ratatui::widgets::{Block, Borders};
+/// use reratui_hooks::theme::use_theme;
+///
+/// let theme = use_theme();
+/// let block = Block::default()
+///     .borders(Borders::ALL)
+///     .border_style(theme.border_style())
+///     .title_style(theme.title_style());
+/// ```
+pub fn use_theme() -> Theme {
+    try_use_context::<Theme>().unwrap_or_default()
+}
+
+/// Props for the `ThemeProvider` component
+#[derive(Clone, Default)]
+pub struct ThemeProviderProps {
+    pub theme: Theme,
+    pub children: Vec<Element>,
+}
+
+impl ThemeProviderProps {
+    /// Sets the theme provided to the children
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ThemeProviderProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders its children with a theme available through [`use_theme`]
+///
+/// A single child fills the whole area; several children are stacked
+/// vertically.
+///
+/// ```ignore
+/// let (dark, set_dark) = use_state(|| true);
+/// let theme = if dark { Theme::dark() } else { Theme::light() };
+///
+/// rsx! {
+///     <ThemeProvider theme={theme}>
+///         <Dashboard />
+///     </ThemeProvider>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ThemeProviderComponent {
+    props: ThemeProviderProps,
+}
+
+impl ThemeProviderComponent {
+    pub fn new(props: ThemeProviderProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for ThemeProviderComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        with_context_scope(|| {
+            use_theme_provider(self.props.theme);
+
+            let children = &self.props.children;
+            if children.len() == 1 {
+                children[0].render(area, buffer);
+                return;
+            }
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0); children.len()])
+                .split(area);
+            for (child, chunk) in children.iter().zip(chunks.iter()) {
+                child.render(*chunk, buffer);
+            }
+        });
+    }
+}
+
+/// The `ThemeProvider` component, usable as `<ThemeProvider theme={...} />` in `rsx!`
+pub type ThemeProvider = ThemeProviderComponent;
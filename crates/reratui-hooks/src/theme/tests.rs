@@ -0,0 +1,103 @@
+//! Tests for the theme context
+
+use super::*;
+use crate::context::clear_context_providers;
+use crate::test_utils::with_component_id;
+use ratatui::widgets::{Paragraph, Widget};
+
+/// Renders its first cell in the theme's primary color
+#[derive(Clone)]
+struct ThemedLabel;
+
+impl Component for ThemedLabel {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let theme = use_theme();
+        Paragraph::new("x")
+            .style(Style::default().fg(theme.primary))
+            .render(area, buffer);
+    }
+}
+
+fn render_primary(provider: &ThemeProviderComponent) -> Option<Color> {
+    clear_context_providers();
+    let area = Rect::new(0, 0, 1, 1);
+    let mut buffer = Buffer::empty(area);
+    provider.render(area, &mut buffer);
+    buffer[(0, 0)].style().fg
+}
+
+#[test]
+fn test_use_theme_defaults_to_dark() {
+    with_component_id("UnthemedComponent", |_ctx| {
+        clear_context_providers();
+        assert_eq!(use_theme(), Theme::dark());
+    });
+}
+
+#[test]
+fn test_use_theme_reads_provided_theme() {
+    with_component_id("ThemeProviderComponent", |_ctx| {
+        clear_context_providers();
+        let provided = use_theme_provider(Theme::high_contrast());
+        assert_eq!(provided, Theme::high_contrast());
+
+        with_component_id("ThemedChild", |_ctx| {
+            assert_eq!(use_theme().primary, Color::Yellow);
+        });
+    });
+}
+
+#[test]
+fn test_theme_provider_restyles_children() {
+    with_component_id("ThemeProviderRender", |_ctx| {
+        let children = vec![Element::component(ThemedLabel)];
+
+        let dark = ThemeProviderComponent::new(
+            ThemeProviderProps::default()
+                .theme(Theme::dark())
+                .with_children(children.clone()),
+        );
+        assert_eq!(render_primary(&dark), Some(Theme::dark().primary));
+
+        let light = ThemeProviderComponent::new(
+            ThemeProviderProps::default()
+                .theme(Theme::light())
+                .with_children(children),
+        );
+        assert_eq!(render_primary(&light), Some(Theme::light().primary));
+    });
+}
+
+#[test]
+fn test_theme_styles() {
+    let theme = Theme::light();
+    assert_eq!(theme.border_style().fg, Some(Color::Gray));
+    assert!(theme.title_style().add_modifier.contains(Modifier::BOLD));
+    assert_eq!(theme.error_style().fg, Some(Color::Red));
+}
+
+#[test]
+fn test_color_scheme_from_background() {
+    assert_eq!(ColorScheme::from_background(0, 0, 0), ColorScheme::Dark);
+    assert_eq!(ColorScheme::from_background(40, 42, 54), ColorScheme::Dark);
+    assert_eq!(
+        ColorScheme::from_background(255, 255, 255),
+        ColorScheme::Light
+    );
+    assert_eq!(
+        ColorScheme::from_background(253, 246, 227),
+        ColorScheme::Light
+    );
+}
+
+#[test]
+fn test_set_color_scheme() {
+    assert_eq!(use_color_scheme(), ColorScheme::Dark);
+
+    set_color_scheme(ColorScheme::Light);
+    assert_eq!(use_color_scheme(), ColorScheme::Light);
+    assert_eq!(use_color_scheme().theme(), Theme::light());
+
+    set_color_scheme(ColorScheme::Dark);
+    assert_eq!(use_color_scheme(), ColorScheme::Dark);
+}
@@ -3,7 +3,9 @@
 //! This module provides the `use_timeout` hook for scheduling one-time callbacks
 //! that execute after a specified duration.
 
+use crate::clock;
 use crate::effect::use_effect;
+use crate::panic_handler::spawn_registered;
 use std::time::Duration;
 
 #[cfg(test)]
@@ -127,17 +129,15 @@ where
             };
 
             // Check if we're in a tokio runtime context
-            let handle = match tokio::runtime::Handle::try_current() {
-                Ok(handle) => handle,
-                Err(_) => {
-                    eprintln!("Warning: use_timeout called outside tokio runtime context");
-                    return None;
-                }
-            };
+            if tokio::runtime::Handle::try_current().is_err() {
+                eprintln!("Warning: use_timeout called outside tokio runtime context");
+                return None;
+            }
 
             // Spawn async timeout task
-            let task_handle = handle.spawn(async move {
-                tokio::time::sleep(safe_duration).await;
+            let sleep = clock::sleep(safe_duration);
+            let task_handle = spawn_registered(async move {
+                sleep.await;
                 callback();
             });
 
@@ -190,18 +190,14 @@ where
                 duration
             };
 
-            let handle = match tokio::runtime::Handle::try_current() {
-                Ok(handle) => handle,
-                Err(_) => {
-                    eprintln!(
-                        "Warning: use_timeout_with_reset called outside tokio runtime context"
-                    );
-                    return None;
-                }
-            };
+            if tokio::runtime::Handle::try_current().is_err() {
+                eprintln!("Warning: use_timeout_with_reset called outside tokio runtime context");
+                return None;
+            }
 
-            let task_handle = handle.spawn(async move {
-                tokio::time::sleep(safe_duration).await;
+            let sleep = clock::sleep(safe_duration);
+            let task_handle = spawn_registered(async move {
+                sleep.await;
                 callback();
             });
 
@@ -279,20 +275,18 @@ where
                     duration
                 };
 
-                let handle = match tokio::runtime::Handle::try_current() {
-                    Ok(handle) => handle,
-                    Err(_) => {
-                        eprintln!(
-                            "Warning: use_timeout_controlled called outside tokio runtime context"
-                        );
-                        return None;
-                    }
-                };
+                if tokio::runtime::Handle::try_current().is_err() {
+                    eprintln!(
+                        "Warning: use_timeout_controlled called outside tokio runtime context"
+                    );
+                    return None;
+                }
 
-                let task_handle = handle.spawn({
+                let sleep = clock::sleep(safe_duration);
+                let task_handle = spawn_registered({
                     let set_is_active = set_is_active.clone();
                     async move {
-                        tokio::time::sleep(safe_duration).await;
+                        sleep.await;
                         callback();
                         set_is_active.set(false);
                     }
@@ -0,0 +1,379 @@
+//! Transient notifications stacked in a corner of the screen
+//!
+//! A [`ToastProvider`] keeps the toasts shown over its children, painted
+//! through a [`Portal`](reratui_core::Portal) so they float above the rest of
+//! the tree. Any component rendered inside it gets a [`ToastManager`] from
+//! [`use_toast`] and pushes notifications with it. Each toast dismisses
+//! itself once its duration has passed.
+
+use crate::clock;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget};
+use reratui_core::{Component, ComponentProps, Element, PortalComponent, PortalProps};
+
+use crate::context::{use_context, use_context_provider, with_context_scope};
+use crate::effect::use_effect;
+use crate::panic_handler::spawn_registered;
+use crate::ref_hook::use_ref;
+use crate::state::{StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// How long [`ToastManager::success`] and [`ToastManager::error`] toasts stay
+pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// What a toast reports, which sets its color and icon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Info,
+}
+
+impl ToastKind {
+    fn color(self) -> Color {
+        match self {
+            ToastKind::Success => Color::Green,
+            ToastKind::Error => Color::Red,
+            ToastKind::Info => Color::Cyan,
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            ToastKind::Success => "✓",
+            ToastKind::Error => "✗",
+            ToastKind::Info => "ℹ",
+        }
+    }
+}
+
+/// A notification shown by a [`ToastProvider`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+    /// When the toast dismisses itself
+    pub expires_at: Instant,
+}
+
+/// Pushes and dismisses the toasts of the nearest [`ToastProvider`]
+#[derive(Clone)]
+pub struct ToastManager {
+    set_toasts: StateSetter<Vec<Toast>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl ToastManager {
+    /// Shows `message` for `duration`, returning the toast's id
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>, duration: Duration) -> u64 {
+        let toast = Toast {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            kind,
+            message: message.into(),
+            expires_at: clock::now() + duration,
+        };
+        let id = toast.id;
+        self.set_toasts.update(move |toasts| {
+            let mut toasts = toasts.clone();
+            toasts.push(toast);
+            toasts
+        });
+        id
+    }
+
+    /// Shows a success toast for [`DEFAULT_TOAST_DURATION`]
+    pub fn success(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Success, message, DEFAULT_TOAST_DURATION)
+    }
+
+    /// Shows an error toast for [`DEFAULT_TOAST_DURATION`]
+    pub fn error(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Error, message, DEFAULT_TOAST_DURATION)
+    }
+
+    /// Shows an informational toast for `duration`
+    pub fn info(&self, message: impl Into<String>, duration: Duration) -> u64 {
+        self.show(ToastKind::Info, message, duration)
+    }
+
+    /// Removes the toast with `id` before it expires
+    pub fn dismiss(&self, id: u64) {
+        self.set_toasts.update(|toasts| {
+            toasts
+                .iter()
+                .filter(|toast| toast.id != id)
+                .cloned()
+                .collect()
+        });
+    }
+
+    /// Removes every toast
+    pub fn clear(&self) {
+        self.set_toasts.set(Vec::new());
+    }
+}
+
+/// Returns the [`ToastManager`] of the nearest [`ToastProvider`]
+///
+/// # Panics
+///
+/// Panics when not rendered inside a `ToastProvider`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reratui_hooks::toast::use_toast;
+/// use std::time::Duration;
+///
+/// let toast = use_toast();
+/// toast.success("Saved");
+/// toast.error("Could not reach the server");
+/// toast.info("Syncing in the background", Duration::from_secs(5));
+/// ```
+pub fn use_toast() -> ToastManager {
+    use_context::<ToastManager>()
+}
+
+/// The corner a [`ToastProvider`] stacks its toasts in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastPosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+/// Props for the `ToastProvider` component
+#[derive(Clone)]
+pub struct ToastProviderProps {
+    /// Corner the toasts are stacked in, the newest nearest to it
+    pub position: ToastPosition,
+    /// Width of each toast including its border, clamped to the frame
+    pub width: u16,
+    /// Stacking order relative to other portals
+    pub z_index: i32,
+    pub children: Vec<Element>,
+}
+
+impl Default for ToastProviderProps {
+    fn default() -> Self {
+        Self {
+            position: ToastPosition::default(),
+            width: 40,
+            z_index: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl ToastProviderProps {
+    /// Sets the corner the toasts are stacked in
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of each toast
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the stacking order among portals
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for ToastProviderProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders its children with a [`ToastManager`] available through
+/// [`use_toast`], and the current toasts stacked above them
+///
+/// A single child fills the whole area; several children are stacked
+/// vertically.
+///
+/// ```ignore
+/// rsx! {
+///     <ToastProvider position={ToastPosition::BottomRight}>
+///         <App />
+///     </ToastProvider>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ToastProviderComponent {
+    props: ToastProviderProps,
+}
+
+impl ToastProviderComponent {
+    pub fn new(props: ToastProviderProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for ToastProviderComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let (toasts, set_toasts) = use_state(Vec::<Toast>::new);
+        let next_id = use_ref(|| Arc::new(AtomicU64::new(0))).get();
+
+        // Dismiss the toast expiring first once its time is up, which starts
+        // the timer for the next one
+        let now = clock::now();
+        let toasts = toasts.get();
+        let next_expiry = toasts
+            .iter()
+            .min_by_key(|toast| toast.expires_at)
+            .map(|toast| (toast.id, toast.expires_at));
+        use_effect::<Option<u64>, _, _>(
+            {
+                let set_toasts = set_toasts.clone();
+                move || {
+                    let (_, expires_at) = next_expiry?;
+                    if tokio::runtime::Handle::try_current().is_err() {
+                        return None;
+                    }
+                    let task = spawn_registered(async move {
+                        clock::sleep_until(expires_at).await;
+                        let now = clock::now();
+                        set_toasts.update(|toasts| {
+                            toasts
+                                .iter()
+                                .filter(|toast| toast.expires_at > now)
+                                .cloned()
+                                .collect()
+                        });
+                    });
+                    Some(move || task.abort())
+                }
+            },
+            next_expiry.map(|(id, _)| id),
+        );
+
+        let visible: Vec<Toast> = toasts
+            .into_iter()
+            .filter(|toast| toast.expires_at > now)
+            .collect();
+
+        with_context_scope(|| {
+            use_context_provider(|| ToastManager {
+                set_toasts,
+                next_id,
+            });
+
+            let children = &self.props.children;
+            if children.len() == 1 {
+                children[0].render(area, buffer);
+            } else if !children.is_empty() {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(vec![Constraint::Min(0); children.len()])
+                    .split(area);
+                for (child, chunk) in children.iter().zip(chunks.iter()) {
+                    child.render(*chunk, buffer);
+                }
+            }
+        });
+
+        if visible.is_empty() {
+            return;
+        }
+        let stack = ToastStack {
+            toasts: visible,
+            position: self.props.position,
+            width: self.props.width,
+        };
+        PortalComponent::new(
+            PortalProps::default()
+                .z_index(self.props.z_index)
+                .with_children(vec![Element::component(stack)]),
+        )
+        .render(area, buffer);
+    }
+}
+
+/// The `ToastProvider` component, usable as `<ToastProvider>` in `rsx!`
+pub type ToastProvider = ToastProviderComponent;
+
+/// Height of a toast: its message between two borders
+const TOAST_HEIGHT: u16 = 3;
+
+/// The visible toasts, rendered over the full frame
+struct ToastStack {
+    toasts: Vec<Toast>,
+    position: ToastPosition,
+    width: u16,
+}
+
+impl Component for ToastStack {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let width = self.width.min(area.width);
+        let x = match self.position {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => area.x,
+            ToastPosition::TopRight | ToastPosition::BottomRight => area.right() - width,
+        };
+        let from_top = matches!(
+            self.position,
+            ToastPosition::TopLeft | ToastPosition::TopRight
+        );
+
+        // The newest toast is nearest to the corner; those that don't fit are
+        // left out
+        let fitting = (area.height / TOAST_HEIGHT) as usize;
+        for (slot, toast) in self.toasts.iter().rev().take(fitting).enumerate() {
+            let offset = slot as u16 * TOAST_HEIGHT;
+            let y = if from_top {
+                area.y + offset
+            } else {
+                area.bottom() - offset - TOAST_HEIGHT
+            };
+            let toast_area = Rect::new(x, y, width, TOAST_HEIGHT);
+
+            let color = toast.kind.color();
+            Clear.render(toast_area, buffer);
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", toast.kind.icon()),
+                    Style::default().fg(color),
+                ),
+                Span::raw(toast.message.clone()),
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(color)),
+            )
+            .render(toast_area, buffer);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Tests for toast notifications
+
+use super::*;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use parking_lot::Mutex;
+use reratui_core::portal::render_portals;
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel test execution
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Hands out the provided toast manager
+#[derive(Clone)]
+struct Page {
+    manager: Arc<Mutex<Option<ToastManager>>>,
+}
+
+impl Component for Page {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        *self.manager.lock() = Some(use_toast());
+        Paragraph::new("page").render(area, buffer);
+    }
+}
+
+/// Renders a provider around a `Page` and returns the rows of the frame
+fn render_provider(props: ToastProviderProps, page: &Page) -> Vec<String> {
+    let area = Rect::new(0, 0, 20, 7);
+    let mut buffer = Buffer::empty(area);
+    let provider =
+        ToastProviderComponent::new(props).with_children(vec![Element::component(page.clone())]);
+    with_component_id("ToastProviderTest", |_ctx| {
+        provider.render(area, &mut buffer)
+    });
+    render_portals(area, &mut buffer);
+    (0..area.height)
+        .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn test_toasts_stack_in_corner() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let page = Page {
+            manager: Arc::default(),
+        };
+        let props = ToastProviderProps::default().width(12);
+        assert_eq!(
+            render_provider(props.clone(), &page)[0],
+            "page                "
+        );
+
+        let toast = page.manager.lock().clone().unwrap();
+        toast.success("Saved");
+        toast.error("Failed");
+        let rows = render_provider(props.clone(), &page);
+        assert_eq!(rows[0], "page    ╭──────────╮");
+        assert_eq!(rows[1], "        │✗ Failed  │");
+        assert_eq!(rows[4], "        │✓ Saved   │");
+        assert_eq!(rows[6], "                    ");
+
+        // Only toasts that fit are shown, the newest first
+        let third = toast.info("Syncing", Duration::from_secs(60));
+        let rows = render_provider(props.clone().position(ToastPosition::BottomLeft), &page);
+        assert_eq!(rows[0], "page                ");
+        assert_eq!(rows[2], "│✗ Failed  │        ");
+        assert_eq!(rows[5], "│ℹ Syncing │        ");
+
+        toast.dismiss(third);
+        let rows = render_provider(props.clone(), &page);
+        assert_eq!(rows[1], "        │✗ Failed  │");
+        toast.clear();
+        assert_eq!(render_provider(props, &page)[1], "                    ");
+    });
+}
+
+#[test]
+fn test_expired_toasts_are_hidden() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        let page = Page {
+            manager: Arc::default(),
+        };
+        let props = ToastProviderProps::default().width(12);
+        render_provider(props.clone(), &page);
+
+        let toast = page.manager.lock().clone().unwrap();
+        toast.info("Gone", Duration::ZERO);
+        assert_eq!(render_provider(props, &page)[1], "                    ");
+    });
+}
+
+#[tokio::test]
+async fn test_toasts_dismiss_themselves() {
+    let page = Page {
+        manager: Arc::default(),
+    };
+    let props = ToastProviderProps::default().width(12);
+    let rows = || {
+        let _lock = TEST_MUTEX.lock();
+        render_provider(props.clone(), &page)
+    };
+    rows();
+
+    let toast = page.manager.lock().clone().unwrap();
+    toast.info("Brief", Duration::from_millis(20));
+    toast.info("Longer", Duration::from_millis(80));
+    // The first render after the push starts the timer for the first expiry
+    assert_eq!(rows()[4], "        │ℹ Brief   │");
+
+    let epoch = crate::state::state_epoch();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_ne!(
+        crate::state::state_epoch(),
+        epoch,
+        "Dismissing updates the state"
+    );
+    let rows_now = rows();
+    assert_eq!(rows_now[1], "        │ℹ Longer  │");
+    assert_eq!(rows_now[4], "                    ");
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    assert_eq!(rows()[1], "                    ");
+    crate::test_utils::cleanup_component_contexts();
+}
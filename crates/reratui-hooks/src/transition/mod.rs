@@ -0,0 +1,103 @@
+//! Transition hook for non-urgent state updates
+//!
+//! This module provides the `use_transition` hook. Updates started as a
+//! transition are held back for a frame, so the current content stays on
+//! screen, with a pending indicator if desired, before the expensive re-render
+//! happens. The render loop applies them by calling [`flush_transitions`]
+//! after each frame.
+
+use crate::ref_hook::use_ref;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(test)]
+mod tests;
+
+type TransitionUpdate = Box<dyn FnOnce() + Send>;
+
+/// Transition updates waiting for the render loop
+#[derive(Default)]
+struct TransitionQueue {
+    /// Updates started during the frame being rendered
+    queued: Vec<TransitionUpdate>,
+    /// Updates whose pending frame has been drawn, applied at the next flush
+    ready: Vec<TransitionUpdate>,
+}
+
+static TRANSITIONS: Mutex<TransitionQueue> = Mutex::new(TransitionQueue {
+    queued: Vec::new(),
+    ready: Vec::new(),
+});
+
+/// Applies transition updates that have had their pending frame
+///
+/// The runtime calls this after drawing each frame. Updates started during a
+/// frame are applied at the end of the following one, so exactly one frame is
+/// drawn with the transition pending. Returns `true` if any update ran.
+pub fn flush_transitions() -> bool {
+    let ready = {
+        let mut queue = TRANSITIONS.lock();
+        let queued = std::mem::take(&mut queue.queued);
+        std::mem::replace(&mut queue.ready, queued)
+    };
+
+    let ran = !ready.is_empty();
+    for update in ready {
+        update();
+    }
+    ran
+}
+
+/// Starts transitions for the component that called [`use_transition`]
+#[derive(Clone)]
+pub struct StartTransition {
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl StartTransition {
+    /// Schedules `update` as a non-urgent update
+    ///
+    /// The component renders with `is_pending == true` until the update has
+    /// been applied.
+    pub fn start<F>(&self, update: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+
+        let in_flight = self.in_flight.clone();
+        TRANSITIONS.lock().queued.push(Box::new(move || {
+            update();
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        }));
+    }
+}
+
+/// A hook for marking state updates as non-urgent, like React's `useTransition`.
+///
+/// Returns whether a transition started by this component is still pending,
+/// and a handle for starting one. Updates passed to
+/// [`StartTransition::start`] run after the next frame has been drawn, so the
+/// old content stays visible in the meantime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::state::use_state;
+/// use reratui_hooks::transition::use_transition;
+///
+/// let (tab, set_tab) = use_state(|| 0);
+/// let (is_pending, start_transition) = use_transition();
+///
+/// // In a key handler: switch tabs without stalling the current frame
+/// start_transition.start(move || set_tab.set(1));
+///
+/// let title = if is_pending { "Loading tab..." } else { "Tab" };
+/// ```
+pub fn use_transition() -> (bool, StartTransition) {
+    let in_flight = use_ref(|| Arc::new(AtomicUsize::new(0))).get();
+    let is_pending = in_flight.load(Ordering::SeqCst) > 0;
+
+    (is_pending, StartTransition { in_flight })
+}
@@ -0,0 +1,76 @@
+//! Tests for the transition hook
+
+use super::*;
+use crate::state::use_state;
+use crate::test_utils::{with_component_id, with_test_isolate};
+use std::sync::LazyLock;
+
+// Test mutex to prevent parallel tests from flushing each other's transitions
+static TEST_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[test]
+fn test_transition_applies_after_one_pending_frame() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        flush_transitions();
+        flush_transitions();
+
+        // Frame 1: the update is started
+        with_component_id("TransitionTest", |_ctx| {
+            let (tab, set_tab) = use_state(|| 0);
+            let (is_pending, start_transition) = use_transition();
+            assert_eq!(tab.get(), 0);
+            assert!(!is_pending);
+
+            start_transition.start(move || set_tab.set(1));
+        });
+        assert!(!flush_transitions(), "Update must wait for a pending frame");
+
+        // Frame 2: the old content is shown as pending
+        with_component_id("TransitionTest", |_ctx| {
+            let (tab, _set_tab) = use_state(|| 0);
+            let (is_pending, _start_transition) = use_transition();
+            assert_eq!(tab.get(), 0);
+            assert!(is_pending);
+        });
+        assert!(flush_transitions());
+
+        // Frame 3: the new content is rendered
+        with_component_id("TransitionTest", |_ctx| {
+            let (tab, _set_tab) = use_state(|| 0);
+            let (is_pending, _start_transition) = use_transition();
+            assert_eq!(tab.get(), 1);
+            assert!(!is_pending);
+        });
+    });
+}
+
+#[test]
+fn test_transition_pending_until_all_updates_applied() {
+    let _lock = TEST_MUTEX.lock();
+    with_test_isolate(|| {
+        flush_transitions();
+        flush_transitions();
+
+        let start_transition = with_component_id("TransitionManyTest", |_ctx| {
+            let (_is_pending, start_transition) = use_transition();
+            start_transition.start(|| {});
+            start_transition
+        });
+        flush_transitions();
+
+        start_transition.start(|| {});
+        flush_transitions();
+
+        with_component_id("TransitionManyTest", |_ctx| {
+            let (is_pending, _start_transition) = use_transition();
+            assert!(is_pending, "The second update has not been applied yet");
+        });
+        flush_transitions();
+
+        with_component_id("TransitionManyTest", |_ctx| {
+            let (is_pending, _start_transition) = use_transition();
+            assert!(!is_pending);
+        });
+    });
+}
@@ -0,0 +1,81 @@
+//! Viewport Hook - Access to the full terminal area
+//!
+//! [`use_area`](crate::area::use_area) tells a component where it renders.
+//! [`use_viewport`] tells any component, however deeply nested, the area of the
+//! whole frame, origin included. Overlays use it to position themselves
+//! relative to the screen and responsive logic to pick a layout.
+
+use crate::frame::use_frame;
+use ratatui::layout::Rect;
+
+/// Hook to access the full area of the frame being rendered
+///
+/// The area is read from the current frame, so it always matches what is on
+/// screen. The renderer draws a new frame after every resize, which renders
+/// all consumers again with the new area; unlike
+/// [`use_terminal_dimensions`](crate::resize::use_terminal_dimensions) there
+/// is no need to wait for the first resize event.
+///
+/// # Panics
+///
+/// Panics if called outside of a render where the renderer provided the
+/// frame, like [`use_frame`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reratui_hooks::viewport::use_viewport;
+/// use ratatui::layout::Rect;
+///
+/// let viewport = use_viewport();
+///
+/// // A tooltip kept on screen near the right edge
+/// let tooltip = Rect::new(viewport.right().saturating_sub(20), viewport.y, 20, 3);
+/// ```
+pub fn use_viewport() -> Rect {
+    use_frame().frame().area()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::use_context_provider;
+    use crate::frame::FrameContext;
+    use crate::test_utils::with_component_id;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use std::time::{Duration, Instant};
+
+    fn viewport_of(terminal: &mut Terminal<TestBackend>) -> Rect {
+        let mut viewport = Rect::default();
+        terminal
+            .draw(|frame| {
+                // SAFETY: The context is only used within this draw call
+                let frame_ctx =
+                    unsafe { FrameContext::new(frame, 0, Duration::ZERO, Instant::now()) };
+                with_component_id("ViewportTest", |_ctx| {
+                    use_context_provider(|| frame_ctx);
+                    viewport = use_viewport();
+                });
+            })
+            .unwrap();
+        viewport
+    }
+
+    #[test]
+    fn test_use_viewport_follows_resizes() {
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        assert_eq!(viewport_of(&mut terminal), Rect::new(0, 0, 40, 12));
+
+        terminal.backend_mut().resize(100, 30);
+        assert_eq!(viewport_of(&mut terminal), Rect::new(0, 0, 100, 30));
+    }
+
+    #[test]
+    #[should_panic(expected = "Context value for type")]
+    fn test_use_viewport_without_frame_panics() {
+        with_component_id("ComponentWithoutFrame", |_ctx| {
+            let _viewport = use_viewport();
+        });
+    }
+}
@@ -0,0 +1,92 @@
+//! Visibility Hook - Whether a component is inside its scroll container's window
+//!
+//! Scroll containers render their whole content and show part of it, so a
+//! component rendered inside one may be entirely out of view. Containers
+//! record the part of their content that is shown as a [`ClipRect`] through
+//! [`with_clip`], and [`use_is_visible`] compares the component's area with
+//! the innermost one.
+//!
+//! Outside any clipping container every component counts as visible, even one
+//! whose area is empty: the frame itself does not clip.
+
+use crate::area::ComponentArea;
+use crate::context::{try_use_context, use_context_provider, with_context_scope};
+use ratatui::layout::Rect;
+
+#[cfg(test)]
+mod tests;
+
+/// The part of a clipping container's content that is shown
+///
+/// The rectangle is in the coordinates the container's children render in,
+/// which for a container rendering into an off-screen buffer are those of the
+/// buffer. An empty rectangle means nothing is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipRect(pub Rect);
+
+/// Renders children of a clipping container that shows only `clip` of them
+///
+/// [`use_is_visible`] calls made while `render` runs compare against `clip`.
+/// If the container is itself clipped out of view by an outer container,
+/// nothing inside it counts as visible.
+///
+/// `area` is where the container renders, in the coordinates of the outer
+/// container's clip.
+pub fn with_clip<R>(area: Rect, clip: Rect, render: impl FnOnce() -> R) -> R {
+    let clip = if is_area_visible(area) {
+        clip
+    } else {
+        Rect::default()
+    };
+
+    with_context_scope(|| {
+        use_context_provider(|| ClipRect(clip));
+        render()
+    })
+}
+
+/// Whether `area` is within the innermost clipping container's window
+///
+/// Always `true` outside clipping containers.
+pub fn is_area_visible(area: Rect) -> bool {
+    try_use_context::<ClipRect>().is_none_or(|clip| clip.0.intersects(area))
+}
+
+/// Hook returning whether any of the component's area is in view
+///
+/// Inside a scroll container such as [`ScrollView`](crate::scroll::ScrollView)
+/// this is `false` while the component is scrolled out of its window, so it
+/// can skip costly work until it scrolls in. Outside of one it is always
+/// `true`. The value is that of the current render; the component renders
+/// again when scrolling changes it.
+///
+/// # Examples
+///
+/// A card that loads its data the first time it scrolls into view:
+///
+/// ```rust,no_run
+/// use reratui_hooks::future::use_future;
+/// use reratui_hooks::ref_hook::use_ref;
+/// use reratui_hooks::visibility::use_is_visible;
+///
+/// let visible = use_is_visible();
+/// // Once seen, keep the data even when scrolled away again
+/// let seen = use_ref(|| false);
+/// if visible {
+///     seen.set(true);
+/// }
+/// let seen = seen.get();
+///
+/// let stats = use_future::<bool, _, _, _, String>(
+///     move |_token| async move {
+///         if !seen {
+///             return Ok(None);
+///         }
+///         Ok(Some("42 requests".to_string()))
+///     },
+///     Some(seen),
+/// );
+/// ```
+pub fn use_is_visible() -> bool {
+    try_use_context::<ComponentArea>().is_none_or(|area| is_area_visible(area.0))
+}
@@ -0,0 +1,100 @@
+//! Tests for the visibility hook
+
+use super::*;
+use crate::event::set_current_event;
+use crate::scroll::{ScrollViewComponent, ScrollViewProps};
+use crate::test_utils::{with_component_id, with_test_isolate};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use reratui_core::{Component, Element};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Renders a component at `area` and returns what `use_is_visible` said
+fn visible_at(area: Rect) -> bool {
+    with_context_scope(|| {
+        use_context_provider(|| ComponentArea(area));
+        use_is_visible()
+    })
+}
+
+#[test]
+fn test_visible_without_clipping_container() {
+    with_component_id("Unclipped", |_ctx| {
+        assert!(use_is_visible());
+        assert!(visible_at(Rect::new(0, 500, 10, 2)));
+        assert!(visible_at(Rect::default()));
+    });
+}
+
+#[test]
+fn test_visible_within_clip() {
+    with_component_id("Clipped", |_ctx| {
+        let container = Rect::new(0, 0, 10, 3);
+        with_clip(container, Rect::new(0, 4, 10, 3), || {
+            assert!(!visible_at(Rect::new(0, 0, 10, 4)));
+            assert!(visible_at(Rect::new(0, 3, 10, 2)));
+            assert!(visible_at(Rect::new(0, 6, 10, 5)));
+            assert!(!visible_at(Rect::new(0, 7, 10, 5)));
+
+            // A nested container scrolled out of view hides all its children
+            with_clip(Rect::new(0, 20, 10, 3), Rect::new(0, 0, 10, 3), || {
+                assert!(!visible_at(Rect::new(0, 0, 10, 1)));
+            });
+            with_clip(Rect::new(0, 5, 10, 3), Rect::new(0, 0, 10, 3), || {
+                assert!(visible_at(Rect::new(0, 0, 10, 1)));
+            });
+        });
+
+        // The clip ends with the container
+        assert!(visible_at(Rect::new(0, 0, 10, 4)));
+    });
+}
+
+/// Records whether it was visible each time it renders
+#[derive(Clone)]
+struct Probe {
+    seen: Rc<RefCell<Vec<bool>>>,
+}
+
+impl Component for Probe {
+    fn render(&self, area: Rect, _buffer: &mut Buffer) {
+        self.seen.borrow_mut().push(visible_at(area));
+    }
+}
+
+#[test]
+fn test_scroll_view_clips_children() {
+    with_test_isolate(|| {
+        set_current_event(None);
+        let top = Rc::new(RefCell::new(Vec::new()));
+        let bottom = Rc::new(RefCell::new(Vec::new()));
+        let view =
+            ScrollViewComponent::new(ScrollViewProps::default().content_height(10).with_children(
+                vec![
+                    Element::component(Probe { seen: top.clone() }),
+                    Element::component(Probe {
+                        seen: bottom.clone(),
+                    }),
+                ],
+            ));
+        let area = Rect::new(0, 0, 10, 3);
+        let render = || {
+            let mut buffer = Buffer::empty(area);
+            with_component_id("ScrollView", |_ctx| view.render(area, &mut buffer));
+        };
+
+        render();
+        assert_eq!(*top.borrow(), [true]);
+        assert_eq!(*bottom.borrow(), [false]);
+
+        let end = KeyEvent::new(KeyCode::End, KeyModifiers::NONE);
+        set_current_event(Some(Arc::new(Event::Key(end))));
+        render();
+        set_current_event(None);
+        render();
+        assert_eq!(top.borrow().last(), Some(&false));
+        assert_eq!(bottom.borrow().last(), Some(&true));
+    });
+}
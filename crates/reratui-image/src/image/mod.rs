@@ -0,0 +1,204 @@
+//! The `Image` component
+
+use crate::protocol::{GraphicsProtocol, kitty_clear, kitty_draw};
+use crate::source::ImageSource;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, BorderType, Paragraph, Widget, Wrap};
+use reratui_core::graphics::{GraphicsPlacement, place_graphics};
+use reratui_core::{Component, ComponentProps, Element};
+
+#[cfg(test)]
+mod tests;
+
+/// Props for the `Image` component
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageProps {
+    /// The image to show; the placeholder is shown without one
+    pub source: Option<ImageSource>,
+    /// How to draw the image
+    pub protocol: GraphicsProtocol,
+    /// A description, shown in the placeholder
+    pub alt: String,
+}
+
+impl ImageProps {
+    /// Sets the image to show
+    pub fn source(mut self, source: ImageSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Sets how the image is drawn
+    pub fn protocol(mut self, protocol: GraphicsProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Sets the description shown in the placeholder
+    pub fn alt(mut self, alt: impl Into<String>) -> Self {
+        self.alt = alt.into();
+        self
+    }
+
+    /// An image has no children; they are ignored
+    pub fn with_children(self, _children: Vec<Element>) -> Self {
+        self
+    }
+}
+
+impl ComponentProps for ImageProps {
+    fn get_children(&self) -> Vec<Element> {
+        Vec::new()
+    }
+
+    fn set_children(&mut self, _children: Vec<Element>) {}
+}
+
+/// Shows an image in the terminal, scaled to fit its area
+///
+/// With the Kitty protocol the picture is drawn over the area's cells,
+/// centered and scaled to fit while keeping its aspect ratio, assuming cells
+/// twice as tall as they are wide. Otherwise, or when the source can't be
+/// decoded, a placeholder box shows `alt` or the decoding error.
+///
+/// ```ignore
+/// use reratui_image::{GraphicsProtocol, ImageComponent, ImageProps, ImageSource};
+///
+/// let logo = use_memo(|| ImageSource::path("assets/logo.png"), ());
+/// rsx! {
+///     <Image source={logo} alt="Project logo" />
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ImageComponent {
+    props: ImageProps,
+}
+
+impl ImageComponent {
+    pub fn new(props: ImageProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(self, _children: Vec<Element>) -> Self {
+        self
+    }
+
+    /// Draws the placeholder box, with `error` if the image failed to load
+    fn render_placeholder(&self, area: Rect, buffer: &mut Buffer, error: Option<&str>) {
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::DarkGray));
+        let inner = block.inner(area);
+        block.render(area, buffer);
+
+        let (text, style) = match error {
+            Some(error) => (format!("⚠ {error}"), Style::default().fg(Color::Red)),
+            None if self.props.alt.is_empty() => {
+                ("🖼 image".to_string(), Style::default().fg(Color::Gray))
+            }
+            None => (
+                format!("🖼 {}", self.props.alt),
+                Style::default().fg(Color::Gray),
+            ),
+        };
+        let lines = Paragraph::new(text.clone())
+            .wrap(Wrap { trim: true })
+            .line_count(inner.width) as u16;
+        let top = inner.height.saturating_sub(lines) / 2;
+        Paragraph::new(text)
+            .style(style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .render(
+                Rect {
+                    y: inner.y + top,
+                    height: inner.height - top,
+                    ..inner
+                },
+                buffer,
+            );
+    }
+}
+
+/// The cells of `area` an image of `width` by `height` pixels fills,
+/// centered, keeping its aspect ratio with cells twice as tall as wide
+pub fn fit(width: u32, height: u32, area: Rect) -> Rect {
+    if width == 0 || height == 0 || area.is_empty() {
+        return Rect {
+            width: 0,
+            height: 0,
+            ..area
+        };
+    }
+
+    // Columns per row the picture needs to keep its shape
+    let ratio = f64::from(width) / (f64::from(height) / 2.0);
+    let (cols, rows) = if ratio >= f64::from(area.width) / f64::from(area.height) {
+        let rows = (f64::from(area.width) / ratio).round().max(1.0) as u16;
+        (area.width, rows.min(area.height))
+    } else {
+        let cols = (f64::from(area.height) * ratio).round().max(1.0) as u16;
+        (cols.min(area.width), area.height)
+    };
+
+    Rect::new(
+        area.x + (area.width - cols) / 2,
+        area.y + (area.height - rows) / 2,
+        cols,
+        rows,
+    )
+}
+
+impl Component for ImageComponent {
+    fn measure(&self, width: u16) -> Option<u16> {
+        let (image_width, image_height) = self.props.source.as_ref()?.dimensions()?;
+        let rows = f64::from(width) * f64::from(image_height) / f64::from(image_width) / 2.0;
+        Some(rows.round().max(1.0).min(f64::from(u16::MAX)) as u16)
+    }
+
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let area = area.intersection(buffer.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let Some(source) = &self.props.source else {
+            self.render_placeholder(area, buffer, None);
+            return;
+        };
+        let decoded = match source.decoded() {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                self.render_placeholder(area, buffer, Some(error));
+                return;
+            }
+        };
+        if self.props.protocol.resolve() != GraphicsProtocol::Kitty {
+            self.render_placeholder(area, buffer, None);
+            return;
+        }
+
+        // Blank cells under the picture, so the diff leaves them alone
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buffer[(x, y)].reset();
+            }
+        }
+
+        let picture = fit(decoded.width, decoded.height, area);
+        let draw = source.cached_sequence((picture.width, picture.height), || {
+            kitty_draw(source.id(), &decoded.png, picture.width, picture.height)
+        });
+        place_graphics(GraphicsPlacement {
+            key: u64::from(source.id()),
+            area: picture,
+            draw,
+            clear: kitty_clear(source.id()).into(),
+        });
+    }
+}
+
+/// The `Image` component, usable as `<Image source={...} />` in `rsx!`
+pub type Image = ImageComponent;
@@ -0,0 +1,75 @@
+//! Tests for the Image component
+
+use super::*;
+use image::{DynamicImage, RgbaImage};
+use reratui_core::graphics::take_graphics;
+
+fn source(width: u32, height: u32) -> ImageSource {
+    ImageSource::from(DynamicImage::ImageRgba8(RgbaImage::new(width, height)))
+}
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect()
+}
+
+#[test]
+fn test_fit_keeps_aspect_ratio() {
+    let area = Rect::new(0, 0, 30, 10);
+    // A square picture takes twice as many columns as rows
+    assert_eq!(fit(100, 100, area), Rect::new(5, 0, 20, 10));
+    // A wide one fills the width
+    assert_eq!(fit(300, 100, area), Rect::new(0, 2, 30, 5));
+    assert_eq!(fit(0, 100, area).area(), 0);
+}
+
+#[test]
+fn test_kitty_places_the_picture() {
+    let image = source(100, 100);
+    let component = ImageComponent::new(
+        ImageProps::default()
+            .source(image.clone())
+            .protocol(GraphicsProtocol::Kitty),
+    );
+    let area = Rect::new(0, 0, 30, 10);
+    let mut buffer = Buffer::empty(area);
+    buffer.set_string(0, 0, "stale text", Style::default());
+    take_graphics();
+
+    component.render(area, &mut buffer);
+    assert_eq!(row(&buffer, 0).trim(), "");
+    let placements = take_graphics();
+    assert_eq!(placements.len(), 1);
+    assert_eq!(placements[0].key, u64::from(image.id()));
+    assert_eq!(placements[0].area, Rect::new(5, 0, 20, 10));
+    assert!(placements[0].draw.starts_with(b"\x1b_Ga=T,f=100"));
+    assert_eq!(component.measure(20), Some(10));
+}
+
+#[test]
+fn test_placeholder_without_graphics() {
+    let area = Rect::new(0, 0, 20, 5);
+    let render = |props: ImageProps| {
+        let mut buffer = Buffer::empty(area);
+        ImageComponent::new(props).render(area, &mut buffer);
+        assert!(take_graphics().is_empty());
+        buffer
+    };
+
+    let buffer = render(
+        ImageProps::default()
+            .source(source(10, 10))
+            .protocol(GraphicsProtocol::Placeholder)
+            .alt("Logo"),
+    );
+    assert!(row(&buffer, 0).starts_with('╭'));
+    assert!(row(&buffer, 2).contains("Logo"));
+
+    let buffer = render(
+        ImageProps::default()
+            .source(ImageSource::bytes(b"nope".to_vec()))
+            .protocol(GraphicsProtocol::Kitty),
+    );
+    assert!((1..4).any(|y| row(&buffer, y).contains('⚠')));
+}
@@ -0,0 +1,35 @@
+//! Reratui Image - Pictures drawn with terminal graphics protocols
+//!
+//! This crate provides the `Image` component, which shows an [`ImageSource`]
+//! decoded with the `image` crate. Terminals speaking the Kitty graphics
+//! protocol (kitty, WezTerm, Ghostty) show the picture itself; elsewhere a
+//! placeholder box with the image's description is drawn instead. Like other
+//! components, `<Image>` in `rsx!` needs `ImageComponent` and `ImageProps` in
+//! scope.
+//!
+//! The picture is written by the runtime after each frame, outside ratatui's
+//! cell buffer; see `reratui_core::graphics` for how it survives redraws.
+//!
+//! ```ignore
+//! use reratui::prelude::*;
+//! use reratui_image::{ImageComponent, ImageProps, ImageSource};
+//!
+//! #[component]
+//! fn Gallery() -> Element {
+//!     let photo = use_memo(|| ImageSource::path("photos/harbor.jpg"), ());
+//!
+//!     rsx! {
+//!         <Block title="Harbor" borders={Borders::ALL}>
+//!             <Image source={photo} alt="Boats in the harbor at dusk" />
+//!         </Block>
+//!     }
+//! }
+//! ```
+
+pub mod image;
+pub mod protocol;
+pub mod source;
+
+pub use image::{Image, ImageComponent, ImageProps, fit};
+pub use protocol::GraphicsProtocol;
+pub use source::ImageSource;
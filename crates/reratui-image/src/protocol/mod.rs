@@ -0,0 +1,101 @@
+//! Terminal graphics protocols and their escape sequences
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use reratui_hooks::capabilities::use_terminal_capabilities;
+use std::env;
+use std::sync::OnceLock;
+
+#[cfg(test)]
+mod tests;
+
+/// Largest payload in one Kitty graphics escape sequence
+const KITTY_CHUNK: usize = 4096;
+
+/// How an `Image` is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    /// Whatever the terminal supports, as found by [`GraphicsProtocol::detect`]
+    #[default]
+    Auto,
+    /// The Kitty graphics protocol, supported by kitty, WezTerm and Ghostty
+    Kitty,
+    /// No graphics: a placeholder box with the image's description
+    Placeholder,
+}
+
+impl GraphicsProtocol {
+    /// The protocol the terminal supports
+    ///
+    /// Kitty is used when the terminal answered the runtime's graphics query,
+    /// see `use_terminal_capabilities`. Terminals that weren't asked are
+    /// recognized from `TERM`, `TERM_PROGRAM` and `KITTY_WINDOW_ID`, looked up
+    /// once. Inside tmux or screen, which don't pass graphics through by
+    /// default, and in any other terminal, this is
+    /// [`Placeholder`](Self::Placeholder). iTerm2's protocol and Sixel are not
+    /// supported yet.
+    pub fn detect() -> Self {
+        static DETECTED: OnceLock<GraphicsProtocol> = OnceLock::new();
+        if use_terminal_capabilities().kitty_graphics {
+            return GraphicsProtocol::Kitty;
+        }
+        *DETECTED.get_or_init(|| {
+            detect_from(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        })
+    }
+
+    /// This protocol, with `Auto` replaced by the detected one
+    pub fn resolve(self) -> Self {
+        match self {
+            GraphicsProtocol::Auto => Self::detect(),
+            protocol => protocol,
+        }
+    }
+}
+
+/// Detects the protocol from environment variables read with `var`
+fn detect_from(var: impl Fn(&str) -> Option<String>) -> GraphicsProtocol {
+    if var("TMUX").is_some() || var("STY").is_some() {
+        return GraphicsProtocol::Placeholder;
+    }
+
+    let term = var("TERM").unwrap_or_default();
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+    let kitty = var("KITTY_WINDOW_ID").is_some()
+        || term.contains("kitty")
+        || term.contains("ghostty")
+        || matches!(program.as_str(), "WezTerm" | "ghostty");
+
+    if kitty {
+        GraphicsProtocol::Kitty
+    } else {
+        GraphicsProtocol::Placeholder
+    }
+}
+
+/// The Kitty sequence showing PNG image `id` scaled to `cols` by `rows` cells
+/// at the cursor, which stays where it is
+pub(crate) fn kitty_draw(id: u32, png: &[u8], cols: u16, rows: u16) -> Vec<u8> {
+    let payload = STANDARD.encode(png);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut sequence = Vec::with_capacity(payload.len() + chunks.len() * 16 + 64);
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        sequence.extend_from_slice(b"\x1b_G");
+        if index == 0 {
+            sequence.extend_from_slice(
+                format!("a=T,f=100,t=d,i={id},c={cols},r={rows},C=1,q=2,").as_bytes(),
+            );
+        }
+        sequence.extend_from_slice(format!("m={more};").as_bytes());
+        sequence.extend_from_slice(chunk);
+        sequence.extend_from_slice(b"\x1b\\");
+    }
+    sequence
+}
+
+/// The Kitty sequence removing image `id` and freeing its data
+pub(crate) fn kitty_clear(id: u32) -> Vec<u8> {
+    format!("\x1b_Ga=d,d=I,i={id},q=2\x1b\\").into_bytes()
+}
@@ -0,0 +1,58 @@
+//! Tests for graphics protocols
+
+use super::*;
+
+fn detect_with(vars: &[(&str, &str)]) -> GraphicsProtocol {
+    detect_from(|name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[test]
+fn test_detect_kitty_terminals() {
+    assert_eq!(
+        detect_with(&[("TERM", "xterm-kitty")]),
+        GraphicsProtocol::Kitty
+    );
+    assert_eq!(
+        detect_with(&[("TERM", "xterm-256color"), ("KITTY_WINDOW_ID", "1")]),
+        GraphicsProtocol::Kitty
+    );
+    assert_eq!(
+        detect_with(&[("TERM_PROGRAM", "WezTerm")]),
+        GraphicsProtocol::Kitty
+    );
+    assert_eq!(
+        detect_with(&[("TERM", "xterm-256color")]),
+        GraphicsProtocol::Placeholder
+    );
+    // Multiplexers swallow graphics unless configured otherwise
+    assert_eq!(
+        detect_with(&[
+            ("TERM", "xterm-kitty"),
+            ("TMUX", "/tmp/tmux-1000/default,1,0")
+        ]),
+        GraphicsProtocol::Placeholder
+    );
+}
+
+#[test]
+fn test_kitty_sequences_are_chunked() {
+    let png = vec![7u8; 5000];
+    let sequence = String::from_utf8(kitty_draw(3, &png, 10, 4)).unwrap();
+    let chunks: Vec<&str> = sequence.split("\x1b\\").filter(|c| !c.is_empty()).collect();
+
+    // 5000 bytes are 6668 characters of base64: two chunks
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[0].starts_with("\x1b_Ga=T,f=100,t=d,i=3,c=10,r=4,C=1,q=2,m=1;"));
+    assert!(chunks[1].starts_with("\x1b_Gm=0;"));
+    let payload: String = chunks
+        .iter()
+        .map(|chunk| &chunk[chunk.find(';').unwrap() + 1..])
+        .collect();
+    assert_eq!(STANDARD.decode(payload).unwrap(), png);
+
+    assert_eq!(kitty_clear(3), b"\x1b_Ga=d,d=I,i=3,q=2\x1b\\");
+}
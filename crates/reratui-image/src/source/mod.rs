@@ -0,0 +1,160 @@
+//! Where an image comes from, decoded once on first use
+
+use image::{DynamicImage, ImageFormat};
+use parking_lot::Mutex;
+use std::fmt;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+
+#[cfg(test)]
+mod tests;
+
+/// Ids for sources, which the terminal uses to tell pictures apart
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// A drawing sequence with the cell size it was built for
+type CachedSequence = ((u16, u16), Arc<[u8]>);
+
+/// The image shown by an `Image` component
+///
+/// A source is decoded the first time it is drawn and keeps the result, so
+/// create it once, for example with `use_memo`, rather than on every render.
+/// Clones share the decoded image.
+///
+/// The terminal knows a source's picture by its id, so a source is shown in
+/// one place at a time; to show the same picture twice, create two sources.
+#[derive(Clone)]
+pub struct ImageSource {
+    id: u32,
+    origin: Arc<Origin>,
+    decoded: Arc<OnceLock<Result<Decoded, String>>>,
+    /// The last sequence built for drawing
+    cached: Arc<Mutex<Option<CachedSequence>>>,
+}
+
+enum Origin {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+    Image(DynamicImage),
+}
+
+/// An image ready to send to the terminal
+pub(crate) struct Decoded {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// The image encoded as PNG, which terminals decode themselves
+    pub(crate) png: Vec<u8>,
+}
+
+impl ImageSource {
+    fn new(origin: Origin) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            origin: Arc::new(origin),
+            decoded: Arc::new(OnceLock::new()),
+            cached: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// An image file, read when first drawn
+    ///
+    /// PNG, JPEG and GIF files are supported.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Self::new(Origin::Path(path.into()))
+    }
+
+    /// The contents of an image file, such as one embedded with `include_bytes!`
+    pub fn bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self::new(Origin::Bytes(bytes.into()))
+    }
+
+    /// The id the terminal knows this source's picture by
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The size of the image in pixels, or `None` if it can't be decoded
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        self.decoded()
+            .ok()
+            .map(|decoded| (decoded.width, decoded.height))
+    }
+
+    /// Why the image can't be decoded, if it can't
+    pub fn error(&self) -> Option<&str> {
+        self.decoded().err()
+    }
+
+    pub(crate) fn decoded(&self) -> Result<&Decoded, &str> {
+        self.decoded
+            .get_or_init(|| decode(&self.origin))
+            .as_ref()
+            .map_err(String::as_str)
+    }
+
+    /// The sequence drawing this image over `size` cells, built by `build`
+    /// unless the last one was for the same size
+    pub(crate) fn cached_sequence(
+        &self,
+        size: (u16, u16),
+        build: impl FnOnce() -> Vec<u8>,
+    ) -> Arc<[u8]> {
+        let mut cached = self.cached.lock();
+        match &*cached {
+            Some((cached_size, sequence)) if *cached_size == size => sequence.clone(),
+            _ => {
+                let sequence: Arc<[u8]> = build().into();
+                *cached = Some((size, sequence.clone()));
+                sequence
+            }
+        }
+    }
+}
+
+impl From<DynamicImage> for ImageSource {
+    fn from(image: DynamicImage) -> Self {
+        Self::new(Origin::Image(image))
+    }
+}
+
+impl PartialEq for ImageSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl fmt::Debug for ImageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let origin = match &*self.origin {
+            Origin::Path(path) => format!("{}", path.display()),
+            Origin::Bytes(bytes) => format!("{} bytes", bytes.len()),
+            Origin::Image(image) => format!("{}x{} image", image.width(), image.height()),
+        };
+        f.debug_struct("ImageSource")
+            .field("id", &self.id)
+            .field("origin", &origin)
+            .finish()
+    }
+}
+
+fn decode(origin: &Origin) -> Result<Decoded, String> {
+    let image = match origin {
+        Origin::Path(path) => image::open(path).map_err(|error| error.to_string())?,
+        Origin::Bytes(bytes) => {
+            image::load_from_memory(bytes).map_err(|error| error.to_string())?
+        }
+        Origin::Image(image) => image.clone(),
+    };
+
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|error| error.to_string())?;
+    Ok(Decoded {
+        width: image.width(),
+        height: image.height(),
+        png,
+    })
+}
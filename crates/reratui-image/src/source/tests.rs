@@ -0,0 +1,40 @@
+//! Tests for image sources
+
+use super::*;
+use image::RgbaImage;
+
+#[test]
+fn test_decoded_once_and_shared_by_clones() {
+    let source = ImageSource::from(DynamicImage::ImageRgba8(RgbaImage::new(4, 2)));
+    let clone = source.clone();
+    assert_eq!(source, clone);
+    assert_eq!(clone.dimensions(), Some((4, 2)));
+    assert!(std::ptr::eq(
+        source.decoded().unwrap(),
+        clone.decoded().unwrap()
+    ));
+
+    let png = &source.decoded().unwrap().png;
+    assert_eq!(ImageSource::bytes(png.clone()).dimensions(), Some((4, 2)));
+}
+
+#[test]
+fn test_undecodable_sources_report_errors() {
+    let missing = ImageSource::path("/nonexistent/picture.png");
+    assert_eq!(missing.dimensions(), None);
+    assert!(missing.error().is_some());
+
+    let garbage = ImageSource::bytes(b"not an image".to_vec());
+    assert!(garbage.error().is_some());
+    assert_ne!(missing, garbage);
+}
+
+#[test]
+fn test_sequences_are_cached_per_size() {
+    let source = ImageSource::bytes(Vec::new());
+    let first = source.cached_sequence((4, 2), || b"a".to_vec());
+    let again = source.cached_sequence((4, 2), || b"b".to_vec());
+    assert_eq!(&*again, b"a");
+    assert!(Arc::ptr_eq(&first, &again));
+    assert_eq!(&*source.cached_sequence((8, 4), || b"c".to_vec()), b"c");
+}
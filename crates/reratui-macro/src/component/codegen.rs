@@ -235,16 +235,21 @@ pub mod common {
         quote! {
             impl #impl_generics Component for #component_struct_name #ty_generics #where_clause {
                 fn render(&self, area: Rect, buffer: &mut Buffer) {
-                    // Provide the component area via context
-                    let _area_context = reratui::hooks::context::use_context_provider(|| {
-                        reratui::hooks::area::ComponentArea(area)
+                    // Context provided here is only visible to this component's subtree
+                    reratui::hooks::context::with_context_scope(|| {
+                        // Provide the component area via context
+                        let _area_context = reratui::hooks::context::use_context_provider(|| {
+                            reratui::hooks::area::ComponentArea(area)
+                        });
+
+                        // Call the component function, measuring what it renders for use_measure
+                        let element = reratui::hooks::measure::measure_content(area, || {
+                            #fn_name(&self.props)
+                        });
+
+                        // Render the element
+                        element.render(area, buffer);
                     });
-
-                    // Call the component function
-                    let element = #fn_name(&self.props);
-
-                    // Render the element
-                    element.render(area, buffer);
                 }
             }
         }
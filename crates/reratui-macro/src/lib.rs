@@ -32,6 +32,71 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     </Block>
 /// }
 /// ```
+///
+/// # Fragments
+///
+/// `<>...</>` groups sibling elements without a wrapper widget. The children
+/// are stacked vertically in the area the fragment is rendered in, splitting
+/// it evenly. Inside `Paragraph` the children contribute lines instead. Lists
+/// and tabs have no layout to stack widgets in, so fragments there are a
+/// compile error.
+///
+/// ```ignore
+/// rsx! {
+///     <>
+///         <Paragraph>{"Header"}</Paragraph>
+///         <Paragraph>{"Body"}</Paragraph>
+///     </>
+/// }
+/// ```
+///
+/// # Match expressions
+///
+/// A `{match ...}` child renders the element produced by the matching arm.
+/// Arms may be elements, fragments, string literals or expressions; expression
+/// arms such as nested `rsx!` calls must evaluate to an `Element`, and a
+/// mismatch is reported at the offending arm.
+///
+/// ```ignore
+/// rsx! {
+///     {match selected_tab {
+///         0 => rsx! { <Overview /> },
+///         1 => <Paragraph>{"Details"}</Paragraph>,
+///         _ => "Nothing here",
+///     }}
+/// }
+/// ```
+///
+/// # Dynamic components
+///
+/// A braced expression in tag position renders a component chosen at runtime.
+/// The expression must evaluate to a value implementing `Component`, including
+/// `Box<dyn Component>` and `Rc<dyn Component>`. Attributes are applied as
+/// builder method calls on that value, and the tag must be self-closing.
+///
+/// ```ignore
+/// let screen: Rc<dyn Component> = routes.current_screen();
+///
+/// rsx! {
+///     <Block borders={Borders::ALL}>
+///         <{screen.clone()} />
+///     </Block>
+/// }
+/// ```
+///
+/// # Event handlers
+///
+/// Widget elements accept `on_click` and `on_key` attributes. The widget is
+/// wrapped so that `on_click` fires for left clicks inside its render area and
+/// `on_key` fires for key presses while it is rendered.
+///
+/// ```ignore
+/// rsx! {
+///     <Block borders={Borders::ALL} on_click={move |_| set_count.update(|c| c + 1)}>
+///         <Paragraph>{"Click me"}</Paragraph>
+///     </Block>
+/// }
+/// ```
 #[proc_macro]
 pub fn rsx(input: TokenStream) -> TokenStream {
     rsx::rsx_impl(input)
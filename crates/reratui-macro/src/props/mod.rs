@@ -50,6 +50,16 @@ pub fn derive_props_impl(input: TokenStream) -> TokenStream {
                     self
                 }
             }
+        } else if is_ref_type(field_type) {
+            quote! {
+                pub fn #field_name<T>(mut self, value: T) -> Self
+                where
+                    T: reratui::hooks::ref_hook::IntoRefProp<#field_type>,
+                {
+                    self.#field_name = value.into_ref_prop();
+                    self
+                }
+            }
         } else {
             quote! {
                 pub fn #field_name<T: Into<#field_type>>(mut self, value: T) -> Self {
@@ -196,3 +206,25 @@ fn is_option_callback_type(ty: &Type) -> bool {
     }
     false
 }
+
+/// Helper function to check if a type is Ref<T> or Option<Ref<T>>
+fn is_ref_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+
+    if segment.ident == "Ref" {
+        return true;
+    }
+    if segment.ident == "Option"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(Type::Path(inner_path))) = args.args.first()
+        && let Some(inner_segment) = inner_path.path.segments.last()
+    {
+        return inner_segment.ident == "Ref";
+    }
+    false
+}
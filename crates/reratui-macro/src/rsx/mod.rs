@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{parse_macro_input, spanned::Spanned};
 
 use crate::rsx::parser::{
-    ConditionalNode, Element, ForLoopNode, FragmentNode, Node, RsxMainParser,
+    ConditionalNode, DynamicComponentNode, Element, ForLoopNode, FragmentNode, Node, RsxMainParser,
 };
 
 pub(crate) mod error;
@@ -86,6 +86,21 @@ fn generate_node_vnode_code(node: &Node) -> proc_macro2::TokenStream {
         Node::Expression(expr) => {
             quote! { Element::text(#expr) }
         }
+        Node::Conditional(ConditionalNode::Match { expr, arms }) => {
+            // Every arm produces an Element, so the match needs no Option or widget wrapping
+            let match_arms = arms.iter().map(|arm| {
+                let pattern = &arm.pattern;
+                let guard = arm.guard.as_ref().map(|guard| quote! { if #guard });
+                let element_code = generate_match_arm_code(&arm.body);
+                quote! { #pattern #guard => #element_code, }
+            });
+
+            quote! {
+                match #expr {
+                    #(#match_arms)*
+                }
+            }
+        }
         Node::Conditional(conditional) => {
             let conditional_code = generate_conditional_code(conditional);
             quote! {
@@ -122,6 +137,9 @@ fn generate_node_vnode_code(node: &Node) -> proc_macro2::TokenStream {
             let fragment_code = generate_fragment_code(fragment);
             quote! { #fragment_code }
         }
+        Node::DynamicComponent(dynamic_component) => {
+            generate_dynamic_component_code(dynamic_component)
+        }
     }
 }
 
@@ -141,6 +159,11 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
         return generate_component_code(element);
     }
 
+    // Elements with event handler attributes render inside an Interactive wrapper
+    if let Some(interactive_code) = generate_interactive_code(element) {
+        return interactive_code;
+    }
+
     // Extract the last segment of the path as a string
     let widget_type = name_str.split("::").last().unwrap_or(&name_str);
 
@@ -362,9 +385,9 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
                         // For-loops in tabs should resolve to empty lines
                         quote! { ::reratui::ratatui::text::Line::from("") }
                     }
-                    Node::Fragment(_) => {
-                        // Fragments in tabs should resolve to empty lines
-                        quote! { ::reratui::ratatui::text::Line::from("") }
+                    Node::Fragment(fragment) => fragment_without_layout_error(fragment, "Tabs"),
+                    Node::DynamicComponent(dynamic_component) => {
+                        dynamic_component_without_layout_error(dynamic_component, "Tabs")
                     }
                 });
 
@@ -407,9 +430,9 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
                         // For-loops in lists should resolve to empty items
                         quote! { ListItem::new("") }
                     }
-                    Node::Fragment(_) => {
-                        // Fragments in lists should resolve to empty items
-                        quote! { ListItem::new("") }
+                    Node::Fragment(fragment) => fragment_without_layout_error(fragment, "List"),
+                    Node::DynamicComponent(dynamic_component) => {
+                        dynamic_component_without_layout_error(dynamic_component, "List")
                     }
                 });
 
@@ -465,6 +488,9 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
                             let fragment_code = generate_fragment_code(fragment);
                             quote! { #fragment_code }
                         },
+                        Node::DynamicComponent(dynamic_component) => {
+                            generate_dynamic_component_code(dynamic_component)
+                        },
                     });
 
                     quote! {
@@ -504,6 +530,9 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
                         let fragment_code = generate_fragment_code(fragment);
                         quote! { #fragment_code }
                     },
+                    Node::DynamicComponent(dynamic_component) => {
+                        generate_dynamic_component_code(dynamic_component)
+                    },
                 });
 
                 quote! {
@@ -518,6 +547,38 @@ fn generate_element_code(element: &Element) -> proc_macro2::TokenStream {
     }
 }
 
+/// Attributes that attach event handlers to a widget element
+const EVENT_HANDLER_ATTRIBUTES: [&str; 2] = ["on_click", "on_key"];
+
+// Helper function to wrap a widget with event handler attributes in an Interactive widget
+// The wrapper captures the widget's render area, so it can bounds-check clicks itself
+fn generate_interactive_code(element: &Element) -> Option<proc_macro2::TokenStream> {
+    let (handlers, attributes): (Vec<_>, Vec<_>) = element
+        .attributes
+        .iter()
+        .cloned()
+        .partition(|attr| EVENT_HANDLER_ATTRIBUTES.contains(&attr.key.to_string().as_str()));
+
+    if handlers.is_empty() {
+        return None;
+    }
+
+    let widget_code = generate_element_code(&Element {
+        attributes,
+        ..element.clone()
+    });
+    let handler_methods = handlers.iter().map(|handler| {
+        let key = &handler.key;
+        let value = &handler.value;
+        quote! { .#key(#value) }
+    });
+
+    Some(quote! {
+        ::reratui::hooks::event::Interactive::new(Element::widget(#widget_code))
+            #(#handler_methods)*
+    })
+}
+
 // Helper function to generate code for any node type (returns AnyWidget)
 fn generate_node_code(node: &Node) -> proc_macro2::TokenStream {
     match node {
@@ -601,13 +662,10 @@ fn generate_node_code(node: &Node) -> proc_macro2::TokenStream {
                     } else if loop_results.len() == 1 {
                         loop_results.into_iter().next().unwrap()
                     } else {
-                        // Multiple elements - create a fragment-like container
+                        // Multiple elements - stack them in a fragment
                         AnyWidget::from(
                             Element::fragment(
-                                loop_results.into_iter().map(|widget| match widget {
-                                    AnyWidget::VNode(vnode) => vnode,
-                                    _ => Element::text("".to_string()),
-                                }).collect()
+                                loop_results.into_iter().map(Element::from).collect()
                             )
                         )
                     }
@@ -620,6 +678,12 @@ fn generate_node_code(node: &Node) -> proc_macro2::TokenStream {
                 AnyWidget::from(#fragment_code)
             }
         }
+        Node::DynamicComponent(dynamic_component) => {
+            let component_code = generate_dynamic_component_code(dynamic_component);
+            quote! {
+                AnyWidget::from(#component_code)
+            }
+        }
     }
 }
 
@@ -660,7 +724,7 @@ fn generate_component_code(element: &Element) -> proc_macro2::TokenStream {
                 #(#children),*
             ].into_iter().map(|widget| match widget {
                 AnyWidget::VNode(vnode) => vnode,
-                _ => Element::text("".to_string()),
+                widget => Element::widget(widget),
             }).collect();
 
             if !children.is_empty() {
@@ -738,7 +802,8 @@ fn generate_conditional_code(conditional: &ConditionalNode) -> proc_macro2::Toke
         ConditionalNode::Match { expr, arms } => {
             let match_arms = arms.iter().map(|arm| {
                 let pattern = &arm.pattern;
-                let body_code = generate_node_code(&arm.body);
+                let element_code = generate_match_arm_code(&arm.body);
+                let body_code = quote! { AnyWidget::from(#element_code) };
 
                 if let Some(guard) = &arm.guard {
                     quote! {
@@ -820,6 +885,88 @@ fn generate_fragment_code(fragment: &FragmentNode) -> proc_macro2::TokenStream {
     }
 }
 
+// Helper function to generate the Element produced by a match arm
+// Expression arms such as `rsx! { ... }` must evaluate to an Element; the binding is
+// spanned to the arm so a mismatch is reported there instead of inside the macro output
+fn generate_match_arm_code(body: &Node) -> proc_macro2::TokenStream {
+    match body {
+        Node::Expression(expr)
+            if !matches!(
+                expr,
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(_),
+                    ..
+                })
+            ) =>
+        {
+            quote::quote_spanned! {expr.span()=>
+                {
+                    let element: Element = #expr;
+                    element
+                }
+            }
+        }
+        _ => generate_node_vnode_code(body),
+    }
+}
+
+// Helper function to reject fragments where children cannot be stacked
+// Lists and tabs take items or titles rather than widgets, so there is no area to split
+fn fragment_without_layout_error(
+    fragment: &FragmentNode,
+    parent: &str,
+) -> proc_macro2::TokenStream {
+    syn::Error::new(
+        fragment.span,
+        format!(
+            "Fragments cannot be used inside `<{}>`: it has no layout to stack the fragment's children in. \
+             Pass the children directly instead",
+            parent
+        ),
+    )
+    .to_compile_error()
+}
+
+// Helper function to generate code for a dynamic component
+// Attributes become builder calls on the value, mirroring how props are set on generated
+// props structs; the Element::component bound is spanned to the expression
+fn generate_dynamic_component_code(
+    dynamic_component: &DynamicComponentNode,
+) -> proc_macro2::TokenStream {
+    let component = &dynamic_component.component;
+    let props_methods = dynamic_component.attributes.iter().map(|prop| {
+        let key = &prop.key;
+        let value = &prop.value;
+        quote! { .#key(#value) }
+    });
+
+    let component_code = quote::quote_spanned! {component.span()=>
+        Element::component(component)
+    };
+
+    quote! {
+        {
+            let component = (#component) #(#props_methods)*;
+            #component_code
+        }
+    }
+}
+
+// Helper function to reject dynamic components where no widget can be rendered
+fn dynamic_component_without_layout_error(
+    dynamic_component: &DynamicComponentNode,
+    parent: &str,
+) -> proc_macro2::TokenStream {
+    syn::Error::new(
+        dynamic_component.span,
+        format!(
+            "Dynamic components cannot be used inside `<{}>`: it only accepts text content",
+            parent
+        ),
+    )
+    .to_compile_error()
+}
+
 // Helper function to collect text content from multiple nodes
 fn collect_text_content(nodes: &[Node]) -> proc_macro2::TokenStream {
     let expressions: Vec<_> = nodes
@@ -882,6 +1029,7 @@ fn generate_paragraph_code(element: &Element, name: &syn::Path) -> proc_macro2::
                 || matches!(child, Node::Conditional(_))
                 || matches!(child, Node::Fragment(_))
                 || matches!(child, Node::ForLoop(_))
+                || matches!(child, Node::DynamicComponent(_))
         });
 
         if has_complex_children {
@@ -1040,6 +1188,9 @@ fn generate_lines_from_node(node: &Node) -> proc_macro2::TokenStream {
                 }
             }
         }
+        Node::DynamicComponent(dynamic_component) => {
+            dynamic_component_without_layout_error(dynamic_component, "Paragraph")
+        }
         Node::ForLoop(for_loop) => {
             // Handle for-loops that generate lines
             let pattern = &for_loop.pattern;
@@ -35,6 +35,7 @@ pub trait AstVisitor {
     fn visit_comment(&mut self, comment: &CommentNode) -> syn::Result<()>;
     fn visit_for_loop(&mut self, for_loop: &ForLoopNode) -> syn::Result<()>;
     fn visit_fragment(&mut self, fragment: &FragmentNode) -> syn::Result<()>;
+    fn visit_dynamic_component(&mut self, component: &DynamicComponentNode) -> syn::Result<()>;
 }
 
 /// Represents an attribute in an XML-like element (key=value)
@@ -102,6 +103,7 @@ pub enum Node {
     Comment(CommentNode),
     ForLoop(ForLoopNode),
     Fragment(FragmentNode),
+    DynamicComponent(DynamicComponentNode),
 }
 
 impl AstNode for Node {
@@ -113,6 +115,7 @@ impl AstNode for Node {
             Node::Comment(c) => c.span(),
             Node::ForLoop(f) => f.span(),
             Node::Fragment(f) => f.span(),
+            Node::DynamicComponent(d) => d.span(),
         }
     }
 
@@ -124,6 +127,7 @@ impl AstNode for Node {
             Node::Comment(c) => c.accept(visitor),
             Node::ForLoop(f) => f.accept(visitor),
             Node::Fragment(f) => f.accept(visitor),
+            Node::DynamicComponent(d) => d.accept(visitor),
         }
     }
 
@@ -135,6 +139,7 @@ impl AstNode for Node {
             Node::Comment(c) => c.validate(),
             Node::ForLoop(f) => f.validate(),
             Node::Fragment(f) => f.validate(),
+            Node::DynamicComponent(d) => d.validate(),
         }
     }
 }
@@ -264,6 +269,43 @@ impl AstNode for FragmentNode {
     }
 }
 
+/// Represents a component chosen at runtime, written `<{expr} prop={value} />`
+/// Single Responsibility: Handles dynamic component structure and validation
+#[derive(Debug, Clone)]
+pub struct DynamicComponentNode {
+    /// Expression evaluating to a value that implements `Component`
+    pub component: Expr,
+    /// Attributes applied as builder method calls on the component value
+    pub attributes: Vec<Prop>,
+    /// Span information for error reporting
+    pub span: proc_macro2::Span,
+}
+
+impl AstNode for DynamicComponentNode {
+    fn span(&self) -> proc_macro2::Span {
+        self.span
+    }
+
+    fn accept<V: AstVisitor>(&self, visitor: &mut V) -> syn::Result<()> {
+        visitor.visit_dynamic_component(self)?;
+
+        // Visit all attributes
+        for attr in &self.attributes {
+            attr.accept(visitor)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> syn::Result<()> {
+        // Validate all attributes
+        for attr in &self.attributes {
+            attr.validate()?;
+        }
+        Ok(())
+    }
+}
+
 /// Represents different types of conditional expressions in RSX
 /// Open/Closed Principle: Easy to add new conditional types
 #[derive(Debug, Clone)]
@@ -481,6 +523,25 @@ impl FragmentFactory {
     }
 }
 
+/// Factory for creating dynamic component nodes
+pub struct DynamicComponentFactory;
+
+impl DynamicComponentFactory {
+    pub fn create(
+        component: Expr,
+        attributes: Vec<Prop>,
+        span: proc_macro2::Span,
+    ) -> syn::Result<DynamicComponentNode> {
+        let dynamic_component = DynamicComponentNode {
+            component,
+            attributes,
+            span,
+        };
+        dynamic_component.validate()?;
+        Ok(dynamic_component)
+    }
+}
+
 /// Factory for creating conditional nodes
 pub struct ConditionalFactory;
 
@@ -432,6 +432,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fragment_shorthand_with_widgets() {
+        // Test that the shorthand groups sibling widgets and passes validation
+        let rsx = quote! {
+            <>
+                <Paragraph>{"Header"}</Paragraph>
+                <Block title="Body">
+                    <>
+                        <Paragraph>{"First"}</Paragraph>
+                        <Paragraph>{"Second"}</Paragraph>
+                    </>
+                </Block>
+            </>
+        };
+        let result = RsxMainParser::parse_react_like_tokens(rsx);
+        assert!(
+            result.is_ok(),
+            "Fragment shorthand should parse and validate"
+        );
+
+        let Ok(Node::Fragment(fragment)) = result else {
+            panic!("Should parse as a fragment node");
+        };
+        assert_eq!(fragment.children.len(), 2);
+        let Node::Element(block) = &fragment.children[1] else {
+            panic!("Second child should be an element");
+        };
+        assert!(
+            matches!(&block.children[..], [Node::Fragment(inner)] if inner.children.len() == 2)
+        );
+    }
+
     #[test]
     fn test_for_loop_with_preparation_statements() {
         // Test that for-loops can handle preparation statements before JSX elements
@@ -492,4 +524,113 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_match_with_rsx_arms() {
+        // Test that match arms can be nested rsx! invocations, as in the data fetcher's DataCard
+        let rsx = quote! {
+            <Block title="Data">
+                {match props.future_handle.state() {
+                    FutureState::Pending => rsx! { <LoadingSpinner /> },
+                    FutureState::Resolved(data) if data.is_empty() => rsx! {
+                        <Paragraph>{"Nothing yet"}</Paragraph>
+                    },
+                    _ => rsx! { <Paragraph>{"Error"}</Paragraph> },
+                }}
+            </Block>
+        };
+        let result = parse2::<Node>(rsx);
+        assert!(
+            result.is_ok(),
+            "Match with rsx! arms should parse successfully"
+        );
+
+        let Ok(Node::Element(block)) = result else {
+            panic!("Should parse as an element node");
+        };
+        let [Node::Conditional(ConditionalNode::Match { arms, .. })] = &block.children[..] else {
+            panic!("Block child should be a match");
+        };
+        assert_eq!(arms.len(), 3);
+        assert!(arms[1].guard.is_some(), "Guard should be kept");
+        assert!(
+            arms.iter()
+                .all(|arm| matches!(arm.body.as_ref(), Node::Expression(syn::Expr::Macro(_)))),
+            "rsx! arms should be kept as expressions"
+        );
+    }
+
+    #[test]
+    fn test_match_with_element_and_fragment_arms() {
+        // Test that arms can be bare elements, parenthesized elements and fragments
+        let rsx = quote! {
+            {match selected {
+                0 => <Paragraph>{"Overview"}</Paragraph>,
+                1 => (<Paragraph>{"Details"}</Paragraph>),
+                _ => <>
+                    <Paragraph>{"Help"}</Paragraph>
+                    <Paragraph>{"More help"}</Paragraph>
+                </>,
+            }}
+        };
+        let result = parse2::<Node>(rsx);
+        assert!(
+            result.is_ok(),
+            "Match with element arms should parse successfully"
+        );
+
+        let Ok(Node::Conditional(ConditionalNode::Match { arms, .. })) = result else {
+            panic!("Should parse as a match node");
+        };
+        assert!(matches!(arms[0].body.as_ref(), Node::Element(_)));
+        assert!(matches!(arms[1].body.as_ref(), Node::Element(_)));
+        assert!(matches!(arms[2].body.as_ref(), Node::Fragment(f) if f.children.len() == 2));
+    }
+
+    #[test]
+    fn test_dynamic_component() {
+        // Test that a braced expression in tag position parses as a dynamic component
+        let rsx = quote! {
+            <Layout direction={Direction::Vertical}>
+                <{current_screen.clone()} title="Home" user_id={id} />
+            </Layout>
+        };
+        let result = RsxMainParser::parse_react_like_tokens(rsx);
+        assert!(
+            result.is_ok(),
+            "Dynamic components should parse and validate"
+        );
+
+        let Ok(Node::Element(layout)) = result else {
+            panic!("Should parse as an element node");
+        };
+        let [Node::DynamicComponent(dynamic_component)] = &layout.children[..] else {
+            panic!("Layout child should be a dynamic component");
+        };
+        assert!(matches!(
+            dynamic_component.component,
+            syn::Expr::MethodCall(_)
+        ));
+        let keys: Vec<_> = dynamic_component
+            .attributes
+            .iter()
+            .map(|attr| attr.key.to_string())
+            .collect();
+        assert_eq!(keys, ["title", "user_id"]);
+    }
+
+    #[test]
+    fn test_dynamic_component_must_self_close() {
+        // Test that dynamic components reject children
+        let rsx = quote! {
+            <{screen}>
+                <Paragraph>{"Child"}</Paragraph>
+            </{screen}>
+        };
+        let result = parse2::<Node>(rsx);
+        let Err(err) = result else {
+            panic!("Dynamic components with children should fail to parse");
+        };
+        assert!(err.to_string().contains("self-closing"));
+    }
 }
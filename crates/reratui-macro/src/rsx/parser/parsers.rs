@@ -531,6 +531,63 @@ impl Default for FragmentParser {
     }
 }
 
+/// Parser for dynamic component syntax (`<{expr} prop={value} />`)
+pub struct DynamicComponentParser {
+    attribute_parser: AttributeParser,
+}
+
+impl DynamicComponentParser {
+    pub fn new() -> Self {
+        Self {
+            attribute_parser: AttributeParser::new(),
+        }
+    }
+
+    /// Check if the input starts with a braced expression in tag position
+    pub fn can_parse(&self, input: ParseStream) -> bool {
+        input.peek(Token![<]) && input.peek2(Brace)
+    }
+}
+
+impl RsxParser<DynamicComponentNode> for DynamicComponentParser {
+    fn parse(&self, input: ParseStream) -> Result<DynamicComponentNode> {
+        input.parse::<Token![<]>()?;
+
+        let content;
+        let braces = syn::braced!(content in input);
+        let component: Expr = content.parse()?;
+        let span = braces.span.join();
+
+        let attributes = self.attribute_parser.parse(input)?;
+
+        // The closing tag would have to repeat the expression, so only self-closing is allowed
+        if input.peek(Token![>]) {
+            return Err(crate::rsx::error::RsxError::SyntaxError {
+                message: "Dynamic components must be self-closing".to_string(),
+                span: input.span(),
+                suggestion: Some(
+                    "Use `<{component} />` and pass children through a prop".to_string(),
+                ),
+            }
+            .to_syn_error());
+        }
+        input.parse::<Token![/]>()?;
+        input.parse::<Token![>]>()?;
+
+        DynamicComponentFactory::create(component, attributes, span)
+    }
+
+    fn can_parse(&self, input: ParseStream) -> bool {
+        self.can_parse(input)
+    }
+}
+
+impl Default for DynamicComponentParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Parser for conditional expressions
 pub struct ConditionalParser {
     logical_and_analyzer: LogicalAndAnalyzer,
@@ -553,6 +610,14 @@ impl ConditionalParser {
             return Ok(Node::Fragment(fragment_parser.parse(input)?));
         }
 
+        // Try to parse as a dynamic component
+        let dynamic_component_parser = DynamicComponentParser::new();
+        if dynamic_component_parser.can_parse(input) {
+            return Ok(Node::DynamicComponent(
+                dynamic_component_parser.parse(input)?,
+            ));
+        }
+
         // Try to parse as an element
         if input.peek(Token![<]) {
             return Ok(Node::Element(input.parse::<Element>()?));
@@ -708,6 +773,12 @@ impl ConditionalParser {
                 } else {
                     Box::new(Node::Expression(paren_content.parse::<Expr>()?))
                 }
+            } else if arms_content.peek(Token![<]) && arms_content.peek2(Token![>]) {
+                Box::new(Node::Fragment(arms_content.parse::<FragmentNode>()?))
+            } else if arms_content.peek(Token![<]) && arms_content.peek2(Brace) {
+                Box::new(Node::DynamicComponent(
+                    DynamicComponentParser::new().parse(&arms_content)?,
+                ))
             } else if arms_content.peek(Token![<]) {
                 Box::new(Node::Element(arms_content.parse::<Element>()?))
             } else {
@@ -861,7 +932,16 @@ impl ForLoopParser {
 
         // Parse statements until we find a JSX element or reach the end
         while !input.is_empty() {
-            // Try to parse as a JSX element first
+            // Try to parse as a dynamic component first, since it also starts with `<`
+            let dynamic_component_parser = DynamicComponentParser::new();
+            if dynamic_component_parser.can_parse(input) {
+                body_node = Some(Node::DynamicComponent(
+                    dynamic_component_parser.parse(input)?,
+                ));
+                break;
+            }
+
+            // Try to parse as a JSX element
             if self.element_parser.can_parse(input) {
                 body_node = Some(Node::Element(self.element_parser.parse(input)?));
                 break;
@@ -960,6 +1040,7 @@ pub struct NodeParser {
     conditional_parser: ConditionalParser,
     for_loop_parser: Option<ForLoopParser>,
     fragment_parser: FragmentParser,
+    dynamic_component_parser: DynamicComponentParser,
     comment_analyzer: CommentAnalyzer,
     conditional_analyzer: ConditionalAnalyzer,
     for_loop_analyzer: ForLoopAnalyzer,
@@ -972,6 +1053,7 @@ impl NodeParser {
             conditional_parser: ConditionalParser::new(),
             for_loop_parser: Some(ParserFactory::create_for_loop_parser()),
             fragment_parser: FragmentParser::new(),
+            dynamic_component_parser: DynamicComponentParser::new(),
             comment_analyzer: CommentAnalyzer::new(),
             conditional_analyzer: ConditionalAnalyzer::new(),
             for_loop_analyzer: ForLoopAnalyzer::new(),
@@ -992,6 +1074,13 @@ impl RsxParser<Node> for NodeParser {
             return Ok(Node::Fragment(self.fragment_parser.parse(input)?));
         }
 
+        // Try to parse as a dynamic component
+        if self.dynamic_component_parser.can_parse(input) {
+            return Ok(Node::DynamicComponent(
+                self.dynamic_component_parser.parse(input)?,
+            ));
+        }
+
         // Try to parse as for-loop
         if let Some(ref for_loop_parser) = self.for_loop_parser
             && for_loop_parser.can_parse(input)
@@ -637,6 +637,14 @@ impl AstVisitor for ValidationVisitor {
         // No specific validation for fragments yet - they are always valid
         Ok(())
     }
+
+    fn visit_dynamic_component(
+        &mut self,
+        _component: &crate::rsx::parser::ast::DynamicComponentNode,
+    ) -> syn::Result<()> {
+        // The component expression is type-checked by the compiler
+        Ok(())
+    }
 }
 
 impl Default for ValidationVisitor {
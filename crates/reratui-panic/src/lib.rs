@@ -4,6 +4,10 @@ use std::panic;
 use std::sync::Once;
 use tokio::task::JoinHandle;
 
+mod task_registry;
+
+pub use task_registry::{TaskPanic, TaskRegistry, spawn_registered};
+
 #[cfg(debug_assertions)]
 use better_panic::{Settings, Verbosity};
 
@@ -24,8 +28,9 @@ static INIT: Once = Once::new();
 ///
 /// # Note
 /// This function does not set up any logging. If you want to log panic information,
-/// set up your own tracing subscriber before calling this function.
-/// See the examples directory for how to integrate logging.
+/// set up your own tracing subscriber before calling this function, or use
+/// `reratui::logging::init_logging` to keep recent logs in memory for an in-app
+/// `LogOverlay`.
 pub fn setup_panic_handler() {
     INIT.call_once(|| {
         #[cfg(debug_assertions)]
@@ -48,13 +53,32 @@ pub fn setup_panic_handler() {
         // to ensure terminal is properly restored
         let original_hook = panic::take_hook();
         panic::set_hook(Box::new(move |panic_info| {
-            use crossterm::event::DisableMouseCapture;
+            // Panics in registered tasks are surfaced by the render loop,
+            // which restores the terminal and returns them as errors along
+            // with the location kept here
+            if task_registry::capture_registered_panic(panic_info) {
+                return;
+            }
+
+            use crossterm::event::{
+                DisableBracketedPaste, DisableFocusChange, DisableMouseCapture,
+                PopKeyboardEnhancementFlags,
+            };
             use crossterm::execute;
             use crossterm::terminal::{LeaveAlternateScreen, disable_raw_mode};
 
             // Restore terminal before calling the panic formatter
             let _ = disable_raw_mode();
-            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+            // Popped once, on the screen the flags were pushed on: they are a
+            // stack, and popping again could drop flags the shell pushed
+            let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+            let _ = execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableFocusChange,
+                DisableBracketedPaste
+            );
             let _ = io::stdout().flush();
 
             // Call the original hook (better_panic/human_panic)
@@ -63,7 +87,13 @@ pub fn setup_panic_handler() {
             // Force terminal back to normal mode after panic output
             // This ensures the terminal is in a state where text can be selected
             let _ = disable_raw_mode();
-            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+            let _ = execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableFocusChange,
+                DisableBracketedPaste
+            );
             let _ = io::stderr().flush();
             let _ = io::stdout().flush();
 
@@ -76,22 +106,15 @@ pub fn setup_panic_handler() {
 
 /// Spawns a new asynchronous task and catches any panics that occur within it.
 ///
-/// If a panic occurs, it will be caught by the custom panic hook.
+/// The task is registered with the global [`TaskRegistry`], so a panic is
+/// recorded for the render loop to report and the returned handle resolves
+/// to a `JoinError`.
 pub fn spawn_catch_panic<F>(future: F) -> JoinHandle<F::Output>
 where
     F: std::future::Future + Send + 'static,
     F::Output: Send + 'static,
 {
-    tokio::spawn(async move {
-        let result = panic::catch_unwind(std::panic::AssertUnwindSafe(|| future));
-        match result {
-            Ok(output_future) => output_future.await,
-            Err(e) => {
-                // Re-panic on the main thread to trigger the custom panic hook
-                panic::resume_unwind(e);
-            }
-        }
-    })
+    spawn_registered(future)
 }
 
 /// Executes a closure and catches any panics that occur, returning a Result.
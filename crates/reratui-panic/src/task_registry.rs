@@ -0,0 +1,283 @@
+//! Registry for framework-spawned async tasks
+//!
+//! Tasks spawned through a [`TaskRegistry`] have their panics recorded instead
+//! of terminating the process from the panic hook. The render loop polls the
+//! registry each frame and, when a task has panicked, restores the terminal
+//! and returns the panic message as an error.
+//!
+//! With the panic hook from [`setup_panic_handler`] installed, a recorded
+//! panic also carries where it happened and, in debug builds, a backtrace.
+//!
+//! [`setup_panic_handler`]: crate::setup_panic_handler
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::task::{Context, Poll};
+use tokio::task::JoinHandle;
+
+thread_local! {
+    /// Depth of registered task polls on this thread
+    static REGISTERED_TASK_DEPTH: Cell<usize> = const { Cell::new(0) };
+
+    /// Location and backtrace of the panic unwinding a registered task on this thread
+    static PANIC_DETAILS: RefCell<Option<PanicDetails>> = const { RefCell::new(None) };
+}
+
+static GLOBAL_REGISTRY: LazyLock<TaskRegistry> = LazyLock::new(TaskRegistry::new);
+
+/// Whether the current thread is polling a task spawned through a registry
+///
+/// The panic hook uses this to leave such panics to the registry.
+pub(crate) fn in_registered_task() -> bool {
+    REGISTERED_TASK_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// What the panic hook saw of a panic, beyond its payload
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct PanicDetails {
+    location: Option<String>,
+    backtrace: Option<String>,
+}
+
+/// Keeps the location of a panic in a registered task, and a backtrace in
+/// debug builds, for the task's [`TaskPanic`]
+///
+/// Returns whether the panic happened in a registered task. Called by the
+/// panic hook, which leaves such panics to the registry.
+pub(crate) fn capture_registered_panic(info: &PanicHookInfo<'_>) -> bool {
+    if !in_registered_task() {
+        return false;
+    }
+
+    let details = PanicDetails {
+        location: info.location().map(ToString::to_string),
+        backtrace: cfg!(debug_assertions)
+            .then(|| std::backtrace::Backtrace::force_capture().to_string()),
+    };
+    PANIC_DETAILS.with(|slot| *slot.borrow_mut() = Some(details));
+    true
+}
+
+/// A panic raised inside a registered task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskPanic {
+    message: String,
+    details: PanicDetails,
+}
+
+impl TaskPanic {
+    fn from_payload(payload: &(dyn Any + Send)) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "<non-string panic payload>".to_string()
+        };
+        let details = PANIC_DETAILS
+            .with(|slot| slot.borrow_mut().take())
+            .unwrap_or_default();
+
+        Self { message, details }
+    }
+
+    /// The panic message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Where the panic happened, as `file:line:column`
+    ///
+    /// Only known when the panic hook from
+    /// [`setup_panic_handler`](crate::setup_panic_handler) is installed.
+    pub fn location(&self) -> Option<&str> {
+        self.details.location.as_deref()
+    }
+
+    /// The backtrace captured when the task panicked
+    ///
+    /// Only captured in debug builds with the panic hook installed.
+    pub fn backtrace(&self) -> Option<&str> {
+        self.details.backtrace.as_deref()
+    }
+}
+
+impl fmt::Display for TaskPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location() {
+            Some(location) => write!(f, "Async task panicked at {location}: {}", self.message)?,
+            None => write!(f, "Async task panicked: {}", self.message)?,
+        }
+        if let Some(backtrace) = self.backtrace() {
+            write!(f, "\n\nBacktrace:\n{backtrace}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TaskPanic {}
+
+/// Tracks panics from spawned tasks so the render loop can surface them
+///
+/// Only the first panic is kept until it is taken; later panics are usually
+/// consequences of the first.
+#[derive(Clone, Default)]
+pub struct TaskRegistry {
+    panic: Arc<Mutex<Option<TaskPanic>>>,
+}
+
+impl TaskRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry used by framework hooks and polled by the render loop
+    pub fn global() -> &'static TaskRegistry {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Spawns a task whose panics are recorded in this registry
+    ///
+    /// The returned handle behaves like one from `tokio::spawn`: awaiting it
+    /// yields a `JoinError` if the task panicked.
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(RegisteredTask {
+            future: Box::pin(future),
+            registry: self.clone(),
+        })
+    }
+
+    /// Takes the recorded panic, if a task has panicked since the last call
+    pub fn take_panic(&self) -> Option<TaskPanic> {
+        self.panic.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+
+    fn record(&self, task_panic: TaskPanic) {
+        let mut panic = self.panic.lock().unwrap_or_else(|e| e.into_inner());
+        if panic.is_none() {
+            *panic = Some(task_panic);
+        }
+    }
+}
+
+/// Spawns a task registered with the global [`TaskRegistry`]
+pub fn spawn_registered<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    TaskRegistry::global().spawn(future)
+}
+
+/// Future wrapper that records panics raised while polling the inner future
+struct RegisteredTask<F: Future> {
+    future: Pin<Box<F>>,
+    registry: TaskRegistry,
+}
+
+impl<F: Future> Future for RegisteredTask<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        REGISTERED_TASK_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| self.future.as_mut().poll(cx)));
+        REGISTERED_TASK_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+        match result {
+            Ok(poll) => {
+                // Drop details of a panic the task caught itself
+                PANIC_DETAILS.with(|slot| slot.borrow_mut().take());
+                poll
+            }
+            Err(payload) => {
+                self.registry.record(TaskPanic::from_payload(&*payload));
+                // Keep tokio's JoinError semantics for whoever awaits the task
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_registered_task_output() {
+        let registry = TaskRegistry::new();
+
+        let result = registry.spawn(async { 42 }).await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(registry.take_panic(), None);
+    }
+
+    #[tokio::test]
+    async fn test_registered_task_panic_is_recorded() {
+        let registry = TaskRegistry::new();
+
+        let result = registry
+            .spawn(async {
+                tokio::task::yield_now().await;
+                panic!("fetch failed: {}", 500);
+            })
+            .await;
+
+        assert!(result.unwrap_err().is_panic());
+        let task_panic = registry.take_panic().expect("panic should be recorded");
+        assert_eq!(task_panic.message(), "fetch failed: 500");
+        assert_eq!(registry.take_panic(), None, "take_panic clears the panic");
+    }
+
+    #[tokio::test]
+    async fn test_registry_keeps_first_panic() {
+        let registry = TaskRegistry::new();
+
+        let _ = registry.spawn(async { panic!("first") }).await;
+        let _ = registry.spawn(async { panic!("second") }).await;
+
+        assert_eq!(registry.take_panic().unwrap().message(), "first");
+    }
+
+    #[tokio::test]
+    async fn test_recorded_panic_keeps_location() {
+        // Capture like the panic hook does, leaving other panics to the default hook
+        static HOOK: std::sync::Once = std::sync::Once::new();
+        HOOK.call_once(|| {
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !capture_registered_panic(info) {
+                    default_hook(info);
+                }
+            }));
+        });
+
+        let registry = TaskRegistry::new();
+        let line = line!() + 1;
+        let _ = registry.spawn(async { panic!("located") }).await;
+
+        let task_panic = registry.take_panic().unwrap();
+        let location = format!("{}:{}:", file!(), line);
+        assert!(
+            task_panic.location().unwrap().starts_with(&location),
+            "{:?}",
+            task_panic.location()
+        );
+        assert!(
+            task_panic
+                .to_string()
+                .starts_with(&format!("Async task panicked at {location}"))
+        );
+        assert_eq!(task_panic.backtrace().is_some(), cfg!(debug_assertions));
+    }
+}
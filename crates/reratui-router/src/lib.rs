@@ -1 +1,46 @@
+//! Reratui Router - Screen routing and navigation
+//!
+//! This crate provides the `Router` component, which renders the screen
+//! matching the current path, and the `use_navigate` and `use_route` hooks for
+//! screens rendered inside it. Children of `<Router>` are a layout shared by
+//! every screen, which shows the screen where it renders `<Outlet />`. Like
+//! other components, `<Router>` in `rsx!` needs `RouterComponent` and
+//! `RouterProps` in scope.
+//!
+//! ```ignore
+//! use reratui::prelude::*;
+//! use reratui_router::{RouterComponent, RouterProps, Routes, use_navigate, use_route};
+//!
+//! #[component]
+//! fn UserDetails() -> Element {
+//!     let route = use_route();
+//!     let id = route.param("id").unwrap_or_default().to_string();
+//!
+//!     let navigate = use_navigate();
+//!     use_keyboard_press(move |key| {
+//!         if key.code == KeyCode::Esc {
+//!             navigate.back();
+//!         }
+//!     });
+//!
+//!     rsx! { <Paragraph>{format!("User {id}")}</Paragraph> }
+//! }
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     let routes = Routes::new()
+//!         .route("/", || rsx! { <Home /> })
+//!         .route("/users/:id", || rsx! { <UserDetails /> });
+//!
+//!     rsx! { <Router routes={routes} /> }
+//! }
+//! ```
 
+pub mod path;
+pub mod router;
+
+pub use path::{Params, RoutePattern};
+pub use router::{
+    CurrentRoute, Navigator, Outlet, OutletComponent, OutletProps, Router, RouterComponent,
+    RouterProps, Routes, use_navigate, use_route,
+};
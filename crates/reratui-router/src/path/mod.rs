@@ -0,0 +1,103 @@
+//! Route path patterns and matching
+//!
+//! A pattern is a `/`-separated list of segments. Literal segments must match
+//! exactly, `:name` segments capture a single path segment as a parameter, and
+//! a trailing `*` matches any remaining segments.
+
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Parameters captured from `:name` segments of a matched route
+pub type Params = HashMap<String, String>;
+
+/// A single segment of a route pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// A literal segment that must match exactly
+    Static(String),
+    /// A `:name` segment capturing one path segment
+    Param(String),
+    /// A trailing `*` matching the rest of the path
+    Wildcard,
+}
+
+/// A parsed route pattern such as `/users/:id`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutePattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl RoutePattern {
+    /// Parses a route pattern
+    ///
+    /// Leading, trailing and repeated slashes are ignored, so `/users/` and
+    /// `users` are the same pattern.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let segments = split_path(&pattern)
+            .map(|segment| {
+                if segment == "*" {
+                    Segment::Wildcard
+                } else if let Some(name) = segment.strip_prefix(':') {
+                    Segment::Param(name.to_string())
+                } else {
+                    Segment::Static(segment.to_string())
+                }
+            })
+            .collect();
+
+        Self { pattern, segments }
+    }
+
+    /// Returns the pattern as it was written
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Matches a path against this pattern, returning the captured parameters
+    pub fn matches(&self, path: &str) -> Option<Params> {
+        let mut params = Params::new();
+        let mut parts = split_path(path);
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Wildcard => return Some(params),
+                Segment::Static(expected) => {
+                    if parts.next()? != expected {
+                        return None;
+                    }
+                }
+                Segment::Param(name) => {
+                    params.insert(name.clone(), parts.next()?.to_string());
+                }
+            }
+        }
+
+        // Every path segment must be consumed unless the pattern ended in `*`
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(params)
+    }
+}
+
+impl From<&str> for RoutePattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for RoutePattern {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+/// Splits a path into its non-empty segments
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
@@ -0,0 +1,41 @@
+//! Tests for route pattern matching
+
+use super::*;
+
+#[test]
+fn test_static_pattern() {
+    let pattern = RoutePattern::new("/settings/profile");
+
+    assert!(pattern.matches("/settings/profile").is_some());
+    assert!(pattern.matches("/settings/profile/").is_some());
+    assert!(pattern.matches("/settings").is_none());
+    assert!(pattern.matches("/settings/profile/edit").is_none());
+}
+
+#[test]
+fn test_root_pattern() {
+    let pattern = RoutePattern::new("/");
+
+    assert!(pattern.matches("/").is_some());
+    assert!(pattern.matches("").is_some());
+    assert!(pattern.matches("/users").is_none());
+}
+
+#[test]
+fn test_param_pattern() {
+    let pattern = RoutePattern::new("/users/:id/posts/:post_id");
+    let params = pattern.matches("/users/42/posts/7").unwrap();
+
+    assert_eq!(params.get("id").map(String::as_str), Some("42"));
+    assert_eq!(params.get("post_id").map(String::as_str), Some("7"));
+    assert!(pattern.matches("/users/42/posts").is_none());
+}
+
+#[test]
+fn test_wildcard_pattern() {
+    let pattern = RoutePattern::new("/docs/*");
+
+    assert!(pattern.matches("/docs").is_some());
+    assert!(pattern.matches("/docs/guide/routing").is_some());
+    assert!(pattern.matches("/blog").is_none());
+}
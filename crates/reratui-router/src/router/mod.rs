@@ -0,0 +1,378 @@
+//! The `Router` component and its navigation hooks
+//!
+//! `Router` renders the screen whose pattern matches the current path. Screens
+//! rendered inside it can read the matched route with [`use_route`] and change
+//! it with [`use_navigate`]. Navigation history is kept with
+//! [`use_history`], so `back` and `forward` walk the same undo/redo stacks.
+//!
+//! Children given to `Router` are a layout around the screen: they render in
+//! its place and show the matched screen wherever they render an [`Outlet`].
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use reratui_core::{Component, ComponentProps, Element};
+use reratui_hooks::context::{use_context, use_context_provider, with_context_scope};
+use reratui_hooks::history::{HistoryManager, use_history};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::path::{Params, RoutePattern};
+
+#[cfg(test)]
+mod tests;
+
+/// Default number of entries kept for `back` navigation
+pub const DEFAULT_MAX_HISTORY: usize = 100;
+
+/// A function producing the element for a route
+type Screen = Rc<dyn Fn() -> Element>;
+
+/// An ordered list of route definitions
+///
+/// Routes are tried in the order they were added and the first matching
+/// pattern wins. The fallback, if any, renders when no route matches.
+#[derive(Clone, Default)]
+pub struct Routes {
+    routes: Vec<(RoutePattern, Screen)>,
+    fallback: Option<Screen>,
+}
+
+impl Routes {
+    /// Creates an empty route list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route rendering `screen` when the path matches `pattern`
+    pub fn route<F>(mut self, pattern: impl Into<RoutePattern>, screen: F) -> Self
+    where
+        F: Fn() -> Element + 'static,
+    {
+        self.routes.push((pattern.into(), Rc::new(screen)));
+        self
+    }
+
+    /// Sets the screen rendered when no route matches
+    pub fn fallback<F>(mut self, screen: F) -> Self
+    where
+        F: Fn() -> Element + 'static,
+    {
+        self.fallback = Some(Rc::new(screen));
+        self
+    }
+
+    /// Finds the screen and route information for a path
+    fn resolve(&self, path: &str) -> (Option<Screen>, CurrentRoute) {
+        for (pattern, screen) in &self.routes {
+            if let Some(params) = pattern.matches(path) {
+                let route = CurrentRoute {
+                    path: path.to_string(),
+                    pattern: Some(pattern.as_str().to_string()),
+                    params,
+                };
+                return (Some(screen.clone()), route);
+            }
+        }
+
+        let route = CurrentRoute {
+            path: path.to_string(),
+            pattern: None,
+            params: Params::new(),
+        };
+        (self.fallback.clone(), route)
+    }
+}
+
+/// The route currently rendered by the nearest `Router`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentRoute {
+    path: String,
+    pattern: Option<String>,
+    params: Params,
+}
+
+impl CurrentRoute {
+    /// The current path, e.g. `/users/42`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The pattern that matched the path, or `None` if the fallback rendered
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Returns a parameter captured by a `:name` segment
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+
+    /// All parameters captured from the path
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+}
+
+/// A handle for changing the current route
+#[derive(Clone)]
+pub struct Navigator {
+    history: HistoryManager<String>,
+}
+
+impl Navigator {
+    /// The path the router is currently showing
+    pub fn current_path(&self) -> String {
+        self.history.current()
+    }
+
+    /// Navigates to `path`, adding an entry to the history
+    pub fn push(&self, path: impl Into<String>) {
+        self.history.push(path.into());
+    }
+
+    /// Navigates to `path`, replacing the current history entry
+    pub fn replace(&self, path: impl Into<String>) {
+        self.history.replace(path.into());
+    }
+
+    /// Returns to the previous path, if any
+    pub fn back(&self) {
+        self.history.undo();
+    }
+
+    /// Moves forward again after `back`, if possible
+    pub fn forward(&self) {
+        self.history.redo();
+    }
+
+    /// Checks if there is a previous path to go back to
+    pub fn can_go_back(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Checks if there is a path to go forward to
+    pub fn can_go_forward(&self) -> bool {
+        self.history.can_redo()
+    }
+}
+
+/// Routing state shared with the screens rendered by a `Router`
+#[derive(Clone)]
+struct RouterContext {
+    navigator: Navigator,
+    route: CurrentRoute,
+}
+
+thread_local! {
+    /// Screens matched by the routers whose layouts are rendering, innermost last
+    static OUTLET_SCREENS: RefCell<Vec<Option<Screen>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns a handle for navigating the nearest `Router`
+///
+/// # Panics
+///
+/// Panics if called outside a screen rendered by a `Router`.
+pub fn use_navigate() -> Navigator {
+    use_context::<RouterContext>().navigator
+}
+
+/// Returns the route rendered by the nearest `Router`
+///
+/// # Panics
+///
+/// Panics if called outside a screen rendered by a `Router`.
+pub fn use_route() -> CurrentRoute {
+    use_context::<RouterContext>().route
+}
+
+/// Props for the `Router` component
+#[derive(Clone)]
+pub struct RouterProps {
+    pub routes: Routes,
+    pub initial_path: String,
+    pub max_history: usize,
+    pub children: Vec<Element>,
+}
+
+impl Default for RouterProps {
+    fn default() -> Self {
+        Self {
+            routes: Routes::default(),
+            initial_path: "/".to_string(),
+            max_history: DEFAULT_MAX_HISTORY,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl RouterProps {
+    /// Sets the route definitions
+    pub fn routes(mut self, routes: impl Into<Routes>) -> Self {
+        self.routes = routes.into();
+        self
+    }
+
+    /// Sets the path shown on the first render
+    pub fn initial_path(mut self, initial_path: impl Into<String>) -> Self {
+        self.initial_path = initial_path.into();
+        self
+    }
+
+    /// Sets how many entries are kept for `back` navigation
+    pub fn max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history;
+        self
+    }
+
+    /// Sets the layout rendered around the matched screen
+    ///
+    /// The screen renders where the layout renders an [`Outlet`].
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl ComponentProps for RouterProps {
+    fn get_children(&self) -> Vec<Element> {
+        self.children.clone()
+    }
+
+    fn set_children(&mut self, children: Vec<Element>) {
+        self.children = children;
+    }
+}
+
+/// Renders the screen matching the current path
+///
+/// Without children the screen fills the router's area. With children, they
+/// render instead, stacked vertically, and the screen appears in their
+/// [`Outlet`].
+///
+/// ```ignore
+/// let routes = Routes::new()
+///     .route("/", || rsx! { <Home /> })
+///     .route("/users/:id", || rsx! { <UserDetails /> })
+///     .fallback(|| rsx! { <NotFound /> });
+///
+/// rsx! { <Router routes={routes} initial_path="/" /> }
+///
+/// // Or with a layout shared by every screen
+/// rsx! {
+///     <Router routes={routes}>
+///         <Layout direction={Direction::Vertical} constraints={[Constraint::Length(1), Constraint::Min(0)]}>
+///             <NavBar />
+///             <Outlet />
+///         </Layout>
+///     </Router>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct RouterComponent {
+    props: RouterProps,
+}
+
+impl RouterComponent {
+    pub fn new(props: RouterProps) -> Self {
+        Self { props }
+    }
+
+    pub fn with_children(mut self, children: Vec<Element>) -> Self {
+        self.props.set_children(children);
+        self
+    }
+}
+
+impl Component for RouterComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let history = use_history(self.props.initial_path.clone(), self.props.max_history);
+        let (screen, route) = self.props.routes.resolve(&history.current());
+
+        with_context_scope(|| {
+            use_context_provider(|| RouterContext {
+                navigator: Navigator { history },
+                route,
+            });
+
+            let children = &self.props.children;
+            if children.is_empty() {
+                if let Some(screen) = screen {
+                    screen().render(area, buffer);
+                }
+                return;
+            }
+
+            OUTLET_SCREENS.with(|screens| screens.borrow_mut().push(screen));
+            match children.len() {
+                1 => children[0].render(area, buffer),
+                _ => {
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints(vec![Constraint::Min(0); children.len()])
+                        .split(area);
+                    for (child, chunk) in children.iter().zip(chunks.iter()) {
+                        child.render(*chunk, buffer);
+                    }
+                }
+            }
+            OUTLET_SCREENS.with(|screens| screens.borrow_mut().pop());
+        });
+    }
+}
+
+/// The `Router` component, usable as `<Router routes={...} />` in `rsx!`
+pub type Router = RouterComponent;
+
+/// Props for the `Outlet` component
+#[derive(Clone, Default)]
+pub struct OutletProps;
+
+impl OutletProps {
+    /// Ignores children: an outlet always shows the matched screen
+    pub fn with_children(self, _children: Vec<Element>) -> Self {
+        self
+    }
+}
+
+impl ComponentProps for OutletProps {
+    fn get_children(&self) -> Vec<Element> {
+        Vec::new()
+    }
+
+    fn set_children(&mut self, _children: Vec<Element>) {}
+}
+
+/// Renders the screen matched by the nearest `Router` inside its layout
+///
+/// Nothing renders if no route matches and there is no fallback.
+///
+/// # Panics
+///
+/// Panics if rendered outside a `Router`.
+#[derive(Clone, Default)]
+pub struct OutletComponent;
+
+impl OutletComponent {
+    pub fn new(_props: OutletProps) -> Self {
+        Self
+    }
+}
+
+impl Component for OutletComponent {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let screen = OUTLET_SCREENS.with(|screens| {
+            screens
+                .borrow()
+                .last()
+                .cloned()
+                .expect("Outlet must be rendered inside a Router's children")
+        });
+        if let Some(screen) = screen {
+            screen().render(area, buffer);
+        }
+    }
+}
+
+/// The `Outlet` component, usable as `<Outlet />` inside a `Router` layout
+pub type Outlet = OutletComponent;
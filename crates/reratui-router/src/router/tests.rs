@@ -0,0 +1,190 @@
+//! Tests for the Router component and navigation hooks
+
+use super::*;
+use ratatui::widgets::{Paragraph, Widget};
+use reratui_hooks::context::clear_context_providers;
+use reratui_hooks::hook_context::{HookContext, clear_hook_context, set_hook_context};
+use std::cell::RefCell;
+
+/// Renders a component as one frame and returns the first line of output
+fn render_frame(context: &HookContext, component: &impl Component) -> String {
+    context.reset_hook_index();
+    clear_context_providers();
+
+    let area = Rect::new(0, 0, 20, 1);
+    let mut buffer = Buffer::empty(area);
+    component.render(area, &mut buffer);
+
+    buffer
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn with_router_test<F: FnOnce(&HookContext)>(test_fn: F) {
+    let context = Rc::new(HookContext::new());
+    set_hook_context(context.clone());
+    test_fn(&context);
+    clear_context_providers();
+    clear_hook_context();
+}
+
+thread_local! {
+    /// Router state seen by the last rendered screen
+    static SEEN: RefCell<Option<(Navigator, CurrentRoute)>> = const { RefCell::new(None) };
+}
+
+/// Records the router state, which is only available while a screen renders
+fn record_router_state() {
+    SEEN.with(|seen| *seen.borrow_mut() = Some((use_navigate(), use_route())));
+}
+
+fn last_navigator() -> Navigator {
+    SEEN.with(|seen| seen.borrow().clone().expect("no screen rendered").0)
+}
+
+fn last_route() -> CurrentRoute {
+    SEEN.with(|seen| seen.borrow().clone().expect("no screen rendered").1)
+}
+
+fn text_screen(text: &'static str) -> impl Fn() -> Element {
+    move || {
+        record_router_state();
+        Element::widget(Paragraph::new(text))
+    }
+}
+
+#[test]
+fn test_router_renders_matching_route() {
+    with_router_test(|context| {
+        let seen_id = Rc::new(RefCell::new(None));
+        let seen_id_screen = seen_id.clone();
+
+        let routes = Routes::new()
+            .route("/", text_screen("Home"))
+            .route("/users/:id", move || {
+                record_router_state();
+                let id = use_route().param("id").map(str::to_string);
+                *seen_id_screen.borrow_mut() = id.clone();
+                Element::widget(Paragraph::new(format!("User {}", id.unwrap())))
+            });
+        let router = RouterComponent::new(RouterProps::default().routes(routes));
+
+        assert_eq!(render_frame(context, &router), "Home");
+
+        last_navigator().push("/users/42");
+
+        assert_eq!(render_frame(context, &router), "User 42");
+        assert_eq!(seen_id.borrow().as_deref(), Some("42"));
+        assert_eq!(last_route().pattern(), Some("/users/:id"));
+    });
+}
+
+#[test]
+fn test_router_back_and_forward() {
+    with_router_test(|context| {
+        let routes = Routes::new()
+            .route("/", text_screen("Home"))
+            .route("/about", text_screen("About"));
+        let router = RouterComponent::new(RouterProps::default().routes(routes));
+
+        render_frame(context, &router);
+        let navigate = last_navigator();
+        assert!(!navigate.can_go_back());
+
+        navigate.push("/about");
+        assert_eq!(render_frame(context, &router), "About");
+
+        navigate.back();
+        assert_eq!(render_frame(context, &router), "Home");
+        assert!(navigate.can_go_forward());
+
+        navigate.forward();
+        assert_eq!(render_frame(context, &router), "About");
+    });
+}
+
+#[test]
+fn test_router_replace_does_not_add_history() {
+    with_router_test(|context| {
+        let routes = Routes::new()
+            .route("/", text_screen("Home"))
+            .route("/login", text_screen("Login"))
+            .route("/dashboard", text_screen("Dashboard"));
+        let router = RouterComponent::new(RouterProps::default().routes(routes));
+
+        render_frame(context, &router);
+        let navigate = last_navigator();
+
+        navigate.push("/login");
+        navigate.replace("/dashboard");
+        assert_eq!(render_frame(context, &router), "Dashboard");
+
+        navigate.back();
+        assert_eq!(render_frame(context, &router), "Home");
+    });
+}
+
+#[test]
+fn test_router_fallback_and_initial_path() {
+    with_router_test(|context| {
+        let routes = Routes::new()
+            .route("/", text_screen("Home"))
+            .fallback(text_screen("Not found"));
+        let router = RouterComponent::new(
+            RouterProps::default()
+                .routes(routes)
+                .initial_path("/missing"),
+        );
+
+        assert_eq!(render_frame(context, &router), "Not found");
+
+        let route = last_route();
+        assert_eq!(route.path(), "/missing");
+        assert_eq!(route.pattern(), None);
+    });
+}
+
+/// A layout drawing a label in front of the outlet
+struct Shell;
+
+impl Component for Shell {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        record_router_state();
+        Paragraph::new("Nav|").render(Rect { width: 4, ..area }, buffer);
+        let rest = Rect {
+            x: area.x + 4,
+            width: area.width - 4,
+            ..area
+        };
+        OutletComponent::new(OutletProps).render(rest, buffer);
+    }
+}
+
+#[test]
+fn test_router_renders_children_as_layout() {
+    with_router_test(|context| {
+        let routes = Routes::new()
+            .route("/", text_screen("Home"))
+            .route("/about", text_screen("About"));
+        let router = RouterComponent::new(
+            RouterProps::default()
+                .routes(routes)
+                .with_children(vec![Shell.into()]),
+        );
+
+        assert_eq!(render_frame(context, &router), "Nav|Home");
+
+        // The layout can navigate too
+        last_navigator().push("/about");
+        assert_eq!(render_frame(context, &router), "Nav|About");
+        assert_eq!(last_route().path(), "/about");
+
+        // Nothing renders in the outlet without a matching route
+        last_navigator().push("/missing");
+        assert_eq!(render_frame(context, &router), "Nav|");
+    });
+}
@@ -0,0 +1,128 @@
+//! Terminal capability detection
+//!
+//! Combines what environment variables say about the terminal with its
+//! answers to [`query_terminal`](crate::terminal_query::query_terminal):
+//! - truecolor and the 256-color palette from `COLORTERM`, `TERM` and
+//!   `TERM_PROGRAM`, as terminals have no reliable way to report them
+//! - Kitty graphics from the reply to the graphics query
+//! - the kitty keyboard protocol from the reply to the flags query
+//! - Sixel from the DA1 reply listing attribute 4
+
+use crate::terminal_query::private_replies;
+use reratui_hooks::capabilities::TerminalCaps;
+use std::env;
+
+/// The reply to the graphics query when the terminal supports it
+const KITTY_GRAPHICS_OK: &[u8] = b"\x1b_Gi=31;OK";
+
+/// Works out the terminal's capabilities from its answers in `response`
+///
+/// `mouse` is whether the runtime enabled mouse capture.
+pub(crate) fn detect_capabilities(response: &[u8], mouse: bool) -> TerminalCaps {
+    capabilities_from(
+        |name| env::var(name).ok().filter(|value| !value.is_empty()),
+        response,
+        mouse,
+    )
+}
+
+/// [`detect_capabilities`] with environment variables read by `var`
+fn capabilities_from(
+    var: impl Fn(&str) -> Option<String>,
+    response: &[u8],
+    mouse: bool,
+) -> TerminalCaps {
+    let kitty_graphics = response
+        .windows(KITTY_GRAPHICS_OK.len())
+        .any(|window| window == KITTY_GRAPHICS_OK);
+
+    let mut keyboard_enhancement = false;
+    let mut sixel = false;
+    for (params, last) in private_replies(response) {
+        match last {
+            b'u' => keyboard_enhancement = true,
+            b'c' => {
+                sixel = params
+                    .split(|&byte| byte == b';')
+                    .any(|param| param == b"4")
+            }
+            _ => {}
+        }
+    }
+
+    let colorterm = var("COLORTERM").unwrap_or_default();
+    let term = var("TERM").unwrap_or_default();
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+    let truecolor = matches!(colorterm.as_str(), "truecolor" | "24bit")
+        || term.ends_with("-direct")
+        || term.contains("kitty")
+        || term.contains("ghostty")
+        || matches!(
+            program.as_str(),
+            "iTerm.app" | "WezTerm" | "ghostty" | "vscode"
+        )
+        // Every terminal with Kitty graphics has truecolor too
+        || kitty_graphics;
+    let ansi256 = truecolor || term.contains("256color") || program == "Apple_Terminal";
+
+    TerminalCaps {
+        truecolor,
+        ansi256,
+        mouse,
+        keyboard_enhancement,
+        kitty_graphics,
+        sixel,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect_with(vars: &[(&str, &str)], response: &[u8]) -> TerminalCaps {
+        capabilities_from(
+            |name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            },
+            response,
+            true,
+        )
+    }
+
+    #[test]
+    fn test_silent_terminal_gets_defaults() {
+        assert_eq!(detect_with(&[], b""), TerminalCaps::default());
+        assert_eq!(
+            detect_with(&[("TERM", "xterm")], b"\x1b[?62;22c"),
+            TerminalCaps::default()
+        );
+    }
+
+    #[test]
+    fn test_truecolor_from_environment() {
+        assert!(detect_with(&[("COLORTERM", "truecolor")], b"").truecolor);
+        assert!(detect_with(&[("TERM", "xterm-direct")], b"").truecolor);
+        assert!(detect_with(&[("TERM_PROGRAM", "iTerm.app")], b"").truecolor);
+        assert!(!detect_with(&[("COLORTERM", "yes")], b"").truecolor);
+
+        let caps = detect_with(&[("TERM", "screen-256color")], b"");
+        assert!(caps.ansi256 && !caps.truecolor);
+        assert!(detect_with(&[("COLORTERM", "24bit")], b"").ansi256);
+    }
+
+    #[test]
+    fn test_capabilities_from_replies() {
+        let caps = detect_with(
+            &[],
+            b"\x1b]11;rgb:0/0/0\x07\x1b_Gi=31;OK\x1b\\\x1b[?0u\x1b[?62;22c",
+        );
+        assert!(caps.kitty_graphics && caps.truecolor && caps.keyboard_enhancement);
+        assert!(!caps.sixel);
+
+        let caps = detect_with(&[], b"\x1b_Gi=31;EINVAL:unsupported\x1b\\\x1b[?63;1;4c");
+        assert!(caps.sixel && caps.graphics());
+        assert!(!caps.kitty_graphics && !caps.keyboard_enhancement);
+    }
+}
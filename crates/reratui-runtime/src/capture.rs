@@ -0,0 +1,275 @@
+//! Capturing stdout and stderr while the app is on screen
+//!
+//! Anything printed behind ratatui's back, such as a dependency's
+//! `eprintln!`, lands in the middle of the frame. [`OutputCapture`] points
+//! file descriptors 1 and 2 at pipes instead: every captured line is recorded
+//! in the log buffer read by `use_logs`, and the raw output is written to the
+//! real streams once the capture ends. The terminal keeps drawing through a
+//! copy of the original stdout, see [`TerminalOutput`](crate::TerminalOutput).
+
+use crate::managed_terminal::TerminalOutput;
+use reratui_hooks::logging::{Level, LogBuffer};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::panic;
+use std::sync::{Arc, Mutex, Once};
+use std::thread::{self, JoinHandle};
+
+/// Streams being captured, for the panic hook to restore
+static ACTIVE: Mutex<Vec<ActiveStream>> = Mutex::new(Vec::new());
+
+/// Wraps the panic hook once, on the first capture
+static PANIC_HOOK: Once = Once::new();
+
+/// What the panic hook needs to hand a stream back to the terminal
+struct ActiveStream {
+    fd: RawFd,
+    saved: RawFd,
+    captured: Arc<Mutex<Vec<u8>>>,
+}
+
+fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+fn dup(fd: RawFd) -> io::Result<OwnedFd> {
+    // SAFETY: `dup` returns a new descriptor that nothing else owns
+    cvt(unsafe { libc::dup(fd) }).map(|fd| unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+fn dup2(from: RawFd, to: RawFd) -> io::Result<()> {
+    // SAFETY: `to` is one of the standard streams, which stay open
+    cvt(unsafe { libc::dup2(from, to) }).map(drop)
+}
+
+/// A pipe as its read and write ends
+fn pipe() -> io::Result<(OwnedFd, OwnedFd)> {
+    let mut fds = [0; 2];
+    // SAFETY: `pipe` fills `fds` with two new descriptors that nothing else owns
+    cvt(unsafe { libc::pipe(fds.as_mut_ptr()) })?;
+    Ok(unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) })
+}
+
+/// Flushes Rust's buffer for `fd` so it goes where `fd` points right now
+fn flush_std(fd: RawFd) {
+    if fd == libc::STDOUT_FILENO {
+        let _ = io::stdout().flush();
+    } else {
+        let _ = io::stderr().flush();
+    }
+}
+
+/// Writes captured bytes to `fd` once it points at the terminal again
+fn write_std(fd: RawFd, bytes: &[u8]) -> io::Result<()> {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    if fd == libc::STDOUT_FILENO {
+        let mut stdout = io::stdout();
+        stdout.write_all(bytes)?;
+        stdout.flush()
+    } else {
+        io::stderr().write_all(bytes)
+    }
+}
+
+/// One standard stream pointed at a pipe
+struct CapturedStream {
+    fd: RawFd,
+    /// The stream as it was before the capture
+    saved: OwnedFd,
+    captured: Arc<Mutex<Vec<u8>>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl CapturedStream {
+    /// Points `fd` at a pipe, recording each line read from it at `level`
+    fn start(fd: RawFd, level: Level, target: &'static str, logs: LogBuffer) -> io::Result<Self> {
+        flush_std(fd);
+        let saved = dup(fd)?;
+        let (read, write) = pipe()?;
+        dup2(write.as_raw_fd(), fd)?;
+        // `fd` now holds the only write end, so restoring it ends the reader
+        drop(write);
+
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let reader = thread::Builder::new()
+            .name(format!("reratui-capture-{target}"))
+            .spawn({
+                let captured = captured.clone();
+                move || {
+                    let mut reader = BufReader::new(File::from(read));
+                    let mut line = Vec::new();
+                    while matches!(reader.read_until(b'\n', &mut line), Ok(read) if read > 0) {
+                        captured.lock().unwrap().extend_from_slice(&line);
+                        let text = String::from_utf8_lossy(&line);
+                        let text = text.trim_end_matches(['\n', '\r']);
+                        if !text.is_empty() {
+                            logs.record(level, target, text);
+                        }
+                        line.clear();
+                    }
+                }
+            });
+        let reader = match reader {
+            Ok(reader) => reader,
+            Err(error) => {
+                let _ = dup2(saved.as_raw_fd(), fd);
+                return Err(error);
+            }
+        };
+
+        Ok(Self {
+            fd,
+            saved,
+            captured,
+            reader: Some(reader),
+        })
+    }
+
+    /// Points the stream back at the terminal and returns what was captured
+    fn stop(mut self) -> io::Result<Vec<u8>> {
+        flush_std(self.fd);
+        dup2(self.saved.as_raw_fd(), self.fd)?;
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+        Ok(std::mem::take(&mut *self.captured.lock().unwrap()))
+    }
+}
+
+/// Hands the captured streams back to the terminal before a panic is printed
+///
+/// The panic hook of `reratui-panic` exits the process, so the capture is
+/// never dropped; without this the panic message would end up in a pipe.
+fn restore_on_panic() {
+    for stream in ACTIVE
+        .lock()
+        .map(|mut active| std::mem::take(&mut *active))
+        .unwrap_or_default()
+    {
+        flush_std(stream.fd);
+        if dup2(stream.saved, stream.fd).is_ok() {
+            let captured =
+                std::mem::take(&mut *stream.captured.lock().unwrap_or_else(|e| e.into_inner()));
+            let _ = write_std(stream.fd, &captured);
+        }
+    }
+}
+
+/// Stdout and stderr captured into a [`LogBuffer`] until dropped
+pub(crate) struct OutputCapture {
+    streams: Vec<CapturedStream>,
+}
+
+impl OutputCapture {
+    /// Starts capturing, recording stdout lines as `INFO` and stderr lines as
+    /// `WARN` entries in `logs`
+    pub(crate) fn start(logs: LogBuffer) -> io::Result<Self> {
+        PANIC_HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                restore_on_panic();
+                hook(info);
+            }));
+        });
+
+        let mut capture = Self {
+            streams: Vec::with_capacity(2),
+        };
+        capture.streams.push(CapturedStream::start(
+            libc::STDOUT_FILENO,
+            Level::INFO,
+            "stdout",
+            logs.clone(),
+        )?);
+        capture.streams.push(CapturedStream::start(
+            libc::STDERR_FILENO,
+            Level::WARN,
+            "stderr",
+            logs,
+        )?);
+
+        *ACTIVE.lock().unwrap() = capture
+            .streams
+            .iter()
+            .map(|stream| ActiveStream {
+                fd: stream.fd,
+                saved: stream.saved.as_raw_fd(),
+                captured: stream.captured.clone(),
+            })
+            .collect();
+        Ok(capture)
+    }
+
+    /// A handle to the original stdout, for the terminal to keep drawing to
+    pub(crate) fn terminal(&self) -> io::Result<TerminalOutput> {
+        let stdout = &self.streams[0].saved;
+        Ok(TerminalOutput::to_file(File::from(stdout.try_clone()?)))
+    }
+
+    /// Ends the capture and returns what each stream captured
+    fn stop(&mut self) -> io::Result<Vec<(RawFd, Vec<u8>)>> {
+        ACTIVE.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.streams
+            .drain(..)
+            .map(|stream| Ok((stream.fd, stream.stop()?)))
+            .collect()
+    }
+
+    /// Ends the capture and writes the captured output to the terminal
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        for (fd, captured) in self.stop()? {
+            write_std(fd, &captured)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for OutputCapture {
+    fn drop(&mut self) {
+        if let Ok(captured) = self.stop() {
+            for (fd, captured) in captured {
+                let _ = write_std(fd, &captured);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_records_lines_and_restores_streams() {
+        let logs = LogBuffer::new(10);
+        let mut capture = OutputCapture::start(logs.clone()).unwrap();
+
+        let mut stdout = io::stdout();
+        writeln!(stdout, "from stdout").unwrap();
+        stdout.flush().unwrap();
+        writeln!(io::stderr(), "from stderr\nagain").unwrap();
+
+        // Other tests may print while the capture is on, so look for ours
+        let captured = capture.stop().unwrap();
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+        assert_eq!(captured[0].0, libc::STDOUT_FILENO);
+        assert!(text(&captured[0].1).contains("from stdout\n"));
+        assert_eq!(captured[1].0, libc::STDERR_FILENO);
+        assert!(text(&captured[1].1).contains("from stderr\nagain\n"));
+
+        let entries = logs.entries(Level::TRACE);
+        let lines: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.level, entry.target.as_str(), entry.message.as_str()))
+            .collect();
+        assert!(lines.contains(&(Level::INFO, "stdout", "from stdout")));
+        assert!(lines.contains(&(Level::WARN, "stderr", "from stderr")));
+        assert!(lines.contains(&(Level::WARN, "stderr", "again")));
+    }
+}
@@ -0,0 +1,289 @@
+//! Downsampling colors for terminals without truecolor
+//!
+//! Terminals without 24-bit color show `Color::Rgb` badly or not at all, so
+//! with a [`ColorMode`] other than truecolor every frame is remapped to the
+//! nearest entries of the palette the terminal has, after it is rendered and
+//! before it is diffed and written.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+use reratui_hooks::capabilities::TerminalCaps;
+
+/// Levels of each channel in the 6x6x6 color cube of the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 ANSI colors with the RGB values xterm gives them
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Which colors are written to the terminal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorMode {
+    /// Whatever the terminal was detected to support, see
+    /// `use_terminal_capabilities`
+    #[default]
+    Auto,
+    /// All colors as given
+    Truecolor,
+    /// `Color::Rgb` as the nearest entry of the 256-color palette
+    Ansi256,
+    /// `Color::Rgb` and `Color::Indexed` as the nearest of the 16 named colors
+    Ansi16,
+}
+
+impl ColorMode {
+    /// This mode, with `Auto` replaced by the best one `caps` allow
+    pub fn resolve(self, caps: TerminalCaps) -> Self {
+        match self {
+            Self::Auto if caps.truecolor => Self::Truecolor,
+            Self::Auto if caps.ansi256 => Self::Ansi256,
+            Self::Auto => Self::Ansi16,
+            mode => mode,
+        }
+    }
+
+    /// The color written for `color` in this mode
+    ///
+    /// `Auto` leaves colors as they are, like `Truecolor`.
+    pub fn convert(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_ansi256(r, g, b)),
+            (Self::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16(r, g, b),
+            (Self::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = indexed_rgb(index);
+                nearest_ansi16(r, g, b)
+            }
+            _ => color,
+        }
+    }
+}
+
+/// Remaps the colors of every cell in `buffer` for `mode`
+///
+/// With 16 colors, text whose foreground and background end up as the same
+/// color is switched to black or white, whichever stands out from the
+/// original background, so it stays readable.
+pub(crate) fn downsample(buffer: &mut Buffer, mode: ColorMode) {
+    if matches!(mode, ColorMode::Auto | ColorMode::Truecolor) {
+        return;
+    }
+
+    for cell in &mut buffer.content {
+        let fg = mode.convert(cell.fg);
+        let bg = mode.convert(cell.bg);
+        cell.fg = if fg == bg && fg != Color::Reset && cell.fg != cell.bg {
+            contrasting(cell.bg)
+        } else {
+            fg
+        };
+        cell.bg = bg;
+        cell.underline_color = mode.convert(cell.underline_color);
+    }
+}
+
+/// The nearest entry of the 256-color palette, from the color cube or the
+/// grayscale ramp
+///
+/// The first 16 entries are left out, since terminals theme them freely.
+pub fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |channel: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&index| CUBE_LEVELS[index].abs_diff(channel))
+            .unwrap_or(0) as u8
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let cube_rgb = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    // The grayscale ramp runs from 8 to 238 in steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * step;
+    let gray = 232 + step;
+
+    if distance((r, g, b), (gray_level, gray_level, gray_level)) < distance((r, g, b), cube_rgb) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// The nearest of the 16 named ANSI colors
+pub fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// The RGB value of a 256-color palette entry
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..16 => ANSI16[index as usize].1,
+        16..232 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Black or white, whichever is easier to read on `background`
+fn contrasting(background: Color) -> Color {
+    let (r, g, b) = match background {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_rgb(index),
+        color => ANSI16
+            .iter()
+            .find(|(named, _)| *named == color)
+            .map_or((0, 0, 0), |(_, rgb)| *rgb),
+    };
+    let luma = 299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b);
+    if luma > 128_000 {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
+/// Squared distance between two colors, weighted for how the eye sees them
+///
+/// The "redmean" approximation: cheap, and much closer to perceived
+/// differences than plain RGB distance.
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let mean_red = (u32::from(r1) + u32::from(r2)) / 2;
+    let dr = i32::from(r1) - i32::from(r2);
+    let dg = i32::from(g1) - i32::from(g2);
+    let db = i32::from(b1) - i32::from(b2);
+    (((512 + mean_red) * (dr * dr) as u32) >> 8)
+        + 4 * (dg * dg) as u32
+        + (((767 - mean_red) * (db * db) as u32) >> 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_nearest_ansi256() {
+        // Exact cube and grayscale entries map to themselves
+        assert_eq!(nearest_ansi256(0, 0, 0), 16);
+        assert_eq!(nearest_ansi256(255, 255, 255), 231);
+        assert_eq!(nearest_ansi256(95, 135, 175), 67);
+        assert_eq!(nearest_ansi256(128, 128, 128), 244);
+        // Near-grays prefer the finer grayscale ramp
+        assert_eq!(nearest_ansi256(17, 24, 39), 234);
+        assert_eq!(nearest_ansi256(59, 130, 246), 69);
+
+        for index in 16..=255 {
+            let (r, g, b) = indexed_rgb(index);
+            assert_eq!(indexed_rgb(nearest_ansi256(r, g, b)), (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_nearest_ansi16() {
+        assert_eq!(nearest_ansi16(17, 24, 39), Color::Black);
+        assert_eq!(nearest_ansi16(239, 68, 68), Color::LightRed);
+        assert_eq!(nearest_ansi16(34, 197, 94), Color::Green);
+        assert_eq!(nearest_ansi16(234, 179, 8), Color::Yellow);
+        assert_eq!(nearest_ansi16(147, 197, 253), Color::Gray);
+        assert_eq!(nearest_ansi16(250, 250, 250), Color::White);
+    }
+
+    #[test]
+    fn test_convert_by_mode() {
+        let rgb = Color::Rgb(59, 130, 246);
+        assert_eq!(ColorMode::Truecolor.convert(rgb), rgb);
+        assert_eq!(ColorMode::Ansi256.convert(rgb), Color::Indexed(69));
+        assert_eq!(
+            ColorMode::Ansi256.convert(Color::Indexed(200)),
+            Color::Indexed(200)
+        );
+        assert_eq!(
+            ColorMode::Ansi16.convert(Color::Indexed(196)),
+            Color::LightRed
+        );
+        assert_eq!(ColorMode::Ansi16.convert(Color::Indexed(3)), Color::Yellow);
+        assert_eq!(ColorMode::Ansi16.convert(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorMode::Ansi16.convert(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn test_resolve_from_capabilities() {
+        let caps = TerminalCaps::default();
+        assert_eq!(ColorMode::Auto.resolve(caps), ColorMode::Ansi16);
+        let caps = TerminalCaps {
+            ansi256: true,
+            ..caps
+        };
+        assert_eq!(ColorMode::Auto.resolve(caps), ColorMode::Ansi256);
+        let caps = TerminalCaps {
+            truecolor: true,
+            ..caps
+        };
+        assert_eq!(ColorMode::Auto.resolve(caps), ColorMode::Truecolor);
+        assert_eq!(ColorMode::Ansi16.resolve(caps), ColorMode::Ansi16);
+    }
+
+    #[test]
+    fn test_downsample_keeps_text_readable() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        // Two dark blues that both become black with 16 colors
+        buffer.set_string(
+            0,
+            0,
+            "a",
+            Style::default()
+                .fg(Color::Rgb(31, 41, 55))
+                .bg(Color::Rgb(17, 24, 39)),
+        );
+        buffer.set_string(
+            1,
+            0,
+            "b",
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Rgb(239, 68, 68)),
+        );
+
+        let mut ansi256 = buffer.clone();
+        downsample(&mut ansi256, ColorMode::Ansi256);
+        assert_eq!(ansi256[(0, 0)].bg, Color::Indexed(234));
+
+        downsample(&mut buffer, ColorMode::Ansi16);
+        assert_eq!(buffer[(0, 0)].bg, Color::Black);
+        assert_eq!(buffer[(0, 0)].fg, Color::White);
+        assert_eq!(buffer[(1, 0)].fg, Color::White);
+        assert_eq!(buffer[(1, 0)].bg, Color::LightRed);
+    }
+}
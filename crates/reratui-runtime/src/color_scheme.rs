@@ -0,0 +1,69 @@
+//! Terminal background detection via OSC 11
+//!
+//! The background color is one of the questions asked by
+//! [`query_terminal`](crate::terminal_query::query_terminal) at startup.
+
+use reratui_hooks::theme::ColorScheme;
+
+/// Classifies the terminal background found in `response` as light or dark
+///
+/// Falls back to [`ColorScheme::Dark`] when the terminal didn't answer or
+/// isn't a terminal at all.
+pub(crate) fn detect_color_scheme(response: &[u8]) -> ColorScheme {
+    parse_background(response)
+        .map(|(r, g, b)| ColorScheme::from_background(r, g, b))
+        .unwrap_or_default()
+}
+
+/// Extracts the color from an OSC 11 reply such as
+/// `ESC ] 11 ; rgb:RRRR/GGGG/BBBB BEL`
+fn parse_background(response: &[u8]) -> Option<(u8, u8, u8)> {
+    let response = String::from_utf8_lossy(response);
+    let start = response.find("\x1b]11;")? + "\x1b]11;".len();
+    let reply = &response[start..];
+    let end = reply.find(['\x07', '\x1b'])?;
+
+    let color = reply[..end]
+        .strip_prefix("rgb:")
+        .or_else(|| reply[..end].strip_prefix("rgba:"))?;
+    let mut channels = color.split('/').map(parse_channel);
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
+
+/// Scales a 1 to 4 digit hex channel to 8 bits
+fn parse_channel(hex: &str) -> Option<u8> {
+    if hex.is_empty() || hex.len() > 4 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let max = (1u32 << (4 * hex.len())) - 1;
+    Some((value * 255 / max) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_background() {
+        assert_eq!(
+            parse_background(b"\x1b]11;rgb:ffff/ffff/ffff\x07\x1b[?62;22c"),
+            Some((255, 255, 255))
+        );
+        assert_eq!(
+            parse_background(b"\x1b]11;rgb:2828/2a2a/3636\x1b\\"),
+            Some((40, 42, 54))
+        );
+        assert_eq!(
+            parse_background(b"\x1b]11;rgb:f/8/0\x07"),
+            Some((255, 136, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_background_without_reply() {
+        assert_eq!(parse_background(b"\x1b[?1;2c"), None);
+        assert_eq!(parse_background(b"\x1b]11;rgb:ffff/ffff"), None);
+        assert_eq!(parse_background(b""), None);
+    }
+}
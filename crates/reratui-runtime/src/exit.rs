@@ -1,32 +1,93 @@
+use std::any::Any;
+use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 static GLOBAL_EXIT: AtomicBool = AtomicBool::new(false);
+static EXIT_CODE: Mutex<Option<i32>> = Mutex::new(None);
+static EXIT_RESULT: Mutex<Option<Box<dyn Any + Send>>> = Mutex::new(None);
+
+/// Serializes tests that touch the global exit state
+#[cfg(test)]
+pub(crate) static TEST_LOCK: Mutex<()> = Mutex::new(());
 
 /// Request the application to exit
 pub fn request_exit() {
     GLOBAL_EXIT.store(true, Ordering::Release);
 }
 
+/// Request the application to exit with a process exit code
+///
+/// The code is returned by `render_with_exit_code` once the render loop stops.
+pub fn request_exit_with(code: i32) {
+    *EXIT_CODE.lock().unwrap_or_else(|e| e.into_inner()) = Some(code);
+    request_exit();
+}
+
+/// Request the application to exit and hand `value` back to the caller
+///
+/// The value is returned by `render_with_result::<T>` once the render loop
+/// stops. Requesting a result of a different type than the one `render_with_result`
+/// expects yields `None`.
+pub fn request_exit_with_result<T: Send + 'static>(value: T) {
+    *EXIT_RESULT.lock().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(value));
+    request_exit();
+}
+
 /// Check if exit has been requested
 pub fn should_exit() -> bool {
     GLOBAL_EXIT.load(Ordering::Acquire)
 }
 
-/// Reset the exit flag (useful for tests)
+/// The exit code passed to `request_exit_with`, if any
+pub fn exit_code() -> Option<i32> {
+    *EXIT_CODE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Takes the value passed to `request_exit_with_result`, if it has type `T`
+pub fn take_exit_result<T: 'static>() -> Option<T> {
+    let value = EXIT_RESULT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()?;
+    value.downcast::<T>().ok().map(|value| *value)
+}
+
+/// Reset the exit flag, code and result (useful for tests)
 pub fn reset_exit() {
     GLOBAL_EXIT.store(false, Ordering::Release);
+    *EXIT_CODE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    *EXIT_RESULT.lock().unwrap_or_else(|e| e.into_inner()) = None;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // The exit state is global, so all checks live in one test to avoid races
     #[test]
     fn test_exit_flag() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
         assert!(!should_exit());
         request_exit();
         assert!(should_exit());
+        assert_eq!(exit_code(), None);
         reset_exit();
         assert!(!should_exit());
+
+        request_exit_with(2);
+        assert!(should_exit());
+        assert_eq!(exit_code(), Some(2));
+        reset_exit();
+        assert_eq!(exit_code(), None);
+
+        request_exit_with_result(String::from("picked"));
+        assert!(should_exit());
+        assert_eq!(take_exit_result::<i32>(), None);
+        reset_exit();
+
+        request_exit_with_result(String::from("picked"));
+        assert_eq!(take_exit_result::<String>().as_deref(), Some("picked"));
+        assert_eq!(take_exit_result::<String>(), None);
+        reset_exit();
     }
 }
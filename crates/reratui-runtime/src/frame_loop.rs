@@ -0,0 +1,188 @@
+//! Rendering frames of a component tree
+//!
+//! [`FrameLoop`] owns what lives across the frames of one application: its
+//! hook context, its root element and the bookkeeping for damage and frame
+//! timing. The render loop and the [`TestHarness`](crate::TestHarness) both
+//! drive it, so events reach components and frames are drawn the same way in
+//! tests as in a terminal.
+
+use crate::color_mode::{self, ColorMode};
+use crossterm::event::Event;
+use ratatui::backend::Backend;
+use ratatui::{CompletedFrame, Terminal};
+use reratui_core::Element;
+use reratui_core::damage::{Damage, DamageTracker};
+use reratui_core::graphics::GraphicsPlacement;
+use reratui_core::hyperlink::LinkRegion;
+use reratui_hooks::frame::{FrameContext, FrameTimings};
+use reratui_hooks::hook_context::HookContext;
+use std::io;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A frame written to the terminal, with what was drawn outside its cells
+pub(crate) struct DrawnFrame<'a> {
+    pub(crate) frame: CompletedFrame<'a>,
+    /// Hyperlinks recorded while rendering
+    pub(crate) links: Vec<LinkRegion>,
+    /// Graphics placed while rendering
+    pub(crate) graphics: Vec<GraphicsPlacement>,
+}
+
+/// The component tree of a running application and its per-frame state
+///
+/// Creating one installs its hook context on the current thread; dropping it
+/// unmounts the components and removes the context again, so only one can be
+/// alive per thread.
+pub(crate) struct FrameLoop {
+    hook_context: Rc<HookContext>,
+    element: Element,
+    frame_count: u64,
+    last_frame_time: Instant,
+    timings: FrameTimings,
+    /// The previous frame, for components that skip regions that haven't changed
+    damage: DamageTracker,
+    last_state_epoch: u64,
+    color_mode: ColorMode,
+}
+
+impl FrameLoop {
+    /// Sets up the hook context and creates the root element
+    pub(crate) fn new(
+        initializer: impl FnOnce() -> Element,
+        timings: FrameTimings,
+        color_mode: ColorMode,
+    ) -> Self {
+        // Create a new hook context for this component tree and set it for
+        // this thread before any component is created
+        let hook_context = Rc::new(HookContext::new());
+        reratui_hooks::hook_context::set_hook_context(hook_context.clone());
+
+        Self {
+            hook_context,
+            element: initializer(),
+            frame_count: 0,
+            last_frame_time: reratui_hooks::clock::now(),
+            timings,
+            damage: DamageTracker::new(),
+            last_state_epoch: reratui_hooks::state::state_epoch(),
+            color_mode,
+        }
+    }
+
+    /// Makes `event` the input of the next frame, or clears it with `None`
+    ///
+    /// Key events go through the global key handlers first, and only reach
+    /// components when no global handler took them. The handlers run under
+    /// the frame lock, so their state updates land as one batch.
+    ///
+    /// Returns whether a global key handler took the event.
+    pub(crate) fn deliver(&self, event: Option<Event>) -> bool {
+        let _frame_lock = reratui_hooks::state::lock_frame();
+        let processed = match &event {
+            Some(Event::Key(key_event)) => {
+                reratui_hooks::event::global_events::process_global_event(key_event)
+            }
+            _ => false,
+        };
+
+        // If not processed as a global event, make it available to components
+        if !processed {
+            reratui_hooks::event::set_current_event(event.map(Arc::new));
+        }
+        processed
+    }
+
+    /// Renders the tree into `terminal` and writes the frame
+    ///
+    /// `received_input` is whether input arrived for this frame, which may
+    /// change anything on screen, so nothing is skipped as undamaged.
+    pub(crate) fn draw<'a, B: Backend>(
+        &mut self,
+        terminal: &'a mut Terminal<B>,
+        received_input: bool,
+    ) -> io::Result<DrawnFrame<'a>> {
+        // Calculate frame timing; the first delta only measures setup
+        let current_time = reratui_hooks::clock::now();
+        let delta = current_time.duration_since(self.last_frame_time);
+        self.last_frame_time = current_time;
+        if self.frame_count > 0 {
+            self.timings.record(delta);
+        }
+        let frame_stats = self.timings.stats();
+
+        // Reset hook index before each render
+        self.hook_context.reset_hook_index();
+
+        // Start each frame without the context values provided by the last one
+        reratui_hooks::context::clear_context_providers();
+
+        // Render the element, never in the middle of a batch of state updates
+        let frame_lock = reratui_hooks::state::lock_frame();
+        let state_epoch = reratui_hooks::state::state_epoch();
+        let invalidated = received_input || state_epoch != self.last_state_epoch;
+        self.last_state_epoch = state_epoch;
+
+        let frame_count = self.frame_count;
+        let (element, damage, color_mode) = (&self.element, &self.damage, self.color_mode);
+        let drawn = terminal.draw(|frame| {
+            // SAFETY: The FrameContext is only used within this render scope
+            // and the frame pointer remains valid for the duration of the draw call
+            let frame_ctx = unsafe { FrameContext::new(frame, frame_count, delta, current_time) }
+                .with_stats(frame_stats);
+
+            // Provide frame context for components
+            let _frame_context = reratui_hooks::context::use_context_provider(|| frame_ctx);
+
+            let area = frame.area();
+            reratui_core::damage::set_frame_damage(damage.damage(area, invalidated));
+            element.render(area, frame.buffer_mut());
+
+            // Paint overlays queued by portals above the tree
+            reratui_core::portal::render_portals(area, frame.buffer_mut());
+
+            // Convert colors the terminal can't show before the frame is diffed
+            color_mode::downsample(frame.buffer_mut(), color_mode);
+        });
+        drop(frame_lock);
+        reratui_core::damage::set_frame_damage(Damage::full());
+
+        let links = reratui_core::hyperlink::take_hyperlinks();
+        let graphics = reratui_core::graphics::take_graphics();
+        let frame = drawn?;
+        self.damage.finish_frame(frame.buffer);
+        Ok(DrawnFrame {
+            frame,
+            links,
+            graphics,
+        })
+    }
+
+    /// Runs the work that follows a drawn frame
+    pub(crate) fn finish_frame(&mut self) {
+        // Clean up unmounted components after render
+        reratui_core::component::cleanup_unmounted();
+
+        // Apply transition updates whose pending frame has now been drawn
+        reratui_hooks::transition::flush_transitions();
+
+        // Deliver values emitted on the event bus during this frame
+        reratui_hooks::bus::flush_event_bus();
+
+        self.frame_count += 1;
+    }
+}
+
+impl Drop for FrameLoop {
+    fn drop(&mut self) {
+        // Clear the current event
+        reratui_hooks::event::set_current_event(None);
+
+        // Unmount the components still on screen
+        reratui_core::component::unmount_all();
+
+        // Clean up the hook context
+        reratui_hooks::hook_context::clear_hook_context();
+    }
+}
@@ -0,0 +1,130 @@
+//! Writing the graphics placed in each frame
+//!
+//! Widgets record pictures as `reratui_core::graphics::GraphicsPlacement`s.
+//! After the frame is drawn, new and moved placements are written at their
+//! area and those no longer placed are cleared.
+
+use crossterm::cursor::{MoveTo, RestorePosition, SavePosition};
+use crossterm::queue;
+use ratatui::layout::Rect;
+use reratui_core::graphics::GraphicsPlacement;
+use std::io::{self, Write};
+
+/// Writes the changes to the graphics on screen, as passed to the render loop
+pub(crate) type PrintGraphics<B> = fn(&mut B, &GraphicsChanges) -> io::Result<()>;
+
+/// What to write to bring the graphics on screen up to date
+#[derive(Default)]
+pub(crate) struct GraphicsChanges {
+    cleared: Vec<GraphicsPlacement>,
+    drawn: Vec<GraphicsPlacement>,
+}
+
+/// Tracks the graphics on screen
+#[derive(Default)]
+pub(crate) struct GraphicsTracker {
+    shown: Vec<GraphicsPlacement>,
+    /// The frame area the graphics were drawn in
+    area: Rect,
+}
+
+impl GraphicsTracker {
+    /// Records the placements of a frame drawn in `area`, returning what changed
+    ///
+    /// Everything is drawn again after a resize, since terminals may drop or
+    /// misplace pictures when the screen is cleared.
+    pub(crate) fn update(
+        &mut self,
+        area: Rect,
+        placements: Vec<GraphicsPlacement>,
+    ) -> GraphicsChanges {
+        let resized = area != self.area;
+        self.area = area;
+        let shown = std::mem::replace(&mut self.shown, placements);
+
+        let unchanged = |placement: &GraphicsPlacement, among: &[GraphicsPlacement]| {
+            !resized
+                && among
+                    .iter()
+                    .any(|other| other.key == placement.key && other.area == placement.area)
+        };
+        GraphicsChanges {
+            cleared: shown
+                .iter()
+                .filter(|placement| !unchanged(placement, &self.shown))
+                .cloned()
+                .collect(),
+            drawn: self
+                .shown
+                .iter()
+                .filter(|placement| !unchanged(placement, &shown))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Writes `changes` to a terminal, leaving the cursor where it was
+pub(crate) fn print_graphics<W: Write>(out: &mut W, changes: &GraphicsChanges) -> io::Result<()> {
+    if changes.cleared.is_empty() && changes.drawn.is_empty() {
+        return Ok(());
+    }
+
+    queue!(out, SavePosition)?;
+    for placement in &changes.cleared {
+        out.write_all(&placement.clear)?;
+    }
+    for placement in &changes.drawn {
+        queue!(out, MoveTo(placement.area.x, placement.area.y))?;
+        out.write_all(&placement.draw)?;
+    }
+    queue!(out, RestorePosition)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placement(key: u64, x: u16) -> GraphicsPlacement {
+        GraphicsPlacement {
+            key,
+            area: Rect::new(x, 0, 4, 2),
+            draw: format!("draw {key};").as_bytes().into(),
+            clear: format!("clear {key};").as_bytes().into(),
+        }
+    }
+
+    fn printed(changes: &GraphicsChanges) -> String {
+        let mut out = Vec::new();
+        print_graphics(&mut out, changes).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_only_changed_placements_are_written() {
+        let screen = Rect::new(0, 0, 20, 5);
+        let mut tracker = GraphicsTracker::default();
+
+        let changes = tracker.update(screen, vec![placement(1, 0), placement(2, 5)]);
+        let output = printed(&changes);
+        assert!(output.contains("draw 1;") && output.contains("draw 2;"));
+        assert!(!output.contains("clear"));
+
+        // Nothing changed, so nothing is written
+        let changes = tracker.update(screen, vec![placement(1, 0), placement(2, 5)]);
+        assert_eq!(printed(&changes), "");
+
+        // A moved picture is cleared and drawn again; a removed one is cleared
+        let changes = tracker.update(screen, vec![placement(1, 8)]);
+        let output = printed(&changes);
+        assert!(output.contains("clear 1;") && output.contains("clear 2;"));
+        assert!(output.find("clear 1;") < output.find("draw 1;"));
+        assert!(!output.contains("draw 2;"));
+
+        // A resize draws everything again
+        let changes = tracker.update(Rect::new(0, 0, 30, 5), vec![placement(1, 8)]);
+        let output = printed(&changes);
+        assert!(output.contains("clear 1;") && output.contains("draw 1;"));
+    }
+}
@@ -0,0 +1,156 @@
+//! Printing the OSC 8 hyperlinks drawn in each frame
+//!
+//! Links are recorded by `reratui_core::hyperlink::Hyperlink` as regions of
+//! the frame. After the frame is drawn, the cells of each region are printed
+//! again between the sequences that open and close the link, which makes them
+//! clickable in terminals that support OSC 8.
+
+use crossterm::cursor::{RestorePosition, SavePosition};
+use crossterm::queue;
+use ratatui::backend::Backend;
+use ratatui::buffer::{Buffer, Cell};
+use reratui_core::hyperlink::LinkRegion;
+use std::io::{self, Write};
+use unicode_width::UnicodeWidthStr;
+
+/// Prints the links of a frame, as passed to the render loop
+pub(crate) type PrintLinks<B> = fn(&mut B, &[PendingLink]) -> io::Result<()>;
+
+/// A link with the cells to print for it
+#[derive(Clone, PartialEq)]
+pub(crate) struct PendingLink {
+    region: LinkRegion,
+    cells: Vec<(u16, u16, Cell)>,
+}
+
+/// Tracks which links are on the terminal, to print only those that changed
+#[derive(Default)]
+pub(crate) struct LinkTracker {
+    printed: Vec<PendingLink>,
+}
+
+impl LinkTracker {
+    /// Records the links of a drawn frame, returning those to print
+    ///
+    /// A link is printed when it is new or its cells changed: the terminal
+    /// keeps the link on cells the frame did not overwrite.
+    pub(crate) fn update(&mut self, buffer: &Buffer, links: Vec<LinkRegion>) -> Vec<PendingLink> {
+        let current: Vec<PendingLink> = links
+            .into_iter()
+            .map(|region| PendingLink {
+                cells: region_cells(buffer, &region),
+                region,
+            })
+            .collect();
+        let changed = current
+            .iter()
+            .filter(|link| !self.printed.contains(link))
+            .cloned()
+            .collect();
+        self.printed = current;
+        changed
+    }
+}
+
+/// The cells of `region` to print, without those hidden by wide symbols
+fn region_cells(buffer: &Buffer, region: &LinkRegion) -> Vec<(u16, u16, Cell)> {
+    let area = region.area.intersection(buffer.area);
+    let mut cells = Vec::new();
+    let mut to_skip = 0;
+    for x in area.left()..area.right() {
+        let cell = &buffer[(x, area.y)];
+        if to_skip > 0 {
+            to_skip -= 1;
+        } else if !cell.skip {
+            to_skip = cell.symbol().width().saturating_sub(1);
+            cells.push((x, area.y, cell.clone()));
+        }
+    }
+    cells
+}
+
+/// Prints `links` to a terminal backend, leaving the cursor where it was
+pub(crate) fn print_links<B>(backend: &mut B, links: &[PendingLink]) -> io::Result<()>
+where
+    B: Backend + Write,
+{
+    if links.is_empty() {
+        return Ok(());
+    }
+
+    queue!(backend, SavePosition)?;
+    for link in links {
+        write!(backend, "{}", link.region.open_sequence())?;
+        Backend::draw(
+            backend,
+            link.cells.iter().map(|(x, y, cell)| (*x, *y, cell)),
+        )?;
+        write!(backend, "{}", LinkRegion::close_sequence())?;
+    }
+    queue!(backend, RestorePosition)?;
+    Backend::flush(backend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+    use reratui_core::hyperlink::{Hyperlink, take_hyperlinks};
+
+    #[test]
+    fn test_only_new_or_changed_links_are_printed() {
+        let area = Rect::new(0, 0, 12, 2);
+        let mut tracker = LinkTracker::default();
+        let draw = |text: &str| {
+            let mut buffer = Buffer::empty(area);
+            Hyperlink::new(text, "https://example.com").render(area, &mut buffer);
+            let links = take_hyperlinks();
+            (buffer, links)
+        };
+
+        let (buffer, links) = draw("docs");
+        let printed = tracker.update(&buffer, links);
+        assert_eq!(printed.len(), 1);
+        assert_eq!(printed[0].region.area, Rect::new(0, 0, 4, 1));
+        assert_eq!(printed[0].cells.len(), 4);
+
+        let (buffer, links) = draw("docs");
+        assert!(tracker.update(&buffer, links).is_empty());
+
+        let (buffer, links) = draw("guide");
+        assert_eq!(tracker.update(&buffer, links).len(), 1);
+    }
+
+    #[test]
+    fn test_links_are_printed_between_osc_8_sequences() {
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buffer = Buffer::empty(area);
+        Hyperlink::new("docs", "https://example.com").render(area, &mut buffer);
+        let pending = LinkTracker::default().update(&buffer, take_hyperlinks());
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        print_links(&mut backend, &pending).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        let open = output.find("\x1b]8;;https://example.com\x1b\\").unwrap();
+        let text = output.find("docs").unwrap();
+        let close = output.rfind("\x1b]8;;\x1b\\").unwrap();
+        assert!(open < text && text < close);
+    }
+
+    #[test]
+    fn test_wide_symbols_are_printed_once() {
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buffer = Buffer::empty(area);
+        Hyperlink::new("日本", "https://example.jp").render(area, &mut buffer);
+
+        let region = take_hyperlinks().remove(0);
+        assert_eq!(region.area.width, 4);
+        let symbols: Vec<_> = region_cells(&buffer, &region)
+            .into_iter()
+            .map(|(x, _, cell)| (x, cell.symbol().to_string()))
+            .collect();
+        assert_eq!(symbols, [(0, "日".to_string()), (2, "本".to_string())]);
+    }
+}
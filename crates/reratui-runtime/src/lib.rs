@@ -3,27 +3,122 @@
 //! This module provides the core runtime functionality for Reratui applications,
 //! including terminal management, event handling, and the render loop.
 
+mod capabilities;
+#[cfg(unix)]
+mod capture;
+mod color_mode;
+mod color_scheme;
 mod exit;
+mod frame_loop;
+mod graphics;
+mod hyperlinks;
 mod managed_terminal;
+mod record;
+mod signal;
+mod terminal_query;
+mod testing;
 
-pub use exit::{request_exit, reset_exit, should_exit};
-pub use managed_terminal::{ManagedTerminal, restore_terminal, setup_terminal};
+pub use color_mode::{ColorMode, nearest_ansi16, nearest_ansi256};
+pub use exit::{
+    exit_code, request_exit, request_exit_with, request_exit_with_result, reset_exit, should_exit,
+    take_exit_result,
+};
+pub use managed_terminal::{
+    ManagedTerminal, TerminalMode, TerminalOutput, restore_terminal, setup_terminal,
+    setup_terminal_with_mode,
+};
+pub use reratui_panic::{TaskPanic, TaskRegistry};
+pub use testing::TestHarness;
 
 use anyhow::Result;
-use crossterm::event::Event;
+use crossterm::event::{Event, EventStream};
+use frame_loop::FrameLoop;
+use graphics::{GraphicsTracker, PrintGraphics};
+use hyperlinks::{LinkTracker, PrintLinks};
+use ratatui::{Terminal, backend::Backend};
+use record::CastRecorder;
 use reratui_core::Element;
-use reratui_hooks::frame::FrameContext;
-use reratui_hooks::hook_context::HookContext;
-use std::{
-    rc::Rc,
-    time::{Duration, Instant},
-};
+use reratui_hooks::frame::FrameTimings;
+use signal::ShutdownSignals;
+use std::{fs::File, io::BufWriter, path::PathBuf, time::Duration};
+use tokio_stream::StreamExt;
+
+/// Options for how the render loop sets up the terminal
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderConfig {
+    /// Whether to take over the screen or render inline below the prompt
+    pub mode: TerminalMode,
+    /// File to record the session to as an asciinema v2 cast
+    ///
+    /// Every drawn frame is written with its timestamp, so the file can be
+    /// replayed with `asciinema play` or turned into a GIF with `agg`.
+    pub record: Option<PathBuf>,
+    /// Whether to capture stdout and stderr while the app is on screen
+    ///
+    /// Stray prints from the app or its dependencies would otherwise garble
+    /// the frame. Captured lines show up in `use_logs` and the raw output is
+    /// written to the terminal on exit. Only supported on Unix.
+    pub capture_output: bool,
+    /// Frame time above which `use_frame` counts a frame as dropped
+    ///
+    /// Defaults to [`DEFAULT_FRAME_BUDGET`](reratui_hooks::frame::DEFAULT_FRAME_BUDGET).
+    pub frame_budget: Option<Duration>,
+    /// Which colors are written to the terminal
+    ///
+    /// With [`ColorMode::Auto`], `Color::Rgb` is downsampled to the 256- or
+    /// 16-color palette when the terminal isn't detected to support truecolor.
+    pub color_mode: ColorMode,
+}
+
+impl RenderConfig {
+    /// Render in a region of `height` lines, leaving the output in scrollback
+    pub fn inline(height: u16) -> Self {
+        Self {
+            mode: TerminalMode::Inline { height },
+            ..Self::default()
+        }
+    }
+
+    /// Record the session to an asciinema cast file at `path`
+    pub fn record(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record = Some(path.into());
+        self
+    }
+
+    /// Capture stdout and stderr until the terminal is restored
+    pub fn capture_output(mut self) -> Self {
+        self.capture_output = true;
+        self
+    }
+
+    /// Count frames slower than `budget` as dropped in the frame statistics
+    pub fn frame_budget(mut self, budget: Duration) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Write colors for terminals supporting `mode`
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode;
+        self
+    }
+}
 
 /// Renders a component-based TUI application with hooks support
 ///
 /// This function sets up a hook context and manages the component lifecycle
 /// including state persistence between renders.
 ///
+/// While running, SIGINT and SIGTERM request an exit like [`request_exit`]
+/// does, so the terminal is restored before `render` returns. The exit code
+/// is set to `130` or `143` respectively, as seen by [`render_with_exit_code`].
+/// A Ctrl-C key press counts as SIGINT unless a global key handler takes it
+/// or a component consumes it through `use_event_handle`.
+///
+/// If a task spawned by a framework hook (such as a `use_future` fetcher)
+/// panics, the loop stops, restores the terminal and returns the
+/// [`TaskPanic`] as the error.
+///
 /// # Arguments
 /// * `app_fn` - A closure that returns an Element (supports both components and RSX)
 ///
@@ -48,100 +143,424 @@ use std::{
 /// # }
 /// ```
 pub async fn render<F>(initializer: F) -> Result<()>
+where
+    F: Fn() -> Element + 'static,
+{
+    run(RenderConfig::default(), initializer).await
+}
+
+/// Renders an application with the given terminal configuration
+///
+/// # Example
+/// ```no_run,ignore
+/// // A live progress region of 3 lines that stays in scrollback on exit
+/// render_with_config(RenderConfig::inline(3), || rsx! { <Progress /> }).await?;
+/// ```
+pub async fn render_with_config<F>(config: RenderConfig, initializer: F) -> Result<()>
+where
+    F: Fn() -> Element + 'static,
+{
+    run(config, initializer).await
+}
+
+/// Renders an application and returns the exit code it requested
+///
+/// The code is the one passed to [`request_exit_with`], or `0` if the app
+/// exited with a plain [`request_exit`].
+///
+/// # Example
+/// ```no_run,ignore
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let code = render_with_exit_code(|| rsx! { <Installer /> }).await?;
+///     std::process::exit(code);
+/// }
+/// ```
+pub async fn render_with_exit_code<F>(initializer: F) -> Result<i32>
+where
+    F: Fn() -> Element + 'static,
+{
+    run(RenderConfig::default(), initializer).await?;
+    Ok(exit_code().unwrap_or(0))
+}
+
+/// Renders an application and returns the value it exited with
+///
+/// The value is the one passed to [`request_exit_with_result`], or `None` if
+/// the app exited without a result or with a value of another type.
+///
+/// # Example
+/// ```no_run,ignore
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     if let Some(file) = render_with_result::<String, _>(|| rsx! { <FilePicker /> }).await? {
+///         println!("{file}");
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn render_with_result<T, F>(initializer: F) -> Result<Option<T>>
+where
+    T: 'static,
+    F: Fn() -> Element + 'static,
+{
+    run(RenderConfig::default(), initializer).await?;
+    Ok(take_exit_result::<T>())
+}
+
+/// Renders an application into the given backend
+///
+/// Unlike [`render`], no terminal setup is done: raw mode, the alternate
+/// screen, mouse capture and the panic hook are left to the caller. The
+/// loop still reads input from crossterm's event stream and stops on
+/// [`request_exit`] or a shutdown signal. Without an attached terminal, such
+/// as in CI, the loop runs without input.
+///
+/// Returns the terminal so the final frame can be inspected through
+/// `terminal.backend()`, e.g. with ratatui's `TestBackend`.
+///
+/// # Example
+/// ```no_run,ignore
+/// let terminal = render_to(TestBackend::new(40, 5), || rsx! { <Summary /> }).await?;
+/// terminal.backend().assert_buffer_lines(["Done", "", "", "", ""]);
+/// ```
+pub async fn render_to<B, F>(backend: B, initializer: F) -> Result<Terminal<B>>
+where
+    B: Backend,
+    F: Fn() -> Element + 'static,
+{
+    let mut terminal = Terminal::new(backend)?;
+    drive(
+        &mut terminal,
+        initializer,
+        None,
+        None,
+        None,
+        ColorMode::Truecolor,
+        FrameTimings::default(),
+    )
+    .await?;
+    Ok(terminal)
+}
+
+/// Runs the render loop in a terminal set up for `config`
+async fn run<F>(config: RenderConfig, initializer: F) -> Result<()>
 where
     F: Fn() -> Element + 'static,
 {
     // Initialize panic handler
     reratui_panic::setup_panic_handler();
 
+    // Open the recording before taking over the terminal so errors are readable
+    let recorder = config
+        .record
+        .as_deref()
+        .map(CastRecorder::create)
+        .transpose()?;
+
     // Initialize terminal backend
-    let mut terminal = setup_terminal()?;
+    let mut terminal = setup_terminal_with_mode(config.mode)?;
+    if config.capture_output {
+        terminal.capture_output()?;
+    }
 
-    // Create a new hook context for this component tree
-    let hook_context = Rc::new(HookContext::new());
+    // Resolved once the terminal has been asked what it supports
+    let color_mode = config
+        .color_mode
+        .resolve(reratui_hooks::capabilities::use_terminal_capabilities());
+    let timings = config
+        .frame_budget
+        .map_or_else(FrameTimings::default, FrameTimings::new);
+    let result = drive(
+        terminal.terminal_mut(),
+        initializer,
+        recorder,
+        Some(hyperlinks::print_links),
+        Some(graphics::print_graphics),
+        color_mode,
+        timings,
+    )
+    .await;
 
-    // Set the hook context for this thread
-    reratui_hooks::hook_context::set_hook_context(hook_context.clone());
+    // Restore terminal state
+    terminal.restore()?;
+
+    result
+}
 
-    // Create the element
-    let element = initializer();
+/// Runs the render loop against `terminal` until exit is requested
+///
+/// Hyperlinks drawn in each frame are printed with `print_links` and
+/// graphics with `print_graphics`, if given. Colors are converted for
+/// `color_mode` before each frame is written.
+async fn drive<B, F>(
+    terminal: &mut Terminal<B>,
+    initializer: F,
+    mut recorder: Option<CastRecorder<BufWriter<File>>>,
+    print_links: Option<PrintLinks<B>>,
+    print_graphics: Option<PrintGraphics<B>>,
+    color_mode: ColorMode,
+    timings: FrameTimings,
+) -> Result<()>
+where
+    B: Backend,
+    F: Fn() -> Element + 'static,
+{
+    let mut frames = FrameLoop::new(initializer, timings, color_mode);
 
-    // Frame tracking
-    let mut frame_count: u64 = 0;
-    let mut last_frame_time = Instant::now();
+    // The hyperlinks on screen, so unchanged ones aren't printed again
+    let mut links = LinkTracker::default();
+    let mut graphics = GraphicsTracker::default();
 
-    // Create async event stream
-    use crossterm::event::EventStream;
-    use tokio_stream::StreamExt;
-    let mut events = EventStream::new();
+    // Stop on SIGINT/SIGTERM through the exit flag so the terminal is restored
+    let mut shutdown_signals = ShutdownSignals::install()?;
+
+    // Create async event stream, unless there is no terminal to read from
+    let mut events = input_available().then(EventStream::new);
+
+    // Set when the loop stops on an error, returned once the hooks are cleaned up
+    let mut result = Ok(());
+
+    // Deliver the real size as a resize on the first frame, so resize handlers
+    // don't start from a guess
+    let mut initial_resize = terminal
+        .size()
+        .ok()
+        .map(|size| Event::Resize(size.width, size.height));
 
     // Main render loop with continuous rendering
     loop {
-        // Calculate frame timing
-        let current_time = Instant::now();
-        let delta = current_time.duration_since(last_frame_time);
-        last_frame_time = current_time;
-
-        // Reset hook index before each render
-        hook_context.reset_hook_index();
-
-        // Poll for events with timeout (allows continuous rendering)
-        let timeout = tokio::time::sleep(Duration::from_millis(16));
-        tokio::pin!(timeout);
-
-        tokio::select! {
-            Some(Ok(event)) = events.next() => {
-                // Process key events through global event system
-                let processed = if let Event::Key(key_event) = &event {
-                    reratui_hooks::event::global_events::process_global_event(key_event)
-                } else {
-                    false
-                };
-
-                // If not processed as a global event, make it available to components
-                if !processed {
-                    reratui_hooks::event::set_current_event(Some(std::sync::Arc::new(event)));
+        // Whether input arrived this frame, which may change anything on screen
+        let mut received_input = true;
+        // Whether this frame's input is a Ctrl-C that reached the components
+        let mut interrupt_key = false;
+        if let Some(event) = initial_resize.take() {
+            frames.deliver(Some(event));
+        } else {
+            // Poll for events with timeout (allows continuous rendering)
+            let timeout = tokio::time::sleep(Duration::from_millis(16));
+            tokio::pin!(timeout);
+
+            tokio::select! {
+                Some(Ok(event)) = next_event(&mut events) => {
+                    let interrupt = signal::is_interrupt_key(&event);
+                    interrupt_key = !frames.deliver(Some(event)) && interrupt;
+                }
+                code = shutdown_signals.recv() => {
+                    request_exit_with(code);
+                }
+                _ = &mut timeout => {
+                    // Timeout - clear event and continue rendering
+                    frames.deliver(None);
+                    received_input = false;
                 }
-            }
-            _ = &mut timeout => {
-                // Timeout - clear event and continue rendering
-                reratui_hooks::event::set_current_event(None);
             }
         }
 
+        // Stop if a framework-spawned task panicked since the last frame
+        if let Some(panic) = TaskRegistry::global().take_panic() {
+            result = Err(panic.into());
+            break;
+        }
+
         // Check for exit
         if should_exit() {
             break;
         }
 
-        // Render the element
-        terminal.draw(|frame| {
-            // SAFETY: The FrameContext is only used within this render scope
-            // and the frame pointer remains valid for the duration of the draw call
-            let frame_ctx = unsafe { FrameContext::new(frame, frame_count, delta, current_time) };
+        let mut pending_links = Vec::new();
+        let mut graphics_changes = Default::default();
+        let drawn = frames.draw(terminal, received_input).map(|drawn| {
+            if print_links.is_some() {
+                pending_links = links.update(drawn.frame.buffer, drawn.links);
+            }
+            if print_graphics.is_some() {
+                graphics_changes = graphics.update(drawn.frame.area, drawn.graphics);
+            }
+            drawn.frame
+        });
+        let recorded = match (drawn, recorder.as_mut()) {
+            (Ok(frame), Some(recorder)) => recorder.record(frame.buffer),
+            (drawn, _) => drawn.map(|_| ()),
+        };
+        if let Err(error) = recorded {
+            result = Err(error.into());
+            break;
+        }
 
-            // Provide frame context for components
-            let _frame_context = reratui_hooks::context::use_context_provider(|| frame_ctx);
+        // Make the links drawn this frame clickable
+        if let Some(print_links) = print_links
+            && let Err(error) = print_links(terminal.backend_mut(), &pending_links)
+        {
+            result = Err(error.into());
+            break;
+        }
 
-            let area = frame.area();
-            element.render(area, frame.buffer_mut());
-        })?;
+        // Draw the pictures placed this frame over the cells
+        if let Some(print_graphics) = print_graphics
+            && let Err(error) = print_graphics(terminal.backend_mut(), &graphics_changes)
+        {
+            result = Err(error.into());
+            break;
+        }
 
-        // Clean up unmounted components after render
-        reratui_core::component::cleanup_unmounted();
+        frames.finish_frame();
 
-        // Increment frame counter
-        frame_count += 1;
+        // Raw mode delivers Ctrl-C as a key; unless a component consumed it
+        // or the app already asked to exit, it stops the app like SIGINT would
+        if interrupt_key && !reratui_hooks::event::is_current_event_consumed() && !should_exit() {
+            request_exit_with(signal::SIGINT_EXIT_CODE);
+        }
     }
 
-    // Clear the current event
-    reratui_hooks::event::set_current_event(None);
+    // Unmount the components and clean up the hook context
+    drop(frames);
+
+    if let Some(mut recorder) = recorder {
+        recorder.finish()?;
+    }
 
-    // Clean up the hook context
-    reratui_hooks::hook_context::clear_hook_context();
+    result
+}
 
-    // Restore terminal state
-    restore_terminal()?;
+/// Whether crossterm can read input, i.e. a terminal is attached
+fn input_available() -> bool {
+    crossterm::event::poll(Duration::ZERO).is_ok()
+}
 
-    Ok(())
+/// The next input event, or never if input is unavailable
+async fn next_event(events: &mut Option<EventStream>) -> Option<std::io::Result<Event>> {
+    match events {
+        Some(events) => events.next().await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Paragraph, Widget};
+    use reratui_core::{Component, Element, PortalComponent, PortalProps};
+
+    /// Renders a greeting and asks to exit once it has been drawn
+    struct Greeting;
+
+    impl Component for Greeting {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            Paragraph::new("Hello").render(area, buffer);
+            request_exit_with(3);
+        }
+    }
+
+    /// Declares two overlapping portals from a one-cell corner of the tree
+    struct Overlays;
+
+    impl Component for Overlays {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            Paragraph::new("Hello").render(area, buffer);
+
+            let corner = Rect::new(0, 0, 1, 1);
+            let portal = |z_index, text: &'static str| {
+                PortalComponent::new(
+                    PortalProps::default()
+                        .z_index(z_index)
+                        .with_children(vec![Element::widget(Paragraph::new(text))]),
+                )
+            };
+            portal(1, "AB").render(corner, buffer);
+            portal(0, "XYZW").render(corner, buffer);
+            request_exit();
+        }
+    }
+
+    /// Prints the size from the resize delivered on the first frame
+    struct FirstResize;
+
+    impl Component for FirstResize {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            let text = match reratui_hooks::event::use_event() {
+                Some(Event::Resize(width, height)) => format!("{}x{}", width, height),
+                _ => "none".to_string(),
+            };
+            Paragraph::new(text).render(area, buffer);
+            request_exit();
+        }
+    }
+
+    #[test]
+    fn test_render_to_custom_backend() {
+        let _guard = exit::TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        reset_exit();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let terminal = runtime
+            .block_on(render_to(TestBackend::new(10, 2), || Greeting.into()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .assert_buffer_lines(["Hello     ", "          "]);
+        assert_eq!(exit_code(), Some(3));
+        reset_exit();
+    }
+
+    #[test]
+    fn test_portals_render_above_tree() {
+        let _guard = exit::TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        reset_exit();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let terminal = runtime
+            .block_on(render_to(TestBackend::new(10, 1), || Overlays.into()))
+            .unwrap();
+
+        // Portals cover the whole frame and stack by z-index
+        terminal.backend().assert_buffer_lines(["ABZWo     "]);
+        reset_exit();
+    }
+
+    #[test]
+    fn test_first_frame_receives_resize() {
+        let _guard = exit::TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        reset_exit();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let terminal = runtime
+            .block_on(render_to(TestBackend::new(10, 1), || FirstResize.into()))
+            .unwrap();
+
+        terminal.backend().assert_buffer_lines(["10x1      "]);
+        reset_exit();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_signal_dispositions_restored_after_loop() {
+        let _guard = exit::TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        reset_exit();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime
+            .block_on(render_to(TestBackend::new(10, 1), || Greeting.into()))
+            .unwrap();
+
+        // Later Ctrl-C and SIGTERM reach the host program again
+        assert!(signal::default_dispositions());
+        reset_exit();
+    }
 }
@@ -4,39 +4,263 @@
 //! functionality for TUI applications.
 
 use ratatui::crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    cursor::MoveTo,
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io::{self, Stdout};
+use ratatui::{Terminal, TerminalOptions, Viewport, backend::CrosstermBackend};
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(unix)]
+use crate::capture::OutputCapture;
+
+/// Whether keyboard enhancement flags are currently pushed
+static KEYBOARD_ENHANCEMENT_PUSHED: AtomicBool = AtomicBool::new(false);
+
+/// Ask the terminal to report key release and repeat events
+///
+/// Only terminals supporting the kitty keyboard protocol understand this, so
+/// the flags are pushed only if the terminal reported `supported`. Failure is
+/// not an error: hooks such as `use_keys_held` fall back to press-only
+/// tracking.
+fn push_keyboard_enhancement(writer: &mut impl Write, supported: bool) {
+    if !supported {
+        return;
+    }
+
+    let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES;
+    if execute!(writer, PushKeyboardEnhancementFlags(flags)).is_ok() {
+        KEYBOARD_ENHANCEMENT_PUSHED.store(true, Ordering::SeqCst);
+        reratui_hooks::keyboard::set_keyboard_enhancement(true);
+    }
+}
+
+/// Undo [`push_keyboard_enhancement`], at most once
+fn pop_keyboard_enhancement(writer: &mut impl Write) -> io::Result<()> {
+    if KEYBOARD_ENHANCEMENT_PUSHED.swap(false, Ordering::SeqCst) {
+        reratui_hooks::keyboard::set_keyboard_enhancement(false);
+        execute!(writer, PopKeyboardEnhancementFlags)?;
+    }
+    Ok(())
+}
+
+/// The stream a [`ManagedTerminal`] draws to
+///
+/// This is stdout, except while output is captured, when it is a copy of the
+/// original stdout so frames still reach the screen.
+#[derive(Debug, Default)]
+pub struct TerminalOutput {
+    captured: Option<File>,
+}
+
+impl TerminalOutput {
+    /// Draws to `file` instead of stdout
+    pub(crate) fn to_file(file: File) -> Self {
+        Self {
+            captured: Some(file),
+        }
+    }
+}
+
+impl Write for TerminalOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.captured {
+            Some(file) => file.write(buf),
+            None => io::stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.captured {
+            Some(file) => file.flush(),
+            None => io::stdout().flush(),
+        }
+    }
+}
+
+/// How the application occupies the terminal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TerminalMode {
+    /// Take over the whole screen using the alternate screen buffer
+    ///
+    /// The previous terminal contents are restored on exit.
+    #[default]
+    Fullscreen,
+    /// Render in a region of `height` lines below the cursor
+    ///
+    /// The rest of the terminal and its scrollback stay intact, and the last
+    /// frame is left in place on exit. Mouse capture is not enabled, so the
+    /// terminal's own scrolling and selection keep working.
+    Inline {
+        /// Number of lines reserved for the application
+        height: u16,
+    },
+}
+
+impl TerminalMode {
+    /// The ratatui viewport for this mode
+    fn viewport(self) -> Viewport {
+        match self {
+            Self::Fullscreen => Viewport::Fullscreen,
+            Self::Inline { height } => Viewport::Inline(height),
+        }
+    }
+}
 
 /// A managed terminal instance that handles setup and cleanup
 pub struct ManagedTerminal {
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+    terminal: Terminal<CrosstermBackend<TerminalOutput>>,
+    mode: TerminalMode,
+    restored: bool,
+    #[cfg(unix)]
+    capture: Option<OutputCapture>,
 }
 
 impl ManagedTerminal {
-    /// Initialize a new terminal with proper setup
+    /// Initialize a new fullscreen terminal with proper setup
     pub fn new() -> io::Result<Self> {
+        Self::with_mode(TerminalMode::Fullscreen)
+    }
+
+    /// Initialize a new terminal in the given mode
+    pub fn with_mode(mode: TerminalMode) -> io::Result<Self> {
         // Enable raw mode for input handling
         enable_raw_mode()?;
 
-        // Get stdout
-        let mut stdout = io::stdout();
+        // Ask the terminal about itself before anything else reads input
+        let response = crate::terminal_query::query_terminal();
+        reratui_hooks::theme::set_color_scheme(crate::color_scheme::detect_color_scheme(&response));
+        let caps = crate::capabilities::detect_capabilities(
+            &response,
+            matches!(mode, TerminalMode::Fullscreen),
+        );
+        reratui_hooks::capabilities::set_terminal_capabilities(caps);
+
+        // Draw to stdout until output is captured
+        let mut stdout = TerminalOutput::default();
+
+        match mode {
+            // Enter alternate screen to preserve terminal state
+            TerminalMode::Fullscreen => execute!(
+                stdout,
+                EnterAlternateScreen,
+                EnableMouseCapture,
+                EnableFocusChange
+            )?,
+            TerminalMode::Inline { .. } => execute!(stdout, EnableFocusChange)?,
+        }
+
+        // Deliver pasted text as one `Event::Paste` instead of a key per character
+        execute!(stdout, EnableBracketedPaste)?;
 
-        // Enter alternate screen to preserve terminal state
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        // Report key releases where the terminal supports it
+        push_keyboard_enhancement(&mut stdout, caps.keyboard_enhancement);
 
         // Create the terminal backend
         let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
+        let terminal = Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: mode.viewport(),
+            },
+        )?;
+
+        Ok(Self {
+            terminal,
+            mode,
+            restored: false,
+            #[cfg(unix)]
+            capture: None,
+        })
+    }
+
+    /// The mode this terminal was set up with
+    pub fn mode(&self) -> TerminalMode {
+        self.mode
+    }
 
-        Ok(Self { terminal })
+    /// Capture stdout and stderr until the terminal is restored
+    ///
+    /// Printed lines are recorded in the log buffer read by `use_logs` instead
+    /// of scribbling over the frame, and the raw output is written to the
+    /// terminal by [`restore`](Self::restore). Only supported on Unix; a no-op
+    /// elsewhere.
+    pub fn capture_output(&mut self) -> io::Result<()> {
+        #[cfg(unix)]
+        if self.capture.is_none() {
+            self.terminal.backend_mut().flush()?;
+            let capture = OutputCapture::start(reratui_hooks::logging::global_log_buffer())?;
+            *self.terminal.backend_mut().writer_mut() = capture.terminal()?;
+            self.capture = Some(capture);
+        }
+        Ok(())
+    }
+
+    /// Restore the terminal to its original state
+    ///
+    /// In inline mode the last frame stays on screen and the cursor moves to
+    /// the line below it. Captured output is written once the terminal is
+    /// back to normal. Called automatically on drop; later calls do nothing.
+    ///
+    /// Every step runs even if an earlier one fails, so one failed write
+    /// doesn't leave the rest of the terminal modes on. The first error is
+    /// returned.
+    pub fn restore(&mut self) -> io::Result<()> {
+        if self.restored {
+            return Ok(());
+        }
+        self.restored = true;
+
+        let mut result = Ok(());
+        let mut step = |outcome: io::Result<()>| {
+            if result.is_ok() {
+                result = outcome;
+            }
+        };
+
+        step(disable_raw_mode());
+        step(pop_keyboard_enhancement(self.terminal.backend_mut()));
+        step(execute!(self.terminal.backend_mut(), DisableBracketedPaste));
+
+        match self.mode {
+            TerminalMode::Fullscreen => {
+                let backend = self.terminal.backend_mut();
+                step(execute!(backend, LeaveAlternateScreen));
+                step(execute!(backend, DisableMouseCapture));
+                step(execute!(backend, DisableFocusChange));
+            }
+            TerminalMode::Inline { .. } => {
+                let viewport = self.terminal.get_frame().area();
+                let backend = self.terminal.backend_mut();
+                step(execute!(backend, DisableFocusChange));
+                step(execute!(
+                    backend,
+                    MoveTo(0, viewport.bottom().saturating_sub(1))
+                ));
+                // Step past the viewport, scrolling if it ends on the last line
+                step(writeln!(backend).and_then(|()| backend.flush()));
+            }
+        }
+
+        step(self.terminal.show_cursor());
+
+        #[cfg(unix)]
+        if let Some(capture) = self.capture.take() {
+            step(capture.finish());
+        }
+        result
     }
 
     /// Get a mutable reference to the terminal
-    pub fn terminal_mut(&mut self) -> &mut Terminal<CrosstermBackend<Stdout>> {
+    pub fn terminal_mut(&mut self) -> &mut Terminal<CrosstermBackend<TerminalOutput>> {
         &mut self.terminal
     }
 
@@ -66,13 +290,7 @@ impl Drop for ManagedTerminal {
     /// Cleanup terminal state when dropped
     fn drop(&mut self) {
         // Restore terminal state
-        let _ = disable_raw_mode();
-        let _ = execute!(
-            self.terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        );
-        let _ = self.terminal.show_cursor();
+        let _ = self.restore();
     }
 }
 
@@ -81,15 +299,37 @@ pub fn setup_terminal() -> io::Result<ManagedTerminal> {
     ManagedTerminal::new()
 }
 
+/// Initialize terminal for TUI applications in the given mode
+pub fn setup_terminal_with_mode(mode: TerminalMode) -> io::Result<ManagedTerminal> {
+    ManagedTerminal::with_mode(mode)
+}
+
 /// Restore terminal to original state
+///
+/// Like [`ManagedTerminal::restore`], every step runs even if an earlier one
+/// fails, and the first error is returned.
 pub fn restore_terminal() -> io::Result<()> {
-    // Disable raw mode
-    disable_raw_mode()?;
+    let mut result = Ok(());
+    let mut step = |outcome: io::Result<()>| {
+        if result.is_ok() {
+            result = outcome;
+        }
+    };
+    let mut stdout = std::io::stdout();
 
-    // Leave alternate screen and disable mouse capture
-    execute!(std::io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    step(disable_raw_mode());
 
-    Ok(())
+    // Stop reporting key releases before handing the terminal back
+    step(pop_keyboard_enhancement(&mut stdout));
+
+    // Leave alternate screen and disable mouse capture, focus reporting and
+    // bracketed paste
+    step(execute!(stdout, LeaveAlternateScreen));
+    step(execute!(stdout, DisableMouseCapture));
+    step(execute!(stdout, DisableFocusChange));
+    step(execute!(stdout, DisableBracketedPaste));
+
+    result
 }
 
 #[cfg(test)]
@@ -159,6 +399,17 @@ mod tests {
         // Compilation success means methods exist
     }
 
+    /// Test that each mode maps to the matching ratatui viewport
+    #[test]
+    fn test_terminal_mode_viewport() {
+        assert_eq!(TerminalMode::default(), TerminalMode::Fullscreen);
+        assert_eq!(TerminalMode::Fullscreen.viewport(), Viewport::Fullscreen);
+        assert_eq!(
+            TerminalMode::Inline { height: 6 }.viewport(),
+            Viewport::Inline(6)
+        );
+    }
+
     /// Test error handling scenarios
     #[test]
     fn test_error_handling() {
@@ -0,0 +1,292 @@
+//! Session recording to asciinema v2 cast files
+//!
+//! Each drawn frame is written as an output event holding the escape
+//! sequences that turn the previous frame into the new one, so the file can
+//! be replayed with `asciinema play` or converted to a GIF.
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Writes drawn frames as an asciinema v2 cast
+pub(crate) struct CastRecorder<W: Write> {
+    writer: W,
+    started: Instant,
+    /// The last recorded frame, positioned at the origin
+    previous: Option<Buffer>,
+}
+
+impl CastRecorder<BufWriter<File>> {
+    /// Creates the cast file at `path`, replacing any existing file
+    pub(crate) fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> CastRecorder<W> {
+    pub(crate) fn new(writer: W) -> Self {
+        Self {
+            writer,
+            started: Instant::now(),
+            previous: None,
+        }
+    }
+
+    /// Records a drawn frame
+    ///
+    /// The header is written with the size of the first frame; later size
+    /// changes are recorded as resize events followed by a full redraw.
+    pub(crate) fn record(&mut self, buffer: &Buffer) -> io::Result<()> {
+        self.record_at(buffer, self.started.elapsed().as_secs_f64())
+    }
+
+    fn record_at(&mut self, buffer: &Buffer, time: f64) -> io::Result<()> {
+        // Inline viewports start below the prompt; the cast only shows the viewport
+        let mut frame = buffer.clone();
+        frame.area = Rect::new(0, 0, buffer.area.width, buffer.area.height);
+
+        let mut output = String::new();
+        match &self.previous {
+            None => {
+                self.write_header(frame.area)?;
+                output.push_str("\x1b[?25l\x1b[2J");
+                write_cells(&mut output, Buffer::empty(frame.area).diff(&frame));
+            }
+            Some(previous) if previous.area != frame.area => {
+                self.write_event(
+                    time,
+                    "r",
+                    &format!("{}x{}", frame.area.width, frame.area.height),
+                )?;
+                output.push_str("\x1b[2J");
+                write_cells(&mut output, Buffer::empty(frame.area).diff(&frame));
+            }
+            Some(previous) => write_cells(&mut output, previous.diff(&frame)),
+        }
+
+        if !output.is_empty() {
+            self.write_event(time, "o", &output)?;
+        }
+        self.previous = Some(frame);
+        Ok(())
+    }
+
+    /// Resets the terminal style at the end of the cast and flushes it
+    pub(crate) fn finish(&mut self) -> io::Result<()> {
+        if self.previous.is_some() {
+            let time = self.started.elapsed().as_secs_f64();
+            self.write_event(time, "o", "\x1b[0m\x1b[?25h")?;
+        }
+        self.writer.flush()
+    }
+
+    fn write_header(&mut self, area: Rect) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        writeln!(
+            self.writer,
+            r#"{{"version": 2, "width": {}, "height": {}, "timestamp": {}}}"#,
+            area.width, area.height, timestamp
+        )
+    }
+
+    fn write_event(&mut self, time: f64, code: &str, data: &str) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "[{:.6}, \"{}\", {}]",
+            time,
+            code,
+            json_string(data)
+        )
+    }
+}
+
+/// Appends the escape sequences that draw `cells`
+fn write_cells(output: &mut String, cells: Vec<(u16, u16, &Cell)>) {
+    let mut style = None;
+    let mut cursor = None;
+
+    for (x, y, cell) in cells {
+        if cursor != Some((x, y)) {
+            let _ = write!(output, "\x1b[{};{}H", y + 1, x + 1);
+        }
+
+        let cell_style = cell.style();
+        if style != Some(cell_style) {
+            output.push_str(&sgr(cell_style));
+            style = Some(cell_style);
+        }
+
+        output.push_str(cell.symbol());
+        cursor = Some((x + 1, y));
+    }
+}
+
+/// The SGR sequence selecting `style` from a reset state
+fn sgr(style: Style) -> String {
+    let mut codes = vec![String::from("0")];
+
+    const MODIFIERS: [(Modifier, &str); 9] = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+    for (modifier, code) in MODIFIERS {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+
+    if let Some(fg) = style.fg.and_then(|color| color_code(color, false)) {
+        codes.push(fg);
+    }
+    if let Some(bg) = style.bg.and_then(|color| color_code(color, true)) {
+        codes.push(bg);
+    }
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// The SGR parameter for a foreground or background color
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let extended = if background { 48 } else { 38 };
+
+    let basic = |code: u8| Some((code + offset).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => basic(30),
+        Color::Red => basic(31),
+        Color::Green => basic(32),
+        Color::Yellow => basic(33),
+        Color::Blue => basic(34),
+        Color::Magenta => basic(35),
+        Color::Cyan => basic(36),
+        Color::Gray => basic(37),
+        Color::DarkGray => basic(90),
+        Color::LightRed => basic(91),
+        Color::LightGreen => basic(92),
+        Color::LightYellow => basic(93),
+        Color::LightBlue => basic(94),
+        Color::LightMagenta => basic(95),
+        Color::LightCyan => basic(96),
+        Color::White => basic(97),
+        Color::Indexed(index) => Some(format!("{};5;{}", extended, index)),
+        Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", extended, r, g, b)),
+    }
+}
+
+/// Encodes `value` as a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(width: u16, text: &str, style: Style) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+        buffer.set_string(0, 0, text, style);
+        buffer
+    }
+
+    fn lines(recorder: CastRecorder<Vec<u8>>) -> Vec<String> {
+        String::from_utf8(recorder.writer)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+        assert_eq!(json_string("\x1b[0m"), r#""\u001b[0m""#);
+    }
+
+    #[test]
+    fn test_sgr_for_styles() {
+        assert_eq!(sgr(Style::default()), "\x1b[0m");
+        assert_eq!(
+            sgr(Style::default()
+                .fg(Color::Red)
+                .bg(Color::Rgb(1, 2, 3))
+                .add_modifier(Modifier::BOLD)),
+            "\x1b[0;1;31;48;2;1;2;3m"
+        );
+    }
+
+    #[test]
+    fn test_records_header_and_frame_diffs() {
+        let mut recorder = CastRecorder::new(Vec::new());
+
+        recorder
+            .record_at(&frame(4, "Hi", Style::default()), 0.0)
+            .unwrap();
+        recorder
+            .record_at(&frame(4, "Ho", Style::default()), 0.5)
+            .unwrap();
+        // An unchanged frame produces no event
+        recorder
+            .record_at(&frame(4, "Ho", Style::default()), 0.75)
+            .unwrap();
+
+        let lines = lines(recorder);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(r#"{"version": 2, "width": 4, "height": 1, "timestamp": "#));
+        assert_eq!(
+            lines[1],
+            r#"[0.000000, "o", "\u001b[?25l\u001b[2J\u001b[1;1H\u001b[0mHi"]"#
+        );
+        assert_eq!(lines[2], r#"[0.500000, "o", "\u001b[1;2H\u001b[0mo"]"#);
+    }
+
+    #[test]
+    fn test_records_resize() {
+        let mut recorder = CastRecorder::new(Vec::new());
+
+        recorder
+            .record_at(&frame(2, "A", Style::default()), 0.0)
+            .unwrap();
+        recorder
+            .record_at(&frame(3, "B", Style::default()), 1.0)
+            .unwrap();
+
+        let lines = lines(recorder);
+        assert_eq!(lines[2], r#"[1.000000, "r", "3x1"]"#);
+        assert_eq!(
+            lines[3],
+            r#"[1.000000, "o", "\u001b[2J\u001b[1;1H\u001b[0mB"]"#
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Shutdown signal handling for the render loop
+//!
+//! While the render loop runs, SIGINT and SIGTERM (Ctrl-C and Ctrl-Break on
+//! Windows) request a normal exit instead of killing the process, so the
+//! terminal is restored on the way out.
+//!
+//! In raw mode the terminal doesn't turn Ctrl-C into SIGINT: it arrives as a
+//! key press instead. The loop treats that key like SIGINT when no global key
+//! handler takes it and no component consumes it, so apps that want Ctrl-C
+//! for something else consume it through `use_event_handle`.
+//!
+//! On unix, tokio installs its signal handlers process-wide and never removes
+//! them, so the dispositions SIGINT and SIGTERM had before the first live
+//! [`ShutdownSignals`] are put back once the last one is dropped. Otherwise
+//! the host program would silently swallow both signals after the loop exits.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::io;
+
+/// Exit code reported for SIGINT, following the shell's `128 + signal` convention
+pub(crate) const SIGINT_EXIT_CODE: i32 = 130;
+
+/// Exit code reported for SIGTERM
+#[cfg_attr(not(unix), allow(dead_code))]
+pub(crate) const SIGTERM_EXIT_CODE: i32 = 143;
+
+/// Whether `event` is the Ctrl-C key press raw mode delivers instead of SIGINT
+pub(crate) fn is_interrupt_key(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) if *modifiers == KeyModifiers::CONTROL
+    )
+}
+
+/// Listeners for the signals that should stop the render loop
+pub(crate) struct ShutdownSignals {
+    #[cfg(unix)]
+    interrupt: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    terminate: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    _dispositions: dispositions::Guard,
+    #[cfg(windows)]
+    ctrl_c: tokio::signal::windows::CtrlC,
+    #[cfg(windows)]
+    ctrl_break: tokio::signal::windows::CtrlBreak,
+}
+
+impl ShutdownSignals {
+    /// Starts listening for shutdown signals
+    ///
+    /// Must be called from within a tokio runtime.
+    pub(crate) fn install() -> io::Result<Self> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            dispositions::Guard::acquire(|| {
+                Ok((
+                    signal(SignalKind::interrupt())?,
+                    signal(SignalKind::terminate())?,
+                ))
+            })
+            .map(|((interrupt, terminate), guard)| Self {
+                interrupt,
+                terminate,
+                _dispositions: guard,
+            })
+        }
+
+        #[cfg(windows)]
+        {
+            use tokio::signal::windows::{ctrl_break, ctrl_c};
+
+            Ok(Self {
+                ctrl_c: ctrl_c()?,
+                ctrl_break: ctrl_break()?,
+            })
+        }
+    }
+
+    /// Waits for the next shutdown signal and returns the exit code it implies
+    pub(crate) async fn recv(&mut self) -> i32 {
+        #[cfg(unix)]
+        {
+            tokio::select! {
+                _ = self.interrupt.recv() => SIGINT_EXIT_CODE,
+                _ = self.terminate.recv() => SIGTERM_EXIT_CODE,
+            }
+        }
+
+        #[cfg(windows)]
+        {
+            tokio::select! {
+                _ = self.ctrl_c.recv() => SIGINT_EXIT_CODE,
+                _ = self.ctrl_break.recv() => SIGINT_EXIT_CODE,
+            }
+        }
+    }
+}
+
+/// Puts back the SIGINT and SIGTERM dispositions tokio replaces
+#[cfg(unix)]
+mod dispositions {
+    use std::io;
+    use std::ptr;
+    use std::sync::Mutex;
+
+    const SIGNALS: [libc::c_int; 2] = [libc::SIGINT, libc::SIGTERM];
+
+    type Actions = [libc::sigaction; 2];
+
+    struct Dispositions {
+        /// Number of live guards
+        guards: usize,
+        /// What the signals did before the first live guard
+        previous: Option<Actions>,
+        /// tokio's handlers, which tokio installs only once per process
+        tokio: Option<Actions>,
+    }
+
+    static DISPOSITIONS: Mutex<Dispositions> = Mutex::new(Dispositions {
+        guards: 0,
+        previous: None,
+        tokio: None,
+    });
+
+    /// Keeps tokio's handlers installed while held
+    pub(super) struct Guard(());
+
+    impl Guard {
+        /// Runs `listen`, which creates tokio listeners, with tokio's handlers installed
+        pub(super) fn acquire<T>(listen: impl FnOnce() -> io::Result<T>) -> io::Result<(T, Self)> {
+            let mut dispositions = DISPOSITIONS.lock().unwrap_or_else(|e| e.into_inner());
+            let previous = current()?;
+
+            let listeners = listen()?;
+            match dispositions.tokio {
+                // tokio won't register again after an earlier restore
+                Some(tokio) => install(&tokio)?,
+                None => dispositions.tokio = Some(current()?),
+            }
+
+            if dispositions.guards == 0 {
+                dispositions.previous = Some(previous);
+            }
+            dispositions.guards += 1;
+            Ok((listeners, Self(())))
+        }
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let mut dispositions = DISPOSITIONS.lock().unwrap_or_else(|e| e.into_inner());
+            dispositions.guards -= 1;
+            if dispositions.guards == 0
+                && let Some(previous) = dispositions.previous.take()
+            {
+                let _ = install(&previous);
+            }
+        }
+    }
+
+    /// The current actions for [`SIGNALS`]
+    pub(super) fn current() -> io::Result<Actions> {
+        // SAFETY: `sigaction` is plain data, fully written by the call below
+        let mut actions: Actions = unsafe { std::mem::zeroed() };
+        for (signal, action) in SIGNALS.into_iter().zip(&mut actions) {
+            // SAFETY: A null new action only reads the current one into `action`
+            if unsafe { libc::sigaction(signal, ptr::null(), action) } == -1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(actions)
+    }
+
+    fn install(actions: &Actions) -> io::Result<()> {
+        for (signal, action) in SIGNALS.into_iter().zip(actions) {
+            // SAFETY: `action` was read back from `sigaction`, so it is a valid action
+            if unsafe { libc::sigaction(signal, action, ptr::null_mut()) } == -1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether SIGINT and SIGTERM currently have their default dispositions
+#[cfg(all(test, unix))]
+pub(crate) fn default_dispositions() -> bool {
+    dispositions::current()
+        .unwrap()
+        .iter()
+        .all(|action| action.sa_sigaction == libc::SIG_DFL)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Sends SIGTERM to this process and waits for `signals` to see it
+    fn send_sigterm(runtime: &tokio::runtime::Runtime, signals: &mut ShutdownSignals) -> i32 {
+        let status = std::process::Command::new("kill")
+            .args(["-TERM", &std::process::id().to_string()])
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        runtime
+            .block_on(tokio::time::timeout(Duration::from_secs(5), signals.recv()))
+            .expect("SIGTERM should be delivered to the listener")
+    }
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sigterm_is_received() {
+        // Loops in other tests would stop on this signal
+        let _guard = crate::exit::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        let runtime = runtime();
+        let _enter = runtime.enter();
+        let mut signals = ShutdownSignals::install().unwrap();
+        assert_eq!(send_sigterm(&runtime, &mut signals), SIGTERM_EXIT_CODE);
+    }
+
+    #[test]
+    fn test_dispositions_restored_after_last_drop() {
+        let _guard = crate::exit::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        assert!(default_dispositions());
+
+        let runtime = runtime();
+        let _enter = runtime.enter();
+        let first = ShutdownSignals::install().unwrap();
+        let second = ShutdownSignals::install().unwrap();
+        assert!(!default_dispositions());
+
+        drop(first);
+        assert!(!default_dispositions(), "a listener is still live");
+        drop(second);
+        assert!(default_dispositions());
+
+        // tokio's handler is reinstalled, so signals still reach a new listener
+        let mut signals = ShutdownSignals::install().unwrap();
+        assert!(!default_dispositions());
+        assert_eq!(send_sigterm(&runtime, &mut signals), SIGTERM_EXIT_CODE);
+        drop(signals);
+        assert!(default_dispositions());
+    }
+
+    #[test]
+    fn test_ctrl_c_key_is_interrupt() {
+        let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+        assert!(is_interrupt_key(&key(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!is_interrupt_key(&key(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_interrupt_key(&key(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+
+        let mut release = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        release.kind = KeyEventKind::Release;
+        assert!(!is_interrupt_key(&Event::Key(release)));
+    }
+}
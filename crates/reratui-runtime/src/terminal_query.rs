@@ -0,0 +1,112 @@
+//! Asking the terminal about itself at startup
+//!
+//! Everything the runtime wants to know is asked in one go, ending with a
+//! primary device attributes (DA1) query. Virtually every terminal answers
+//! DA1, and terminals answer in order, so the DA1 reply means every other
+//! answer has arrived and the query ends without waiting for the timeout.
+//! Queries a terminal doesn't understand are ignored, leaving their answer
+//! missing from the response.
+
+use std::time::Duration;
+
+/// How long to wait for the terminal to answer
+const QUERY_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// The queries, in the order of their answers:
+/// - OSC 11, the background color
+/// - a Kitty graphics query for a 1x1 image that is never stored
+/// - the kitty keyboard protocol flags
+/// - DA1
+const QUERY: &[u8] = b"\x1b]11;?\x07\x1b_Gi=31,s=1,v=1,a=q,t=d,f=24;AAAA\x1b\\\x1b[?u\x1b[c";
+
+/// Sends the startup queries and returns the terminal's answers
+///
+/// Raw mode must be enabled. The response is empty when there is no terminal
+/// to ask.
+#[cfg(unix)]
+pub(crate) fn query_terminal() -> Vec<u8> {
+    use std::fs::OpenOptions;
+    use std::io::{Read, Write};
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let Ok(mut tty) = OpenOptions::new().read(true).write(true).open("/dev/tty") else {
+        return Vec::new();
+    };
+    if tty.write_all(QUERY).and_then(|()| tty.flush()).is_err() {
+        return Vec::new();
+    }
+
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    let mut chunk = [0u8; 256];
+
+    while !has_device_attributes(&response) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        let mut poll_fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `poll_fd` is a valid pollfd for the duration of the call
+        let ready = unsafe { libc::poll(&mut poll_fd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+
+        match tty.read(&mut chunk) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => response.extend_from_slice(&chunk[..read]),
+        }
+    }
+
+    response
+}
+
+#[cfg(not(unix))]
+pub(crate) fn query_terminal() -> Vec<u8> {
+    Vec::new()
+}
+
+/// The parameters and final byte of each private CSI reply
+/// (`ESC [ ? params final`) in `response`
+pub(crate) fn private_replies(response: &[u8]) -> impl Iterator<Item = (&[u8], u8)> {
+    response
+        .split(|&byte| byte == 0x1b)
+        .filter_map(|sequence| sequence.strip_prefix(b"[?"))
+        .filter_map(|reply| {
+            let end = reply
+                .iter()
+                .position(|byte| !(byte.is_ascii_digit() || *byte == b';'))?;
+            Some((&reply[..end], reply[end]))
+        })
+}
+
+/// Checks if the DA1 reply (`ESC [ ? ... c`) has been received
+fn has_device_attributes(response: &[u8]) -> bool {
+    private_replies(response).any(|(_, last)| last == b'c')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_device_attributes() {
+        assert!(has_device_attributes(b"\x1b]11;rgb:0/0/0\x07\x1b[?62;22c"));
+        assert!(has_device_attributes(b"\x1b[?1u\x1b[?62;4c"));
+        assert!(!has_device_attributes(b"\x1b]11;rgb:0/0/0\x07\x1b[?62;2"));
+        assert!(!has_device_attributes(b"\x1b]11;rgb:cccc/0/0\x07\x1b[?1u"));
+        assert!(!has_device_attributes(b""));
+    }
+
+    #[test]
+    fn test_private_replies() {
+        let replies: Vec<_> = private_replies(b"\x1b_Gi=31;OK\x1b\\\x1b[?15u\x1b[?62;4c").collect();
+        assert_eq!(replies, [(&b"15"[..], b'u'), (&b"62;4"[..], b'c')]);
+    }
+}
@@ -0,0 +1,380 @@
+//! Driving an application from tests
+//!
+//! [`TestHarness`] renders a component tree into a `TestBackend` one frame at
+//! a time, with input scripted by the test instead of read from a terminal.
+
+use crate::color_mode::ColorMode;
+use crate::frame_loop::FrameLoop;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use reratui_core::Element;
+use reratui_core::snapshot::{snapshot, styled_snapshot};
+use reratui_hooks::clock::{self, Clock, MockClock};
+use reratui_hooks::frame::FrameTimings;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often spawned tasks get to run after the clock moves, enough for a
+/// woken task to run its callback and start sleeping again
+const SETTLE_YIELDS: usize = 8;
+
+/// Renders an application frame by frame with scripted input
+///
+/// Each `send_*` method delivers one event and draws one frame with it,
+/// through the same steps as the render loop: keys go to the global key
+/// handlers first, components see the event through `use_event`, and the
+/// frame is followed by the usual cleanup, transitions and event bus
+/// delivery. The first frame is drawn on creation, with the resize event the
+/// render loop starts with.
+///
+/// Hooks that spawn tasks, such as `use_future`, need a tokio runtime, so
+/// tests using them run under `#[tokio::test]` and call [`tick`](Self::tick)
+/// to let the tasks make progress between frames.
+///
+/// Time stands still in the harness: it installs a [`MockClock`] that
+/// timeouts, intervals, stopwatches and frame timestamps read, and only
+/// [`advance_time`](Self::advance_time) moves it forward.
+///
+/// The harness installs its hook context and clock on the current thread
+/// until it is dropped, so create one harness at a time per thread.
+///
+/// # Example
+/// ```no_run,ignore
+/// let mut harness = TestHarness::new(30, 3, || rsx! { <NameForm /> });
+/// harness.send_text("hello");
+/// harness.send_key(KeyCode::Enter, KeyModifiers::NONE);
+/// assert_eq!(harness.snapshot(), "Hello, hello!");
+///
+/// harness.advance_time(Duration::from_secs(5)).await;
+/// assert_eq!(harness.snapshot(), "Session expired");
+/// ```
+pub struct TestHarness {
+    // Dropped first, so components unmount before the terminal goes away
+    frames: FrameLoop,
+    terminal: Terminal<TestBackend>,
+    clock: MockClock,
+}
+
+impl TestHarness {
+    /// Renders the first frame of the tree created by `initializer` on a
+    /// `width` by `height` screen
+    pub fn new<F>(width: u16, height: u16, initializer: F) -> Self
+    where
+        F: FnOnce() -> Element,
+    {
+        let terminal = Terminal::new(TestBackend::new(width, height))
+            .expect("creating a terminal on a TestBackend cannot fail");
+        let clock = MockClock::new();
+        clock::set_clock(Arc::new(clock.clone()));
+        let frames = FrameLoop::new(initializer, FrameTimings::default(), ColorMode::Truecolor);
+        let mut harness = Self {
+            frames,
+            terminal,
+            clock,
+        };
+        harness.send_event(Event::Resize(width, height));
+        harness
+    }
+
+    /// Delivers `event` and draws a frame with it
+    pub fn send_event(&mut self, event: Event) {
+        self.frames.deliver(Some(event));
+        self.frame(true);
+    }
+
+    /// Presses `code` with `modifiers` held
+    pub fn send_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        self.send_event(Event::Key(KeyEvent::new(code, modifiers)));
+    }
+
+    /// Types `text`, one key press and frame per character
+    ///
+    /// Uppercase letters are sent with `SHIFT` held, as terminals report them.
+    pub fn send_text(&mut self, text: &str) {
+        for character in text.chars() {
+            let modifiers = if character.is_uppercase() {
+                KeyModifiers::SHIFT
+            } else {
+                KeyModifiers::NONE
+            };
+            self.send_key(KeyCode::Char(character), modifiers);
+        }
+    }
+
+    /// Delivers a mouse event and draws a frame with it
+    pub fn send_mouse(&mut self, event: MouseEvent) {
+        self.send_event(Event::Mouse(event));
+    }
+
+    /// Lets spawned tasks run, then draws a frame without input
+    ///
+    /// This is a frame where the render loop's wait for input timed out.
+    /// Tasks still waiting on timers need time to pass first, e.g. with
+    /// `tokio::time::advance` in a test with paused time.
+    pub async fn tick(&mut self) {
+        tokio::task::yield_now().await;
+        self.frames.deliver(None);
+        self.frame(false);
+    }
+
+    /// Moves the clock forward by `duration`, then draws a frame
+    ///
+    /// The clock stops at every timer due along the way, so spawned tasks
+    /// run in order and see the time they were waiting for: a 100ms interval
+    /// advanced by one second ticks ten times. The frame is drawn as in
+    /// [`tick`](Self::tick).
+    pub async fn advance_time(&mut self, duration: Duration) {
+        let target = self.clock.now() + duration;
+        // Let tasks spawned by the last frame start waiting first
+        settle().await;
+        while let Some(deadline) = self.clock.next_deadline().filter(|due| *due <= target) {
+            self.clock.advance_to(deadline);
+            settle().await;
+        }
+        self.clock.advance_to(target);
+        settle().await;
+        self.tick().await;
+    }
+
+    /// The clock the application reads, which only moves when advanced
+    pub fn clock(&self) -> &MockClock {
+        &self.clock
+    }
+
+    /// The last frame drawn
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// The characters of the last frame, see
+    /// [`snapshot`](reratui_core::snapshot::snapshot)
+    pub fn snapshot(&self) -> String {
+        snapshot(self.buffer())
+    }
+
+    /// The characters and styles of the last frame, see
+    /// [`styled_snapshot`](reratui_core::snapshot::styled_snapshot)
+    pub fn styled_snapshot(&self) -> String {
+        styled_snapshot(self.buffer())
+    }
+
+    /// Draws a frame with the input delivered last
+    fn frame(&mut self, received_input: bool) {
+        self.frames
+            .draw(&mut self.terminal, received_input)
+            .expect("drawing to a TestBackend cannot fail");
+        self.frames.finish_frame();
+    }
+}
+
+impl Drop for TestHarness {
+    fn drop(&mut self) {
+        clock::clear_clock();
+    }
+}
+
+/// Yields to spawned tasks until woken ones had a chance to finish
+async fn settle() {
+    for _ in 0..SETTLE_YIELDS {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Paragraph, Widget};
+    use reratui_core::Component;
+    use reratui_hooks::event::use_key_event;
+    use reratui_hooks::future::use_future;
+    use reratui_hooks::interval::use_interval;
+    use reratui_hooks::state::use_state;
+    use reratui_hooks::timeout::use_timeout;
+
+    /// Echoes typed text, greeting it once Enter is pressed
+    struct NameForm;
+
+    impl Component for NameForm {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            let (name, set_name) = use_state(String::new);
+            let (submitted, set_submitted) = use_state(|| false);
+            match use_key_event().map(|key| key.code) {
+                Some(KeyCode::Char(character)) => {
+                    set_name.update(|name| format!("{name}{character}"))
+                }
+                Some(KeyCode::Backspace) => set_name.update(|name| {
+                    let mut name = name.clone();
+                    name.pop();
+                    name
+                }),
+                Some(KeyCode::Enter) => set_submitted.set(true),
+                _ => {}
+            }
+
+            let text = if submitted.get() {
+                format!("Hello, {}!", name.get())
+            } else {
+                format!("> {}", name.get())
+            };
+            Paragraph::new(text).render(area, buffer);
+        }
+    }
+
+    /// Shows the result of a future that resolves right away
+    struct Loader;
+
+    impl Component for Loader {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            let data = use_future::<(), _, _, _, String>(|_| async { Ok("loaded") }, ());
+            Paragraph::new(data.value().unwrap_or("loading")).render(area, buffer);
+        }
+    }
+
+    /// Like [`Loader`], loading again each time `r` is pressed
+    struct Reloader;
+
+    impl Component for Reloader {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            let (loads, set_loads) = use_state(|| 0);
+            if use_key_event().is_some_and(|key| key.code == KeyCode::Char('r')) {
+                set_loads.update(|loads| loads + 1);
+            }
+            let data = use_future::<_, _, _, _, String>(|_| async { Ok("loaded") }, loads.get());
+            Paragraph::new(data.value().unwrap_or("loading")).render(area, buffer);
+        }
+    }
+
+    /// Waits forever, counting the key events it receives
+    struct Stuck {
+        keys: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Component for Stuck {
+        fn render(&self, _area: Rect, _buffer: &mut Buffer) {
+            use_future::<(), _, _, _, String>(|_| std::future::pending::<Result<(), String>>(), ());
+            if use_key_event().is_some() {
+                self.keys.set(self.keys.get() + 1);
+            }
+        }
+    }
+
+    /// Expires after five seconds, counting ticks of a 100ms interval
+    struct Session;
+
+    impl Component for Session {
+        fn render(&self, area: Rect, buffer: &mut Buffer) {
+            let (expired, set_expired) = use_state(|| false);
+            let (ticks, set_ticks) = use_state(|| 0);
+            use_timeout(move || set_expired.set(true), Duration::from_secs(5));
+            use_interval(
+                move || set_ticks.update(|ticks| ticks + 1),
+                Duration::from_millis(100),
+            );
+
+            let status = if expired.get() { "expired" } else { "active" };
+            Paragraph::new(format!("{status} {}", ticks.get())).render(area, buffer);
+        }
+    }
+
+    #[test]
+    fn test_scripted_typing() {
+        let mut harness = TestHarness::new(16, 1, || NameForm.into());
+        assert_eq!(harness.snapshot(), ">");
+
+        harness.send_text("Bobx");
+        harness.send_key(KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(harness.snapshot(), "> Bob");
+
+        harness.send_key(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(harness.snapshot(), "Hello, Bob!");
+    }
+
+    #[tokio::test]
+    async fn test_tick_lets_futures_resolve() {
+        let mut harness = TestHarness::new(10, 1, || Loader.into());
+        assert_eq!(harness.snapshot(), "loading");
+
+        harness.tick().await;
+        harness.tick().await;
+        assert_eq!(harness.snapshot(), "loaded");
+    }
+
+    #[tokio::test]
+    async fn test_suspense_waits_for_futures() {
+        let mut harness = TestHarness::new(10, 1, || {
+            let fallback = Element::widget(Paragraph::new("waiting"));
+            Element::component(
+                reratui_core::Suspense::new(
+                    reratui_core::SuspenseProps::default().fallback(fallback),
+                )
+                .with_children(vec![Loader.into()]),
+            )
+        });
+        assert_eq!(harness.snapshot(), "waiting");
+
+        harness.tick().await;
+        harness.tick().await;
+        assert_eq!(harness.snapshot(), "loaded");
+    }
+
+    #[tokio::test]
+    async fn test_suspense_keeps_content_while_refreshing() {
+        let mut harness = TestHarness::new(10, 1, || {
+            let fallback = Element::widget(Paragraph::new("waiting"));
+            Element::component(
+                reratui_core::Suspense::new(
+                    reratui_core::SuspenseProps::default().fallback(fallback),
+                )
+                .with_children(vec![Element::component(Reloader)]),
+            )
+        });
+        harness.tick().await;
+        harness.tick().await;
+        assert_eq!(harness.snapshot(), "loaded");
+
+        // The reload is pending, but the boundary keeps showing its children
+        harness.send_key(KeyCode::Char('r'), KeyModifiers::NONE);
+        assert_eq!(harness.snapshot(), "loading");
+
+        harness.tick().await;
+        harness.tick().await;
+        assert_eq!(harness.snapshot(), "loaded");
+    }
+
+    #[tokio::test]
+    async fn test_suspended_children_receive_no_input() {
+        let keys = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut harness = TestHarness::new(10, 1, {
+            let keys = keys.clone();
+            move || {
+                Element::component(
+                    reratui_core::Suspense::new(
+                        reratui_core::SuspenseProps::default().fallback(NameForm),
+                    )
+                    .with_children(vec![Element::component(Stuck { keys: keys.clone() })]),
+                )
+            }
+        });
+        assert_eq!(harness.snapshot(), ">");
+
+        // Typing reaches the fallback, not the hidden children
+        harness.send_text("ab");
+        assert_eq!(harness.snapshot(), "> ab");
+        assert_eq!(keys.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_advance_time_fires_timers() {
+        let mut harness = TestHarness::new(16, 1, || Session.into());
+        assert_eq!(harness.snapshot(), "active 0");
+
+        harness.advance_time(Duration::from_millis(350)).await;
+        assert_eq!(harness.snapshot(), "active 3");
+
+        harness.advance_time(Duration::from_millis(4650)).await;
+        assert_eq!(harness.snapshot(), "expired 50");
+    }
+}
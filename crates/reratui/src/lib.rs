@@ -50,14 +50,35 @@
 //! ## Available Hooks
 //!
 //! - [`use_state`] - Local component state
+//! - [`batch`] - Apply several state updates before the next frame
 //! - [`use_reducer`] - Complex state with actions
+//! - [`use_persistent_reducer`] - Reducer state saved across restarts
+//! - [`use_reducer_devtools`] - Reducer with a time-travel log for debugging
 //! - [`use_effect`] - Side effects with dependencies
+//! - [`use_mount`], [`use_unmount`] - Run code when a component mounts and unmounts
 //! - [`use_context`] - Share data across components
 //! - [`use_ref`] - Mutable references
 //! - [`use_callback`](hooks::callback) - Memoized callbacks
+//! - [`use_computed`] - Values derived from other state
+//! - [`use_debounced_callback`] - Callbacks that run once calls settle
 //! - [`use_event`] - Terminal event handling
+//! - [`use_key_event`], [`use_mouse_event`], [`use_paste_event`] - Events of one kind
+//! - [`use_paste`] - Pasted text, delivered as one event
+//! - [`use_command`] - Named key bindings listed by a `HelpOverlay`
+//! - [`use_hotkey`](hooks::keyboard::use_hotkey) - Key bindings scoped to a focused or visible panel
+//! - [`use_event_bus`] - Typed messages between components
 //! - [`use_frame`] - Frame timing and context
 //! - [`use_area`] - Component rendering area
+//! - [`use_measure`] - Size of the content a component rendered last
+//! - [`use_viewport`] - Full terminal area
+//! - [`use_terminal_capabilities`] - Truecolor, graphics and keyboard support of the terminal
+//! - [`use_is_visible`] - Whether a component is scrolled into view
+//! - [`use_list_selection`] - Keyboard-driven list selection
+//! - [`use_active_tab`] - Active tab of a `TabView`
+//! - [`use_logs`] - Recent entries recorded by [`logging::init_logging`]
+//! - [`use_theme`] - Shared colors and modifiers from a `ThemeProvider`
+//! - [`use_toast`] - Timed notifications shown by a `ToastProvider`
+//! - [`use_confirm`] - Confirmation dialogs awaited as futures
 //!
 //! ## Component Patterns
 //!
@@ -145,7 +166,7 @@
 //!
 //! - **counter** - Basic state management and event handling
 //! - **rsx_demo** - Comprehensive RSX macro features
-//! - **router** - Navigation and routing (coming soon)
+//! - **router** - Navigation and routing with `reratui-router`
 
 // Re-export core types
 pub use reratui_core as core;
@@ -160,27 +181,65 @@ pub use reratui_runtime as runtime;
 pub use reratui_macro::{Props, component, rsx};
 
 // Re-export commonly used core types at the top level
-pub use reratui_core::{AnyWidget, Component, ComponentProps, Element, PropValue};
+pub use reratui_core::{
+    AnyWidget, AutoLayout, AutoLayoutComponent, AutoLayoutProps, Component, ComponentProps,
+    Element, For, ForComponent, ForProps, Hyperlink, Portal, PortalComponent, PortalProps,
+    PropValue, Show, ShowComponent, ShowProps, Suspense, SuspenseComponent, SuspenseProps,
+    auto_constraints,
+};
 
 // Re-export commonly used hook types
 pub use reratui_hooks::{
     area::use_area,
+    async_action::use_async,
+    bus::{EventBus, use_event_bus},
     callback::Callback,
-    context::{use_context, use_context_provider},
-    effect::{use_effect, use_effect_always, use_effect_once},
-    event::use_event,
+    capabilities::{TerminalCaps, use_terminal_capabilities},
+    clock::{Clock, MockClock},
+    command_palette::{CommandPalette, PaletteCommand},
+    confirm::{ConfirmProvider, use_confirm},
+    context::{use_context, use_context_provider, use_context_selector},
+    countdown::use_countdown,
+    data_table::{Column, DataTable},
+    debounce::use_debounced_callback,
+    deferred::use_deferred_value,
+    effect::{use_effect, use_effect_always, use_effect_once, use_mount, use_unmount},
+    event::{
+        EventHandle,
+        global_events::{KeyBinding, KeymapRegistry},
+        use_event, use_event_filtered, use_event_handle, use_key_event, use_mouse_event, use_paste,
+        use_paste_event,
+    },
+    focus::{use_focus, use_focus_manager},
     form::use_form,
     frame::use_frame,
-    future::use_future,
+    future::{use_future, use_future_with_options},
     history::use_history,
     id::use_id,
-    memo::use_memo,
+    idle::use_idle,
+    keymap::{HelpOverlay, use_command, use_keymap},
+    list_selection::use_list_selection,
+    logging::{self, LogOverlay, use_logs},
+    measure::use_measure,
+    memo::{use_computed, use_memo},
+    modal::Modal,
     mutation::use_mutation,
-    query::use_query,
-    reducer::use_reducer,
-    ref_hook::use_ref,
-    state::use_state,
+    progress::ProgressBar,
+    query::{use_query, use_query_select},
+    reducer::{DevtoolsHandle, use_persistent_reducer, use_reducer, use_reducer_devtools},
+    ref_hook::{use_forward_ref, use_ref},
+    scroll::{ScrollView, VirtualList, use_scroll},
+    spinner::{Spinner, SpinnerVariant, use_spinner},
+    state::{batch, use_state},
+    stopwatch::use_stopwatch,
+    tabs::{TabView, use_active_tab},
+    text_input::TextInput,
+    theme::{ColorScheme, Theme, use_color_scheme, use_theme},
     timeout::use_timeout,
+    toast::{ToastProvider, use_toast},
+    transition::use_transition,
+    viewport::use_viewport,
+    visibility::use_is_visible,
 };
 
 // Re-export ratatui for use by the rsx macro and user convenience
@@ -191,37 +250,81 @@ pub use crossterm;
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::core::{AnyWidget, Component, ComponentProps, Element, PropValue};
+    pub use crate::core::damage::Damage;
+    pub use crate::core::text::{display_width, pad_to_width, truncate_to_width};
+    pub use crate::core::{
+        AnyWidget, AutoLayout, AutoLayoutComponent, AutoLayoutProps, Component, ComponentProps,
+        Element, For, ForComponent, ForProps, Hyperlink, Portal, PortalComponent, PortalProps,
+        PropValue, Show, ShowComponent, ShowProps, Suspense, SuspenseComponent, SuspenseProps,
+        auto_constraints,
+    };
     pub use crate::{component, render, rsx};
 
     // Re-export hooks
     pub use crate::hooks::area::*;
+    pub use crate::hooks::async_action::*;
+    pub use crate::hooks::bus::*;
     pub use crate::hooks::callback::*;
+    pub use crate::hooks::capabilities::*;
+    pub use crate::hooks::command_palette::*;
+    pub use crate::hooks::confirm::*;
     pub use crate::hooks::context::*;
+    pub use crate::hooks::countdown::*;
+    pub use crate::hooks::data_table::*;
+    pub use crate::hooks::debounce::*;
+    pub use crate::hooks::deferred::*;
     pub use crate::hooks::effect::*;
-    pub use crate::hooks::event::{global_events::on_global_event, use_event};
+    pub use crate::hooks::event::{
+        EventHandle,
+        global_events::{
+            Command, KeyBinding, KeymapRegistry, on_global_event, on_global_event_with_modifiers,
+        },
+        use_event, use_event_filtered, use_event_handle, use_key_event, use_mouse_event, use_paste,
+        use_paste_event,
+    };
+    pub use crate::hooks::focus::*;
     pub use crate::hooks::form::*;
     pub use crate::hooks::frame::*;
     pub use crate::hooks::future::*;
     pub use crate::hooks::history::*;
     pub use crate::hooks::id::*;
+    pub use crate::hooks::idle::*;
     pub use crate::hooks::interval::*;
     pub use crate::hooks::keyboard::*;
+    pub use crate::hooks::keymap::*;
+    pub use crate::hooks::list_selection::*;
+    pub use crate::hooks::logging::*;
+    pub use crate::hooks::measure::*;
     pub use crate::hooks::memo::*;
+    pub use crate::hooks::modal::*;
     pub use crate::hooks::mouse::*;
     pub use crate::hooks::mutation::*;
+    pub use crate::hooks::progress::*;
     pub use crate::hooks::query::*;
     pub use crate::hooks::reducer::*;
     pub use crate::hooks::ref_hook::*;
     pub use crate::hooks::resize::*;
+    pub use crate::hooks::scroll::*;
+    pub use crate::hooks::spinner::*;
     pub use crate::hooks::state::*;
+    pub use crate::hooks::stopwatch::*;
+    pub use crate::hooks::tabs::*;
+    pub use crate::hooks::text_input::*;
+    pub use crate::hooks::theme::*;
     pub use crate::hooks::timeout::*;
+    pub use crate::hooks::toast::*;
+    pub use crate::hooks::transition::*;
+    pub use crate::hooks::viewport::*;
+    pub use crate::hooks::visibility::*;
 
     // Re-export Props derive macro
     pub use reratui_macro::Props;
 
     // Re-export runtime utilities
-    pub use crate::runtime::{request_exit, should_exit};
+    pub use crate::runtime::{
+        ColorMode, RenderConfig, TerminalMode, request_exit, request_exit_with,
+        request_exit_with_result, should_exit,
+    };
 
     // Re-export ratatui types for convenience
     pub use ratatui::{Frame, layout::*, prelude::*, style::*, text::*, widgets::*};
@@ -263,3 +366,83 @@ where
 {
     runtime::render(app_fn).await
 }
+
+/// Renders the application and returns the exit code it requested
+///
+/// Use [`request_exit_with`](runtime::request_exit_with) inside the app to
+/// choose the code; a plain `request_exit()` yields `0`.
+///
+/// # Example
+/// ```ignore
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let code = reratui::render_with_exit_code(|| rsx! { <App /> }).await?;
+///     std::process::exit(code);
+/// }
+/// ```
+pub async fn render_with_exit_code<F>(app_fn: F) -> anyhow::Result<i32>
+where
+    F: Fn() -> core::Element + 'static,
+{
+    runtime::render_with_exit_code(app_fn).await
+}
+
+/// Renders the application and returns the value it exited with
+///
+/// Use [`request_exit_with_result`](runtime::request_exit_with_result) inside
+/// the app to hand a value of type `T` back to `main`.
+///
+/// # Example
+/// ```ignore
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let choice = reratui::render_with_result::<String, _>(|| rsx! { <Menu /> }).await?;
+///     println!("{choice:?}");
+///     Ok(())
+/// }
+/// ```
+pub async fn render_with_result<T, F>(app_fn: F) -> anyhow::Result<Option<T>>
+where
+    T: 'static,
+    F: Fn() -> core::Element + 'static,
+{
+    runtime::render_with_result(app_fn).await
+}
+
+/// Renders the application with the given terminal configuration
+///
+/// Use [`RenderConfig::inline`](runtime::RenderConfig::inline) to draw in a
+/// few lines below the prompt instead of taking over the screen.
+///
+/// # Example
+/// ```ignore
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     reratui::render_with_config(RenderConfig::inline(4), || rsx! { <Progress /> }).await
+/// }
+/// ```
+pub async fn render_with_config<F>(config: runtime::RenderConfig, app_fn: F) -> anyhow::Result<()>
+where
+    F: Fn() -> core::Element + 'static,
+{
+    runtime::render_with_config(config, app_fn).await
+}
+
+/// Renders the application into a caller-provided ratatui backend
+///
+/// No terminal setup is done, which makes this suitable for tests with
+/// `TestBackend` or for embedding. The terminal is returned so the last frame
+/// can be inspected.
+///
+/// # Example
+/// ```ignore
+/// let terminal = reratui::render_to(TestBackend::new(40, 5), || rsx! { <App /> }).await?;
+/// println!("{:?}", terminal.backend().buffer());
+/// ```
+pub async fn render_to<B, F>(backend: B, app_fn: F) -> anyhow::Result<ratatui::Terminal<B>>
+where
+    B: ratatui::backend::Backend,
+    F: Fn() -> core::Element + 'static,
+{
+    runtime::render_to(backend, app_fn).await
+}
@@ -22,9 +22,10 @@ impl Component for App {
         let (post_id, set_post_id) = use_state(|| 1u32);
         let current_post_id = post_id.get();
 
-        // Fetch post data with automatic refetch on ID change
+        // Fetch post data with automatic refetch on ID change; a fetch still in
+        // flight when the ID changes is cancelled, so the latest ID always wins
         let future_handle = use_future(
-            move || async move {
+            move |_token| async move {
                 // Simulate network delay for better UX demonstration
                 tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
@@ -58,15 +59,12 @@ impl Component for App {
             KeyCode::Char('q') => {
                 request_exit();
             }
+            // Step from the latest id, even if several presses arrive before a render
             KeyCode::Left | KeyCode::Char('h') => {
-                if current_post_id > 1 {
-                    set_post_id.set(current_post_id - 1);
-                }
+                set_post_id.update(|id| id.saturating_sub(1).max(1));
             }
             KeyCode::Right | KeyCode::Char('l') => {
-                if current_post_id < 100 {
-                    set_post_id.set(current_post_id + 1);
-                }
+                set_post_id.update(|id| (id + 1).min(100));
             }
             KeyCode::Home => {
                 set_post_id.set(1);
@@ -24,7 +24,7 @@ impl Component for CommandPaletteComponent {
                 let set_cursor_visible = set_cursor_visible.clone();
 
                 move || {
-                    set_frame_count.set(frame_count.get() + 1);
+                    set_frame_count.update(|count| count + 1);
                     if frame_count.get() % 10 == 0 {
                         set_cursor_visible.set(!cursor_visible.get());
                     }
@@ -55,11 +55,10 @@ impl Component for StatusIconsComponent {
         use_interval(
             {
                 // Set up animation interval
-                let animation_step_clone = animation_step.clone();
                 let set_animation_step = set_animation_step.clone();
                 move || {
                     // Simple counter for animation
-                    set_animation_step.set(animation_step_clone.get() + 1);
+                    set_animation_step.update(|step| step + 1);
                 }
             },
             Duration::from_millis(500), // Slower animation speed to reduce CPU usage
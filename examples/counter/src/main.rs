@@ -25,36 +25,17 @@ struct ButtonProps {
 #[component]
 fn Button(props: &ButtonProps) -> Element {
     let label = props.label.clone();
-    let area = use_area();
-    let (is_hovered, set_hovered) = use_state(|| false);
+    let is_hovered = use_hover_region(*use_area(), 0);
 
-    // Handle mouse events
-    if let Some(event) = use_event()
-        && let Event::Mouse(mouse) = event
-    {
-        let click_x = mouse.column;
-        let click_y = mouse.row;
-
-        let in_bounds = click_x >= area.x
-            && click_x < area.x + area.width
-            && click_y >= area.y
-            && click_y < area.y + area.height;
-
-        // Update hover state
-        if in_bounds && !is_hovered.get() {
-            set_hovered.set(true);
-        } else if !in_bounds && is_hovered.get() {
-            set_hovered.set(false);
-        }
-
-        // Handle click
-        if in_bounds
-            && mouse.kind == MouseEventKind::Down(MouseButton::Left)
-            && let Some(callback) = &props.on_click
-        {
-            callback.emit(());
+    // Forward clicks on the block to the optional handler
+    let on_click = {
+        let callback = props.on_click.clone();
+        move |_| {
+            if let Some(callback) = &callback {
+                callback.emit(());
+            }
         }
-    }
+    };
 
     // Beautiful styling with hover effect
     let base_style = props.style.unwrap_or_else(|| {
@@ -68,13 +49,9 @@ fn Button(props: &ButtonProps) -> Element {
         .bg(Color::Rgb(37, 99, 235)) // Blue-600
         .add_modifier(Modifier::BOLD);
 
-    let style = if is_hovered.get() {
-        hover_style
-    } else {
-        base_style
-    };
+    let style = if is_hovered { hover_style } else { base_style };
 
-    let border_style = if is_hovered.get() {
+    let border_style = if is_hovered {
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD)
@@ -83,7 +60,7 @@ fn Button(props: &ButtonProps) -> Element {
     };
 
     // Add visual indicator for hover
-    let display_text = if is_hovered.get() {
+    let display_text = if is_hovered {
         format!("▶ {} ◀", label)
     } else {
         format!("  {}  ", label)
@@ -94,6 +71,7 @@ fn Button(props: &ButtonProps) -> Element {
             borders={Borders::ALL}
             border_style={border_style}
             style={style}
+            on_click={on_click}
         >
             <Paragraph alignment={Alignment::Center}>
                 {display_text}
@@ -142,7 +120,12 @@ fn Counter() -> Element {
     };
 
     // Beautiful gradient title
-    let title = format!("✨ Counter App | FPS: {:.1}", frame_ctx.fps());
+    let title = format!(
+        "✨ Counter App | FPS: {:.1} (min {:.1}, {} dropped)",
+        frame_ctx.avg_fps(),
+        frame_ctx.min_fps(),
+        frame_ctx.dropped_frames()
+    );
 
     // Access the ratatui Frame directly!
     let frame = frame_ctx.frame_mut();
@@ -5,6 +5,7 @@
 //! - Elegant loading states with animations
 //! - Modern UI with gradients and styling
 //! - Multiple data sources with different loading times
+//! - Automatic retries with backoff for a flaky source
 //! - Global and individual refresh functionality
 //! - Interactive cards with hover effects
 //! - Keyboard shortcuts and mouse support
@@ -20,6 +21,7 @@
 //! - Press 'q' to exit
 
 use reratui::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -27,23 +29,7 @@ use tokio::time::sleep;
 ///
 /// Returns `true` when the mouse is hovering over the component's bounding box
 fn use_hover() -> bool {
-    let area = use_area();
-    let (is_hovered, set_hovered) = use_state(|| false);
-
-    if let Some(event) = use_event()
-        && let Event::Mouse(mouse) = event
-    {
-        let in_bounds = mouse.column >= area.x
-            && mouse.column < area.x + area.width
-            && mouse.row >= area.y
-            && mouse.row < area.y + area.height;
-
-        if in_bounds != is_hovered.get() {
-            set_hovered.set(in_bounds);
-        }
-    }
-
-    is_hovered.get()
+    use_hover_region(*use_area(), 0)
 }
 
 /// Simulates fetching user data from an API
@@ -52,9 +38,14 @@ async fn fetch_user_data() -> Result<String, String> {
     Ok("👤 John Doe | john@example.com | Premium User".to_string())
 }
 
-/// Simulates fetching weather data
+/// Simulates fetching weather data from a flaky API that fails every other call
 async fn fetch_weather_data() -> Result<String, String> {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
     sleep(Duration::from_millis(2000)).await;
+    if CALLS.fetch_add(1, Ordering::Relaxed).is_multiple_of(2) {
+        return Err("Weather service timed out".to_string());
+    }
     Ok("☀️ Sunny | 24°C | Humidity: 65% | Wind: 12 km/h".to_string())
 }
 
@@ -73,10 +64,7 @@ async fn fetch_notifications() -> Result<String, String> {
 /// Loading spinner component with animation
 #[component]
 fn LoadingSpinner() -> Element {
-    let frame = use_frame();
-    let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-    let idx = (frame.count / 3) as usize % spinner_chars.len();
-    let spinner = spinner_chars[idx];
+    let spinner = use_spinner(SpinnerVariant::Braille, Duration::from_millis(50));
 
     rsx! {
         <Paragraph
@@ -155,6 +143,9 @@ fn DataCard(props: &DataCardProps) -> Element {
     let area = use_area();
     let is_hovered = use_hover();
 
+    // Keep the grid's Suspense boundary waiting while this source loads
+    props.future_handle.suspend();
+
     // Handle click to refresh
     if let Some(event) = use_event()
         && let Event::Mouse(mouse) = event
@@ -204,6 +195,12 @@ fn DataCard(props: &DataCardProps) -> Element {
             "{} [Press '{}' or Click to refresh]",
             props.title, props.refresh_key
         )
+    } else if props.future_handle.is_pending() && props.future_handle.attempt() > 1 {
+        format!(
+            "{} (retry {})",
+            props.title,
+            props.future_handle.attempt() - 1
+        )
     } else {
         props.title.clone()
     };
@@ -261,6 +258,13 @@ fn DataFetcherApp() -> Element {
     let (stats_refresh, set_stats_refresh) = use_state(|| 0);
     let (notifications_refresh, set_notifications_refresh) = use_state(|| 0);
 
+    // Stable refresh callbacks, so the cards get the same callback every frame
+    let refresh_user = use_callback(move |_| set_user_refresh.update(|c| c + 1), ());
+    let refresh_weather = use_callback(move |_| set_weather_refresh.update(|c| c + 1), ());
+    let refresh_stats = use_callback(move |_| set_stats_refresh.update(|c| c + 1), ());
+    let refresh_notifications =
+        use_callback(move |_| set_notifications_refresh.update(|c| c + 1), ());
+
     // Handle global refresh (r key)
     use_keyboard_shortcut(KeyCode::Char('r'), KeyModifiers::NONE, {
         let set_refresh_count = set_refresh_count.clone();
@@ -275,22 +279,32 @@ fn DataFetcherApp() -> Element {
     });
 
     // Fetch data from multiple sources (re-runs when either global or individual refresh changes)
-    let user_data = use_future(fetch_user_data, (refresh_count.get(), user_refresh.get()));
-    let weather_data = use_future(
-        fetch_weather_data,
+    let user_data = use_future(
+        |_| fetch_user_data(),
+        (refresh_count.get(), user_refresh.get()),
+    );
+    let weather_data = use_future_with_options(
+        |_| fetch_weather_data(),
         (refresh_count.get(), weather_refresh.get()),
+        FutureOptions {
+            retry_attempts: 2,
+            retry_delay: Duration::from_millis(500),
+            exponential_backoff: true,
+        },
+    );
+    let stats_data = use_future(
+        |_| fetch_stats(),
+        (refresh_count.get(), stats_refresh.get()),
     );
-    let stats_data = use_future(fetch_stats, (refresh_count.get(), stats_refresh.get()));
     let notifications_data = use_future(
-        fetch_notifications,
+        |_| fetch_notifications(),
         (refresh_count.get(), notifications_refresh.get()),
     );
 
     // Calculate overall progress
-    let completed = [&user_data, &weather_data, &stats_data, &notifications_data]
-        .iter()
-        .filter(|h| h.is_resolved())
-        .count();
+    let loaded = [&user_data, &weather_data, &stats_data, &notifications_data]
+        .map(|handle| handle.is_resolved());
+    let completed = use_computed(move || loaded.iter().filter(|done| **done).count(), loaded);
     let total = 4;
 
     // Animated title
@@ -300,18 +314,24 @@ fn DataFetcherApp() -> Element {
         .fg(title_color)
         .add_modifier(Modifier::BOLD);
 
-    // Status message for marquee
-    let status_msg = if completed == total {
-        format!(
-            "✓ All data loaded successfully! | Press 'r' to refresh all | Press '1-4' or click cards to refresh individually | Refresh #{} ",
-            refresh_count.get()
-        )
-    } else {
-        format!(
-            "⏳ Loading data from multiple sources... ({}/{}) | Press 'r' to refresh all | Press '1-4' or click cards ",
-            completed, total
-        )
-    };
+    // Status message for marquee, rebuilt only when the progress changes
+    let refreshes = refresh_count.get();
+    let status_msg = use_computed(
+        move || {
+            if completed == total {
+                format!(
+                    "✓ All data loaded successfully! | Press 'r' to refresh all | Press '1-4' or click cards to refresh individually | Refresh #{} ",
+                    refreshes
+                )
+            } else {
+                format!(
+                    "⏳ Loading data from multiple sources... ({}/{}) | Press 'r' to refresh all | Press '1-4' or click cards ",
+                    completed, total
+                )
+            }
+        },
+        (completed, refreshes),
+    );
 
     let status_color = if completed == total {
         Color::Green
@@ -336,6 +356,7 @@ fn DataFetcherApp() -> Element {
                 direction={Direction::Vertical}
                 constraints={vec![
                     Constraint::Length(2),
+                    Constraint::Length(1),
                     Constraint::Min(0),
                 ]}
             >
@@ -352,50 +373,50 @@ fn DataFetcherApp() -> Element {
                     />
                 </Block>
 
-                // Data cards grid
-                <Layout direction={Direction::Vertical} constraints={vec![
-                    Constraint::Percentage(25),
-                    Constraint::Percentage(25),
-                    Constraint::Percentage(25),
-                    Constraint::Percentage(25),
-                ]}>
-                    <DataCard
-                        title={"👤 User Profile"}
-                        future_handle={user_data}
-                        refresh_key={'1'}
-                        on_refresh={{
-                            let set_user_refresh = set_user_refresh.clone();
-                            move |_| set_user_refresh.update(|c| c + 1)
-                        }}
-                    />
-                    <DataCard
-                        title={"🌤️  Weather Info"}
-                        future_handle={weather_data}
-                        refresh_key={'2'}
-                        on_refresh={{
-                            let set_weather_refresh = set_weather_refresh.clone();
-                            move |_| set_weather_refresh.update(|c| c + 1)
-                        }}
-                    />
-                    <DataCard
-                        title={"📊 Statistics"}
-                        future_handle={stats_data}
-                        refresh_key={'3'}
-                        on_refresh={{
-                            let set_stats_refresh = set_stats_refresh.clone();
-                            move |_| set_stats_refresh.update(|c| c + 1)
-                        }}
-                    />
-                    <DataCard
-                        title={"🔔 Notifications"}
-                        future_handle={notifications_data}
-                        refresh_key={'4'}
-                        on_refresh={{
-                            let set_notifications_refresh = set_notifications_refresh.clone();
-                            move |_| set_notifications_refresh.update(|c| c + 1)
-                        }}
-                    />
-                </Layout>
+                // Overall progress
+                <ProgressBar
+                    value={completed as f64 / total as f64}
+                    label={format!("{}/{} sources loaded", completed, total)}
+                    style={Style::default().fg(status_color).bg(Color::Rgb(24, 24, 37))}
+                    animate={true}
+                />
+
+                // Data cards grid, one spinner until every source has loaded
+                <Suspense fallback={rsx! {
+                    <Spinner variant={SpinnerVariant::Arc} style={Style::default().fg(Color::Cyan)} />
+                }}>
+                    <Layout direction={Direction::Vertical} constraints={vec![
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(25),
+                    ]}>
+                        <DataCard
+                            title={"👤 User Profile"}
+                            future_handle={user_data}
+                            refresh_key={'1'}
+                            on_refresh={refresh_user}
+                        />
+                        <DataCard
+                            title={"🌤️  Weather Info"}
+                            future_handle={weather_data}
+                            refresh_key={'2'}
+                            on_refresh={refresh_weather}
+                        />
+                        <DataCard
+                            title={"📊 Statistics"}
+                            future_handle={stats_data}
+                            refresh_key={'3'}
+                            on_refresh={refresh_stats}
+                        />
+                        <DataCard
+                            title={"🔔 Notifications"}
+                            future_handle={notifications_data}
+                            refresh_key={'4'}
+                            on_refresh={refresh_notifications}
+                        />
+                    </Layout>
+                </Suspense>
             </Layout>
         </Block>
     }
@@ -3,6 +3,7 @@
 //! Demonstrates all event hooks working together:
 //! - use_keyboard
 //! - use_mouse
+//! - use_mouse_gestures
 //! - use_on_resize
 //!
 //! Features:
@@ -10,6 +11,7 @@
 //! - Real-time event visualization
 //! - Interactive drawing canvas
 //! - Terminal resize handling
+//! - Named commands listed by a `HelpOverlay` ('?')
 
 use reratui::prelude::*;
 
@@ -51,7 +53,8 @@ fn showcase_reducer(state: ShowcaseState, action: ShowcaseAction) -> ShowcaseSta
     match action {
         ShowcaseAction::Resize { width, height } => ShowcaseState {
             terminal_size: (width, height),
-            resize_count: state.resize_count + 1,
+            // The runtime reports the initial size as a resize too
+            resize_count: state.resize_count + u32::from(state.terminal_size != (0, 0)),
             ..state
         },
         ShowcaseAction::KeyPress { description } => ShowcaseState {
@@ -97,10 +100,7 @@ struct EventsShowcase;
 impl Component for EventsShowcase {
     fn on_mount(&self) {
         // Set up exit handlers
-        on_global_event(KeyCode::Char('q'), || {
-            request_exit();
-            false
-        });
+        KeymapRegistry::global().register("quit", KeyCode::Char('q'), "Quit", request_exit);
 
         on_global_event(KeyCode::Esc, || {
             request_exit();
@@ -111,7 +111,8 @@ impl Component for EventsShowcase {
     fn render(&self, area: Rect, buffer: &mut Buffer) {
         // Initialize state with reducer
         let initial_state = ShowcaseState {
-            terminal_size: (area.width, area.height),
+            // Filled in by the resize delivered on the first frame
+            terminal_size: (0, 0),
             resize_count: 0,
             last_key: String::from("None"),
             key_count: 0,
@@ -132,21 +133,26 @@ impl Component for EventsShowcase {
             }
         });
 
+        // Canvas commands, listed by the help overlay
+        use_command(
+            "toggle-drawing",
+            KeyCode::Char('d'),
+            "Toggle drawing mode",
+            {
+                let dispatch = dispatch.clone();
+                move || dispatch.call(ShowcaseAction::ToggleDrawing)
+            },
+        );
+        use_command("clear-canvas", KeyCode::Char('c'), "Clear the canvas", {
+            let dispatch = dispatch.clone();
+            move || dispatch.call(ShowcaseAction::ClearCanvas)
+        });
+
         // Handle keyboard events
         use_keyboard_press({
             let dispatch = dispatch.clone();
 
             move |key_event| {
-                // Handle special keys first
-                if let KeyCode::Char(c) = key_event.code {
-                    if c == 'd' {
-                        dispatch.call(ShowcaseAction::ToggleDrawing);
-                    }
-                    if c == 'c' {
-                        dispatch.call(ShowcaseAction::ClearCanvas);
-                    }
-                }
-
                 // Build key description
                 let key_desc = match key_event.code {
                     KeyCode::Char(c) => {
@@ -192,7 +198,6 @@ impl Component for EventsShowcase {
         // Handle mouse events
         use_mouse({
             let dispatch = dispatch.clone();
-            let drawing = state.field(|s| s.drawing_mode);
 
             move |mouse_event| {
                 let event_type = match mouse_event.kind {
@@ -203,16 +208,6 @@ impl Component for EventsShowcase {
                     MouseEventKind::Down(MouseButton::Right) => "Right Click",
                     MouseEventKind::Down(MouseButton::Middle) => "Middle Click",
                     MouseEventKind::Up(_) => "Button Up",
-                    MouseEventKind::Drag(MouseButton::Left) => {
-                        // Add pixel to canvas if in drawing mode
-                        if drawing {
-                            dispatch.call(ShowcaseAction::AddPixel {
-                                x: mouse_event.column,
-                                y: mouse_event.row,
-                            });
-                        }
-                        "Dragging"
-                    }
                     MouseEventKind::Drag(_) => "Dragging",
                     MouseEventKind::Moved => "Moved",
                     MouseEventKind::ScrollDown => "Scroll Down",
@@ -439,6 +434,15 @@ impl Component for EventsShowcase {
             horizontal: 1,
             vertical: 1,
         });
+
+        // Draw while dragging across the canvas
+        let canvas_gestures = use_mouse_gestures(canvas_area);
+        if drawing_mode
+            && canvas_gestures.drag_delta.is_some()
+            && let Some((x, y)) = canvas_gestures.position
+        {
+            dispatch.call(ShowcaseAction::AddPixel { x, y });
+        }
         for (px, py) in canvas_pixels.iter() {
             if *px >= canvas_area.x
                 && *px < canvas_area.x + canvas_area.width
@@ -486,7 +490,14 @@ impl Component for EventsShowcase {
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" to clear canvas", Style::default().fg(Color::Gray)),
+                Span::styled(" to clear canvas  |  ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "'?'",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(" for shortcuts", Style::default().fg(Color::Gray)),
             ]),
             Line::from(vec![
                 Span::styled("Press ", Style::default().fg(Color::Gray)),
@@ -527,6 +538,11 @@ impl Component for EventsShowcase {
 /// Entry point for the application
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    render(|| EventsShowcase.into()).await?;
+    render(|| {
+        Element::component(HelpOverlay::new(
+            HelpOverlayProps::default().with_children(vec![EventsShowcase.into()]),
+        ))
+    })
+    .await?;
     Ok(())
 }
@@ -7,6 +7,16 @@ pub struct FormFieldProps {
 
     /// Render callback for custom layout
     pub render: Callback<FormFieldContext, Element>,
+
+    /// Receives this field's handle while it is focused
+    pub focused_ref: Option<Ref<FieldHandle>>,
+}
+
+/// Where a field was rendered, reported to the parent through a ref
+#[derive(Clone)]
+pub struct FieldHandle {
+    pub name: String,
+    pub area: Rect,
 }
 
 #[component]
@@ -14,53 +24,62 @@ pub fn FormField(props: &FormFieldProps) -> Element {
     // Access form from context
     let form = use_form_context();
 
-    // Access field registry and auto-register this field
-    let registry = use_context::<crate::form::FieldRegistry>();
-    let field_index = registry.register_field(&props.name);
-
-    // Check if this field is focused
-    let is_focused = registry.get_focused_index() == field_index;
+    // Register as focusable; fields are tabbed through in render order
+    let focus = use_focus(0);
+    let is_focused = focus.is_focused();
 
     // Get form state for this field
     let value = form.get_value(&props.name).unwrap_or_default();
     let error = form.get_error(&props.name);
     let touched = form.is_touched(&props.name);
+    let is_validating = form.is_validating(&props.name);
 
-    // Handle keyboard input if focused
+    // Typing is handled by the Input's TextInput; the field only submits
     let event = use_event();
     if is_focused {
         if let Some(Event::Key(key)) = event {
-            if key.is_press() {
-                match key.code {
-                    KeyCode::Char(c) => {
-                        let mut new_value = value.clone();
-                        new_value.push(c);
-                        form.set_value(&props.name, new_value);
-                        form.set_touched(&props.name, true);
-                    }
-                    KeyCode::Backspace => {
-                        let mut new_value = value.clone();
-                        new_value.pop();
-                        form.set_value(&props.name, new_value);
-                        form.set_touched(&props.name, true);
-                    }
-                    KeyCode::Enter => {
-                        form.submit();
-                    }
-                    _ => {}
+            if key.is_press() && key.code == KeyCode::Enter {
+                form.submit();
+                // Clear the form once the submission went through
+                if form.is_valid() {
+                    form.reset();
                 }
             }
         }
     }
 
+    // Edits from the input go straight into the form
+    let on_change = Callback::from({
+        let form = form.clone();
+        let name = props.name.clone();
+        move |value: String| {
+            form.set_value(&name, value);
+            form.set_touched(&name, true);
+        }
+    });
+
     // Create field context
     let field_context = FormFieldContext {
         value,
         error: error.clone(),
         touched,
         is_focused,
+        is_validating,
+        on_change,
     };
 
+    // Let the parent know where the focused field is, e.g. to scroll it into view
+    let area = *use_area();
+    if is_focused {
+        forward_ref(
+            &props.focused_ref,
+            FieldHandle {
+                name: props.name.clone(),
+                area,
+            },
+        );
+    }
+
     // Provide context to children
     use_context_provider(|| field_context.clone());
 
@@ -75,4 +94,7 @@ pub struct FormFieldContext {
     pub error: Option<String>,
     pub touched: bool,
     pub is_focused: bool,
+    pub is_validating: bool,
+    /// Stores an edited value in the form
+    pub on_change: Callback<String>,
 }
@@ -83,6 +83,9 @@ pub struct InputProps {
 
     /// Custom class/style modifier
     pub class: Option<String>,
+
+    /// Called with the new value after each edit
+    pub on_change: Option<Callback<String>>,
 }
 
 #[component]
@@ -111,22 +114,20 @@ pub fn Input(props: &InputProps) -> Element {
     // Get colors based on state and variant
     let (border_color, fg_color, bg_color) = get_input_colors(state, variant);
 
-    // Display value (masked if password)
-    let display_value = if is_password && !value.is_empty() {
-        "•".repeat(value.len())
-    } else if value.is_empty() {
-        props.placeholder.clone().unwrap_or_default()
-    } else {
-        value.clone()
-    };
-
-    // Value style
-    let value_style = if value.is_empty() {
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::ITALIC)
-    } else {
-        Style::default().fg(fg_color)
+    // Editing is handled by TextInput, which masks passwords itself
+    let placeholder_style = Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::ITALIC);
+    let text_input = rsx! {
+        <TextInput
+            value={value}
+            on_change={props.on_change.clone().unwrap_or_default()}
+            placeholder={props.placeholder.clone().unwrap_or_default()}
+            style={Style::default().fg(fg_color)}
+            placeholder_style={placeholder_style}
+            mask={if is_password { Some('•') } else { None }}
+            focused={is_focused && !disabled}
+        />
     };
 
     rsx! {
@@ -136,13 +137,19 @@ pub fn Input(props: &InputProps) -> Element {
             style={Style::default().bg(bg_color)}
         >
             // Icon and value row
-            <Paragraph style={value_style}>
-                {if let Some(ref icon) = props.icon {
-                    format!("{} {}", icon, display_value)
-                } else {
-                    display_value
-                }}
-            </Paragraph>
+            {if let Some(ref icon) = props.icon {
+                rsx! {
+                    <Layout
+                        direction={Direction::Horizontal}
+                        constraints={vec![Constraint::Length(3), Constraint::Min(0)]}
+                    >
+                        <Paragraph style={Style::default().fg(fg_color)}>{icon.clone()}</Paragraph>
+                        {text_input}
+                    </Layout>
+                }
+            } else {
+                text_input
+            }}
         </Block>
     }
 }
@@ -17,7 +17,7 @@ pub fn FormItem(props: &FormItemProps) -> Element {
 
     // Dynamically allocate space based on what's present
     let child_count = props.children.len();
-    let has_error_to_show = error.is_some() && touched;
+    let has_error_to_show = (error.is_some() && touched) || field_ctx.is_validating;
 
     let constraints = match (child_count, has_error_to_show) {
         // 4 children (label, input, description, message) with error
@@ -1,4 +1,5 @@
 use reratui::prelude::*;
+use std::time::Duration;
 
 /// Message variant types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,6 +8,8 @@ pub enum MessageVariant {
     Error,
     /// Helper text (gray)
     Helper,
+    /// Validation in progress (yellow, with spinner)
+    Validating,
 }
 
 impl Default for MessageVariant {
@@ -35,6 +38,7 @@ pub fn FormMessage(props: &FormMessageProps) -> Element {
     let (color, icon) = match variant {
         MessageVariant::Error => (Color::Red, "⚠"),
         MessageVariant::Helper => (Color::DarkGray, ""),
+        MessageVariant::Validating => (Color::Yellow, ""),
     };
 
     // Build message text with icon
@@ -54,6 +58,7 @@ pub fn FormMessage(props: &FormMessageProps) -> Element {
         style = match variant {
             MessageVariant::Error => style.add_modifier(Modifier::ITALIC),
             MessageVariant::Helper => style.add_modifier(Modifier::DIM),
+            MessageVariant::Validating => style.add_modifier(Modifier::ITALIC),
         };
 
         style
@@ -68,10 +73,25 @@ pub fn FormMessage(props: &FormMessageProps) -> Element {
 
 #[component]
 pub fn FormFieldMessage() -> Element {
+    let spinner = use_spinner(
+        SpinnerVariant::Braille,
+        Duration::from_millis(DEFAULT_SPINNER_SPEED_MS),
+    );
+
     // Get field context
     let field_ctx = use_field_context_optional();
 
     if let Some(ctx) = field_ctx {
+        // Show a spinner while async validation is in flight
+        if ctx.is_validating {
+            return rsx! {
+                <FormMessage
+                    text={format!("{spinner} Checking...")}
+                    variant={MessageVariant::Validating}
+                />
+            };
+        }
+
         // Show error if present and touched
         if let Some(error) = ctx.error {
             if ctx.touched {
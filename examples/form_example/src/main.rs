@@ -8,14 +8,25 @@
 //! - useFormContext for accessing form in child components
 //! - Reusable form field components
 //! - Clean component composition
+//! - Async validation (username availability) with a spinner
+//! - Programmatic reset and loading an existing record for editing
+//! - Unsaved-changes indicator driven by dirty tracking
+//! - Validation error popup rendered through a Portal
+//! - Focused field location reported to the app through a forwarded ref
+//! - Output printed on submit captured instead of garbling the screen
 //!
 //! Controls:
 //! - Tab/Shift+Tab to navigate between fields
 //! - Type to enter values
-//! - Enter to submit form
+//! - Enter to submit form (the form is cleared after a successful submit)
+//! - Ctrl+L to load a sample user for editing
+//! - Ctrl+R to reset the form
+//! - Ctrl+E to show or hide a summary of validation errors
+//! - F12 to show or hide captured output
 //! - Press 'q' to exit
 
 use reratui::prelude::*;
+use std::collections::HashMap;
 
 mod form;
 mod form_description;
@@ -33,6 +44,9 @@ use form_item::*;
 use form_label::*;
 use form_message::*;
 
+/// Usernames rejected by the simulated availability check
+const TAKEN_USERNAMES: &[&str] = &["admin", "root", "reratui"];
+
 /// Main application component
 #[component]
 fn App() -> Element {
@@ -46,6 +60,13 @@ fn App() -> Element {
     let pulse = ((frame.count as f32 / 10.0).sin() * 0.5 + 0.5) * 255.0;
     let title_color = Color::Rgb((59.0 + pulse * 0.3) as u8, (130.0 + pulse * 0.2) as u8, 246);
 
+    // Filled by whichever field is focused
+    let focused_field = use_forward_ref::<FieldHandle>();
+    let focus_hint = focused_field
+        .get()
+        .map(|field| format!(" • Editing {} at row {}", field.name, field.area.y))
+        .unwrap_or_default();
+
     // Create form handle
     let form = use_form(
         FormConfig::builder()
@@ -58,6 +79,18 @@ fn App() -> Element {
                 "username",
                 Validator::min_length(3, "Username must be at least 3 characters"),
             )
+            .validator(
+                "username",
+                Validator::async_fn(|username| async move {
+                    // Simulate checking availability against a server
+                    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+                    if TAKEN_USERNAMES.contains(&username.to_lowercase().as_str()) {
+                        Some(format!("Username '{username}' is already taken"))
+                    } else {
+                        None
+                    }
+                }),
+            )
             .validator("email", Validator::required("Email is required"))
             .validator("email", Validator::email("Invalid email format"))
             .validator("password", Validator::required("Password is required"))
@@ -71,6 +104,23 @@ fn App() -> Element {
             .build(),
     );
 
+    use_keyboard_shortcut(KeyCode::Char('l'), KeyModifiers::CONTROL, {
+        let form = form.clone();
+        move || {
+            // Simulate loading an existing user record into the form
+            form.reset_to(HashMap::from([
+                ("username".to_string(), "ada".to_string()),
+                ("email".to_string(), "ada@example.com".to_string()),
+                ("phone".to_string(), "+1 555 0100".to_string()),
+            ]));
+        }
+    });
+
+    use_keyboard_shortcut(KeyCode::Char('r'), KeyModifiers::CONTROL, {
+        let form = form.clone();
+        move || form.reset()
+    });
+
     rsx! {
         <Block
             title={"📝 Form Context Example"}
@@ -97,7 +147,7 @@ fn App() -> Element {
                         style={Style::default().fg(Color::Gray)}
                         alignment={Alignment::Center}
                     >
-                        {"Using Form component with context - No prop drilling!"}
+                        {format!("Using Form component with context - No prop drilling!{focus_hint}")}
                     </Paragraph>
                 </Block>
 
@@ -105,6 +155,7 @@ fn App() -> Element {
                 <Form form={form}>
                     <FormField
                         name={"username"}
+                        focused_ref={&focused_field}
                         render={|ctx: FormFieldContext| {
                             rsx! {
                                 <FormItem>
@@ -116,6 +167,7 @@ fn App() -> Element {
                                         icon={"👤".to_string()}
                                         focused={ctx.is_focused}
                                         error={ctx.error.is_some() && ctx.touched}
+                                        on_change={ctx.on_change.clone()}
                                     />
                                     <FormFieldMessage />
                                 </FormItem>
@@ -124,6 +176,7 @@ fn App() -> Element {
                     />
                     <FormField
                         name={"email"}
+                        focused_ref={&focused_field}
                         render={|ctx: FormFieldContext| {
                             rsx! {
                                 <FormItem>
@@ -135,6 +188,7 @@ fn App() -> Element {
                                         icon={"📧".to_string()}
                                         focused={ctx.is_focused}
                                         error={ctx.error.is_some() && ctx.touched}
+                                        on_change={ctx.on_change.clone()}
                                     />
                                     <FormDescription text={"We'll never share your email with anyone.".to_string()} />
                                     <FormFieldMessage />
@@ -144,6 +198,7 @@ fn App() -> Element {
                     />
                     <FormField
                         name={"phone"}
+                        focused_ref={&focused_field}
                         render={|ctx: FormFieldContext| {
                             rsx! {
                                 <FormItem>
@@ -155,6 +210,7 @@ fn App() -> Element {
                                         icon={"📱".to_string()}
                                         focused={ctx.is_focused}
                                         error={ctx.error.is_some() && ctx.touched}
+                                        on_change={ctx.on_change.clone()}
                                     />
                                     <FormFieldMessage />
                                 </FormItem>
@@ -163,6 +219,7 @@ fn App() -> Element {
                     />
                     <FormField
                         name={"password"}
+                        focused_ref={&focused_field}
                         render={|ctx: FormFieldContext| {
                             rsx! {
                                 <FormItem>
@@ -175,6 +232,7 @@ fn App() -> Element {
                                         icon={"🔒".to_string()}
                                         focused={ctx.is_focused}
                                         error={ctx.error.is_some() && ctx.touched}
+                                        on_change={ctx.on_change.clone()}
                                     />
                                     <FormFieldMessage />
                                 </FormItem>
@@ -195,6 +253,18 @@ fn App() -> Element {
 fn FormSubmitButton() -> Element {
     // Access form from context
     let form = use_form_context();
+    let (show_errors, set_show_errors) = use_state(|| false);
+
+    use_keyboard_shortcut(KeyCode::Char('e'), KeyModifiers::CONTROL, move || {
+        set_show_errors.update(|shown| !shown);
+    });
+
+    // Declared here but painted above the whole form, not clipped to the footer
+    let errors = if show_errors.get() {
+        form.get_errors()
+    } else {
+        HashMap::new()
+    };
 
     rsx! {
         <Block
@@ -208,22 +278,85 @@ fn FormSubmitButton() -> Element {
                     .add_modifier(Modifier::BOLD)}
                 alignment={Alignment::Center}
             >
-                {if form.is_valid() {
-                    "✓ Form is valid - Press Enter to submit"
-                } else if form.has_errors() {
-                    "⚠ Please fix errors before submitting"
-                } else {
-                    "Fill out all required fields"
-                }}
+                {format!(
+                    "{}{}",
+                    if form.is_valid() {
+                        "✓ Form is valid - Press Enter to submit"
+                    } else if form.is_validating_any() {
+                        "⏳ Validating..."
+                    } else if form.has_errors() {
+                        "⚠ Please fix errors before submitting"
+                    } else {
+                        "Fill out all required fields"
+                    },
+                    if form.is_dirty() { " • Unsaved changes" } else { "" }
+                )}
             </Paragraph>
+            <Portal z_index={1}>
+                {Element::component(ErrorSummary { errors })}
+            </Portal>
         </Block>
     }
 }
 
+/// Popup listing the current validation errors, centered over the frame
+struct ErrorSummary {
+    errors: HashMap<String, String>,
+}
+
+impl Component for ErrorSummary {
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        if self.errors.is_empty() {
+            return;
+        }
+
+        let mut errors: Vec<_> = self.errors.iter().collect();
+        errors.sort();
+        let lines: Vec<Line> = errors
+            .into_iter()
+            .map(|(field, message)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{field}: "),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(message.clone()),
+                ])
+            })
+            .collect();
+
+        let width = area.width.min(60);
+        let height = area.height.min(lines.len() as u16 + 2);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        Clear.render(popup, buffer);
+        Paragraph::new(lines)
+            .style(Style::default().fg(Color::Red).bg(Color::Rgb(24, 24, 37)))
+            .block(
+                Block::default()
+                    .title(" ⚠ Validation errors (Ctrl+E to close) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .render(popup, buffer);
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    render(|| {
-        rsx! { <App /> }
+    // `on_submit` prints, so keep stdout off the screen while the form is up
+    let config = RenderConfig::default().capture_output();
+    reratui::render_with_config(config, || {
+        rsx! {
+            <LogOverlay>
+                <App />
+            </LogOverlay>
+        }
     })
     .await?;
     Ok(())
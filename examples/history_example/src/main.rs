@@ -3,12 +3,20 @@
 //! A beautiful demonstration of the `use_history` hook with undo/redo functionality.
 //! Features:
 //! - 🎨 Rich text editor with history tracking
-//! - ⏮️ Undo/Redo operations with keyboard shortcuts
-//! - 📊 Visual history timeline
+//! - ⏮️ Undo/Redo operations with keyboard shortcuts, one word at a time
+//! - 📊 Visual history timeline - click a snapshot to jump to it
 //! - 🎯 Real-time state visualization
 //! - ⌨️ Intuitive keyboard controls
+//! - 📋 Pasting inserts the whole text as one undo step
+//! - 💾 Autosaves to a temporary file once typing pauses
 
 use reratui::prelude::*;
+use std::time::Duration;
+
+/// Where the editor content is autosaved
+fn autosave_path() -> std::path::PathBuf {
+    std::env::temp_dir().join("reratui_history_example.txt")
+}
 
 #[derive(Clone, PartialEq, Debug)]
 struct EditorState {
@@ -35,8 +43,17 @@ impl Component for App {
     fn render(&self, area: Rect, buffer: &mut Buffer) {
         let history = use_history(EditorState::new(), 50);
 
+        // Save once typing pauses rather than on every keystroke
+        let autosave = use_debounced_callback(
+            |content: String| {
+                let _ = std::fs::write(autosave_path(), content);
+            },
+            Duration::from_millis(500),
+        );
+
         // Clone history for use in the keyboard handler
         let history_for_handler = history.clone();
+        let autosave_for_handler = autosave.clone();
 
         // Keyboard controls
         use_keyboard_press(move |key| {
@@ -53,18 +70,25 @@ impl Component for App {
                 KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     history_for_handler.redo();
                 }
+                KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    history_for_handler.clear();
+                }
                 KeyCode::Char(c) => {
                     new_state.content.push(c);
                     new_state.cursor_position += 1;
                     new_state.operation = format!("Added '{}'", c);
-                    history_for_handler.push(new_state);
+                    // Group a word's keystrokes into one undo step
+                    history_for_handler.push_coalesced(new_state, "typing");
+                    if c == ' ' {
+                        history_for_handler.commit();
+                    }
                 }
                 KeyCode::Backspace => {
                     if !new_state.content.is_empty() && new_state.cursor_position > 0 {
                         new_state.content.pop();
                         new_state.cursor_position = new_state.cursor_position.saturating_sub(1);
                         new_state.operation = String::from("Deleted character");
-                        history_for_handler.push(new_state);
+                        history_for_handler.push_coalesced(new_state, "deleting");
                     }
                 }
                 KeyCode::Enter => {
@@ -75,8 +99,19 @@ impl Component for App {
                 }
                 _ => {}
             }
+            autosave_for_handler(history_for_handler.current().content);
         });
 
+        // A paste arrives as one event, inserted as a single undo step
+        if let Some(text) = use_paste() {
+            let mut new_state = history.current();
+            new_state.content.push_str(&text);
+            new_state.cursor_position += text.chars().count();
+            new_state.operation = format!("Pasted {} characters", text.chars().count());
+            history.push(new_state);
+            autosave(history.current().content);
+        }
+
         // Get current state after setting up keyboard handler
         let current_state = history.current();
 
@@ -91,11 +126,30 @@ impl Component for App {
             ])
             .split(area);
 
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(20), Constraint::Length(32)])
+            .split(chunks[1]);
+
+        // Jump to the snapshot whose timeline row was clicked
+        let timeline = Timeline::new(&history, body[1]);
+        let history_for_click = history.clone();
+        use_mouse_click(move |button, x, y| {
+            if button == MouseButton::Left
+                && let Some(index) = timeline.index_at(x, y)
+            {
+                history_for_click.jump_to(index);
+            }
+        });
+
         // Render title
         render_title(buffer, chunks[0]);
 
         // Render editor
-        render_editor(buffer, chunks[1], &current_state);
+        render_editor(buffer, body[0], &current_state);
+
+        // Render timeline
+        render_timeline(buffer, body[1], &history, timeline);
 
         // Render status
         render_status(buffer, chunks[2], &history, &current_state);
@@ -154,6 +208,82 @@ fn render_editor(buffer: &mut Buffer, area: Rect, state: &EditorState) {
     editor.render(area, buffer);
 }
 
+/// Which history entries the timeline panel shows
+#[derive(Clone, Copy)]
+struct Timeline {
+    /// Rows inside the panel border
+    rows: Rect,
+    /// Index of the entry on the first row
+    offset: usize,
+}
+
+impl Timeline {
+    /// Scrolls the timeline so the current entry is visible
+    fn new<T: Clone + 'static>(history: &HistoryManager<T>, area: Rect) -> Self {
+        let rows = Block::default().borders(Borders::ALL).inner(area);
+        let height = rows.height as usize;
+        let offset = history
+            .current_index()
+            .saturating_sub(height.saturating_sub(1))
+            .min(history.len().saturating_sub(height));
+        Self { rows, offset }
+    }
+
+    /// The entry index shown at a screen position, if any
+    fn index_at(&self, x: u16, y: u16) -> Option<usize> {
+        let position = Position::new(x, y);
+        if !self.rows.contains(position) {
+            return None;
+        }
+        Some(self.offset + (y - self.rows.y) as usize)
+    }
+}
+
+fn render_timeline(
+    buffer: &mut Buffer,
+    area: Rect,
+    history: &HistoryManager<EditorState>,
+    timeline: Timeline,
+) {
+    let timeline_block = Block::default()
+        .title("🕒 Timeline")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .border_type(BorderType::Rounded);
+
+    let current_index = history.current_index();
+    let lines: Vec<Line> = history
+        .entries()
+        .iter()
+        .enumerate()
+        .skip(timeline.offset)
+        .take(timeline.rows.height as usize)
+        .map(|(index, entry)| {
+            let (marker, style) = if index == current_index {
+                (
+                    "▶",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else if index > current_index {
+                // Undone entries that a new edit would discard
+                (" ", Style::default().fg(Color::DarkGray))
+            } else {
+                (" ", Style::default().fg(Color::White))
+            };
+            Line::from(Span::styled(
+                format!("{} {:>2}. {}", marker, index + 1, entry.operation),
+                style,
+            ))
+        })
+        .collect();
+
+    Paragraph::new(lines)
+        .block(timeline_block)
+        .render(area, buffer);
+}
+
 fn render_status<T: Clone + 'static>(
     buffer: &mut Buffer,
     area: Rect,
@@ -271,7 +401,21 @@ fn render_controls(buffer: &mut Buffer, area: Rect) {
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to delete", Style::default().fg(Color::Gray)),
+            Span::styled(" to delete  ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "Click",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" a snapshot to jump  ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "Ctrl+L",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" Clear history", Style::default().fg(Color::Gray)),
         ]),
     ];
 
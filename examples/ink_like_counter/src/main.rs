@@ -60,20 +60,21 @@ fn EnhancedCounter() -> Element {
         Duration::from_millis(100),
     );
 
-    // Slower counter for failed tests (every 500ms)
-    use_interval(
-        {
-            move || {
-                if tests_failed.get() < 5 {
-                    set_tests_failed.update(|tests_failed| tests_failed + 1);
-                }
+    // Slower counter for failed tests (every 500ms) that stops at 5; the guard
+    // is a dependency, so the timer restarts with a callback that sees it
+    let still_failing = tests_failed_value < 5;
+    use_interval_with_deps(
+        move || {
+            if still_failing {
+                set_tests_failed.update(|tests_failed| tests_failed + 1);
             }
         },
         Duration::from_millis(500),
+        still_failing,
     );
 
-    // Uptime counter (every second)
-    use_interval(
+    // Uptime counter (every second) - paused while the terminal is unfocused
+    let uptime_interval = use_interval_controlled(
         {
             move || {
                 set_uptime_seconds.update(|uptime_seconds| uptime_seconds + 1);
@@ -82,6 +83,18 @@ fn EnhancedCounter() -> Element {
         Duration::from_secs(1),
     );
 
+    match use_event() {
+        Some(Event::FocusLost) => uptime_interval.pause(),
+        Some(Event::FocusGained) => uptime_interval.resume(),
+        _ => {}
+    }
+
+    let uptime_label = if uptime_interval.is_paused() {
+        format!("{}s uptime (paused)", uptime_seconds_value)
+    } else {
+        format!("{}s uptime", uptime_seconds_value)
+    };
+
     rsx! {
         <Layout
             direction={Direction::Vertical}
@@ -130,7 +143,7 @@ fn EnhancedCounter() -> Element {
                     style={Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)}
                     alignment={Alignment::Center}
                 >
-                    {format!("{}s uptime", uptime_seconds_value)}
+                    {uptime_label}
                 </Paragraph>
             </Block>
 
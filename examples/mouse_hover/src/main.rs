@@ -10,29 +10,6 @@
 use reratui::prelude::*;
 use std::sync::{Arc, Mutex};
 
-/// Hook for detecting if the mouse is hovering over a specific area.
-///
-/// Takes a rectangular area and returns a boolean indicating whether the mouse
-/// is currently hovering over that area.
-pub fn use_mouse_hover(area: Rect) -> bool {
-    let (is_hovering, set_hovering) = use_state(|| false);
-
-    if let Some(event) = use_event()
-        && let Event::Mouse(MouseEvent { column, row, .. }) = event
-    {
-        let is_inside = column >= area.x
-            && column < area.x + area.width
-            && row >= area.y
-            && row < area.y + area.height;
-
-        if is_inside != is_hovering.get() {
-            set_hovering.set(is_inside);
-        }
-    }
-
-    is_hovering.get()
-}
-
 #[derive(Clone)]
 struct HoverStats {
     total_hovers: Arc<Mutex<u32>>,
@@ -166,7 +143,7 @@ fn render_interactive_cards(buffer: &mut Buffer, area: Rect, stats: &HoverStats)
     ];
 
     for (i, (title, subtitle, color)) in cards.iter().enumerate() {
-        let is_hovering = use_mouse_hover(card_chunks[i]);
+        let is_hovering = use_hover_region(card_chunks[i], 0);
 
         if is_hovering {
             let prev_count = stats.get_count();
@@ -276,7 +253,7 @@ fn render_button_grid(buffer: &mut Buffer, area: Rect, stats: &HoverStats) {
             .split(*row_area);
 
         for (col_idx, col_area) in cols.iter().enumerate() {
-            let is_hovering = use_mouse_hover(*col_area);
+            let is_hovering = use_hover_region(*col_area, 0);
 
             if is_hovering {
                 stats.increment();
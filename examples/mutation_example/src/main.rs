@@ -3,12 +3,15 @@
 //! A beautiful demonstration of the `use_mutation` and `use_reducer` hooks with:
 //! - 🎯 Create, Update, Delete operations
 //! - 🔄 Retry logic with exponential backoff
-//! - ✅ Success/Error callbacks with notifications
+//! - ✅ Success/Error callbacks with `ToastProvider` notifications
 //! - 🎨 Professional UI with color-coded states
 //! - ⌨️ Intuitive keyboard navigation
 //! - 🚀 Optimistic updates and rollback
-//! - ❌ Cancellation support
+//! - ❌ Cancellation support, reported through `on_settled`
+//! - 🧵 Mutations queued in a shared `MutationScope` so they never race
+//! - 🪟 Confirm-delete dialog awaited through `use_confirm`
 //! - 📦 Reducer pattern for form state management
+//! - 📜 In-app log viewer with `LogOverlay` (F12)
 
 use parking_lot::Mutex;
 use reratui::prelude::*;
@@ -146,22 +149,18 @@ fn form_reducer(state: FormState, action: FormAction) -> FormState {
     }
 }
 
-#[derive(Clone)]
-struct Notification {
-    message: String,
-    notification_type: NotificationType,
-}
-
-#[derive(Clone, PartialEq)]
-enum NotificationType {
-    Success,
-    Error,
+/// The role after `role` in the cycle User → Admin → Moderator
+fn next_role(role: &str) -> &'static str {
+    match role {
+        "User" => "Admin",
+        "Admin" => "Moderator",
+        _ => "User",
+    }
 }
 
 #[derive(Clone)]
 struct App {
     users: Arc<Mutex<Vec<User>>>,
-    notification: Arc<Mutex<Option<Notification>>>,
 }
 
 impl Component for App {
@@ -171,7 +170,8 @@ impl Component for App {
         let (selected_index, set_selected_index) = use_state(|| 0usize);
 
         let users_clone = self.users.clone();
-        let notification_clone = self.notification.clone();
+        let toast = use_toast();
+        let confirm = use_confirm();
 
         // Create User Mutation
         let create_mutation = use_mutation(
@@ -182,24 +182,33 @@ impl Component for App {
                     .retry_attempts(3)
                     .retry_delay(Duration::from_millis(500))
                     .retry_exponential_backoff(true)
+                    .scope(MutationScope::new("users"))
                     .on_success({
                         let users = users_clone.clone();
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |user: &User, _vars, _ctx| {
+                            tracing::info!(id = user.id, "created user '{}'", user.name);
                             users.lock().push(user.clone());
-                            *notif.lock() = Some(Notification {
-                                message: format!("✅ User '{}' created successfully!", user.name),
-                                notification_type: NotificationType::Success,
-                            });
+                            toast.success(format!("User '{}' created successfully!", user.name));
                         }
                     })
                     .on_error({
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |error, _vars, _ctx| {
-                            *notif.lock() = Some(Notification {
-                                message: format!("❌ Failed to create user: {}", error),
-                                notification_type: NotificationType::Error,
-                            });
+                            tracing::warn!("failed to create user: {}", error);
+                            toast.error(format!("Failed to create user: {}", error));
+                        }
+                    })
+                    .on_settled({
+                        let toast = toast.clone();
+                        move |data, error, request: &CreateUserRequest, _ctx| {
+                            // Neither data nor error: the create was cancelled
+                            if data.is_none() && error.is_none() {
+                                toast.info(
+                                    format!("Creating '{}' cancelled", request.name),
+                                    Duration::from_secs(3),
+                                );
+                            }
                         }
                     })
                     .build(),
@@ -213,27 +222,24 @@ impl Component for App {
                 MutationOptions::builder()
                     .retry(true)
                     .retry_attempts(2)
+                    .scope(MutationScope::new("users"))
                     .on_success({
                         let users = users_clone.clone();
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |updated_user: &User, _vars, _ctx| {
+                            tracing::info!(id = updated_user.id, role = %updated_user.role, "updated user");
                             let mut users = users.lock();
                             if let Some(user) = users.iter_mut().find(|u| u.id == updated_user.id) {
                                 *user = updated_user.clone();
                             }
-                            *notif.lock() = Some(Notification {
-                                message: format!("✅ User '{}' updated!", updated_user.name),
-                                notification_type: NotificationType::Success,
-                            });
+                            toast.success(format!("User '{}' updated!", updated_user.name));
                         }
                     })
                     .on_error({
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |error, _vars, _ctx| {
-                            *notif.lock() = Some(Notification {
-                                message: format!("❌ Update failed: {}", error),
-                                notification_type: NotificationType::Error,
-                            });
+                            tracing::warn!("failed to update user: {}", error);
+                            toast.error(format!("Update failed: {}", error));
                         }
                     })
                     .build(),
@@ -245,25 +251,22 @@ impl Component for App {
             |user_id: u32| async move { delete_user_api(user_id).await },
             Some(
                 MutationOptions::builder()
+                    .scope(MutationScope::new("users"))
                     .on_success({
                         let users = users_clone.clone();
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |deleted_id: &u32, _vars, _ctx| {
+                            tracing::info!(id = deleted_id, "deleted user");
                             let mut users = users.lock();
                             users.retain(|u| u.id != *deleted_id);
-                            *notif.lock() = Some(Notification {
-                                message: "🗑️  User deleted successfully!".to_string(),
-                                notification_type: NotificationType::Success,
-                            });
+                            toast.success("User deleted successfully!");
                         }
                     })
                     .on_error({
-                        let notif = notification_clone.clone();
+                        let toast = toast.clone();
                         move |error, _vars, _ctx| {
-                            *notif.lock() = Some(Notification {
-                                message: format!("❌ Delete failed: {}", error),
-                                notification_type: NotificationType::Error,
-                            });
+                            tracing::warn!("failed to delete user: {}", error);
+                            toast.error(format!("Delete failed: {}", error));
                         }
                     })
                     .build(),
@@ -277,10 +280,16 @@ impl Component for App {
         let form_dispatch_clone = form_dispatch.clone();
         let form_state_clone = form_state.clone();
         let selected_index_clone = selected_index.clone();
+        let confirm_for_kb = confirm.clone();
 
         use_keyboard_press(move |key| {
             let form = form_state_clone.get();
 
+            // The confirm dialog takes all keys while it is open
+            if confirm_for_kb.is_open() {
+                return;
+            }
+
             match key.code {
                 KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     request_exit();
@@ -293,7 +302,7 @@ impl Component for App {
                 {
                     form_dispatch_clone.dispatch(FormAction::Reset);
                 }
-                KeyCode::Char('c') if form.is_open => {
+                KeyCode::Enter if form.is_open => {
                     if !form.name.is_empty() && !form.email.is_empty() {
                         create_mut_clone.mutate(CreateUserRequest {
                             name: form.name.clone(),
@@ -303,41 +312,31 @@ impl Component for App {
                         form_dispatch_clone.dispatch(FormAction::Submit);
                     }
                 }
-                KeyCode::Char('1') if form.is_open => {
-                    // Toggle role between User, Admin, Moderator
-                    let new_role = match form.role.as_str() {
-                        "User" => "Admin",
-                        "Admin" => "Moderator",
-                        _ => "User",
-                    };
+                KeyCode::Up | KeyCode::Down if form.is_open => {
+                    let new_role = next_role(&form.role);
                     form_dispatch_clone.dispatch(FormAction::SetRole(new_role.to_string()));
                 }
-                KeyCode::Char(c)
-                    if form.is_open && c.is_alphanumeric() || c == '@' || c == '.' || c == ' ' =>
+                KeyCode::Char('e')
+                    if key.modifiers.contains(KeyModifiers::CONTROL) && form.is_open =>
                 {
-                    // Simple text input - append to name field
-                    let mut new_name = form.name.clone();
-                    new_name.push(c);
-                    form_dispatch_clone.dispatch(FormAction::SetName(new_name));
-                }
-                KeyCode::Char('2') if form.is_open => {
-                    // Switch to email input mode (for demo, we'll use a preset)
+                    // Fill the email in from the name (for demo, we'll use a preset)
                     let email =
                         format!("{}@example.com", form.name.to_lowercase().replace(' ', "."));
                     form_dispatch_clone.dispatch(FormAction::SetEmail(email));
                 }
-                KeyCode::Backspace if form.is_open && !form.name.is_empty() => {
-                    let mut new_name = form.name.clone();
-                    new_name.pop();
-                    form_dispatch_clone.dispatch(FormAction::SetName(new_name));
-                }
                 KeyCode::Char('d') if !form.is_open => {
-                    let users = users_for_kb.lock();
-                    if let Some(user) = users.get(selected_index_clone.get()) {
-                        delete_mut_clone.mutate(user.id);
+                    let user = users_for_kb.lock().get(selected_index_clone.get()).cloned();
+                    if let Some(user) = user {
+                        let confirm = confirm_for_kb.clone();
+                        let delete_mutation = delete_mut_clone.clone();
+                        tokio::spawn(async move {
+                            if confirm.ask(format!("Delete '{}'?", user.name)).await {
+                                delete_mutation.mutate(user.id);
+                            }
+                        });
                     }
                 }
-                KeyCode::Char('x') => {
+                KeyCode::Char('x') if !form.is_open => {
                     // Cancel any pending mutations
                     create_mut_clone.cancel();
                     delete_mut_clone.cancel();
@@ -345,17 +344,6 @@ impl Component for App {
                 KeyCode::Esc => {
                     form_dispatch_clone.dispatch(FormAction::Close);
                 }
-                KeyCode::Up if !form.is_open => {
-                    if selected_index_clone.get() > 0 {
-                        set_selected_index.set(selected_index_clone.get() - 1);
-                    }
-                }
-                KeyCode::Down if !form.is_open => {
-                    let users = users_for_kb.lock();
-                    if selected_index_clone.get() < users.len().saturating_sub(1) {
-                        set_selected_index.set(selected_index_clone.get() + 1);
-                    }
-                }
                 _ => {}
             }
         });
@@ -365,7 +353,6 @@ impl Component for App {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3), // Title
-                Constraint::Length(3), // Notification
                 Constraint::Min(10),   // Main content
                 Constraint::Length(6), // Status panel
                 Constraint::Length(5), // Controls
@@ -373,23 +360,91 @@ impl Component for App {
             .split(area);
 
         render_title(buffer, chunks[0]);
-        render_notification(buffer, chunks[1], &self.notification);
 
         let form = form_state.get();
-        if form.is_open {
-            render_create_form(buffer, chunks[2], &form, &create_mutation);
+        let (table_area, name_area) = if form.is_open {
+            let name_area = render_create_form(buffer, chunks[1], &form, &create_mutation);
+            (Rect::default(), name_area)
         } else {
-            render_user_list(buffer, chunks[2], &self.users, selected_index.get());
-        }
+            (
+                render_user_list(buffer, chunks[1], &self.users),
+                Rect::default(),
+            )
+        };
+
+        // Like the name input below, rendered every frame so its hooks keep their place
+        let users = self.users.lock().clone();
+        DataTableComponent::new(
+            DataTableProps::default()
+                .columns(vec![
+                    Column::new("ID", 5),
+                    Column::new("Name", 17),
+                    Column::new("Email", 25),
+                    Column::new("Role", 10),
+                ])
+                .rows(
+                    users
+                        .iter()
+                        .map(|user| {
+                            vec![
+                                user.id.to_string(),
+                                user.name.clone(),
+                                user.email.clone(),
+                                user.role.clone(),
+                            ]
+                        })
+                        .collect(),
+                )
+                .selected(selected_index.get())
+                .focused(!form.is_open && !confirm.is_open())
+                .header_style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .on_change(move |index| set_selected_index.set(index))
+                .on_select({
+                    let update_mutation = update_mutation.clone();
+                    move |index: usize| {
+                        // Enter promotes the user to the next role
+                        if let Some(user) = users.get(index) {
+                            update_mutation.mutate(UpdateUserRequest {
+                                id: user.id,
+                                name: user.name.clone(),
+                                email: user.email.clone(),
+                                role: next_role(&user.role).to_string(),
+                            });
+                        }
+                    }
+                }),
+        )
+        .render(table_area, buffer);
+
+        // Rendered every frame, even off-screen, so its hooks keep their place
+        TextInputComponent::new(
+            TextInputProps::default()
+                .value(form.name.clone())
+                .placeholder("Type a name")
+                .style(Style::default().fg(Color::White))
+                .focused(form.is_open && !confirm.is_open())
+                .on_change(move |name| form_dispatch.dispatch(FormAction::SetName(name))),
+        )
+        .render(name_area, buffer);
 
         render_status_panel(
             buffer,
-            chunks[3],
+            chunks[2],
             &create_mutation,
             &update_mutation,
             &delete_mutation,
         );
-        render_controls(buffer, chunks[4], form.is_open);
+        render_controls(buffer, chunks[3], form.is_open);
     }
 }
 
@@ -415,39 +470,8 @@ fn render_title(buffer: &mut Buffer, area: Rect) {
     title.render(area, buffer);
 }
 
-fn render_notification(
-    buffer: &mut Buffer,
-    area: Rect,
-    notification: &Arc<Mutex<Option<Notification>>>,
-) {
-    let notif = notification.lock();
-
-    if let Some(n) = notif.as_ref() {
-        let (color, icon) = match n.notification_type {
-            NotificationType::Success => (Color::Green, "✅"),
-            NotificationType::Error => (Color::Red, "❌"),
-        };
-
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(color))
-            .border_type(BorderType::Rounded);
-
-        let text = Paragraph::new(format!("{} {}", icon, n.message))
-            .alignment(Alignment::Center)
-            .style(Style::default().fg(color).add_modifier(Modifier::BOLD))
-            .block(block);
-
-        text.render(area, buffer);
-    }
-}
-
-fn render_user_list(
-    buffer: &mut Buffer,
-    area: Rect,
-    users: &Arc<Mutex<Vec<User>>>,
-    selected_index: usize,
-) {
+/// Draws the user list frame and returns the area for its table
+fn render_user_list(buffer: &mut Buffer, area: Rect, users: &Arc<Mutex<Vec<User>>>) -> Rect {
     let users = users.lock();
 
     let block = Block::default()
@@ -475,67 +499,19 @@ fn render_user_list(
         .alignment(Alignment::Center);
 
         empty_text.render(inner, buffer);
-        return;
+        return Rect::default();
     }
 
-    let mut lines = vec![
-        Line::from(vec![
-            Span::styled(
-                "ID    ",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "Name              ",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "Email                     ",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "Role",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        Line::from("─".repeat(80)),
-    ];
-
-    for (i, user) in users.iter().enumerate() {
-        let style = if i == selected_index {
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default()
-        };
-
-        lines.push(Line::from(vec![
-            Span::styled(format!("{:<6}", user.id), style),
-            Span::styled(format!("{:<18}", user.name), style),
-            Span::styled(format!("{:<26}", user.email), style),
-            Span::styled(&user.role, style),
-        ]));
-    }
-
-    let list = Paragraph::new(lines);
-    list.render(inner, buffer);
+    inner
 }
 
+/// Draws the create form and returns the area of its name input
 fn render_create_form(
     buffer: &mut Buffer,
     area: Rect,
     form: &FormState,
     mutation: &Mutation<User, ApiError, CreateUserRequest>,
-) {
+) -> Rect {
     let block = Block::default()
         .title("➕ Create New User")
         .borders(Borders::ALL)
@@ -552,22 +528,23 @@ fn render_create_form(
         "Ready to create"
     };
 
-    let lines = vec![
+    let [fields_area, status_area] =
+        Layout::vertical([Constraint::Length(6), Constraint::Min(0)]).areas(inner);
+    // The fields sit in a centered column so their values line up
+    let [_, fields_area, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Max(50),
+        Constraint::Fill(1),
+    ])
+    .areas(fields_area);
+
+    let label = Style::default().fg(Color::Yellow);
+    let fields = vec![
         Line::from(""),
-        Line::from(vec![
-            Span::styled("Name:  ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                if form.name.is_empty() {
-                    "_"
-                } else {
-                    &form.name
-                },
-                Style::default().fg(Color::White),
-            ),
-        ]),
+        Line::from(Span::styled("Name:  ", label)),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Email: ", Style::default().fg(Color::Yellow)),
+            Span::styled("Email: ", label),
             Span::styled(
                 if form.email.is_empty() {
                     "_"
@@ -579,9 +556,13 @@ fn render_create_form(
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Role:  ", Style::default().fg(Color::Yellow)),
+            Span::styled("Role:  ", label),
             Span::styled(&form.role, Style::default().fg(Color::White)),
         ]),
+    ];
+    Paragraph::new(fields).render(fields_area, buffer);
+
+    let lines = vec![
         Line::from(""),
         Line::from("─".repeat(60)),
         Line::from(""),
@@ -597,13 +578,23 @@ fn render_create_form(
         )),
         Line::from(""),
         Line::from(Span::styled(
-            "Press 'c' to create • 'Esc' to cancel",
+            "Press 'Enter' to create • 'Esc' to cancel",
             Style::default().fg(Color::DarkGray),
         )),
     ];
 
-    let form = Paragraph::new(lines).alignment(Alignment::Center);
-    form.render(inner, buffer);
+    let status = Paragraph::new(lines).alignment(Alignment::Center);
+    status.render(status_area, buffer);
+
+    // The name input goes after its label
+    let label_width = 7;
+    Rect {
+        x: fields_area.x + label_width,
+        y: fields_area.y + 1,
+        width: fields_area.width.saturating_sub(label_width),
+        height: 1,
+    }
+    .intersection(fields_area)
 }
 
 fn render_status_panel(
@@ -682,14 +673,14 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
                 ),
                 Span::styled(" Name  ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    "2",
+                    "Ctrl+E",
                     Style::default()
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" Auto-email  ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    "1",
+                    "↑/↓",
                     Style::default()
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD),
@@ -698,14 +689,14 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
             ]),
             Line::from(vec![
                 Span::styled(
-                    "c",
+                    "Enter",
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" Create  ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    "r",
+                    "Ctrl+R",
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -717,12 +708,12 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
                 ),
                 Span::styled(" Cancel  ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    "Backspace",
+                    "Ctrl+W",
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" Delete char", Style::default().fg(Color::Gray)),
+                Span::styled(" Delete word", Style::default().fg(Color::Gray)),
             ]),
         ]
     } else {
@@ -746,7 +737,14 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" Navigate", Style::default().fg(Color::Gray)),
+                Span::styled(" Navigate  ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "Enter",
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(" Promote", Style::default().fg(Color::Gray)),
             ]),
             Line::from(vec![
                 Span::styled(
@@ -756,6 +754,13 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" Cancel mutation  ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "F12",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(" Logs  ", Style::default().fg(Color::Gray)),
                 Span::styled(
                     "Ctrl+Q",
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
@@ -774,6 +779,9 @@ fn render_controls(buffer: &mut Buffer, area: Rect, in_form: bool) {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Keep logs in memory; printing would corrupt the alternate screen
+    reratui::logging::init_logging(DEFAULT_LOG_CAPACITY)?;
+
     // Initialize with some sample users
     let initial_users = vec![
         User {
@@ -794,13 +802,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             email: "carol@example.com".to_string(),
             role: "Moderator".to_string(),
         },
+        User {
+            id: 4,
+            name: "Dana 🎨 Kim".to_string(),
+            email: "dana@example.com".to_string(),
+            role: "User".to_string(),
+        },
     ];
 
     let app = App {
         users: Arc::new(Mutex::new(initial_users)),
-        notification: Arc::new(Mutex::new(None)),
     };
 
-    render(move || app.clone().into()).await?;
+    render(move || {
+        let app = Element::component(ConfirmProvider::new(
+            ConfirmProviderProps::default()
+                .title(" 🗑️  Confirm Delete ")
+                .with_children(vec![app.clone().into()]),
+        ));
+        let app = Element::component(ToastProvider::new(
+            ToastProviderProps::default().with_children(vec![app]),
+        ));
+        Element::component(LogOverlay::new(
+            LogOverlayProps::default().with_children(vec![app]),
+        ))
+    })
+    .await?;
     Ok(())
 }
@@ -58,14 +58,20 @@ impl Component for App {
     fn render(&self, area: Rect, buffer: &mut Buffer) {
         let (search_query, set_search_query) = use_state(|| String::from("rust"));
         let current_query = search_query.get();
+        let (selected_repo, set_selected_repo) = use_state(|| 0usize);
 
-        // Query with caching and background refresh
+        // Query with caching, polled every 30 seconds
         let query_options = QueryOptions {
             enabled: true,
-            stale_time: Duration::from_secs(30), // Refresh every 30 seconds
+            stale_time: Duration::ZERO, // Polling keeps the data current
             cache_time: Duration::from_secs(300), // Cache for 5 minutes
             retry: true,
             retry_attempts: 3,
+            refetch_interval: Some(Duration::from_secs(30)),
+            refetch_interval_in_background: false,
+            // Keep the last search's results up while a new term loads
+            keep_previous_data: true,
+            ..Default::default()
         };
 
         // Clone for the query closure
@@ -79,6 +85,15 @@ impl Component for App {
             Some(query_options),
         );
 
+        // Just the repo names, derived once per response
+        let repo_names = use_query_select(&query_result, |response: &SearchResponse| {
+            response
+                .items
+                .iter()
+                .map(|repo| repo.name.clone())
+                .collect::<Vec<_>>()
+        });
+
         // Clone for keyboard handler
         let refetch = query_result.refetch.clone();
         let invalidate = query_result.invalidate.clone();
@@ -122,8 +137,14 @@ impl Component for App {
             .split(area);
 
         render_title(buffer, chunks[0]);
-        render_search_info(buffer, chunks[1], &current_query, &query_result);
-        render_results(buffer, chunks[2], &query_result);
+        render_search_info(buffer, chunks[1], &current_query, &repo_names);
+        render_results(
+            buffer,
+            chunks[2],
+            &query_result,
+            selected_repo.get(),
+            Callback::from(move |index| set_selected_repo.set(index)),
+        );
         render_status(buffer, chunks[3], &query_result);
         render_controls(buffer, chunks[4]);
     }
@@ -155,7 +176,7 @@ fn render_search_info(
     buffer: &mut Buffer,
     area: Rect,
     query: &str,
-    result: &QueryResult<SearchResponse, String>,
+    result: &QueryResult<Vec<String>, String>,
 ) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -176,9 +197,14 @@ fn render_search_info(
         ""
     };
 
+    let names = match &result.data {
+        Some(names) if !names.is_empty() => format!(" → {}", names.join(", ")),
+        _ => String::new(),
+    };
+
     let text = Paragraph::new(format!(
-        "{} Searching for: \"{}\"{}",
-        status_icon, query, stale_indicator
+        "{} Searching for: \"{}\"{}{}",
+        status_icon, query, stale_indicator, names
     ))
     .alignment(Alignment::Center)
     .style(Style::default().fg(Color::White))
@@ -187,20 +213,26 @@ fn render_search_info(
     text.render(area, buffer);
 }
 
-fn render_results(buffer: &mut Buffer, area: Rect, result: &QueryResult<SearchResponse, String>) {
+fn render_results(
+    buffer: &mut Buffer,
+    area: Rect,
+    result: &QueryResult<SearchResponse, String>,
+    selected: usize,
+    on_change: Callback<usize>,
+) {
     match result.status {
         QueryStatus::Idle => render_idle(buffer, area),
-        QueryStatus::Loading => render_loading(buffer, area),
-        QueryStatus::Refreshing => {
+        // Loading and refreshing both show the data they have, dimmed
+        QueryStatus::Loading | QueryStatus::Refreshing => {
             if let Some(data) = &result.data {
-                render_repos(buffer, area, data, true);
+                render_repos(buffer, area, data, true, selected, on_change);
             } else {
                 render_loading(buffer, area);
             }
         }
         QueryStatus::Success => {
             if let Some(data) = &result.data {
-                render_repos(buffer, area, data, false);
+                render_repos(buffer, area, data, false, selected, on_change);
             }
         }
         QueryStatus::Error => {
@@ -255,7 +287,14 @@ fn render_loading(buffer: &mut Buffer, area: Rect) {
     text.render(area, buffer);
 }
 
-fn render_repos(buffer: &mut Buffer, area: Rect, data: &SearchResponse, is_refreshing: bool) {
+fn render_repos(
+    buffer: &mut Buffer,
+    area: Rect,
+    data: &SearchResponse,
+    is_refreshing: bool,
+    selected: usize,
+    on_change: Callback<usize>,
+) {
     let title = if is_refreshing {
         "🔄 Top Repositories (refreshing...)".to_string()
     } else {
@@ -275,56 +314,82 @@ fn render_repos(buffer: &mut Buffer, area: Rect, data: &SearchResponse, is_refre
     let inner = block.inner(area);
     block.render(area, buffer);
 
-    let mut lines = vec![Line::from("")];
-
-    for (i, repo) in data.items.iter().enumerate() {
-        // Repository name
-        lines.push(Line::from(vec![
-            Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                &repo.name,
+    let [table_area, details_area] = Layout::vertical([
+        Constraint::Length(data.items.len() as u16 + 1),
+        Constraint::Min(0),
+    ])
+    .areas(inner.inner(Margin::new(1, 1)));
+
+    let rows = data
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, repo)| {
+            vec![
+                format!("{}.", i + 1),
+                repo.name.clone(),
+                format!("⭐ {}", repo.stargazers_count),
+                format!("🍴 {}", repo.forks_count),
+                repo.language
+                    .clone()
+                    .unwrap_or_else(|| "Unknown".to_string()),
+            ]
+        })
+        .collect();
+
+    DataTableComponent::new(
+        DataTableProps::default()
+            .columns(vec![
+                Column::new("#", 3),
+                Column::new("Repository", 28),
+                Column::new("Stars", 10).alignment(Alignment::Right),
+                Column::new("Forks", 9).alignment(Alignment::Right),
+                Column::new("Language", 12),
+            ])
+            .rows(rows)
+            .selected(selected)
+            .on_change(on_change)
+            .header_style(
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]));
-
-        // Description
-        if let Some(desc) = &repo.description {
-            let truncated = if desc.len() > 60 {
-                format!("{}...", &desc[..57])
-            } else {
-                desc.clone()
-            };
-            lines.push(Line::from(Span::styled(
-                format!("   {}", truncated),
+            )
+            .highlight_style(Style::default().bg(Color::Rgb(49, 50, 68))),
+    )
+    .render(table_area, buffer);
+
+    // Details of the selected repository
+    if let Some(repo) = data
+        .items
+        .get(selected.min(data.items.len().saturating_sub(1)))
+    {
+        let description = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                repo.description.as_deref().unwrap_or("No description"),
                 Style::default().fg(Color::Gray),
-            )));
-        }
-
-        // Stats
-        lines.push(Line::from(vec![
-            Span::styled("   ⭐ ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{}", repo.stargazers_count),
-                Style::default().fg(Color::Yellow),
-            ),
-            Span::styled("  🍴 ", Style::default().fg(Color::Blue)),
-            Span::styled(
-                format!("{}", repo.forks_count),
-                Style::default().fg(Color::Blue),
-            ),
-            Span::styled(
-                format!("  {}", repo.language.as_deref().unwrap_or("Unknown")),
-                Style::default().fg(Color::Magenta),
-            ),
-        ]));
-
-        lines.push(Line::from(""));
+            )),
+        ])
+        .wrap(Wrap { trim: false });
+        let description_height = description.line_count(details_area.width) as u16;
+        let [description_area, link_area] =
+            Layout::vertical([Constraint::Length(description_height), Constraint::Min(0)])
+                .areas(details_area);
+        description.render(description_area, buffer);
+
+        // Clickable in terminals that support OSC 8 links
+        Hyperlink::from_url(&repo.html_url)
+            .style(
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::UNDERLINED),
+            )
+            .render(link_area, buffer);
     }
 
-    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
-    paragraph.render(inner, buffer);
+    if is_refreshing {
+        buffer.set_style(area, Style::default().add_modifier(Modifier::DIM));
+    }
 }
 
 fn render_error(buffer: &mut Buffer, area: Rect, error: &str) {
@@ -472,6 +537,13 @@ fn render_controls(buffer: &mut Buffer, area: Rect) {
             Span::styled(" Clear cache", Style::default().fg(Color::Gray)),
         ]),
         Line::from(vec![
+            Span::styled(
+                "↑↓",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" Select repo  ", Style::default().fg(Color::Gray)),
             Span::styled(
                 "Ctrl+Q",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
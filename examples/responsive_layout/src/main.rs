@@ -1,30 +1,26 @@
 //! Responsive Layout Example
 //!
-//! Demonstrates the use_media_query hook for creating responsive TUI layouts
-//! that adapt to different terminal sizes.
+//! Demonstrates the use_breakpoint and use_media_query hooks for creating
+//! responsive TUI layouts that adapt to different terminal sizes.
 
 use reratui::prelude::*;
 
 /// Main app component with responsive layout
 #[component]
 fn ResponsiveApp() -> Element {
-    // Define breakpoints using media queries
-    let is_mobile = use_media_query(|(w, _)| w < 60);
-    let is_tablet = use_media_query(|(w, _)| (60..120).contains(&w));
-    let is_desktop = use_media_query(|(w, _)| w >= 120);
+    // Mobile below 60 columns, tablet below 120, desktop from there
+    let breakpoint = use_breakpoint();
 
     // Get actual dimensions for display
-    let (width, height) = use_terminal_dimensions();
+    let viewport = use_viewport();
+    let (width, height) = (viewport.width, viewport.height);
 
     // Determine layout based on screen size
-    let layout_name = if is_mobile {
-        "Mobile"
-    } else if is_tablet {
-        "Tablet"
-    } else if is_desktop {
-        "Desktop"
-    } else {
-        "Unknown"
+    let layout_name = match breakpoint {
+        Breakpoint::Mobile => "Mobile",
+        Breakpoint::Tablet => "Tablet",
+        Breakpoint::Desktop => "Desktop",
+        Breakpoint::Custom(name) => name,
     };
 
     rsx! {
@@ -40,12 +36,10 @@ fn ResponsiveApp() -> Element {
             <Header layout_name={layout_name.to_string()} />
 
             {/* Main content - changes based on screen size */}
-            {if is_mobile {
-                rsx! { <MobileLayout width={width} height={height} /> }
-            } else if is_tablet {
-                rsx! { <TabletLayout width={width} height={height} /> }
-            } else {
-                rsx! { <DesktopLayout width={width} height={height} /> }
+            {match breakpoint {
+                Breakpoint::Mobile => rsx! { <MobileLayout width={width} height={height} /> },
+                Breakpoint::Tablet => rsx! { <TabletLayout width={width} height={height} /> },
+                _ => rsx! { <DesktopLayout width={width} height={height} /> },
             }}
 
             {/* Footer */}
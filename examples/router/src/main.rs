@@ -2,7 +2,119 @@
 //!
 //! A multi-page application demonstrating routing and navigation.
 
+use reratui::prelude::*;
+use reratui_router::{RouterComponent, RouterProps, Routes, use_navigate, use_route};
+
+const USERS: [&str; 3] = ["Ada Lovelace", "Alan Turing", "Grace Hopper"];
+
+/// Lists the users and navigates to the one picked with a number key
+#[component]
+fn Home() -> Element {
+    let navigate = use_navigate();
+    use_keyboard_press(move |key| match key.code {
+        KeyCode::Char(c @ '1'..='3') => navigate.push(format!("/users/{c}")),
+        KeyCode::Char('a') => navigate.push("/about"),
+        _ => {}
+    });
+
+    let lines: Vec<String> = USERS
+        .iter()
+        .enumerate()
+        .map(|(index, name)| format!("{}: {}", index + 1, name))
+        .collect();
+
+    rsx! {
+        <Block title="🏠 Users" borders={Borders::ALL}>
+            <Paragraph>{lines.join("\n")}</Paragraph>
+        </Block>
+    }
+}
+
+/// Shows the user selected by the `:id` route parameter
+#[component]
+fn UserDetails() -> Element {
+    let route = use_route();
+    let id = route.param("id").unwrap_or_default().to_string();
+    let name = id
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| USERS.get(index.wrapping_sub(1)))
+        .copied()
+        .unwrap_or("Unknown user");
+
+    let navigate = use_navigate();
+    use_keyboard_press(move |key| {
+        if key.code == KeyCode::Esc {
+            navigate.back();
+        }
+    });
+
+    rsx! {
+        <Block title={format!("👤 User {id}")} borders={Borders::ALL}>
+            <Paragraph style={Style::default().fg(Color::Cyan)}>
+                {format!("Name: {name}\nPath: {}", route.path())}
+            </Paragraph>
+        </Block>
+    }
+}
+
+/// A static page reached with `a`
+#[component]
+fn About() -> Element {
+    let navigate = use_navigate();
+    use_keyboard_press(move |key| {
+        if key.code == KeyCode::Esc {
+            navigate.back();
+        }
+    });
+
+    rsx! {
+        <Block title="ℹ️ About" borders={Borders::ALL}>
+            <Paragraph>{"Routes are matched in order; the first match wins."}</Paragraph>
+        </Block>
+    }
+}
+
+/// Shown for paths without a matching route
+#[component]
+fn NotFound() -> Element {
+    rsx! {
+        <Paragraph style={Style::default().fg(Color::Red)}>{"Page not found"}</Paragraph>
+    }
+}
+
+#[component]
+fn App() -> Element {
+    use_keyboard_press(|key| {
+        if key.code == KeyCode::Char('q') {
+            request_exit();
+        }
+    });
+
+    let routes = Routes::new()
+        .route("/", || rsx! { <Home /> })
+        .route("/users/:id", || rsx! { <UserDetails /> })
+        .route("/about", || rsx! { <About /> })
+        .fallback(|| rsx! { <NotFound /> });
+
+    rsx! {
+        <Layout
+            direction={Direction::Vertical}
+            constraints={vec![Constraint::Min(0), Constraint::Length(1)]}
+        >
+            <Router routes={routes} initial_path="/" />
+            <Paragraph style={Style::default().fg(Color::DarkGray)}>
+                {"1-3: Open user • a: About • Esc: Back • q: Quit"}
+            </Paragraph>
+        </Layout>
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    render(|| {
+        rsx! { <App /> }
+    })
+    .await?;
     Ok(())
 }
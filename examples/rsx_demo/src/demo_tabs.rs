@@ -364,15 +364,18 @@ pub fn render_if_else_tab(state: &DemoState) -> Element {
                             </Paragraph>
                         }}
 
-                        {if state.show_debug {
+                        <Show
+                            when={state.show_debug}
+                            fallback={rsx! {
+                                <Paragraph alignment={Alignment::Center}>
+                                    {"🔒 Debug mode off"}
+                                </Paragraph>
+                            }}
+                        >
                             <Paragraph alignment={Alignment::Center}>
                                 {"🐛 Debug mode active"}
                             </Paragraph>
-                        } else {
-                            <Paragraph alignment={Alignment::Center}>
-                                {"🔒 Debug mode off"}
-                            </Paragraph>
-                        }}
+                        </Show>
 
                         <Paragraph alignment={Alignment::Center}>
                             {format!("Counter: {} | Debug: {}", state.counter, state.show_debug)}
@@ -703,7 +706,7 @@ pub fn render_help_tab(_state: &DemoState) -> Element {
                             {"j/k - Increment/Decrement counter"}
                         </Paragraph>
                         <Paragraph alignment={Alignment::Left}>
-                            {"Tab - Switch between tabs"}
+                            {"Tab/Shift+Tab or 1-7 - Switch between tabs"}
                         </Paragraph>
                         <Paragraph alignment={Alignment::Left}>
                             {"d - Toggle debug mode"}
@@ -104,7 +104,7 @@ impl Default for DemoState {
 
 /// Control instructions for the demo
 pub fn get_control_instructions() -> &'static str {
-    "j/k: Counter ±1 | Tab: Next section | d: Toggle debug | t: Change theme | r: Reset"
+    "j/k: Counter ±1 | Tab/1-7: Switch section | d: Toggle debug | t: Change theme | r: Reset"
 }
 
 /// Get features list for overview
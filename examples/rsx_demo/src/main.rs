@@ -32,6 +32,27 @@ fn Title(props: &TitleProps) -> Element {
     }
 }
 
+#[derive(Props)]
+struct DemoTabContentProps {
+    state: DemoState,
+}
+
+/// The section of the active tab
+#[component]
+fn DemoTabContent(props: &DemoTabContentProps) -> Element {
+    let state = &props.state;
+    match use_active_tab() {
+        0 => render_overview_tab(state),
+        1 => render_match_tab(state),
+        2 => render_logical_and_tab(state),
+        3 => render_if_else_tab(state),
+        4 => render_mixed_conditionals_tab(state),
+        5 => render_nested_layouts_tab(state),
+        6 => render_help_tab(state),
+        _ => render_overview_tab(state), // Fallback
+    }
+}
+
 /// A comprehensive component that demonstrates all RSX macro features
 struct RsxDemoImpl {
     title: String,
@@ -52,6 +73,8 @@ impl Component for RsxDemoImpl {
         let (selected_tab, set_selected_tab) = use_state(|| 0);
         let (show_debug, set_show_debug) = use_state(|| true);
         let (theme_mode, set_theme_mode) = use_state(|| 0);
+        let (tab_pending, start_transition) = use_transition();
+        let tab_titles = use_memo(get_tab_titles, ());
 
         // Process keyboard events
         if let Some(Event::Key(key)) = use_event()
@@ -64,9 +87,6 @@ impl Component for RsxDemoImpl {
                         set_counter.update(|prev| prev - 1);
                     }
                 }
-                KeyCode::Tab => {
-                    set_selected_tab.set((selected_tab.get() + 1) % get_tab_titles().len())
-                }
                 KeyCode::Char('d') => set_show_debug.set(!show_debug.get()),
                 KeyCode::Char('t') => set_theme_mode.set((theme_mode.get() + 1) % 3),
                 KeyCode::Char('r') => {
@@ -90,8 +110,7 @@ impl Component for RsxDemoImpl {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3), // Title
-                Constraint::Length(3), // Tabs
-                Constraint::Min(5),    // Content
+                Constraint::Min(8),    // Tabs and content
                 Constraint::Length(3), // Help
             ])
             .split(area);
@@ -104,31 +123,28 @@ impl Component for RsxDemoImpl {
         );
         title_vnode.render(chunks[0], buffer);
 
-        // Render tabs using rsx!
+        // The tab view switches on Tab/Shift+Tab and number keys; its content
+        // picks the section to show from the active tab
         let tabs_vnode = rsx!(
-            <Tabs
-                titles={get_tab_titles()}
-                select={selected_tab.get()}
+            <TabView
+                titles={tab_titles.clone()}
+                selected={selected_tab.get()}
+                on_change={move |next_tab: usize| {
+                    // Keep the current tab on screen while the next one renders
+                    let set_selected_tab = set_selected_tab.clone();
+                    start_transition.start(move || set_selected_tab.set(next_tab));
+                }}
                 style={Style::default().fg(Color::White)}
                 highlight_style={Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)}
-                block={Block::default().borders(Borders::ALL)}
-            />
+                block={Block::default()
+                    .borders(Borders::ALL)
+                    .title(if tab_pending { "⏳ Switching..." } else { "" })}
+            >
+                <DemoTabContent state={demo_state} />
+            </TabView>
         );
         tabs_vnode.render(chunks[1], buffer);
 
-        // Render content based on selected tab using modular components
-        let content_vnode = match selected_tab.get() {
-            0 => render_overview_tab(&demo_state),
-            1 => render_match_tab(&demo_state),
-            2 => render_logical_and_tab(&demo_state),
-            3 => render_if_else_tab(&demo_state),
-            4 => render_mixed_conditionals_tab(&demo_state),
-            5 => render_nested_layouts_tab(&demo_state),
-            6 => render_help_tab(&demo_state),
-            _ => render_overview_tab(&demo_state), // Fallback
-        };
-        content_vnode.render(chunks[2], buffer);
-
         // Render help footer
         let help_vnode = rsx!(
             <Block
@@ -141,7 +157,7 @@ impl Component for RsxDemoImpl {
                 </Paragraph>
             </Block>
         );
-        help_vnode.render(chunks[3], buffer);
+        help_vnode.render(chunks[2], buffer);
     }
 }
 
@@ -82,6 +82,7 @@ fn CounterToggle() -> Element {
 
     let current_count = count.get();
     let is_increment = increment_mode.get();
+    let theme = use_theme();
 
     // Handle arrow keys and 'm' for mode toggle
     if let Some(event) = use_event()
@@ -115,7 +116,7 @@ fn CounterToggle() -> Element {
         <Block
             title="🔢 Counter Toggle"
             borders={Borders::ALL}
-            border_style={Style::default().fg(Color::Cyan)}
+            border_style={theme.border_style()}
         >
             <Layout
                 direction={Direction::Vertical}
@@ -128,7 +129,7 @@ fn CounterToggle() -> Element {
                 ]}
             >
                 <Paragraph
-                    style={Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)}
+                    style={theme.title_style()}
                     alignment={Alignment::Center}
                 >
                     {format!("Count: {}", current_count)}
@@ -136,9 +137,9 @@ fn CounterToggle() -> Element {
                 <Paragraph
                     style={
                         if is_increment {
-                            Style::default().fg(Color::Green)
+                            Style::default().fg(theme.primary)
                         } else {
-                            Style::default().fg(Color::Red)
+                            theme.error_style()
                         }
                     }
                     alignment={Alignment::Center}
@@ -152,13 +153,13 @@ fn CounterToggle() -> Element {
                     }
                 </Paragraph>
                 <Paragraph
-                    style={Style::default().fg(Color::Yellow)}
+                    style={Style::default().fg(theme.secondary)}
                     alignment={Alignment::Center}
                 >
                     {"↑/↓: Count"}
                 </Paragraph>
                 <Paragraph
-                    style={Style::default().fg(Color::Gray)}
+                    style={Style::default().fg(theme.foreground)}
                     alignment={Alignment::Center}
                 >
                     {"m: Toggle mode"}
@@ -168,6 +169,18 @@ fn CounterToggle() -> Element {
     }
 }
 
+/// Names of the themes cycled with the `t` key
+const THEME_NAMES: [&str; 3] = ["dark", "light", "high contrast"];
+
+/// The built-in theme at `index` in [`THEME_NAMES`]
+fn theme_at(index: usize) -> Theme {
+    match index {
+        1 => Theme::light(),
+        2 => Theme::high_contrast(),
+        _ => Theme::dark(),
+    }
+}
+
 /// Main app component
 struct SimpleToggleApp;
 
@@ -179,65 +192,80 @@ impl SimpleToggleApp {
 
 impl Component for SimpleToggleApp {
     fn render(&self, area: Rect, buffer: &mut Buffer) {
-        // Handle quit
+        // Start with the theme matching the terminal background
+        let color_scheme = use_color_scheme();
+        let (theme_index, set_theme_index) = use_state(|| match color_scheme {
+            ColorScheme::Light => 1,
+            ColorScheme::Dark => 0,
+        });
+        let theme_index = theme_index.get();
+
+        // Handle quit and theme switching
         if let Some(event) = use_event()
             && let Event::Key(key) = event
             && key.kind == KeyEventKind::Press
-            && key.code == KeyCode::Char('q')
         {
-            request_exit();
+            match key.code {
+                KeyCode::Char('q') => request_exit(),
+                KeyCode::Char('t') => {
+                    set_theme_index.update(|index| (index + 1) % THEME_NAMES.len())
+                }
+                _ => {}
+            }
         }
 
         let layout = rsx! {
-            <Layout
-                direction={Direction::Vertical}
-                margin={1}
-                constraints={vec![
-                    Constraint::Length(3),  // Header
-                    Constraint::Min(0),     // Content
-                    Constraint::Length(3),  // Footer
-                ]}
-            >
-                {/* Header */}
-                <Block
-                    title="🔄 Simple Toggle Demo"
-                    borders={Borders::ALL}
-                    border_style={Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)}
-                >
-                    <Paragraph
-                        alignment={Alignment::Center}
-                        style={Style::default().fg(Color::White).add_modifier(Modifier::BOLD)}
-                    >
-                        {"useState for Boolean Toggles"}
-                    </Paragraph>
-                </Block>
-
-                {/* Main content */}
+            <ThemeProvider theme={theme_at(theme_index)}>
                 <Layout
-                    direction={Direction::Horizontal}
+                    direction={Direction::Vertical}
+                    margin={1}
                     constraints={vec![
-                        Constraint::Percentage(50),
-                        Constraint::Percentage(50),
+                        Constraint::Length(3),  // Header
+                        Constraint::Min(0),     // Content
+                        Constraint::Length(3),  // Footer
                     ]}
                 >
-                    <ToggleButton label={"🔘 Toggle Switch".to_string()} />
-                    <CounterToggle />
-                </Layout>
+                    {/* Header */}
+                    <Block
+                        title="🔄 Simple Toggle Demo"
+                        borders={Borders::ALL}
+                        border_style={Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)}
+                    >
+                        <Paragraph
+                            alignment={Alignment::Center}
+                            style={Style::default().fg(Color::White).add_modifier(Modifier::BOLD)}
+                        >
+                            {"useState for Boolean Toggles"}
+                        </Paragraph>
+                    </Block>
 
-                {/* Footer */}
-                <Block
-                    title="📋 Controls"
-                    borders={Borders::ALL}
-                    border_style={Style::default().fg(Color::Yellow)}
-                >
-                    <Paragraph
-                        alignment={Alignment::Center}
-                        style={Style::default().fg(Color::Yellow)}
+                    {/* Main content */}
+                    <Layout
+                        direction={Direction::Horizontal}
+                        constraints={vec![
+                            Constraint::Percentage(50),
+                            Constraint::Percentage(50),
+                        ]}
                     >
-                        {"SPACE: Toggle • ↑/↓: Count • m: Mode • q: Quit"}
-                    </Paragraph>
-                </Block>
-            </Layout>
+                        <ToggleButton label={"🔘 Toggle Switch".to_string()} />
+                        <CounterToggle />
+                    </Layout>
+
+                    {/* Footer */}
+                    <Block
+                        title="📋 Controls"
+                        borders={Borders::ALL}
+                        border_style={Style::default().fg(Color::Yellow)}
+                    >
+                        <Paragraph
+                            alignment={Alignment::Center}
+                            style={Style::default().fg(Color::Yellow)}
+                        >
+                            {format!("SPACE: Toggle • ↑/↓: Count • m: Mode • t: Theme ({}) • q: Quit", THEME_NAMES[theme_index])}
+                        </Paragraph>
+                    </Block>
+                </Layout>
+            </ThemeProvider>
         };
 
         layout.render(area, buffer);
@@ -249,7 +277,7 @@ impl Component for SimpleToggleApp {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🔄 Simple Toggle Demo");
     println!("Demonstrates useState patterns for boolean toggles");
-    println!("Controls: SPACE, ↑/↓, m, q\n");
+    println!("Controls: SPACE, ↑/↓, m, t, q\n");
 
     if let Err(err) = render(|| SimpleToggleApp::new().into()).await {
         eprintln!("❌ Error: {:?}", err);
@@ -36,6 +36,9 @@ fn TimeoutDemo() -> Element {
         Duration::from_secs(2),
     );
 
+    let resettable_message = use_resettable_message();
+    let controlled_status = use_controlled_status();
+
     rsx! {
         <Layout
             direction={Direction::Vertical}
@@ -55,90 +58,66 @@ fn TimeoutDemo() -> Element {
                 </Paragraph>
             </Block>
 
-            {/* Main content */}
-            <Layout
-                direction={Direction::Vertical}
-                margin={1}
-                constraints={vec![
-                    Constraint::Length(5),
-                    Constraint::Length(7),
-                    Constraint::Length(7),
-                    Constraint::Min(0),
-                ]}
-            >
-                {/* Auto-hide notification */}
-                <NotificationCard notification={notification.get()} />
-
-                {/* Loading state */}
-                <LoadingCard loading={loading.get()} />
-
-                {/* Resettable timeout */}
-                <ResettableTimeoutCard />
-
-                {/* Controlled timeout */}
-                <ControlledTimeoutCard />
+            {/* Main content: each card is as tall as its wrapped text */}
+            <Layout margin={1}>
+                <AutoLayout>
+                    {notification_card(notification.get())}
+                    {loading_card(loading.get())}
+                    {resettable_timeout_card(resettable_message)}
+                    {controlled_timeout_card(controlled_status)}
+                </AutoLayout>
             </Layout>
         </Layout>
     }
 }
 
-/// Auto-hide notification card
-#[component]
-fn NotificationCard(notification: Option<String>) -> Element {
+/// A bordered card with a status line and a hint, as tall as its wrapped text
+fn card(title: &str, color: Color, status: String, hint: &str) -> Element {
     rsx! {
         <Block
-            title="📢 Auto-Hide Notification"
+            title={title.to_string()}
             borders={Borders::ALL}
-            border_style={Style::default().fg(Color::Yellow)}
+            border_style={Style::default().fg(color)}
+            padding={Padding::uniform(1)}
         >
-            <Layout margin={1}>
-                <Paragraph>
-                    {if let Some(msg) = notification {
-                        msg
-                    } else {
-                        "Notification hidden!".to_string()
-                    }}
-                </Paragraph>
-            </Layout>
+            <Paragraph wrap={Wrap { trim: true }}>
+                {status}
+            </Paragraph>
+            <Paragraph style={Style::default().fg(Color::Gray)} wrap={Wrap { trim: true }}>
+                {hint.to_string()}
+            </Paragraph>
         </Block>
     }
 }
 
+/// Auto-hide notification card
+fn notification_card(notification: Option<String>) -> Element {
+    let message = notification.unwrap_or_else(|| "Notification hidden!".to_string());
+    card(
+        "📢 Auto-Hide Notification",
+        Color::Yellow,
+        message,
+        "Hides itself after 5 seconds",
+    )
+}
+
 /// Loading state card
-#[component]
-fn LoadingCard(loading: bool) -> Element {
-    rsx! {
-        <Block
-            title="⏳ Delayed Loading State"
-            borders={Borders::ALL}
-            border_style={Style::default().fg(Color::Blue)}
-        >
-            <Layout
-                direction={Direction::Vertical}
-                margin={1}
-                constraints={vec![
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                ]}
-            >
-                <Paragraph>
-                    {if loading {
-                        "Status: Loading... (2 seconds)"
-                    } else {
-                        "Status: ✅ Loaded!"
-                    }}
-                </Paragraph>
-                <Paragraph style={Style::default().fg(Color::Gray)}>
-                    {"Simulates async data loading"}
-                </Paragraph>
-            </Layout>
-        </Block>
-    }
+fn loading_card(loading: bool) -> Element {
+    let status = if loading {
+        "Status: Loading... (2 seconds)"
+    } else {
+        "Status: ✅ Loaded!"
+    };
+    card(
+        "⏳ Delayed Loading State",
+        Color::Blue,
+        status.to_string(),
+        "Simulates async data loading",
+    )
 }
 
-/// Resettable timeout card
-#[component]
-fn ResettableTimeoutCard() -> Element {
+/// A message driven by a timeout that keeps being reset
+fn use_resettable_message() -> String {
     let (message, set_message) = use_state(|| "Waiting for timeout...".to_string());
     let (reset_count, set_reset_count) = use_state(|| 0);
 
@@ -167,34 +146,21 @@ fn ResettableTimeoutCard() -> Element {
         Duration::from_millis(1500),
     );
 
-    rsx! {
-        <Block
-            title="🔄 Resettable Timeout"
-            borders={Borders::ALL}
-            border_style={Style::default().fg(Color::Green)}
-        >
-            <Layout
-                direction={Direction::Vertical}
-                margin={1}
-                constraints={vec![
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                ]}
-            >
-                <Paragraph>
-                    {message.get()}
-                </Paragraph>
-                <Paragraph style={Style::default().fg(Color::Gray)}>
-                    {"Auto-resets every 1.5s (simulating user activity)"}
-                </Paragraph>
-            </Layout>
-        </Block>
-    }
+    message.get()
 }
 
-/// Controlled timeout card
-#[component]
-fn ControlledTimeoutCard() -> Element {
+/// Resettable timeout card
+fn resettable_timeout_card(message: String) -> Element {
+    card(
+        "🔄 Resettable Timeout",
+        Color::Green,
+        message,
+        "Auto-resets every 1.5s (simulating user activity)",
+    )
+}
+
+/// The status of a timeout that is started, then cancelled before it fires
+fn use_controlled_status() -> String {
     let (status, set_status) = use_state(|| "Idle".to_string());
 
     let (start, cancel, is_active) = use_timeout_controlled(
@@ -235,29 +201,17 @@ fn ControlledTimeoutCard() -> Element {
         Duration::from_millis(2500),
     );
 
-    rsx! {
-        <Block
-            title="🎮 Controlled Timeout"
-            borders={Borders::ALL}
-            border_style={Style::default().fg(Color::Magenta)}
-        >
-            <Layout
-                direction={Direction::Vertical}
-                margin={1}
-                constraints={vec![
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                ]}
-            >
-                <Paragraph>
-                    {format!("Status: {}", status.get())}
-                </Paragraph>
-                <Paragraph style={Style::default().fg(Color::Gray)}>
-                    {"Starts at 1s, cancelled at 2.5s"}
-                </Paragraph>
-            </Layout>
-        </Block>
-    }
+    status.get()
+}
+
+/// Controlled timeout card
+fn controlled_timeout_card(status: String) -> Element {
+    card(
+        "🎮 Controlled Timeout",
+        Color::Magenta,
+        format!("Status: {status}"),
+        "Starts at 1s, cancelled at 2.5s",
+    )
 }
 
 #[tokio::main]